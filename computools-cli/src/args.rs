@@ -0,0 +1,123 @@
+//! 명령행 인자 파싱과 입력 읽기
+
+use std::collections::HashMap;
+use std::fs;
+use std::io::{self, Read};
+
+/// 서브커맨드 이후의 인자를 위치 인자, 값 옵션, 플래그로 나눈 결과
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Args {
+    pub positionals: Vec<String>,
+    options: HashMap<String, String>,
+    flags: Vec<String>,
+}
+
+impl Args {
+    /// `--name value`, `--name=value`, `--flag` 형식의 인자를 파싱합니다.
+    ///
+    /// `flag_names`에 포함된 옵션은 값을 받지 않는 플래그로 취급합니다.
+    /// `--` 이후의 인자는 모두 위치 인자입니다.
+    pub fn parse(raw: &[String], flag_names: &[&str]) -> Result<Self, String> {
+        let mut args = Args::default();
+        let mut iter = raw.iter();
+
+        while let Some(arg) = iter.next() {
+            if arg == "--" {
+                args.positionals.extend(iter.by_ref().cloned());
+                break;
+            }
+
+            let Some(name) = arg.strip_prefix("--") else {
+                args.positionals.push(arg.clone());
+                continue;
+            };
+
+            if let Some((key, value)) = name.split_once('=') {
+                args.options.insert(key.to_string(), value.to_string());
+            } else if flag_names.contains(&name) {
+                args.flags.push(name.to_string());
+            } else {
+                let value = iter
+                    .next()
+                    .ok_or_else(|| format!("Option --{} requires a value.", name))?;
+                args.options.insert(name.to_string(), value.clone());
+            }
+        }
+
+        Ok(args)
+    }
+
+    pub fn option(&self, name: &str) -> Option<&str> {
+        self.options.get(name).map(|value| value.as_str())
+    }
+
+    pub fn flag(&self, name: &str) -> bool {
+        self.flags.iter().any(|flag| flag == name)
+    }
+
+    /// 첫 번째 위치 인자가 `actions` 중 하나이면 꺼내서 반환합니다. (encode/decode 같은 동작 선택용)
+    pub fn take_action(&mut self, actions: &[&str]) -> Option<String> {
+        match self.positionals.first() {
+            Some(first) if actions.contains(&first.as_str()) => Some(self.positionals.remove(0)),
+            _ => None,
+        }
+    }
+
+    /// 숫자 옵션을 파싱합니다. 옵션이 없으면 `default`를 반환합니다.
+    pub fn number_option<T: std::str::FromStr>(&self, name: &str, default: T) -> Result<T, String> {
+        match self.option(name) {
+            Some(value) => value
+                .trim()
+                .parse::<T>()
+                .map_err(|_| format!("Invalid value for --{}: {}", name, value)),
+            None => Ok(default),
+        }
+    }
+
+    /// 입력 바이트를 읽습니다.
+    ///
+    /// `--file PATH`(`-`는 stdin) → 위치 인자(공백으로 연결) → stdin 순으로 사용합니다.
+    pub fn read_input(&self) -> Result<Vec<u8>, String> {
+        if let Some(path) = self.option("file") {
+            return read_path(path);
+        }
+        if !self.positionals.is_empty() {
+            return Ok(self.positionals.join(" ").into_bytes());
+        }
+        read_stdin()
+    }
+
+    /// 입력을 UTF-8 텍스트로 읽습니다. stdin/파일 끝의 줄바꿈 하나는 제거합니다.
+    pub fn read_text(&self) -> Result<String, String> {
+        let from_positionals = self.option("file").is_none() && !self.positionals.is_empty();
+        let bytes = self.read_input()?;
+        let mut text = String::from_utf8(bytes).map_err(|_| "Input is not valid UTF-8.".to_string())?;
+
+        if !from_positionals {
+            if text.ends_with('\n') {
+                text.pop();
+            }
+            if text.ends_with('\r') {
+                text.pop();
+            }
+        }
+
+        Ok(text)
+    }
+}
+
+fn read_path(path: &str) -> Result<Vec<u8>, String> {
+    if path == "-" {
+        read_stdin()
+    } else {
+        fs::read(path).map_err(|e| format!("Failed to read {}: {}", path, e))
+    }
+}
+
+fn read_stdin() -> Result<Vec<u8>, String> {
+    let mut buffer = Vec::new();
+    io::stdin()
+        .read_to_end(&mut buffer)
+        .map_err(|e| format!("Failed to read stdin: {}", e))?;
+    Ok(buffer)
+}
@@ -0,0 +1,54 @@
+pub mod ascii;
+pub mod base;
+pub mod base64;
+pub mod cipher;
+pub mod crc;
+pub mod hash;
+pub mod html;
+pub mod json;
+pub mod jwt;
+pub mod kdf;
+pub mod pki;
+pub mod quaternion;
+pub mod unixtime;
+pub mod url;
+pub mod uuid;
+
+use crate::args::Args;
+
+/// 서브커맨드 실행 함수. 성공 시 stdout에 쓸 바이트를 반환합니다.
+pub type Handler = fn(Args) -> Result<Vec<u8>, CommandError>;
+
+/// 서브커맨드 실패. 실패해도 stdout에 남길 결과(`hash --check`의 파일별 줄 등)를 함께 담습니다.
+#[derive(Debug)]
+pub struct CommandError {
+    /// 에러 메시지보다 먼저 stdout에 쓸 바이트
+    pub output: Vec<u8>,
+    pub message: String,
+}
+
+impl From<String> for CommandError {
+    fn from(message: String) -> Self {
+        Self { output: Vec::new(), message }
+    }
+}
+
+impl From<&str> for CommandError {
+    fn from(message: &str) -> Self {
+        message.to_string().into()
+    }
+}
+
+/// 텍스트 결과를 줄바꿈으로 끝나는 출력 바이트로 변환합니다.
+pub fn text_output(text: impl Into<String>) -> Vec<u8> {
+    let mut text = text.into();
+    if !text.ends_with('\n') {
+        text.push('\n');
+    }
+    text.into_bytes()
+}
+
+/// 위치 인자에서 encode/decode 동작을 꺼냅니다. 생략하면 encode입니다.
+pub fn take_decode_action(args: &mut Args) -> bool {
+    args.take_action(&["encode", "decode"]).as_deref() == Some("decode")
+}
@@ -0,0 +1,57 @@
+use computools_core::ascii::{self, AsciiMode, BinaryStyle, FormatStyles, HexStyle, OctalStyle};
+
+use super::{CommandError, take_decode_action, text_output};
+use crate::args::Args;
+
+pub const USAGE: &str = "\
+Usage: computools ascii [encode|decode] [options] [input]
+
+encode converts text to ASCII codes; decode converts codes back to text.
+
+Options:
+  --mode MODE          dec | hex | bin | oct (default: dec)
+  --style STYLE        prefix | short | none | escape (encode only, default: prefix)
+  --file PATH          Read input from PATH (- for stdin)
+";
+
+pub const FLAGS: &[&str] = &[];
+
+pub fn run(mut args: Args) -> Result<Vec<u8>, CommandError> {
+    let decode = take_decode_action(&mut args);
+    let mode = match args.option("mode").unwrap_or("dec") {
+        "dec" => AsciiMode::Decimal,
+        "hex" => AsciiMode::Hex,
+        "bin" => AsciiMode::Binary,
+        "oct" => AsciiMode::Octal,
+        other => return Err(format!("Unknown mode '{}'.", other).into()),
+    };
+
+    if decode {
+        let input = args.read_text()?;
+        let bytes = ascii::parse_input(&input, &mode)?;
+        return Ok(text_output(ascii::bytes_to_text(bytes)));
+    }
+
+    let styles = match args.option("style").unwrap_or("prefix") {
+        "prefix" => FormatStyles::default(),
+        "short" => FormatStyles {
+            hex_style: HexStyle::ShortPrefix,
+            binary_style: BinaryStyle::ShortPrefix,
+            octal_style: OctalStyle::ShortPrefix,
+        },
+        "none" => FormatStyles {
+            hex_style: HexStyle::NoPrefix,
+            binary_style: BinaryStyle::NoPrefix,
+            octal_style: OctalStyle::NoPrefix,
+        },
+        "escape" => FormatStyles {
+            hex_style: HexStyle::EscapeSequence,
+            binary_style: BinaryStyle::NoPrefix,
+            octal_style: OctalStyle::EscapeSequence,
+        },
+        other => return Err(format!("Unknown style '{}'.", other).into()),
+    };
+
+    let input = args.read_text()?;
+    Ok(text_output(ascii::convert_text_to_ascii(input.as_bytes(), &mode, &styles)))
+}
@@ -0,0 +1,61 @@
+use computools_core::base;
+
+use super::{CommandError, text_output};
+use crate::args::Args;
+
+pub const USAGE: &str = "\
+Usage: computools base [options] <number>
+
+Options:
+  --from BASE          Input base 2-36 (default: 10). Prefixes like 0x, 0b, 0o are accepted.
+  --to BASE            Output base 2-36 (default: print bases 2, 8, 10 and 16)
+  --precision N        Fractional digits for decimal-point input (default: 6)
+";
+
+pub const FLAGS: &[&str] = &[];
+
+const DEFAULT_BASES: &[(u32, &str)] = &[(2, "BIN"), (8, "OCT"), (10, "DEC"), (16, "HEX")];
+
+pub fn run(args: Args) -> Result<Vec<u8>, CommandError> {
+    let from = args.number_option("from", 10u32)?;
+    let precision = args.number_option("precision", 6u32)?;
+    let to = match args.option("to") {
+        Some(_) => Some(args.number_option("to", 10u32)?),
+        None => None,
+    };
+
+    for value in std::iter::once(from).chain(to) {
+        if !(2..=36).contains(&value) {
+            return Err(format!("Base must be between 2 and 36, got {}.", value).into());
+        }
+    }
+
+    let input = args.read_text()?;
+    let input = input.trim();
+    if input.is_empty() {
+        return Err("Input value is empty.".into());
+    }
+
+    let is_float = input.contains('.');
+    base::validate_input(input, from, is_float)?;
+
+    let convert: Box<dyn Fn(u32) -> String> = if is_float {
+        let value = base::parse_float_input(input, from)?;
+        Box::new(move |to| base::convert_float_to_base(value, to, precision))
+    } else {
+        let value = base::parse_integer_input(input, from)?
+            .ok_or_else(|| "Input contains only a prefix.".to_string())?;
+        Box::new(move |to| base::format_integer(value, to))
+    };
+
+    let output = match to {
+        Some(to) => convert(to),
+        None => DEFAULT_BASES
+            .iter()
+            .map(|(base, label)| format!("{}  {}", label, convert(*base)))
+            .collect::<Vec<String>>()
+            .join("\n"),
+    };
+
+    Ok(text_output(output))
+}
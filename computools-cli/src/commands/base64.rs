@@ -0,0 +1,74 @@
+use computools_core::base64::{self, OutputFormat};
+use computools_core::codec::Codec;
+use computools_core::file_type::detect_file_type;
+
+use super::{CommandError, take_decode_action, text_output};
+use crate::args::Args;
+
+pub const USAGE: &str = "\
+Usage: computools base64 [encode|decode] [options] [input]
+
+Options:
+  --mode MODE          standard | url-safe | no-padding (Base64, default: standard)
+                       base32 | base32hex | base58 | base58check | ascii85 | z85 | base45
+                       quoted-printable | uuencode | yenc | mime-header (email)
+  --format FORMAT      continuous | lines76 | lines64 | chunks4 | chunks8 | data-url | css
+                       (encode only, default: continuous)
+  --mime TYPE          MIME type for data-url/css formats (default: detected or text/plain)
+  --file PATH          Read input from PATH (- for stdin)
+
+decode writes the raw decoded bytes to stdout and also accepts data: URLs (Base64 modes).
+data-url and css formats are only available for Base64 modes.
+Email modes keep their own line structure and only use the continuous format;
+mime-header decode reads a whole header block and decodes its =?charset?B|Q?...?= words.
+";
+
+pub const FLAGS: &[&str] = &[];
+
+pub fn run(mut args: Args) -> Result<Vec<u8>, CommandError> {
+    let decode = take_decode_action(&mut args);
+    let name = args.option("mode").unwrap_or("standard");
+    let codec = Codec::from_key(name).ok_or_else(|| format!("Unknown mode '{}'.", name))?;
+
+    if decode {
+        let input = args.read_text()?;
+        // data: URL이면 Base64 부분만 사용
+        let encoded = base64::parse_data_url(&input)
+            .filter(|_| codec.is_base64())
+            .map(|(_, data)| data)
+            .unwrap_or(input);
+        return Ok(codec.decode(&encoded)?);
+    }
+
+    let format = match args.option("format").unwrap_or("continuous") {
+        "continuous" => OutputFormat::Continuous,
+        "lines76" => OutputFormat::LineBreaks76,
+        "lines64" => OutputFormat::LineBreaks64,
+        "chunks4" => OutputFormat::Chunks4,
+        "chunks8" => OutputFormat::Chunks8,
+        "data-url" => OutputFormat::DataUrlImg,
+        "css" => OutputFormat::DataUrlCss,
+        other => return Err(format!("Unknown format '{}'.", other).into()),
+    };
+    if !codec.is_base64() && matches!(format, OutputFormat::DataUrlImg | OutputFormat::DataUrlCss) {
+        return Err(format!("Data URLs need Base64; {} can't be used with --format {}.", codec.name(), args.option("format").unwrap_or_default()).into());
+    }
+    if codec.is_line_structured() && format != OutputFormat::Continuous {
+        return Err(format!("{} has its own line structure; --format {} can't be applied.", codec.name(), args.option("format").unwrap_or_default()).into());
+    }
+
+    let data = args.read_input()?;
+    let mime_type = args
+        .option("mime")
+        .map(|mime| mime.to_string())
+        .or_else(|| detect_file_type(&data).map(|file_type| file_type.mime_type.to_string()))
+        .unwrap_or_else(|| "text/plain".to_string());
+    let file_info = base64::FileInfo {
+        name: args.option("file").unwrap_or("-").to_string(),
+        size: data.len(),
+        mime_type,
+    };
+
+    let encoded = codec.encode(&data)?;
+    Ok(text_output(base64::format_output(&encoded, &format, Some(&file_info))))
+}
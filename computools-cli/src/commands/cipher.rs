@@ -0,0 +1,65 @@
+use computools_core::bytes::InputEncoding;
+use computools_core::cipher::{self, CipherMode};
+
+use super::{CommandError, text_output};
+use crate::args::Args;
+
+pub const USAGE: &str = "\
+Usage: computools cipher <encrypt|decrypt> --mode MODE --key KEY --iv IV [options] [input]
+
+Options:
+  --mode MODE          aes-128-gcm | aes-256-gcm | aes-128-cbc | aes-256-cbc |
+                       aes-128-ctr | aes-256-ctr | chacha20-poly1305 (default: aes-256-gcm)
+  --key KEY            Key (16 bytes for AES-128, 32 bytes for AES-256 and ChaCha20)
+  --iv IV              IV (16 bytes, CBC/CTR) or nonce (12 bytes, GCM/ChaCha20-Poly1305)
+  --aad DATA           Additional authenticated data (GCM/ChaCha20-Poly1305 only)
+  --encoding ENC       How to read KEY, IV and DATA: hex | base64 (default: hex)
+  --data-format FMT    Ciphertext format: hex | base64 | raw (default: hex). encrypt prints
+                       it, decrypt reads the input in it
+  --file PATH          Read input bytes from PATH (- for stdin)
+
+AEAD ciphertext ends with the 16-byte authentication tag. CBC uses PKCS#7 padding.
+decrypt writes the plaintext bytes to stdout.
+";
+
+pub const FLAGS: &[&str] = &[];
+
+pub fn run(mut args: Args) -> Result<Vec<u8>, CommandError> {
+    let action = args
+        .take_action(&["encrypt", "decrypt"])
+        .ok_or("Choose encrypt or decrypt.")?;
+    let name = args.option("mode").unwrap_or("aes-256-gcm");
+    let mode = CipherMode::from_key(name).ok_or_else(|| {
+        let supported: Vec<&str> = CipherMode::all().iter().map(CipherMode::key).collect();
+        format!("Unknown mode '{}'. Supported: {}", name, supported.join(", "))
+    })?;
+
+    let encoding = parse_format(args.option("encoding").unwrap_or("hex"))?;
+    let key = encoding.decode(args.option("key").ok_or("--key is required.")?)?;
+    let iv = encoding.decode(args.option("iv").ok_or("--iv is required.")?)?;
+    let aad = encoding.decode(args.option("aad").unwrap_or(""))?;
+    let data_format = match args.option("data-format").unwrap_or("hex") {
+        "raw" => None,
+        name => Some(parse_format(name)?),
+    };
+
+    if action == "encrypt" {
+        let ciphertext = cipher::encrypt(mode, &key, &iv, &aad, &args.read_input()?)?;
+        return Ok(match data_format {
+            Some(format) => text_output(format.encode(&ciphertext)),
+            None => ciphertext,
+        });
+    }
+
+    let ciphertext = match data_format {
+        Some(format) => format.decode(&args.read_text()?)?,
+        None => args.read_input()?,
+    };
+    Ok(cipher::decrypt(mode, &key, &iv, &aad, &ciphertext)?)
+}
+
+fn parse_format(name: &str) -> Result<InputEncoding, String> {
+    InputEncoding::from_key(name)
+        .filter(|encoding| InputEncoding::binary().contains(encoding))
+        .ok_or_else(|| format!("Unknown encoding '{}'. Use hex or base64.", name))
+}
@@ -0,0 +1,329 @@
+use computools_core::crc::codegen::{self, CodeLanguage, CodeStyle};
+use computools_core::crc::compare;
+use computools_core::crc::forcing::{self, PatchPosition};
+use computools_core::crc::reveng::{self, CrcMatch};
+use computools_core::crc::verify;
+use computools_core::crc::{
+    self, BinaryStyle, ByteFormatting, CrcAlgorithm, CrcParams, Endianness, HexStyle, InputMode,
+    OctalStyle, OutputMode, OutputOptions,
+};
+
+use std::fs::File;
+use std::io::{self, Read};
+
+use super::{CommandError, text_output};
+use crate::args::Args;
+
+pub const USAGE: &str = "\
+Usage: computools crc [options] [input]
+
+Options:
+  --algo NAME          Algorithm name, e.g. CRC-16/MODBUS (default: CRC-32/ISO-HDLC)
+  --list               List all supported algorithm names
+  --all                Compute every catalogue algorithm (use --width N to filter)
+  --find QUERY         With --all, only rows whose name or result contains QUERY
+  --codegen LANG       Print an implementation: c | rust | python | verilog | vhdl
+  --bitwise            With --codegen, emit a bitwise instead of table-driven implementation
+  --table              Print the 256-entry lookup table used by the generated code
+  --verify             Treat the input as a frame with the CRC appended and check it
+                       (CRC byte order follows --little-endian; exits non-zero on mismatch)
+  --check              Print the check value (CRC of \"123456789\") instead of hashing input
+  --input-ascii TEXT   Use TEXT as input bytes
+  --input-hex HEX      Hex bytes, e.g. \"01 03\" or 0x01,0x03
+  --input-bin BITS     Binary bytes, e.g. 0b00000001
+  --input-dec NUMS     Decimal bytes, e.g. \"1 3\"
+  --input-oct NUMS     Octal bytes, e.g. \"001 003\"
+  --file PATH          Read raw input bytes from PATH (- for stdin)
+  --output MODE        hex | dec | bin | oct (default: hex)
+  --style STYLE        prefix | short | none | escape (default: prefix)
+  --little-endian      Output bytes in little-endian order
+  --separate-bytes     Separate output bytes with spaces
+
+Reverse engineering (input is one \"<data> <crc>\" sample per line or argument):
+  --search             Search the catalogue for algorithms matching every sample
+  --brute-force WIDTH  Also search poly/init/xorout for WIDTH bits (max 16)
+  --sample-mode MODE   Sample data format: ascii | hex | bin | dec | oct (default: hex)
+
+CRC forcing (make the input hit a target CRC):
+  --force HEX          Print the bytes to append so the CRC becomes HEX
+  --offset N           Overwrite the bytes at offset N instead of appending
+  --patched            Write the whole patched input as raw bytes instead
+
+Custom parameters (--algo custom, Rocksoft model):
+  --width N            CRC width in bits, 1-64 (default: 32)
+  --poly HEX           Generator polynomial without the top bit (required)
+  --init HEX           Initial register value (default: 0)
+  --xorout HEX         Final XOR value (default: 0)
+  --refin              Reflect input bytes
+  --refout             Reflect the result before the final XOR
+";
+
+pub const FLAGS: &[&str] = &["list", "all", "check", "verify", "search", "bitwise", "table", "patched", "little-endian", "separate-bytes", "refin", "refout"];
+
+const INPUT_OPTIONS: &[(&str, InputMode)] = &[
+    ("input-ascii", InputMode::Ascii),
+    ("input-hex", InputMode::Hex),
+    ("input-bin", InputMode::Binary),
+    ("input-dec", InputMode::Decimal),
+    ("input-oct", InputMode::Octal),
+];
+
+pub fn run(args: Args) -> Result<Vec<u8>, CommandError> {
+    if args.flag("list") {
+        let names: Vec<String> = CrcAlgorithm::all()
+            .iter()
+            .map(|algorithm| algorithm.name().to_string())
+            .collect();
+        return Ok(text_output(names.join("\n")));
+    }
+
+    if args.flag("search") || args.option("brute-force").is_some() {
+        return Ok(search(&args)?);
+    }
+
+    let options = output_options(&args)?;
+    if args.flag("all") {
+        return Ok(compare_all(&args, &options)?);
+    }
+
+    let algorithm = selected_algorithm(&args)?;
+    if let Some(language) = args.option("codegen") {
+        let language = CodeLanguage::from_name(language)
+            .ok_or_else(|| format!("Unknown language '{}'. Use c, rust, python, verilog or vhdl.", language))?;
+        let style = if args.flag("bitwise") { CodeStyle::Bitwise } else { CodeStyle::TableDriven };
+        return Ok(text_output(codegen::generate(
+            &algorithm.params(),
+            &codegen::code_name(&algorithm),
+            language,
+            style,
+        )));
+    }
+    if args.flag("table") {
+        return Ok(text_output(codegen::format_table(&algorithm.params())));
+    }
+
+    if args.flag("verify") {
+        return Ok(verify_frame(&args, &algorithm, &options)?);
+    }
+    if args.option("force").is_some() {
+        return Ok(force(&args, &algorithm)?);
+    }
+
+    let (crc_result, width) = if args.flag("check") {
+        let params = algorithm.params();
+        (params.check(), params.width)
+    } else if let (None, Some(path)) = (input_option(&args), args.option("file")) {
+        stream_file(path, &algorithm)?
+    } else {
+        algorithm.calculate(&read_data(&args)?)
+    };
+    Ok(text_output(crc::format_crc_output(crc_result, width, &options)))
+}
+
+fn compare_all(args: &Args, options: &OutputOptions) -> Result<Vec<u8>, String> {
+    let width = match args.option("width") {
+        Some(_) => Some(args.number_option("width", 0u8)?),
+        None => None,
+    };
+    let query = args.option("find").unwrap_or("");
+    let rows = compare::calculate_all(&read_data(args)?, width);
+
+    let lines: Vec<String> = rows
+        .iter()
+        .filter(|row| compare::matches_query(row, query, options))
+        .map(|row| format!("{:<20}{}", row.algorithm.name(), crc::format_crc_output(row.crc, row.width, options)))
+        .collect();
+    if lines.is_empty() {
+        return Err("No algorithm matched.".to_string());
+    }
+    Ok(text_output(lines.join("\n")))
+}
+
+fn verify_frame(args: &Args, algorithm: &CrcAlgorithm, options: &OutputOptions) -> Result<Vec<u8>, String> {
+    let result = verify::verify(algorithm, &read_data(args)?, &options.endianness)?;
+    let expected = crc::format_crc_output(result.expected, result.width, options);
+    let actual = crc::format_crc_output(result.actual, result.width, options);
+    if !result.matches() {
+        return Err(format!("CRC mismatch: frame has {}, data computes {}", expected, actual));
+    }
+
+    let residue = match result.residue_ok {
+        Some(true) => "passed",
+        Some(false) => "failed",
+        None => "not applicable",
+    };
+    Ok(text_output(format!("OK {} ({} data bytes, residue check {})", actual, result.data_len, residue)))
+}
+
+fn force(args: &Args, algorithm: &CrcAlgorithm) -> Result<Vec<u8>, String> {
+    let target = crc::parse_param_value(args.option("force").unwrap_or_default())
+        .map_err(|e| format!("--force: {}", e))?;
+    let position = match args.option("offset") {
+        Some(_) => PatchPosition::Overwrite(args.number_option("offset", 0usize)?),
+        None => PatchPosition::Append,
+    };
+
+    let forced = forcing::force(algorithm, &read_data(args)?, target, position)?;
+    if args.flag("patched") {
+        return Ok(forced.data);
+    }
+    Ok(text_output(crc::bytes_to_string(&forced.patch)))
+}
+
+fn search(args: &Args) -> Result<Vec<u8>, String> {
+    let mode = match args.option("sample-mode").unwrap_or("hex") {
+        "ascii" => InputMode::Ascii,
+        "hex" => InputMode::Hex,
+        "bin" => InputMode::Binary,
+        "dec" => InputMode::Decimal,
+        "oct" => InputMode::Octal,
+        other => return Err(format!("Unknown sample mode '{}'.", other)),
+    };
+
+    // 위치 인자는 하나가 샘플 하나이고, 파일/stdin은 한 줄이 샘플 하나입니다.
+    let text = if args.option("file").is_none() && !args.positionals.is_empty() {
+        args.positionals.join("\n")
+    } else {
+        args.read_text()?
+    };
+    let samples = reveng::parse_samples(&text, &mode)?;
+    if samples.is_empty() {
+        return Err("No samples given.".to_string());
+    }
+
+    let mut matches = reveng::search_catalogue(&samples);
+    if let Some(width) = args.option("brute-force") {
+        let width = width
+            .trim()
+            .parse::<u8>()
+            .map_err(|_| format!("Invalid value for --brute-force: {}", width))?;
+        for found in reveng::brute_force(&samples, width)? {
+            if !matches.contains(&found) {
+                matches.push(found);
+            }
+        }
+    }
+
+    if matches.is_empty() {
+        return Err("No matching CRC parameters found.".to_string());
+    }
+    Ok(text_output(matches.iter().map(format_match).collect::<Vec<String>>().join("\n")))
+}
+
+fn format_match(found: &CrcMatch) -> String {
+    let endianness = match found.endianness {
+        Endianness::BigEndian => "big-endian",
+        Endianness::LittleEndian => "little-endian",
+    };
+    format!("{:<20}{}  {}", found.algorithm.name(), found.algorithm.params().describe(), endianness)
+}
+
+fn selected_algorithm(args: &Args) -> Result<CrcAlgorithm, String> {
+    let algo_name = args.option("algo").unwrap_or("CRC-32/ISO-HDLC");
+    if algo_name.eq_ignore_ascii_case("custom") {
+        return custom_params(args).map(CrcAlgorithm::Custom);
+    }
+    CrcAlgorithm::from_name(algo_name)
+        .ok_or_else(|| format!("Unknown algorithm '{}'. Use --list to see supported names.", algo_name))
+}
+
+fn custom_params(args: &Args) -> Result<CrcParams, String> {
+    let hex_option = |name: &str| match args.option(name) {
+        Some(value) => crc::parse_param_value(value).map_err(|e| format!("--{}: {}", name, e)),
+        None => Ok(0),
+    };
+    if args.option("poly").is_none() {
+        return Err("--algo custom requires --poly.".to_string());
+    }
+
+    let params = CrcParams {
+        width: args.number_option("width", 32)?,
+        poly: hex_option("poly")?,
+        init: hex_option("init")?,
+        refin: args.flag("refin"),
+        refout: args.flag("refout"),
+        xorout: hex_option("xorout")?,
+    };
+    params.validate()?;
+    Ok(params)
+}
+
+fn input_option(args: &Args) -> Option<(&str, &'static InputMode)> {
+    INPUT_OPTIONS
+        .iter()
+        .find_map(|(name, mode)| args.option(name).map(|value| (value, mode)))
+}
+
+fn read_data(args: &Args) -> Result<Vec<u8>, String> {
+    match input_option(args) {
+        Some((value, mode)) => crc::parse_input(value, mode),
+        None => args.read_input(),
+    }
+}
+
+/// 큰 파일도 메모리에 모두 올리지 않도록 조각 단위로 읽으며 CRC를 계산합니다.
+fn stream_file(path: &str, algorithm: &CrcAlgorithm) -> Result<(u64, u8), String> {
+    let mut reader: Box<dyn Read> = if path == "-" {
+        Box::new(io::stdin())
+    } else {
+        Box::new(File::open(path).map_err(|e| format!("Failed to read {}: {}", path, e))?)
+    };
+
+    let engine = algorithm.engine();
+    let mut digest = engine.digest();
+    let mut buffer = vec![0u8; 1024 * 1024];
+    loop {
+        let read = reader
+            .read(&mut buffer)
+            .map_err(|e| format!("Failed to read {}: {}", path, e))?;
+        if read == 0 {
+            break;
+        }
+        digest.update(&buffer[..read]);
+    }
+
+    Ok((digest.finalize(), engine.width()))
+}
+
+fn output_options(args: &Args) -> Result<OutputOptions, String> {
+    let output_mode = match args.option("output").unwrap_or("hex") {
+        "hex" => OutputMode::Hex,
+        "dec" => OutputMode::Decimal,
+        "bin" => OutputMode::Binary,
+        "oct" => OutputMode::Octal,
+        other => return Err(format!("Unknown output mode '{}'.", other)),
+    };
+    let mut options = OutputOptions {
+        output_mode,
+        ..OutputOptions::default()
+    };
+
+    match args.option("style").unwrap_or("prefix") {
+        "prefix" => {}
+        "short" => {
+            options.hex_style = HexStyle::ShortPrefix;
+            options.binary_style = BinaryStyle::ShortPrefix;
+            options.octal_style = OctalStyle::ShortPrefix;
+        }
+        "none" => {
+            options.hex_style = HexStyle::NoPrefix;
+            options.binary_style = BinaryStyle::NoPrefix;
+            options.octal_style = OctalStyle::NoPrefix;
+        }
+        "escape" => {
+            options.hex_style = HexStyle::EscapeSequence;
+            options.binary_style = BinaryStyle::NoPrefix;
+            options.octal_style = OctalStyle::EscapeSequence;
+        }
+        other => return Err(format!("Unknown style '{}'.", other)),
+    }
+
+    if args.flag("little-endian") {
+        options.endianness = Endianness::LittleEndian;
+    }
+    if args.flag("separate-bytes") {
+        options.byte_formatting = ByteFormatting::ByteSeparated;
+    }
+
+    Ok(options)
+}
@@ -0,0 +1,282 @@
+use std::fs::{self, File};
+use std::io::Read;
+use std::path::Path;
+
+use computools_core::bytes::InputEncoding;
+use computools_core::file_hash::manifest::{self, HashedFile, ManifestStyle};
+use computools_core::file_hash::{self, FileHasher, HashAlgorithm, HashResults, OutputFormat};
+
+use super::{CommandError, text_output};
+use crate::args::Args;
+
+pub const USAGE: &str = "\
+Usage: computools hash [options] [input]
+
+Options:
+  --algo LIST          Comma-separated: md5, sha1, sha224, sha256, sha384, sha512,
+                       sha512-256, sha3-256, sha3-512, blake2b, blake3, ripemd160, crc32
+                       (default: md5,sha1,sha256,sha512,crc32; \"all\" selects every algorithm)
+  --hmac-key KEY       Also compute HMACs with KEY
+  --key-encoding ENC   How to read KEY: utf8 | hex | base64 (default: utf8)
+  --input-encoding ENC Decode the input text as utf8 | hex | base64 before hashing
+                       (default: raw bytes)
+  --format FORMAT      lower | upper | colon | base64 | c-array (default: lower)
+  --verify HASH        Compare HASH (hex, colon, Base64 or C array) with the results
+  --file PATH          Read input bytes from PATH (- for stdin)
+  --check MANIFEST     Verify the files listed in MANIFEST (sha256sum/md5sum, BSD
+                       `SHA256 (file) = ...` or SFV format); paths are relative to
+                       the current directory
+  --manifest STYLE     Print a gnu | bsd | sfv | json manifest for the files and
+                       directories given as arguments (gnu/bsd use a single --algo,
+                       default sha256; json includes every selected algorithm)
+
+With a single algorithm only the hash is printed; otherwise each line is `NAME  hash`.
+--verify exits with an error when no result matches. --check prints `file: OK`,
+`FAILED` or `MISSING` for each line to stdout and exits with an error summary on
+stderr unless all are OK.
+";
+
+pub const FLAGS: &[&str] = &[];
+
+pub fn run(args: Args) -> Result<Vec<u8>, CommandError> {
+    if let Some(path) = args.option("check") {
+        return check_manifest(path);
+    }
+    if let Some(style) = args.option("manifest") {
+        return Ok(generate_manifest(&args, style)?);
+    }
+
+    let selected = selected_algorithms(&args)?;
+    let output_format = parse_output_format(args.option("format").unwrap_or("lower"))?;
+    let hmac_key = match args.option("hmac-key") {
+        Some(key) => Some(parse_encoding(args.option("key-encoding").unwrap_or("utf8"))?.decode(key)?),
+        None => None,
+    };
+    let data = match args.option("input-encoding") {
+        Some(encoding) => parse_encoding(encoding)?.decode(&args.read_text()?)?,
+        None => args.read_input()?,
+    };
+
+    let results = file_hash::compute_hashes(&data, &selected, hmac_key.as_deref());
+
+    if let Some(expected) = args.option("verify") {
+        return Ok(verify(expected, &results, hmac_key.as_deref(), &data, &selected)?);
+    }
+
+    let lines = result_entries(&results);
+
+    let output = if lines.len() == 1 {
+        file_hash::format_hash_output(lines[0].1, &output_format)
+    } else {
+        lines
+            .iter()
+            .map(|(name, hash)| format!("{}  {}", name, file_hash::format_hash_output(hash, &output_format)))
+            .collect::<Vec<String>>()
+            .join("\n")
+    };
+
+    Ok(text_output(output))
+}
+
+fn selected_algorithms(args: &Args) -> Result<Vec<HashAlgorithm>, String> {
+    let Some(list) = args.option("algo") else {
+        return Ok(HashAlgorithm::all().into_iter().filter(HashAlgorithm::is_default).collect());
+    };
+    if list.trim().eq_ignore_ascii_case("all") {
+        return Ok(HashAlgorithm::all().to_vec());
+    }
+
+    list.split(',')
+        .map(|name| {
+            // "SHA3-256", "sha3_256", "sha512/256"처럼 구분자가 달라도 같은 알고리즘으로 취급
+            let wanted = simplify_name(name);
+            HashAlgorithm::all()
+                .into_iter()
+                .find(|algorithm| simplify_name(algorithm.key()) == wanted)
+                .ok_or_else(|| {
+                    let supported: Vec<&str> = HashAlgorithm::all().iter().map(HashAlgorithm::key).collect();
+                    format!("Unknown hash algorithm '{}'. Supported: {}", name.trim(), supported.join(", "))
+                })
+        })
+        .collect()
+}
+
+fn simplify_name(name: &str) -> String {
+    name.trim()
+        .to_lowercase()
+        .chars()
+        .filter(|c| !matches!(c, '-' | '_' | '/'))
+        .collect()
+}
+
+fn parse_encoding(name: &str) -> Result<InputEncoding, String> {
+    InputEncoding::from_key(name).ok_or_else(|| format!("Unknown encoding '{}'. Use utf8, hex or base64.", name))
+}
+
+fn parse_output_format(name: &str) -> Result<OutputFormat, String> {
+    match name {
+        "lower" => Ok(OutputFormat::Lowercase),
+        "upper" => Ok(OutputFormat::Uppercase),
+        "colon" => Ok(OutputFormat::ColonSeparated),
+        "base64" => Ok(OutputFormat::Base64),
+        "c-array" => Ok(OutputFormat::CStyleArray),
+        other => Err(format!("Unknown output format '{}'.", other)),
+    }
+}
+
+fn result_entries(results: &HashResults) -> Vec<(String, &str)> {
+    let hashes = results
+        .hashes
+        .iter()
+        .map(|(algorithm, hash)| (algorithm.name().to_string(), hash.as_str()));
+    let hmacs = results
+        .hmacs
+        .iter()
+        .map(|(algorithm, hash)| (algorithm.hmac_name(), hash.as_str()));
+    hashes.chain(hmacs).collect()
+}
+
+fn verify(
+    expected: &str,
+    results: &HashResults,
+    hmac_key: Option<&[u8]>,
+    data: &[u8],
+    algorithms: &[HashAlgorithm],
+) -> Result<Vec<u8>, String> {
+    let comparison = match hmac_key {
+        Some(key) => file_hash::compare_hmac(expected, key, data, algorithms),
+        None => file_hash::compare_hash(expected, results),
+    }
+    .ok_or_else(|| "Nothing to compare: the expected hash is empty or the matching algorithm was not selected.".to_string())?;
+
+    if comparison.matches {
+        Ok(text_output(format!("OK  {}", comparison.algorithm)))
+    } else {
+        Err(format!(
+            "MISMATCH  {}\n  expected: {}\n  actual:   {}",
+            comparison.algorithm, comparison.expected, comparison.actual
+        ))
+    }
+}
+
+fn check_manifest(path: &str) -> Result<Vec<u8>, CommandError> {
+    let text = std::fs::read_to_string(path).map_err(|e| format!("Failed to read {}: {}", path, e))?;
+    let parsed = manifest::parse(&text, Some(path));
+    if parsed.entries.is_empty() {
+        return Err(format!("No checksum lines found in {}.", path).into());
+    }
+
+    // 파일마다 한 번만 읽으면서 그 파일에 필요한 알고리즘을 모두 계산
+    // (읽을 수 없는 파일도 빈 결과로 넣어야 같은 이름의 다른 파일과 비교되지 않고 MISSING이 됨)
+    let mut names: Vec<&str> = Vec::new();
+    for entry in &parsed.entries {
+        if !names.contains(&entry.file_name.as_str()) {
+            names.push(&entry.file_name);
+        }
+    }
+    let files: Vec<(String, HashResults)> = names
+        .iter()
+        .enumerate()
+        .map(|(index, name)| {
+            let results = hash_file(name, &parsed.algorithms_for(&names, index)).unwrap_or_default();
+            (name.to_string(), results)
+        })
+        .collect();
+
+    let checks = manifest::check_all(&parsed, &files);
+    let lines: Vec<String> = checks
+        .iter()
+        .map(|check| format!("{}: {}", check.entry.file_name, check.status.label()))
+        .collect();
+
+    // sha256sum -c처럼 파일별 결과는 stdout, 경고는 stderr에 출력
+    if !parsed.malformed_lines.is_empty() {
+        eprintln!("computools hash: WARNING: {} line(s) are improperly formatted", parsed.malformed_lines.len());
+    }
+    let summary = manifest::summarize(&checks);
+    if summary.all_ok() {
+        return Ok(text_output(lines.join("\n")));
+    }
+
+    // 실패해도 파일별 결과는 모두 stdout에 남기고, 에러 메시지는 요약 한 줄
+    let mut problems = Vec::new();
+    if summary.failed > 0 {
+        problems.push(format!("{} computed checksum(s) did NOT match", summary.failed));
+    }
+    if summary.missing > 0 {
+        problems.push(format!("{} listed file(s) could not be read", summary.missing));
+    }
+    Err(CommandError { output: text_output(lines.join("\n")), message: format!("WARNING: {}", problems.join(", ")) })
+}
+
+fn generate_manifest(args: &Args, style: &str) -> Result<Vec<u8>, String> {
+    let style = ManifestStyle::from_key(style).ok_or_else(|| {
+        let supported: Vec<&str> = ManifestStyle::all().iter().map(ManifestStyle::key).collect();
+        format!("Unknown manifest style '{}'. Supported: {}", style, supported.join(", "))
+    })?;
+    let algorithm = match args.option("algo") {
+        Some(_) => {
+            let selected = selected_algorithms(args)?;
+            if selected.len() != 1 && style.algorithm(selected[0]).is_some() {
+                return Err(format!("A {} manifest needs a single --algo.", style.key()));
+            }
+            selected[0]
+        }
+        None => HashAlgorithm::Sha256,
+    };
+    let algorithms = match style.algorithm(algorithm) {
+        Some(algorithm) => vec![algorithm],
+        None => selected_algorithms(args)?,
+    };
+
+    let mut paths = Vec::new();
+    for path in &args.positionals {
+        collect_files(Path::new(path), &mut paths).map_err(|e| format!("Failed to read {}: {}", path, e))?;
+    }
+    if paths.is_empty() {
+        return Err("Give the files or directories to include in the manifest.".to_string());
+    }
+
+    let files = paths
+        .into_iter()
+        .map(|path| {
+            let results = hash_file(&path, &algorithms).map_err(|e| format!("Failed to read {}: {}", path, e))?;
+            let size = fs::metadata(&path).map(|metadata| metadata.len()).unwrap_or(0);
+            Ok(HashedFile { name: path, size, results })
+        })
+        .collect::<Result<Vec<_>, String>>()?;
+
+    manifest::generate(&files, style, algorithm).map(String::into_bytes)
+}
+
+/// 디렉터리는 하위 파일을 이름 순으로 모두 포함합니다.
+fn collect_files(path: &Path, paths: &mut Vec<String>) -> std::io::Result<()> {
+    if !path.is_dir() {
+        paths.push(path.to_string_lossy().into_owned());
+        return Ok(());
+    }
+
+    let mut children: Vec<_> = fs::read_dir(path)?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<Result<_, _>>()?;
+    children.sort();
+    for child in children {
+        collect_files(&child, paths)?;
+    }
+    Ok(())
+}
+
+/// 파일을 청크 단위로 읽으며 해시합니다.
+fn hash_file(path: &str, algorithms: &[HashAlgorithm]) -> std::io::Result<HashResults> {
+    let mut file = File::open(path)?;
+    let mut hasher = FileHasher::new(algorithms, None);
+    let mut buffer = vec![0u8; 1024 * 1024];
+    loop {
+        let read = file.read(&mut buffer)?;
+        if read == 0 {
+            break;
+        }
+        hasher.update(&buffer[..read]);
+    }
+    Ok(hasher.finalize())
+}
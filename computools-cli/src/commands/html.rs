@@ -0,0 +1,50 @@
+use computools_core::html::{self, EncodeOptions, EntityStyle, SelectiveMode};
+
+use super::{CommandError, take_decode_action, text_output};
+use crate::args::Args;
+
+pub const USAGE: &str = "\
+Usage: computools html [encode|decode] [options] [input]
+
+Options:
+  --style STYLE        named | decimal | hex | named-hex | named-decimal (default: named)
+  --select SET         essential | extended | unicode | all | custom (default: essential)
+  --chars LIST         Comma-separated characters for --select custom
+  --file PATH          Read input from PATH (- for stdin)
+";
+
+pub const FLAGS: &[&str] = &[];
+
+pub fn run(mut args: Args) -> Result<Vec<u8>, CommandError> {
+    let decode = take_decode_action(&mut args);
+    let input = args.read_text()?;
+
+    if decode {
+        return Ok(html::decode_html(&input).map(text_output)?);
+    }
+
+    let entity_style = match args.option("style").unwrap_or("named") {
+        "named" => EntityStyle::Named,
+        "decimal" => EntityStyle::Decimal,
+        "hex" => EntityStyle::Hexadecimal,
+        "named-hex" => EntityStyle::MixedNamedHex,
+        "named-decimal" => EntityStyle::MixedNamedDecimal,
+        other => return Err(format!("Unknown entity style '{}'.", other).into()),
+    };
+    let selective_mode = match args.option("select").unwrap_or("essential") {
+        "essential" => SelectiveMode::Essential,
+        "extended" => SelectiveMode::Extended,
+        "unicode" => SelectiveMode::Unicode,
+        "all" => SelectiveMode::All,
+        "custom" => SelectiveMode::Custom,
+        other => return Err(format!("Unknown selection '{}'.", other).into()),
+    };
+
+    let options = EncodeOptions {
+        entity_style,
+        selective_mode,
+        custom_chars: args.option("chars").unwrap_or_default().to_string(),
+    };
+
+    Ok(text_output(html::encode_html(&input, &options)))
+}
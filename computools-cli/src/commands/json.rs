@@ -0,0 +1,34 @@
+use computools_core::json;
+
+use super::{CommandError, text_output};
+use crate::args::Args;
+
+pub const USAGE: &str = "\
+Usage: computools json [format|minify|yaml] [options] [input]
+
+Options:
+  --indent STYLE       2space | 3space | 4space | 1tab (format only, default: 4space)
+  --file PATH          Read input from PATH (- for stdin)
+
+Key order is preserved.
+";
+
+pub const FLAGS: &[&str] = &[];
+
+pub fn run(mut args: Args) -> Result<Vec<u8>, CommandError> {
+    let action = args.take_action(&["format", "minify", "yaml"]);
+    let input = args.read_text()?;
+    let value: serde_json::Value =
+        serde_json::from_str(&input).map_err(|e| json::format_error_message(&input, e))?;
+
+    let output = match action.as_deref() {
+        Some("minify") => json::format_json_with_order_preservation(&value, &json::indent_for_style("compact")),
+        Some("yaml") => json::convert_to_yaml_with_order_preservation(&value),
+        _ => {
+            let indent = json::indent_for_style(args.option("indent").unwrap_or("4space"));
+            json::format_json_with_order_preservation(&value, &indent)
+        }
+    };
+
+    Ok(text_output(output))
+}
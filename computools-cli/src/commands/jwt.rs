@@ -0,0 +1,95 @@
+use chrono::Utc;
+use computools_core::bytes::InputEncoding;
+use computools_core::json;
+use computools_core::jwt::{self, JwtAlgorithm};
+use computools_core::unixtime;
+
+use super::{CommandError, text_output};
+use crate::args::Args;
+
+pub const USAGE: &str = "\
+Usage: computools jwt [decode|verify|sign] [options] [input]
+
+Actions:
+  decode               Print the header and payload as JSON with exp/nbf/iat dates (default)
+  verify               Check an HS256/HS384/HS512 signature with --secret
+  sign                 Sign the JSON payload given as input and print the token
+
+Options:
+  --secret KEY         HMAC secret (verify, sign)
+  --secret-encoding ENC
+                       How to read KEY: utf8 | hex | base64 (default: utf8)
+  --alg NAME           HS256 | HS384 | HS512 (sign, default: HS256)
+  --header JSON        Extra header fields, e.g. '{\"kid\":\"1\"}' (sign)
+  --tz ZONE            IANA time zone for claim dates (default: UTC)
+  --file PATH          Read input from PATH (- for stdin)
+
+A leading `Bearer ` is ignored. verify also decodes the token and exits with an
+error when the signature does not match.
+";
+
+pub const FLAGS: &[&str] = &[];
+
+pub fn run(mut args: Args) -> Result<Vec<u8>, CommandError> {
+    let action = args.take_action(&["decode", "verify", "sign"]);
+    let input = args.read_text()?;
+
+    match action.as_deref() {
+        Some("sign") => Ok(sign(&args, &input)?),
+        Some("verify") => {
+            let decoded = jwt::decode(&input)?;
+            if !jwt::verify(&decoded, &secret(&args)?)? {
+                return Err(format!("{} signature does not match.", decoded.algorithm()).into());
+            }
+            Ok(text_output(format!("{}\nSignature: OK ({})", describe(&args, &decoded)?, decoded.algorithm())))
+        }
+        _ => Ok(text_output(describe(&args, &jwt::decode(&input)?)?)),
+    }
+}
+
+fn describe(args: &Args, decoded: &jwt::DecodedJwt) -> Result<String, String> {
+    let timezone = args.option("tz").unwrap_or("UTC");
+    if unixtime::datetime_str_to_unixtime("1970-01-01T00:00:00", timezone).is_none() {
+        return Err(format!("Unknown time zone '{}'.", timezone));
+    }
+    let indent = json::indent_for_style("2space");
+
+    let mut lines = vec![
+        "Header:".to_string(),
+        json::format_json_with_order_preservation(&decoded.header, &indent),
+        "Payload:".to_string(),
+        json::format_json_with_order_preservation(&decoded.payload, &indent),
+    ];
+    lines.extend(jwt::time_claims(&decoded.payload).into_iter().map(|(name, seconds)| {
+        format!("{}: {} ({})", name, unixtime::unixtime_to_datetime_str_form2(seconds, timezone), timezone)
+    }));
+    lines.extend(
+        jwt::time_warnings(&decoded.payload, Utc::now().timestamp())
+            .into_iter()
+            .map(|warning| format!("Warning: {}", warning)),
+    );
+
+    Ok(lines.join("\n"))
+}
+
+fn sign(args: &Args, input: &str) -> Result<Vec<u8>, String> {
+    let payload: serde_json::Value =
+        serde_json::from_str(input).map_err(|e| json::format_error_message(input, e))?;
+    let header: serde_json::Value = match args.option("header") {
+        Some(header) => serde_json::from_str(header).map_err(|e| json::format_error_message(header, e))?,
+        None => serde_json::json!({}),
+    };
+    let name = args.option("alg").unwrap_or("HS256");
+    let algorithm = JwtAlgorithm::from_name(name)
+        .ok_or_else(|| format!("Unsupported algorithm '{}'. Use HS256, HS384 or HS512.", name))?;
+
+    Ok(text_output(jwt::sign(&header, &payload, algorithm, &secret(args)?)?))
+}
+
+fn secret(args: &Args) -> Result<Vec<u8>, String> {
+    let secret = args.option("secret").ok_or("--secret is required.")?;
+    let name = args.option("secret-encoding").unwrap_or("utf8");
+    InputEncoding::from_key(name)
+        .ok_or_else(|| format!("Unknown encoding '{}'. Use utf8, hex or base64.", name))?
+        .decode(secret)
+}
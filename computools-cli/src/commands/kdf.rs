@@ -0,0 +1,147 @@
+use computools_core::bytes::{self, InputEncoding};
+use computools_core::file_hash::{self, OutputFormat};
+use computools_core::kdf::{self, KdfHash, KdfParams};
+
+use super::{CommandError, text_output};
+use crate::args::Args;
+
+pub const USAGE: &str = "\
+Usage: computools kdf <pbkdf2 | hkdf | scrypt | argon2id | bcrypt | verify> [options] [password]
+
+Algorithms:
+  pbkdf2               --digest sha256|sha512 (default: sha256)
+                       --iterations N (default: 600000)
+  hkdf                 --digest sha256|sha512 (default: sha256), --info TEXT
+                       The password is the input key material; the salt may be empty.
+  scrypt               --log-n N (default: 17), --r N (default: 8), --p N (default: 1)
+  argon2id             --memory KIB (default: 19456), --iterations N (default: 2),
+                       --parallelism N (default: 1)
+  bcrypt               --cost N (default: 12); prints a $2b$ hash
+  verify               --hash HASH   Check the password against a bcrypt or PHC string
+                       ($argon2id$, $scrypt$, $pbkdf2-sha256$, ...)
+
+Options:
+  --salt SALT          Salt (required for raw keys; random 16 bytes for --phc and bcrypt)
+  --salt-encoding ENC  How to read SALT: utf8 | hex | base64 (default: utf8)
+  --info-encoding ENC  How to read the HKDF --info: utf8 | hex | base64 (default: utf8)
+  --password-encoding ENC
+                       How to read the password: utf8 | hex | base64 (default: utf8)
+  --length N           Derived key length in bytes (default: 32)
+  --format FORMAT      hex | base64 (default: hex)
+  --phc                Print a PHC string instead of the raw key (pbkdf2, scrypt, argon2id)
+  --file PATH          Read the password from PATH (- for stdin)
+
+verify prints the parsed parameters followed by OK, and exits with an error when the
+password does not match.
+";
+
+pub const FLAGS: &[&str] = &["phc"];
+
+const ACTIONS: &[&str] = &["pbkdf2", "hkdf", "scrypt", "argon2id", "bcrypt", "verify"];
+
+pub fn run(mut args: Args) -> Result<Vec<u8>, CommandError> {
+    let action = args
+        .take_action(ACTIONS)
+        .ok_or_else(|| format!("Choose one of: {}", ACTIONS.join(", ")))?;
+    let password = parse_encoding(args.option("password-encoding").unwrap_or("utf8"))?.decode(&args.read_text()?)?;
+
+    match action.as_str() {
+        "verify" => Ok(verify(&args, &password)?),
+        "bcrypt" => Ok(bcrypt(&args, &password)?),
+        _ => Ok(derive(&args, &action, &password)?),
+    }
+}
+
+fn derive(args: &Args, action: &str, password: &[u8]) -> Result<Vec<u8>, String> {
+    let params = match action {
+        "pbkdf2" => KdfParams::Pbkdf2 {
+            hash: parse_digest(args)?,
+            iterations: args.number_option("iterations", kdf::DEFAULT_PBKDF2_ITERATIONS)?,
+        },
+        "hkdf" => KdfParams::Hkdf {
+            hash: parse_digest(args)?,
+            info: parse_encoding(args.option("info-encoding").unwrap_or("utf8"))?.decode(args.option("info").unwrap_or(""))?,
+        },
+        "scrypt" => KdfParams::Scrypt {
+            log_n: args.number_option("log-n", kdf::DEFAULT_SCRYPT_LOG_N)?,
+            r: args.number_option("r", kdf::DEFAULT_SCRYPT_R)?,
+            p: args.number_option("p", kdf::DEFAULT_SCRYPT_P)?,
+        },
+        _ => KdfParams::Argon2id {
+            memory_kib: args.number_option("memory", kdf::DEFAULT_ARGON2_MEMORY_KIB)?,
+            iterations: args.number_option("iterations", kdf::DEFAULT_ARGON2_ITERATIONS)?,
+            parallelism: args.number_option("parallelism", kdf::DEFAULT_ARGON2_PARALLELISM)?,
+        },
+    };
+    let length = args.number_option("length", 32usize)?;
+
+    if args.flag("phc") {
+        let salt = match read_salt(args)? {
+            Some(salt) => salt,
+            None => bytes::random_bytes(16)?,
+        };
+        return Ok(text_output(kdf::phc_string(&params, password, &salt, length)?));
+    }
+
+    // 원시 키는 같은 salt로만 재현할 수 있으므로 임의 salt를 만들지 않음 (HKDF는 salt 생략 가능)
+    let salt = match (read_salt(args)?, &params) {
+        (Some(salt), _) => salt,
+        (None, KdfParams::Hkdf { .. }) => Vec::new(),
+        (None, _) => return Err("--salt is required to derive a raw key (or use --phc).".to_string()),
+    };
+    let key = kdf::derive_key(&params, password, &salt, length)?;
+
+    let output_format = match args.option("format").unwrap_or("hex") {
+        "hex" => OutputFormat::Lowercase,
+        "base64" => OutputFormat::Base64,
+        other => return Err(format!("Unknown format '{}'. Use hex or base64.", other)),
+    };
+    let hex: String = key.iter().map(|byte| format!("{:02x}", byte)).collect();
+    Ok(text_output(file_hash::format_hash_output(&hex, &output_format)))
+}
+
+fn bcrypt(args: &Args, password: &[u8]) -> Result<Vec<u8>, String> {
+    let cost = args.number_option("cost", kdf::DEFAULT_BCRYPT_COST)?;
+    let salt = match read_salt(args)? {
+        Some(salt) => salt,
+        None => bytes::random_bytes(16)?,
+    };
+    let salt: [u8; 16] = salt
+        .try_into()
+        .map_err(|salt: Vec<u8>| format!("bcrypt needs a 16-byte salt, got {} bytes.", salt.len()))?;
+
+    Ok(text_output(kdf::bcrypt_hash(password, cost, salt)?))
+}
+
+fn verify(args: &Args, password: &[u8]) -> Result<Vec<u8>, String> {
+    let hash = args.option("hash").ok_or("--hash is required for verify.")?;
+    let info = kdf::inspect_password_hash(hash)?;
+
+    if !kdf::verify_password(password, hash)? {
+        return Err(format!("Password does not match the {} hash.", info.scheme));
+    }
+
+    let mut lines = vec![format!("scheme  {}", info.scheme)];
+    lines.extend(info.params.iter().map(|(name, value)| format!("{:<8}{}", name, value)));
+    lines.push(format!("salt    {}", info.salt));
+    lines.push(format!("length  {}", info.output_len));
+    lines.push("OK".to_string());
+    Ok(text_output(lines.join("\n")))
+}
+
+fn read_salt(args: &Args) -> Result<Option<Vec<u8>>, String> {
+    let Some(salt) = args.option("salt") else {
+        return Ok(None);
+    };
+    parse_encoding(args.option("salt-encoding").unwrap_or("utf8"))?.decode(salt).map(Some)
+}
+
+fn parse_digest(args: &Args) -> Result<KdfHash, String> {
+    let name = args.option("digest").unwrap_or("sha256");
+    KdfHash::from_key(&name.to_lowercase().replace('-', ""))
+        .ok_or_else(|| format!("Unknown digest '{}'. Use sha256 or sha512.", name))
+}
+
+fn parse_encoding(name: &str) -> Result<InputEncoding, String> {
+    InputEncoding::from_key(name).ok_or_else(|| format!("Unknown encoding '{}'. Use utf8, hex or base64.", name))
+}
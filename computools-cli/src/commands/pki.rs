@@ -0,0 +1,36 @@
+use chrono::Utc;
+use computools_core::json;
+use computools_core::pki;
+use computools_core::unixtime;
+
+use super::{CommandError, text_output};
+use crate::args::Args;
+
+pub const USAGE: &str = "\
+Usage: computools pki [options] [input]
+
+Decode PEM or DER keys and X.509 certificates into JSON: subject, issuer,
+validity, SANs and other extensions, key parameters and SHA-1/SHA-256
+fingerprints. Several PEM blocks (e.g. a certificate chain) print as an array.
+
+Supported: CERTIFICATE, PUBLIC KEY, RSA PUBLIC KEY, PRIVATE KEY (PKCS#8),
+RSA PRIVATE KEY, EC PRIVATE KEY with RSA, EC and Ed25519/Ed448/X25519/X448 keys.
+
+Options:
+  --tz ZONE            IANA time zone for validity dates (default: UTC)
+  --indent STYLE       2space | 4space | tab | compact (default: 2space)
+  --file PATH          Read input from PATH (- for stdin)
+";
+
+pub const FLAGS: &[&str] = &[];
+
+pub fn run(args: Args) -> Result<Vec<u8>, CommandError> {
+    let timezone = args.option("tz").unwrap_or("UTC");
+    if unixtime::datetime_str_to_unixtime("1970-01-01T00:00:00", timezone).is_none() {
+        return Err(format!("Unknown time zone '{}'.", timezone).into());
+    }
+
+    let value = pki::inspect_input(&args.read_input()?, timezone, Utc::now().timestamp())?;
+    let indent = json::indent_for_style(args.option("indent").unwrap_or("2space"));
+    Ok(text_output(json::format_json_with_order_preservation(&value, &indent)))
+}
@@ -0,0 +1,69 @@
+use computools_core::quaternion::{self, EulerAngles, Quaternion};
+
+use super::{CommandError, text_output};
+use crate::args::Args;
+
+pub const USAGE: &str = "\
+Usage: computools quaternion <to-euler W X Y Z | from-euler ROLL PITCH YAW> [options]
+
+Options:
+  --degrees            Read and print Euler angles in degrees instead of radians
+";
+
+pub const FLAGS: &[&str] = &["degrees"];
+
+pub fn run(mut args: Args) -> Result<Vec<u8>, CommandError> {
+    let action = args
+        .take_action(&["to-euler", "from-euler"])
+        .ok_or_else(|| "Specify to-euler or from-euler.".to_string())?;
+    let degrees = args.flag("degrees");
+
+    let values = args
+        .positionals
+        .iter()
+        .map(|value| {
+            value
+                .trim()
+                .parse::<f64>()
+                .map_err(|_| format!("Invalid number '{}'.", value))
+        })
+        .collect::<Result<Vec<f64>, String>>()?;
+
+    let output = if action == "to-euler" {
+        let [w, x, y, z] = values[..] else {
+            return Err("to-euler needs four values: W X Y Z.".into());
+        };
+        let (euler, normalized) = quaternion::quaternion_to_euler(Quaternion { w, x, y, z });
+        format!(
+            "{}\n{}",
+            format_euler(&euler, degrees),
+            format_quaternion("normalized", &normalized)
+        )
+    } else {
+        let [roll, pitch, yaw] = values[..] else {
+            return Err("from-euler needs three values: ROLL PITCH YAW.".into());
+        };
+        let to_radians = |angle: f64| if degrees { angle.to_radians() } else { angle };
+        let (_, q) = quaternion::euler_to_quaternion(EulerAngles {
+            roll: to_radians(roll),
+            pitch: to_radians(pitch),
+            yaw: to_radians(yaw),
+        });
+        format_quaternion("quaternion", &q)
+    };
+
+    Ok(text_output(output))
+}
+
+fn format_euler(euler: &EulerAngles, degrees: bool) -> String {
+    let (roll, pitch, yaw) = if degrees {
+        (euler.roll.to_degrees(), euler.pitch.to_degrees(), euler.yaw.to_degrees())
+    } else {
+        (euler.roll, euler.pitch, euler.yaw)
+    };
+    format!("roll {:.6}\npitch {:.6}\nyaw {:.6}", roll, pitch, yaw)
+}
+
+fn format_quaternion(label: &str, q: &Quaternion) -> String {
+    format!("{} w {:.6} x {:.6} y {:.6} z {:.6}", label, q.w, q.x, q.y, q.z)
+}
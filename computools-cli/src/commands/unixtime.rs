@@ -0,0 +1,64 @@
+use chrono::Utc;
+use computools_core::unixtime;
+
+use super::{CommandError, text_output};
+use crate::args::Args;
+
+pub const USAGE: &str = "\
+Usage: computools unixtime [options] [timestamp]
+
+Without a timestamp the current time is used.
+
+Options:
+  --tz ZONE            IANA time zone, e.g. Asia/Seoul (default: UTC)
+  --date DATETIME      Convert YYYY-MM-DDTHH:MM[:SS] in --tz to a unix timestamp
+  --list-tz            List all time zones with their current UTC offsets
+";
+
+pub const FLAGS: &[&str] = &["list-tz"];
+
+pub fn run(args: Args) -> Result<Vec<u8>, CommandError> {
+    if args.flag("list-tz") {
+        let labels: Vec<String> = unixtime::timezone_list(Utc::now())
+            .into_iter()
+            .map(|(label, _)| label)
+            .collect();
+        return Ok(text_output(labels.join("\n")));
+    }
+
+    let timezone = args.option("tz").unwrap_or("UTC");
+    // 유닉스 시작 시각을 변환할 수 없으면 타임존 이름이 잘못된 것
+    if unixtime::datetime_str_to_unixtime("1970-01-01T00:00:00", timezone).is_none() {
+        return Err(format!("Unknown time zone '{}'. Use --list-tz to see supported names.", timezone).into());
+    }
+
+    if let Some(date) = args.option("date") {
+        let datetime = unixtime::normalize_datetime_input(date.trim());
+        let timestamp = unixtime::datetime_str_to_unixtime(&datetime, timezone)
+            .ok_or_else(|| format!("Invalid date/time '{}'. Expected YYYY-MM-DDTHH:MM[:SS].", date))?;
+        return Ok(text_output(timestamp.to_string()));
+    }
+
+    let timestamp = if args.positionals.is_empty() && args.option("file").is_none() {
+        Utc::now().timestamp()
+    } else {
+        let input = args.read_text()?;
+        let timestamp = input
+            .trim()
+            .parse::<i64>()
+            .map_err(|_| format!("Invalid unix timestamp '{}'.", input.trim()))?;
+        // 웹 UI와 같은 허용 범위를 사용
+        if unixtime::parse_unixtime_input(input.trim()) != timestamp {
+            return Err(format!("Unix timestamp {} is out of range (0 to 100000000000).", timestamp).into());
+        }
+        timestamp
+    };
+
+    Ok(text_output(format!(
+        "{}\n{}\n{}\n{}",
+        timestamp,
+        unixtime::unixtime_to_datetime_str_form1(timestamp, timezone),
+        unixtime::unixtime_to_datetime_str_form2(timestamp, timezone),
+        unixtime::unixtime_to_datetime_str_form3(timestamp, timezone),
+    )))
+}
@@ -0,0 +1,24 @@
+use computools_core::url;
+
+use super::{CommandError, take_decode_action, text_output};
+use crate::args::Args;
+
+pub const USAGE: &str = "\
+Usage: computools url [encode|decode] [options] [input]
+
+Options:
+  --file PATH          Read input from PATH (- for stdin)
+";
+
+pub const FLAGS: &[&str] = &[];
+
+pub fn run(mut args: Args) -> Result<Vec<u8>, CommandError> {
+    let decode = take_decode_action(&mut args);
+    let input = args.read_text()?;
+
+    if decode {
+        Ok(url::decode_url(&input).map(text_output)?)
+    } else {
+        Ok(text_output(url::encode_url(&input)))
+    }
+}
@@ -0,0 +1,31 @@
+use computools_core::uuid;
+
+use super::{CommandError, text_output};
+use crate::args::Args;
+
+pub const USAGE: &str = "\
+Usage: computools uuid [options]
+
+Options:
+  --count N            Number of UUIDs to generate (default: 1)
+  --upper              Print uppercase UUIDs
+";
+
+pub const FLAGS: &[&str] = &["upper"];
+
+pub fn run(args: Args) -> Result<Vec<u8>, CommandError> {
+    let count = args.number_option("count", 1usize)?;
+
+    let uuids: Vec<String> = (0..count)
+        .map(|_| {
+            let value = uuid::generate_v4();
+            if args.flag("upper") {
+                value.to_uppercase()
+            } else {
+                value
+            }
+        })
+        .collect();
+
+    Ok(text_output(uuids.join("\n")))
+}
@@ -0,0 +1,96 @@
+//! CompuTools 명령행 도구
+//!
+//! 웹 UI와 같은 `computools-core` 함수를 호출하므로 스크립트와 브라우저의 결과가 항상 일치합니다.
+
+mod args;
+mod commands;
+
+use std::io::{self, Write};
+use std::process::ExitCode;
+
+use args::Args;
+use commands::CommandError;
+
+const USAGE: &str = "\
+Usage: computools <command> [options] [input]
+
+Commands:
+  crc          Calculate CRC checksums (--algo NAME, --list)
+  hash         Calculate MD5/SHA/CRC32 hashes and HMACs
+  cipher       Encrypt or decrypt with AES-GCM/CBC/CTR or ChaCha20-Poly1305
+  kdf          Derive keys (PBKDF2, HKDF, scrypt, Argon2id) and verify password hashes
+  pki          Decode PEM/DER keys and X.509 certificates
+  base64       Encode or decode Base64, Base32/58/85/45 and email encodings (encode | decode)
+  base         Convert numbers between bases 2-36
+  ascii        Convert text to ASCII codes and back (encode | decode)
+  html         Encode or decode HTML entities (encode | decode)
+  url          Percent-encode or decode URLs (encode | decode)
+  json         Format or convert JSON (format | minify | yaml)
+  jwt          Decode, verify or sign HS256/384/512 JWTs (decode | verify | sign)
+  uuid         Generate random UUID v4 values
+  unixtime     Convert unix timestamps and date/time strings
+  quaternion   Convert between quaternions and Euler angles
+
+Input is read from --file PATH, positional arguments, or stdin (in that order).
+Run `computools <command> --help` for command options.
+";
+
+fn main() -> ExitCode {
+    let raw: Vec<String> = std::env::args().skip(1).collect();
+
+    let Some((command, rest)) = raw.split_first() else {
+        eprint!("{}", USAGE);
+        return ExitCode::FAILURE;
+    };
+
+    if command == "--help" || command == "-h" || command == "help" {
+        print!("{}", USAGE);
+        return ExitCode::SUCCESS;
+    }
+
+    // 실패해도 그 전까지의 결과(hash --check의 파일별 줄 등)를 stdout에 먼저 쓰고 에러를 출력
+    let (output, error) = match run(command, rest) {
+        Ok(output) => (output, None),
+        Err(e) => (e.output, Some(e.message)),
+    };
+    let mut stdout = io::stdout().lock();
+    if let Err(e) = stdout.write_all(&output).and_then(|_| stdout.flush()) {
+        eprintln!("computools: {}", e);
+        return ExitCode::FAILURE;
+    }
+    match error {
+        Some(message) => {
+            eprintln!("computools {}: {}", command, message);
+            ExitCode::FAILURE
+        }
+        None => ExitCode::SUCCESS,
+    }
+}
+
+fn run(command: &str, rest: &[String]) -> Result<Vec<u8>, CommandError> {
+    let (usage, flags, handler): (&str, &[&str], commands::Handler) = match command {
+        "crc" => (commands::crc::USAGE, commands::crc::FLAGS, commands::crc::run),
+        "hash" => (commands::hash::USAGE, commands::hash::FLAGS, commands::hash::run),
+        "cipher" => (commands::cipher::USAGE, commands::cipher::FLAGS, commands::cipher::run),
+        "kdf" => (commands::kdf::USAGE, commands::kdf::FLAGS, commands::kdf::run),
+        "pki" => (commands::pki::USAGE, commands::pki::FLAGS, commands::pki::run),
+        "base64" => (commands::base64::USAGE, commands::base64::FLAGS, commands::base64::run),
+        "base" => (commands::base::USAGE, commands::base::FLAGS, commands::base::run),
+        "ascii" => (commands::ascii::USAGE, commands::ascii::FLAGS, commands::ascii::run),
+        "html" => (commands::html::USAGE, commands::html::FLAGS, commands::html::run),
+        "url" => (commands::url::USAGE, commands::url::FLAGS, commands::url::run),
+        "json" => (commands::json::USAGE, commands::json::FLAGS, commands::json::run),
+        "jwt" => (commands::jwt::USAGE, commands::jwt::FLAGS, commands::jwt::run),
+        "uuid" => (commands::uuid::USAGE, commands::uuid::FLAGS, commands::uuid::run),
+        "unixtime" => (commands::unixtime::USAGE, commands::unixtime::FLAGS, commands::unixtime::run),
+        "quaternion" => (commands::quaternion::USAGE, commands::quaternion::FLAGS, commands::quaternion::run),
+        _ => return Err(format!("unknown command.\n\n{}", USAGE).into()),
+    };
+
+    if rest.iter().any(|arg| arg == "--help" || arg == "-h") {
+        return Ok(usage.as_bytes().to_vec());
+    }
+
+    let args = Args::parse(rest, flags)?;
+    handler(args)
+}
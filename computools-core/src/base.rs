@@ -0,0 +1,362 @@
+//! 2진수부터 36진수까지의 진법 변환
+
+const DIGITS: &str = "0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// 진법별로 허용되는 문자 집합을 반환합니다.
+fn valid_digits(base: u32) -> Result<&'static str, String> {
+    if !(2..=36).contains(&base) {
+        return Err("Unsupported base.".to_string());
+    }
+    Ok(&DIGITS[..base as usize])
+}
+
+/// 입력 문자열이 해당 진법에서 유효한지 검사합니다.
+///
+/// `supports_float`가 참이면 소수점이 포함된 입력도 허용합니다.
+pub fn validate_input(input: &str, base: u32, supports_float: bool) -> Result<(), String> {
+    if input.trim().is_empty() {
+        return Ok(());
+    }
+
+    let input = input.trim();
+
+    // 음수 부호 처리
+    let number_part = input.strip_prefix('-').unwrap_or(input);
+
+    if number_part.is_empty() {
+        return Err("Input value is empty.".to_string());
+    }
+
+    // 부동소수점 모드에서 소수점 처리
+    if supports_float && number_part.contains('.') {
+        return validate_float_input(number_part, base);
+    }
+
+    // 다양한 포맷에서 실제 숫자 부분 추출
+    let cleaned_input = parse_flexible_format(number_part, base)?;
+
+    // prefix만 입력된 경우 (예: "0b", "0x", "0o") - 타이핑 중으로 간주
+    if cleaned_input.is_empty() {
+        return Ok(());
+    }
+
+    // 각 문자가 해당 진수에서 유효한지 검사
+    validate_digits(&cleaned_input, base)?;
+
+    // 실제 파싱 테스트
+    match i64::from_str_radix(&cleaned_input, base) {
+        Ok(_) => Ok(()),
+        Err(_) => Err(format!("Cannot convert to base {}.", base)),
+    }
+}
+
+pub fn validate_float_input(input: &str, base: u32) -> Result<(), String> {
+    let parts: Vec<&str> = input.split('.').collect();
+    if parts.len() > 2 {
+        return Err("Multiple decimal points are not allowed.".to_string());
+    }
+
+    let integer_part = parts[0];
+    let fractional_part = if parts.len() == 2 { parts[1] } else { "" };
+
+    // 정수 부분 검증
+    if !integer_part.is_empty() {
+        let cleaned_integer = parse_flexible_format(integer_part, base)?;
+        if !cleaned_integer.is_empty() {
+            validate_digits(&cleaned_integer, base)?;
+        }
+    }
+
+    // 소수 부분 검증 (prefix 없이)
+    if !fractional_part.is_empty() {
+        validate_digits(fractional_part, base)?;
+    }
+
+    Ok(())
+}
+
+pub fn validate_digits(input: &str, base: u32) -> Result<(), String> {
+    let max_digit = valid_digits(base)?;
+
+    for ch in input.chars() {
+        let upper_ch = ch.to_ascii_uppercase();
+        if !max_digit.contains(upper_ch) {
+            return Err(format!(
+                "'{}' is not a valid character for base {}. Valid characters: {}",
+                ch, base, max_digit
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// 정수 입력을 파싱합니다. prefix만 입력된 경우 `Ok(None)`을 반환합니다.
+pub fn parse_integer_input(input: &str, base: u32) -> Result<Option<i64>, String> {
+    let input = input.trim();
+    let (is_negative, number_part) = match input.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, input),
+    };
+
+    let cleaned_input = parse_flexible_format(number_part, base)?;
+    if cleaned_input.is_empty() {
+        return Ok(None);
+    }
+
+    let num = i64::from_str_radix(&cleaned_input, base)
+        .map_err(|_| format!("Cannot convert to base {}.", base))?;
+    Ok(Some(if is_negative { -num } else { num }))
+}
+
+pub fn parse_float_input(input: &str, base: u32) -> Result<f64, String> {
+    let input = input.trim();
+
+    // 음수 처리
+    let (is_negative, number_part) = match input.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, input),
+    };
+
+    if !number_part.contains('.') {
+        // 정수인 경우
+        let cleaned = parse_flexible_format(number_part, base)?;
+        if cleaned.is_empty() {
+            return Ok(0.0);
+        }
+        let int_value = i64::from_str_radix(&cleaned, base)
+            .map_err(|_| format!("Cannot convert to base {}.", base))? as f64;
+        return Ok(if is_negative { -int_value } else { int_value });
+    }
+
+    // 소수점 분리
+    let parts: Vec<&str> = number_part.split('.').collect();
+    if parts.len() != 2 {
+        return Err("Invalid decimal format.".to_string());
+    }
+
+    let integer_part = parts[0];
+    let fractional_part = parts[1];
+
+    // 정수 부분 변환
+    let integer_value = if integer_part.is_empty() {
+        0.0
+    } else {
+        let cleaned = parse_flexible_format(integer_part, base)?;
+        if cleaned.is_empty() {
+            0.0
+        } else {
+            i64::from_str_radix(&cleaned, base)
+                .map_err(|_| format!("Cannot convert integer part to base {}.", base))? as f64
+        }
+    };
+
+    // 소수 부분 변환
+    let fractional_value = if fractional_part.is_empty() {
+        0.0
+    } else {
+        convert_fractional_from_base(fractional_part, base)?
+    };
+
+    let result = integer_value + fractional_value;
+    Ok(if is_negative { -result } else { result })
+}
+
+pub fn convert_fractional_from_base(fractional: &str, base: u32) -> Result<f64, String> {
+    let mut result = 0.0;
+    let mut power = 1.0 / base as f64;
+
+    for ch in fractional.chars() {
+        let digit_value = char_to_digit(ch)?;
+        if digit_value >= base {
+            return Err(format!("Invalid digit '{}' for base {}.", ch, base));
+        }
+        result += digit_value as f64 * power;
+        power /= base as f64;
+    }
+
+    Ok(result)
+}
+
+pub fn char_to_digit(ch: char) -> Result<u32, String> {
+    match ch.to_ascii_uppercase() {
+        '0'..='9' => Ok(ch as u32 - '0' as u32),
+        'A'..='Z' => Ok(ch.to_ascii_uppercase() as u32 - 'A' as u32 + 10),
+        _ => Err(format!("Invalid character: {}", ch)),
+    }
+}
+
+/// 부동소수점 값을 지정한 진법 문자열로 변환합니다.
+///
+/// 소수 부분은 `precision` 자리까지 계산하며, 반복 소수는 괄호로 표시합니다.
+pub fn convert_float_to_base(value: f64, base: u32, precision: u32) -> String {
+    if value.is_nan() {
+        return "NaN".to_string();
+    }
+    if value.is_infinite() {
+        return if value.is_sign_positive() { "∞" } else { "-∞" }.to_string();
+    }
+
+    let is_negative = value < 0.0;
+    let abs_value = value.abs();
+
+    let integer_part = abs_value.trunc() as i64;
+    let fractional_part = abs_value.fract();
+
+    // 정수 부분 변환
+    let integer_str = convert_integer_to_base(integer_part, base);
+
+    // 소수 부분 변환
+    let fractional_str = if fractional_part == 0.0 {
+        String::new()
+    } else {
+        convert_fractional_to_base(fractional_part, base, precision)
+    };
+
+    let result = if fractional_str.is_empty() {
+        integer_str
+    } else {
+        format!("{}.{}", integer_str, fractional_str)
+    };
+
+    if is_negative {
+        format!("-{}", result)
+    } else {
+        result
+    }
+}
+
+/// 0 이상의 정수를 지정한 진법 문자열로 변환합니다.
+pub fn convert_integer_to_base(num: i64, base: u32) -> String {
+    convert_unsigned_to_base(num.max(0) as u64, base)
+}
+
+fn convert_unsigned_to_base(mut num: u64, base: u32) -> String {
+    if num == 0 {
+        return "0".to_string();
+    }
+
+    let digits = DIGITS.as_bytes();
+    let mut result = String::new();
+
+    while num > 0 {
+        let digit = (num % base as u64) as usize;
+        result.insert(0, digits[digit] as char);
+        num /= base as u64;
+    }
+
+    result
+}
+
+pub fn convert_fractional_to_base(mut fractional: f64, base: u32, precision: u32) -> String {
+    let digits = DIGITS.as_bytes();
+    let mut result = String::new();
+    let mut seen_values = Vec::new();
+
+    for _ in 0..precision {
+        if fractional == 0.0 {
+            break;
+        }
+
+        // 반복 소수 감지
+        if seen_values.contains(&fractional) {
+            // 반복 패턴 찾기
+            let repeat_start = seen_values
+                .iter()
+                .position(|&x: &f64| (x - fractional).abs() < 1e-15)
+                .unwrap_or(0);
+            let repeating_part = &result[repeat_start..];
+            if !repeating_part.is_empty() {
+                return format!("{}({})", &result[..repeat_start], repeating_part);
+            }
+            break;
+        }
+
+        seen_values.push(fractional);
+        fractional *= base as f64;
+        let digit = fractional.trunc() as usize;
+
+        if digit < base as usize {
+            result.push(digits[digit] as char);
+        }
+
+        fractional = fractional.fract();
+    }
+
+    // 정밀도 한계에 도달한 경우 끝에 ... 추가
+    if fractional != 0.0 && result.len() == precision as usize {
+        result.push_str("...");
+    }
+
+    result
+}
+
+/// 부호 있는 정수를 지정한 진법 문자열로 변환합니다.
+pub fn format_integer(num: i64, base: u32) -> String {
+    if base == 10 {
+        return num.to_string();
+    }
+    let sign = if num < 0 { "-" } else { "" };
+    format!("{}{}", sign, convert_unsigned_to_base(num.unsigned_abs(), base))
+}
+
+/// 진법별 prefix(0b, 0o, 0x, \x 등)를 제거한 숫자 부분을 반환합니다.
+pub fn parse_flexible_format(input: &str, base: u32) -> Result<String, String> {
+    let input = input.trim();
+
+    if input.is_empty() {
+        return Ok(input.to_string());
+    }
+
+    match base {
+        2 => {
+            // Binary: 0b101010, b101010, 101010
+            if input.starts_with("0b") || input.starts_with("0B") {
+                Ok(input[2..].to_string())
+            } else if input.starts_with('b') || input.starts_with('B') {
+                Ok(input[1..].to_string())
+            } else {
+                Ok(input.to_string())
+            }
+        }
+        8 => {
+            // Octal: 0o52, o52, 052, 52, \052
+            if input.starts_with("0o") || input.starts_with("0O") {
+                Ok(input[2..].to_string())
+            } else if input.starts_with('o') || input.starts_with('O') {
+                Ok(input[1..].to_string())
+            } else if input.starts_with('\\') && input.len() > 1 {
+                // Octal escape sequence: \052
+                Ok(input[1..].to_string())
+            } else if input.starts_with('0')
+                && input.len() > 1
+                && !input.starts_with("0x")
+                && !input.starts_with("0X")
+                && !input.starts_with("0b")
+                && !input.starts_with("0B")
+            {
+                // Leading zero for octal (traditional C style)
+                // But exclude other prefixes like 0x, 0b
+                Ok(input[1..].to_string())
+            } else {
+                Ok(input.to_string())
+            }
+        }
+        16 => {
+            // Hexadecimal: 0x2A, x2A, 2A, \x2A
+            if input.starts_with("0x") || input.starts_with("0X") {
+                Ok(input[2..].to_string())
+            } else if input.starts_with('x') || input.starts_with('X') {
+                Ok(input[1..].to_string())
+            } else if input.starts_with("\\x") || input.starts_with("\\X") {
+                Ok(input[2..].to_string())
+            } else {
+                Ok(input.to_string())
+            }
+        }
+        _ => {
+            // Other bases: just return as-is
+            Ok(input.to_string())
+        }
+    }
+}
@@ -0,0 +1,374 @@
+//! Base64 인코딩/디코딩과 출력 포맷팅
+
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+
+use crate::file_type::detect_file_type;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Base64Mode {
+    Standard,   // RFC 4648 표준 Base64
+    UrlSafe,    // URL-safe Base64 (+ → -, / → _)
+    NoPadding,  // 패딩 없는 Base64
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum OutputFormat {
+    Continuous,     // 연속된 문자열
+    LineBreaks76,   // 76자마다 줄바꿈 (MIME)
+    LineBreaks64,   // 64자마다 줄바꿈
+    Chunks4,        // 4자마다 공백
+    Chunks8,        // 8자마다 공백
+    DataUrlImg,     // data:image/[type];base64,[data] for <img>
+    DataUrlCss,     // url(data:image/[type];base64,[data]) for CSS
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FileInfo {
+    pub name: String,
+    pub size: usize,
+    pub mime_type: String,
+}
+
+/// 모드에 맞춰 바이트 배열을 Base64 문자열로 인코딩합니다. (포맷팅 없음)
+pub fn encode_with_mode(input: &[u8], mode: &Base64Mode) -> String {
+    match mode {
+        Base64Mode::Standard => STANDARD.encode(input),
+        Base64Mode::UrlSafe => {
+            // URL-safe Base64: + → -, / → _
+            STANDARD
+                .encode(input)
+                .chars()
+                .map(|c| match c {
+                    '+' => '-',
+                    '/' => '_',
+                    other => other,
+                })
+                .collect()
+        }
+        Base64Mode::NoPadding => {
+            // 패딩 제거
+            STANDARD.encode(input).trim_end_matches('=').to_string()
+        }
+    }
+}
+
+/// 모드에 맞춰 Base64 문자열을 디코딩합니다. 공백과 줄바꿈은 무시합니다.
+pub fn decode_with_mode(input: &str, mode: &Base64Mode) -> Result<Vec<u8>, String> {
+    // 포맷팅 제거 (공백, 줄바꿈 등)
+    let cleaned_input = input
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .collect::<String>();
+
+    let standardized_input = match mode {
+        Base64Mode::Standard => cleaned_input,
+        Base64Mode::UrlSafe => {
+            // URL-safe를 표준으로 변환: - → +, _ → /
+            cleaned_input
+                .chars()
+                .map(|c| match c {
+                    '-' => '+',
+                    '_' => '/',
+                    other => other,
+                })
+                .collect()
+        }
+        Base64Mode::NoPadding => {
+            // 패딩 추가
+            let mut padded = cleaned_input;
+            while padded.len() % 4 != 0 {
+                padded.push('=');
+            }
+            padded
+        }
+    };
+
+    STANDARD
+        .decode(&standardized_input)
+        .map_err(|_| "Failed to decode Base64".to_string())
+}
+
+/// 큰 Base64 입력을 청크로 나눠 디코딩합니다.
+///
+/// 4글자 묶음이 완성되는 만큼만 디코딩하고 나머지는 다음 청크로 넘기므로, 청크 경계가 묶음이나 줄바꿈
+/// 중간에 걸려도 전체를 한 번에 디코딩한 것과 결과가 같습니다. 공백과 줄바꿈은 무시합니다.
+#[derive(Debug, Clone)]
+pub struct Base64StreamDecoder {
+    mode: Base64Mode,
+    pending: String,    // 아직 4글자가 안 된 나머지 (패딩을 만난 뒤에는 끝까지)
+    position: usize,    // 공백을 뺀 지금까지의 글자 수 (에러 위치 표시용)
+    padding_seen: bool, // '=' 뒤에는 '='만 올 수 있음
+}
+
+impl Base64StreamDecoder {
+    pub fn new(mode: Base64Mode) -> Self {
+        Self { mode, pending: String::new(), position: 0, padding_seen: false }
+    }
+
+    /// 다음 청크를 넣고, 완성된 묶음을 디코딩한 바이트를 돌려줍니다.
+    pub fn push(&mut self, chunk: &[u8]) -> Result<Vec<u8>, String> {
+        for &byte in chunk.iter().filter(|byte| !byte.is_ascii_whitespace()) {
+            self.position += 1;
+            if byte == b'=' && self.mode != Base64Mode::NoPadding {
+                self.padding_seen = true;
+            } else if !self.is_alphabet(byte) {
+                return Err(format!("Invalid byte {} at position {} for {}.", describe_byte(byte), self.position, mode_name(&self.mode)));
+            } else if self.padding_seen {
+                return Err(format!(
+                    "Invalid padding: '=' characters can only appear at the end of Base64 string (found '{}' at position {}).",
+                    char::from(byte),
+                    self.position
+                ));
+            }
+            self.pending.push(char::from(byte));
+        }
+
+        if self.padding_seen {
+            return Ok(Vec::new());
+        }
+        let complete = self.pending.len() - self.pending.len() % 4;
+        let quartets: String = self.pending.drain(..complete).collect();
+        decode_with_mode(&quartets, &self.mode)
+    }
+
+    /// 남은 글자를 디코딩합니다. 패딩이 있는 모드는 전체 길이가 4의 배수여야 합니다.
+    pub fn finish(self) -> Result<Vec<u8>, String> {
+        if self.mode != Base64Mode::NoPadding && !self.position.is_multiple_of(4) {
+            return Err(format!(
+                "Invalid Base64 length: {} characters. Base64 strings must be multiples of 4 characters.",
+                self.position
+            ));
+        }
+        let padding = self.pending.bytes().filter(|byte| *byte == b'=').count();
+        if padding > 2 {
+            return Err(format!("Invalid padding: too many '=' characters ({}). Maximum allowed is 2.", padding));
+        }
+        decode_with_mode(&self.pending, &self.mode)
+    }
+
+    fn is_alphabet(&self, byte: u8) -> bool {
+        byte.is_ascii_alphanumeric()
+            || match self.mode {
+                Base64Mode::Standard | Base64Mode::NoPadding => matches!(byte, b'+' | b'/'),
+                Base64Mode::UrlSafe => matches!(byte, b'-' | b'_'),
+            }
+    }
+}
+
+fn mode_name(mode: &Base64Mode) -> &'static str {
+    match mode {
+        Base64Mode::Standard => "Standard Base64",
+        Base64Mode::UrlSafe => "URL-safe Base64",
+        Base64Mode::NoPadding => "Base64 without padding",
+    }
+}
+
+fn describe_byte(byte: u8) -> String {
+    if byte.is_ascii_graphic() {
+        format!("'{}'", char::from(byte))
+    } else {
+        format!("0x{:02X}", byte)
+    }
+}
+
+/// 문자열을 `size`자 단위로 잘라 `separator`로 연결합니다.
+fn split_every(input: &str, size: usize, separator: &str) -> String {
+    input
+        .chars()
+        .collect::<Vec<char>>()
+        .chunks(size)
+        .map(|chunk| chunk.iter().collect::<String>())
+        .collect::<Vec<String>>()
+        .join(separator)
+}
+
+/// 줄바꿈/공백 단위 포맷을 적용합니다. Data URL 포맷은 그대로 반환합니다.
+pub fn format_chunk(input: &str, format: &OutputFormat) -> String {
+    match format {
+        OutputFormat::LineBreaks76 => split_every(input, 76, "\n"),
+        OutputFormat::LineBreaks64 => split_every(input, 64, "\n"),
+        OutputFormat::Chunks4 => split_every(input, 4, " "),
+        OutputFormat::Chunks8 => split_every(input, 8, " "),
+        OutputFormat::Continuous | OutputFormat::DataUrlImg | OutputFormat::DataUrlCss => {
+            input.to_string()
+        }
+    }
+}
+
+/// 출력 포맷을 적용합니다. Data URL 포맷은 파일 정보의 MIME 타입을 사용합니다.
+pub fn format_output(input: &str, format: &OutputFormat, file_info: Option<&FileInfo>) -> String {
+    let mime_type = file_info
+        .map(|info| info.mime_type.as_str())
+        .unwrap_or("text/plain");
+
+    match format {
+        OutputFormat::DataUrlImg => format!("data:{};base64,{}", mime_type, input),
+        OutputFormat::DataUrlCss => format!("url(data:{};base64,{})", mime_type, input),
+        _ => format_chunk(input, format),
+    }
+}
+
+/// 줄바꿈/공백 포맷인지 여부 (청크 단위 포맷팅 대상)
+pub fn is_chunkable_format(format: &OutputFormat) -> bool {
+    matches!(
+        format,
+        OutputFormat::LineBreaks76
+            | OutputFormat::LineBreaks64
+            | OutputFormat::Chunks4
+            | OutputFormat::Chunks8
+    )
+}
+
+pub fn validate_base64_input(input: &str, mode: &Base64Mode) -> Result<(), String> {
+    if input.trim().is_empty() {
+        return Ok(());
+    }
+
+    // 포맷팅 제거 (공백, 줄바꿈 등)
+    let cleaned_input = input
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .collect::<String>();
+
+    // Base64 모드별 유효한 문자 집합
+    let valid_chars = match mode {
+        Base64Mode::Standard => "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/=",
+        Base64Mode::UrlSafe => "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_=",
+        Base64Mode::NoPadding => "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/",
+    };
+
+    // 길이 검증 (NoPadding 모드는 예외)
+    if *mode != Base64Mode::NoPadding && cleaned_input.len() % 4 != 0 {
+        return Err(format!("Invalid Base64 length: {} characters. Base64 strings must be multiples of 4 characters.", cleaned_input.len()));
+    }
+
+    // 문자 집합 검증
+    for (index, ch) in cleaned_input.chars().enumerate() {
+        if !valid_chars.contains(ch) {
+            return Err(format!("Invalid character '{}' at position {} for {}. Allowed characters: {}", ch, index + 1, mode_name(mode), valid_chars));
+        }
+    }
+
+    // 패딩 검증 (Standard와 UrlSafe 모드)
+    if *mode != Base64Mode::NoPadding {
+        if let Some(start_pos) = cleaned_input.find('=') {
+            let padding_part = &cleaned_input[start_pos..];
+            if !padding_part.chars().all(|c| c == '=') {
+                return Err("Invalid padding: '=' characters can only appear at the end of Base64 string.".to_string());
+            }
+
+            if padding_part.len() > 2 {
+                return Err(format!("Invalid padding: too many '=' characters ({}). Maximum allowed is 2.", padding_part.len()));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+pub fn get_detailed_base64_error(input: &str) -> String {
+    // 더 구체적인 에러 분석
+    if input.trim().is_empty() {
+        return "Input is empty.".to_string();
+    }
+
+    let input = input.trim();
+
+    // 길이 검사
+    if !input.len().is_multiple_of(4) {
+        let missing = 4 - (input.len() % 4);
+        return format!("Invalid length: {} characters. Need {} more character(s) to make it a multiple of 4.", input.len(), missing);
+    }
+
+    // 잘못된 문자 찾기
+    let valid_chars = "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/=";
+    for (index, ch) in input.chars().enumerate() {
+        if !valid_chars.contains(ch) {
+            if ch.is_whitespace() {
+                return format!("Whitespace character found at position {}. Remove all spaces, tabs, and newlines.", index + 1);
+            } else if ch.is_ascii_punctuation() {
+                return format!("Invalid punctuation '{}' at position {}. Only '+', '/', and '=' are allowed.", ch, index + 1);
+            } else {
+                return format!("Invalid character '{}' at position {}. Use only A-Z, a-z, 0-9, +, /, =", ch, index + 1);
+            }
+        }
+    }
+
+    "Unknown Base64 format error.".to_string()
+}
+
+pub fn format_file_size(size: usize) -> String {
+    if size < 1024 {
+        format!("{} B", size)
+    } else if size < 1024 * 1024 {
+        format!("{:.1} KB", size as f64 / 1024.0)
+    } else if size < 1024 * 1024 * 1024 {
+        format!("{:.1} MB", size as f64 / (1024.0 * 1024.0))
+    } else {
+        format!("{:.1} GB", size as f64 / (1024.0 * 1024.0 * 1024.0))
+    }
+}
+
+pub fn get_file_icon(mime_type: &str) -> &'static str {
+    if mime_type.starts_with("image/") {
+        "🖼️"
+    } else if mime_type == "application/pdf" {
+        "📄"
+    } else if mime_type.starts_with("text/") {
+        "📝"
+    } else if mime_type.starts_with("audio/") {
+        "🎵"
+    } else if mime_type.starts_with("video/") {
+        "🎬"
+    } else if mime_type.contains("zip") || mime_type.contains("rar") || mime_type.contains("tar") || mime_type.contains("x-7z") || mime_type == "application/x-xz" || mime_type == "application/zstd" {
+        "📦"
+    } else if matches!(mime_type, "application/x-elf" | "application/vnd.microsoft.portable-executable" | "application/x-mach-binary" | "application/wasm" | "application/java-vm") {
+        "⚙️"
+    } else {
+        "📁"
+    }
+}
+
+/// 이미지 파일 시그니처를 검사해 MIME 타입을 반환합니다.
+pub fn detect_image_mime(data: &[u8]) -> Option<String> {
+    detect_file_type(data)
+        .filter(|file_type| file_type.is_image())
+        .map(|file_type| file_type.mime_type.to_string())
+}
+
+pub fn create_data_url(data: &[u8], mime_type: &str) -> String {
+    format!("data:{};base64,{}", mime_type, STANDARD.encode(data))
+}
+
+/// `data:` URL(또는 CSS `url(data:...)`)에서 MIME 타입과 Base64 데이터를 분리합니다.
+pub fn parse_data_url(input: &str) -> Option<(String, String)> {
+    let cleaned_input = input.trim();
+
+    // CSS url() 래퍼 제거
+    let data_part = cleaned_input
+        .strip_prefix("url(")
+        .and_then(|rest| rest.strip_suffix(')'))
+        .unwrap_or(cleaned_input);
+
+    // data: URL 형식 확인
+    let without_data = data_part.strip_prefix("data:")?;
+
+    // MIME 타입과 base64 데이터 분리
+    let comma_pos = without_data.find(',')?;
+    let header = &without_data[..comma_pos];
+    let base64_data = &without_data[comma_pos + 1..];
+
+    // MIME 타입 추출 (;base64 제거)
+    let mime_type = match header.find(';') {
+        Some(semicolon_pos) => header[..semicolon_pos].to_string(),
+        None => header.to_string(),
+    };
+
+    // base64 키워드 확인
+    if header.contains("base64") {
+        Some((mime_type, base64_data.to_string()))
+    } else {
+        None
+    }
+}
@@ -0,0 +1,99 @@
+//! 여러 도구가 함께 쓰는 바이트 입력 해석과 난수 생성
+//!
+//! 해시의 텍스트 입력과 HMAC 키, JWT secret, KDF 비밀번호와 salt, 암호화 키/IV/암호문이
+//! 모두 같은 규칙으로 Hex와 Base64를 읽도록 한곳에 모았습니다.
+
+use base64::engine::general_purpose::{STANDARD, STANDARD_NO_PAD};
+use base64::Engine;
+
+/// 텍스트 입력을 바이트로 해석하는 방식
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InputEncoding {
+    Utf8,
+    Hex,
+    Base64,
+}
+
+impl InputEncoding {
+    pub fn all() -> [InputEncoding; 3] {
+        [InputEncoding::Utf8, InputEncoding::Hex, InputEncoding::Base64]
+    }
+
+    /// 텍스트가 아닌 바이트(키, IV, 암호문)에 쓰는 인코딩. UTF-8을 뺀 목록입니다.
+    pub fn binary() -> [InputEncoding; 2] {
+        [InputEncoding::Hex, InputEncoding::Base64]
+    }
+
+    pub fn key(&self) -> &'static str {
+        match self {
+            InputEncoding::Utf8 => "utf8",
+            InputEncoding::Hex => "hex",
+            InputEncoding::Base64 => "base64",
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            InputEncoding::Utf8 => "UTF-8",
+            InputEncoding::Hex => "Hex",
+            InputEncoding::Base64 => "Base64",
+        }
+    }
+
+    pub fn from_key(key: &str) -> Option<InputEncoding> {
+        let key = key.trim().to_lowercase();
+        Self::all().into_iter().find(|encoding| encoding.key() == key)
+    }
+
+    /// 입력을 바이트로 바꿉니다. 빈 입력(공백만 있는 입력 포함)은 빈 바이트열입니다.
+    ///
+    /// Hex는 공백, `:`, `,`로 나눈 묶음마다 `0x`나 `\x` 접두사를 붙일 수 있고
+    /// (`61 62:63`, `0x616263`, `\x61\x62\x63`), 묶음마다 자릿수가 짝수여야 합니다.
+    /// Base64는 공백을 무시하고 URL-safe 문자와 생략된 패딩도 받습니다.
+    pub fn decode(&self, input: &str) -> Result<Vec<u8>, String> {
+        match self {
+            InputEncoding::Utf8 => Ok(input.as_bytes().to_vec()),
+            InputEncoding::Hex => {
+                let mut bytes = Vec::new();
+                let groups = input
+                    .split(|c: char| c.is_whitespace() || c == ':' || c == ',')
+                    .flat_map(|group| group.split("\\x").flat_map(|part| part.split("\\X")));
+                for group in groups.filter(|group| !group.is_empty()) {
+                    let digits = group.strip_prefix("0x").or_else(|| group.strip_prefix("0X")).unwrap_or(group);
+                    bytes.extend(hex::decode(digits).map_err(|e| format!("Invalid hex input '{}': {}", group, e))?);
+                }
+                Ok(bytes)
+            }
+            InputEncoding::Base64 => {
+                let cleaned: String = input
+                    .chars()
+                    .filter(|c| !c.is_whitespace() && *c != '=')
+                    .map(|c| match c {
+                        '-' => '+',
+                        '_' => '/',
+                        other => other,
+                    })
+                    .collect();
+                STANDARD_NO_PAD.decode(&cleaned).map_err(|e| format!("Invalid Base64 input: {}", e))
+            }
+        }
+    }
+
+    /// 바이트를 이 인코딩의 텍스트로 바꿉니다. Hex는 소문자, Base64는 패딩을 붙이고,
+    /// UTF-8이 아닌 바이트는 U+FFFD로 바뀝니다.
+    pub fn encode(&self, data: &[u8]) -> String {
+        match self {
+            InputEncoding::Utf8 => String::from_utf8_lossy(data).into_owned(),
+            InputEncoding::Hex => hex::encode(data),
+            InputEncoding::Base64 => STANDARD.encode(data),
+        }
+    }
+}
+
+/// 운영체제(브라우저에서는 `crypto.getRandomValues`) 난수로 `length` 바이트를 만듭니다.
+/// salt, 키, IV에 씁니다.
+pub fn random_bytes(length: usize) -> Result<Vec<u8>, String> {
+    let mut bytes = vec![0u8; length];
+    getrandom::fill(&mut bytes).map_err(|e| format!("Failed to generate random bytes: {}", e))?;
+    Ok(bytes)
+}
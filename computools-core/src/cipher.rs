@@ -0,0 +1,203 @@
+//! 대칭키 암호화/복호화 (AES-GCM, AES-CBC, AES-CTR, ChaCha20-Poly1305)
+//!
+//! AEAD 모드(GCM, ChaCha20-Poly1305)의 암호문은 WebCrypto, OpenSSL EVP와 같이
+//! 암호문 뒤에 16바이트 인증 태그를 붙인 형식입니다. CBC는 PKCS#7 패딩을 사용합니다.
+
+use aes::cipher::block_padding::Pkcs7;
+use aes::cipher::{BlockDecryptMut, BlockEncryptMut, KeyIvInit, StreamCipher};
+use aes::{Aes128, Aes256};
+use aes_gcm::aead::{Aead, KeyInit, Payload};
+use aes_gcm::{Aes128Gcm, Aes256Gcm};
+use chacha20poly1305::ChaCha20Poly1305;
+
+/// AEAD 인증 태그 길이 (바이트)
+pub const TAG_LENGTH: usize = 16;
+
+const BLOCK_SIZE: usize = 16;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CipherMode {
+    Aes128Gcm,
+    Aes256Gcm,
+    Aes128Cbc,
+    Aes256Cbc,
+    Aes128Ctr,
+    Aes256Ctr,
+    ChaCha20Poly1305,
+}
+
+impl CipherMode {
+    pub fn all() -> [CipherMode; 7] {
+        [
+            CipherMode::Aes128Gcm,
+            CipherMode::Aes256Gcm,
+            CipherMode::Aes128Cbc,
+            CipherMode::Aes256Cbc,
+            CipherMode::Aes128Ctr,
+            CipherMode::Aes256Ctr,
+            CipherMode::ChaCha20Poly1305,
+        ]
+    }
+
+    /// CLI와 select 값에 쓰는 이름 (예: "aes-256-gcm", OpenSSL 이름과 같음)
+    pub fn key(&self) -> &'static str {
+        match self {
+            CipherMode::Aes128Gcm => "aes-128-gcm",
+            CipherMode::Aes256Gcm => "aes-256-gcm",
+            CipherMode::Aes128Cbc => "aes-128-cbc",
+            CipherMode::Aes256Cbc => "aes-256-cbc",
+            CipherMode::Aes128Ctr => "aes-128-ctr",
+            CipherMode::Aes256Ctr => "aes-256-ctr",
+            CipherMode::ChaCha20Poly1305 => "chacha20-poly1305",
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            CipherMode::Aes128Gcm => "AES-128-GCM",
+            CipherMode::Aes256Gcm => "AES-256-GCM",
+            CipherMode::Aes128Cbc => "AES-128-CBC",
+            CipherMode::Aes256Cbc => "AES-256-CBC",
+            CipherMode::Aes128Ctr => "AES-128-CTR",
+            CipherMode::Aes256Ctr => "AES-256-CTR",
+            CipherMode::ChaCha20Poly1305 => "ChaCha20-Poly1305",
+        }
+    }
+
+    pub fn from_key(key: &str) -> Option<CipherMode> {
+        let key = key.trim().to_lowercase();
+        Self::all().into_iter().find(|mode| mode.key() == key)
+    }
+
+    /// 키 길이 (바이트)
+    pub fn key_length(&self) -> usize {
+        match self {
+            CipherMode::Aes128Gcm | CipherMode::Aes128Cbc | CipherMode::Aes128Ctr => 16,
+            _ => 32,
+        }
+    }
+
+    /// IV/nonce 길이 (바이트). GCM과 ChaCha20-Poly1305는 96비트 nonce를 사용합니다.
+    pub fn iv_length(&self) -> usize {
+        match self {
+            CipherMode::Aes128Gcm | CipherMode::Aes256Gcm | CipherMode::ChaCha20Poly1305 => 12,
+            _ => 16,
+        }
+    }
+
+    /// 인증 태그와 AAD를 사용하는 모드인지 여부
+    pub fn is_aead(&self) -> bool {
+        matches!(self, CipherMode::Aes128Gcm | CipherMode::Aes256Gcm | CipherMode::ChaCha20Poly1305)
+    }
+
+    /// IV 입력칸 이름 ("Nonce" 또는 "IV")
+    pub fn iv_label(&self) -> &'static str {
+        if self.is_aead() {
+            "Nonce"
+        } else {
+            "IV"
+        }
+    }
+}
+
+/// `plaintext`를 암호화합니다. AEAD 모드는 인증 태그가 붙은 암호문을 반환합니다.
+pub fn encrypt(mode: CipherMode, key: &[u8], iv: &[u8], aad: &[u8], plaintext: &[u8]) -> Result<Vec<u8>, String> {
+    check_lengths(mode, key, iv, aad)?;
+
+    let payload = Payload { msg: plaintext, aad };
+    let aead_error = |_| format!("{} encryption failed.", mode.name());
+    Ok(match mode {
+        CipherMode::Aes128Gcm => aead_cipher::<Aes128Gcm>(key).encrypt(iv.into(), payload).map_err(aead_error)?,
+        CipherMode::Aes256Gcm => aead_cipher::<Aes256Gcm>(key).encrypt(iv.into(), payload).map_err(aead_error)?,
+        CipherMode::ChaCha20Poly1305 => {
+            aead_cipher::<ChaCha20Poly1305>(key).encrypt(iv.into(), payload).map_err(aead_error)?
+        }
+        CipherMode::Aes128Cbc => {
+            cbc::Encryptor::<Aes128>::new(key.into(), iv.into()).encrypt_padded_vec_mut::<Pkcs7>(plaintext)
+        }
+        CipherMode::Aes256Cbc => {
+            cbc::Encryptor::<Aes256>::new(key.into(), iv.into()).encrypt_padded_vec_mut::<Pkcs7>(plaintext)
+        }
+        CipherMode::Aes128Ctr | CipherMode::Aes256Ctr => apply_ctr(mode, key, iv, plaintext),
+    })
+}
+
+/// `ciphertext`를 복호화합니다. 인증 태그나 패딩이 맞지 않으면 원인을 설명하는 에러를 반환합니다.
+pub fn decrypt(mode: CipherMode, key: &[u8], iv: &[u8], aad: &[u8], ciphertext: &[u8]) -> Result<Vec<u8>, String> {
+    check_lengths(mode, key, iv, aad)?;
+
+    if mode.is_aead() {
+        if ciphertext.len() < TAG_LENGTH {
+            return Err(format!(
+                "{} ciphertext must end with a {}-byte authentication tag, but only {} bytes were given.",
+                mode.name(),
+                TAG_LENGTH,
+                ciphertext.len()
+            ));
+        }
+        let payload = Payload { msg: ciphertext, aad };
+        let result = match mode {
+            CipherMode::Aes128Gcm => aead_cipher::<Aes128Gcm>(key).decrypt(iv.into(), payload),
+            CipherMode::Aes256Gcm => aead_cipher::<Aes256Gcm>(key).decrypt(iv.into(), payload),
+            _ => aead_cipher::<ChaCha20Poly1305>(key).decrypt(iv.into(), payload),
+        };
+        return result.map_err(|_| {
+            "Authentication failed: the tag doesn't match. The key, nonce or AAD is wrong, or the ciphertext was modified."
+                .to_string()
+        });
+    }
+
+    match mode {
+        CipherMode::Aes128Cbc | CipherMode::Aes256Cbc => {
+            if ciphertext.is_empty() || !ciphertext.len().is_multiple_of(BLOCK_SIZE) {
+                return Err(format!(
+                    "CBC ciphertext must be a non-empty multiple of {} bytes, got {} bytes.",
+                    BLOCK_SIZE,
+                    ciphertext.len()
+                ));
+            }
+            let result = if mode == CipherMode::Aes128Cbc {
+                cbc::Decryptor::<Aes128>::new(key.into(), iv.into()).decrypt_padded_vec_mut::<Pkcs7>(ciphertext)
+            } else {
+                cbc::Decryptor::<Aes256>::new(key.into(), iv.into()).decrypt_padded_vec_mut::<Pkcs7>(ciphertext)
+            };
+            result.map_err(|_| "Invalid PKCS#7 padding: the key or IV is wrong, or the data isn't CBC ciphertext.".to_string())
+        }
+        _ => Ok(apply_ctr(mode, key, iv, ciphertext)),
+    }
+}
+
+fn check_lengths(mode: CipherMode, key: &[u8], iv: &[u8], aad: &[u8]) -> Result<(), String> {
+    if key.len() != mode.key_length() {
+        return Err(format!("{} needs a {}-byte key, got {} bytes.", mode.name(), mode.key_length(), key.len()));
+    }
+    if iv.len() != mode.iv_length() {
+        return Err(format!(
+            "{} needs a {}-byte {}, got {} bytes.",
+            mode.name(),
+            mode.iv_length(),
+            if mode.is_aead() { "nonce" } else { "IV" },
+            iv.len()
+        ));
+    }
+    if !mode.is_aead() && !aad.is_empty() {
+        return Err(format!("{} doesn't use AAD; leave it empty.", mode.name()));
+    }
+    Ok(())
+}
+
+/// 키 길이는 `check_lengths`에서 확인했으므로 실패하지 않음
+fn aead_cipher<C: KeyInit>(key: &[u8]) -> C {
+    C::new_from_slice(key).expect("key length checked")
+}
+
+/// CTR은 암호화와 복호화가 같은 연산 (128비트 빅엔디언 카운터, OpenSSL과 같음)
+fn apply_ctr(mode: CipherMode, key: &[u8], iv: &[u8], data: &[u8]) -> Vec<u8> {
+    let mut output = data.to_vec();
+    if mode == CipherMode::Aes128Ctr {
+        ctr::Ctr128BE::<Aes128>::new(key.into(), iv.into()).apply_keystream(&mut output);
+    } else {
+        ctr::Ctr128BE::<Aes256>::new(key.into(), iv.into()).apply_keystream(&mut output);
+    }
+    output
+}
@@ -0,0 +1,454 @@
+//! Base64와 함께 쓰는 텍스트 인코딩 (Base32, Base58, Ascii85, Z85, Base45, 이메일 인코딩)
+//!
+//! `Codec`은 Base64 변형(`Base64Mode`)과 다른 Base-N 알파벳을 한 목록으로 묶어 Base64 도구와 CLI가
+//! 같은 인코딩/디코딩 파이프라인(파일 업로드, 청크 처리, 출력 포맷)을 쓰게 합니다.
+
+use sha2::{Digest, Sha256};
+
+use crate::base64::{decode_with_mode, encode_with_mode, validate_base64_input, Base64Mode};
+use crate::mime;
+
+const BASE32_ALPHABET: &[u8; 32] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+const BASE32HEX_ALPHABET: &[u8; 32] = b"0123456789ABCDEFGHIJKLMNOPQRSTUV";
+const BASE58_ALPHABET: &[u8; 58] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+const Z85_ALPHABET: &[u8; 85] = b"0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ.-:+=^!/*?&<>()[]{}@%$#";
+const BASE45_ALPHABET: &[u8; 45] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ $%*+-./:";
+
+/// Base58은 입력 길이의 제곱에 비례해 느려지므로 키, 주소 같은 짧은 값만 받습니다.
+pub const BASE58_MAX_BYTES: usize = 8 * 1024;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Codec {
+    Base64(Base64Mode),
+    Base32,    // RFC 4648 (TOTP 시크릿 등)
+    Base32Hex, // RFC 4648 "Extended Hex" 알파벳
+    Base58,    // 비트코인 알파벳
+    Base58Check,
+    Ascii85, // btoa/Adobe (PDF, Git 바이너리 패치)
+    Z85,     // ZeroMQ
+    Base45,  // RFC 9285 (EU 디지털 코로나 증명서 QR)
+    QuotedPrintable, // RFC 2045 메일 본문
+    Uuencode,
+    Yenc,
+    MimeHeader, // RFC 2047 인코딩된 단어 (=?charset?B|Q?...?=)
+}
+
+/// uuencode/yEnc 헤더에 쓰는 파일 이름
+const ENCODED_FILE_NAME: &str = "data";
+
+impl Codec {
+    pub fn all() -> [Codec; 14] {
+        [
+            Codec::Base64(Base64Mode::Standard),
+            Codec::Base64(Base64Mode::UrlSafe),
+            Codec::Base64(Base64Mode::NoPadding),
+            Codec::Base32,
+            Codec::Base32Hex,
+            Codec::Base58,
+            Codec::Base58Check,
+            Codec::Ascii85,
+            Codec::Z85,
+            Codec::Base45,
+            Codec::QuotedPrintable,
+            Codec::Uuencode,
+            Codec::Yenc,
+            Codec::MimeHeader,
+        ]
+    }
+
+    /// 설정 저장과 CLI `--mode`에 쓰는 키
+    pub fn key(&self) -> &'static str {
+        match self {
+            Codec::Base64(Base64Mode::Standard) => "standard",
+            Codec::Base64(Base64Mode::UrlSafe) => "url-safe",
+            Codec::Base64(Base64Mode::NoPadding) => "no-padding",
+            Codec::Base32 => "base32",
+            Codec::Base32Hex => "base32hex",
+            Codec::Base58 => "base58",
+            Codec::Base58Check => "base58check",
+            Codec::Ascii85 => "ascii85",
+            Codec::Z85 => "z85",
+            Codec::Base45 => "base45",
+            Codec::QuotedPrintable => "quoted-printable",
+            Codec::Uuencode => "uuencode",
+            Codec::Yenc => "yenc",
+            Codec::MimeHeader => "mime-header",
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Codec::Base64(Base64Mode::Standard) => "Base64",
+            Codec::Base64(Base64Mode::UrlSafe) => "Base64 URL-Safe",
+            Codec::Base64(Base64Mode::NoPadding) => "Base64 No Padding",
+            Codec::Base32 => "Base32",
+            Codec::Base32Hex => "Base32hex",
+            Codec::Base58 => "Base58",
+            Codec::Base58Check => "Base58Check",
+            Codec::Ascii85 => "Ascii85",
+            Codec::Z85 => "Z85",
+            Codec::Base45 => "Base45",
+            Codec::QuotedPrintable => "Quoted-Printable",
+            Codec::Uuencode => "uuencode",
+            Codec::Yenc => "yEnc",
+            Codec::MimeHeader => "MIME Encoded-Word",
+        }
+    }
+
+    pub fn from_key(key: &str) -> Option<Codec> {
+        Self::all().into_iter().find(|codec| codec.key() == key)
+    }
+
+    pub fn is_base64(&self) -> bool {
+        matches!(self, Codec::Base64(_))
+    }
+
+    /// 인코딩 자체에 줄 구조가 있어 출력 포맷(줄바꿈, 청크)을 적용하지 않는 코덱
+    pub fn is_line_structured(&self) -> bool {
+        matches!(self, Codec::QuotedPrintable | Codec::Uuencode | Codec::Yenc | Codec::MimeHeader)
+    }
+
+    /// 이 바이트 수의 배수로 나눠 인코딩한 결과를 이어 붙이면 전체를 한 번에 인코딩한 것과 같습니다.
+    ///
+    /// Base58처럼 전체를 하나의 수로 다루는 코덱은 `None`입니다.
+    pub fn block_size(&self) -> Option<usize> {
+        match self {
+            Codec::Base64(_) => Some(3),
+            Codec::Base32 | Codec::Base32Hex => Some(5),
+            Codec::Ascii85 | Codec::Z85 => Some(4),
+            Codec::Base45 => Some(2),
+            Codec::Base58 | Codec::Base58Check => None,
+            Codec::QuotedPrintable | Codec::Uuencode | Codec::Yenc | Codec::MimeHeader => None,
+        }
+    }
+
+    /// 인코딩할 수 있는 입력 길이인지 확인합니다.
+    pub fn check_input_length(&self, length: usize) -> Result<(), String> {
+        match self {
+            Codec::Z85 if !length.is_multiple_of(4) => Err(format!(
+                "Z85 encodes 4-byte groups; the input is {} bytes, which is not a multiple of 4.",
+                length
+            )),
+            Codec::Base58 | Codec::Base58Check if length > BASE58_MAX_BYTES => Err(format!(
+                "{} is meant for short values such as keys and addresses; inputs over {} KB aren't supported.",
+                self.name(),
+                BASE58_MAX_BYTES / 1024
+            )),
+            _ => Ok(()),
+        }
+    }
+
+    pub fn encode(&self, data: &[u8]) -> Result<String, String> {
+        self.check_input_length(data.len())?;
+        Ok(match self {
+            Codec::Base64(mode) => encode_with_mode(data, mode),
+            Codec::Base32 => encode_base32(data, BASE32_ALPHABET),
+            Codec::Base32Hex => encode_base32(data, BASE32HEX_ALPHABET),
+            Codec::Base58 => encode_base58(data),
+            Codec::Base58Check => {
+                let mut payload = data.to_vec();
+                payload.extend_from_slice(&checksum(data));
+                encode_base58(&payload)
+            }
+            Codec::Ascii85 => encode_ascii85(data),
+            Codec::Z85 => data.chunks(4).map(|group| encode_base85_group(group, Z85_ALPHABET, 5)).collect(),
+            Codec::Base45 => encode_base45(data),
+            Codec::QuotedPrintable => mime::encode_quoted_printable(data),
+            Codec::Uuencode => mime::encode_uuencode(data, ENCODED_FILE_NAME),
+            Codec::Yenc => mime::encode_yenc(data, ENCODED_FILE_NAME),
+            Codec::MimeHeader => mime::encode_encoded_words(data),
+        })
+    }
+
+    /// 디코딩합니다. 공백과 줄바꿈은 무시합니다 (Base45는 공백이 알파벳에 있으므로 줄바꿈만 무시).
+    ///
+    /// 줄 구조가 있는 이메일 인코딩은 입력을 그대로 넘깁니다.
+    pub fn decode(&self, input: &str) -> Result<Vec<u8>, String> {
+        let cleaned: String = match self {
+            _ if self.is_line_structured() => input.to_string(),
+            Codec::Base45 => input.chars().filter(|c| !matches!(c, '\n' | '\r' | '\t')).collect(),
+            _ => input.chars().filter(|c| !c.is_whitespace()).collect(),
+        };
+        match self {
+            Codec::Base64(mode) => {
+                validate_base64_input(&cleaned, mode)?;
+                decode_with_mode(&cleaned, mode)
+            }
+            Codec::Base32 => decode_base32(&cleaned, BASE32_ALPHABET, self.name()),
+            Codec::Base32Hex => decode_base32(&cleaned, BASE32HEX_ALPHABET, self.name()),
+            Codec::Base58 => decode_base58(&cleaned),
+            Codec::Base58Check => {
+                let decoded = decode_base58(&cleaned)?;
+                if decoded.len() < 4 {
+                    return Err("Base58Check data must include a 4-byte checksum.".to_string());
+                }
+                let (payload, expected) = decoded.split_at(decoded.len() - 4);
+                if checksum(payload) != expected {
+                    return Err("Base58Check checksum mismatch: the value is mistyped or not Base58Check.".to_string());
+                }
+                Ok(payload.to_vec())
+            }
+            Codec::Ascii85 => decode_ascii85(&cleaned),
+            Codec::Z85 => decode_z85(&cleaned),
+            Codec::Base45 => decode_base45(&cleaned),
+            Codec::QuotedPrintable => mime::decode_quoted_printable(&cleaned),
+            Codec::Uuencode => mime::decode_uuencode(&cleaned),
+            Codec::Yenc => mime::decode_yenc(&cleaned),
+            Codec::MimeHeader => mime::decode_header(&cleaned),
+        }
+    }
+}
+
+fn invalid_character(codec: &str, character: char, position: usize) -> String {
+    format!("Invalid {} character '{}' at position {}.", codec, character, position + 1)
+}
+
+fn encode_base32(data: &[u8], alphabet: &[u8; 32]) -> String {
+    let mut output = String::with_capacity(data.len().div_ceil(5) * 8);
+    for group in data.chunks(5) {
+        let mut block = [0u8; 5];
+        block[..group.len()].copy_from_slice(group);
+        let bits = block.iter().fold(0u64, |bits, byte| (bits << 8) | u64::from(*byte));
+        // 5바이트 그룹의 n바이트는 ceil(n*8/5)자로 표현됨
+        let used = (group.len() * 8).div_ceil(5);
+        for index in 0..8 {
+            if index < used {
+                output.push(alphabet[((bits >> (35 - index * 5)) & 0x1f) as usize] as char);
+            } else {
+                output.push('=');
+            }
+        }
+    }
+    output
+}
+
+/// 대소문자를 구분하지 않고, 패딩은 있어도 없어도 됩니다.
+fn decode_base32(input: &str, alphabet: &[u8; 32], name: &str) -> Result<Vec<u8>, String> {
+    let data = input.trim_end_matches('=');
+    let mut output = Vec::with_capacity(data.len() * 5 / 8);
+    let mut bits: u64 = 0;
+    let mut bit_count = 0;
+
+    for (position, character) in data.chars().enumerate() {
+        let upper = character.to_ascii_uppercase();
+        let value = alphabet
+            .iter()
+            .position(|symbol| *symbol as char == upper)
+            .ok_or_else(|| invalid_character(name, character, position))?;
+        bits = (bits << 5) | value as u64;
+        bit_count += 5;
+        if bit_count >= 8 {
+            bit_count -= 8;
+            output.push((bits >> bit_count) as u8);
+            bits &= (1 << bit_count) - 1;
+        }
+    }
+
+    // 남는 비트는 0 패딩이어야 하고, 1·3·6자로 끝나는 그룹은 나올 수 없음
+    if matches!(data.len() % 8, 1 | 3 | 6) {
+        return Err(format!("Invalid {} length: {} characters (without padding).", name, data.len()));
+    }
+    Ok(output)
+}
+
+fn checksum(data: &[u8]) -> [u8; 4] {
+    let hash = Sha256::digest(Sha256::digest(data));
+    [hash[0], hash[1], hash[2], hash[3]]
+}
+
+fn encode_base58(data: &[u8]) -> String {
+    let zeros = data.iter().take_while(|byte| **byte == 0).count();
+    // 58진수 자릿수 (작은 자리부터)
+    let mut digits: Vec<u8> = Vec::with_capacity(data.len() * 138 / 100 + 1);
+    for byte in &data[zeros..] {
+        let mut carry = u32::from(*byte);
+        for digit in digits.iter_mut() {
+            carry += u32::from(*digit) << 8;
+            *digit = (carry % 58) as u8;
+            carry /= 58;
+        }
+        while carry > 0 {
+            digits.push((carry % 58) as u8);
+            carry /= 58;
+        }
+    }
+
+    let mut output = "1".repeat(zeros);
+    output.extend(digits.iter().rev().map(|digit| BASE58_ALPHABET[*digit as usize] as char));
+    output
+}
+
+fn decode_base58(input: &str) -> Result<Vec<u8>, String> {
+    if input.len() > BASE58_MAX_BYTES * 2 {
+        return Err(format!("Base58 input is too long (over {} characters).", BASE58_MAX_BYTES * 2));
+    }
+    let zeros = input.chars().take_while(|c| *c == '1').count();
+    // 256진수 바이트 (작은 자리부터)
+    let mut bytes: Vec<u8> = Vec::with_capacity(input.len());
+    for (position, character) in input.chars().enumerate().skip(zeros) {
+        let value = BASE58_ALPHABET
+            .iter()
+            .position(|symbol| *symbol as char == character)
+            .ok_or_else(|| invalid_character("Base58", character, position))?;
+        let mut carry = value as u32;
+        for byte in bytes.iter_mut() {
+            carry += u32::from(*byte) * 58;
+            *byte = carry as u8;
+            carry >>= 8;
+        }
+        while carry > 0 {
+            bytes.push(carry as u8);
+            carry >>= 8;
+        }
+    }
+
+    let mut output = vec![0u8; zeros];
+    output.extend(bytes.iter().rev());
+    Ok(output)
+}
+
+/// 4바이트 그룹(마지막은 더 짧을 수 있음)을 85진수 `length`자로 인코딩합니다.
+fn encode_base85_group(group: &[u8], alphabet: &[u8; 85], length: usize) -> String {
+    let mut block = [0u8; 4];
+    block[..group.len()].copy_from_slice(group);
+    let mut value = u32::from_be_bytes(block);
+    let mut digits = [0u8; 5];
+    for digit in digits.iter_mut().rev() {
+        *digit = alphabet[(value % 85) as usize];
+        value /= 85;
+    }
+    digits[..length].iter().map(|digit| *digit as char).collect()
+}
+
+fn encode_ascii85(data: &[u8]) -> String {
+    const ALPHABET: [u8; 85] = {
+        let mut alphabet = [0u8; 85];
+        let mut index = 0;
+        while index < 85 {
+            alphabet[index] = b'!' + index as u8;
+            index += 1;
+        }
+        alphabet
+    };
+
+    data.chunks(4)
+        .map(|group| {
+            if group == [0, 0, 0, 0] {
+                "z".to_string()
+            } else {
+                encode_base85_group(group, &ALPHABET, group.len() + 1)
+            }
+        })
+        .collect()
+}
+
+/// 5자 그룹을 4바이트로 디코딩합니다. 85진수 값이 32비트를 넘으면 에러입니다.
+fn decode_base85_group(values: &[u32; 5], codec: &str) -> Result<[u8; 4], String> {
+    let value = values.iter().try_fold(0u32, |value, digit| value.checked_mul(85)?.checked_add(*digit));
+    value
+        .map(u32::to_be_bytes)
+        .ok_or_else(|| format!("Invalid {} group: the value is larger than 32 bits.", codec))
+}
+
+/// `<~ ~>` 구분자와 `z`(4바이트 0) 약식 표기를 허용합니다.
+fn decode_ascii85(input: &str) -> Result<Vec<u8>, String> {
+    let data = input.strip_prefix("<~").unwrap_or(input);
+    let data = data.strip_suffix("~>").unwrap_or(data);
+
+    let mut output = Vec::with_capacity(data.len() * 4 / 5);
+    let mut group = [0u32; 5];
+    let mut count = 0;
+    for (position, character) in data.chars().enumerate() {
+        match character {
+            'z' if count == 0 => output.extend_from_slice(&[0, 0, 0, 0]),
+            '!'..='u' => {
+                group[count] = character as u32 - '!' as u32;
+                count += 1;
+                if count == 5 {
+                    output.extend_from_slice(&decode_base85_group(&group, "Ascii85")?);
+                    count = 0;
+                }
+            }
+            _ => return Err(invalid_character("Ascii85", character, position)),
+        }
+    }
+
+    if count == 1 {
+        return Err("Invalid Ascii85 data: a final group must have at least 2 characters.".to_string());
+    }
+    if count > 1 {
+        // 부족한 자리를 'u'(84)로 채우고 실제 길이만큼만 사용
+        for value in group.iter_mut().skip(count) {
+            *value = 84;
+        }
+        output.extend_from_slice(&decode_base85_group(&group, "Ascii85")?[..count - 1]);
+    }
+    Ok(output)
+}
+
+fn decode_z85(input: &str) -> Result<Vec<u8>, String> {
+    if !input.len().is_multiple_of(5) {
+        return Err(format!("Invalid Z85 length: {} characters. Z85 strings are multiples of 5 characters.", input.len()));
+    }
+    let mut output = Vec::with_capacity(input.len() / 5 * 4);
+    let mut group = [0u32; 5];
+    for (position, character) in input.chars().enumerate() {
+        group[position % 5] = Z85_ALPHABET
+            .iter()
+            .position(|symbol| *symbol as char == character)
+            .ok_or_else(|| invalid_character("Z85", character, position))? as u32;
+        if position % 5 == 4 {
+            output.extend_from_slice(&decode_base85_group(&group, "Z85")?);
+        }
+    }
+    Ok(output)
+}
+
+fn encode_base45(data: &[u8]) -> String {
+    let mut output = String::with_capacity(data.len().div_ceil(2) * 3);
+    for pair in data.chunks(2) {
+        let (mut value, length) = match pair {
+            [a, b] => (usize::from(*a) * 256 + usize::from(*b), 3),
+            [a] => (usize::from(*a), 2),
+            _ => unreachable!(),
+        };
+        for _ in 0..length {
+            output.push(BASE45_ALPHABET[value % 45] as char);
+            value /= 45;
+        }
+    }
+    output
+}
+
+fn decode_base45(input: &str) -> Result<Vec<u8>, String> {
+    let values = input
+        .chars()
+        .enumerate()
+        .map(|(position, character)| {
+            BASE45_ALPHABET
+                .iter()
+                .position(|symbol| *symbol as char == character)
+                .ok_or_else(|| invalid_character("Base45", character, position))
+        })
+        .collect::<Result<Vec<usize>, String>>()?;
+
+    if values.len() % 3 == 1 {
+        return Err(format!("Invalid Base45 length: {} characters.", values.len()));
+    }
+    let mut output = Vec::with_capacity(values.len() * 2 / 3);
+    for group in values.chunks(3) {
+        let value = group.iter().rev().fold(0usize, |value, digit| value * 45 + digit);
+        if group.len() == 3 {
+            if value > 0xffff {
+                return Err("Invalid Base45 data: a 3-character group is larger than 65535.".to_string());
+            }
+            output.extend_from_slice(&(value as u16).to_be_bytes());
+        } else {
+            if value > 0xff {
+                return Err("Invalid Base45 data: the final 2-character group is larger than 255.".to_string());
+            }
+            output.push(value as u8);
+        }
+    }
+    Ok(output)
+}
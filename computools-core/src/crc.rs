@@ -0,0 +1,1629 @@
+pub mod codegen;
+pub mod compare;
+pub mod forcing;
+pub mod reveng;
+pub mod verify;
+
+use crc::{
+    Algorithm, Crc, Digest, Width, CRC_10_ATM, CRC_10_CDMA2000, CRC_10_GSM, CRC_11_FLEXRAY, CRC_11_UMTS, CRC_12_CDMA2000,
+    CRC_12_DECT, CRC_12_GSM, CRC_12_UMTS, CRC_13_BBC, CRC_14_DARC, CRC_14_GSM, CRC_15_CAN,
+    CRC_15_MPT1327, CRC_16_ARC, CRC_16_CDMA2000, CRC_16_CMS, CRC_16_DDS_110, CRC_16_DECT_R,
+    CRC_16_DECT_X, CRC_16_DNP, CRC_16_EN_13757, CRC_16_GENIBUS, CRC_16_GSM, CRC_16_IBM_3740,
+    CRC_16_IBM_SDLC, CRC_16_ISO_IEC_14443_3_A, CRC_16_KERMIT, CRC_16_LJ1200, CRC_16_M17,
+    CRC_16_MAXIM_DOW, CRC_16_MCRF4XX, CRC_16_MODBUS, CRC_16_NRSC_5, CRC_16_OPENSAFETY_A,
+    CRC_16_OPENSAFETY_B, CRC_16_PROFIBUS, CRC_16_RIELLO, CRC_16_SPI_FUJITSU, CRC_16_T10_DIF,
+    CRC_16_TELEDISK, CRC_16_TMS37157, CRC_16_UMTS, CRC_16_USB, CRC_16_XMODEM, CRC_17_CAN_FD,
+    CRC_21_CAN_FD, CRC_24_BLE, CRC_24_FLEXRAY_A, CRC_24_FLEXRAY_B, CRC_24_INTERLAKEN, CRC_24_LTE_A,
+    CRC_24_LTE_B, CRC_24_OPENPGP, CRC_24_OS_9, CRC_30_CDMA, CRC_31_PHILIPS, CRC_32_AIXM,
+    CRC_32_AUTOSAR, CRC_32_BASE91_D, CRC_32_BZIP2, CRC_32_CD_ROM_EDC, CRC_32_CKSUM, CRC_32_ISCSI,
+    CRC_32_ISO_HDLC, CRC_32_JAMCRC, CRC_32_MEF, CRC_32_MPEG_2, CRC_32_XFER, CRC_3_GSM, CRC_3_ROHC,
+    CRC_40_GSM, CRC_4_G_704, CRC_4_INTERLAKEN, CRC_5_EPC_C1G2, CRC_5_G_704, CRC_5_USB,
+    CRC_64_ECMA_182, CRC_64_GO_ISO, CRC_64_MS, CRC_64_REDIS, CRC_64_WE, CRC_64_XZ,
+    CRC_6_CDMA2000_A, CRC_6_CDMA2000_B, CRC_6_DARC, CRC_6_GSM, CRC_6_G_704, CRC_7_MMC, CRC_7_ROHC,
+    CRC_7_UMTS, CRC_8_AUTOSAR, CRC_8_BLUETOOTH, CRC_8_CDMA2000, CRC_8_DARC, CRC_8_DVB_S2,
+    CRC_8_GSM_A, CRC_8_GSM_B, CRC_8_HITAG, CRC_8_I_432_1, CRC_8_I_CODE, CRC_8_LTE, CRC_8_MAXIM_DOW,
+    CRC_8_MIFARE_MAD, CRC_8_NRSC_5, CRC_8_OPENSAFETY, CRC_8_ROHC, CRC_8_SAE_J1850, CRC_8_SMBUS,
+    CRC_8_TECH_3250, CRC_8_WCDMA,
+};
+
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum InputMode {
+    Ascii,
+    Hex,
+    Binary,
+    Decimal,
+    Octal,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum OutputMode {
+    Decimal,
+    Hex,
+    Binary,
+    Octal,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum HexStyle {
+    WithPrefix,     // 0x48
+    ShortPrefix,    // x48
+    NoPrefix,       // 48
+    EscapeSequence, // \x48
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum BinaryStyle {
+    WithPrefix,    // 0b01001000
+    ShortPrefix,   // b01001000
+    NoPrefix,      // 01001000
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum OctalStyle {
+    WithPrefix,     // 0o110
+    ShortPrefix,    // o110
+    NoPrefix,       // 110
+    EscapeSequence, // \110
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Endianness {
+    BigEndian,    // 네트워크 바이트 순서 (MSB first)
+    LittleEndian, // 인텔 x86 바이트 순서 (LSB first)
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ByteFormatting {
+    Continuous,   // 0x12345678
+    ByteSeparated, // 0x12 0x34 0x56 0x78
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum CrcAlgorithm {
+    Crc3Gsm,
+    Crc3Rohc,
+    Crc4G704,
+    Crc4Interlaken,
+    Crc5EpcC1g2,
+    Crc5G704,
+    Crc5Usb,
+    Crc6Cdma2000A,
+    Crc6Cdma2000B,
+    Crc6Darc,
+    Crc6Gsm,
+    Crc6G704,
+    Crc7Mmc,
+    Crc7Rohc,
+    Crc7Umts,
+    Crc8Autosar,
+    Crc8Bluetooth,
+    Crc8Cdma2000,
+    Crc8Darc,
+    Crc8DvbS2,
+    Crc8GsmA,
+    Crc8GsmB,
+    Crc8Hitag,
+    Crc8I4321,
+    Crc8ICode,
+    Crc8Lte,
+    Crc8MaximDow,
+    Crc8MifareMad,
+    Crc8Nrsc5,
+    Crc8Opensafety,
+    Crc8Rohc,
+    Crc8SaeJ1850,
+    Crc8Smbus,
+    Crc8Tech3250,
+    Crc8Wcdma,
+    Crc10Atm,
+    Crc10Cdma2000,
+    Crc10Gsm,
+    Crc11Flexray,
+    Crc11Umts,
+    Crc12Cdma2000,
+    Crc12Dect,
+    Crc12Gsm,
+    Crc12Umts,
+    Crc13Bbc,
+    Crc14Darc,
+    Crc14Gsm,
+    Crc15Can,
+    Crc15Mpt1327,
+    Crc16Arc,
+    Crc16Cdma2000,
+    Crc16Cms,
+    Crc16Dds110,
+    Crc16DectR,
+    Crc16DectX,
+    Crc16Dnp,
+    Crc16En13757,
+    Crc16Genibus,
+    Crc16Gsm,
+    Crc16Ibm3740,
+    Crc16IbmSdlc,
+    Crc16IsoIec144433A,
+    Crc16Kermit,
+    Crc16Lj1200,
+    Crc16M17,
+    Crc16MaximDow,
+    Crc16Mcrf4xx,
+    Crc16Modbus,
+    Crc16Nrsc5,
+    Crc16OpensafetyA,
+    Crc16OpensafetyB,
+    Crc16Profibus,
+    Crc16Riello,
+    Crc16SpiFujitsu,
+    Crc16T10Dif,
+    Crc16Teledisk,
+    Crc16Tms37157,
+    Crc16Umts,
+    Crc16Usb,
+    Crc16Xmodem,
+    Crc17CanFd,
+    Crc21CanFd,
+    Crc24Ble,
+    Crc24FlexrayA,
+    Crc24FlexrayB,
+    Crc24Interlaken,
+    Crc24LteA,
+    Crc24LteB,
+    Crc24Openpgp,
+    Crc24Os9,
+    Crc30Cdma,
+    Crc31Philips,
+    Crc32Aixm,
+    Crc32Autosar,
+    Crc32Base91D,
+    Crc32Bzip2,
+    Crc32CdRomEdc,
+    Crc32Cksum,
+    Crc32Iscsi,
+    Crc32IsoHdlc,
+    Crc32Jamcrc,
+    Crc32Mef,
+    Crc32Mpeg2,
+    Crc32Xfer,
+    Crc40Gsm,
+    Crc64Ecma182,
+    Crc64GoIso,
+    Crc64Ms,
+    Crc64Redis,
+    Crc64We,
+    Crc64Xz,
+    Custom(CrcParams), // 사용자 정의 Rocksoft 모델 파라미터
+}
+
+impl CrcAlgorithm {
+    pub fn name(&self) -> &str {
+        match self {
+            CrcAlgorithm::Crc3Gsm => "CRC-3/GSM",
+            CrcAlgorithm::Crc3Rohc => "CRC-3/ROHC",
+            CrcAlgorithm::Crc4G704 => "CRC-4/G-704",
+            CrcAlgorithm::Crc4Interlaken => "CRC-4/INTERLAKEN",
+            CrcAlgorithm::Crc5EpcC1g2 => "CRC-5/EPC-C1G2",
+            CrcAlgorithm::Crc5G704 => "CRC-5/G-704",
+            CrcAlgorithm::Crc5Usb => "CRC-5/USB",
+            CrcAlgorithm::Crc6Cdma2000A => "CRC-6/CDMA2000-A",
+            CrcAlgorithm::Crc6Cdma2000B => "CRC-6/CDMA2000-B",
+            CrcAlgorithm::Crc6Darc => "CRC-6/DARC",
+            CrcAlgorithm::Crc6Gsm => "CRC-6/GSM",
+            CrcAlgorithm::Crc6G704 => "CRC-6/G-704",
+            CrcAlgorithm::Crc7Mmc => "CRC-7/MMC",
+            CrcAlgorithm::Crc7Rohc => "CRC-7/ROHC",
+            CrcAlgorithm::Crc7Umts => "CRC-7/UMTS",
+            CrcAlgorithm::Crc8Autosar => "CRC-8/AUTOSAR",
+            CrcAlgorithm::Crc8Bluetooth => "CRC-8/BLUETOOTH",
+            CrcAlgorithm::Crc8Cdma2000 => "CRC-8/CDMA2000",
+            CrcAlgorithm::Crc8Darc => "CRC-8/DARC",
+            CrcAlgorithm::Crc8DvbS2 => "CRC-8/DVB-S2",
+            CrcAlgorithm::Crc8GsmA => "CRC-8/GSM-A",
+            CrcAlgorithm::Crc8GsmB => "CRC-8/GSM-B",
+            CrcAlgorithm::Crc8Hitag => "CRC-8/HITAG",
+            CrcAlgorithm::Crc8I4321 => "CRC-8/I-432-1",
+            CrcAlgorithm::Crc8ICode => "CRC-8/I-CODE",
+            CrcAlgorithm::Crc8Lte => "CRC-8/LTE",
+            CrcAlgorithm::Crc8MaximDow => "CRC-8/MAXIM-DOW",
+            CrcAlgorithm::Crc8MifareMad => "CRC-8/MIFARE-MAD",
+            CrcAlgorithm::Crc8Nrsc5 => "CRC-8/NRSC-5",
+            CrcAlgorithm::Crc8Opensafety => "CRC-8/OPENSAFETY",
+            CrcAlgorithm::Crc8Rohc => "CRC-8/ROHC",
+            CrcAlgorithm::Crc8SaeJ1850 => "CRC-8/SAE-J1850",
+            CrcAlgorithm::Crc8Smbus => "CRC-8/SMBUS",
+            CrcAlgorithm::Crc8Tech3250 => "CRC-8/TECH-3250",
+            CrcAlgorithm::Crc8Wcdma => "CRC-8/WCDMA",
+            CrcAlgorithm::Crc10Atm => "CRC-10/ATM",
+            CrcAlgorithm::Crc10Cdma2000 => "CRC-10/CDMA2000",
+            CrcAlgorithm::Crc10Gsm => "CRC-10/GSM",
+            CrcAlgorithm::Crc11Flexray => "CRC-11/FLEXRAY",
+            CrcAlgorithm::Crc11Umts => "CRC-11/UMTS",
+            CrcAlgorithm::Crc12Cdma2000 => "CRC-12/CDMA2000",
+            CrcAlgorithm::Crc12Dect => "CRC-12/DECT",
+            CrcAlgorithm::Crc12Gsm => "CRC-12/GSM",
+            CrcAlgorithm::Crc12Umts => "CRC-12/UMTS",
+            CrcAlgorithm::Crc13Bbc => "CRC-13/BBC",
+            CrcAlgorithm::Crc14Darc => "CRC-14/DARC",
+            CrcAlgorithm::Crc14Gsm => "CRC-14/GSM",
+            CrcAlgorithm::Crc15Can => "CRC-15/CAN",
+            CrcAlgorithm::Crc15Mpt1327 => "CRC-15/MPT1327",
+            CrcAlgorithm::Crc16Arc => "CRC-16/ARC",
+            CrcAlgorithm::Crc16Cdma2000 => "CRC-16/CDMA2000",
+            CrcAlgorithm::Crc16Cms => "CRC-16/CMS",
+            CrcAlgorithm::Crc16Dds110 => "CRC-16/DDS-110",
+            CrcAlgorithm::Crc16DectR => "CRC-16/DECT-R",
+            CrcAlgorithm::Crc16DectX => "CRC-16/DECT-X",
+            CrcAlgorithm::Crc16Dnp => "CRC-16/DNP",
+            CrcAlgorithm::Crc16En13757 => "CRC-16/EN-13757",
+            CrcAlgorithm::Crc16Genibus => "CRC-16/GENIBUS",
+            CrcAlgorithm::Crc16Gsm => "CRC-16/GSM",
+            CrcAlgorithm::Crc16Ibm3740 => "CRC-16/IBM-3740",
+            CrcAlgorithm::Crc16IbmSdlc => "CRC-16/IBM-SDLC",
+            CrcAlgorithm::Crc16IsoIec144433A => "CRC-16/ISO-IEC-14443-3-A",
+            CrcAlgorithm::Crc16Kermit => "CRC-16/KERMIT",
+            CrcAlgorithm::Crc16Lj1200 => "CRC-16/LJ1200",
+            CrcAlgorithm::Crc16M17 => "CRC-16/M17",
+            CrcAlgorithm::Crc16MaximDow => "CRC-16/MAXIM-DOW",
+            CrcAlgorithm::Crc16Mcrf4xx => "CRC-16/MCRF4XX",
+            CrcAlgorithm::Crc16Modbus => "CRC-16/MODBUS",
+            CrcAlgorithm::Crc16Nrsc5 => "CRC-16/NRSC-5",
+            CrcAlgorithm::Crc16OpensafetyA => "CRC-16/OPENSAFETY-A",
+            CrcAlgorithm::Crc16OpensafetyB => "CRC-16/OPENSAFETY-B",
+            CrcAlgorithm::Crc16Profibus => "CRC-16/PROFIBUS",
+            CrcAlgorithm::Crc16Riello => "CRC-16/RIELLO",
+            CrcAlgorithm::Crc16SpiFujitsu => "CRC-16/SPI-FUJITSU",
+            CrcAlgorithm::Crc16T10Dif => "CRC-16/T10-DIF",
+            CrcAlgorithm::Crc16Teledisk => "CRC-16/TELEDISK",
+            CrcAlgorithm::Crc16Tms37157 => "CRC-16/TMS37157",
+            CrcAlgorithm::Crc16Umts => "CRC-16/UMTS",
+            CrcAlgorithm::Crc16Usb => "CRC-16/USB",
+            CrcAlgorithm::Crc16Xmodem => "CRC-16/XMODEM",
+            CrcAlgorithm::Crc17CanFd => "CRC-17/CAN-FD",
+            CrcAlgorithm::Crc21CanFd => "CRC-21/CAN-FD",
+            CrcAlgorithm::Crc24Ble => "CRC-24/BLE",
+            CrcAlgorithm::Crc24FlexrayA => "CRC-24/FLEXRAY-A",
+            CrcAlgorithm::Crc24FlexrayB => "CRC-24/FLEXRAY-B",
+            CrcAlgorithm::Crc24Interlaken => "CRC-24/INTERLAKEN",
+            CrcAlgorithm::Crc24LteA => "CRC-24/LTE-A",
+            CrcAlgorithm::Crc24LteB => "CRC-24/LTE-B",
+            CrcAlgorithm::Crc24Openpgp => "CRC-24/OPENPGP",
+            CrcAlgorithm::Crc24Os9 => "CRC-24/OS-9",
+            CrcAlgorithm::Crc30Cdma => "CRC-30/CDMA",
+            CrcAlgorithm::Crc31Philips => "CRC-31/PHILIPS",
+            CrcAlgorithm::Crc32Aixm => "CRC-32/AIXM",
+            CrcAlgorithm::Crc32Autosar => "CRC-32/AUTOSAR",
+            CrcAlgorithm::Crc32Base91D => "CRC-32/BASE91-D",
+            CrcAlgorithm::Crc32Bzip2 => "CRC-32/BZIP2",
+            CrcAlgorithm::Crc32CdRomEdc => "CRC-32/CD-ROM-EDC",
+            CrcAlgorithm::Crc32Cksum => "CRC-32/CKSUM",
+            CrcAlgorithm::Crc32Iscsi => "CRC-32/ISCSI",
+            CrcAlgorithm::Crc32IsoHdlc => "CRC-32/ISO-HDLC",
+            CrcAlgorithm::Crc32Jamcrc => "CRC-32/JAMCRC",
+            CrcAlgorithm::Crc32Mef => "CRC-32/MEF",
+            CrcAlgorithm::Crc32Mpeg2 => "CRC-32/MPEG-2",
+            CrcAlgorithm::Crc32Xfer => "CRC-32/XFER",
+            CrcAlgorithm::Crc40Gsm => "CRC-40/GSM",
+            CrcAlgorithm::Crc64Ecma182 => "CRC-64/ECMA-182",
+            CrcAlgorithm::Crc64GoIso => "CRC-64/GO-ISO",
+            CrcAlgorithm::Crc64Ms => "CRC-64/MS",
+            CrcAlgorithm::Crc64Redis => "CRC-64/REDIS",
+            CrcAlgorithm::Crc64We => "CRC-64/WE",
+            CrcAlgorithm::Crc64Xz => "CRC-64/XZ",
+            CrcAlgorithm::Custom(_) => "Custom",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<CrcAlgorithm> {
+        match name {
+            "CRC-3/GSM" => Some(CrcAlgorithm::Crc3Gsm),
+            "CRC-3/ROHC" => Some(CrcAlgorithm::Crc3Rohc),
+            "CRC-4/G-704" => Some(CrcAlgorithm::Crc4G704),
+            "CRC-4/INTERLAKEN" => Some(CrcAlgorithm::Crc4Interlaken),
+            "CRC-5/EPC-C1G2" => Some(CrcAlgorithm::Crc5EpcC1g2),
+            "CRC-5/G-704" => Some(CrcAlgorithm::Crc5G704),
+            "CRC-5/USB" => Some(CrcAlgorithm::Crc5Usb),
+            "CRC-6/CDMA2000-A" => Some(CrcAlgorithm::Crc6Cdma2000A),
+            "CRC-6/CDMA2000-B" => Some(CrcAlgorithm::Crc6Cdma2000B),
+            "CRC-6/DARC" => Some(CrcAlgorithm::Crc6Darc),
+            "CRC-6/GSM" => Some(CrcAlgorithm::Crc6Gsm),
+            "CRC-6/G-704" => Some(CrcAlgorithm::Crc6G704),
+            "CRC-7/MMC" => Some(CrcAlgorithm::Crc7Mmc),
+            "CRC-7/ROHC" => Some(CrcAlgorithm::Crc7Rohc),
+            "CRC-7/UMTS" => Some(CrcAlgorithm::Crc7Umts),
+            "CRC-8/AUTOSAR" => Some(CrcAlgorithm::Crc8Autosar),
+            "CRC-8/BLUETOOTH" => Some(CrcAlgorithm::Crc8Bluetooth),
+            "CRC-8/CDMA2000" => Some(CrcAlgorithm::Crc8Cdma2000),
+            "CRC-8/DARC" => Some(CrcAlgorithm::Crc8Darc),
+            "CRC-8/DVB-S2" => Some(CrcAlgorithm::Crc8DvbS2),
+            "CRC-8/GSM-A" => Some(CrcAlgorithm::Crc8GsmA),
+            "CRC-8/GSM-B" => Some(CrcAlgorithm::Crc8GsmB),
+            "CRC-8/HITAG" => Some(CrcAlgorithm::Crc8Hitag),
+            "CRC-8/I-432-1" => Some(CrcAlgorithm::Crc8I4321),
+            "CRC-8/I-CODE" => Some(CrcAlgorithm::Crc8ICode),
+            "CRC-8/LTE" => Some(CrcAlgorithm::Crc8Lte),
+            "CRC-8/MAXIM-DOW" => Some(CrcAlgorithm::Crc8MaximDow),
+            "CRC-8/MIFARE-MAD" => Some(CrcAlgorithm::Crc8MifareMad),
+            "CRC-8/NRSC-5" => Some(CrcAlgorithm::Crc8Nrsc5),
+            "CRC-8/OPENSAFETY" => Some(CrcAlgorithm::Crc8Opensafety),
+            "CRC-8/ROHC" => Some(CrcAlgorithm::Crc8Rohc),
+            "CRC-8/SAE-J1850" => Some(CrcAlgorithm::Crc8SaeJ1850),
+            "CRC-8/SMBUS" => Some(CrcAlgorithm::Crc8Smbus),
+            "CRC-8/TECH-3250" => Some(CrcAlgorithm::Crc8Tech3250),
+            "CRC-8/WCDMA" => Some(CrcAlgorithm::Crc8Wcdma),
+            "CRC-10/ATM" => Some(CrcAlgorithm::Crc10Atm),
+            "CRC-10/CDMA2000" => Some(CrcAlgorithm::Crc10Cdma2000),
+            "CRC-10/GSM" => Some(CrcAlgorithm::Crc10Gsm),
+            "CRC-11/FLEXRAY" => Some(CrcAlgorithm::Crc11Flexray),
+            "CRC-11/UMTS" => Some(CrcAlgorithm::Crc11Umts),
+            "CRC-12/CDMA2000" => Some(CrcAlgorithm::Crc12Cdma2000),
+            "CRC-12/DECT" => Some(CrcAlgorithm::Crc12Dect),
+            "CRC-12/GSM" => Some(CrcAlgorithm::Crc12Gsm),
+            "CRC-12/UMTS" => Some(CrcAlgorithm::Crc12Umts),
+            "CRC-13/BBC" => Some(CrcAlgorithm::Crc13Bbc),
+            "CRC-14/DARC" => Some(CrcAlgorithm::Crc14Darc),
+            "CRC-14/GSM" => Some(CrcAlgorithm::Crc14Gsm),
+            "CRC-15/CAN" => Some(CrcAlgorithm::Crc15Can),
+            "CRC-15/MPT1327" => Some(CrcAlgorithm::Crc15Mpt1327),
+            "CRC-16/ARC" => Some(CrcAlgorithm::Crc16Arc),
+            "CRC-16/CDMA2000" => Some(CrcAlgorithm::Crc16Cdma2000),
+            "CRC-16/CMS" => Some(CrcAlgorithm::Crc16Cms),
+            "CRC-16/DDS-110" => Some(CrcAlgorithm::Crc16Dds110),
+            "CRC-16/DECT-R" => Some(CrcAlgorithm::Crc16DectR),
+            "CRC-16/DECT-X" => Some(CrcAlgorithm::Crc16DectX),
+            "CRC-16/DNP" => Some(CrcAlgorithm::Crc16Dnp),
+            "CRC-16/EN-13757" => Some(CrcAlgorithm::Crc16En13757),
+            "CRC-16/GENIBUS" => Some(CrcAlgorithm::Crc16Genibus),
+            "CRC-16/GSM" => Some(CrcAlgorithm::Crc16Gsm),
+            "CRC-16/IBM-3740" => Some(CrcAlgorithm::Crc16Ibm3740),
+            "CRC-16/IBM-SDLC" => Some(CrcAlgorithm::Crc16IbmSdlc),
+            "CRC-16/ISO-IEC-14443-3-A" => Some(CrcAlgorithm::Crc16IsoIec144433A),
+            "CRC-16/KERMIT" => Some(CrcAlgorithm::Crc16Kermit),
+            "CRC-16/LJ1200" => Some(CrcAlgorithm::Crc16Lj1200),
+            "CRC-16/M17" => Some(CrcAlgorithm::Crc16M17),
+            "CRC-16/MAXIM-DOW" => Some(CrcAlgorithm::Crc16MaximDow),
+            "CRC-16/MCRF4XX" => Some(CrcAlgorithm::Crc16Mcrf4xx),
+            "CRC-16/MODBUS" => Some(CrcAlgorithm::Crc16Modbus),
+            "CRC-16/NRSC-5" => Some(CrcAlgorithm::Crc16Nrsc5),
+            "CRC-16/OPENSAFETY-A" => Some(CrcAlgorithm::Crc16OpensafetyA),
+            "CRC-16/OPENSAFETY-B" => Some(CrcAlgorithm::Crc16OpensafetyB),
+            "CRC-16/PROFIBUS" => Some(CrcAlgorithm::Crc16Profibus),
+            "CRC-16/RIELLO" => Some(CrcAlgorithm::Crc16Riello),
+            "CRC-16/SPI-FUJITSU" => Some(CrcAlgorithm::Crc16SpiFujitsu),
+            "CRC-16/T10-DIF" => Some(CrcAlgorithm::Crc16T10Dif),
+            "CRC-16/TELEDISK" => Some(CrcAlgorithm::Crc16Teledisk),
+            "CRC-16/TMS37157" => Some(CrcAlgorithm::Crc16Tms37157),
+            "CRC-16/UMTS" => Some(CrcAlgorithm::Crc16Umts),
+            "CRC-16/USB" => Some(CrcAlgorithm::Crc16Usb),
+            "CRC-16/XMODEM" => Some(CrcAlgorithm::Crc16Xmodem),
+            "CRC-17/CAN-FD" => Some(CrcAlgorithm::Crc17CanFd),
+            "CRC-21/CAN-FD" => Some(CrcAlgorithm::Crc21CanFd),
+            "CRC-24/BLE" => Some(CrcAlgorithm::Crc24Ble),
+            "CRC-24/FLEXRAY-A" => Some(CrcAlgorithm::Crc24FlexrayA),
+            "CRC-24/FLEXRAY-B" => Some(CrcAlgorithm::Crc24FlexrayB),
+            "CRC-24/INTERLAKEN" => Some(CrcAlgorithm::Crc24Interlaken),
+            "CRC-24/LTE-A" => Some(CrcAlgorithm::Crc24LteA),
+            "CRC-24/LTE-B" => Some(CrcAlgorithm::Crc24LteB),
+            "CRC-24/OPENPGP" => Some(CrcAlgorithm::Crc24Openpgp),
+            "CRC-24/OS-9" => Some(CrcAlgorithm::Crc24Os9),
+            "CRC-30/CDMA" => Some(CrcAlgorithm::Crc30Cdma),
+            "CRC-31/PHILIPS" => Some(CrcAlgorithm::Crc31Philips),
+            "CRC-32/AIXM" => Some(CrcAlgorithm::Crc32Aixm),
+            "CRC-32/AUTOSAR" => Some(CrcAlgorithm::Crc32Autosar),
+            "CRC-32/BASE91-D" => Some(CrcAlgorithm::Crc32Base91D),
+            "CRC-32/BZIP2" => Some(CrcAlgorithm::Crc32Bzip2),
+            "CRC-32/CD-ROM-EDC" => Some(CrcAlgorithm::Crc32CdRomEdc),
+            "CRC-32/CKSUM" => Some(CrcAlgorithm::Crc32Cksum),
+            "CRC-32/ISCSI" => Some(CrcAlgorithm::Crc32Iscsi),
+            "CRC-32/ISO-HDLC" => Some(CrcAlgorithm::Crc32IsoHdlc),
+            "CRC-32/JAMCRC" => Some(CrcAlgorithm::Crc32Jamcrc),
+            "CRC-32/MEF" => Some(CrcAlgorithm::Crc32Mef),
+            "CRC-32/MPEG-2" => Some(CrcAlgorithm::Crc32Mpeg2),
+            "CRC-32/XFER" => Some(CrcAlgorithm::Crc32Xfer),
+            "CRC-40/GSM" => Some(CrcAlgorithm::Crc40Gsm),
+            "CRC-64/ECMA-182" => Some(CrcAlgorithm::Crc64Ecma182),
+            "CRC-64/GO-ISO" => Some(CrcAlgorithm::Crc64GoIso),
+            "CRC-64/MS" => Some(CrcAlgorithm::Crc64Ms),
+            "CRC-64/REDIS" => Some(CrcAlgorithm::Crc64Redis),
+            "CRC-64/WE" => Some(CrcAlgorithm::Crc64We),
+            "CRC-64/XZ" => Some(CrcAlgorithm::Crc64Xz),
+            "Custom" => Some(CrcAlgorithm::Custom(CrcParams::default())),
+            _ => None, // name이 매칭되지 않으면 None 반환
+        }
+    }
+
+    pub fn calculate(&self, data: &[u8]) -> (u64, u8) {
+        let engine = self.engine();
+        let mut digest = engine.digest();
+        digest.update(data);
+        (digest.finalize(), engine.width())
+    }
+
+    /// 조각 단위 계산에 쓸 CRC 엔진을 만듭니다. 카탈로그 알고리즘은 `crc` 크레이트의 테이블을 사용합니다.
+    pub fn engine(&self) -> CrcEngine {
+        match self {
+            CrcAlgorithm::Crc3Gsm => CrcEngine::U8(Box::new(Crc::<u8>::new(&CRC_3_GSM))),
+            CrcAlgorithm::Crc3Rohc => CrcEngine::U8(Box::new(Crc::<u8>::new(&CRC_3_ROHC))),
+            CrcAlgorithm::Crc4G704 => CrcEngine::U8(Box::new(Crc::<u8>::new(&CRC_4_G_704))),
+            CrcAlgorithm::Crc4Interlaken => CrcEngine::U8(Box::new(Crc::<u8>::new(&CRC_4_INTERLAKEN))),
+            CrcAlgorithm::Crc5EpcC1g2 => CrcEngine::U8(Box::new(Crc::<u8>::new(&CRC_5_EPC_C1G2))),
+            CrcAlgorithm::Crc5G704 => CrcEngine::U8(Box::new(Crc::<u8>::new(&CRC_5_G_704))),
+            CrcAlgorithm::Crc5Usb => CrcEngine::U8(Box::new(Crc::<u8>::new(&CRC_5_USB))),
+            CrcAlgorithm::Crc6Cdma2000A => CrcEngine::U8(Box::new(Crc::<u8>::new(&CRC_6_CDMA2000_A))),
+            CrcAlgorithm::Crc6Cdma2000B => CrcEngine::U8(Box::new(Crc::<u8>::new(&CRC_6_CDMA2000_B))),
+            CrcAlgorithm::Crc6Darc => CrcEngine::U8(Box::new(Crc::<u8>::new(&CRC_6_DARC))),
+            CrcAlgorithm::Crc6Gsm => CrcEngine::U8(Box::new(Crc::<u8>::new(&CRC_6_GSM))),
+            CrcAlgorithm::Crc6G704 => CrcEngine::U8(Box::new(Crc::<u8>::new(&CRC_6_G_704))),
+            CrcAlgorithm::Crc7Mmc => CrcEngine::U8(Box::new(Crc::<u8>::new(&CRC_7_MMC))),
+            CrcAlgorithm::Crc7Rohc => CrcEngine::U8(Box::new(Crc::<u8>::new(&CRC_7_ROHC))),
+            CrcAlgorithm::Crc7Umts => CrcEngine::U8(Box::new(Crc::<u8>::new(&CRC_7_UMTS))),
+            CrcAlgorithm::Crc8Autosar => CrcEngine::U8(Box::new(Crc::<u8>::new(&CRC_8_AUTOSAR))),
+            CrcAlgorithm::Crc8Bluetooth => CrcEngine::U8(Box::new(Crc::<u8>::new(&CRC_8_BLUETOOTH))),
+            CrcAlgorithm::Crc8Cdma2000 => CrcEngine::U8(Box::new(Crc::<u8>::new(&CRC_8_CDMA2000))),
+            CrcAlgorithm::Crc8Darc => CrcEngine::U8(Box::new(Crc::<u8>::new(&CRC_8_DARC))),
+            CrcAlgorithm::Crc8DvbS2 => CrcEngine::U8(Box::new(Crc::<u8>::new(&CRC_8_DVB_S2))),
+            CrcAlgorithm::Crc8GsmA => CrcEngine::U8(Box::new(Crc::<u8>::new(&CRC_8_GSM_A))),
+            CrcAlgorithm::Crc8GsmB => CrcEngine::U8(Box::new(Crc::<u8>::new(&CRC_8_GSM_B))),
+            CrcAlgorithm::Crc8Hitag => CrcEngine::U8(Box::new(Crc::<u8>::new(&CRC_8_HITAG))),
+            CrcAlgorithm::Crc8I4321 => CrcEngine::U8(Box::new(Crc::<u8>::new(&CRC_8_I_432_1))),
+            CrcAlgorithm::Crc8ICode => CrcEngine::U8(Box::new(Crc::<u8>::new(&CRC_8_I_CODE))),
+            CrcAlgorithm::Crc8Lte => CrcEngine::U8(Box::new(Crc::<u8>::new(&CRC_8_LTE))),
+            CrcAlgorithm::Crc8MaximDow => CrcEngine::U8(Box::new(Crc::<u8>::new(&CRC_8_MAXIM_DOW))),
+            CrcAlgorithm::Crc8MifareMad => CrcEngine::U8(Box::new(Crc::<u8>::new(&CRC_8_MIFARE_MAD))),
+            CrcAlgorithm::Crc8Nrsc5 => CrcEngine::U8(Box::new(Crc::<u8>::new(&CRC_8_NRSC_5))),
+            CrcAlgorithm::Crc8Opensafety => CrcEngine::U8(Box::new(Crc::<u8>::new(&CRC_8_OPENSAFETY))),
+            CrcAlgorithm::Crc8Rohc => CrcEngine::U8(Box::new(Crc::<u8>::new(&CRC_8_ROHC))),
+            CrcAlgorithm::Crc8SaeJ1850 => CrcEngine::U8(Box::new(Crc::<u8>::new(&CRC_8_SAE_J1850))),
+            CrcAlgorithm::Crc8Smbus => CrcEngine::U8(Box::new(Crc::<u8>::new(&CRC_8_SMBUS))),
+            CrcAlgorithm::Crc8Tech3250 => CrcEngine::U8(Box::new(Crc::<u8>::new(&CRC_8_TECH_3250))),
+            CrcAlgorithm::Crc8Wcdma => CrcEngine::U8(Box::new(Crc::<u8>::new(&CRC_8_WCDMA))),
+            CrcAlgorithm::Crc10Atm => CrcEngine::U16(Box::new(Crc::<u16>::new(&CRC_10_ATM))),
+            CrcAlgorithm::Crc10Cdma2000 => CrcEngine::U16(Box::new(Crc::<u16>::new(&CRC_10_CDMA2000))),
+            CrcAlgorithm::Crc10Gsm => CrcEngine::U16(Box::new(Crc::<u16>::new(&CRC_10_GSM))),
+            CrcAlgorithm::Crc11Flexray => CrcEngine::U16(Box::new(Crc::<u16>::new(&CRC_11_FLEXRAY))),
+            CrcAlgorithm::Crc11Umts => CrcEngine::U16(Box::new(Crc::<u16>::new(&CRC_11_UMTS))),
+            CrcAlgorithm::Crc12Cdma2000 => CrcEngine::U16(Box::new(Crc::<u16>::new(&CRC_12_CDMA2000))),
+            CrcAlgorithm::Crc12Dect => CrcEngine::U16(Box::new(Crc::<u16>::new(&CRC_12_DECT))),
+            CrcAlgorithm::Crc12Gsm => CrcEngine::U16(Box::new(Crc::<u16>::new(&CRC_12_GSM))),
+            CrcAlgorithm::Crc12Umts => CrcEngine::U16(Box::new(Crc::<u16>::new(&CRC_12_UMTS))),
+            CrcAlgorithm::Crc13Bbc => CrcEngine::U16(Box::new(Crc::<u16>::new(&CRC_13_BBC))),
+            CrcAlgorithm::Crc14Darc => CrcEngine::U16(Box::new(Crc::<u16>::new(&CRC_14_DARC))),
+            CrcAlgorithm::Crc14Gsm => CrcEngine::U16(Box::new(Crc::<u16>::new(&CRC_14_GSM))),
+            CrcAlgorithm::Crc15Can => CrcEngine::U16(Box::new(Crc::<u16>::new(&CRC_15_CAN))),
+            CrcAlgorithm::Crc15Mpt1327 => CrcEngine::U16(Box::new(Crc::<u16>::new(&CRC_15_MPT1327))),
+            CrcAlgorithm::Crc16Arc => CrcEngine::U16(Box::new(Crc::<u16>::new(&CRC_16_ARC))),
+            CrcAlgorithm::Crc16Cdma2000 => CrcEngine::U16(Box::new(Crc::<u16>::new(&CRC_16_CDMA2000))),
+            CrcAlgorithm::Crc16Cms => CrcEngine::U16(Box::new(Crc::<u16>::new(&CRC_16_CMS))),
+            CrcAlgorithm::Crc16Dds110 => CrcEngine::U16(Box::new(Crc::<u16>::new(&CRC_16_DDS_110))),
+            CrcAlgorithm::Crc16DectR => CrcEngine::U16(Box::new(Crc::<u16>::new(&CRC_16_DECT_R))),
+            CrcAlgorithm::Crc16DectX => CrcEngine::U16(Box::new(Crc::<u16>::new(&CRC_16_DECT_X))),
+            CrcAlgorithm::Crc16Dnp => CrcEngine::U16(Box::new(Crc::<u16>::new(&CRC_16_DNP))),
+            CrcAlgorithm::Crc16En13757 => CrcEngine::U16(Box::new(Crc::<u16>::new(&CRC_16_EN_13757))),
+            CrcAlgorithm::Crc16Genibus => CrcEngine::U16(Box::new(Crc::<u16>::new(&CRC_16_GENIBUS))),
+            CrcAlgorithm::Crc16Gsm => CrcEngine::U16(Box::new(Crc::<u16>::new(&CRC_16_GSM))),
+            CrcAlgorithm::Crc16Ibm3740 => CrcEngine::U16(Box::new(Crc::<u16>::new(&CRC_16_IBM_3740))),
+            CrcAlgorithm::Crc16IbmSdlc => CrcEngine::U16(Box::new(Crc::<u16>::new(&CRC_16_IBM_SDLC))),
+            CrcAlgorithm::Crc16IsoIec144433A => CrcEngine::U16(Box::new(Crc::<u16>::new(&CRC_16_ISO_IEC_14443_3_A))),
+            CrcAlgorithm::Crc16Kermit => CrcEngine::U16(Box::new(Crc::<u16>::new(&CRC_16_KERMIT))),
+            CrcAlgorithm::Crc16Lj1200 => CrcEngine::U16(Box::new(Crc::<u16>::new(&CRC_16_LJ1200))),
+            CrcAlgorithm::Crc16M17 => CrcEngine::U16(Box::new(Crc::<u16>::new(&CRC_16_M17))),
+            CrcAlgorithm::Crc16MaximDow => CrcEngine::U16(Box::new(Crc::<u16>::new(&CRC_16_MAXIM_DOW))),
+            CrcAlgorithm::Crc16Mcrf4xx => CrcEngine::U16(Box::new(Crc::<u16>::new(&CRC_16_MCRF4XX))),
+            CrcAlgorithm::Crc16Modbus => CrcEngine::U16(Box::new(Crc::<u16>::new(&CRC_16_MODBUS))),
+            CrcAlgorithm::Crc16Nrsc5 => CrcEngine::U16(Box::new(Crc::<u16>::new(&CRC_16_NRSC_5))),
+            CrcAlgorithm::Crc16OpensafetyA => CrcEngine::U16(Box::new(Crc::<u16>::new(&CRC_16_OPENSAFETY_A))),
+            CrcAlgorithm::Crc16OpensafetyB => CrcEngine::U16(Box::new(Crc::<u16>::new(&CRC_16_OPENSAFETY_B))),
+            CrcAlgorithm::Crc16Profibus => CrcEngine::U16(Box::new(Crc::<u16>::new(&CRC_16_PROFIBUS))),
+            CrcAlgorithm::Crc16Riello => CrcEngine::U16(Box::new(Crc::<u16>::new(&CRC_16_RIELLO))),
+            CrcAlgorithm::Crc16SpiFujitsu => CrcEngine::U16(Box::new(Crc::<u16>::new(&CRC_16_SPI_FUJITSU))),
+            CrcAlgorithm::Crc16T10Dif => CrcEngine::U16(Box::new(Crc::<u16>::new(&CRC_16_T10_DIF))),
+            CrcAlgorithm::Crc16Teledisk => CrcEngine::U16(Box::new(Crc::<u16>::new(&CRC_16_TELEDISK))),
+            CrcAlgorithm::Crc16Tms37157 => CrcEngine::U16(Box::new(Crc::<u16>::new(&CRC_16_TMS37157))),
+            CrcAlgorithm::Crc16Umts => CrcEngine::U16(Box::new(Crc::<u16>::new(&CRC_16_UMTS))),
+            CrcAlgorithm::Crc16Usb => CrcEngine::U16(Box::new(Crc::<u16>::new(&CRC_16_USB))),
+            CrcAlgorithm::Crc16Xmodem => CrcEngine::U16(Box::new(Crc::<u16>::new(&CRC_16_XMODEM))),
+            CrcAlgorithm::Crc17CanFd => CrcEngine::U32(Box::new(Crc::<u32>::new(&CRC_17_CAN_FD))),
+            CrcAlgorithm::Crc21CanFd => CrcEngine::U32(Box::new(Crc::<u32>::new(&CRC_21_CAN_FD))),
+            CrcAlgorithm::Crc24Ble => CrcEngine::U32(Box::new(Crc::<u32>::new(&CRC_24_BLE))),
+            CrcAlgorithm::Crc24FlexrayA => CrcEngine::U32(Box::new(Crc::<u32>::new(&CRC_24_FLEXRAY_A))),
+            CrcAlgorithm::Crc24FlexrayB => CrcEngine::U32(Box::new(Crc::<u32>::new(&CRC_24_FLEXRAY_B))),
+            CrcAlgorithm::Crc24Interlaken => CrcEngine::U32(Box::new(Crc::<u32>::new(&CRC_24_INTERLAKEN))),
+            CrcAlgorithm::Crc24LteA => CrcEngine::U32(Box::new(Crc::<u32>::new(&CRC_24_LTE_A))),
+            CrcAlgorithm::Crc24LteB => CrcEngine::U32(Box::new(Crc::<u32>::new(&CRC_24_LTE_B))),
+            CrcAlgorithm::Crc24Openpgp => CrcEngine::U32(Box::new(Crc::<u32>::new(&CRC_24_OPENPGP))),
+            CrcAlgorithm::Crc24Os9 => CrcEngine::U32(Box::new(Crc::<u32>::new(&CRC_24_OS_9))),
+            CrcAlgorithm::Crc30Cdma => CrcEngine::U32(Box::new(Crc::<u32>::new(&CRC_30_CDMA))),
+            CrcAlgorithm::Crc31Philips => CrcEngine::U32(Box::new(Crc::<u32>::new(&CRC_31_PHILIPS))),
+            CrcAlgorithm::Crc32Aixm => CrcEngine::U32(Box::new(Crc::<u32>::new(&CRC_32_AIXM))),
+            CrcAlgorithm::Crc32Autosar => CrcEngine::U32(Box::new(Crc::<u32>::new(&CRC_32_AUTOSAR))),
+            CrcAlgorithm::Crc32Base91D => CrcEngine::U32(Box::new(Crc::<u32>::new(&CRC_32_BASE91_D))),
+            CrcAlgorithm::Crc32Bzip2 => CrcEngine::U32(Box::new(Crc::<u32>::new(&CRC_32_BZIP2))),
+            CrcAlgorithm::Crc32CdRomEdc => CrcEngine::U32(Box::new(Crc::<u32>::new(&CRC_32_CD_ROM_EDC))),
+            CrcAlgorithm::Crc32Cksum => CrcEngine::U32(Box::new(Crc::<u32>::new(&CRC_32_CKSUM))),
+            CrcAlgorithm::Crc32Iscsi => CrcEngine::U32(Box::new(Crc::<u32>::new(&CRC_32_ISCSI))),
+            CrcAlgorithm::Crc32IsoHdlc => CrcEngine::U32(Box::new(Crc::<u32>::new(&CRC_32_ISO_HDLC))),
+            CrcAlgorithm::Crc32Jamcrc => CrcEngine::U32(Box::new(Crc::<u32>::new(&CRC_32_JAMCRC))),
+            CrcAlgorithm::Crc32Mef => CrcEngine::U32(Box::new(Crc::<u32>::new(&CRC_32_MEF))),
+            CrcAlgorithm::Crc32Mpeg2 => CrcEngine::U32(Box::new(Crc::<u32>::new(&CRC_32_MPEG_2))),
+            CrcAlgorithm::Crc32Xfer => CrcEngine::U32(Box::new(Crc::<u32>::new(&CRC_32_XFER))),
+            CrcAlgorithm::Crc40Gsm => CrcEngine::U64(Box::new(Crc::<u64>::new(&CRC_40_GSM))),
+            CrcAlgorithm::Crc64Ecma182 => CrcEngine::U64(Box::new(Crc::<u64>::new(&CRC_64_ECMA_182))),
+            CrcAlgorithm::Crc64GoIso => CrcEngine::U64(Box::new(Crc::<u64>::new(&CRC_64_GO_ISO))),
+            CrcAlgorithm::Crc64Ms => CrcEngine::U64(Box::new(Crc::<u64>::new(&CRC_64_MS))),
+            CrcAlgorithm::Crc64Redis => CrcEngine::U64(Box::new(Crc::<u64>::new(&CRC_64_REDIS))),
+            CrcAlgorithm::Crc64We => CrcEngine::U64(Box::new(Crc::<u64>::new(&CRC_64_WE))),
+            CrcAlgorithm::Crc64Xz => CrcEngine::U64(Box::new(Crc::<u64>::new(&CRC_64_XZ))),
+            CrcAlgorithm::Custom(params) => CrcEngine::Bitwise(*params),
+        }
+    }
+
+    /// 알고리즘의 Rocksoft 모델 파라미터를 반환합니다.
+    pub fn params(&self) -> CrcParams {
+        match self {
+            CrcAlgorithm::Crc3Gsm => CrcParams::from_catalog(&CRC_3_GSM),
+            CrcAlgorithm::Crc3Rohc => CrcParams::from_catalog(&CRC_3_ROHC),
+            CrcAlgorithm::Crc4G704 => CrcParams::from_catalog(&CRC_4_G_704),
+            CrcAlgorithm::Crc4Interlaken => CrcParams::from_catalog(&CRC_4_INTERLAKEN),
+            CrcAlgorithm::Crc5EpcC1g2 => CrcParams::from_catalog(&CRC_5_EPC_C1G2),
+            CrcAlgorithm::Crc5G704 => CrcParams::from_catalog(&CRC_5_G_704),
+            CrcAlgorithm::Crc5Usb => CrcParams::from_catalog(&CRC_5_USB),
+            CrcAlgorithm::Crc6Cdma2000A => CrcParams::from_catalog(&CRC_6_CDMA2000_A),
+            CrcAlgorithm::Crc6Cdma2000B => CrcParams::from_catalog(&CRC_6_CDMA2000_B),
+            CrcAlgorithm::Crc6Darc => CrcParams::from_catalog(&CRC_6_DARC),
+            CrcAlgorithm::Crc6Gsm => CrcParams::from_catalog(&CRC_6_GSM),
+            CrcAlgorithm::Crc6G704 => CrcParams::from_catalog(&CRC_6_G_704),
+            CrcAlgorithm::Crc7Mmc => CrcParams::from_catalog(&CRC_7_MMC),
+            CrcAlgorithm::Crc7Rohc => CrcParams::from_catalog(&CRC_7_ROHC),
+            CrcAlgorithm::Crc7Umts => CrcParams::from_catalog(&CRC_7_UMTS),
+            CrcAlgorithm::Crc8Autosar => CrcParams::from_catalog(&CRC_8_AUTOSAR),
+            CrcAlgorithm::Crc8Bluetooth => CrcParams::from_catalog(&CRC_8_BLUETOOTH),
+            CrcAlgorithm::Crc8Cdma2000 => CrcParams::from_catalog(&CRC_8_CDMA2000),
+            CrcAlgorithm::Crc8Darc => CrcParams::from_catalog(&CRC_8_DARC),
+            CrcAlgorithm::Crc8DvbS2 => CrcParams::from_catalog(&CRC_8_DVB_S2),
+            CrcAlgorithm::Crc8GsmA => CrcParams::from_catalog(&CRC_8_GSM_A),
+            CrcAlgorithm::Crc8GsmB => CrcParams::from_catalog(&CRC_8_GSM_B),
+            CrcAlgorithm::Crc8Hitag => CrcParams::from_catalog(&CRC_8_HITAG),
+            CrcAlgorithm::Crc8I4321 => CrcParams::from_catalog(&CRC_8_I_432_1),
+            CrcAlgorithm::Crc8ICode => CrcParams::from_catalog(&CRC_8_I_CODE),
+            CrcAlgorithm::Crc8Lte => CrcParams::from_catalog(&CRC_8_LTE),
+            CrcAlgorithm::Crc8MaximDow => CrcParams::from_catalog(&CRC_8_MAXIM_DOW),
+            CrcAlgorithm::Crc8MifareMad => CrcParams::from_catalog(&CRC_8_MIFARE_MAD),
+            CrcAlgorithm::Crc8Nrsc5 => CrcParams::from_catalog(&CRC_8_NRSC_5),
+            CrcAlgorithm::Crc8Opensafety => CrcParams::from_catalog(&CRC_8_OPENSAFETY),
+            CrcAlgorithm::Crc8Rohc => CrcParams::from_catalog(&CRC_8_ROHC),
+            CrcAlgorithm::Crc8SaeJ1850 => CrcParams::from_catalog(&CRC_8_SAE_J1850),
+            CrcAlgorithm::Crc8Smbus => CrcParams::from_catalog(&CRC_8_SMBUS),
+            CrcAlgorithm::Crc8Tech3250 => CrcParams::from_catalog(&CRC_8_TECH_3250),
+            CrcAlgorithm::Crc8Wcdma => CrcParams::from_catalog(&CRC_8_WCDMA),
+            CrcAlgorithm::Crc10Atm => CrcParams::from_catalog(&CRC_10_ATM),
+            CrcAlgorithm::Crc10Cdma2000 => CrcParams::from_catalog(&CRC_10_CDMA2000),
+            CrcAlgorithm::Crc10Gsm => CrcParams::from_catalog(&CRC_10_GSM),
+            CrcAlgorithm::Crc11Flexray => CrcParams::from_catalog(&CRC_11_FLEXRAY),
+            CrcAlgorithm::Crc11Umts => CrcParams::from_catalog(&CRC_11_UMTS),
+            CrcAlgorithm::Crc12Cdma2000 => CrcParams::from_catalog(&CRC_12_CDMA2000),
+            CrcAlgorithm::Crc12Dect => CrcParams::from_catalog(&CRC_12_DECT),
+            CrcAlgorithm::Crc12Gsm => CrcParams::from_catalog(&CRC_12_GSM),
+            CrcAlgorithm::Crc12Umts => CrcParams::from_catalog(&CRC_12_UMTS),
+            CrcAlgorithm::Crc13Bbc => CrcParams::from_catalog(&CRC_13_BBC),
+            CrcAlgorithm::Crc14Darc => CrcParams::from_catalog(&CRC_14_DARC),
+            CrcAlgorithm::Crc14Gsm => CrcParams::from_catalog(&CRC_14_GSM),
+            CrcAlgorithm::Crc15Can => CrcParams::from_catalog(&CRC_15_CAN),
+            CrcAlgorithm::Crc15Mpt1327 => CrcParams::from_catalog(&CRC_15_MPT1327),
+            CrcAlgorithm::Crc16Arc => CrcParams::from_catalog(&CRC_16_ARC),
+            CrcAlgorithm::Crc16Cdma2000 => CrcParams::from_catalog(&CRC_16_CDMA2000),
+            CrcAlgorithm::Crc16Cms => CrcParams::from_catalog(&CRC_16_CMS),
+            CrcAlgorithm::Crc16Dds110 => CrcParams::from_catalog(&CRC_16_DDS_110),
+            CrcAlgorithm::Crc16DectR => CrcParams::from_catalog(&CRC_16_DECT_R),
+            CrcAlgorithm::Crc16DectX => CrcParams::from_catalog(&CRC_16_DECT_X),
+            CrcAlgorithm::Crc16Dnp => CrcParams::from_catalog(&CRC_16_DNP),
+            CrcAlgorithm::Crc16En13757 => CrcParams::from_catalog(&CRC_16_EN_13757),
+            CrcAlgorithm::Crc16Genibus => CrcParams::from_catalog(&CRC_16_GENIBUS),
+            CrcAlgorithm::Crc16Gsm => CrcParams::from_catalog(&CRC_16_GSM),
+            CrcAlgorithm::Crc16Ibm3740 => CrcParams::from_catalog(&CRC_16_IBM_3740),
+            CrcAlgorithm::Crc16IbmSdlc => CrcParams::from_catalog(&CRC_16_IBM_SDLC),
+            CrcAlgorithm::Crc16IsoIec144433A => CrcParams::from_catalog(&CRC_16_ISO_IEC_14443_3_A),
+            CrcAlgorithm::Crc16Kermit => CrcParams::from_catalog(&CRC_16_KERMIT),
+            CrcAlgorithm::Crc16Lj1200 => CrcParams::from_catalog(&CRC_16_LJ1200),
+            CrcAlgorithm::Crc16M17 => CrcParams::from_catalog(&CRC_16_M17),
+            CrcAlgorithm::Crc16MaximDow => CrcParams::from_catalog(&CRC_16_MAXIM_DOW),
+            CrcAlgorithm::Crc16Mcrf4xx => CrcParams::from_catalog(&CRC_16_MCRF4XX),
+            CrcAlgorithm::Crc16Modbus => CrcParams::from_catalog(&CRC_16_MODBUS),
+            CrcAlgorithm::Crc16Nrsc5 => CrcParams::from_catalog(&CRC_16_NRSC_5),
+            CrcAlgorithm::Crc16OpensafetyA => CrcParams::from_catalog(&CRC_16_OPENSAFETY_A),
+            CrcAlgorithm::Crc16OpensafetyB => CrcParams::from_catalog(&CRC_16_OPENSAFETY_B),
+            CrcAlgorithm::Crc16Profibus => CrcParams::from_catalog(&CRC_16_PROFIBUS),
+            CrcAlgorithm::Crc16Riello => CrcParams::from_catalog(&CRC_16_RIELLO),
+            CrcAlgorithm::Crc16SpiFujitsu => CrcParams::from_catalog(&CRC_16_SPI_FUJITSU),
+            CrcAlgorithm::Crc16T10Dif => CrcParams::from_catalog(&CRC_16_T10_DIF),
+            CrcAlgorithm::Crc16Teledisk => CrcParams::from_catalog(&CRC_16_TELEDISK),
+            CrcAlgorithm::Crc16Tms37157 => CrcParams::from_catalog(&CRC_16_TMS37157),
+            CrcAlgorithm::Crc16Umts => CrcParams::from_catalog(&CRC_16_UMTS),
+            CrcAlgorithm::Crc16Usb => CrcParams::from_catalog(&CRC_16_USB),
+            CrcAlgorithm::Crc16Xmodem => CrcParams::from_catalog(&CRC_16_XMODEM),
+            CrcAlgorithm::Crc17CanFd => CrcParams::from_catalog(&CRC_17_CAN_FD),
+            CrcAlgorithm::Crc21CanFd => CrcParams::from_catalog(&CRC_21_CAN_FD),
+            CrcAlgorithm::Crc24Ble => CrcParams::from_catalog(&CRC_24_BLE),
+            CrcAlgorithm::Crc24FlexrayA => CrcParams::from_catalog(&CRC_24_FLEXRAY_A),
+            CrcAlgorithm::Crc24FlexrayB => CrcParams::from_catalog(&CRC_24_FLEXRAY_B),
+            CrcAlgorithm::Crc24Interlaken => CrcParams::from_catalog(&CRC_24_INTERLAKEN),
+            CrcAlgorithm::Crc24LteA => CrcParams::from_catalog(&CRC_24_LTE_A),
+            CrcAlgorithm::Crc24LteB => CrcParams::from_catalog(&CRC_24_LTE_B),
+            CrcAlgorithm::Crc24Openpgp => CrcParams::from_catalog(&CRC_24_OPENPGP),
+            CrcAlgorithm::Crc24Os9 => CrcParams::from_catalog(&CRC_24_OS_9),
+            CrcAlgorithm::Crc30Cdma => CrcParams::from_catalog(&CRC_30_CDMA),
+            CrcAlgorithm::Crc31Philips => CrcParams::from_catalog(&CRC_31_PHILIPS),
+            CrcAlgorithm::Crc32Aixm => CrcParams::from_catalog(&CRC_32_AIXM),
+            CrcAlgorithm::Crc32Autosar => CrcParams::from_catalog(&CRC_32_AUTOSAR),
+            CrcAlgorithm::Crc32Base91D => CrcParams::from_catalog(&CRC_32_BASE91_D),
+            CrcAlgorithm::Crc32Bzip2 => CrcParams::from_catalog(&CRC_32_BZIP2),
+            CrcAlgorithm::Crc32CdRomEdc => CrcParams::from_catalog(&CRC_32_CD_ROM_EDC),
+            CrcAlgorithm::Crc32Cksum => CrcParams::from_catalog(&CRC_32_CKSUM),
+            CrcAlgorithm::Crc32Iscsi => CrcParams::from_catalog(&CRC_32_ISCSI),
+            CrcAlgorithm::Crc32IsoHdlc => CrcParams::from_catalog(&CRC_32_ISO_HDLC),
+            CrcAlgorithm::Crc32Jamcrc => CrcParams::from_catalog(&CRC_32_JAMCRC),
+            CrcAlgorithm::Crc32Mef => CrcParams::from_catalog(&CRC_32_MEF),
+            CrcAlgorithm::Crc32Mpeg2 => CrcParams::from_catalog(&CRC_32_MPEG_2),
+            CrcAlgorithm::Crc32Xfer => CrcParams::from_catalog(&CRC_32_XFER),
+            CrcAlgorithm::Crc40Gsm => CrcParams::from_catalog(&CRC_40_GSM),
+            CrcAlgorithm::Crc64Ecma182 => CrcParams::from_catalog(&CRC_64_ECMA_182),
+            CrcAlgorithm::Crc64GoIso => CrcParams::from_catalog(&CRC_64_GO_ISO),
+            CrcAlgorithm::Crc64Ms => CrcParams::from_catalog(&CRC_64_MS),
+            CrcAlgorithm::Crc64Redis => CrcParams::from_catalog(&CRC_64_REDIS),
+            CrcAlgorithm::Crc64We => CrcParams::from_catalog(&CRC_64_WE),
+            CrcAlgorithm::Crc64Xz => CrcParams::from_catalog(&CRC_64_XZ),
+            CrcAlgorithm::Custom(params) => *params,
+        }
+    }
+
+    pub fn all() -> Vec<CrcAlgorithm> {
+        vec![
+            CrcAlgorithm::Crc3Gsm,
+            CrcAlgorithm::Crc3Rohc,
+            CrcAlgorithm::Crc4G704,
+            CrcAlgorithm::Crc4Interlaken,
+            CrcAlgorithm::Crc5EpcC1g2,
+            CrcAlgorithm::Crc5G704,
+            CrcAlgorithm::Crc5Usb,
+            CrcAlgorithm::Crc6Cdma2000A,
+            CrcAlgorithm::Crc6Cdma2000B,
+            CrcAlgorithm::Crc6Darc,
+            CrcAlgorithm::Crc6Gsm,
+            CrcAlgorithm::Crc6G704,
+            CrcAlgorithm::Crc7Mmc,
+            CrcAlgorithm::Crc7Rohc,
+            CrcAlgorithm::Crc7Umts,
+            CrcAlgorithm::Crc8Autosar,
+            CrcAlgorithm::Crc8Bluetooth,
+            CrcAlgorithm::Crc8Cdma2000,
+            CrcAlgorithm::Crc8Darc,
+            CrcAlgorithm::Crc8DvbS2,
+            CrcAlgorithm::Crc8GsmA,
+            CrcAlgorithm::Crc8GsmB,
+            CrcAlgorithm::Crc8Hitag,
+            CrcAlgorithm::Crc8I4321,
+            CrcAlgorithm::Crc8ICode,
+            CrcAlgorithm::Crc8Lte,
+            CrcAlgorithm::Crc8MaximDow,
+            CrcAlgorithm::Crc8MifareMad,
+            CrcAlgorithm::Crc8Nrsc5,
+            CrcAlgorithm::Crc8Opensafety,
+            CrcAlgorithm::Crc8Rohc,
+            CrcAlgorithm::Crc8SaeJ1850,
+            CrcAlgorithm::Crc8Smbus,
+            CrcAlgorithm::Crc8Tech3250,
+            CrcAlgorithm::Crc8Wcdma,
+            CrcAlgorithm::Crc10Atm,
+            CrcAlgorithm::Crc10Cdma2000,
+            CrcAlgorithm::Crc10Gsm,
+            CrcAlgorithm::Crc11Flexray,
+            CrcAlgorithm::Crc11Umts,
+            CrcAlgorithm::Crc12Cdma2000,
+            CrcAlgorithm::Crc12Dect,
+            CrcAlgorithm::Crc12Gsm,
+            CrcAlgorithm::Crc12Umts,
+            CrcAlgorithm::Crc13Bbc,
+            CrcAlgorithm::Crc14Darc,
+            CrcAlgorithm::Crc14Gsm,
+            CrcAlgorithm::Crc15Can,
+            CrcAlgorithm::Crc15Mpt1327,
+            CrcAlgorithm::Crc16Arc,
+            CrcAlgorithm::Crc16Cdma2000,
+            CrcAlgorithm::Crc16Cms,
+            CrcAlgorithm::Crc16Dds110,
+            CrcAlgorithm::Crc16DectR,
+            CrcAlgorithm::Crc16DectX,
+            CrcAlgorithm::Crc16Dnp,
+            CrcAlgorithm::Crc16En13757,
+            CrcAlgorithm::Crc16Genibus,
+            CrcAlgorithm::Crc16Gsm,
+            CrcAlgorithm::Crc16Ibm3740,
+            CrcAlgorithm::Crc16IbmSdlc,
+            CrcAlgorithm::Crc16IsoIec144433A,
+            CrcAlgorithm::Crc16Kermit,
+            CrcAlgorithm::Crc16Lj1200,
+            CrcAlgorithm::Crc16M17,
+            CrcAlgorithm::Crc16MaximDow,
+            CrcAlgorithm::Crc16Mcrf4xx,
+            CrcAlgorithm::Crc16Modbus,
+            CrcAlgorithm::Crc16Nrsc5,
+            CrcAlgorithm::Crc16OpensafetyA,
+            CrcAlgorithm::Crc16OpensafetyB,
+            CrcAlgorithm::Crc16Profibus,
+            CrcAlgorithm::Crc16Riello,
+            CrcAlgorithm::Crc16SpiFujitsu,
+            CrcAlgorithm::Crc16T10Dif,
+            CrcAlgorithm::Crc16Teledisk,
+            CrcAlgorithm::Crc16Tms37157,
+            CrcAlgorithm::Crc16Umts,
+            CrcAlgorithm::Crc16Usb,
+            CrcAlgorithm::Crc16Xmodem,
+            CrcAlgorithm::Crc17CanFd,
+            CrcAlgorithm::Crc21CanFd,
+            CrcAlgorithm::Crc24Ble,
+            CrcAlgorithm::Crc24FlexrayA,
+            CrcAlgorithm::Crc24FlexrayB,
+            CrcAlgorithm::Crc24Interlaken,
+            CrcAlgorithm::Crc24LteA,
+            CrcAlgorithm::Crc24LteB,
+            CrcAlgorithm::Crc24Openpgp,
+            CrcAlgorithm::Crc24Os9,
+            CrcAlgorithm::Crc30Cdma,
+            CrcAlgorithm::Crc31Philips,
+            CrcAlgorithm::Crc32Aixm,
+            CrcAlgorithm::Crc32Autosar,
+            CrcAlgorithm::Crc32Base91D,
+            CrcAlgorithm::Crc32Bzip2,
+            CrcAlgorithm::Crc32CdRomEdc,
+            CrcAlgorithm::Crc32Cksum,
+            CrcAlgorithm::Crc32Iscsi,
+            CrcAlgorithm::Crc32IsoHdlc,
+            CrcAlgorithm::Crc32Jamcrc,
+            CrcAlgorithm::Crc32Mef,
+            CrcAlgorithm::Crc32Mpeg2,
+            CrcAlgorithm::Crc32Xfer,
+            CrcAlgorithm::Crc40Gsm,
+            CrcAlgorithm::Crc64Ecma182,
+            CrcAlgorithm::Crc64GoIso,
+            CrcAlgorithm::Crc64Ms,
+            CrcAlgorithm::Crc64Redis,
+            CrcAlgorithm::Crc64We,
+            CrcAlgorithm::Crc64Xz,
+        ]
+    }
+}
+
+/// Rocksoft 모델 CRC 파라미터 (폭 1~64비트)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CrcParams {
+    pub width: u8,
+    pub poly: u64,
+    pub init: u64,
+    pub refin: bool,
+    pub refout: bool,
+    pub xorout: u64,
+}
+
+impl Default for CrcParams {
+    fn default() -> Self {
+        // CRC-32/ISO-HDLC
+        Self {
+            width: 32,
+            poly: 0x04C11DB7,
+            init: 0xFFFFFFFF,
+            refin: true,
+            refout: true,
+            xorout: 0xFFFFFFFF,
+        }
+    }
+}
+
+impl CrcParams {
+    fn from_catalog<W: Width + Copy + Into<u64>>(algorithm: &Algorithm<W>) -> Self {
+        Self {
+            width: algorithm.width,
+            poly: algorithm.poly.into(),
+            init: algorithm.init.into(),
+            refin: algorithm.refin,
+            refout: algorithm.refout,
+            xorout: algorithm.xorout.into(),
+        }
+    }
+
+    /// 폭에 해당하는 비트 마스크 (예: 16비트 → 0xFFFF)
+    pub fn mask(&self) -> u64 {
+        u64::MAX >> (64 - self.width.clamp(1, 64) as u32)
+    }
+
+    /// 폭이 1~64이고 poly/init/xorout이 폭 안에 들어가는지 검사합니다.
+    pub fn validate(&self) -> Result<(), String> {
+        if !(1..=64).contains(&self.width) {
+            return Err(format!("Width must be between 1 and 64 bits, got {}.", self.width));
+        }
+
+        let mask = self.mask();
+        for (name, value) in [("Poly", self.poly), ("Init", self.init), ("XorOut", self.xorout)] {
+            if value & !mask != 0 {
+                return Err(format!("{} 0x{:X} does not fit in {} bits.", name, value, self.width));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// 비트 단위로 CRC를 계산합니다. 검증되지 않은 파라미터는 폭 안으로 잘라서 사용합니다.
+    pub fn calculate(&self, data: &[u8]) -> u64 {
+        self.finalize(self.update(self.init & self.mask(), data))
+    }
+
+    /// 반사/XOR 적용 전의 레지스터 값에 데이터를 이어서 반영합니다.
+    fn update(&self, mut crc: u64, data: &[u8]) -> u64 {
+        let width = self.width.clamp(1, 64) as u32;
+        let mask = self.mask();
+        let top_bit = 1u64 << (width - 1);
+        let poly = self.poly & mask;
+
+        for &byte in data {
+            let byte = if self.refin { byte.reverse_bits() } else { byte };
+            for bit_index in (0..8).rev() {
+                let input_bit = (byte >> bit_index) & 1 == 1;
+                let top_set = crc & top_bit != 0;
+                crc = (crc << 1) & mask;
+                if top_set != input_bit {
+                    crc ^= poly;
+                }
+            }
+        }
+        crc
+    }
+
+    fn finalize(&self, mut crc: u64) -> u64 {
+        let width = self.width.clamp(1, 64) as u32;
+        let mask = self.mask();
+        if self.refout {
+            crc = crc.reverse_bits() >> (64 - width);
+        }
+
+        (crc ^ self.xorout) & mask
+    }
+
+    /// 표준 검사 문자열 "123456789"의 CRC 값
+    pub fn check(&self) -> u64 {
+        self.calculate(b"123456789")
+    }
+
+    /// 올바른 코드워드(메시지 + 알고리즘의 비트 순서대로 붙인 CRC)를 처리한 뒤
+    /// xorout 적용 전의 레지스터 값 (카탈로그의 residue)
+    ///
+    /// 메시지와 무관하게 xorout·x^width mod poly로 정해집니다.
+    pub fn residue(&self) -> u64 {
+        let width = self.width.clamp(1, 64) as u32;
+        let mask = self.mask();
+        let top_bit = 1u64 << (width - 1);
+        let poly = self.poly & mask;
+        let reflect = |value: u64| value.reverse_bits() >> (64 - width);
+
+        let mut register = if self.refout { reflect(self.xorout & mask) } else { self.xorout & mask };
+        for _ in 0..width {
+            let top_set = register & top_bit != 0;
+            register = (register << 1) & mask;
+            if top_set {
+                register ^= poly;
+            }
+        }
+
+        if self.refout {
+            reflect(register)
+        } else {
+            register
+        }
+    }
+
+    /// CRC 카탈로그 형식의 한 줄 설명 (예: "width=16 poly=0x8005 init=0xffff ...")
+    pub fn describe(&self) -> String {
+        let digits = self.width.div_ceil(4) as usize;
+        format!(
+            "width={} poly=0x{:0digits$x} init=0x{:0digits$x} refin={} refout={} xorout=0x{:0digits$x} check=0x{:0digits$x}",
+            self.width,
+            self.poly,
+            self.init,
+            self.refin,
+            self.refout,
+            self.xorout,
+            self.check(),
+            digits = digits
+        )
+    }
+}
+
+/// 조각 단위 CRC 계산 엔진. 테이블을 한 번만 만들고 여러 번 `digest()`할 수 있습니다.
+#[derive(Clone)]
+pub enum CrcEngine {
+    // 룩업 테이블 크기가 폭마다 256B~2KB로 달라서 박싱
+    U8(Box<Crc<u8>>),
+    U16(Box<Crc<u16>>),
+    U32(Box<Crc<u32>>),
+    U64(Box<Crc<u64>>),
+    Bitwise(CrcParams),
+}
+
+impl CrcEngine {
+    pub fn width(&self) -> u8 {
+        match self {
+            CrcEngine::U8(crc) => crc.algorithm.width,
+            CrcEngine::U16(crc) => crc.algorithm.width,
+            CrcEngine::U32(crc) => crc.algorithm.width,
+            CrcEngine::U64(crc) => crc.algorithm.width,
+            CrcEngine::Bitwise(params) => params.width,
+        }
+    }
+
+    pub fn digest(&self) -> CrcDigest<'_> {
+        match self {
+            CrcEngine::U8(crc) => CrcDigest::U8(crc.digest()),
+            CrcEngine::U16(crc) => CrcDigest::U16(crc.digest()),
+            CrcEngine::U32(crc) => CrcDigest::U32(crc.digest()),
+            CrcEngine::U64(crc) => CrcDigest::U64(crc.digest()),
+            CrcEngine::Bitwise(params) => CrcDigest::Bitwise {
+                params: *params,
+                register: params.init & params.mask(),
+            },
+        }
+    }
+}
+
+/// 진행 중인 CRC 계산. 데이터를 여러 번 `update`한 뒤 `finalize`합니다.
+#[derive(Clone)]
+pub enum CrcDigest<'a> {
+    U8(Digest<'a, u8>),
+    U16(Digest<'a, u16>),
+    U32(Digest<'a, u32>),
+    U64(Digest<'a, u64>),
+    Bitwise { params: CrcParams, register: u64 },
+}
+
+impl CrcDigest<'_> {
+    pub fn update(&mut self, data: &[u8]) {
+        match self {
+            CrcDigest::U8(digest) => digest.update(data),
+            CrcDigest::U16(digest) => digest.update(data),
+            CrcDigest::U32(digest) => digest.update(data),
+            CrcDigest::U64(digest) => digest.update(data),
+            CrcDigest::Bitwise { params, register } => *register = params.update(*register, data),
+        }
+    }
+
+    pub fn finalize(self) -> u64 {
+        match self {
+            CrcDigest::U8(digest) => digest.finalize() as u64,
+            CrcDigest::U16(digest) => digest.finalize() as u64,
+            CrcDigest::U32(digest) => digest.finalize() as u64,
+            CrcDigest::U64(digest) => digest.finalize(),
+            CrcDigest::Bitwise { params, register } => params.finalize(register),
+        }
+    }
+}
+
+/// "0x1021", "1021h", "1021" 같은 16진수 파라미터 값을 파싱합니다.
+pub fn parse_param_value(input: &str) -> Result<u64, String> {
+    let trimmed = input.trim();
+    let digits = trimmed
+        .strip_prefix("0x")
+        .or_else(|| trimmed.strip_prefix("0X"))
+        .or_else(|| trimmed.strip_suffix('h'))
+        .or_else(|| trimmed.strip_suffix('H'))
+        .unwrap_or(trimmed)
+        .replace('_', "");
+
+    if digits.is_empty() {
+        return Err("Value is empty.".to_string());
+    }
+
+    u64::from_str_radix(&digits, 16).map_err(|_| format!("Invalid hexadecimal value: '{}'", trimmed))
+}
+
+/// 입력 모드에 맞춰 문자열을 바이트 배열로 변환합니다.
+pub fn parse_input(input: &str, mode: &InputMode) -> Result<Vec<u8>, String> {
+    match mode {
+        InputMode::Ascii => Ok(input.as_bytes().to_vec()),
+        InputMode::Hex => parse_hex_input(input),
+        InputMode::Binary => parse_binary_input(input),
+        InputMode::Decimal => parse_decimal_input(input),
+        InputMode::Octal => parse_octal_input(input),
+    }
+}
+
+pub fn parse_hex_input(input: &str) -> Result<Vec<u8>, String> {
+    if input.trim().is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let mut result = Vec::new();
+    let mut current_number = String::new();
+    let mut chars = input.chars().peekable();
+
+    while let Some(&c) = chars.peek() {
+        match c {
+            // 공백 문자 처리
+            ' ' | '\n' | '\t' | '\r' => {
+                if !current_number.is_empty() {
+                    result.push(parse_hex_string(&current_number)?);
+                    current_number.clear();
+                }
+                chars.next();
+            }
+            // "0x" 또는 "\x" 접두사 처리
+            '0' => {
+                chars.next();
+                if let Some(&next) = chars.peek() {
+                    if next == 'x' || next == 'X' {
+                        if !current_number.is_empty() {
+                            result.push(parse_hex_string(&current_number)?);
+                            current_number.clear();
+                        }
+                        chars.next(); // 'x' 건너뛰기
+                        current_number = collect_hex_digits(&mut chars)?;
+                        if !current_number.is_empty() {
+                            result.push(parse_hex_string(&current_number)?);
+                            current_number.clear();
+                        }
+                    } else {
+                        current_number.push('0');
+                    }
+                } else {
+                    current_number.push('0');
+                }
+
+                // 두 자리가 모이면 바이트로 변환
+                if current_number.len() == 2 {
+                    result.push(parse_hex_string(&current_number)?);
+                    current_number.clear();
+                }
+            }
+            '\\' => {
+                chars.next();
+                if let Some(&next) = chars.peek() {
+                    if next == 'x' || next == 'X' {
+                        if !current_number.is_empty() {
+                            result.push(parse_hex_string(&current_number)?);
+                            current_number.clear();
+                        }
+                        chars.next(); // 'x' 건너뛰기
+                        current_number = collect_hex_digits(&mut chars)?;
+                        if !current_number.is_empty() {
+                            result.push(parse_hex_string(&current_number)?);
+                            current_number.clear();
+                        }
+                    } else {
+                        return Err("Invalid escape sequence: expected 'x' after '\\'".to_string());
+                    }
+                } else {
+                    return Err("Incomplete escape sequence: unexpected end of input after '\\'".to_string());
+                }
+            }
+            'x' | 'X' => {
+                if !current_number.is_empty() {
+                    result.push(parse_hex_string(&current_number)?);
+                    current_number.clear();
+                }
+                chars.next(); // 'x' 건너뛰기
+                current_number = collect_hex_digits(&mut chars)?;
+                if !current_number.is_empty() {
+                    result.push(parse_hex_string(&current_number)?);
+                    current_number.clear();
+                }
+            }
+            // 16진수 숫자 수집
+            _ => {
+                if c.is_ascii_hexdigit() {
+                    current_number.push(chars.next().unwrap());
+                } else {
+                    return Err(format!("Invalid character '{}' in hexadecimal input. Only 0-9, A-F, a-f are allowed.", c));
+                }
+
+                // 두 자리가 모이면 바이트로 변환
+                if current_number.len() == 2 {
+                    result.push(parse_hex_string(&current_number)?);
+                    current_number.clear();
+                }
+            }
+        }
+    }
+
+    // 남은 숫자 처리
+    if !current_number.is_empty() {
+        // 한 자리 숫자인 경우 앞에 0을 붙임
+        if current_number.len() == 1 {
+            current_number.insert(0, '0');
+        }
+        result.push(parse_hex_string(&current_number)?);
+    }
+
+    if result.is_empty() {
+        return Err("No valid hexadecimal values found in input".to_string());
+    }
+
+    Ok(result)
+}
+
+fn collect_hex_digits(
+    chars: &mut std::iter::Peekable<std::str::Chars>,
+) -> Result<String, String> {
+    let mut hex_str = String::new();
+
+    while let Some(&c) = chars.peek() {
+        if c.is_ascii_hexdigit() {
+            hex_str.push(chars.next().unwrap());
+            if hex_str.len() == 2 {
+                break;
+            }
+        } else {
+            break;
+        }
+    }
+
+    if hex_str.is_empty() {
+        return Err("Expected hexadecimal digits after prefix".to_string());
+    }
+
+    // 한 자리 숫자인 경우 앞에 0을 붙임
+    if hex_str.len() == 1 {
+        hex_str.insert(0, '0');
+    }
+
+    Ok(hex_str)
+}
+
+fn parse_hex_string(hex_str: &str) -> Result<u8, String> {
+    u8::from_str_radix(hex_str, 16).map_err(|_| format!("Invalid hexadecimal value: '{}'", hex_str))
+}
+
+pub fn parse_binary_input(input: &str) -> Result<Vec<u8>, String> {
+    if input.trim().is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let mut result = Vec::new();
+    let mut current_number = String::new();
+    let mut chars = input.chars().peekable();
+
+    while let Some(&c) = chars.peek() {
+        match c {
+            // 공백 문자 처리
+            ' ' | '\n' | '\t' | '\r' => {
+                if !current_number.is_empty() {
+                    result.push(parse_binary_string(&current_number)?);
+                    current_number.clear();
+                }
+                chars.next();
+            }
+            // "0b" 접두사 처리
+            '0' => {
+                chars.next();
+                if let Some(&next) = chars.peek() {
+                    if next == 'b' || next == 'B' {
+                        if !current_number.is_empty() {
+                            result.push(parse_binary_string(&current_number)?);
+                            current_number.clear();
+                        }
+                        chars.next(); // 'b' 건너뛰기
+                        current_number = collect_binary_digits(&mut chars)?;
+                        if !current_number.is_empty() {
+                            result.push(parse_binary_string(&current_number)?);
+                            current_number.clear();
+                        }
+                    } else {
+                        // 일반 '0' 이진수 숫자
+                        current_number.push('0');
+                        if current_number.len() == 8 {
+                            result.push(parse_binary_string(&current_number)?);
+                            current_number.clear();
+                        }
+                    }
+                } else {
+                    // 마지막 문자가 '0'인 경우
+                    current_number.push('0');
+                    if current_number.len() == 8 {
+                        result.push(parse_binary_string(&current_number)?);
+                        current_number.clear();
+                    }
+                }
+            }
+            'b' | 'B' => {
+                if !current_number.is_empty() {
+                    result.push(parse_binary_string(&current_number)?);
+                    current_number.clear();
+                }
+                chars.next(); // 'b' 건너뛰기
+                current_number = collect_binary_digits(&mut chars)?;
+                if !current_number.is_empty() {
+                    result.push(parse_binary_string(&current_number)?);
+                    current_number.clear();
+                }
+            }
+            // 이진수 숫자 수집
+            '1' => {
+                current_number.push(chars.next().unwrap());
+                // 8비트가 모이면 바이트로 변환
+                if current_number.len() == 8 {
+                    result.push(parse_binary_string(&current_number)?);
+                    current_number.clear();
+                }
+            }
+            _ => {
+                return Err(format!("Invalid character '{}' in binary input. Only 0 and 1 are allowed.", c));
+            }
+        }
+    }
+
+    // 남은 숫자 처리
+    if !current_number.is_empty() {
+        if current_number.len() > 8 {
+            return Err(format!("Binary sequence '{}' is longer than 8 bits", current_number));
+        }
+        result.push(parse_binary_string(&current_number)?);
+    }
+
+    if result.is_empty() {
+        return Err("No valid binary values found in input".to_string());
+    }
+
+    Ok(result)
+}
+
+fn collect_binary_digits(
+    chars: &mut std::iter::Peekable<std::str::Chars>,
+) -> Result<String, String> {
+    let mut binary_str = String::new();
+
+    while let Some(&c) = chars.peek() {
+        if c == '0' || c == '1' {
+            binary_str.push(chars.next().unwrap());
+            if binary_str.len() == 8 {
+                break;
+            }
+        } else {
+            break;
+        }
+    }
+
+    if binary_str.is_empty() {
+        return Err("Expected binary digits after prefix".to_string());
+    }
+
+    Ok(binary_str)
+}
+
+fn parse_binary_string(binary_str: &str) -> Result<u8, String> {
+    if binary_str.len() > 8 {
+        return Err(format!("Binary sequence '{}' exceeds 8 bits", binary_str));
+    }
+    u8::from_str_radix(binary_str, 2).map_err(|_| format!("Invalid binary value: '{}'", binary_str))
+}
+
+pub fn parse_decimal_input(input: &str) -> Result<Vec<u8>, String> {
+    if input.trim().is_empty() {
+        return Ok(Vec::new());
+    }
+
+    input
+        .split_whitespace()
+        .map(|s| {
+            s.parse::<u16>()
+                .map_err(|_| format!("Invalid decimal number: '{}'", s))
+                .and_then(|num| {
+                    if num > 255 {
+                        Err(format!("Decimal value {} exceeds maximum range (0-255)", num))
+                    } else {
+                        Ok(num as u8)
+                    }
+                })
+        })
+        .collect()
+}
+
+pub fn parse_octal_input(input: &str) -> Result<Vec<u8>, String> {
+    if input.trim().is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let mut result = Vec::new();
+    let mut current_number = String::new();
+    let mut chars = input.chars().peekable();
+
+    while let Some(&c) = chars.peek() {
+        match c {
+            // 공백 문자 처리
+            ' ' | '\n' | '\t' | '\r' => {
+                if !current_number.is_empty() {
+                    result.push(parse_octal_string(&current_number)?);
+                    current_number.clear();
+                }
+                chars.next();
+            }
+            // "\NNN" escape sequence 처리
+            '\\' => {
+                if !current_number.is_empty() {
+                    result.push(parse_octal_string(&current_number)?);
+                    current_number.clear();
+                }
+                chars.next(); // '\' 건너뛰기
+                current_number = collect_octal_digits(&mut chars)?;
+                if !current_number.is_empty() {
+                    result.push(parse_octal_string(&current_number)?);
+                    current_number.clear();
+                }
+            }
+            // "0o" 접두사 처리
+            '0' => {
+                chars.next();
+                if let Some(&next) = chars.peek() {
+                    if next == 'o' || next == 'O' {
+                        if !current_number.is_empty() {
+                            result.push(parse_octal_string(&current_number)?);
+                            current_number.clear();
+                        }
+                        chars.next(); // 'o' 건너뛰기
+                        current_number = collect_octal_digits(&mut chars)?;
+                        if !current_number.is_empty() {
+                            result.push(parse_octal_string(&current_number)?);
+                            current_number.clear();
+                        }
+                    } else {
+                        current_number.push('0');
+                    }
+                } else {
+                    current_number.push('0');
+                }
+
+                // 3자리가 모이면 바이트로 변환
+                if current_number.len() == 3 {
+                    result.push(parse_octal_string(&current_number)?);
+                    current_number.clear();
+                }
+            }
+            'o' | 'O' => {
+                if !current_number.is_empty() {
+                    result.push(parse_octal_string(&current_number)?);
+                    current_number.clear();
+                }
+                chars.next(); // 'o' 건너뛰기
+                current_number = collect_octal_digits(&mut chars)?;
+                if !current_number.is_empty() {
+                    result.push(parse_octal_string(&current_number)?);
+                    current_number.clear();
+                }
+            }
+            // 8진수 숫자 수집
+            '0'..='7' => {
+                current_number.push(chars.next().unwrap());
+                // 3자리가 모이면 바이트로 변환 (8진수 377 = 255)
+                if current_number.len() == 3 {
+                    result.push(parse_octal_string(&current_number)?);
+                    current_number.clear();
+                }
+            }
+            _ => {
+                return Err(format!("Invalid character '{}' in octal input. Only 0-7 are allowed.", c));
+            }
+        }
+    }
+
+    // 남은 숫자 처리
+    if !current_number.is_empty() {
+        if current_number.len() > 3 {
+            return Err(format!("Octal sequence '{}' is longer than 3 digits", current_number));
+        }
+        result.push(parse_octal_string(&current_number)?);
+    }
+
+    if result.is_empty() {
+        return Err("No valid octal values found in input".to_string());
+    }
+
+    Ok(result)
+}
+
+fn collect_octal_digits(
+    chars: &mut std::iter::Peekable<std::str::Chars>,
+) -> Result<String, String> {
+    let mut octal_str = String::new();
+
+    while let Some(&c) = chars.peek() {
+        if ('0'..='7').contains(&c) {
+            octal_str.push(chars.next().unwrap());
+            if octal_str.len() == 3 {
+                break;
+            }
+        } else {
+            break;
+        }
+    }
+
+    if octal_str.is_empty() {
+        return Err("Expected octal digits after prefix".to_string());
+    }
+
+    Ok(octal_str)
+}
+
+fn parse_octal_string(octal_str: &str) -> Result<u8, String> {
+    if octal_str.len() > 3 {
+        return Err(format!("Octal sequence '{}' exceeds 3 digits", octal_str));
+    }
+    u8::from_str_radix(octal_str, 8).map_err(|_| format!("Invalid octal value: '{}'", octal_str))
+}
+
+/// 바이트 배열을 "0x12 0x34" 형태의 문자열로 변환합니다.
+pub fn bytes_to_string(bytes: &[u8]) -> String {
+    bytes
+        .iter()
+        .map(|byte| format!("0x{:02X}", byte))
+        .collect::<Vec<String>>()
+        .join(" ")
+}
+
+/// CRC 결과 출력 형식 설정
+#[derive(Debug, Clone, PartialEq)]
+pub struct OutputOptions {
+    pub output_mode: OutputMode,
+    pub hex_style: HexStyle,
+    pub binary_style: BinaryStyle,
+    pub octal_style: OctalStyle,
+    pub endianness: Endianness,
+    pub byte_formatting: ByteFormatting,
+}
+
+impl Default for OutputOptions {
+    fn default() -> Self {
+        Self {
+            output_mode: OutputMode::Hex,
+            hex_style: HexStyle::WithPrefix,
+            binary_style: BinaryStyle::WithPrefix,
+            octal_style: OctalStyle::WithPrefix,
+            endianness: Endianness::BigEndian,
+            byte_formatting: ByteFormatting::Continuous,
+        }
+    }
+}
+
+/// 엔디안에 따라 CRC 값의 바이트 순서를 조정합니다.
+pub fn apply_endianness(crc_result: u64, width: u8, endianness: &Endianness) -> u64 {
+    match endianness {
+        Endianness::BigEndian => crc_result,
+        Endianness::LittleEndian => {
+            match width {
+                8 => crc_result, // 8비트는 엔디안 무관
+                16 => ((crc_result & 0xFF) << 8) | ((crc_result >> 8) & 0xFF),
+                24 => {
+                    let b0 = (crc_result >> 16) & 0xFF;
+                    let b1 = (crc_result >> 8) & 0xFF;
+                    let b2 = crc_result & 0xFF;
+                    (b2 << 16) | (b1 << 8) | b0
+                }
+                32 => {
+                    let b0 = (crc_result >> 24) & 0xFF;
+                    let b1 = (crc_result >> 16) & 0xFF;
+                    let b2 = (crc_result >> 8) & 0xFF;
+                    let b3 = crc_result & 0xFF;
+                    (b3 << 24) | (b2 << 16) | (b1 << 8) | b0
+                }
+                64 => {
+                    let mut result = 0u64;
+                    for i in 0..8 {
+                        let byte = (crc_result >> (i * 8)) & 0xFF;
+                        result |= byte << ((7 - i) * 8);
+                    }
+                    result
+                }
+                _ => {
+                    // 다른 비트 크기에 대한 일반적인 처리
+                    let bytes = width.div_ceil(8) as usize;
+                    let mut result = 0u64;
+                    for i in 0..bytes {
+                        let byte = (crc_result >> (i * 8)) & 0xFF;
+                        result |= byte << (((bytes - 1 - i) as u64) * 8);
+                    }
+                    result
+                }
+            }
+        }
+    }
+}
+
+/// CRC 값을 출력 설정에 맞춰 문자열로 변환합니다.
+pub fn format_crc_output(crc_result: u64, width: u8, options: &OutputOptions) -> String {
+    let crc_value = apply_endianness(crc_result, width, &options.endianness);
+
+    match options.output_mode {
+        OutputMode::Decimal => format!("{}", crc_value),
+        OutputMode::Hex => format_hex_output(crc_value, width, options),
+        OutputMode::Binary => format_binary_output(crc_value, width, options),
+        OutputMode::Octal => format_octal_output(crc_value, width, options),
+    }
+}
+
+fn format_hex_output(value: u64, width: u8, options: &OutputOptions) -> String {
+    let bytes = width.div_ceil(8) as usize;
+
+    match options.byte_formatting {
+        ByteFormatting::Continuous => {
+            let digits = bytes * 2;
+            match options.hex_style {
+                HexStyle::WithPrefix => format!("0x{:0width$X}", value, width = digits),
+                HexStyle::ShortPrefix => format!("x{:0width$X}", value, width = digits),
+                HexStyle::NoPrefix => format!("{:0width$X}", value, width = digits),
+                HexStyle::EscapeSequence => format!("\\x{:0width$X}", value, width = digits),
+            }
+        }
+        ByteFormatting::ByteSeparated => {
+            let mut byte_strings = Vec::new();
+            for i in 0..bytes {
+                let byte = (value >> ((bytes - 1 - i) * 8)) & 0xFF;
+                let byte_str = match options.hex_style {
+                    HexStyle::WithPrefix => format!("0x{:02X}", byte),
+                    HexStyle::ShortPrefix => format!("x{:02X}", byte),
+                    HexStyle::NoPrefix => format!("{:02X}", byte),
+                    HexStyle::EscapeSequence => format!("\\x{:02X}", byte),
+                };
+                byte_strings.push(byte_str);
+            }
+            byte_strings.join(" ")
+        }
+    }
+}
+
+fn format_binary_output(value: u64, width: u8, options: &OutputOptions) -> String {
+    match options.byte_formatting {
+        ByteFormatting::Continuous => {
+            let binary_str = format!("{:0width$b}", value, width = width as usize);
+            match options.binary_style {
+                BinaryStyle::WithPrefix => format!("0b{}", binary_str),
+                BinaryStyle::ShortPrefix => format!("b{}", binary_str),
+                BinaryStyle::NoPrefix => binary_str,
+            }
+        }
+        ByteFormatting::ByteSeparated => {
+            let bytes = width.div_ceil(8) as usize;
+            let mut byte_strings = Vec::new();
+            for i in 0..bytes {
+                let byte = (value >> ((bytes - 1 - i) * 8)) & 0xFF;
+                let byte_str = match options.binary_style {
+                    BinaryStyle::WithPrefix => format!("0b{:08b}", byte),
+                    BinaryStyle::ShortPrefix => format!("b{:08b}", byte),
+                    BinaryStyle::NoPrefix => format!("{:08b}", byte),
+                };
+                byte_strings.push(byte_str);
+            }
+            byte_strings.join(" ")
+        }
+    }
+}
+
+fn format_octal_output(value: u64, width: u8, options: &OutputOptions) -> String {
+    let bytes = width.div_ceil(8) as usize;
+
+    match options.byte_formatting {
+        ByteFormatting::Continuous => {
+            let digits = ((width as f32 / 3.0).ceil()) as usize;
+            match options.octal_style {
+                OctalStyle::WithPrefix => format!("0o{:0width$o}", value, width = digits),
+                OctalStyle::ShortPrefix => format!("o{:0width$o}", value, width = digits),
+                OctalStyle::NoPrefix => format!("{:0width$o}", value, width = digits),
+                OctalStyle::EscapeSequence => format!("\\{:0width$o}", value, width = digits),
+            }
+        }
+        ByteFormatting::ByteSeparated => {
+            let mut byte_strings = Vec::new();
+            for i in 0..bytes {
+                let byte = (value >> ((bytes - 1 - i) * 8)) & 0xFF;
+                let byte_str = match options.octal_style {
+                    OctalStyle::WithPrefix => format!("0o{:03o}", byte),
+                    OctalStyle::ShortPrefix => format!("o{:03o}", byte),
+                    OctalStyle::NoPrefix => format!("{:03o}", byte),
+                    OctalStyle::EscapeSequence => format!("\\{:03o}", byte),
+                };
+                byte_strings.push(byte_str);
+            }
+            byte_strings.join(" ")
+        }
+    }
+}
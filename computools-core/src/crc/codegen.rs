@@ -0,0 +1,620 @@
+//! CRC 구현 코드 생성기
+//!
+//! 선택한 파라미터로 C, Rust, Python의 테이블/비트 단위 구현과
+//! 한 클럭에 1바이트를 처리하는 병렬 Verilog/VHDL 모듈을 만듭니다.
+//!
+//! 소프트웨어 구현은 `crc` 크레이트와 같은 방식을 따릅니다. refin이면 반사된 레지스터를
+//! 오른쪽으로 밀고, 아니면 레지스터를 8/16/32/64비트 타입의 최상위 쪽에 맞춰 왼쪽으로 밉니다.
+
+use std::fmt::Write;
+
+use super::{CrcAlgorithm, CrcParams};
+
+/// 생성할 언어
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CodeLanguage {
+    C,
+    Rust,
+    Python,
+    Verilog,
+    Vhdl,
+}
+
+/// 소프트웨어 구현 방식. HDL은 항상 병렬(조합 논리) 구현이므로 무시합니다.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CodeStyle {
+    TableDriven,
+    Bitwise,
+}
+
+impl CodeLanguage {
+    pub fn all() -> [CodeLanguage; 5] {
+        [
+            CodeLanguage::C,
+            CodeLanguage::Rust,
+            CodeLanguage::Python,
+            CodeLanguage::Verilog,
+            CodeLanguage::Vhdl,
+        ]
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            CodeLanguage::C => "C",
+            CodeLanguage::Rust => "Rust",
+            CodeLanguage::Python => "Python",
+            CodeLanguage::Verilog => "Verilog",
+            CodeLanguage::Vhdl => "VHDL",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        CodeLanguage::all()
+            .into_iter()
+            .find(|language| language.name().eq_ignore_ascii_case(name))
+    }
+
+    pub fn is_hdl(&self) -> bool {
+        matches!(self, CodeLanguage::Verilog | CodeLanguage::Vhdl)
+    }
+}
+
+/// 생성 코드에 표시할 알고리즘 이름. Custom은 폭을 붙여 "CRC-16/CUSTOM"처럼 만듭니다.
+pub fn code_name(algorithm: &CrcAlgorithm) -> String {
+    match algorithm {
+        CrcAlgorithm::Custom(params) => format!("CRC-{}/CUSTOM", params.width),
+        other => other.name().to_string(),
+    }
+}
+
+/// "CRC-16/MODBUS" → "crc16_modbus" 처럼 코드에서 쓸 식별자를 만듭니다.
+pub fn identifier(name: &str) -> String {
+    let lower = name.to_lowercase();
+    let lower = lower.strip_prefix("crc-").map(|rest| format!("crc{}", rest)).unwrap_or(lower);
+
+    let mut identifier = String::new();
+    for c in lower.chars() {
+        if c.is_ascii_alphanumeric() {
+            identifier.push(c);
+        } else if !identifier.ends_with('_') {
+            identifier.push('_');
+        }
+    }
+    let identifier = identifier.trim_matches('_').to_string();
+
+    match identifier.chars().next() {
+        Some(first) if first.is_ascii_alphabetic() => identifier,
+        _ => format!("crc_{}", identifier),
+    }
+}
+
+/// 생성 코드가 쓰는 레지스터 타입의 비트 수 (8, 16, 32, 64)
+pub fn register_bits(width: u8) -> u32 {
+    match width {
+        0..=8 => 8,
+        9..=16 => 16,
+        17..=32 => 32,
+        _ => 64,
+    }
+}
+
+/// 생성 코드와 같은 규칙으로 만든 256개 항목의 룩업 테이블
+///
+/// refin이면 반사된 다항식으로, 아니면 레지스터 타입의 최상위 쪽에 맞춘 다항식으로 계산합니다.
+pub fn lookup_table(params: &CrcParams) -> Vec<u64> {
+    let layout = Layout::new(params);
+    (0..256u64)
+        .map(|index| {
+            if params.refin {
+                let mut crc = index;
+                for _ in 0..8 {
+                    crc = if crc & 1 != 0 { (crc >> 1) ^ layout.poly } else { crc >> 1 };
+                }
+                crc
+            } else {
+                let mut crc = index << (layout.bits - 8);
+                for _ in 0..8 {
+                    crc = if crc & layout.top_bit != 0 {
+                        (crc << 1) ^ layout.poly
+                    } else {
+                        crc << 1
+                    } & layout.type_mask;
+                }
+                crc
+            }
+        })
+        .collect()
+}
+
+/// 한 바이트 처리 후 레지스터 각 비트의 XOR 식
+///
+/// `next[i]`는 (`crc_mask`에 켜진 현재 레지스터 비트) XOR (`data_mask`에 켜진 입력 바이트 비트)입니다.
+/// 레지스터는 반사하지 않은 Rocksoft 모델 그대로이며, refin은 입력 비트 순서에 반영됩니다.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct ParallelTerm {
+    pub crc_mask: u64,
+    pub data_mask: u8,
+}
+
+pub fn parallel_equations(params: &CrcParams) -> Vec<ParallelTerm> {
+    let width = params.width.clamp(1, 64) as usize;
+    let poly = params.poly & params.mask();
+    let mut register: Vec<ParallelTerm> = (0..width)
+        .map(|bit| ParallelTerm {
+            crc_mask: 1 << bit,
+            data_mask: 0,
+        })
+        .collect();
+
+    // 먼저 처리되는 비트: refin이면 LSB, 아니면 MSB
+    for step in 0..8 {
+        let data_bit = if params.refin { step } else { 7 - step };
+        let top = register[width - 1];
+        let feedback = ParallelTerm {
+            crc_mask: top.crc_mask,
+            data_mask: top.data_mask ^ (1 << data_bit),
+        };
+
+        register.rotate_right(1);
+        register[0] = ParallelTerm::default();
+        for (bit, term) in register.iter_mut().enumerate() {
+            if poly >> bit & 1 == 1 {
+                term.crc_mask ^= feedback.crc_mask;
+                term.data_mask ^= feedback.data_mask;
+            }
+        }
+    }
+    register
+}
+
+/// 선택한 언어와 방식으로 CRC 구현 코드를 생성합니다. `name`은 함수/모듈 이름의 바탕이 됩니다.
+pub fn generate(params: &CrcParams, name: &str, language: CodeLanguage, style: CodeStyle) -> String {
+    let generator = Generator {
+        params: *params,
+        name: name.to_string(),
+        ident: identifier(name),
+        layout: Layout::new(params),
+    };
+    match language {
+        CodeLanguage::C => generator.c(style),
+        CodeLanguage::Rust => generator.rust(style),
+        CodeLanguage::Python => generator.python(style),
+        CodeLanguage::Verilog => generator.verilog(),
+        CodeLanguage::Vhdl => generator.vhdl(),
+    }
+}
+
+/// 룩업 테이블을 한 줄에 8개씩 16진수로 나열합니다.
+pub fn format_table(params: &CrcParams) -> String {
+    let layout = Layout::new(params);
+    let digits = layout.bits as usize / 4;
+    lookup_table(params)
+        .chunks(8)
+        .map(|row| {
+            row.iter()
+                .map(|value| format!("0x{:0digits$X}", value, digits = digits))
+                .collect::<Vec<String>>()
+                .join(", ")
+        })
+        .collect::<Vec<String>>()
+        .join(",\n")
+}
+
+/// 레지스터 타입과 정렬 방식에 따른 상수
+struct Layout {
+    bits: u32,
+    /// 비반사 구현에서 레지스터를 왼쪽으로 맞춘 만큼의 비트 수
+    shift: u32,
+    type_mask: u64,
+    top_bit: u64,
+    /// 반사 구현이면 반사된 다항식, 아니면 왼쪽으로 맞춘 다항식
+    poly: u64,
+    /// 구현에서 쓰는 레지스터 초깃값
+    init: u64,
+}
+
+impl Layout {
+    fn new(params: &CrcParams) -> Self {
+        let width = params.width.clamp(1, 64);
+        let bits = register_bits(width);
+        let shift = bits - width as u32;
+        let type_mask = u64::MAX >> (64 - bits);
+        let (poly, init) = if params.refin {
+            (reflect(params.poly, width), reflect(params.init, width))
+        } else {
+            (params.poly << shift, params.init << shift)
+        };
+
+        Self {
+            bits,
+            shift,
+            type_mask,
+            top_bit: 1 << (bits - 1),
+            poly: poly & type_mask,
+            init: init & type_mask,
+        }
+    }
+}
+
+fn reflect(value: u64, width: u8) -> u64 {
+    value.reverse_bits() >> (64 - width as u32)
+}
+
+struct Generator {
+    params: CrcParams,
+    name: String,
+    ident: String,
+    layout: Layout,
+}
+
+impl Generator {
+    fn hex(&self, value: u64) -> String {
+        format!("0x{:0digits$X}", value, digits = self.layout.bits as usize / 4)
+    }
+
+    fn width_hex(&self, value: u64) -> String {
+        format!("0x{:0digits$X}", value, digits = self.params.width.div_ceil(4) as usize)
+    }
+
+    /// 마지막에 출력 반사가 필요한지 (반사 구현의 레지스터는 이미 반사되어 있음)
+    fn needs_reflect(&self) -> bool {
+        self.params.refin != self.params.refout
+    }
+
+    fn header(&self, comment: &str) -> String {
+        format!("{} {}: {}\n", comment, self.name, self.params.describe())
+    }
+
+    fn table_lines(&self, indent: &str) -> String {
+        format_table(&self.params)
+            .lines()
+            .map(|line| format!("{}{}", indent, line))
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+
+    fn c(&self, style: CodeStyle) -> String {
+        let ty = format!("uint{}_t", self.layout.bits);
+        let id = &self.ident;
+        let mut out = String::new();
+
+        out.push_str(&self.header("//"));
+        out.push_str("#include <stddef.h>\n#include <stdint.h>\n\n");
+
+        if self.needs_reflect() {
+            let _ = writeln!(out, "static {ty} {id}_reflect({ty} value)\n{{");
+            let _ = writeln!(out, "    {ty} result = 0;");
+            let _ = writeln!(out, "    for (int i = 0; i < {}; i++) {{", self.params.width);
+            let _ = writeln!(out, "        result = (result << 1) | (value & 1);\n        value >>= 1;\n    }}");
+            let _ = writeln!(out, "    return result;\n}}\n");
+        }
+
+        if style == CodeStyle::TableDriven {
+            let _ = writeln!(out, "static const {ty} {id}_table[256] = {{");
+            let _ = writeln!(out, "{}", self.table_lines("    "));
+            let _ = writeln!(out, "}};\n");
+        }
+
+        let _ = writeln!(out, "{ty} {id}(const uint8_t *data, size_t len)\n{{");
+        let _ = writeln!(out, "    {ty} crc = {};\n", self.hex(self.layout.init));
+        let _ = writeln!(out, "    while (len--) {{");
+        match (style, self.params.refin, self.layout.bits) {
+            (CodeStyle::TableDriven, true, 8) | (CodeStyle::TableDriven, false, 8) => {
+                let _ = writeln!(out, "        crc = {id}_table[crc ^ *data++];");
+            }
+            (CodeStyle::TableDriven, true, _) => {
+                let _ = writeln!(out, "        crc = (crc >> 8) ^ {id}_table[(crc ^ *data++) & 0xFF];");
+            }
+            (CodeStyle::TableDriven, false, bits) => {
+                let _ = writeln!(
+                    out,
+                    "        crc = (crc << 8) ^ {id}_table[((crc >> {}) ^ *data++) & 0xFF];",
+                    bits - 8
+                );
+            }
+            (CodeStyle::Bitwise, true, _) => {
+                let _ = writeln!(out, "        crc ^= *data++;");
+                let _ = writeln!(out, "        for (int i = 0; i < 8; i++) {{");
+                let _ = writeln!(
+                    out,
+                    "            crc = (crc & 1) ? (crc >> 1) ^ {} : crc >> 1;",
+                    self.hex(self.layout.poly)
+                );
+                let _ = writeln!(out, "        }}");
+            }
+            (CodeStyle::Bitwise, false, bits) => {
+                if bits == 8 {
+                    let _ = writeln!(out, "        crc ^= *data++;");
+                } else {
+                    let _ = writeln!(out, "        crc ^= ({ty})*data++ << {};", bits - 8);
+                }
+                let _ = writeln!(out, "        for (int i = 0; i < 8; i++) {{");
+                let _ = writeln!(
+                    out,
+                    "            crc = (crc & {}) ? (crc << 1) ^ {} : crc << 1;",
+                    self.hex(self.layout.top_bit),
+                    self.hex(self.layout.poly)
+                );
+                let _ = writeln!(out, "        }}");
+            }
+        }
+        let _ = writeln!(out, "    }}\n");
+
+        if !self.params.refin && self.layout.shift > 0 {
+            let _ = writeln!(out, "    crc >>= {};", self.layout.shift);
+        }
+        if self.needs_reflect() {
+            let _ = writeln!(out, "    crc = {id}_reflect(crc);");
+        }
+        let _ = writeln!(out, "    return crc ^ {};\n}}", self.width_hex(self.params.xorout));
+        out
+    }
+
+    fn rust(&self, style: CodeStyle) -> String {
+        let ty = format!("u{}", self.layout.bits);
+        let id = &self.ident;
+        let upper = id.to_uppercase();
+        let mut out = String::new();
+
+        out.push_str(&self.header("//"));
+
+        if style == CodeStyle::TableDriven {
+            let _ = writeln!(out, "const {upper}_TABLE: [{ty}; 256] = [");
+            let _ = writeln!(out, "{}", self.table_lines("    "));
+            let _ = writeln!(out, "];\n");
+        }
+
+        let _ = writeln!(out, "pub fn {id}(data: &[u8]) -> {ty} {{");
+        let _ = writeln!(out, "    let mut crc: {ty} = {};", self.hex(self.layout.init));
+        let _ = writeln!(out, "    for &byte in data {{");
+        match (style, self.params.refin, self.layout.bits) {
+            (CodeStyle::TableDriven, _, 8) => {
+                let _ = writeln!(out, "        crc = {upper}_TABLE[(crc ^ byte) as usize];");
+            }
+            (CodeStyle::TableDriven, true, _) => {
+                let _ = writeln!(
+                    out,
+                    "        crc = (crc >> 8) ^ {upper}_TABLE[((crc as u8) ^ byte) as usize];"
+                );
+            }
+            (CodeStyle::TableDriven, false, bits) => {
+                let _ = writeln!(
+                    out,
+                    "        crc = (crc << 8) ^ {upper}_TABLE[(((crc >> {}) as u8) ^ byte) as usize];",
+                    bits - 8
+                );
+            }
+            (CodeStyle::Bitwise, true, _) => {
+                if self.layout.bits == 8 {
+                    let _ = writeln!(out, "        crc ^= byte;");
+                } else {
+                    let _ = writeln!(out, "        crc ^= byte as {ty};");
+                }
+                let _ = writeln!(out, "        for _ in 0..8 {{");
+                let _ = writeln!(
+                    out,
+                    "            crc = if crc & 1 != 0 {{ (crc >> 1) ^ {} }} else {{ crc >> 1 }};",
+                    self.hex(self.layout.poly)
+                );
+                let _ = writeln!(out, "        }}");
+            }
+            (CodeStyle::Bitwise, false, bits) => {
+                if bits == 8 {
+                    let _ = writeln!(out, "        crc ^= byte;");
+                } else {
+                    let _ = writeln!(out, "        crc ^= (byte as {ty}) << {};", bits - 8);
+                }
+                let _ = writeln!(out, "        for _ in 0..8 {{");
+                let _ = writeln!(
+                    out,
+                    "            crc = if crc & {} != 0 {{ (crc << 1) ^ {} }} else {{ crc << 1 }};",
+                    self.hex(self.layout.top_bit),
+                    self.hex(self.layout.poly)
+                );
+                let _ = writeln!(out, "        }}");
+            }
+        }
+        let _ = writeln!(out, "    }}");
+
+        if !self.params.refin && self.layout.shift > 0 {
+            let _ = writeln!(out, "    crc >>= {};", self.layout.shift);
+        }
+        if self.needs_reflect() {
+            let _ = writeln!(
+                out,
+                "    crc = crc.reverse_bits() >> {};",
+                self.layout.bits - self.params.width as u32
+            );
+        }
+        let _ = writeln!(out, "    crc ^ {}\n}}", self.width_hex(self.params.xorout));
+        out
+    }
+
+    fn python(&self, style: CodeStyle) -> String {
+        let id = &self.ident;
+        let upper = id.to_uppercase();
+        let mask = self.hex(self.layout.type_mask);
+        let mut out = String::new();
+
+        out.push_str(&self.header("#"));
+        out.push('\n');
+
+        if style == CodeStyle::TableDriven {
+            let _ = writeln!(out, "{upper}_TABLE = [");
+            let _ = writeln!(out, "{},", self.table_lines("    "));
+            let _ = writeln!(out, "]\n\n");
+        }
+
+        let _ = writeln!(out, "def {id}(data: bytes) -> int:");
+        let _ = writeln!(out, "    crc = {}", self.hex(self.layout.init));
+        let _ = writeln!(out, "    for byte in data:");
+        match (style, self.params.refin) {
+            (CodeStyle::TableDriven, true) => {
+                let _ = writeln!(out, "        crc = (crc >> 8) ^ {upper}_TABLE[(crc ^ byte) & 0xFF]");
+            }
+            (CodeStyle::TableDriven, false) => {
+                let _ = writeln!(
+                    out,
+                    "        crc = ((crc << 8) & {mask}) ^ {upper}_TABLE[((crc >> {}) ^ byte) & 0xFF]",
+                    self.layout.bits - 8
+                );
+            }
+            (CodeStyle::Bitwise, true) => {
+                let _ = writeln!(out, "        crc ^= byte");
+                let _ = writeln!(out, "        for _ in range(8):");
+                let _ = writeln!(
+                    out,
+                    "            crc = (crc >> 1) ^ {} if crc & 1 else crc >> 1",
+                    self.hex(self.layout.poly)
+                );
+            }
+            (CodeStyle::Bitwise, false) => {
+                if self.layout.bits == 8 {
+                    let _ = writeln!(out, "        crc ^= byte");
+                } else {
+                    let _ = writeln!(out, "        crc ^= byte << {}", self.layout.bits - 8);
+                }
+                let _ = writeln!(out, "        for _ in range(8):");
+                let _ = writeln!(
+                    out,
+                    "            crc = ((crc << 1) ^ {}) & {mask} if crc & {} else (crc << 1) & {mask}",
+                    self.hex(self.layout.poly),
+                    self.hex(self.layout.top_bit)
+                );
+            }
+        }
+
+        if !self.params.refin && self.layout.shift > 0 {
+            let _ = writeln!(out, "    crc >>= {}", self.layout.shift);
+        }
+        if self.needs_reflect() {
+            let _ = writeln!(
+                out,
+                "    crc = int(format(crc, '0{}b')[::-1], 2)",
+                self.params.width
+            );
+        }
+        let _ = writeln!(out, "    return crc ^ {}", self.width_hex(self.params.xorout));
+        out
+    }
+
+    /// 출력 비트 i에 해당하는 레지스터 비트 (refout이면 뒤집힘)
+    fn output_bit(&self, bit: usize) -> usize {
+        if self.params.refout {
+            self.params.width as usize - 1 - bit
+        } else {
+            bit
+        }
+    }
+
+    fn verilog(&self) -> String {
+        let width = self.params.width as usize;
+        let id = &self.ident;
+        let mut out = String::new();
+
+        out.push_str(&self.header("//"));
+        let _ = writeln!(out, "// Processes one byte per clock while data_valid is high.");
+        let crc_range = format!("[{}:0]", width - 1);
+        let _ = writeln!(out, "module {id} (");
+        let _ = writeln!(out, "    input  wire {:<8} clk,", "");
+        let _ = writeln!(out, "    input  wire {:<8} rst,        // synchronous, loads init", "");
+        let _ = writeln!(out, "    input  wire {:<8} data_valid,", "");
+        let _ = writeln!(out, "    input  wire {:<8} data_in,", "[7:0]");
+        let _ = writeln!(out, "    output wire {:<8} crc_out", crc_range);
+        let _ = writeln!(out, ");");
+        let _ = writeln!(out, "    reg  {} crc;", crc_range);
+        let _ = writeln!(out, "    wire {} next;\n", crc_range);
+
+        for (bit, term) in parallel_equations(&self.params).iter().enumerate() {
+            let operands = self.operands(term, |i| format!("crc[{}]", i), |i| format!("data_in[{}]", i));
+            let expression = if operands.is_empty() { "1'b0".to_string() } else { operands.join(" ^ ") };
+            let _ = writeln!(out, "    assign next[{}] = {};", bit, expression);
+        }
+
+        let _ = writeln!(out, "\n    always @(posedge clk) begin");
+        let _ = writeln!(out, "        if (rst)");
+        let _ = writeln!(out, "            crc <= {}'h{:X};", width, self.params.init);
+        let _ = writeln!(out, "        else if (data_valid)");
+        let _ = writeln!(out, "            crc <= next;");
+        let _ = writeln!(out, "    end\n");
+
+        let output_bits: Vec<String> = (0..width)
+            .rev()
+            .map(|bit| format!("crc[{}]", self.output_bit(bit)))
+            .collect();
+        let _ = writeln!(
+            out,
+            "    assign crc_out = {{{}}} ^ {}'h{:X};",
+            output_bits.join(", "),
+            width,
+            self.params.xorout
+        );
+        let _ = writeln!(out, "endmodule");
+        out
+    }
+
+    fn vhdl(&self) -> String {
+        let width = self.params.width as usize;
+        let id = &self.ident;
+        let mut out = String::new();
+
+        out.push_str(&self.header("--"));
+        let _ = writeln!(out, "-- Processes one byte per clock while data_valid is '1'.");
+        let _ = writeln!(out, "library ieee;\nuse ieee.std_logic_1164.all;\n");
+        let _ = writeln!(out, "entity {id} is");
+        let _ = writeln!(out, "    port (");
+        let _ = writeln!(out, "        clk        : in  std_logic;");
+        let _ = writeln!(out, "        rst        : in  std_logic;  -- synchronous, loads init");
+        let _ = writeln!(out, "        data_valid : in  std_logic;");
+        let _ = writeln!(out, "        data_in    : in  std_logic_vector(7 downto 0);");
+        let _ = writeln!(out, "        crc_out    : out std_logic_vector({} downto 0)", width - 1);
+        let _ = writeln!(out, "    );");
+        let _ = writeln!(out, "end entity;\n");
+        let _ = writeln!(out, "architecture rtl of {id} is");
+        let _ = writeln!(out, "    signal crc      : std_logic_vector({} downto 0);", width - 1);
+        let _ = writeln!(out, "    signal next_crc : std_logic_vector({} downto 0);", width - 1);
+        let _ = writeln!(out, "begin");
+
+        for (bit, term) in parallel_equations(&self.params).iter().enumerate() {
+            let operands = self.operands(term, |i| format!("crc({})", i), |i| format!("data_in({})", i));
+            let expression = if operands.is_empty() { "'0'".to_string() } else { operands.join(" xor ") };
+            let _ = writeln!(out, "    next_crc({}) <= {};", bit, expression);
+        }
+
+        let _ = writeln!(out, "\n    process (clk)");
+        let _ = writeln!(out, "    begin");
+        let _ = writeln!(out, "        if rising_edge(clk) then");
+        let _ = writeln!(out, "            if rst = '1' then");
+        let _ = writeln!(out, "                crc <= \"{}\";", self.bit_string(self.params.init));
+        let _ = writeln!(out, "            elsif data_valid = '1' then");
+        let _ = writeln!(out, "                crc <= next_crc;");
+        let _ = writeln!(out, "            end if;");
+        let _ = writeln!(out, "        end if;");
+        let _ = writeln!(out, "    end process;\n");
+
+        for bit in 0..width {
+            let xor_bit = if self.params.xorout >> bit & 1 == 1 { " xor '1'" } else { "" };
+            let _ = writeln!(out, "    crc_out({}) <= crc({}){};", bit, self.output_bit(bit), xor_bit);
+        }
+        let _ = writeln!(out, "end architecture;");
+        out
+    }
+
+    fn operands(
+        &self,
+        term: &ParallelTerm,
+        crc_bit: impl Fn(usize) -> String,
+        data_bit: impl Fn(usize) -> String,
+    ) -> Vec<String> {
+        let crc_bits = (0..self.params.width as usize).filter(|i| term.crc_mask >> i & 1 == 1).map(crc_bit);
+        let data_bits = (0..8).filter(|i| term.data_mask >> i & 1 == 1).map(data_bit);
+        crc_bits.chain(data_bits).collect()
+    }
+
+    fn bit_string(&self, value: u64) -> String {
+        (0..self.params.width)
+            .rev()
+            .map(|bit| if value >> bit & 1 == 1 { '1' } else { '0' })
+            .collect()
+    }
+}
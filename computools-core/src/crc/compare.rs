@@ -0,0 +1,78 @@
+//! 모든 카탈로그 알고리즘으로 같은 입력의 CRC를 계산해 비교합니다.
+
+use super::{format_crc_output, CrcAlgorithm, OutputOptions};
+
+/// 비교 표의 한 행
+#[derive(Debug, Clone, PartialEq)]
+pub struct CrcComparison {
+    pub algorithm: CrcAlgorithm,
+    pub crc: u64,
+    pub width: u8,
+}
+
+/// 비교 표 정렬 기준
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ComparisonSort {
+    Name,
+    Width,
+    Value,
+}
+
+/// 카탈로그의 모든 알고리즘(또는 `width` 폭의 알고리즘만)으로 CRC를 계산합니다.
+pub fn calculate_all(data: &[u8], width: Option<u8>) -> Vec<CrcComparison> {
+    CrcAlgorithm::all()
+        .into_iter()
+        .filter(|algorithm| width.is_none_or(|width| algorithm.params().width == width))
+        .map(|algorithm| {
+            let (crc, width) = algorithm.calculate(data);
+            CrcComparison { algorithm, crc, width }
+        })
+        .collect()
+}
+
+/// 카탈로그에 있는 폭 목록 (오름차순, 중복 없음)
+pub fn catalogue_widths() -> Vec<u8> {
+    let mut widths: Vec<u8> = CrcAlgorithm::all()
+        .iter()
+        .map(|algorithm| algorithm.params().width)
+        .collect();
+    widths.sort_unstable();
+    widths.dedup();
+    widths
+}
+
+/// 비교 표를 정렬합니다. 같은 값끼리는 이름순입니다.
+pub fn sort_comparisons(rows: &mut [CrcComparison], sort: ComparisonSort, descending: bool) {
+    rows.sort_by(|a, b| {
+        let ordering = match sort {
+            ComparisonSort::Name => a.algorithm.name().cmp(b.algorithm.name()),
+            ComparisonSort::Width => a.width.cmp(&b.width).then_with(|| a.algorithm.name().cmp(b.algorithm.name())),
+            ComparisonSort::Value => a.crc.cmp(&b.crc).then_with(|| a.algorithm.name().cmp(b.algorithm.name())),
+        };
+        if descending {
+            ordering.reverse()
+        } else {
+            ordering
+        }
+    });
+}
+
+/// 검색어가 알고리즘 이름이나 현재 출력 형식으로 표시된 결과 값에 포함되는지 확인합니다.
+///
+/// 대소문자와 공백은 무시하므로 "0xCBF4 3926", "cbf43926" 모두 CRC-32 결과를 찾습니다.
+pub fn matches_query(row: &CrcComparison, query: &str, options: &OutputOptions) -> bool {
+    let query = normalize(query);
+    if query.is_empty() {
+        return true;
+    }
+
+    normalize(row.algorithm.name()).contains(&query)
+        || normalize(&format_crc_output(row.crc, row.width, options)).contains(&query)
+}
+
+fn normalize(text: &str) -> String {
+    text.chars()
+        .filter(|c| !c.is_whitespace())
+        .flat_map(char::to_lowercase)
+        .collect()
+}
@@ -0,0 +1,142 @@
+//! CRC 강제(forcing)
+//!
+//! 메시지의 끝에 덧붙이거나 지정한 위치에 덮어쓸 바이트를 계산해서 전체 CRC가 원하는 값이
+//! 되도록 만듭니다. 부트로더가 고정된 CRC를 검사하는 펌웨어 이미지를 패치할 때 씁니다.
+//!
+//! 길이가 같은 메시지에서 CRC는 데이터 비트에 대해 아핀이므로, 패치 위치의 비트 하나를
+//! 뒤집었을 때 CRC가 바뀌는 양(열 벡터)을 모아 GF(2) 위의 연립방정식을 풉니다.
+
+use super::CrcAlgorithm;
+
+/// 패치 바이트를 넣을 위치
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PatchPosition {
+    /// 메시지 끝에 덧붙임
+    Append,
+    /// 주어진 오프셋부터 기존 바이트를 덮어씀
+    Overwrite(usize),
+}
+
+/// 강제 결과
+#[derive(Debug, Clone, PartialEq)]
+pub struct ForcedCrc {
+    /// 패치 바이트가 들어간 위치
+    pub offset: usize,
+    /// 넣어야 할 바이트
+    pub patch: Vec<u8>,
+    /// 패치를 적용한 전체 메시지
+    pub data: Vec<u8>,
+}
+
+/// 폭이 `width`인 CRC를 강제하는 데 필요한 바이트 수
+pub fn patch_len(width: u8) -> usize {
+    width.div_ceil(8) as usize
+}
+
+/// `data`의 CRC가 `target`이 되도록 `position`에 넣을 바이트를 계산합니다.
+pub fn force(
+    algorithm: &CrcAlgorithm,
+    data: &[u8],
+    target: u64,
+    position: PatchPosition,
+) -> Result<ForcedCrc, String> {
+    let params = algorithm.params();
+    if target & !params.mask() != 0 {
+        return Err(format!("Target 0x{:X} does not fit in {} bits.", target, params.width));
+    }
+
+    let len = patch_len(params.width);
+    let (offset, mut message) = match position {
+        PatchPosition::Append => {
+            let mut message = data.to_vec();
+            message.resize(data.len() + len, 0);
+            (data.len(), message)
+        }
+        PatchPosition::Overwrite(offset) => {
+            if offset.checked_add(len).is_none_or(|end| end > data.len()) {
+                return Err(format!(
+                    "A {}-bit CRC needs {} byte(s) at offset {}, but the data is only {} byte(s) long.",
+                    params.width,
+                    len,
+                    offset,
+                    data.len()
+                ));
+            }
+            let mut message = data.to_vec();
+            message[offset..offset + len].fill(0);
+            (offset, message)
+        }
+    };
+
+    // 패치 앞부분은 한 번만 계산하고, 비트마다 복제해서 패치와 뒷부분만 다시 계산
+    let engine = algorithm.engine();
+    let mut prefix = engine.digest();
+    prefix.update(&message[..offset]);
+    let suffix = &message[offset + len..];
+    let crc_with = |patch: &[u8]| {
+        let mut digest = prefix.clone();
+        digest.update(patch);
+        digest.update(suffix);
+        digest.finalize()
+    };
+
+    let base = crc_with(&vec![0u8; len]);
+    let columns: Vec<u64> = (0..len * 8)
+        .map(|bit| {
+            let mut patch = vec![0u8; len];
+            patch[bit / 8] = 0x80 >> (bit % 8);
+            crc_with(&patch) ^ base
+        })
+        .collect();
+
+    let bits = solve(&columns, target ^ base).ok_or_else(|| {
+        format!(
+            "No {}-byte patch reaches 0x{:X}; the polynomial does not let these bytes control every CRC bit.",
+            len, target
+        )
+    })?;
+
+    let mut patch = vec![0u8; len];
+    for bit in (0..len * 8).filter(|bit| (bits >> bit) & 1 == 1) {
+        patch[bit / 8] |= 0x80 >> (bit % 8);
+    }
+    message[offset..offset + len].copy_from_slice(&patch);
+
+    Ok(ForcedCrc {
+        offset,
+        patch,
+        data: message,
+    })
+}
+
+/// `columns`의 부분집합 중 XOR이 `target`인 것을 찾아 비트마스크(i번째 비트 = i번째 열)로 반환합니다.
+fn solve(columns: &[u64], target: u64) -> Option<u64> {
+    // 최상위 비트별 기저 벡터와, 그 벡터를 만드는 열 조합
+    let mut basis: [Option<(u64, u64)>; 64] = [None; 64];
+
+    for (index, &column) in columns.iter().enumerate() {
+        let (mut vector, mut combo) = (column, 1u64 << index);
+        while vector != 0 {
+            let pivot = 63 - vector.leading_zeros() as usize;
+            match basis[pivot] {
+                Some((basis_vector, basis_combo)) => {
+                    vector ^= basis_vector;
+                    combo ^= basis_combo;
+                }
+                None => {
+                    basis[pivot] = Some((vector, combo));
+                    break;
+                }
+            }
+        }
+    }
+
+    let (mut remaining, mut combo) = (target, 0u64);
+    while remaining != 0 {
+        let pivot = 63 - remaining.leading_zeros() as usize;
+        let (basis_vector, basis_combo) = basis[pivot]?;
+        remaining ^= basis_vector;
+        combo ^= basis_combo;
+    }
+    Some(combo)
+}
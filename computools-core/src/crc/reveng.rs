@@ -0,0 +1,234 @@
+//! CRC 리버스 엔지니어링
+//!
+//! 메시지와 그 메시지에 붙어 있던 CRC 쌍(샘플)만으로 알고리즘을 추정합니다.
+//! 카탈로그 검색과 CRC RevEng 방식의 파라미터 전수 탐색을 제공합니다.
+
+use std::collections::HashMap;
+
+use super::{apply_endianness, parse_hex_input, parse_input, CrcAlgorithm, CrcParams, Endianness, InputMode};
+
+/// 전수 탐색을 허용하는 최대 폭. 다항식 후보가 2^(폭-1)개이므로 이보다 크면 현실적이지 않습니다.
+pub const MAX_BRUTE_FORCE_WIDTH: u8 = 16;
+
+/// 메시지와 메시지에 붙어 있던 CRC 바이트
+#[derive(Debug, Clone, PartialEq)]
+pub struct CrcSample {
+    pub data: Vec<u8>,
+    pub crc: Vec<u8>,
+}
+
+impl CrcSample {
+    /// CRC 바이트를 받은 순서 그대로(빅 엔디안으로) 읽은 값
+    fn crc_value(&self) -> u64 {
+        self.crc.iter().fold(0u64, |acc, &byte| (acc << 8) | byte as u64)
+    }
+}
+
+/// 모든 샘플을 만족하는 파라미터와 CRC 바이트 순서
+#[derive(Debug, Clone, PartialEq)]
+pub struct CrcMatch {
+    /// 카탈로그에 있는 파라미터면 해당 알고리즘, 아니면 `CrcAlgorithm::Custom`
+    pub algorithm: CrcAlgorithm,
+    pub endianness: Endianness,
+}
+
+/// "데이터 CRC" 형식의 한 줄을 샘플로 변환합니다.
+///
+/// 마지막 공백 뒤의 토큰이 16진수 CRC이고, 나머지는 `mode`에 맞춰 데이터로 해석합니다.
+pub fn parse_sample_line(line: &str, mode: &InputMode) -> Result<CrcSample, String> {
+    let trimmed = line.trim();
+    let (data, crc) = trimmed
+        .rsplit_once(char::is_whitespace)
+        .ok_or_else(|| format!("Expected '<data> <crc>', got '{}'", trimmed))?;
+
+    let crc = parse_hex_input(crc)?;
+    if crc.is_empty() || crc.len() > 8 {
+        return Err(format!("CRC must be 1 to 8 bytes, got {} in '{}'", crc.len(), trimmed));
+    }
+
+    Ok(CrcSample {
+        data: parse_input(data.trim_end(), mode)?,
+        crc,
+    })
+}
+
+/// 여러 줄의 샘플을 파싱합니다. 빈 줄은 건너뜁니다.
+pub fn parse_samples(text: &str, mode: &InputMode) -> Result<Vec<CrcSample>, String> {
+    text.lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(index, line)| {
+            parse_sample_line(line, mode).map_err(|e| format!("Line {}: {}", index + 1, e))
+        })
+        .collect()
+}
+
+/// 카탈로그의 모든 알고리즘 중 샘플을 모두 만족하는 것을 찾습니다.
+pub fn search_catalogue(samples: &[CrcSample]) -> Vec<CrcMatch> {
+    let Some(crc_len) = common_crc_len(samples) else {
+        return Vec::new();
+    };
+
+    let mut matches = Vec::new();
+    for algorithm in CrcAlgorithm::all() {
+        let width = algorithm.params().width;
+        if width.div_ceil(8) as usize != crc_len {
+            continue;
+        }
+        for endianness in candidate_endianness(width) {
+            let all_match = samples.iter().all(|sample| {
+                let (crc, _) = algorithm.calculate(&sample.data);
+                apply_endianness(crc, width, &endianness) == sample.crc_value()
+            });
+            if all_match {
+                matches.push(CrcMatch {
+                    algorithm,
+                    endianness,
+                });
+            }
+        }
+    }
+    matches
+}
+
+/// 주어진 폭에서 poly/init/xorout과 refin/refout을 전수 탐색합니다.
+///
+/// CRC는 데이터에 대해 아핀(affine)이므로 길이가 같은 두 샘플을 XOR하면 init과 xorout이
+/// 상쇄됩니다. 이 차분으로 다항식을 먼저 찾고, 남은 init/xorout을 샘플로 풉니다.
+/// 모든 샘플의 길이가 같으면 init과 xorout을 구분할 수 없으므로 init은 0과 전부 1인 값만 시도합니다.
+pub fn brute_force(samples: &[CrcSample], width: u8) -> Result<Vec<CrcMatch>, String> {
+    if !(1..=MAX_BRUTE_FORCE_WIDTH).contains(&width) {
+        return Err(format!(
+            "Brute force supports widths from 1 to {} bits, got {}.",
+            MAX_BRUTE_FORCE_WIDTH, width
+        ));
+    }
+    match common_crc_len(samples) {
+        Some(len) if len == width.div_ceil(8) as usize => {}
+        Some(len) => {
+            return Err(format!(
+                "A {}-bit CRC is {} byte(s), but the samples have {}-byte CRCs.",
+                width,
+                width.div_ceil(8),
+                len
+            ))
+        }
+        None => return Err("All samples must have a CRC of the same length.".to_string()),
+    }
+
+    let mask = CrcParams {
+        width,
+        ..CrcParams::default()
+    }
+    .mask();
+    let mut matches = Vec::new();
+
+    for endianness in candidate_endianness(width) {
+        let targets: Vec<(&[u8], u64)> = samples
+            .iter()
+            .map(|sample| {
+                let value = apply_endianness(sample.crc_value(), width, &endianness);
+                (sample.data.as_slice(), value)
+            })
+            .collect();
+        if targets.iter().any(|(_, value)| value & !mask != 0) {
+            continue;
+        }
+
+        let differences = same_length_differences(&targets);
+        if differences.is_empty() {
+            return Err("Brute force needs at least two samples of the same length.".to_string());
+        }
+
+        for (refin, refout) in [(false, false), (true, true), (false, true), (true, false)] {
+            // 다항식의 최하위 비트는 항상 1입니다.
+            for poly in (1..=mask).step_by(2) {
+                let linear = CrcParams {
+                    width,
+                    poly,
+                    init: 0,
+                    refin,
+                    refout,
+                    xorout: 0,
+                };
+                if !differences.iter().all(|(data, value)| linear.calculate(data) == *value) {
+                    continue;
+                }
+
+                for params in solve_init_xorout(linear, &targets) {
+                    matches.push(CrcMatch {
+                        algorithm: catalogue_or_custom(params),
+                        endianness: endianness.clone(),
+                    });
+                }
+            }
+        }
+    }
+
+    Ok(matches)
+}
+
+/// 파라미터가 카탈로그에 있으면 해당 알고리즘을, 없으면 `Custom`을 반환합니다.
+pub fn catalogue_or_custom(params: CrcParams) -> CrcAlgorithm {
+    CrcAlgorithm::all()
+        .into_iter()
+        .find(|algorithm| algorithm.params() == params)
+        .unwrap_or(CrcAlgorithm::Custom(params))
+}
+
+fn common_crc_len(samples: &[CrcSample]) -> Option<usize> {
+    let len = samples.first()?.crc.len();
+    samples.iter().all(|sample| sample.crc.len() == len).then_some(len)
+}
+
+/// 한 바이트 이하의 CRC는 바이트 순서가 의미 없으므로 빅 엔디안만 시도합니다.
+fn candidate_endianness(width: u8) -> Vec<Endianness> {
+    if width <= 8 {
+        vec![Endianness::BigEndian]
+    } else {
+        vec![Endianness::BigEndian, Endianness::LittleEndian]
+    }
+}
+
+/// 길이가 같은 샘플 쌍의 (데이터 XOR, CRC XOR) 목록
+fn same_length_differences(targets: &[(&[u8], u64)]) -> Vec<(Vec<u8>, u64)> {
+    let mut first_by_len: HashMap<usize, (&[u8], u64)> = HashMap::new();
+    let mut differences = Vec::new();
+
+    for &(data, value) in targets {
+        match first_by_len.get(&data.len()) {
+            Some(&(first_data, first_value)) => {
+                let xored = first_data.iter().zip(data).map(|(a, b)| a ^ b).collect();
+                differences.push((xored, first_value ^ value));
+            }
+            None => {
+                first_by_len.insert(data.len(), (data, value));
+            }
+        }
+    }
+    differences
+}
+
+/// 다항식과 반사 설정이 정해진 상태에서 모든 샘플을 만족하는 init/xorout 조합을 찾습니다.
+fn solve_init_xorout(linear: CrcParams, targets: &[(&[u8], u64)]) -> Vec<CrcParams> {
+    let mask = linear.mask();
+    let (first_data, first_value) = targets[0];
+    let lengths_differ = targets.iter().any(|(data, _)| data.len() != first_data.len());
+    let inits: Box<dyn Iterator<Item = u64>> = if lengths_differ {
+        Box::new(0..=mask)
+    } else {
+        Box::new([0, mask].into_iter())
+    };
+
+    inits
+        .filter_map(|init| {
+            let without_xorout = CrcParams { init, ..linear };
+            let xorout = without_xorout.calculate(first_data) ^ first_value;
+            let params = CrcParams { xorout, ..without_xorout };
+            targets
+                .iter()
+                .all(|(data, value)| params.calculate(data) == *value)
+                .then_some(params)
+        })
+        .collect()
+}
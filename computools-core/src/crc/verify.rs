@@ -0,0 +1,59 @@
+//! 끝에 CRC가 붙은 프레임(예: Modbus RTU)을 검증합니다.
+
+use super::{apply_endianness, CrcAlgorithm, Endianness};
+
+/// 프레임 검증 결과
+#[derive(Debug, Clone, PartialEq)]
+pub struct CrcVerification {
+    /// CRC를 뗀 데이터 길이
+    pub data_len: usize,
+    /// 프레임 끝에 붙어 있던 CRC
+    pub expected: u64,
+    /// 데이터로 다시 계산한 CRC
+    pub actual: u64,
+    pub width: u8,
+    /// 프레임 전체를 계산한 CRC가 residue ^ xorout과 같은지.
+    /// 폭이 8의 배수가 아니거나 바이트 순서가 알고리즘의 비트 순서와 맞지 않으면 `None`
+    pub residue_ok: Option<bool>,
+}
+
+impl CrcVerification {
+    pub fn matches(&self) -> bool {
+        self.expected == self.actual
+    }
+}
+
+/// 폭에 맞는 바이트 수만큼 프레임 끝의 CRC를 `endianness` 순서로 읽어 데이터의 CRC와 비교합니다.
+pub fn verify(algorithm: &CrcAlgorithm, frame: &[u8], endianness: &Endianness) -> Result<CrcVerification, String> {
+    let params = algorithm.params();
+    let crc_len = params.width.div_ceil(8) as usize;
+    if frame.len() < crc_len {
+        return Err(format!(
+            "A {}-bit CRC needs {} trailing byte(s), but the frame is only {} byte(s) long.",
+            params.width,
+            crc_len,
+            frame.len()
+        ));
+    }
+
+    let (data, crc_bytes) = frame.split_at(frame.len() - crc_len);
+    let received = crc_bytes.iter().fold(0u64, |acc, &byte| (acc << 8) | byte as u64);
+    let expected = apply_endianness(received, params.width, endianness);
+    let (actual, width) = algorithm.calculate(data);
+
+    // 반사 알고리즘은 리틀 엔디안, 아니면 빅 엔디안으로 붙여야 레지스터에 비트 순서대로 들어감
+    let natural_order = match endianness {
+        Endianness::BigEndian => !params.refout,
+        Endianness::LittleEndian => params.refout,
+    };
+    let residue_ok = (params.width.is_multiple_of(8) && params.refin == params.refout && (crc_len == 1 || natural_order))
+        .then(|| algorithm.calculate(frame).0 == params.residue() ^ params.xorout);
+
+    Ok(CrcVerification {
+        data_len: data.len(),
+        expected,
+        actual,
+        width,
+        residue_ok,
+    })
+}
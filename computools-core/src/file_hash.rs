@@ -0,0 +1,643 @@
+//! 파일 해시(MD5, SHA-1/2/3, BLAKE2/3, RIPEMD-160, CRC32)와 HMAC 계산, 해시 비교
+//!
+//! 알고리즘은 `HashAlgorithm`에 등록되어 있고, 각 알고리즘은 조각 단위로 갱신하는 `Hasher`를
+//! 만듭니다. `FileHasher`는 선택한 알고리즘과 HMAC을 청크마다 한꺼번에 갱신합니다.
+
+pub mod manifest;
+
+use base64::engine::general_purpose::STANDARD as BASE64_STANDARD;
+use base64::Engine;
+use blake2::Blake2b512;
+use crc::{Crc, Digest as CrcDigest, CRC_32_ISO_HDLC};
+use hmac::{Hmac, Mac, SimpleHmac};
+use md5::{Digest, Md5};
+use ripemd::Ripemd160;
+use sha1::Sha1;
+use sha2::{Sha224, Sha256, Sha384, Sha512, Sha512_256};
+use sha3::{Sha3_256, Sha3_512};
+
+static CRC32: Crc<u32> = Crc::<u32>::new(&CRC_32_ISO_HDLC);
+
+/// 지원하는 해시 알고리즘
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HashAlgorithm {
+    Md5,
+    Sha1,
+    Sha224,
+    Sha256,
+    Sha384,
+    Sha512,
+    Sha512_256,
+    Sha3_256,
+    Sha3_512,
+    Blake2b,
+    Blake3,
+    Ripemd160,
+    Crc32,
+}
+
+impl HashAlgorithm {
+    /// 화면과 CLI에 표시하는 순서
+    pub fn all() -> [HashAlgorithm; 13] {
+        [
+            HashAlgorithm::Md5,
+            HashAlgorithm::Sha1,
+            HashAlgorithm::Sha224,
+            HashAlgorithm::Sha256,
+            HashAlgorithm::Sha384,
+            HashAlgorithm::Sha512,
+            HashAlgorithm::Sha512_256,
+            HashAlgorithm::Sha3_256,
+            HashAlgorithm::Sha3_512,
+            HashAlgorithm::Blake2b,
+            HashAlgorithm::Blake3,
+            HashAlgorithm::Ripemd160,
+            HashAlgorithm::Crc32,
+        ]
+    }
+
+    /// 설정 저장과 CLI 인자에 쓰는 키 (예: "sha3_256")
+    pub fn key(&self) -> &'static str {
+        match self {
+            HashAlgorithm::Md5 => "md5",
+            HashAlgorithm::Sha1 => "sha1",
+            HashAlgorithm::Sha224 => "sha224",
+            HashAlgorithm::Sha256 => "sha256",
+            HashAlgorithm::Sha384 => "sha384",
+            HashAlgorithm::Sha512 => "sha512",
+            HashAlgorithm::Sha512_256 => "sha512_256",
+            HashAlgorithm::Sha3_256 => "sha3_256",
+            HashAlgorithm::Sha3_512 => "sha3_512",
+            HashAlgorithm::Blake2b => "blake2b",
+            HashAlgorithm::Blake3 => "blake3",
+            HashAlgorithm::Ripemd160 => "ripemd160",
+            HashAlgorithm::Crc32 => "crc32",
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            HashAlgorithm::Md5 => "MD5",
+            HashAlgorithm::Sha1 => "SHA-1",
+            HashAlgorithm::Sha224 => "SHA-224",
+            HashAlgorithm::Sha256 => "SHA-256",
+            HashAlgorithm::Sha384 => "SHA-384",
+            HashAlgorithm::Sha512 => "SHA-512",
+            HashAlgorithm::Sha512_256 => "SHA-512/256",
+            HashAlgorithm::Sha3_256 => "SHA3-256",
+            HashAlgorithm::Sha3_512 => "SHA3-512",
+            HashAlgorithm::Blake2b => "BLAKE2b",
+            HashAlgorithm::Blake3 => "BLAKE3",
+            HashAlgorithm::Ripemd160 => "RIPEMD-160",
+            HashAlgorithm::Crc32 => "CRC32",
+        }
+    }
+
+    pub fn from_key(key: &str) -> Option<Self> {
+        HashAlgorithm::all().into_iter().find(|algorithm| algorithm.key() == key)
+    }
+
+    /// 출력 길이 (바이트)
+    pub fn output_len(&self) -> usize {
+        match self {
+            HashAlgorithm::Md5 => 16,
+            HashAlgorithm::Sha1 | HashAlgorithm::Ripemd160 => 20,
+            HashAlgorithm::Sha224 => 28,
+            HashAlgorithm::Sha256 | HashAlgorithm::Sha512_256 | HashAlgorithm::Sha3_256 | HashAlgorithm::Blake3 => 32,
+            HashAlgorithm::Sha384 => 48,
+            HashAlgorithm::Sha512 | HashAlgorithm::Sha3_512 | HashAlgorithm::Blake2b => 64,
+            HashAlgorithm::Crc32 => 4,
+        }
+    }
+
+    /// 저장된 선택이 없을 때 기본으로 계산하는지 여부
+    pub fn is_default(&self) -> bool {
+        matches!(
+            self,
+            HashAlgorithm::Md5 | HashAlgorithm::Sha1 | HashAlgorithm::Sha256 | HashAlgorithm::Sha512 | HashAlgorithm::Crc32
+        )
+    }
+
+    pub fn hasher(&self) -> Box<dyn Hasher> {
+        match self {
+            HashAlgorithm::Md5 => Box::new(DigestHasher(Md5::new())),
+            HashAlgorithm::Sha1 => Box::new(DigestHasher(Sha1::new())),
+            HashAlgorithm::Sha224 => Box::new(DigestHasher(Sha224::new())),
+            HashAlgorithm::Sha256 => Box::new(DigestHasher(Sha256::new())),
+            HashAlgorithm::Sha384 => Box::new(DigestHasher(Sha384::new())),
+            HashAlgorithm::Sha512 => Box::new(DigestHasher(Sha512::new())),
+            HashAlgorithm::Sha512_256 => Box::new(DigestHasher(Sha512_256::new())),
+            HashAlgorithm::Sha3_256 => Box::new(DigestHasher(Sha3_256::new())),
+            HashAlgorithm::Sha3_512 => Box::new(DigestHasher(Sha3_512::new())),
+            HashAlgorithm::Blake2b => Box::new(DigestHasher(Blake2b512::new())),
+            HashAlgorithm::Blake3 => Box::new(blake3::Hasher::new()),
+            HashAlgorithm::Ripemd160 => Box::new(DigestHasher(Ripemd160::new())),
+            HashAlgorithm::Crc32 => Box::new(Crc32Hasher(CRC32.digest())),
+        }
+    }
+
+    /// HMAC 표시 이름 (예: "HMAC-SHA256")
+    pub fn hmac_name(&self) -> String {
+        format!("HMAC-{}", self.name().replacen("SHA-", "SHA", 1))
+    }
+
+    /// `key`로 HMAC을 계산하는 해셔. BLAKE3와 CRC32는 HMAC을 지원하지 않아 `None`입니다.
+    pub fn hmac(&self, key: &[u8]) -> Option<Box<dyn Hasher>> {
+        let hasher: Box<dyn Hasher> = match self {
+            HashAlgorithm::Md5 => mac_hasher::<Hmac<Md5>>(key),
+            HashAlgorithm::Sha1 => mac_hasher::<Hmac<Sha1>>(key),
+            HashAlgorithm::Sha224 => mac_hasher::<Hmac<Sha224>>(key),
+            HashAlgorithm::Sha256 => mac_hasher::<Hmac<Sha256>>(key),
+            HashAlgorithm::Sha384 => mac_hasher::<Hmac<Sha384>>(key),
+            HashAlgorithm::Sha512 => mac_hasher::<Hmac<Sha512>>(key),
+            HashAlgorithm::Sha512_256 => mac_hasher::<Hmac<Sha512_256>>(key),
+            HashAlgorithm::Sha3_256 => mac_hasher::<Hmac<Sha3_256>>(key),
+            HashAlgorithm::Sha3_512 => mac_hasher::<Hmac<Sha3_512>>(key),
+            // BLAKE2b 코어는 Hmac이 요구하는 버퍼 방식을 쓰지 않아 SimpleHmac 사용
+            HashAlgorithm::Blake2b => mac_hasher::<SimpleHmac<Blake2b512>>(key),
+            HashAlgorithm::Ripemd160 => mac_hasher::<Hmac<Ripemd160>>(key),
+            HashAlgorithm::Blake3 | HashAlgorithm::Crc32 => return None,
+        };
+        Some(hasher)
+    }
+
+    pub fn supports_hmac(&self) -> bool {
+        !matches!(self, HashAlgorithm::Blake3 | HashAlgorithm::Crc32)
+    }
+}
+
+/// 조각 단위로 데이터를 받아 해시를 계산하는 상태
+pub trait Hasher {
+    fn update(&mut self, data: &[u8]);
+    fn finalize(self: Box<Self>) -> Vec<u8>;
+}
+
+struct DigestHasher<D>(D);
+
+impl<D: Digest> Hasher for DigestHasher<D> {
+    fn update(&mut self, data: &[u8]) {
+        Digest::update(&mut self.0, data);
+    }
+
+    fn finalize(self: Box<Self>) -> Vec<u8> {
+        self.0.finalize().to_vec()
+    }
+}
+
+struct MacHasher<M>(M);
+
+impl<M: Mac> Hasher for MacHasher<M> {
+    fn update(&mut self, data: &[u8]) {
+        Mac::update(&mut self.0, data);
+    }
+
+    fn finalize(self: Box<Self>) -> Vec<u8> {
+        self.0.finalize().into_bytes().to_vec()
+    }
+}
+
+fn mac_hasher<M: Mac + hmac::digest::KeyInit + 'static>(key: &[u8]) -> Box<dyn Hasher> {
+    // HMAC은 어떤 길이의 키든 받으므로 실패하지 않음
+    Box::new(MacHasher(<M as Mac>::new_from_slice(key).expect("HMAC accepts keys of any length")))
+}
+
+impl Hasher for blake3::Hasher {
+    fn update(&mut self, data: &[u8]) {
+        blake3::Hasher::update(self, data);
+    }
+
+    fn finalize(self: Box<Self>) -> Vec<u8> {
+        blake3::Hasher::finalize(&self).as_bytes().to_vec()
+    }
+}
+
+struct Crc32Hasher(CrcDigest<'static, u32>);
+
+impl Hasher for Crc32Hasher {
+    fn update(&mut self, data: &[u8]) {
+        self.0.update(data);
+    }
+
+    fn finalize(self: Box<Self>) -> Vec<u8> {
+        self.0.finalize().to_be_bytes().to_vec()
+    }
+}
+
+// 파일 검증을 위한 상수들
+pub const ALLOWED_MIME_TYPES: &[&str] = &[
+    // 일반적으로 안전한 파일 타입들
+    "application/octet-stream",
+    "application/pdf",
+    "application/zip",
+    "application/x-zip-compressed",
+    "application/x-rar-compressed",
+    "application/x-7z-compressed",
+    "application/gzip",
+    "application/x-tar",
+    "application/json",
+    "application/xml",
+    "application/msword",
+    "application/vnd.openxmlformats-officedocument.wordprocessingml.document",
+    "application/vnd.ms-excel",
+    "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet",
+    "application/vnd.ms-powerpoint",
+    "application/vnd.openxmlformats-officedocument.presentationml.presentation",
+    "text/plain",
+    "text/csv",
+    "text/html",
+    "text/css",
+    "text/javascript",
+    "text/xml",
+    "image/jpeg",
+    "image/png",
+    "image/gif",
+    "image/bmp",
+    "image/webp",
+    "image/svg+xml",
+    "image/tiff",
+    "audio/mpeg",
+    "audio/wav",
+    "audio/ogg",
+    "audio/mp4",
+    "video/mp4",
+    "video/mpeg",
+    "video/quicktime",
+    "video/x-msvideo",
+    "video/webm",
+];
+
+// 출력 포맷 옵션
+#[derive(Debug, Clone, PartialEq)]
+pub enum OutputFormat {
+    Lowercase,     // d85e5d59b4d49efce3398dc6b6d4b91b
+    Uppercase,     // D85E5D59B4D49EFCE3398DC6B6D4B91B
+    ColonSeparated, // d8:5e:5d:59:b4:d4:9e:fc:e3:39:8d:c6:b6:d4:b9:1b
+    Base64,        // 2F5dWbTUnvzjOY3GttS5Gw==
+    CStyleArray,   // {0xd8, 0x5e, 0x5d, 0x59, 0xb4, 0xd4, 0x9e, 0xfc, 0xe3, 0x39, 0x8d, 0xc6, 0xb6, 0xd4, 0xb9, 0x1b}
+}
+
+// 검증 타입 옵션
+#[derive(Debug, Clone, PartialEq)]
+pub enum VerificationType {
+    Hash,  // 일반 해시 검증
+    Hmac,  // HMAC 검증
+}
+
+// 해시 비교 결과
+#[derive(Debug, Clone, PartialEq)]
+pub struct HashComparison {
+    pub algorithm: String,
+    pub matches: bool,
+    pub expected: String,
+    pub actual: String,
+}
+
+/// 해시 계산 결과 (소문자 hex). 선택하지 않은 알고리즘은 들어 있지 않습니다.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct HashResults {
+    pub hashes: Vec<(HashAlgorithm, String)>,
+    pub hmacs: Vec<(HashAlgorithm, String)>,
+}
+
+impl HashResults {
+    pub fn get(&self, algorithm: HashAlgorithm) -> Option<&str> {
+        Self::find(&self.hashes, algorithm)
+    }
+
+    pub fn hmac(&self, algorithm: HashAlgorithm) -> Option<&str> {
+        Self::find(&self.hmacs, algorithm)
+    }
+
+    fn find(entries: &[(HashAlgorithm, String)], algorithm: HashAlgorithm) -> Option<&str> {
+        entries
+            .iter()
+            .find(|(entry, _)| *entry == algorithm)
+            .map(|(_, hash)| hash.as_str())
+    }
+}
+
+/// 선택한 알고리즘(과 HMAC)을 한꺼번에 갱신하는 해셔. 파일을 청크 단위로 읽으며 `update`합니다.
+pub struct FileHasher {
+    hashers: Vec<(HashAlgorithm, Box<dyn Hasher>)>,
+    hmacs: Vec<(HashAlgorithm, Box<dyn Hasher>)>,
+}
+
+impl FileHasher {
+    /// `hmac_key`가 주어지면 선택한 알고리즘 중 HMAC을 지원하는 것에 대해 HMAC도 계산합니다.
+    pub fn new(algorithms: &[HashAlgorithm], hmac_key: Option<&[u8]>) -> Self {
+        let mut hasher = match hmac_key {
+            Some(key) => Self::hmac_only(algorithms, key),
+            None => Self { hashers: Vec::new(), hmacs: Vec::new() },
+        };
+        hasher.hashers = algorithms
+            .iter()
+            .map(|algorithm| (*algorithm, algorithm.hasher()))
+            .collect();
+        hasher
+    }
+
+    /// 일반 해시 없이 HMAC만 계산합니다. 파일을 다시 읽어 HMAC을 검증할 때 씁니다.
+    pub fn hmac_only(algorithms: &[HashAlgorithm], key: &[u8]) -> Self {
+        let hmacs = algorithms
+            .iter()
+            .filter_map(|algorithm| algorithm.hmac(key).map(|hasher| (*algorithm, hasher)))
+            .collect();
+        Self { hashers: Vec::new(), hmacs }
+    }
+
+    pub fn update(&mut self, data: &[u8]) {
+        for (_, hasher) in self.hashers.iter_mut().chain(self.hmacs.iter_mut()) {
+            hasher.update(data);
+        }
+    }
+
+    pub fn finalize(self) -> HashResults {
+        let finish = |entries: Vec<(HashAlgorithm, Box<dyn Hasher>)>| {
+            entries
+                .into_iter()
+                .map(|(algorithm, hasher)| (algorithm, hex::encode(hasher.finalize())))
+                .collect()
+        };
+        HashResults {
+            hashes: finish(self.hashers),
+            hmacs: finish(self.hmacs),
+        }
+    }
+}
+
+/// 메모리에 있는 데이터를 선택한 알고리즘으로 한 번에 해시합니다.
+pub fn compute_hashes(data: &[u8], algorithms: &[HashAlgorithm], hmac_key: Option<&[u8]>) -> HashResults {
+    let mut hasher = FileHasher::new(algorithms, hmac_key);
+    hasher.update(data);
+    hasher.finalize()
+}
+
+/// 다양한 해시 입력 포맷(hex, 콜론 구분, Base64, C 배열)을 소문자 hex로 정규화합니다.
+///
+/// 짝수 길이의 hex 문자열은 Base64 문자셋에도 속하지만(예: MD5, SHA-256 해시) 항상 hex로 해석합니다.
+/// hex 해시를 Base64로 붙여넣는 경우는 거의 없고, 반대로 해석하면 올바른 해시가 불일치로 보입니다.
+pub fn normalize_hash_input(input: &str) -> Result<String, String> {
+    let trimmed = input.trim();
+    
+    if trimmed.is_empty() {
+        return Err("Empty input".to_string());
+    }
+    
+    // 1. Colon-separated format (d8:5e:5d:59:b4:d4...)
+    if trimmed.contains(':') {
+        let hex_string: String = trimmed.split(':')
+            .map(|part| part.trim())
+            .collect::<Vec<_>>()
+            .join("");
+        
+        // 유효한 hex인지 확인
+        if hex_string.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Ok(hex_string.to_lowercase());
+        } else {
+            return Err("Invalid colon-separated hex format".to_string());
+        }
+    }
+    
+    // 2. C-style array format ({0xd8, 0x5e, 0x5d...})
+    if trimmed.starts_with('{') && trimmed.ends_with('}') {
+        let inner = &trimmed[1..trimmed.len()-1];
+        let hex_parts: Result<Vec<String>, String> = inner
+            .split(',')
+            .map(|part| {
+                let part = part.trim();
+                if part.starts_with("0x") || part.starts_with("0X") {
+                    let hex_part = &part[2..];
+                    if hex_part.len() == 2 && hex_part.chars().all(|c| c.is_ascii_hexdigit()) {
+                        Ok(hex_part.to_string())
+                    } else {
+                        Err("Invalid hex byte in C-style array".to_string())
+                    }
+                } else {
+                    Err("C-style array must contain 0x prefixed values".to_string())
+                }
+            })
+            .collect();
+        
+        match hex_parts {
+            Ok(parts) => return Ok(parts.join("").to_lowercase()),
+            Err(e) => return Err(e),
+        }
+    }
+    
+    // 3. Base64 format (길이와 문자셋으로 추정, hex로 읽을 수 있으면 hex 우선)
+    if !is_plain_hex(trimmed) && trimmed.chars().all(|c| c.is_ascii_alphanumeric() || c == '+' || c == '/' || c == '=') 
+        && (trimmed.len().is_multiple_of(4) || trimmed.ends_with('=')) {
+        
+        match BASE64_STANDARD.decode(trimmed) {
+            Ok(bytes) => {
+                let hex_string = bytes.iter()
+                    .map(|b| format!("{:02x}", b))
+                    .collect::<String>();
+                return Ok(hex_string);
+            }
+            Err(_) => {
+                // Base64 디코딩 실패 시 일반 hex로 처리
+            }
+        }
+    }
+    
+    // 4. 일반 hex format (기본값)
+    let clean_hex = trimmed.to_lowercase();
+    if clean_hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        Ok(clean_hex)
+    } else {
+        Err("Invalid hash format. Supported formats: hex, colon-separated (d8:5e:5d), Base64, or C-style array ({0xd8, 0x5e})".to_string())
+    }
+}
+
+/// 구분자 없이 hex 문자로만 된 짝수 길이 문자열인지
+fn is_plain_hex(text: &str) -> bool {
+    text.len().is_multiple_of(2) && text.chars().all(|c| c.is_ascii_hexdigit())
+}
+
+/// `algorithm`으로 HMAC을 계산해 소문자 hex로 반환합니다.
+pub fn calculate_hmac(algorithm: HashAlgorithm, key: &[u8], data: &[u8]) -> Result<String, String> {
+    let mut hasher = algorithm
+        .hmac(key)
+        .ok_or_else(|| format!("{} does not support HMAC", algorithm.name()))?;
+    hasher.update(data);
+    Ok(hex::encode(hasher.finalize()))
+}
+
+pub fn format_file_size(size: usize) -> String {
+    const UNITS: &[&str] = &["B", "KB", "MB", "GB"];
+    let mut size_f = size as f64;
+    let mut unit_index = 0;
+    
+    while size_f >= 1024.0 && unit_index < UNITS.len() - 1 {
+        size_f /= 1024.0;
+        unit_index += 1;
+    }
+    
+    if unit_index == 0 {
+        format!("{} {}", size, UNITS[unit_index])
+    } else {
+        format!("{:.2} {}", size_f, UNITS[unit_index])
+    }
+}
+
+/// 소문자 hex 해시를 선택한 출력 포맷으로 변환합니다.
+pub fn format_hash_output(hash: &str, output_format: &OutputFormat) -> String {
+    if hash.is_empty() {
+        return String::new();
+    }
+    
+    match output_format {
+        OutputFormat::Lowercase => hash.to_lowercase(),
+        OutputFormat::Uppercase => hash.to_uppercase(),
+        OutputFormat::ColonSeparated => {
+            let lower_hash = hash.to_lowercase();
+            let mut result = String::new();
+            for (i, c) in lower_hash.chars().enumerate() {
+                if i > 0 && i % 2 == 0 {
+                    result.push(':');
+                }
+                result.push(c);
+            }
+            result
+        }
+        OutputFormat::Base64 => {
+            // hex 문자열을 바이트로 변환한 후 base64 인코딩
+            if let Ok(bytes) = hex::decode(hash) {
+                BASE64_STANDARD.encode(&bytes)
+            } else {
+                hash.to_string()
+            }
+        }
+        OutputFormat::CStyleArray => {
+            // hex 문자열을 바이트로 변환한 후 C 스타일 배열로 포맷팅
+            if let Ok(bytes) = hex::decode(hash) {
+                let mut result = String::from("{");
+                for (i, byte) in bytes.iter().enumerate() {
+                    if i > 0 {
+                        result.push_str(", ");
+                    }
+                    result.push_str(&format!("0x{:02x}", byte));
+                }
+                result.push('}');
+                result
+            } else {
+                hash.to_string()
+            }
+        }
+    }
+}
+
+/// 기대 해시를 계산된 해시들과 비교합니다.
+///
+/// 일치하는 알고리즘이 없으면 기대값 길이로 가장 가능성 높은 알고리즘을 골라 불일치로 보고합니다.
+pub fn compare_hash(expected: &str, results: &HashResults) -> Option<HashComparison> {
+    if expected.trim().is_empty() {
+        return None;
+    }
+
+    // 입력된 해시를 표준 hex 포맷으로 정규화
+    let expected_clean = match normalize_hash_input(expected) {
+        Ok(normalized) => normalized,
+        Err(error) => return Some(format_error_comparison(expected, error)),
+    };
+
+    for (algorithm, actual_hash) in &results.hashes {
+        if actual_hash.to_lowercase() == expected_clean {
+            return Some(HashComparison {
+                algorithm: algorithm.name().to_string(),
+                matches: true,
+                expected: expected_clean.clone(),
+                actual: actual_hash.to_lowercase(),
+            });
+        }
+    }
+
+    // 일치하는 해시가 없는 경우, 출력 길이가 같은 첫 번째 알고리즘을 가장 가능성 높은 것으로 추정
+    // 길이가 같은 것도 없으면 SHA-256, 그것도 계산하지 않았으면 첫 번째 해시 사용
+    let (algorithm, actual_hash) = results
+        .hashes
+        .iter()
+        .find(|(algorithm, _)| algorithm.output_len() * 2 == expected_clean.len())
+        .or_else(|| results.hashes.iter().find(|(algorithm, _)| *algorithm == HashAlgorithm::Sha256))
+        .or_else(|| results.hashes.first())?;
+
+    Some(HashComparison {
+        algorithm: algorithm.name().to_string(),
+        matches: false,
+        expected: expected_clean,
+        actual: actual_hash.to_lowercase(),
+    })
+}
+
+/// 기대 HMAC 값을 `algorithms` 중 HMAC을 지원하는 알고리즘으로 계산한 HMAC들과 비교합니다.
+pub fn compare_hmac(expected: &str, key: &[u8], data: &[u8], algorithms: &[HashAlgorithm]) -> Option<HashComparison> {
+    let mut hasher = FileHasher::hmac_only(algorithms, key);
+    hasher.update(data);
+    compare_hmac_results(expected, &hasher.finalize())
+}
+
+/// 이미 계산된 `results.hmacs`와 기대 HMAC을 비교합니다. 파일을 청크 단위로 읽어 HMAC을 계산한 경우에 씁니다.
+pub fn compare_hmac_results(expected: &str, results: &HashResults) -> Option<HashComparison> {
+    if expected.trim().is_empty() {
+        return None;
+    }
+
+    let expected_clean = match normalize_hash_input(expected) {
+        Ok(normalized) => normalized,
+        Err(error) => return Some(format_error_comparison(expected, error)),
+    };
+
+    if let Some((algorithm, actual)) = results.hmacs.iter().find(|(_, hmac)| *hmac == expected_clean) {
+        return Some(HashComparison {
+            algorithm: algorithm.hmac_name(),
+            matches: true,
+            expected: expected_clean,
+            actual: actual.clone(),
+        });
+    }
+
+    // 일치하는 HMAC이 없는 경우, 출력 길이가 같은 알고리즘(없으면 SHA-256, 그것도 없으면 첫 번째)으로 비교
+    let (algorithm, actual) = results
+        .hmacs
+        .iter()
+        .find(|(algorithm, _)| algorithm.output_len() * 2 == expected_clean.len())
+        .or_else(|| results.hmacs.iter().find(|(algorithm, _)| *algorithm == HashAlgorithm::Sha256))
+        .or_else(|| results.hmacs.first())?;
+
+    Some(HashComparison {
+        algorithm: algorithm.hmac_name(),
+        matches: false,
+        expected: expected_clean,
+        actual: actual.clone(),
+    })
+}
+
+fn format_error_comparison(expected: &str, error: String) -> HashComparison {
+    HashComparison {
+        algorithm: "Format Error".to_string(),
+        matches: false,
+        expected: expected.trim().to_string(),
+        actual: error,
+    }
+}
+
+pub fn get_file_icon(mime_type: &str) -> &'static str {
+    match mime_type {
+        t if t.starts_with("image/") => "🖼️",
+        t if t.starts_with("video/") => "🎥",
+        t if t.starts_with("audio/") => "🎵",
+        t if t.starts_with("text/") => "📄",
+        "application/pdf" => "📕",
+        "application/zip" | "application/x-zip-compressed" | "application/gzip" | "application/x-bzip2" | "application/x-xz"
+        | "application/zstd" | "application/x-7z-compressed" | "application/vnd.rar" | "application/x-tar" => "📦",
+        "application/x-elf" | "application/vnd.microsoft.portable-executable" | "application/x-mach-binary" | "application/wasm"
+        | "application/java-vm" => "⚙️",
+        "application/json" => "📋",
+        "application/xml" => "📰",
+        t if t.contains("word") => "📝",
+        t if t.contains("excel") | t.contains("sheet") => "📊",
+        t if t.contains("powerpoint") | t.contains("presentation") => "📽️",
+        _ => "🗂️",
+    }
+}
@@ -0,0 +1,489 @@
+//! 체크섬 매니페스트 검증과 생성
+//!
+//! GNU coreutils(`sha256sum` 출력, `SHA256SUMS`), BSD/태그 형식(`SHA256 (file) = ...`),
+//! SFV(`file CRC32`) 형식의 매니페스트를 읽고, 파일별로 계산한 해시와 비교해서
+//! 줄마다 OK/FAILED/MISSING을 보고합니다. 같은 형식(과 JSON)으로 매니페스트를 만들 수도 있습니다.
+
+use super::{normalize_hash_input, HashAlgorithm, HashResults};
+
+/// 매니페스트 줄의 형식
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ManifestFormat {
+    /// `hash  file` 또는 `hash *file` (coreutils)
+    Gnu,
+    /// `ALGO (file) = hash` (BSD, `sha256sum --tag`, OpenSSL)
+    Bsd,
+    /// `file CRC32` (Simple File Verification)
+    Sfv,
+}
+
+impl ManifestFormat {
+    pub fn name(&self) -> &'static str {
+        match self {
+            ManifestFormat::Gnu => "GNU",
+            ManifestFormat::Bsd => "BSD",
+            ManifestFormat::Sfv => "SFV",
+        }
+    }
+}
+
+/// 매니페스트의 체크섬 한 줄
+#[derive(Debug, Clone, PartialEq)]
+pub struct ManifestEntry {
+    /// 줄 번호 (1부터)
+    pub line: usize,
+    pub format: ManifestFormat,
+    pub file_name: String,
+    pub algorithm: HashAlgorithm,
+    /// 정규화된 기대 해시 (소문자 hex)
+    pub expected: String,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Manifest {
+    pub entries: Vec<ManifestEntry>,
+    /// 형식을 알 수 없어 건너뛴 줄 번호
+    pub malformed_lines: Vec<usize>,
+}
+
+impl Manifest {
+    /// `file_names`의 `index`번 파일에 대해 계산해야 하는 알고리즘 (중복 제거, 매니페스트 순서)
+    ///
+    /// 어느 줄이 어느 파일을 가리키는지는 [`find_file`]로 정하므로 주어진 파일 이름 전체가 필요합니다.
+    pub fn algorithms_for<S: AsRef<str>>(&self, file_names: &[S], index: usize) -> Vec<HashAlgorithm> {
+        let mut algorithms = Vec::new();
+        for entry in self.entries.iter().filter(|entry| find_file(&entry.file_name, file_names) == FileMatch::Found(index)) {
+            if !algorithms.contains(&entry.algorithm) {
+                algorithms.push(entry.algorithm);
+            }
+        }
+        algorithms
+    }
+}
+
+/// 줄별 검증 상태
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CheckStatus {
+    Ok,
+    Failed,
+    /// 매니페스트에 있지만 파일이 주어지지 않음
+    Missing,
+    /// 경로가 같은 파일은 없고 파일 이름이 같은 파일이 여럿이라 어느 것인지 알 수 없음
+    Ambiguous,
+}
+
+impl CheckStatus {
+    pub fn label(&self) -> &'static str {
+        match self {
+            CheckStatus::Ok => "OK",
+            CheckStatus::Failed => "FAILED",
+            CheckStatus::Missing => "MISSING",
+            CheckStatus::Ambiguous => "AMBIGUOUS",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ManifestCheck {
+    pub entry: ManifestEntry,
+    pub status: CheckStatus,
+    /// 계산한 해시 (파일이 없으면 `None`)
+    pub actual: Option<String>,
+}
+
+/// 상태별 줄 수
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CheckSummary {
+    pub ok: usize,
+    pub failed: usize,
+    pub missing: usize,
+    pub ambiguous: usize,
+}
+
+impl CheckSummary {
+    pub fn all_ok(&self) -> bool {
+        self.failed == 0 && self.missing == 0 && self.ambiguous == 0
+    }
+}
+
+/// 매니페스트 텍스트를 파싱합니다.
+///
+/// GNU 형식은 해시 길이만으로 알고리즘을 알 수 없을 때가 있어(SHA-256과 SHA3-256, BLAKE3 등),
+/// `manifest_name`(예: "SHA3-256SUMS", "release.b2")에서 알고리즘을 추정할 수 있으면 그것을
+/// 우선하고, 아니면 coreutils 기본 도구(md5sum, sha1sum, sha2 계열)로 간주합니다.
+pub fn parse(text: &str, manifest_name: Option<&str>) -> Manifest {
+    let hint = manifest_name.and_then(algorithm_from_manifest_name);
+    let mut manifest = Manifest::default();
+
+    for (index, raw_line) in text.lines().enumerate() {
+        let line = raw_line.trim_end_matches('\r');
+        let trimmed = line.trim();
+        // 빈 줄과 주석(SFV는 ';', 그 밖에는 '#')은 무시
+        if trimmed.is_empty() || trimmed.starts_with(';') || trimmed.starts_with('#') {
+            continue;
+        }
+
+        let parsed = parse_bsd(trimmed)
+            .or_else(|| parse_gnu(line, hint))
+            .or_else(|| parse_sfv(trimmed));
+        let entry = parsed.and_then(|(format, file_name, algorithm, hash)| {
+            let expected = normalize_hash_input(hash).ok()?;
+            (expected.len() == algorithm.output_len() * 2 && !file_name.is_empty()).then(|| ManifestEntry {
+                line: index + 1,
+                format,
+                file_name,
+                algorithm,
+                expected,
+            })
+        });
+
+        match entry {
+            Some(entry) => manifest.entries.push(entry),
+            None => manifest.malformed_lines.push(index + 1),
+        }
+    }
+
+    manifest
+}
+
+/// `ALGO (file) = hash` 또는 OpenSSL의 `ALGO(file)= hash`
+fn parse_bsd(line: &str) -> Option<(ManifestFormat, String, HashAlgorithm, &str)> {
+    let open = line.find('(')?;
+    // 해시에는 공백이 없으므로 마지막 "= "가 구분자 (Base64 해시 끝의 '=' 패딩과 구분)
+    let (left, hash) = line.rsplit_once("= ")?;
+    let name = left.trim_end().strip_suffix(')')?.get(open + 1..)?;
+    let algorithm = algorithm_from_tag(&line[..open])?;
+    Some((ManifestFormat::Bsd, name.to_string(), algorithm, hash.trim()))
+}
+
+/// `hash  file`(텍스트 모드) 또는 `hash *file`(바이너리 모드)
+fn parse_gnu(line: &str, hint: Option<HashAlgorithm>) -> Option<(ManifestFormat, String, HashAlgorithm, &str)> {
+    // 이름에 줄바꿈이나 역슬래시가 있으면 coreutils는 줄 앞에 '\'를 붙이고 이름을 이스케이프함
+    let (escaped, line) = match line.strip_prefix('\\') {
+        Some(rest) => (true, rest),
+        None => (false, line),
+    };
+    let (hash, rest) = line.split_once(' ')?;
+    let name = rest
+        .strip_prefix(' ')
+        .or_else(|| rest.strip_prefix('*'))
+        .unwrap_or(rest);
+    if !hash.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+
+    let algorithm = algorithm_for_length(hash.len() / 2, hint)?;
+    let name = if escaped { unescape_name(name) } else { name.to_string() };
+    Some((ManifestFormat::Gnu, name, algorithm, hash))
+}
+
+/// `file CRC32`
+fn parse_sfv(line: &str) -> Option<(ManifestFormat, String, HashAlgorithm, &str)> {
+    let (name, hash) = line.rsplit_once(|c: char| c.is_whitespace())?;
+    if hash.len() != 8 || !hash.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+    Some((ManifestFormat::Sfv, name.trim_end().to_string(), HashAlgorithm::Crc32, hash))
+}
+
+fn unescape_name(name: &str) -> String {
+    let mut result = String::with_capacity(name.len());
+    let mut chars = name.chars();
+    while let Some(c) = chars.next() {
+        match (c, chars.clone().next()) {
+            ('\\', Some('n')) => {
+                result.push('\n');
+                chars.next();
+            }
+            ('\\', Some('\\')) => {
+                result.push('\\');
+                chars.next();
+            }
+            _ => result.push(c),
+        }
+    }
+    result
+}
+
+/// GNU 형식에서 해시 길이(바이트)로 알고리즘을 정합니다.
+fn algorithm_for_length(len: usize, hint: Option<HashAlgorithm>) -> Option<HashAlgorithm> {
+    if let Some(hint) = hint.filter(|hint| hint.output_len() == len) {
+        return Some(hint);
+    }
+    // 4바이트(CRC32)는 파일 이름이 8자리 hex인 SFV 줄과 구별할 수 없어 힌트가 있을 때만 인정
+    match len {
+        16 => Some(HashAlgorithm::Md5),
+        20 => Some(HashAlgorithm::Sha1),
+        28 => Some(HashAlgorithm::Sha224),
+        32 => Some(HashAlgorithm::Sha256),
+        48 => Some(HashAlgorithm::Sha384),
+        64 => Some(HashAlgorithm::Sha512),
+        _ => None,
+    }
+}
+
+/// BSD/OpenSSL 태그 이름 ("SHA256", "SHA512t256", "SHA2-256", "BLAKE2b", "RMD160" 등)
+fn algorithm_from_tag(tag: &str) -> Option<HashAlgorithm> {
+    let simplified = simplify(tag);
+    let key = match simplified.as_str() {
+        "sha2224" => "sha224",
+        "sha2256" => "sha256",
+        "sha2384" => "sha384",
+        "sha2512" => "sha512",
+        "sha512t256" | "sha2512256" => "sha512256",
+        "blake2b512" => "blake2b",
+        "rmd160" => "ripemd160",
+        other => other,
+    };
+    HashAlgorithm::all()
+        .into_iter()
+        .find(|algorithm| simplify(algorithm.key()) == key)
+}
+
+/// 매니페스트 파일 이름에서 알고리즘을 추정합니다. ("SHA256SUMS", "sha512sum.txt", "files.md5", "release.sfv")
+pub fn algorithm_from_manifest_name(name: &str) -> Option<HashAlgorithm> {
+    let lower = base_name(name).to_lowercase();
+    // b2sum/b3sum 출력은 흔히 ".b2", ".b3" 확장자로 배포됨
+    match lower.rsplit_once('.') {
+        Some((_, "b2")) => return Some(HashAlgorithm::Blake2b),
+        Some((_, "b3")) => return Some(HashAlgorithm::Blake3),
+        _ => {}
+    }
+
+    let name = simplify(&lower);
+    // 긴 이름부터 확인 ("sha512256"이 "sha512"로, "sha3256"이 "sha256"으로 잘못 잡히지 않도록)
+    const PATTERNS: &[(&str, HashAlgorithm)] = &[
+        ("sha512256", HashAlgorithm::Sha512_256),
+        ("sha3512", HashAlgorithm::Sha3_512),
+        ("sha3256", HashAlgorithm::Sha3_256),
+        ("sha224", HashAlgorithm::Sha224),
+        ("sha256", HashAlgorithm::Sha256),
+        ("sha384", HashAlgorithm::Sha384),
+        ("sha512", HashAlgorithm::Sha512),
+        ("sha1", HashAlgorithm::Sha1),
+        ("md5", HashAlgorithm::Md5),
+        ("blake2", HashAlgorithm::Blake2b),
+        ("b2sum", HashAlgorithm::Blake2b),
+        ("blake3", HashAlgorithm::Blake3),
+        ("b3sum", HashAlgorithm::Blake3),
+        ("ripemd160", HashAlgorithm::Ripemd160),
+        ("rmd160", HashAlgorithm::Ripemd160),
+        ("crc32", HashAlgorithm::Crc32),
+        ("sfv", HashAlgorithm::Crc32),
+    ];
+    PATTERNS
+        .iter()
+        .find(|(pattern, _)| name.contains(pattern))
+        .map(|(_, algorithm)| *algorithm)
+}
+
+/// 매니페스트로 보이는 파일 이름인지 확인합니다. 여러 파일을 한 번에 받았을 때 매니페스트를 골라내는 데 씁니다.
+pub fn is_manifest_name(name: &str) -> bool {
+    let lower = base_name(name).to_lowercase();
+    let extension = lower.rsplit_once('.').map(|(_, extension)| extension).unwrap_or("");
+    lower.contains("sums")
+        || lower.contains("checksum")
+        || matches!(
+            extension,
+            "md5" | "sha1" | "sha224" | "sha256" | "sha384" | "sha512" | "b2" | "b3" | "sfv"
+        )
+}
+
+/// 매니페스트 줄이 가리키는 파일을 찾은 결과
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FileMatch {
+    /// `file_names`에서의 번호
+    Found(usize),
+    Missing,
+    Ambiguous,
+}
+
+/// `file_names`에서 매니페스트 경로가 가리키는 파일을 찾습니다.
+///
+/// 경로가 같은 파일을 먼저 찾고, 없으면 파일 이름(마지막 구성 요소)으로 찾습니다.
+/// 파일 이름만 같은 후보가 둘 이상이면 (`sub1/f`와 `sub2/f`) 어느 쪽인지 알 수 없으므로 `Ambiguous`입니다.
+pub fn find_file<S: AsRef<str>>(manifest_path: &str, file_names: &[S]) -> FileMatch {
+    let strip = |path: &str| path.strip_prefix("./").unwrap_or(path).replace('\\', "/");
+    let manifest_path = strip(manifest_path);
+    if let Some(index) = file_names.iter().position(|name| strip(name.as_ref()) == manifest_path) {
+        return FileMatch::Found(index);
+    }
+
+    let mut candidates = file_names
+        .iter()
+        .enumerate()
+        .filter(|(_, name)| base_name(name.as_ref()) == base_name(&manifest_path))
+        .map(|(index, _)| index);
+    match (candidates.next(), candidates.next()) {
+        (Some(index), None) => FileMatch::Found(index),
+        (Some(_), Some(_)) => FileMatch::Ambiguous,
+        (None, _) => FileMatch::Missing,
+    }
+}
+
+/// 매니페스트의 모든 줄을 파일별 계산 결과와 비교합니다. `files`는 (파일 이름, 결과) 목록입니다.
+///
+/// 같은 이름의 파일을 구분할 수 있도록, 읽지 못했거나 해시하지 않은 파일도 빈 결과로 넣어야 합니다.
+pub fn check_all(manifest: &Manifest, files: &[(String, HashResults)]) -> Vec<ManifestCheck> {
+    let names: Vec<&str> = files.iter().map(|(name, _)| name.as_str()).collect();
+    manifest
+        .entries
+        .iter()
+        .map(|entry| match find_file(&entry.file_name, &names) {
+            FileMatch::Found(index) => check(entry, Some(&files[index].1)),
+            FileMatch::Missing => check(entry, None),
+            FileMatch::Ambiguous => ManifestCheck {
+                entry: entry.clone(),
+                status: CheckStatus::Ambiguous,
+                actual: None,
+            },
+        })
+        .collect()
+}
+
+/// 매니페스트 한 줄을 계산 결과와 비교합니다. 결과가 없거나 해당 알고리즘을 계산하지 않았으면 MISSING입니다.
+pub fn check(entry: &ManifestEntry, results: Option<&HashResults>) -> ManifestCheck {
+    let actual = results.and_then(|results| results.get(entry.algorithm)).map(str::to_lowercase);
+    let status = match &actual {
+        None => CheckStatus::Missing,
+        Some(actual) if *actual == entry.expected => CheckStatus::Ok,
+        Some(_) => CheckStatus::Failed,
+    };
+    ManifestCheck {
+        entry: entry.clone(),
+        status,
+        actual,
+    }
+}
+
+pub fn summarize(checks: &[ManifestCheck]) -> CheckSummary {
+    let mut summary = CheckSummary::default();
+    for check in checks {
+        match check.status {
+            CheckStatus::Ok => summary.ok += 1,
+            CheckStatus::Failed => summary.failed += 1,
+            CheckStatus::Missing => summary.missing += 1,
+            CheckStatus::Ambiguous => summary.ambiguous += 1,
+        }
+    }
+    summary
+}
+
+/// 생성할 매니페스트 형식
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ManifestStyle {
+    /// `hash  file` (`sha256sum` 출력과 같음)
+    Gnu,
+    /// `SHA256 (file) = hash` (`sha256sum --tag` 출력과 같음)
+    Bsd,
+    /// `file CRC32`
+    Sfv,
+    /// 파일별 이름, 크기, 계산한 모든 해시
+    Json,
+}
+
+impl ManifestStyle {
+    pub fn all() -> [ManifestStyle; 4] {
+        [ManifestStyle::Gnu, ManifestStyle::Bsd, ManifestStyle::Sfv, ManifestStyle::Json]
+    }
+
+    pub fn key(&self) -> &'static str {
+        match self {
+            ManifestStyle::Gnu => "gnu",
+            ManifestStyle::Bsd => "bsd",
+            ManifestStyle::Sfv => "sfv",
+            ManifestStyle::Json => "json",
+        }
+    }
+
+    pub fn from_key(key: &str) -> Option<Self> {
+        ManifestStyle::all().into_iter().find(|style| style.key() == key)
+    }
+
+    /// 이 형식에 쓰이는 알고리즘. SFV는 항상 CRC32이고, JSON은 선택한 모든 알고리즘을 쓰므로 `None`입니다.
+    pub fn algorithm(&self, selected: HashAlgorithm) -> Option<HashAlgorithm> {
+        match self {
+            ManifestStyle::Gnu | ManifestStyle::Bsd => Some(selected),
+            ManifestStyle::Sfv => Some(HashAlgorithm::Crc32),
+            ManifestStyle::Json => None,
+        }
+    }
+
+    /// 다운로드할 때 쓰는 기본 파일 이름 ("SHA256SUMS", "B2SUMS", "checksums.sfv" 등)
+    pub fn file_name(&self, algorithm: HashAlgorithm) -> String {
+        match self {
+            ManifestStyle::Gnu | ManifestStyle::Bsd => match algorithm {
+                HashAlgorithm::Blake2b => "B2SUMS".to_string(),
+                HashAlgorithm::Blake3 => "B3SUMS".to_string(),
+                _ => format!("{}SUMS", bsd_tag(algorithm).replace('/', "_").to_uppercase()),
+            },
+            ManifestStyle::Sfv => "checksums.sfv".to_string(),
+            ManifestStyle::Json => "checksums.json".to_string(),
+        }
+    }
+}
+
+/// 매니페스트를 만들 파일 하나의 계산 결과
+#[derive(Debug, Clone, PartialEq)]
+pub struct HashedFile {
+    /// 매니페스트에 적을 이름 (디렉터리를 올렸으면 상대 경로)
+    pub name: String,
+    pub size: u64,
+    pub results: HashResults,
+}
+
+/// 계산한 결과로 매니페스트를 만듭니다. GNU/BSD 형식은 `algorithm`을, SFV는 CRC32를 쓰고,
+/// JSON에는 파일마다 계산한 모든 해시를 넣습니다.
+pub fn generate(files: &[HashedFile], style: ManifestStyle, algorithm: HashAlgorithm) -> Result<String, String> {
+    if style == ManifestStyle::Json {
+        let entries: Vec<serde_json::Value> = files
+            .iter()
+            .map(|file| {
+                let mut object = serde_json::Map::new();
+                object.insert("file".to_string(), file.name.clone().into());
+                object.insert("size".to_string(), file.size.into());
+                for (algorithm, hash) in &file.results.hashes {
+                    object.insert(algorithm.key().to_string(), hash.clone().into());
+                }
+                serde_json::Value::Object(object)
+            })
+            .collect();
+        return serde_json::to_string_pretty(&entries)
+            .map(|json| json + "\n")
+            .map_err(|e| e.to_string());
+    }
+
+    let algorithm = style.algorithm(algorithm).unwrap_or(algorithm);
+    let mut output = String::new();
+    for file in files {
+        let hash = file
+            .results
+            .get(algorithm)
+            .ok_or_else(|| format!("{} was not computed for {}.", algorithm.name(), file.name))?;
+        let line = match style {
+            ManifestStyle::Gnu if file.name.contains(['\\', '\n']) => {
+                // coreutils와 같이 줄 앞에 '\'를 붙이고 이름을 이스케이프
+                format!("\\{}  {}", hash, file.name.replace('\\', "\\\\").replace('\n', "\\n"))
+            }
+            ManifestStyle::Gnu => format!("{}  {}", hash, file.name),
+            ManifestStyle::Bsd => format!("{} ({}) = {}", bsd_tag(algorithm), file.name, hash),
+            _ => format!("{} {}", file.name, hash.to_uppercase()),
+        };
+        output.push_str(&line);
+        output.push('\n');
+    }
+    Ok(output)
+}
+
+/// BSD 태그 이름 ("SHA256", "SHA3-256", "BLAKE2b"). coreutils `--tag` 출력과 같습니다.
+fn bsd_tag(algorithm: HashAlgorithm) -> String {
+    algorithm.name().replacen("SHA-", "SHA", 1)
+}
+
+fn base_name(path: &str) -> &str {
+    path.rsplit(['/', '\\']).next().unwrap_or(path)
+}
+
+fn simplify(name: &str) -> String {
+    name.to_lowercase().chars().filter(|c| c.is_ascii_alphanumeric()).collect()
+}
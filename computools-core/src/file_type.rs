@@ -0,0 +1,334 @@
+//! 매직 바이트(파일 시그니처)로 파일 종류를 알아냅니다.
+//!
+//! Base64 도구의 디코딩 결과 미리보기/다운로드 확장자와 파일 해시 도구의 파일 정보에서 함께 씁니다.
+//! 큰 파일은 앞부분 `SNIFF_LENGTH` 바이트만 넘겨도 됩니다.
+
+use crate::preview::{decode_text, is_readable, TextEncoding};
+
+/// 판별에 쓰는 앞부분 길이 (ZIP 안의 파일 이름을 찾을 수 있을 만큼)
+pub const SNIFF_LENGTH: usize = 64 * 1024;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FileType {
+    pub name: &'static str,      // 사람이 읽는 이름 (예: "PNG image")
+    pub mime_type: &'static str, // MIME 타입
+    pub extension: &'static str, // 다운로드할 때 쓰는 확장자 (점 없이)
+}
+
+impl FileType {
+    const fn new(name: &'static str, mime_type: &'static str, extension: &'static str) -> Self {
+        Self { name, mime_type, extension }
+    }
+
+    pub fn is_image(&self) -> bool {
+        self.mime_type.starts_with("image/")
+    }
+
+    /// 텍스트로 보여줄 수 있는 종류인지 여부
+    pub fn is_text(&self) -> bool {
+        self.mime_type.starts_with("text/")
+            || matches!(self.mime_type, "application/json" | "application/xml" | "image/svg+xml" | "application/x-pem-file")
+    }
+}
+
+const PNG: FileType = FileType::new("PNG image", "image/png", "png");
+const JPEG: FileType = FileType::new("JPEG image", "image/jpeg", "jpg");
+const GIF: FileType = FileType::new("GIF image", "image/gif", "gif");
+const WEBP: FileType = FileType::new("WebP image", "image/webp", "webp");
+const AVIF: FileType = FileType::new("AVIF image", "image/avif", "avif");
+const HEIC: FileType = FileType::new("HEIC image", "image/heic", "heic");
+const BMP: FileType = FileType::new("BMP image", "image/bmp", "bmp");
+const ICO: FileType = FileType::new("Windows icon", "image/x-icon", "ico");
+const TIFF: FileType = FileType::new("TIFF image", "image/tiff", "tif");
+const SVG: FileType = FileType::new("SVG image", "image/svg+xml", "svg");
+const PDF: FileType = FileType::new("PDF document", "application/pdf", "pdf");
+const ZIP: FileType = FileType::new("ZIP archive", "application/zip", "zip");
+const JAR: FileType = FileType::new("Java archive (JAR)", "application/java-archive", "jar");
+const APK: FileType = FileType::new("Android package (APK)", "application/vnd.android.package-archive", "apk");
+const DOCX: FileType = FileType::new("Word document (DOCX)", "application/vnd.openxmlformats-officedocument.wordprocessingml.document", "docx");
+const XLSX: FileType = FileType::new("Excel workbook (XLSX)", "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet", "xlsx");
+const PPTX: FileType = FileType::new("PowerPoint presentation (PPTX)", "application/vnd.openxmlformats-officedocument.presentationml.presentation", "pptx");
+const EPUB: FileType = FileType::new("EPUB e-book", "application/epub+zip", "epub");
+const ODT: FileType = FileType::new("OpenDocument text", "application/vnd.oasis.opendocument.text", "odt");
+const ODS: FileType = FileType::new("OpenDocument spreadsheet", "application/vnd.oasis.opendocument.spreadsheet", "ods");
+const ODP: FileType = FileType::new("OpenDocument presentation", "application/vnd.oasis.opendocument.presentation", "odp");
+const GZIP: FileType = FileType::new("gzip archive", "application/gzip", "gz");
+const BZIP2: FileType = FileType::new("bzip2 archive", "application/x-bzip2", "bz2");
+const XZ: FileType = FileType::new("XZ archive", "application/x-xz", "xz");
+const ZSTD: FileType = FileType::new("Zstandard archive", "application/zstd", "zst");
+const SEVEN_ZIP: FileType = FileType::new("7-Zip archive", "application/x-7z-compressed", "7z");
+const RAR: FileType = FileType::new("RAR archive", "application/vnd.rar", "rar");
+const TAR: FileType = FileType::new("tar archive", "application/x-tar", "tar");
+const ELF: FileType = FileType::new("ELF executable", "application/x-elf", "bin");
+const EXE: FileType = FileType::new("Windows executable (PE)", "application/vnd.microsoft.portable-executable", "exe");
+const DLL: FileType = FileType::new("Windows DLL (PE)", "application/vnd.microsoft.portable-executable", "dll");
+const MACH_O: FileType = FileType::new("Mach-O executable", "application/x-mach-binary", "bin");
+const JAVA_CLASS: FileType = FileType::new("Java class file", "application/java-vm", "class");
+const WASM: FileType = FileType::new("WebAssembly module", "application/wasm", "wasm");
+const SQLITE: FileType = FileType::new("SQLite database", "application/vnd.sqlite3", "sqlite");
+const MP3: FileType = FileType::new("MP3 audio", "audio/mpeg", "mp3");
+const OGG: FileType = FileType::new("Ogg media", "audio/ogg", "ogg");
+const FLAC: FileType = FileType::new("FLAC audio", "audio/flac", "flac");
+const WAV: FileType = FileType::new("WAV audio", "audio/wav", "wav");
+const M4A: FileType = FileType::new("MPEG-4 audio", "audio/mp4", "m4a");
+const MP4: FileType = FileType::new("MP4 video", "video/mp4", "mp4");
+const MOV: FileType = FileType::new("QuickTime video", "video/quicktime", "mov");
+const AVI: FileType = FileType::new("AVI video", "video/x-msvideo", "avi");
+const WEBM: FileType = FileType::new("WebM video", "video/webm", "webm");
+const MKV: FileType = FileType::new("Matroska video", "video/x-matroska", "mkv");
+const WOFF: FileType = FileType::new("WOFF font", "font/woff", "woff");
+const WOFF2: FileType = FileType::new("WOFF2 font", "font/woff2", "woff2");
+const TTF: FileType = FileType::new("TrueType font", "font/ttf", "ttf");
+const OTF: FileType = FileType::new("OpenType font", "font/otf", "otf");
+const PROTOBUF: FileType = FileType::new("Protocol Buffers message (guess)", "application/x-protobuf", "bin");
+const PEM: FileType = FileType::new("PEM data", "application/x-pem-file", "pem");
+const JSON: FileType = FileType::new("JSON", "application/json", "json");
+const XML: FileType = FileType::new("XML document", "application/xml", "xml");
+const HTML: FileType = FileType::new("HTML document", "text/html", "html");
+const TEXT: FileType = FileType::new("Plain text", "text/plain", "txt");
+const UTF16_TEXT: FileType = FileType::new("UTF-16 text", "text/plain", "txt");
+
+/// (오프셋, 시그니처, 종류). 위에서부터 처음 맞는 것을 씁니다.
+const SIGNATURES: &[(usize, &[u8], FileType)] = &[
+    (0, &[0x89, b'P', b'N', b'G'], PNG),
+    (0, &[0xFF, 0xD8, 0xFF], JPEG),
+    (0, b"GIF87a", GIF),
+    (0, b"GIF89a", GIF),
+    (0, b"II*\0", TIFF),
+    (0, b"MM\0*", TIFF),
+    (0, &[0x00, 0x00, 0x01, 0x00], ICO),
+    (0, b"%PDF-", PDF),
+    (0, &[0x1F, 0x8B, 0x08], GZIP),
+    (0, b"BZh", BZIP2),
+    (0, &[0xFD, b'7', b'z', b'X', b'Z', 0x00], XZ),
+    (0, &[0x28, 0xB5, 0x2F, 0xFD], ZSTD),
+    (0, &[b'7', b'z', 0xBC, 0xAF, 0x27, 0x1C], SEVEN_ZIP),
+    (0, b"Rar!\x1a\x07", RAR),
+    (257, b"ustar", TAR),
+    (0, &[0x7F, b'E', b'L', b'F'], ELF),
+    (0, &[0xFE, 0xED, 0xFA, 0xCE], MACH_O),
+    (0, &[0xFE, 0xED, 0xFA, 0xCF], MACH_O),
+    (0, &[0xCE, 0xFA, 0xED, 0xFE], MACH_O),
+    (0, &[0xCF, 0xFA, 0xED, 0xFE], MACH_O),
+    (0, b"\0asm", WASM),
+    (0, b"SQLite format 3\0", SQLITE),
+    (0, b"OggS", OGG),
+    (0, b"fLaC", FLAC),
+    (0, b"ID3\x02", MP3),
+    (0, b"ID3\x03", MP3),
+    (0, b"ID3\x04", MP3),
+    (0, &[0xFF, 0xFB], MP3),
+    (0, &[0xFF, 0xF3], MP3),
+    (0, &[0xFF, 0xF2], MP3),
+    (0, b"wOFF", WOFF),
+    (0, b"wOF2", WOFF2),
+    (0, b"OTTO", OTF),
+    (0, &[0x00, 0x01, 0x00, 0x00, 0x00], TTF),
+];
+
+/// 데이터의 종류를 알아냅니다. 알 수 없는 바이너리면 `None`입니다.
+pub fn detect_file_type(data: &[u8]) -> Option<FileType> {
+    let data = &data[..data.len().min(SNIFF_LENGTH)];
+    if data.is_empty() {
+        return None;
+    }
+
+    if let Some((_, _, file_type)) = SIGNATURES
+        .iter()
+        .find(|(offset, signature, _)| data.get(*offset..).is_some_and(|rest| rest.starts_with(signature)))
+    {
+        return Some(file_type.clone());
+    }
+
+    detect_container(data)
+        .or_else(|| detect_text(data))
+        .or_else(|| is_protobuf(data).then_some(PROTOBUF))
+}
+
+fn u32_le(data: &[u8], offset: usize) -> Option<usize> {
+    let bytes = data.get(offset..offset + 4)?;
+    Some(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]) as usize)
+}
+
+fn u32_be(data: &[u8], offset: usize) -> Option<usize> {
+    let bytes = data.get(offset..offset + 4)?;
+    Some(u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]) as usize)
+}
+
+fn contains(data: &[u8], needle: &[u8]) -> bool {
+    data.windows(needle.len()).any(|window| window == needle)
+}
+
+/// 헤더 안쪽까지 봐야 구분되는 형식 (RIFF, ISO BMFF, ZIP, PE, BMP, Matroska, CAFEBABE)
+fn detect_container(data: &[u8]) -> Option<FileType> {
+    if data.starts_with(b"RIFF") {
+        return match data.get(8..12)? {
+            b"WEBP" => Some(WEBP),
+            b"WAVE" => Some(WAV),
+            b"AVI " => Some(AVI),
+            _ => None,
+        };
+    }
+
+    if data.get(4..8) == Some(b"ftyp") {
+        return Some(detect_ftyp(data));
+    }
+
+    if data.starts_with(b"PK\x03\x04") || data.starts_with(b"PK\x05\x06") {
+        return Some(detect_zip(data));
+    }
+
+    if data.starts_with(b"MZ") {
+        // DOS 헤더의 e_lfanew가 가리키는 곳에 PE 시그니처가 있어야 함
+        let pe = u32_le(data, 0x3C)?;
+        if data.get(pe..pe + 4)? != b"PE\0\0" {
+            return None;
+        }
+        let characteristics = data.get(pe + 22..pe + 24)?;
+        let is_dll = u16::from_le_bytes([characteristics[0], characteristics[1]]) & 0x2000 != 0;
+        return Some(if is_dll { DLL } else { EXE });
+    }
+
+    if data.starts_with(b"BM") {
+        // "BM"으로 시작하는 텍스트와 구분하려고 DIB 헤더 크기까지 확인
+        return matches!(u32_le(data, 14)?, 12 | 40 | 52 | 56 | 108 | 124).then_some(BMP);
+    }
+
+    if data.starts_with(&[0x1A, 0x45, 0xDF, 0xA3]) {
+        let header = &data[..data.len().min(64)];
+        return Some(if contains(header, b"webm") { WEBM } else { MKV });
+    }
+
+    if data.starts_with(&[0xCA, 0xFE, 0xBA, 0xBE]) {
+        // 유니버설 Mach-O는 아키텍처 수(작은 값), 클래스 파일은 버전(45 이상)이 이어짐
+        return Some(if u32_be(data, 4)? < 20 { MACH_O } else { JAVA_CLASS });
+    }
+
+    None
+}
+
+/// ISO BMFF(`ftyp` 박스)의 브랜드로 AVIF/HEIC/MP4/MOV를 구분합니다.
+fn detect_ftyp(data: &[u8]) -> FileType {
+    let box_end = u32_be(data, 0).unwrap_or(0).min(data.len());
+    let major = data.get(8..12).unwrap_or_default();
+    // 주 브랜드와 호환 브랜드 (12..16은 minor version)
+    let mut brands = std::iter::once(major).chain(data.get(16..box_end).unwrap_or_default().chunks_exact(4));
+
+    if brands.clone().any(|brand| brand == b"avif" || brand == b"avis") {
+        AVIF
+    } else if brands.clone().any(|brand| matches!(brand, b"heic" | b"heix" | b"heim" | b"heis" | b"mif1")) {
+        HEIC
+    } else if major == b"qt  " {
+        MOV
+    } else if brands.any(|brand| brand == b"M4A ") {
+        M4A
+    } else {
+        MP4
+    }
+}
+
+/// 앞부분의 파일 이름으로 ZIP 기반 형식을 구분합니다.
+fn detect_zip(data: &[u8]) -> FileType {
+    // ODF, EPUB은 압축하지 않은 첫 항목 "mimetype"에 MIME 타입을 넣음
+    if data.get(30..38) == Some(b"mimetype") {
+        let rest = &data[38..];
+        for file_type in [EPUB, ODT, ODS, ODP] {
+            if rest.starts_with(file_type.mime_type.as_bytes()) {
+                return file_type;
+            }
+        }
+    }
+
+    if contains(data, b"[Content_Types].xml") {
+        if contains(data, b"word/") {
+            return DOCX;
+        }
+        if contains(data, b"xl/") {
+            return XLSX;
+        }
+        if contains(data, b"ppt/") {
+            return PPTX;
+        }
+    }
+    if contains(data, b"AndroidManifest.xml") {
+        return APK;
+    }
+    if contains(data, b"META-INF/MANIFEST.MF") {
+        return JAR;
+    }
+    ZIP
+}
+
+/// UTF-8 텍스트면 내용으로 JSON, XML, SVG, HTML, PEM을 구분합니다.
+fn detect_text(data: &[u8]) -> Option<FileType> {
+    if matches!(decode_text(data), Some((TextEncoding::Utf16Le | TextEncoding::Utf16Be, _))) {
+        return Some(UTF16_TEXT);
+    }
+    let text = match std::str::from_utf8(data) {
+        Ok(text) => text,
+        // 앞부분만 받은 경우 끝에서 잘린 문자는 허용
+        Err(error) if error.error_len().is_none() => std::str::from_utf8(&data[..error.valid_up_to()]).ok()?,
+        Err(_) => return None,
+    };
+    if !is_readable(text) {
+        return None;
+    }
+
+    let trimmed = text.trim_start_matches('\u{feff}').trim_start();
+    let lowercase_head = trimmed.chars().take(256).collect::<String>().to_ascii_lowercase();
+    if trimmed.starts_with("-----BEGIN ") {
+        Some(PEM)
+    } else if (trimmed.starts_with('{') || trimmed.starts_with('[')) && serde_json::from_str::<serde_json::Value>(trimmed).is_ok() {
+        Some(JSON)
+    } else if lowercase_head.starts_with("<!doctype html") || lowercase_head.starts_with("<html") {
+        Some(HTML)
+    } else if trimmed.starts_with("<svg") || (trimmed.starts_with("<?xml") && contains(data, b"<svg")) {
+        Some(SVG)
+    } else if trimmed.starts_with("<?xml") {
+        Some(XML)
+    } else {
+        Some(TEXT)
+    }
+}
+
+fn read_varint(data: &[u8], position: &mut usize) -> Option<u64> {
+    let mut value = 0u64;
+    for shift in (0..64).step_by(7) {
+        let byte = *data.get(*position)?;
+        *position += 1;
+        value |= u64::from(byte & 0x7F) << shift;
+        if byte & 0x80 == 0 {
+            return Some(value);
+        }
+    }
+    None
+}
+
+/// 전체가 올바른 Protocol Buffers 필드 나열로 읽히는지 확인합니다 (스키마 없이 추정).
+fn is_protobuf(data: &[u8]) -> bool {
+    if data.len() < 4 {
+        return false;
+    }
+    let mut position = 0;
+    while position < data.len() {
+        let Some(key) = read_varint(data, &mut position) else {
+            return false;
+        };
+        let field = key >> 3;
+        if field == 0 || field > 0x1FFF_FFFF {
+            return false;
+        }
+        let skip = match key & 0x07 {
+            0 => read_varint(data, &mut position).map(|_| 0),
+            1 => Some(8),
+            2 => read_varint(data, &mut position),
+            5 => Some(4),
+            _ => None,
+        };
+        match skip.and_then(|skip| position.checked_add(usize::try_from(skip).ok()?)) {
+            Some(end) if end <= data.len() => position = end,
+            _ => return false,
+        }
+    }
+    true
+}
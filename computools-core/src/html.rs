@@ -0,0 +1,359 @@
+//! HTML 엔티티 인코딩/디코딩
+
+use regex::Regex;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum HtmlMode {
+    Encode,
+    Decode,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum EntityStyle {
+    Named,         // &lt; &gt; &amp; &quot; &apos;
+    Decimal,       // &#60; &#62; &#38; &#34; &#39;
+    Hexadecimal,   // &#x3C; &#x3E; &#x26; &#x22; &#x27;
+    MixedNamedHex, // Named for common, hex for others
+    MixedNamedDecimal, // Named for common, decimal for others
+    None,          // No encoding (pass through)
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum SelectiveMode {
+    Essential,     // Only &, <, >, ", '
+    Extended,      // Common HTML entities + accented characters
+    Unicode,       // All non-ASCII characters
+    All,          // Everything that can be encoded
+    Custom,       // User-defined characters
+}
+
+/// 인코딩 설정
+#[derive(Debug, Clone, PartialEq)]
+pub struct EncodeOptions {
+    pub entity_style: EntityStyle,
+    pub selective_mode: SelectiveMode,
+    pub custom_chars: String, // 쉼표로 구분된 사용자 정의 문자 (SelectiveMode::Custom)
+}
+
+impl Default for EncodeOptions {
+    fn default() -> Self {
+        Self {
+            entity_style: EntityStyle::Named,
+            selective_mode: SelectiveMode::Essential,
+            custom_chars: String::new(),
+        }
+    }
+}
+
+/// 선택 모드에 해당하는 문자만 지정한 엔티티 스타일로 인코딩합니다.
+pub fn encode_html(input: &str, options: &EncodeOptions) -> String {
+    // None 모드일 때는 그대로 반환
+    if options.entity_style == EntityStyle::None {
+        return input.to_string();
+    }
+
+    let chars: Vec<char> = input.chars().collect();
+    let mut result = String::new();
+
+    for &ch in &chars {
+        if should_encode_char(ch, options) {
+            result.push_str(&encode_char(ch, &options.entity_style));
+        } else {
+            result.push(ch);
+        }
+    }
+
+    result
+}
+
+pub fn should_encode_char(ch: char, options: &EncodeOptions) -> bool {
+    match options.selective_mode {
+        SelectiveMode::Essential => {
+            matches!(ch, '&' | '<' | '>' | '"' | '\'')
+        }
+        SelectiveMode::Extended => {
+            matches!(ch, '&' | '<' | '>' | '"' | '\'' | '©' | '®' | '™' | '€' | '£' | '¥' | '¢' | '¿' | '¡' | 'á' | 'à' | 'â' | 'ä' | 'ã' | 'å' | 'ą' | 'æ' | 'ć' | 'ç' | 'é' | 'è' | 'ê' | 'ë' | 'ę' | 'í' | 'ì' | 'î' | 'ï' | 'ł' | 'ñ' | 'ó' | 'ò' | 'ô' | 'ö' | 'õ' | 'ø' | 'ś' | 'š' | 'ú' | 'ù' | 'û' | 'ü' | 'ý' | 'ÿ' | 'ź' | 'ž' | 'Á' | 'À' | 'Â' | 'Ä' | 'Ã' | 'Å' | 'Ą' | 'Æ' | 'Ć' | 'Ç' | 'É' | 'È' | 'Ê' | 'Ë' | 'Ę' | 'Í' | 'Ì' | 'Î' | 'Ï' | 'Ł' | 'Ñ' | 'Ó' | 'Ò' | 'Ô' | 'Ö' | 'Õ' | 'Ø' | 'Ś' | 'Š' | 'Ú' | 'Ù' | 'Û' | 'Ü' | 'Ý' | 'Ÿ' | 'Ź' | 'Ž')
+        }
+        SelectiveMode::Unicode => {
+            !ch.is_ascii() || matches!(ch, '&' | '<' | '>' | '"' | '\'')
+        }
+        SelectiveMode::All => {
+            !ch.is_ascii_alphanumeric() && ch != ' ' && ch != '\n' && ch != '\r' && ch != '\t'
+        }
+        SelectiveMode::Custom => {
+            // 쉼표로 구분된 문자들을 파싱하여 정확히 매칭
+            parse_custom_chars(&options.custom_chars).contains(&ch)
+        }
+    }
+}
+
+/// 쉼표로 구분된 사용자 정의 문자 목록을 파싱합니다.
+pub fn parse_custom_chars(custom_chars: &str) -> Vec<char> {
+    if custom_chars.trim().is_empty() {
+        return Vec::new();
+    }
+    
+    custom_chars
+        .split(',')
+        .filter_map(|s| {
+            let trimmed = s.trim();
+            if trimmed.len() == 1 {
+                trimmed.chars().next()
+            } else if trimmed.is_empty() {
+                None
+            } else {
+                // 여러 문자로 이루어진 경우 첫 번째 문자만 사용
+                trimmed.chars().next()
+            }
+        })
+        .collect()
+}
+
+pub fn encode_char(ch: char, entity_style: &EntityStyle) -> String {
+    match entity_style {
+        EntityStyle::Named => encode_char_named(ch),
+        EntityStyle::Decimal => format!("&#{};", ch as u32),
+        EntityStyle::Hexadecimal => format!("&#x{:X};", ch as u32),
+        EntityStyle::MixedNamedHex => {
+            // Named for common characters, hex for others
+            match ch {
+                '&' => "&amp;".to_string(),
+                '<' => "&lt;".to_string(),
+                '>' => "&gt;".to_string(),
+                '"' => "&quot;".to_string(),
+                '\'' => "&apos;".to_string(),
+                ' ' => "&nbsp;".to_string(),
+                '©' => "&copy;".to_string(),
+                '®' => "&reg;".to_string(),
+                '™' => "&trade;".to_string(),
+                _ => format!("&#x{:X};", ch as u32),
+            }
+        }
+        EntityStyle::MixedNamedDecimal => {
+            // Named for common characters, decimal for others
+            match ch {
+                '&' => "&amp;".to_string(),
+                '<' => "&lt;".to_string(),
+                '>' => "&gt;".to_string(),
+                '"' => "&quot;".to_string(),
+                '\'' => "&apos;".to_string(),
+                ' ' => "&nbsp;".to_string(),
+                '©' => "&copy;".to_string(),
+                '®' => "&reg;".to_string(),
+                '™' => "&trade;".to_string(),
+                _ => format!("&#{};", ch as u32),
+            }
+        }
+        EntityStyle::None => {
+            ch.to_string()
+        }
+    }
+}
+
+pub fn encode_char_named(ch: char) -> String {
+    match ch {
+        '&' => "&amp;".to_string(),
+        '<' => "&lt;".to_string(),
+        '>' => "&gt;".to_string(),
+        '"' => "&quot;".to_string(),
+        '\'' => "&apos;".to_string(),
+        ' ' => "&nbsp;".to_string(),
+        '©' => "&copy;".to_string(),
+        '®' => "&reg;".to_string(),
+        '™' => "&trade;".to_string(),
+        '€' => "&euro;".to_string(),
+        '£' => "&pound;".to_string(),
+        '¥' => "&yen;".to_string(),
+        '¢' => "&cent;".to_string(),
+        'á' => "&aacute;".to_string(),
+        'à' => "&agrave;".to_string(),
+        'â' => "&acirc;".to_string(),
+        'ä' => "&auml;".to_string(),
+        'ã' => "&atilde;".to_string(),
+        'å' => "&aring;".to_string(),
+        'æ' => "&aelig;".to_string(),
+        'ç' => "&ccedil;".to_string(),
+        'é' => "&eacute;".to_string(),
+        'è' => "&egrave;".to_string(),
+        'ê' => "&ecirc;".to_string(),
+        'ë' => "&euml;".to_string(),
+        'í' => "&iacute;".to_string(),
+        'ì' => "&igrave;".to_string(),
+        'î' => "&icirc;".to_string(),
+        'ï' => "&iuml;".to_string(),
+        'ñ' => "&ntilde;".to_string(),
+        'ó' => "&oacute;".to_string(),
+        'ò' => "&ograve;".to_string(),
+        'ô' => "&ocirc;".to_string(),
+        'ö' => "&ouml;".to_string(),
+        'õ' => "&otilde;".to_string(),
+        'ø' => "&oslash;".to_string(),
+        'ú' => "&uacute;".to_string(),
+        'ù' => "&ugrave;".to_string(),
+        'û' => "&ucirc;".to_string(),
+        'ü' => "&uuml;".to_string(),
+        'ý' => "&yacute;".to_string(),
+        'ÿ' => "&yuml;".to_string(),
+        // 대문자 버전들
+        'Á' => "&Aacute;".to_string(),
+        'À' => "&Agrave;".to_string(),
+        'Â' => "&Acirc;".to_string(),
+        'Ä' => "&Auml;".to_string(),
+        'Ã' => "&Atilde;".to_string(),
+        'Å' => "&Aring;".to_string(),
+        'Æ' => "&AElig;".to_string(),
+        'Ç' => "&Ccedil;".to_string(),
+        'É' => "&Eacute;".to_string(),
+        'È' => "&Egrave;".to_string(),
+        'Ê' => "&Ecirc;".to_string(),
+        'Ë' => "&Euml;".to_string(),
+        'Í' => "&Iacute;".to_string(),
+        'Ì' => "&Igrave;".to_string(),
+        'Î' => "&Icirc;".to_string(),
+        'Ï' => "&Iuml;".to_string(),
+        'Ñ' => "&Ntilde;".to_string(),
+        'Ó' => "&Oacute;".to_string(),
+        'Ò' => "&Ograve;".to_string(),
+        'Ô' => "&Ocirc;".to_string(),
+        'Ö' => "&Ouml;".to_string(),
+        'Õ' => "&Otilde;".to_string(),
+        'Ø' => "&Oslash;".to_string(),
+        'Ú' => "&Uacute;".to_string(),
+        'Ù' => "&Ugrave;".to_string(),
+        'Û' => "&Ucirc;".to_string(),
+        'Ü' => "&Uuml;".to_string(),
+        'Ý' => "&Yacute;".to_string(),
+        // 명명된 엔티티가 없는 경우 16진수로 폴백
+        _ => format!("&#x{:X};", ch as u32),
+    }
+}
+
+/// 명명된 엔티티와 10진수/16진수 숫자 엔티티를 디코딩합니다.
+pub fn decode_html(input: &str) -> Result<String, String> {
+    if input.trim().is_empty() {
+        return Ok(String::new());
+    }
+
+    // 불완전/잘못된 엔티티 경고는 detect_invalid_entities()로 별도 확인
+    let named_entities = [
+        ("&amp;", "&"),
+        ("&lt;", "<"),
+        ("&gt;", ">"),
+        ("&quot;", "\""),
+        ("&apos;", "'"),
+        ("&nbsp;", " "),
+        ("&copy;", "©"),
+        ("&reg;", "®"),
+        ("&trade;", "™"),
+        ("&euro;", "€"),
+        ("&pound;", "£"),
+        ("&yen;", "¥"),
+        ("&cent;", "¢"),
+        // 라틴 문자들
+        ("&aacute;", "á"), ("&agrave;", "à"), ("&acirc;", "â"), ("&auml;", "ä"),
+        ("&atilde;", "ã"), ("&aring;", "å"), ("&aelig;", "æ"), ("&ccedil;", "ç"),
+        ("&eacute;", "é"), ("&egrave;", "è"), ("&ecirc;", "ê"), ("&euml;", "ë"),
+        ("&iacute;", "í"), ("&igrave;", "ì"), ("&icirc;", "î"), ("&iuml;", "ï"),
+        ("&ntilde;", "ñ"), ("&oacute;", "ó"), ("&ograve;", "ò"), ("&ocirc;", "ô"),
+        ("&ouml;", "ö"), ("&otilde;", "õ"), ("&oslash;", "ø"), ("&uacute;", "ú"),
+        ("&ugrave;", "ù"), ("&ucirc;", "û"), ("&uuml;", "ü"), ("&yacute;", "ý"),
+        ("&yuml;", "ÿ"),
+        // 대문자들
+        ("&Aacute;", "Á"), ("&Agrave;", "À"), ("&Acirc;", "Â"), ("&Auml;", "Ä"),
+        ("&Atilde;", "Ã"), ("&Aring;", "Å"), ("&AElig;", "Æ"), ("&Ccedil;", "Ç"),
+        ("&Eacute;", "É"), ("&Egrave;", "È"), ("&Ecirc;", "Ê"), ("&Euml;", "Ë"),
+        ("&Iacute;", "Í"), ("&Igrave;", "Ì"), ("&Icirc;", "Î"), ("&Iuml;", "Ï"),
+        ("&Ntilde;", "Ñ"), ("&Oacute;", "Ó"), ("&Ograve;", "Ò"), ("&Ocirc;", "Ô"),
+        ("&Ouml;", "Ö"), ("&Otilde;", "Õ"), ("&Oslash;", "Ø"), ("&Uacute;", "Ú"),
+        ("&Ugrave;", "Ù"), ("&Ucirc;", "Û"), ("&Uuml;", "Ü"), ("&Yacute;", "Ý"),
+    ];
+    
+    // 모든 엔티티를 한 번에 찾아 치환하므로 "&amp;lt;" 같은 입력이 두 번 디코딩되지 않음
+    let entity_re = Regex::new(r"&(#[xX][0-9A-Fa-f]+|#[0-9]+|[A-Za-z]+);").map_err(|_| "Regex compilation failed")?;
+
+    let decoded = entity_re.replace_all(input, |caps: &regex::Captures| {
+        let body = &caps[1];
+        let character = if let Some(hex_str) = body.strip_prefix("#x").or_else(|| body.strip_prefix("#X")) {
+            // 16진수 엔티티 (&#x3C;)
+            u32::from_str_radix(hex_str, 16).ok().and_then(char::from_u32).map(String::from)
+        } else if let Some(decimal_str) = body.strip_prefix('#') {
+            // 10진수 엔티티 (&#60;)
+            decimal_str.parse::<u32>().ok().and_then(char::from_u32).map(String::from)
+        } else {
+            named_entities
+                .iter()
+                .find(|(entity, _)| &entity[1..entity.len() - 1] == body)
+                .map(|(_, replacement)| replacement.to_string())
+        };
+        // 알 수 없는 이름이나 유효하지 않은 코드 포인트는 원래 문자열 유지
+        character.unwrap_or_else(|| caps[0].to_string())
+    });
+
+    Ok(decoded.into_owned())
+}
+
+/// 불완전하거나 알 수 없는 엔티티를 찾아 위치와 함께 경고 메시지를 반환합니다.
+pub fn detect_invalid_entities(input: &str) -> Option<String> {
+    use std::collections::HashSet;
+    let known_entities: HashSet<&'static str> = [
+        "&amp;", "&lt;", "&gt;", "&quot;", "&apos;", "&nbsp;", "&copy;", "&reg;", "&trade;", "&euro;", "&pound;", "&yen;", "&cent;",
+        "&aacute;", "&agrave;", "&acirc;", "&auml;", "&atilde;", "&aring;", "&aelig;", "&ccedil;", "&eacute;", "&egrave;", "&ecirc;", "&euml;",
+        "&iacute;", "&igrave;", "&icirc;", "&iuml;", "&ntilde;", "&oacute;", "&ograve;", "&ocirc;", "&ouml;", "&otilde;", "&oslash;", "&uacute;",
+        "&ugrave;", "&ucirc;", "&uuml;", "&yacute;", "&yuml;",
+        "&Aacute;", "&Agrave;", "&Acirc;", "&Auml;", "&Atilde;", "&Aring;", "&AElig;", "&Ccedil;", "&Eacute;", "&Egrave;", "&Ecirc;", "&Euml;",
+        "&Iacute;", "&Igrave;", "&Icirc;", "&Iuml;", "&Ntilde;", "&Oacute;", "&Ograve;", "&Ocirc;", "&Ouml;", "&Otilde;", "&Oslash;", "&Uacute;",
+        "&Ugrave;", "&Ucirc;", "&Uuml;", "&Yacute;"
+    ].iter().cloned().collect();
+    let re = Regex::new(r"&[#a-zA-Z0-9xX]+;?").unwrap();
+    for mat in re.find_iter(input) {
+        let entity = mat.as_str();
+        let idx = mat.start();
+        // 줄 번호, 열 번호 계산
+        let (line, col) = {
+            let mut line = 1;
+            let mut last_newline = 0;
+            for (i, c) in input[..idx].char_indices() {
+                if c == '\n' {
+                    line += 1;
+                    last_newline = i + 1;
+                }
+            }
+            (line, idx - last_newline + 1)
+        };
+        let pos_info = format!(" at line {}, column {}", line, col);
+        if entity.starts_with("&#x") || entity.starts_with("&#X") {
+            // 16진수 엔티티
+            let hex = entity.trim_start_matches("&#x").trim_start_matches("&#X").trim_end_matches(';');
+            if hex.is_empty() {
+                return Some(format!("Invalid hexadecimal entity (no value): {}{}", entity, pos_info));
+            }
+            if u32::from_str_radix(hex, 16).is_err() {
+                return Some(format!("Invalid hexadecimal entity: {}{}", entity, pos_info));
+            }
+            if !entity.ends_with(';') {
+                return Some(format!("Incomplete hexadecimal entity (missing semicolon): {}{}", entity, pos_info));
+            }
+        } else if entity.starts_with("&#") {
+            // 10진수 엔티티
+            let dec = entity.trim_start_matches("&#").trim_end_matches(';');
+            if dec.is_empty() {
+                return Some(format!("Invalid decimal entity (no value): {}{}", entity, pos_info));
+            }
+            if dec.parse::<u32>().is_err() {
+                return Some(format!("Invalid decimal entity: {}{}", entity, pos_info));
+            }
+            if !entity.ends_with(';') {
+                return Some(format!("Incomplete decimal entity (missing semicolon): {}{}", entity, pos_info));
+            }
+        } else if entity.ends_with(';') {
+            // 명명된 엔티티
+            if !known_entities.contains(entity) {
+                return Some(format!("Unknown named entity: {}{}", entity, pos_info));
+            }
+        } else {
+            // 세미콜론 없는 불완전 명명 엔티티
+            return Some(format!("Incomplete named entity (missing semicolon): {}{}", entity, pos_info));
+        }
+    }
+    None
+}
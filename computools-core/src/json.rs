@@ -0,0 +1,248 @@
+//! JSON 포맷팅, YAML 변환, 트리/테이블 뷰 데이터 생성
+
+use serde::ser::Serialize;
+use serde_json::ser::{PrettyFormatter, Serializer};
+use std::io::Cursor;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum JsonViewMode {
+    Formatted,
+    TreeView,
+    Yaml,
+    Table,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TableColumn {
+    pub name: String,
+    pub data_type: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TableRow {
+    pub values: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum SortDirection {
+    Ascending,
+    Descending,
+    None,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TableState {
+    pub sort_column: Option<String>,
+    pub sort_direction: SortDirection,
+    pub current_page: usize,
+    pub rows_per_page: usize,
+    pub search_query: String,
+}
+
+impl Default for TableState {
+    fn default() -> Self {
+        Self {
+            sort_column: None,
+            sort_direction: SortDirection::None,
+            current_page: 0,
+            rows_per_page: 5,
+            search_query: String::new(),
+        }
+    }
+}
+
+/// 탭 스타일 이름("2space", "3space", "4space", "compact", "1tab")을 들여쓰기 바이트로 변환합니다.
+pub fn indent_for_style(tab_style: &str) -> Vec<u8> {
+    match tab_style {
+        "2space" => vec![b' '; 2],
+        "3space" => vec![b' '; 3],
+        "4space" => vec![b' '; 4],
+        "compact" => vec![],
+        "1tab" => vec![b'\t'],
+        _ => vec![b' '; 4],
+    }
+}
+
+/// 파싱 오류 위치를 표시한 에러 메시지를 만듭니다.
+pub fn format_error_message(input: &str, err: serde_json::Error) -> String {
+    let line = err.line();
+    let column = err.column();
+    let lines: Vec<&str> = input.lines().collect();
+
+    if line > 0 && line <= lines.len() {
+        let error_line = lines[line - 1];
+        let marker = format!("{}^", "-".repeat(column.saturating_sub(1)));
+        return format!("Invalid JSON:\n{}\n{}\nError: {}", error_line, marker, err);
+    }
+    format!("Invalid JSON: {}", err)
+}
+
+/// 키 순서를 유지한 채 지정한 들여쓰기로 직렬화합니다. 들여쓰기가 비어 있으면 한 줄로 출력합니다.
+pub fn format_json_with_order_preservation(json_value: &serde_json::Value, indent: &[u8]) -> String {
+    let mut output = Vec::new();
+    
+    if indent.is_empty() {
+        let mut serializer = Serializer::with_formatter(
+            Cursor::new(&mut output),
+            serde_json::ser::CompactFormatter,
+        );
+        json_value.serialize(&mut serializer).unwrap();
+    } else {
+        let formatter = PrettyFormatter::with_indent(indent);
+        let mut serializer = Serializer::with_formatter(Cursor::new(&mut output), formatter);
+        json_value.serialize(&mut serializer).unwrap();
+    }
+    
+    String::from_utf8(output).unwrap()
+}
+
+pub fn convert_to_yaml_with_order_preservation(json_value: &serde_json::Value) -> String {
+    match serde_yaml::to_string(json_value) {
+        Ok(yaml_string) => yaml_string,
+        Err(_) => "Error converting to YAML".to_string()
+    }
+}
+
+pub fn get_type_icon(value: &serde_json::Value) -> &'static str {
+    match value {
+        serde_json::Value::String(_) => "📄",
+        serde_json::Value::Number(_) => "🔢",
+        serde_json::Value::Bool(true) => "✅",
+        serde_json::Value::Bool(false) => "❌",
+        serde_json::Value::Null => "❓",
+        serde_json::Value::Object(_) => "📁",
+        serde_json::Value::Array(_) => "📋",
+    }
+}
+
+pub fn format_value(value: &serde_json::Value) -> String {
+    match value {
+        serde_json::Value::String(s) => format!("\"{}\"", s),
+        serde_json::Value::Number(n) => n.to_string(),
+        serde_json::Value::Bool(b) => b.to_string(),
+        serde_json::Value::Null => "null".to_string(),
+        serde_json::Value::Object(obj) => format!("{{ {} items }}", obj.len()),
+        serde_json::Value::Array(arr) => format!("[ {} items ]", arr.len()),
+    }
+}
+
+/// 객체 배열을 테이블 컬럼/행으로 변환합니다. 컬럼 순서는 첫 번째 객체를 따릅니다.
+pub fn convert_to_table_data(value: &serde_json::Value) -> Option<(Vec<TableColumn>, Vec<TableRow>)> {
+    match value {
+        serde_json::Value::Array(arr) => {
+            if arr.is_empty() {
+                return None;
+            }
+
+            // 첫 번째 객체에서 컬럼 순서를 결정
+            let first_item = &arr[0];
+            let column_order: Vec<String> = if let serde_json::Value::Object(obj) = first_item {
+                obj.keys().cloned().collect()
+            } else {
+                return None;
+            };
+
+            // 컬럼 정보 생성 (첫 번째 객체의 순서 유지)
+            let columns: Vec<TableColumn> = column_order.iter().map(|key| {
+                let data_type = if let serde_json::Value::Object(obj) = first_item {
+                    obj.get(key)
+                        .map(get_value_type)
+                        .unwrap_or_else(|| "unknown".to_string())
+                } else {
+                    "unknown".to_string()
+                };
+                
+                TableColumn {
+                    name: key.clone(),
+                    data_type,
+                }
+            }).collect();
+
+            // 모든 행 데이터 추출 (컬럼 순서 유지)
+            let rows: Vec<TableRow> = arr.iter().filter_map(|item| {
+                if let serde_json::Value::Object(obj) = item {
+                    let values: Vec<String> = column_order.iter().map(|col_name| {
+                        obj.get(col_name)
+                            .map(format_value_for_table)
+                            .unwrap_or_else(|| "".to_string())
+                    }).collect();
+                    Some(TableRow { values })
+                } else {
+                    None
+                }
+            }).collect();
+
+            if rows.is_empty() {
+                None
+            } else {
+                Some((columns, rows))
+            }
+        }
+        _ => None,
+    }
+}
+
+pub fn get_value_type(value: &serde_json::Value) -> String {
+    match value {
+        serde_json::Value::String(_) => "string".to_string(),
+        serde_json::Value::Number(_) => "number".to_string(),
+        serde_json::Value::Bool(_) => "boolean".to_string(),
+        serde_json::Value::Null => "null".to_string(),
+        serde_json::Value::Object(_) => "object".to_string(),
+        serde_json::Value::Array(_) => "array".to_string(),
+    }
+}
+
+pub fn format_value_for_table(value: &serde_json::Value) -> String {
+    match value {
+        serde_json::Value::String(s) => s.clone(),
+        serde_json::Value::Number(n) => n.to_string(),
+        serde_json::Value::Bool(b) => b.to_string(),
+        serde_json::Value::Null => "null".to_string(),
+        serde_json::Value::Object(obj) => format!("{{ {} items }}", obj.len()),
+        serde_json::Value::Array(arr) => format!("[ {} items ]", arr.len()),
+    }
+}
+
+/// 검색어 필터링, 정렬, 페이지네이션을 적용한 행과 전체 페이지 수를 반환합니다.
+pub fn get_sorted_and_paginated_rows(columns: &[TableColumn], rows: &[TableRow], table_state: &TableState) -> (Vec<TableRow>, usize) {
+    let mut filtered_rows = rows.to_vec();
+    
+    // 검색 필터링 적용
+    if !table_state.search_query.is_empty() {
+        let query = table_state.search_query.to_lowercase();
+        filtered_rows.retain(|row| {
+            row.values.iter().any(|value| value.to_lowercase().contains(&query))
+        });
+    }
+    
+    // 정렬이 명시적으로 요청된 경우에만 정렬 적용
+    if let Some(sort_column) = &table_state.sort_column {
+        if let Some(column_index) = columns.iter().position(|col| &col.name == sort_column) {
+            filtered_rows.sort_by(|a, b| {
+                let a_val = &a.values[column_index];
+                let b_val = &b.values[column_index];
+                
+                match table_state.sort_direction {
+                    SortDirection::Ascending => a_val.cmp(b_val),
+                    SortDirection::Descending => b_val.cmp(a_val),
+                    SortDirection::None => std::cmp::Ordering::Equal,
+                }
+            });
+        }
+    }
+    // 정렬이 요청되지 않은 경우 원본 순서 유지
+    
+    // 페이지네이션 적용
+    let total_pages = filtered_rows.len().div_ceil(table_state.rows_per_page);
+    let start_index = table_state.current_page * table_state.rows_per_page;
+    let end_index = std::cmp::min(start_index + table_state.rows_per_page, filtered_rows.len());
+    
+    let paginated_rows = if start_index < filtered_rows.len() {
+        filtered_rows[start_index..end_index].to_vec()
+    } else {
+        vec![]
+    };
+    
+    (paginated_rows, total_pages)
+}
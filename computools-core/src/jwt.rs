@@ -0,0 +1,184 @@
+//! JWT(JWS Compact) 디코딩, HS256/384/512 서명 검증과 서명
+
+use hmac::{Hmac, Mac};
+use serde_json::Value;
+use sha2::{Sha256, Sha384, Sha512};
+
+use crate::base64::{decode_with_mode, encode_with_mode, Base64Mode};
+use crate::json::format_json_with_order_preservation;
+use crate::unixtime::format_duration;
+
+/// 날짜로 표시하는 등록 클레임 (RFC 7519 4.1)
+pub const TIME_CLAIMS: [&str; 3] = ["exp", "nbf", "iat"];
+
+/// 서명과 검증을 지원하는 HMAC 알고리즘
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JwtAlgorithm {
+    Hs256,
+    Hs384,
+    Hs512,
+}
+
+impl JwtAlgorithm {
+    pub fn all() -> [JwtAlgorithm; 3] {
+        [JwtAlgorithm::Hs256, JwtAlgorithm::Hs384, JwtAlgorithm::Hs512]
+    }
+
+    /// 헤더의 `alg` 값 (예: "HS256")
+    pub fn name(&self) -> &'static str {
+        match self {
+            JwtAlgorithm::Hs256 => "HS256",
+            JwtAlgorithm::Hs384 => "HS384",
+            JwtAlgorithm::Hs512 => "HS512",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<JwtAlgorithm> {
+        Self::all().into_iter().find(|algorithm| algorithm.name().eq_ignore_ascii_case(name.trim()))
+    }
+
+    fn mac(&self, secret: &[u8], data: &[u8]) -> Vec<u8> {
+        // HMAC은 모든 길이의 키를 받으므로 new_from_slice는 실패하지 않음
+        match self {
+            JwtAlgorithm::Hs256 => {
+                let mut mac = Hmac::<Sha256>::new_from_slice(secret).expect("HMAC accepts any key length");
+                mac.update(data);
+                mac.finalize().into_bytes().to_vec()
+            }
+            JwtAlgorithm::Hs384 => {
+                let mut mac = Hmac::<Sha384>::new_from_slice(secret).expect("HMAC accepts any key length");
+                mac.update(data);
+                mac.finalize().into_bytes().to_vec()
+            }
+            JwtAlgorithm::Hs512 => {
+                let mut mac = Hmac::<Sha512>::new_from_slice(secret).expect("HMAC accepts any key length");
+                mac.update(data);
+                mac.finalize().into_bytes().to_vec()
+            }
+        }
+    }
+}
+
+/// `header.payload.signature`로 나눈 토큰
+#[derive(Debug, Clone, PartialEq)]
+pub struct DecodedJwt {
+    pub header: Value,
+    pub payload: Value,
+    pub signature: Vec<u8>,
+    /// 서명 대상인 `header.payload` 원문
+    pub signing_input: String,
+}
+
+impl DecodedJwt {
+    /// 헤더의 `alg` 값. 없으면 빈 문자열입니다.
+    pub fn algorithm(&self) -> &str {
+        self.header.get("alg").and_then(Value::as_str).unwrap_or("")
+    }
+}
+
+/// 토큰을 디코딩합니다. 앞의 `Bearer `와 공백은 무시합니다. 서명은 확인하지 않습니다.
+pub fn decode(token: &str) -> Result<DecodedJwt, String> {
+    let token = token.trim();
+    let token = token.strip_prefix("Bearer ").unwrap_or(token).trim();
+    let parts: Vec<&str> = token.split('.').collect();
+
+    match parts.len() {
+        3 => {}
+        5 => return Err("This is an encrypted JWT (JWE); only signed tokens (JWS) can be decoded.".to_string()),
+        count => return Err(format!("A JWT has 3 parts separated by '.', found {}.", count)),
+    }
+
+    let header = decode_json(parts[0], "header")?;
+    let payload = decode_json(parts[1], "payload")?;
+    let signature = decode_segment(parts[2]).map_err(|_| "The signature is not valid Base64URL.".to_string())?;
+
+    Ok(DecodedJwt {
+        header,
+        payload,
+        signature,
+        signing_input: format!("{}.{}", parts[0], parts[1]),
+    })
+}
+
+/// HS256/384/512 서명을 확인합니다. 다른 알고리즘(`none`, RS256 등)은 에러입니다.
+pub fn verify(jwt: &DecodedJwt, secret: &[u8]) -> Result<bool, String> {
+    let algorithm = JwtAlgorithm::from_name(jwt.algorithm()).ok_or_else(|| {
+        format!("Can't verify alg '{}'; only HS256, HS384 and HS512 are supported.", jwt.algorithm())
+    })?;
+
+    let expected = algorithm.mac(secret, jwt.signing_input.as_bytes());
+    // 첫 번째 다른 바이트에서 멈추지 않도록 모든 바이트를 비교 (타이밍 차이 방지)
+    let matches = expected.len() == jwt.signature.len()
+        && expected.iter().zip(&jwt.signature).fold(0u8, |diff, (a, b)| diff | (a ^ b)) == 0;
+    Ok(matches)
+}
+
+/// 헤더와 페이로드로 서명된 토큰을 만듭니다.
+///
+/// 헤더의 `alg`는 `algorithm`으로 덮어쓰고, `typ`이 없으면 "JWT"를 추가합니다.
+pub fn sign(header: &Value, payload: &Value, algorithm: JwtAlgorithm, secret: &[u8]) -> Result<String, String> {
+    let Value::Object(fields) = header else {
+        return Err("The header must be a JSON object.".to_string());
+    };
+    let mut header = fields.clone();
+    header.insert("alg".to_string(), Value::String(algorithm.name().to_string()));
+    header.entry("typ").or_insert_with(|| Value::String("JWT".to_string()));
+
+    let signing_input = format!(
+        "{}.{}",
+        encode_segment(format_json_with_order_preservation(&Value::Object(header), &[]).as_bytes()),
+        encode_segment(format_json_with_order_preservation(payload, &[]).as_bytes())
+    );
+    let signature = algorithm.mac(secret, signing_input.as_bytes());
+
+    Ok(format!("{}.{}", signing_input, encode_segment(&signature)))
+}
+
+/// 페이로드의 `exp`/`nbf`/`iat` 클레임 (이름, 유닉스 초). 숫자가 아닌 값은 건너뜁니다.
+pub fn time_claims(payload: &Value) -> Vec<(&'static str, i64)> {
+    TIME_CLAIMS
+        .iter()
+        .filter_map(|name| {
+            let value = payload.get(*name)?;
+            // NumericDate는 소수일 수 있음 (초 단위로 내림)
+            let seconds = value.as_i64().or_else(|| value.as_f64().map(|seconds| seconds.floor() as i64))?;
+            Some((*name, seconds))
+        })
+        .collect()
+}
+
+/// `now`(유닉스 초) 기준으로 만료/사용 전 상태를 설명하는 경고 목록을 반환합니다.
+pub fn time_warnings(payload: &Value, now: i64) -> Vec<String> {
+    time_claims(payload)
+        .into_iter()
+        .filter_map(|(name, seconds)| match name {
+            "exp" if seconds <= now => Some(format!("Expired {} ago.", format_duration(now - seconds))),
+            "nbf" if seconds > now => Some(format!("Not valid for another {}.", format_duration(seconds - now))),
+            "iat" if seconds > now => Some(format!("Issued {} in the future.", format_duration(seconds - now))),
+            _ => None,
+        })
+        .collect()
+}
+
+fn decode_json(segment: &str, name: &str) -> Result<Value, String> {
+    let bytes = decode_segment(segment).map_err(|_| format!("The {} is not valid Base64URL.", name))?;
+    let text = String::from_utf8(bytes).map_err(|_| format!("The {} is not UTF-8 text.", name))?;
+    match serde_json::from_str::<Value>(&text) {
+        Ok(value @ Value::Object(_)) => Ok(value),
+        Ok(_) => Err(format!("The {} is not a JSON object.", name)),
+        Err(e) => Err(format!("The {} is not valid JSON: {}", name, e)),
+    }
+}
+
+/// 패딩 없는 Base64URL 조각을 디코딩합니다.
+fn decode_segment(segment: &str) -> Result<Vec<u8>, String> {
+    let mut padded = segment.trim_end_matches('=').to_string();
+    while !padded.len().is_multiple_of(4) {
+        padded.push('=');
+    }
+    decode_with_mode(&padded, &Base64Mode::UrlSafe)
+}
+
+fn encode_segment(data: &[u8]) -> String {
+    encode_with_mode(data, &Base64Mode::UrlSafe).trim_end_matches('=').to_string()
+}
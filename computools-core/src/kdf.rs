@@ -0,0 +1,334 @@
+//! 비밀번호 해시와 키 유도 (PBKDF2, HKDF, scrypt, Argon2id, bcrypt)
+//!
+//! 원시 키 유도는 [`derive_key`], 저장 형식 문자열 생성은 [`phc_string`]/[`bcrypt_hash`],
+//! 저장된 해시 확인은 [`inspect_password_hash`]/[`verify_password`]를 사용합니다.
+
+use argon2::{Algorithm as Argon2Algorithm, Argon2, Params as Argon2Params, Version as Argon2Version};
+use hkdf::Hkdf;
+use password_hash::{PasswordHash, PasswordHasher, PasswordVerifier, SaltString};
+use pbkdf2::{Algorithm as Pbkdf2Algorithm, Pbkdf2};
+use scrypt::Scrypt;
+use sha2::{Sha256, Sha512};
+
+/// 유도할 수 있는 최대 키 길이 (바이트)
+pub const MAX_KEY_LENGTH: usize = 1024;
+
+// OWASP Password Storage Cheat Sheet 권장값
+pub const DEFAULT_PBKDF2_ITERATIONS: u32 = 600_000;
+pub const DEFAULT_SCRYPT_LOG_N: u8 = 17;
+pub const DEFAULT_SCRYPT_R: u32 = 8;
+pub const DEFAULT_SCRYPT_P: u32 = 1;
+pub const DEFAULT_ARGON2_MEMORY_KIB: u32 = 19 * 1024;
+pub const DEFAULT_ARGON2_ITERATIONS: u32 = 2;
+pub const DEFAULT_ARGON2_PARALLELISM: u32 = 1;
+pub const DEFAULT_BCRYPT_COST: u32 = 12;
+
+// 비용 상한. 잘못 입력했거나 악의적인 해시 문자열의 비용으로 메모리를 할당하거나
+// 몇 시간씩 계산하기 전에 에러로 거부합니다. 기본값의 수십 배 이상 여유가 있습니다.
+pub const MAX_PBKDF2_ITERATIONS: u32 = 10_000_000;
+/// scrypt가 쓰는 메모리(128 · r · N 바이트)의 상한
+pub const MAX_SCRYPT_MEMORY: u64 = 1024 * 1024 * 1024;
+pub const MAX_SCRYPT_P: u32 = 16;
+pub const MAX_ARGON2_MEMORY_KIB: u32 = 1024 * 1024;
+pub const MAX_ARGON2_ITERATIONS: u32 = 100;
+pub const MAX_ARGON2_PARALLELISM: u32 = 64;
+pub const MAX_BCRYPT_COST: u32 = 20;
+
+/// PBKDF2/HKDF에 쓰는 해시 함수
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KdfHash {
+    Sha256,
+    Sha512,
+}
+
+impl KdfHash {
+    pub fn all() -> [KdfHash; 2] {
+        [KdfHash::Sha256, KdfHash::Sha512]
+    }
+
+    pub fn key(&self) -> &'static str {
+        match self {
+            KdfHash::Sha256 => "sha256",
+            KdfHash::Sha512 => "sha512",
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            KdfHash::Sha256 => "SHA-256",
+            KdfHash::Sha512 => "SHA-512",
+        }
+    }
+
+    pub fn from_key(key: &str) -> Option<KdfHash> {
+        Self::all().into_iter().find(|hash| hash.key() == key)
+    }
+}
+
+/// 키 유도 알고리즘과 파라미터
+#[derive(Debug, Clone, PartialEq)]
+pub enum KdfParams {
+    Pbkdf2 { hash: KdfHash, iterations: u32 },
+    Hkdf { hash: KdfHash, info: Vec<u8> },
+    /// N = 2^log_n
+    Scrypt { log_n: u8, r: u32, p: u32 },
+    Argon2id { memory_kib: u32, iterations: u32, parallelism: u32 },
+}
+
+impl KdfParams {
+    /// 화면과 CLI 출력에 쓰는 이름 (예: "PBKDF2-HMAC-SHA256")
+    pub fn name(&self) -> String {
+        match self {
+            KdfParams::Pbkdf2 { hash, .. } => format!("PBKDF2-HMAC-{}", hash.name().replace('-', "")),
+            KdfParams::Hkdf { hash, .. } => format!("HKDF-{}", hash.name().replace('-', "")),
+            KdfParams::Scrypt { .. } => "scrypt".to_string(),
+            KdfParams::Argon2id { .. } => "Argon2id".to_string(),
+        }
+    }
+}
+
+/// `password`와 `salt`로 `length` 바이트 키를 유도합니다.
+///
+/// HKDF에서는 `password`가 입력 키 재료(IKM)이고, 빈 `salt`는 salt 없음(0으로 채운 블록)으로 취급합니다.
+pub fn derive_key(params: &KdfParams, password: &[u8], salt: &[u8], length: usize) -> Result<Vec<u8>, String> {
+    if length == 0 || length > MAX_KEY_LENGTH {
+        return Err(format!("Key length must be between 1 and {} bytes.", MAX_KEY_LENGTH));
+    }
+    let mut output = vec![0u8; length];
+
+    match params {
+        KdfParams::Pbkdf2 { hash, iterations } => {
+            check_pbkdf2_iterations(*iterations)?;
+            match hash {
+                KdfHash::Sha256 => pbkdf2::pbkdf2_hmac::<Sha256>(password, salt, *iterations, &mut output),
+                KdfHash::Sha512 => pbkdf2::pbkdf2_hmac::<Sha512>(password, salt, *iterations, &mut output),
+            }
+        }
+        KdfParams::Hkdf { hash, info } => {
+            let salt = (!salt.is_empty()).then_some(salt);
+            let expanded = match hash {
+                KdfHash::Sha256 => Hkdf::<Sha256>::new(salt, password).expand(info, &mut output),
+                KdfHash::Sha512 => Hkdf::<Sha512>::new(salt, password).expand(info, &mut output),
+            };
+            expanded.map_err(|_| format!("HKDF-{} can produce at most {} bytes.", hash.name(), 255 * hash_len(*hash)))?;
+        }
+        KdfParams::Scrypt { log_n, r, p } => {
+            let scrypt_params = scrypt_params(*log_n, *r, *p, length)?;
+            scrypt::scrypt(password, salt, &scrypt_params, &mut output)
+                .map_err(|e| format!("scrypt failed: {}", e))?;
+        }
+        KdfParams::Argon2id { memory_kib, iterations, parallelism } => {
+            let argon2 = argon2id(*memory_kib, *iterations, *parallelism, Some(length))?;
+            argon2
+                .hash_password_into(password, salt, &mut output)
+                .map_err(|e| format!("Argon2id failed: {}", e))?;
+        }
+    }
+
+    Ok(output)
+}
+
+/// 유도 결과를 PHC 문자열(`$pbkdf2-sha256$...`, `$scrypt$...`, `$argon2id$...`)로 만듭니다.
+///
+/// PHC 형식의 salt는 4~48 바이트여야 합니다. HKDF는 비밀번호 해시 형식이 없으므로 에러입니다.
+pub fn phc_string(params: &KdfParams, password: &[u8], salt: &[u8], length: usize) -> Result<String, String> {
+    let salt = SaltString::encode_b64(salt).map_err(|e| format!("Salt can't be stored in a PHC string: {}", e))?;
+
+    let hash = match params {
+        KdfParams::Pbkdf2 { hash, iterations } => {
+            let algorithm = match hash {
+                KdfHash::Sha256 => Pbkdf2Algorithm::Pbkdf2Sha256,
+                KdfHash::Sha512 => Pbkdf2Algorithm::Pbkdf2Sha512,
+            };
+            check_pbkdf2_iterations(*iterations)?;
+            let pbkdf2_params = pbkdf2::Params { rounds: *iterations, output_length: length };
+            Pbkdf2.hash_password_customized(password, Some(algorithm.ident()), None, pbkdf2_params, &salt)
+        }
+        KdfParams::Hkdf { .. } => return Err("HKDF has no password hash string format.".to_string()),
+        KdfParams::Scrypt { log_n, r, p } => {
+            Scrypt.hash_password_customized(password, None, None, scrypt_params(*log_n, *r, *p, length)?, &salt)
+        }
+        KdfParams::Argon2id { memory_kib, iterations, parallelism } => {
+            argon2id(*memory_kib, *iterations, *parallelism, Some(length))?.hash_password(password, &salt)
+        }
+    };
+
+    hash.map(|hash| hash.to_string()).map_err(|e| e.to_string())
+}
+
+/// 주어진 salt로 bcrypt `$2b$` 해시를 만듭니다. 72바이트를 넘는 비밀번호는 bcrypt 규칙대로 잘립니다.
+pub fn bcrypt_hash(password: &[u8], cost: u32, salt: [u8; 16]) -> Result<String, String> {
+    check_bcrypt_cost(cost)?;
+    bcrypt::hash_with_salt(password, cost, salt)
+        .map(|parts| parts.format_for_version(bcrypt::Version::TwoB))
+        .map_err(|e| e.to_string())
+}
+
+/// 저장된 비밀번호 해시에서 읽은 정보
+#[derive(Debug, Clone, PartialEq)]
+pub struct PasswordHashInfo {
+    /// "bcrypt", "argon2id", "scrypt", "pbkdf2-sha256" 등
+    pub scheme: String,
+    /// 버전과 비용 파라미터 (예: ("m", "19456"), ("t", "2"))
+    pub params: Vec<(String, String)>,
+    pub salt: String,
+    /// 해시 출력 길이 (바이트)
+    pub output_len: usize,
+}
+
+/// bcrypt(`$2a$`/`$2b$`/`$2x$`/`$2y$`) 또는 PHC 문자열을 해석합니다.
+pub fn inspect_password_hash(hash: &str) -> Result<PasswordHashInfo, String> {
+    let hash = hash.trim();
+    if let Some(info) = inspect_bcrypt(hash) {
+        return info;
+    }
+
+    let parsed = PasswordHash::new(hash).map_err(|e| format!("Not a bcrypt or PHC string: {}", e))?;
+    let mut params = Vec::new();
+    if let Some(version) = parsed.version {
+        params.push(("v".to_string(), version.to_string()));
+    }
+    params.extend(
+        parsed
+            .params
+            .iter()
+            .map(|(name, value)| (name.as_str().to_string(), value.as_str().to_string())),
+    );
+
+    Ok(PasswordHashInfo {
+        scheme: parsed.algorithm.as_str().to_string(),
+        params,
+        salt: parsed.salt.map(|salt| salt.as_str().to_string()).unwrap_or_default(),
+        output_len: parsed.hash.map(|output| output.len()).unwrap_or(0),
+    })
+}
+
+/// 비밀번호가 저장된 해시와 일치하는지 확인합니다. 해시 형식이 잘못되었으면 에러입니다.
+pub fn verify_password(password: &[u8], hash: &str) -> Result<bool, String> {
+    let hash = hash.trim();
+    if let Some(info) = inspect_bcrypt(hash) {
+        info?;
+        // 형식을 확인했으므로 `$2b$` 다음 두 글자가 비용
+        check_bcrypt_cost(hash[4..6].parse().unwrap_or(u32::MAX))?;
+        return bcrypt::verify(password, hash).map_err(|e| e.to_string());
+    }
+
+    let parsed = PasswordHash::new(hash).map_err(|e| format!("Not a bcrypt or PHC string: {}", e))?;
+    let verifier: &dyn PasswordVerifier = match parsed.algorithm.as_str() {
+        "argon2id" | "argon2i" | "argon2d" => &Argon2::default(),
+        "scrypt" => &Scrypt,
+        "pbkdf2" | "pbkdf2-sha256" | "pbkdf2-sha512" => &Pbkdf2,
+        other => return Err(format!("Unsupported password hash scheme '{}'.", other)),
+    };
+    check_phc_cost(&parsed)?;
+
+    match verifier.verify_password(password, &parsed) {
+        Ok(()) => Ok(true),
+        Err(password_hash::Error::Password) => Ok(false),
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+/// bcrypt 형식이 아니면 None, bcrypt 접두사가 있지만 형식이 잘못되었으면 Some(Err)
+fn inspect_bcrypt(hash: &str) -> Option<Result<PasswordHashInfo, String>> {
+    let rest = hash.strip_prefix('$')?;
+    let (version, rest) = rest.split_once('$')?;
+    if !matches!(version, "2a" | "2b" | "2x" | "2y") {
+        return None;
+    }
+
+    let parsed = rest.split_once('$').and_then(|(cost, tail)| {
+        let cost = cost.parse::<u32>().ok().filter(|_| cost.len() == 2)?;
+        // 22자 salt + 31자 해시 (bcrypt 전용 Base64)
+        (tail.len() == 53 && tail.is_ascii()).then(|| (cost, &tail[..22]))
+    });
+    Some(match parsed {
+        Some((cost, salt)) => Ok(PasswordHashInfo {
+            scheme: "bcrypt".to_string(),
+            params: vec![("version".to_string(), version.to_string()), ("cost".to_string(), cost.to_string())],
+            salt: salt.to_string(),
+            output_len: 23,
+        }),
+        None => Err("Invalid bcrypt hash: expected $2b$<cost>$ followed by 53 characters.".to_string()),
+    })
+}
+
+fn hash_len(hash: KdfHash) -> usize {
+    match hash {
+        KdfHash::Sha256 => 32,
+        KdfHash::Sha512 => 64,
+    }
+}
+
+fn check_pbkdf2_iterations(iterations: u32) -> Result<(), String> {
+    if !(1..=MAX_PBKDF2_ITERATIONS).contains(&iterations) {
+        return Err(format!("PBKDF2 iterations must be between 1 and {}.", MAX_PBKDF2_ITERATIONS));
+    }
+    Ok(())
+}
+
+fn check_bcrypt_cost(cost: u32) -> Result<(), String> {
+    if cost > MAX_BCRYPT_COST {
+        return Err(format!("bcrypt cost {} is too high (at most {}).", cost, MAX_BCRYPT_COST));
+    }
+    Ok(())
+}
+
+/// 저장된 PHC 문자열의 비용을 검증 전에 상한과 비교합니다.
+fn check_phc_cost(parsed: &PasswordHash) -> Result<(), String> {
+    match parsed.algorithm.as_str() {
+        "scrypt" => {
+            let params = scrypt::Params::try_from(parsed).map_err(|e| format!("Invalid scrypt parameters: {}", e))?;
+            check_scrypt_cost(params.log_n(), params.r(), params.p())
+        }
+        "pbkdf2" | "pbkdf2-sha256" | "pbkdf2-sha512" => {
+            let params = pbkdf2::Params::try_from(parsed).map_err(|e| format!("Invalid PBKDF2 parameters: {}", e))?;
+            check_pbkdf2_iterations(params.rounds)
+        }
+        _ => {
+            let params = Argon2Params::try_from(parsed).map_err(|e| format!("Invalid Argon2 parameters: {}", e))?;
+            check_argon2_cost(params.m_cost(), params.t_cost(), params.p_cost())
+        }
+    }
+}
+
+fn check_scrypt_cost(log_n: u8, r: u32, p: u32) -> Result<(), String> {
+    let memory = 1u128.checked_shl(log_n.into()).map(|n| 128 * u128::from(r) * n);
+    if memory.is_none_or(|memory| memory > u128::from(MAX_SCRYPT_MEMORY)) {
+        return Err(format!(
+            "scrypt parameters need too much memory (128 · r · N must be at most {} MiB).",
+            MAX_SCRYPT_MEMORY / (1024 * 1024)
+        ));
+    }
+    if p > MAX_SCRYPT_P {
+        return Err(format!("scrypt p must be at most {}.", MAX_SCRYPT_P));
+    }
+    Ok(())
+}
+
+fn check_argon2_cost(memory_kib: u32, iterations: u32, parallelism: u32) -> Result<(), String> {
+    if memory_kib > MAX_ARGON2_MEMORY_KIB {
+        return Err(format!("Argon2 memory must be at most {} KiB.", MAX_ARGON2_MEMORY_KIB));
+    }
+    if iterations > MAX_ARGON2_ITERATIONS {
+        return Err(format!("Argon2 iterations must be at most {}.", MAX_ARGON2_ITERATIONS));
+    }
+    if parallelism > MAX_ARGON2_PARALLELISM {
+        return Err(format!("Argon2 parallelism must be at most {}.", MAX_ARGON2_PARALLELISM));
+    }
+    Ok(())
+}
+
+fn scrypt_params(log_n: u8, r: u32, p: u32, length: usize) -> Result<scrypt::Params, String> {
+    check_scrypt_cost(log_n, r, p)?;
+    scrypt::Params::new(log_n, r, p, length).map_err(|_| {
+        format!("Invalid scrypt parameters (log2 N = {}, r = {}, p = {}).", log_n, r, p)
+    })
+}
+
+fn argon2id(memory_kib: u32, iterations: u32, parallelism: u32, length: Option<usize>) -> Result<Argon2<'static>, String> {
+    check_argon2_cost(memory_kib, iterations, parallelism)?;
+    let params = Argon2Params::new(memory_kib, iterations, parallelism, length)
+        .map_err(|e| format!("Invalid Argon2id parameters: {}", e))?;
+    Ok(Argon2::new(Argon2Algorithm::Argon2id, Argon2Version::V0x13, params))
+}
@@ -0,0 +1,25 @@
+//! CompuTools 변환 로직 모음
+//!
+//! 각 모듈은 UI(Yew)에 의존하지 않는 순수 Rust 함수와 타입만 제공하므로
+//! 웹 컴포넌트, 네이티브 도구, 테스트에서 동일하게 재사용할 수 있습니다.
+
+pub mod ascii;
+pub mod base;
+pub mod base64;
+pub mod bytes;
+pub mod cipher;
+pub mod codec;
+pub mod crc;
+pub mod file_hash;
+pub mod file_type;
+pub mod html;
+pub mod json;
+pub mod jwt;
+pub mod kdf;
+pub mod mime;
+pub mod pki;
+pub mod preview;
+pub mod quaternion;
+pub mod unixtime;
+pub mod url;
+pub mod uuid;
@@ -0,0 +1,399 @@
+//! 이메일 인코딩 (Quoted-Printable, uuencode, yEnc, MIME 인코딩된 단어)
+//!
+//! 인코딩된 단어(`=?UTF-8?B?...?=`)의 B 인코딩은 Base64 도구와 같은 `decode_with_mode`로 풉니다.
+//! yEnc는 8비트 인코딩이므로 문자열에서는 바이트 하나를 ISO-8859-1 문자 하나로 표현합니다.
+
+use std::fmt::Write;
+
+use crate::base64::{decode_with_mode, encode_with_mode, Base64Mode};
+use crate::crc::CrcAlgorithm;
+
+/// Quoted-Printable 한 줄의 최대 길이 (RFC 2045)
+const QP_LINE_LENGTH: usize = 76;
+/// uuencode 한 줄에 담는 바이트 수
+const UU_LINE_BYTES: usize = 45;
+const YENC_LINE_LENGTH: usize = 128;
+/// 인코딩된 단어 하나에 담는 바이트 수 (`=?UTF-8?B?` + Base64 60자 + `?=` = 72자)
+const ENCODED_WORD_BYTES: usize = 45;
+
+fn hex_pair(high: u8, low: u8) -> Option<u8> {
+    let high = char::from(high).to_digit(16)?;
+    let low = char::from(low).to_digit(16)?;
+    Some((high * 16 + low) as u8)
+}
+
+fn is_line_end(rest: &[u8]) -> bool {
+    rest.is_empty() || rest[0] == b'\n' || rest.starts_with(b"\r\n")
+}
+
+/// 텍스트 모드 Quoted-Printable 인코딩. 줄바꿈(LF, CRLF)은 그대로 두고 76자를 넘는 줄은 `=`로 나눕니다.
+pub fn encode_quoted_printable(data: &[u8]) -> String {
+    let mut output = String::with_capacity(data.len() * 3 / 2);
+    let mut line_length = 0;
+    let mut index = 0;
+
+    while index < data.len() {
+        let byte = data[index];
+        if byte == b'\n' || data[index..].starts_with(b"\r\n") {
+            let newline = if byte == b'\n' { "\n" } else { "\r\n" };
+            output.push_str(newline);
+            index += newline.len();
+            line_length = 0;
+            continue;
+        }
+
+        // 줄 끝 공백은 전송 중 지워질 수 있으므로 인코딩
+        let at_line_end = is_line_end(&data[index + 1..]);
+        let literal = matches!(byte, b'!'..=b'<' | b'>'..=b'~') || (matches!(byte, b' ' | b'\t') && !at_line_end);
+        let token_length = if literal { 1 } else { 3 };
+        // 줄의 마지막 토큰이 아니면 소프트 줄바꿈 `=` 자리를 남겨 둠
+        let limit = if at_line_end { QP_LINE_LENGTH } else { QP_LINE_LENGTH - 1 };
+        if line_length + token_length > limit {
+            output.push_str("=\n");
+            line_length = 0;
+        }
+
+        if literal {
+            output.push(char::from(byte));
+        } else {
+            let _ = write!(output, "={:02X}", byte);
+        }
+        line_length += token_length;
+        index += 1;
+    }
+    output
+}
+
+/// Quoted-Printable 디코딩. 소프트 줄바꿈(`=` + 줄바꿈)은 지우고 줄 끝 공백은 무시합니다.
+pub fn decode_quoted_printable(input: &str) -> Result<Vec<u8>, String> {
+    let mut output = Vec::with_capacity(input.len());
+
+    for (line_number, line) in input.split_inclusive('\n').enumerate() {
+        let (content, newline) = if let Some(content) = line.strip_suffix("\r\n") {
+            (content, "\r\n")
+        } else if let Some(content) = line.strip_suffix('\n') {
+            (content, "\n")
+        } else {
+            (line, "")
+        };
+        let content = content.trim_end_matches([' ', '\t']);
+        let (content, soft_break) = match content.strip_suffix('=') {
+            Some(content) => (content, true),
+            None => (content, false),
+        };
+
+        let bytes = content.as_bytes();
+        let mut index = 0;
+        while index < bytes.len() {
+            if bytes[index] == b'=' {
+                let byte = bytes
+                    .get(index + 1..index + 3)
+                    .and_then(|pair| hex_pair(pair[0], pair[1]))
+                    .ok_or_else(|| {
+                        format!(
+                            "Invalid quoted-printable escape '{}' on line {}: '=' must be followed by two hex digits or a line break.",
+                            content[index..].chars().take(3).collect::<String>(),
+                            line_number + 1
+                        )
+                    })?;
+                output.push(byte);
+                index += 3;
+            } else {
+                output.push(bytes[index]);
+                index += 1;
+            }
+        }
+
+        if !soft_break {
+            output.extend_from_slice(newline.as_bytes());
+        }
+    }
+    Ok(output)
+}
+
+fn uu_char(value: u8) -> char {
+    // 0은 공백 대신 '`'로 써서 줄 끝 공백이 지워져도 안전하게
+    if value == 0 {
+        '`'
+    } else {
+        char::from(value + 32)
+    }
+}
+
+/// `begin 644 name` ... `end` 형식으로 uuencode 합니다.
+pub fn encode_uuencode(data: &[u8], name: &str) -> String {
+    let mut output = format!("begin 644 {}\n", name);
+    for line in data.chunks(UU_LINE_BYTES) {
+        output.push(uu_char(line.len() as u8));
+        for group in line.chunks(3) {
+            let mut block = [0u8; 3];
+            block[..group.len()].copy_from_slice(group);
+            output.push(uu_char(block[0] >> 2));
+            output.push(uu_char(((block[0] & 0x03) << 4) | (block[1] >> 4)));
+            output.push(uu_char(((block[1] & 0x0f) << 2) | (block[2] >> 6)));
+            output.push(uu_char(block[2] & 0x3f));
+        }
+        output.push('\n');
+    }
+    output.push_str("`\nend\n");
+    output
+}
+
+/// uuencode 디코딩. `begin` 줄이 있으면 그 앞의 내용(메일 본문 등)은 건너뛰고 `end`에서 멈춥니다.
+pub fn decode_uuencode(input: &str) -> Result<Vec<u8>, String> {
+    let mut output = Vec::new();
+    let mut started = !input.lines().any(|line| line.starts_with("begin "));
+
+    for (line_number, line) in input.lines().enumerate() {
+        let line = line.trim_end();
+        if !started {
+            started = line.starts_with("begin ");
+            continue;
+        }
+        if line == "end" {
+            break;
+        }
+        if line.is_empty() {
+            continue;
+        }
+
+        let value = |character: u8| {
+            if (b' '..=b'`').contains(&character) {
+                Ok((character - b' ') & 0x3f)
+            } else {
+                Err(format!("Invalid uuencode character '{}' on line {}.", char::from(character), line_number + 1))
+            }
+        };
+        let bytes = line.as_bytes();
+        let length = usize::from(value(bytes[0])?);
+        // 줄 끝 공백(값 0)이 지워진 경우를 위해 모자란 부분은 0으로 채움
+        let mut values = bytes[1..].iter().map(|character| value(*character)).collect::<Result<Vec<u8>, String>>()?;
+        values.resize(values.len().max(length.div_ceil(3) * 4), 0);
+
+        let mut decoded = Vec::with_capacity(length + 2);
+        for group in values.chunks(4).take(length.div_ceil(3)) {
+            decoded.push((group[0] << 2) | (group[1] >> 4));
+            decoded.push((group[1] << 4) | (group[2] >> 2));
+            decoded.push((group[2] << 6) | group[3]);
+        }
+        output.extend_from_slice(&decoded[..length]);
+    }
+    Ok(output)
+}
+
+fn crc32(data: &[u8]) -> u32 {
+    CrcAlgorithm::Crc32IsoHdlc.calculate(data).0 as u32
+}
+
+/// `=ybegin` ... `=yend` 형식으로 yEnc 인코딩합니다. 출력 문자는 ISO-8859-1 바이트를 나타냅니다.
+pub fn encode_yenc(data: &[u8], name: &str) -> String {
+    let mut output = format!("=ybegin line={} size={} name={}\n", YENC_LINE_LENGTH, data.len(), name);
+    let mut line_length = 0;
+
+    for (index, byte) in data.iter().enumerate() {
+        let encoded = byte.wrapping_add(42);
+        let at_line_edge = line_length == 0 || line_length + 1 >= YENC_LINE_LENGTH || index + 1 == data.len();
+        let escape = matches!(encoded, 0 | b'\n' | b'\r' | b'=')
+            || (matches!(encoded, b'\t' | b' ') && at_line_edge)
+            || (encoded == b'.' && line_length == 0);
+        if escape {
+            output.push('=');
+            output.push(char::from(encoded.wrapping_add(64)));
+            line_length += 2;
+        } else {
+            output.push(char::from(encoded));
+            line_length += 1;
+        }
+        if line_length >= YENC_LINE_LENGTH {
+            output.push('\n');
+            line_length = 0;
+        }
+    }
+    if line_length > 0 {
+        output.push('\n');
+    }
+    let _ = writeln!(output, "=yend size={} crc32={:08x}", data.len(), crc32(data));
+    output
+}
+
+fn latin1_byte(character: char) -> Result<u8, String> {
+    u8::try_from(u32::from(character))
+        .map_err(|_| format!("Invalid yEnc character '{}': yEnc text must be ISO-8859-1.", character))
+}
+
+/// yEnc 디코딩. 단일 파트면 `=yend`의 size와 crc32를 확인합니다.
+pub fn decode_yenc(input: &str) -> Result<Vec<u8>, String> {
+    let mut output = Vec::new();
+    let mut started = !input.contains("=ybegin");
+    let mut multipart = false;
+    let mut trailer = None;
+
+    for line in input.lines() {
+        if line.starts_with("=ybegin") {
+            started = true;
+            continue;
+        }
+        if !started {
+            continue;
+        }
+        if line.starts_with("=ypart") {
+            multipart = true;
+            continue;
+        }
+        if line.starts_with("=yend") {
+            trailer = Some(line);
+            break;
+        }
+
+        let mut characters = line.trim_end_matches('\r').chars();
+        while let Some(character) = characters.next() {
+            let mut byte = latin1_byte(character)?;
+            if byte == b'=' {
+                let escaped = characters.next().ok_or("Incomplete yEnc escape at the end of a line.")?;
+                byte = latin1_byte(escaped)?.wrapping_sub(64);
+            }
+            output.push(byte.wrapping_sub(42));
+        }
+    }
+
+    if let (Some(trailer), false) = (trailer, multipart) {
+        for (key, value) in trailer.split_whitespace().filter_map(|field| field.split_once('=')) {
+            match key {
+                "size" if value.parse::<usize>().ok() != Some(output.len()) => {
+                    return Err(format!("yEnc size mismatch: the trailer says {} bytes but {} were decoded.", value, output.len()));
+                }
+                "crc32" if u32::from_str_radix(value, 16).ok() != Some(crc32(&output)) => {
+                    return Err(format!("yEnc CRC32 mismatch: the trailer says {} but the data has {:08x}.", value, crc32(&output)));
+                }
+                _ => {}
+            }
+        }
+    }
+    Ok(output)
+}
+
+/// 텍스트를 UTF-8 B 인코딩된 단어로 바꿉니다. 단어마다 한 줄에 두고 헤더 접기(줄바꿈 + 공백)로 잇습니다.
+pub fn encode_encoded_words(data: &[u8]) -> String {
+    let mut words = Vec::new();
+    let mut rest = data;
+    while !rest.is_empty() {
+        let mut end = rest.len().min(ENCODED_WORD_BYTES);
+        // UTF-8 문자 중간에서 나누지 않음
+        while end < rest.len() && end + 3 > ENCODED_WORD_BYTES && rest[end] & 0xc0 == 0x80 {
+            end -= 1;
+        }
+        words.push(format!("=?UTF-8?B?{}?=", encode_with_mode(&rest[..end], &Base64Mode::Standard)));
+        rest = &rest[end..];
+    }
+    words.join("\n ")
+}
+
+/// `=?charset?B|Q?text?=`를 찾아 (charset, 인코딩, text, 전체 길이)를 돌려줍니다.
+fn parse_encoded_word(input: &str) -> Option<(&str, char, &str, usize)> {
+    let body = input.strip_prefix("=?")?;
+    let (charset, rest) = body.split_once('?')?;
+    let (encoding, rest) = rest.split_once('?')?;
+    let text = &rest[..rest.find("?=")?];
+    if charset.is_empty() || charset.contains(char::is_whitespace) || text.contains(char::is_whitespace) {
+        return None;
+    }
+    let encoding = match encoding {
+        "B" | "b" => 'B',
+        "Q" | "q" => 'Q',
+        _ => return None,
+    };
+    Some((charset, encoding, text, charset.len() + text.len() + 7))
+}
+
+fn decode_q(text: &str) -> Result<Vec<u8>, String> {
+    let bytes = text.as_bytes();
+    let mut output = Vec::with_capacity(bytes.len());
+    let mut index = 0;
+    while index < bytes.len() {
+        match bytes[index] {
+            b'_' => output.push(b' '),
+            b'=' => {
+                let byte = bytes
+                    .get(index + 1..index + 3)
+                    .and_then(|pair| hex_pair(pair[0], pair[1]))
+                    .ok_or_else(|| format!("Invalid Q-encoding escape in encoded-word '{}'.", text))?;
+                output.push(byte);
+                index += 2;
+            }
+            byte => output.push(byte),
+        }
+        index += 1;
+    }
+    Ok(output)
+}
+
+/// Windows-1252의 0x80~0x9F (정의되지 않은 자리는 같은 코드 포인트의 C1 제어 문자)
+const WINDOWS_1252_HIGH: [char; 32] = [
+    '€', '\u{81}', '‚', 'ƒ', '„', '…', '†', '‡', 'ˆ', '‰', 'Š', '‹', 'Œ', '\u{8d}', 'Ž', '\u{8f}',
+    '\u{90}', '‘', '’', '“', '”', '•', '–', '—', '˜', '™', 'š', '›', 'œ', '\u{9d}', 'ž', 'Ÿ',
+];
+
+fn charset_to_utf8(charset: &str, bytes: &[u8]) -> Result<Vec<u8>, String> {
+    // RFC 2231 언어 태그 (charset*lang) 제거
+    let name = charset.split('*').next().unwrap_or_default().to_ascii_lowercase();
+    match name.as_str() {
+        "utf-8" | "utf8" | "us-ascii" | "ascii" => Ok(bytes.to_vec()),
+        "iso-8859-1" | "iso8859-1" | "latin1" => Ok(bytes.iter().map(|byte| char::from(*byte)).collect::<String>().into_bytes()),
+        "windows-1252" | "cp1252" => Ok(bytes
+            .iter()
+            .map(|byte| match byte {
+                0x80..=0x9f => WINDOWS_1252_HIGH[usize::from(byte - 0x80)],
+                _ => char::from(*byte),
+            })
+            .collect::<String>()
+            .into_bytes()),
+        _ => Err(format!(
+            "Unsupported charset '{}' in encoded-word (supported: UTF-8, US-ASCII, ISO-8859-1, Windows-1252).",
+            charset
+        )),
+    }
+}
+
+fn decode_encoded_word(charset: &str, encoding: char, text: &str) -> Result<Vec<u8>, String> {
+    let bytes = if encoding == 'B' {
+        // 패딩을 빼고 보내는 메일 클라이언트도 있음
+        let mode = if text.len().is_multiple_of(4) { Base64Mode::Standard } else { Base64Mode::NoPadding };
+        decode_with_mode(text, &mode).map_err(|_| format!("Invalid Base64 in encoded-word '{}'.", text))?
+    } else {
+        decode_q(text)?
+    };
+    charset_to_utf8(charset, &bytes)
+}
+
+/// 원본 헤더 블록의 인코딩된 단어를 UTF-8 텍스트로 풉니다.
+///
+/// 접힌 헤더는 한 줄로 펴고, 이어지는 인코딩된 단어 사이의 공백은 지웁니다 (RFC 2047 6.2).
+/// 인코딩된 단어가 아닌 부분은 그대로 둡니다.
+pub fn decode_header(input: &str) -> Result<Vec<u8>, String> {
+    let unfolded = input.replace("\r\n", "\n").replace("\n ", " ").replace("\n\t", "\t");
+    let mut output = Vec::with_capacity(unfolded.len());
+    let mut rest = unfolded.as_str();
+    let mut previous_encoded = false;
+
+    while let Some(start) = rest.find("=?") {
+        let (before, candidate) = rest.split_at(start);
+        match parse_encoded_word(candidate) {
+            Some((charset, encoding, text, length)) => {
+                if !(previous_encoded && before.chars().all(|c| c == ' ' || c == '\t')) {
+                    output.extend_from_slice(before.as_bytes());
+                }
+                output.extend(decode_encoded_word(charset, encoding, text)?);
+                previous_encoded = true;
+                rest = &candidate[length..];
+            }
+            None => {
+                output.extend_from_slice(before.as_bytes());
+                output.extend_from_slice(b"=?");
+                previous_encoded = false;
+                rest = &candidate[2..];
+            }
+        }
+    }
+    output.extend_from_slice(rest.as_bytes());
+    Ok(output)
+}
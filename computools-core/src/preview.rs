@@ -0,0 +1,118 @@
+//! 디코딩 결과 미리보기 (텍스트 인코딩 판별, 헥스 덤프)
+
+use std::fmt::Write;
+
+/// 헥스 덤프 한 페이지의 바이트 수 (16바이트 × 256줄)
+pub const HEX_DUMP_PAGE_SIZE: usize = 4096;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TextEncoding {
+    Utf8,
+    Utf16Le,
+    Utf16Be,
+}
+
+impl TextEncoding {
+    pub fn name(&self) -> &'static str {
+        match self {
+            TextEncoding::Utf8 => "UTF-8",
+            TextEncoding::Utf16Le => "UTF-16LE",
+            TextEncoding::Utf16Be => "UTF-16BE",
+        }
+    }
+}
+
+/// 탭, 줄바꿈, 폼피드 외의 제어 문자가 없는지
+pub(crate) fn is_readable(text: &str) -> bool {
+    !text.chars().any(|c| c.is_control() && !matches!(c, '\t' | '\n' | '\r' | '\x0c'))
+}
+
+/// BOM이 있거나, ASCII 범위 문자가 대부분이라 한쪽 바이트가 거의 0인 UTF-16을 찾습니다.
+fn detect_utf16(data: &[u8]) -> Option<TextEncoding> {
+    if data.starts_with(&[0xFF, 0xFE]) {
+        return Some(TextEncoding::Utf16Le);
+    }
+    if data.starts_with(&[0xFE, 0xFF]) {
+        return Some(TextEncoding::Utf16Be);
+    }
+    if data.len() < 4 {
+        return None;
+    }
+    let units = data.len() / 2;
+    let zeros_at = |parity: usize| data.iter().skip(parity).step_by(2).filter(|byte| **byte == 0).count();
+    let (even_zeros, odd_zeros) = (zeros_at(0), zeros_at(1));
+    // 상위 바이트가 80% 이상 0이고 하위 바이트는 거의 0이 아니어야 함
+    if odd_zeros * 5 >= units * 4 && even_zeros * 10 < units {
+        Some(TextEncoding::Utf16Le)
+    } else if even_zeros * 5 >= units * 4 && odd_zeros * 10 < units {
+        Some(TextEncoding::Utf16Be)
+    } else {
+        None
+    }
+}
+
+fn decode_utf16(data: &[u8], encoding: TextEncoding) -> Option<String> {
+    let data = data.strip_prefix(if encoding == TextEncoding::Utf16Le { &[0xFF, 0xFE] } else { &[0xFE, 0xFF] }).unwrap_or(data);
+    let mut units: Vec<u16> = data
+        .chunks_exact(2)
+        .map(|pair| match encoding {
+            TextEncoding::Utf16Le => u16::from_le_bytes([pair[0], pair[1]]),
+            _ => u16::from_be_bytes([pair[0], pair[1]]),
+        })
+        .collect();
+    // 앞부분만 받은 경우 끝에서 잘린 서로게이트 쌍은 버림
+    if units.last().is_some_and(|unit| (0xD800..0xDC00).contains(unit)) {
+        units.pop();
+    }
+    String::from_utf16(&units).ok().filter(|text| is_readable(text))
+}
+
+/// 사람이 읽을 수 있는 텍스트면 인코딩과 함께 디코딩한 문자열을 돌려줍니다.
+///
+/// UTF-8(BOM 포함)과 UTF-16(BOM, 또는 ASCII 위주라 0 바이트가 번갈아 나오는 경우)을 인식합니다.
+pub fn decode_text(data: &[u8]) -> Option<(TextEncoding, String)> {
+    if let Some(encoding) = detect_utf16(data) {
+        return decode_utf16(data, encoding).map(|text| (encoding, text));
+    }
+    let text = std::str::from_utf8(data.strip_prefix(&[0xEF, 0xBB, 0xBF]).unwrap_or(data)).ok()?;
+    is_readable(text).then(|| (TextEncoding::Utf8, text.to_string()))
+}
+
+/// `hexdump -C` 형식의 헥스 덤프. 주소는 `start_offset`부터 셉니다.
+///
+/// ```text
+/// 00000000  48 65 6c 6c 6f 2c 20 57  6f 72 6c 64 21 0a        |Hello, World!.|
+/// ```
+pub fn hex_dump(data: &[u8], start_offset: usize) -> String {
+    let mut output = String::with_capacity(data.len().div_ceil(16) * 80);
+    for (index, line) in data.chunks(16).enumerate() {
+        let _ = write!(output, "{:08x}  ", start_offset + index * 16);
+        for column in 0..16 {
+            match line.get(column) {
+                Some(byte) => {
+                    let _ = write!(output, "{:02x} ", byte);
+                }
+                None => output.push_str("   "),
+            }
+            if column == 7 {
+                output.push(' ');
+            }
+        }
+        output.push_str(" |");
+        output.extend(line.iter().map(|byte| if byte.is_ascii_graphic() || *byte == b' ' { char::from(*byte) } else { '.' }));
+        output.push_str("|\n");
+    }
+    output
+}
+
+/// 헥스 덤프 페이지 수
+pub fn hex_dump_pages(length: usize) -> usize {
+    length.div_ceil(HEX_DUMP_PAGE_SIZE).max(1)
+}
+
+/// `page`번째 페이지(0부터)의 헥스 덤프
+pub fn hex_dump_page(data: &[u8], page: usize) -> String {
+    let start = (page * HEX_DUMP_PAGE_SIZE).min(data.len());
+    let end = (start + HEX_DUMP_PAGE_SIZE).min(data.len());
+    hex_dump(&data[start..end], start)
+}
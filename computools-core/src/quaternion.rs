@@ -0,0 +1,98 @@
+//! 쿼터니언과 오일러 각 간 변환
+
+use std::f64::consts::PI;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Quaternion {
+    pub w: f64,
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct EulerAngles {
+    pub roll: f64,
+    pub pitch: f64,
+    pub yaw: f64,
+}
+
+/// 쿼터니언을 정규화한 뒤 오일러 각(라디안)으로 변환합니다.
+///
+/// 크기가 0인 쿼터니언은 단위 쿼터니언으로 간주합니다.
+pub fn quaternion_to_euler(q: Quaternion) -> (EulerAngles, Quaternion) {
+    let qw = q.w;
+    let qx = q.x;
+    let qy = q.y;
+    let qz = q.z;
+
+    let norm = (qx * qx + qy * qy + qz * qz + qw * qw).sqrt();
+    if norm != 0.0 {
+        let x = qx / norm;
+        let y = qy / norm;
+        let z = qz / norm;
+        let w = qw / norm;
+
+        let sinr_cosp = 2.0 * (w * x + y * z);
+        let cosr_cosp = 1.0 - 2.0 * (x * x + y * y);
+        let roll = sinr_cosp.atan2(cosr_cosp);
+
+        let sinp = 2.0 * (w * y - z * x);
+        let pitch = if sinp.abs() >= 1.0 {
+            PI / 2.0 * sinp.signum()
+        } else {
+            sinp.asin()
+        };
+
+        let siny_cosp = 2.0 * (w * z + x * y);
+        let cosy_cosp = 1.0 - 2.0 * (y * y + z * z);
+        let yaw = siny_cosp.atan2(cosy_cosp);
+
+        (EulerAngles { roll, pitch, yaw }, Quaternion { x, y, z, w })
+    } else {
+        let x = 0.0;
+        let y = 0.0;
+        let z = 0.0;
+        let w = 1.0;
+
+        (
+            EulerAngles {
+                roll: 0.0,
+                pitch: 0.0,
+                yaw: 0.0,
+            },
+            Quaternion { x, y, z, w },
+        )
+    }
+}
+
+/// 오일러 각(라디안)을 [-π, π] 범위로 정규화한 뒤 쿼터니언으로 변환합니다.
+pub fn euler_to_quaternion(e: EulerAngles) -> (EulerAngles, Quaternion) {
+    let roll = normalize_angle(e.roll);
+    let pitch = normalize_angle(e.pitch);
+    let yaw = normalize_angle(e.yaw);
+
+    let cy = (yaw * 0.5).cos();
+    let sy = (yaw * 0.5).sin();
+    let cr = (roll * 0.5).cos();
+    let sr = (roll * 0.5).sin();
+    let cp = (pitch * 0.5).cos();
+    let sp = (pitch * 0.5).sin();
+
+    let w = cr * cp * cy + sr * sp * sy;
+    let x = sr * cp * cy - cr * sp * sy;
+    let y = cr * sp * cy + sr * cp * sy;
+    let z = cr * cp * sy - sr * sp * cy;
+
+    (EulerAngles { roll, pitch, yaw }, Quaternion { x, y, z, w })
+}
+
+pub fn normalize_angle(angle: f64) -> f64 {
+    let mut norm_angle = angle % (2.0 * PI);
+    if norm_angle > PI {
+        norm_angle -= 2.0 * PI;
+    } else if norm_angle <= -PI {
+        norm_angle += 2.0 * PI;
+    }
+    norm_angle
+}
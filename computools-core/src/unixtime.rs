@@ -0,0 +1,104 @@
+//! 유닉스 타임스탬프와 타임존별 날짜/시간 문자열 간 변환
+
+use chrono::{DateTime, NaiveDateTime, Offset, TimeZone, Utc};
+use chrono_tz::TZ_VARIANTS;
+
+/// 타임스탬프를 지정한 타임존의 시각으로 포맷합니다.
+///
+/// 타임존 이름을 해석할 수 없으면 `fallback`을 반환합니다.
+fn format_in_timezone(unixtime: i64, selected_timezone: &str, pattern: &str, fallback: &str) -> String {
+    let datetime = DateTime::<Utc>::from_timestamp(unixtime, 0).unwrap_or_default();
+
+    match selected_timezone.parse::<chrono_tz::Tz>() {
+        Ok(tz) => datetime.with_timezone(&tz).format(pattern).to_string(),
+        Err(_) => fallback.to_string(),
+    }
+}
+
+/// "01/01/1970, 12:00:00 AM" 형식
+pub fn unixtime_to_datetime_str_form1(unixtime: i64, selected_timezone: &str) -> String {
+    format_in_timezone(unixtime, selected_timezone, "%m/%d/%Y, %I:%M:%S %p", "01/01/1970, 12:00:00 AM")
+}
+
+/// "Jan 01, 1970, 12:00:00 AM" 형식
+pub fn unixtime_to_datetime_str_form2(unixtime: i64, selected_timezone: &str) -> String {
+    format_in_timezone(unixtime, selected_timezone, "%b %d, %Y, %I:%M:%S %p", "Jan 01, 1970, 12:00:00 AM")
+}
+
+/// "1970-01-01T00:00:00" 형식 (datetime-local 입력값)
+pub fn unixtime_to_datetime_str_form3(unixtime: i64, selected_timezone: &str) -> String {
+    format_in_timezone(unixtime, selected_timezone, "%Y-%m-%dT%H:%M:%S", "1970-01-01T00:00:00")
+}
+
+/// "YYYY-MM-DDTHH:MM:SS" 형식의 현지 시각을 타임존 기준 유닉스 타임스탬프로 변환합니다.
+pub fn datetime_str_to_unixtime(datetime_str: &str, selected_timezone: &str) -> Option<i64> {
+    let naive_datetime = NaiveDateTime::parse_from_str(datetime_str, "%Y-%m-%dT%H:%M:%S").ok()?;
+
+    // Parse the selected timezone
+    let tz = selected_timezone.parse::<chrono_tz::Tz>().ok()?;
+
+    // 현지 시각 기준으로 오프셋을 결정 (서머타임 전환으로 두 번 나타나는 시각은 이른 쪽 사용)
+    let datetime = tz.from_local_datetime(&naive_datetime).earliest()?;
+
+    Some(datetime.timestamp())
+}
+
+/// 입력된 유닉스 타임스탬프 문자열을 파싱합니다.
+///
+/// 숫자가 아니거나 허용 범위(0 ~ 100,000,000,000)를 벗어나면 0을 반환합니다.
+pub fn parse_unixtime_input(value: &str) -> i64 {
+    let parsed_value = value.trim().parse::<i64>().unwrap_or(0);
+    if (0..=100_000_000_000).contains(&parsed_value) {
+        parsed_value
+    } else {
+        0
+    }
+}
+
+/// datetime-local 입력값에 초가 없으면 ":00"을 붙입니다.
+pub fn normalize_datetime_input(value: &str) -> String {
+    let mut value = value.to_string();
+    if value.len() <= 16 {
+        // "YYYY-MM-DDTHH:MM" 길이 확인
+        value.push_str(":00");
+    }
+    value
+}
+
+/// 초를 "2d 3h", "5m 10s"처럼 가장 큰 두 단위로 표시합니다.
+pub fn format_duration(seconds: i64) -> String {
+    let units = [("d", 86_400), ("h", 3_600), ("m", 60), ("s", 1)];
+    let mut remaining = seconds.max(0);
+    let parts: Vec<String> = units
+        .iter()
+        .filter_map(|(unit, size)| {
+            let count = remaining / size;
+            remaining %= size;
+            (count > 0).then(|| format!("{}{}", count, unit))
+        })
+        .take(2)
+        .collect();
+
+    if parts.is_empty() {
+        "0s".to_string()
+    } else {
+        parts.join(" ")
+    }
+}
+
+/// 모든 IANA 타임존을 "이름 (UTC±HH:MM)" 라벨과 현재 오프셋(초)으로 나열합니다.
+pub fn timezone_list(now: DateTime<Utc>) -> Vec<(String, i32)> {
+    TZ_VARIANTS
+        .iter()
+        .map(|tz| {
+            let offset = tz
+                .offset_from_utc_datetime(&now.naive_utc())
+                .fix()
+                .local_minus_utc();
+            let hours = offset / 3600;
+            let minutes = (offset % 3600) / 60;
+            let offset_str = format!("UTC{:+03}:{:02}", hours, minutes.abs());
+            (format!("{} ({})", tz.name(), offset_str), offset)
+        })
+        .collect()
+}
@@ -0,0 +1,13 @@
+//! URL 퍼센트 인코딩/디코딩
+
+use urlencoding::{decode, encode};
+
+pub fn encode_url(input: &str) -> String {
+    encode(input).into_owned()
+}
+
+pub fn decode_url(input: &str) -> Result<String, String> {
+    decode(input)
+        .map(|decoded| decoded.into_owned())
+        .map_err(|_| "Decoding Error".to_string())
+}
@@ -0,0 +1,8 @@
+//! UUID 생성
+
+use uuid::Uuid;
+
+/// 무작위 UUID v4를 하이픈이 포함된 소문자 문자열로 생성합니다.
+pub fn generate_v4() -> String {
+    Uuid::new_v4().to_string()
+}
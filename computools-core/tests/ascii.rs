@@ -0,0 +1,117 @@
+use computools_core::ascii::{self, AsciiMode, BinaryStyle, FormatStyles, HexStyle, OctalStyle};
+use proptest::prelude::*;
+
+const MODES: [AsciiMode; 4] = [AsciiMode::Decimal, AsciiMode::Hex, AsciiMode::Binary, AsciiMode::Octal];
+
+fn all_styles() -> Vec<FormatStyles> {
+    vec![
+        FormatStyles::default(),
+        FormatStyles {
+            hex_style: HexStyle::ShortPrefix,
+            binary_style: BinaryStyle::ShortPrefix,
+            octal_style: OctalStyle::ShortPrefix,
+        },
+        FormatStyles {
+            hex_style: HexStyle::NoPrefix,
+            binary_style: BinaryStyle::NoPrefix,
+            octal_style: OctalStyle::NoPrefix,
+        },
+        FormatStyles {
+            hex_style: HexStyle::EscapeSequence,
+            binary_style: BinaryStyle::NoPrefix,
+            octal_style: OctalStyle::EscapeSequence,
+        },
+    ]
+}
+
+#[test]
+fn convert_text_to_ascii_styles() {
+    let styles = FormatStyles::default();
+    assert_eq!(ascii::convert_text_to_ascii(b"Hi", &AsciiMode::Decimal, &styles), "72 105");
+    assert_eq!(ascii::convert_text_to_ascii(b"Hi", &AsciiMode::Hex, &styles), "0x48 0x69");
+    assert_eq!(ascii::convert_text_to_ascii(b"H", &AsciiMode::Binary, &styles), "0b01001000");
+    assert_eq!(ascii::convert_text_to_ascii(b"H", &AsciiMode::Octal, &styles), "0o110");
+
+    let escape = &all_styles()[3];
+    assert_eq!(ascii::convert_text_to_ascii(b"H", &AsciiMode::Hex, escape), "\\x48");
+    assert_eq!(ascii::convert_text_to_ascii(b"H", &AsciiMode::Octal, escape), "\\110");
+}
+
+#[test]
+fn parse_hex_input_notations() {
+    let expected = Ok(vec![0x48, 0x69]);
+    assert_eq!(ascii::parse_hex_input("48 69"), expected);
+    assert_eq!(ascii::parse_hex_input("4869"), expected);
+    assert_eq!(ascii::parse_hex_input("0x48 0x69"), expected);
+    assert_eq!(ascii::parse_hex_input("0x480x69"), expected);
+    assert_eq!(ascii::parse_hex_input("x48x69"), expected);
+    assert_eq!(ascii::parse_hex_input("\\x48\\x69"), expected);
+    assert_eq!(ascii::parse_hex_input("48\n69\t"), expected);
+}
+
+#[test]
+fn parse_hex_input_zero_digits() {
+    assert_eq!(ascii::parse_hex_input("00"), Ok(vec![0x00]));
+    assert_eq!(ascii::parse_hex_input("0000"), Ok(vec![0x00, 0x00]));
+    assert_eq!(ascii::parse_hex_input("A0B0"), Ok(vec![0xA0, 0xB0]));
+    assert_eq!(ascii::parse_hex_input("0"), Ok(vec![0x00]));
+    // 홀수 자리는 마지막 한 자리를 0으로 채움
+    assert_eq!(ascii::parse_hex_input("486"), Ok(vec![0x48, 0x06]));
+    assert_eq!(ascii::parse_hex_input("0x6"), Ok(vec![0x06]));
+}
+
+#[test]
+fn parse_hex_input_errors() {
+    assert_eq!(ascii::parse_hex_input("   "), Ok(vec![]));
+    assert!(ascii::parse_hex_input("4G").is_err());
+    assert!(ascii::parse_hex_input("0x").is_err());
+    assert!(ascii::parse_hex_input("\\y41").is_err());
+    assert!(ascii::parse_hex_input("41\\").is_err());
+}
+
+#[test]
+fn parse_other_modes() {
+    assert_eq!(ascii::parse_decimal_input("72 105"), Ok(vec![72, 105]));
+    assert!(ascii::parse_decimal_input("256").is_err());
+    assert!(ascii::parse_decimal_input("-1").is_err());
+
+    assert_eq!(ascii::parse_binary_input("0b01001000 b01101001"), Ok(vec![72, 105]));
+    assert_eq!(ascii::parse_binary_input("0100100001101001"), Ok(vec![72, 105]));
+    assert!(ascii::parse_binary_input("012").is_err());
+
+    assert_eq!(ascii::parse_octal_input("110 151"), Ok(vec![72, 105]));
+    assert_eq!(ascii::parse_octal_input("\\110\\151"), Ok(vec![72, 105]));
+    assert_eq!(ascii::parse_octal_input("0o110 o151"), Ok(vec![72, 105]));
+    assert_eq!(ascii::parse_octal_input("100101"), Ok(vec![0o100, 0o101]));
+    assert!(ascii::parse_octal_input("8").is_err());
+}
+
+#[test]
+fn bytes_to_text_replaces_invalid_utf8() {
+    assert_eq!(ascii::bytes_to_text(b"Hi".to_vec()), "Hi");
+    assert_eq!(ascii::bytes_to_text("한".as_bytes().to_vec()), "한");
+    assert_eq!(ascii::bytes_to_text(vec![0x48, 0xFF]), "H?");
+}
+
+proptest! {
+    #[test]
+    fn convert_then_parse_is_identity(bytes in prop::collection::vec(any::<u8>(), 1..48)) {
+        for mode in &MODES {
+            for styles in all_styles() {
+                let text = ascii::convert_text_to_ascii(&bytes, mode, &styles);
+                prop_assert_eq!(ascii::parse_input(&text, mode), Ok(bytes.clone()), "{:?} {:?}", mode, styles);
+            }
+        }
+    }
+
+    #[test]
+    fn packed_hex_is_identity(bytes in prop::collection::vec(any::<u8>(), 1..48)) {
+        let packed: String = bytes.iter().map(|byte| format!("{:02X}", byte)).collect();
+        prop_assert_eq!(ascii::parse_hex_input(&packed), Ok(bytes));
+    }
+
+    #[test]
+    fn utf8_text_round_trips(text in "\\PC{0,32}") {
+        prop_assert_eq!(ascii::bytes_to_text(text.as_bytes().to_vec()), text);
+    }
+}
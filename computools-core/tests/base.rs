@@ -0,0 +1,100 @@
+use computools_core::base;
+use proptest::prelude::*;
+
+#[test]
+fn parse_flexible_format_strips_prefixes() {
+    let cases = [
+        ("0b101010", 2, "101010"),
+        ("0B101010", 2, "101010"),
+        ("b101010", 2, "101010"),
+        ("101010", 2, "101010"),
+        ("0o52", 8, "52"),
+        ("o52", 8, "52"),
+        ("052", 8, "52"),
+        ("\\052", 8, "052"),
+        ("52", 8, "52"),
+        ("0x2A", 16, "2A"),
+        ("x2A", 16, "2A"),
+        ("\\x2A", 16, "2A"),
+        ("2A", 16, "2A"),
+        ("  0x2A  ", 16, "2A"),
+        ("0x2A", 10, "0x2A"),
+        ("", 16, ""),
+    ];
+    for (input, base, expected) in cases {
+        assert_eq!(base::parse_flexible_format(input, base), Ok(expected.to_string()), "{} (base {})", input, base);
+    }
+}
+
+#[test]
+fn prefix_only_input_is_treated_as_typing() {
+    assert_eq!(base::parse_flexible_format("0x", 16), Ok(String::new()));
+    assert_eq!(base::parse_integer_input("0x", 16), Ok(None));
+    assert_eq!(base::validate_input("0b", 2, false), Ok(()));
+}
+
+#[test]
+fn validate_input_rejects_invalid_digits() {
+    assert_eq!(base::validate_input("", 10, false), Ok(()));
+    assert!(base::validate_input("102", 2, false).is_err());
+    assert!(base::validate_input("0xG1", 16, false).is_err());
+    assert!(base::validate_input("-", 10, false).is_err());
+    assert!(base::validate_input("1.5", 10, false).is_err());
+    assert_eq!(base::validate_input("1.5", 10, true), Ok(()));
+    assert!(base::validate_input("1.2.3", 10, true).is_err());
+    assert!(base::validate_input("1.9", 8, true).is_err());
+    assert!(base::validate_digits("Z", 37).is_err());
+}
+
+#[test]
+fn integer_conversions() {
+    assert_eq!(base::parse_integer_input("0xff", 16), Ok(Some(255)));
+    assert_eq!(base::parse_integer_input("-0b101", 2), Ok(Some(-5)));
+    assert!(base::parse_integer_input("99999999999999999999", 10).is_err());
+
+    assert_eq!(base::format_integer(255, 2), "11111111");
+    assert_eq!(base::format_integer(255, 16), "FF");
+    assert_eq!(base::format_integer(-255, 16), "-FF");
+    assert_eq!(base::format_integer(35, 36), "Z");
+    assert_eq!(base::format_integer(i64::MIN, 16), "-8000000000000000");
+    assert_eq!(base::convert_integer_to_base(0, 7), "0");
+}
+
+#[test]
+fn float_conversions() {
+    assert_eq!(base::parse_float_input("1010.101", 2), Ok(10.625));
+    assert_eq!(base::parse_float_input("-0x1.8", 16), Ok(-1.5));
+    assert_eq!(base::parse_float_input(".5", 10), Ok(0.5));
+    assert!(base::parse_float_input("1.2", 2).is_err());
+
+    assert_eq!(base::convert_float_to_base(10.625, 2, 6), "1010.101");
+    assert_eq!(base::convert_float_to_base(-1.5, 16, 6), "-1.8");
+    assert_eq!(base::convert_float_to_base(f64::NAN, 2, 6), "NaN");
+    assert_eq!(base::convert_float_to_base(f64::NEG_INFINITY, 2, 6), "-∞");
+    // 0.1은 2진수에서 유한하게 끝나지 않음
+    assert!(base::convert_float_to_base(0.1, 2, 6).ends_with("..."));
+}
+
+#[test]
+fn char_to_digit_is_case_insensitive() {
+    assert_eq!(base::char_to_digit('a'), Ok(10));
+    assert_eq!(base::char_to_digit('Z'), Ok(35));
+    assert_eq!(base::char_to_digit('7'), Ok(7));
+    assert!(base::char_to_digit('!').is_err());
+}
+
+proptest! {
+    #[test]
+    fn integer_round_trips_through_every_base(value in any::<i64>().prop_filter("i64::MIN has no positive counterpart", |v| *v != i64::MIN), radix in 2u32..=36) {
+        let text = base::format_integer(value, radix);
+        prop_assert_eq!(base::validate_input(&text, radix, false), Ok(()));
+        prop_assert_eq!(base::parse_integer_input(&text, radix), Ok(Some(value)));
+    }
+
+    #[test]
+    fn dyadic_fractions_round_trip_in_binary(numerator in 0u32..1024) {
+        let value = numerator as f64 / 64.0;
+        let text = base::convert_float_to_base(value, 2, 12);
+        prop_assert_eq!(base::parse_float_input(&text, 2), Ok(value));
+    }
+}
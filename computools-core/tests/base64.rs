@@ -0,0 +1,158 @@
+use computools_core::base64::{self, Base64Mode, Base64StreamDecoder, FileInfo, OutputFormat};
+use proptest::prelude::*;
+
+const MODES: [Base64Mode; 3] = [Base64Mode::Standard, Base64Mode::UrlSafe, Base64Mode::NoPadding];
+
+#[test]
+fn rfc4648_test_vectors() {
+    let vectors = [
+        ("", ""),
+        ("f", "Zg=="),
+        ("fo", "Zm8="),
+        ("foo", "Zm9v"),
+        ("foob", "Zm9vYg=="),
+        ("fooba", "Zm9vYmE="),
+        ("foobar", "Zm9vYmFy"),
+    ];
+    for (plain, encoded) in vectors {
+        assert_eq!(base64::encode_with_mode(plain.as_bytes(), &Base64Mode::Standard), encoded);
+        assert_eq!(base64::decode_with_mode(encoded, &Base64Mode::Standard), Ok(plain.as_bytes().to_vec()));
+    }
+}
+
+#[test]
+fn url_safe_and_no_padding_alphabets() {
+    let data = [0xFB, 0xFF, 0xBF];
+    assert_eq!(base64::encode_with_mode(&data, &Base64Mode::Standard), "+/+/");
+    assert_eq!(base64::encode_with_mode(&data, &Base64Mode::UrlSafe), "-_-_");
+    assert_eq!(base64::encode_with_mode(b"f", &Base64Mode::NoPadding), "Zg");
+    assert_eq!(base64::decode_with_mode("Zg", &Base64Mode::NoPadding), Ok(b"f".to_vec()));
+}
+
+#[test]
+fn validate_base64_input_errors() {
+    assert_eq!(base64::validate_base64_input("", &Base64Mode::Standard), Ok(()));
+    assert_eq!(base64::validate_base64_input("Zm9v\nYmFy", &Base64Mode::Standard), Ok(()));
+    assert!(base64::validate_base64_input("Zm9", &Base64Mode::Standard).is_err());
+    assert!(base64::validate_base64_input("Zm-v", &Base64Mode::Standard).is_err());
+    assert!(base64::validate_base64_input("Zm+v", &Base64Mode::UrlSafe).is_err());
+    assert!(base64::validate_base64_input("Z=9v", &Base64Mode::Standard).is_err());
+    assert_eq!(base64::validate_base64_input("Zm9", &Base64Mode::NoPadding), Ok(()));
+}
+
+#[test]
+fn format_output_variants() {
+    let encoded = "QUJDREVGR0g=";
+    assert_eq!(base64::format_output(encoded, &OutputFormat::Chunks4, None), "QUJD REVG R0g=");
+    assert_eq!(base64::format_output(encoded, &OutputFormat::Chunks8, None), "QUJDREVG R0g=");
+    assert_eq!(base64::format_output(encoded, &OutputFormat::DataUrlImg, None), "data:text/plain;base64,QUJDREVGR0g=");
+
+    let info = FileInfo { name: "a.png".to_string(), size: 1, mime_type: "image/png".to_string() };
+    assert_eq!(base64::format_output(encoded, &OutputFormat::DataUrlCss, Some(&info)), "url(data:image/png;base64,QUJDREVGR0g=)");
+
+    let long = "A".repeat(100);
+    let line_lengths: Vec<usize> = base64::format_chunk(&long, &OutputFormat::LineBreaks76).lines().map(str::len).collect();
+    assert_eq!(line_lengths, vec![76, 24]);
+    assert!(base64::is_chunkable_format(&OutputFormat::LineBreaks64));
+    assert!(!base64::is_chunkable_format(&OutputFormat::DataUrlImg));
+}
+
+#[test]
+fn data_url_parsing() {
+    assert_eq!(
+        base64::parse_data_url("data:image/png;base64,iVBORw0KGgo="),
+        Some(("image/png".to_string(), "iVBORw0KGgo=".to_string()))
+    );
+    assert_eq!(
+        base64::parse_data_url("url(data:text/plain;base64,QQ==)"),
+        Some(("text/plain".to_string(), "QQ==".to_string()))
+    );
+    assert_eq!(base64::parse_data_url("data:text/plain,hello"), None);
+    assert_eq!(base64::parse_data_url("QQ=="), None);
+    assert_eq!(base64::create_data_url(b"A", "text/plain"), "data:text/plain;base64,QQ==");
+}
+
+#[test]
+fn detect_image_mime_signatures() {
+    assert_eq!(base64::detect_image_mime(&[0x89, 0x50, 0x4E, 0x47, 0x0D]), Some("image/png".to_string()));
+    assert_eq!(base64::detect_image_mime(&[0xFF, 0xD8, 0xFF, 0xE0]), Some("image/jpeg".to_string()));
+    assert_eq!(base64::detect_image_mime(b"GIF89a.."), Some("image/gif".to_string()));
+    assert_eq!(base64::detect_image_mime(b"RIFF\0\0\0\0WEBP"), Some("image/webp".to_string()));
+    assert_eq!(base64::detect_image_mime(b"text"), None);
+    assert_eq!(base64::detect_image_mime(b"BM"), None);
+}
+
+#[test]
+fn file_size_formatting() {
+    assert_eq!(base64::format_file_size(512), "512 B");
+    assert_eq!(base64::format_file_size(1536), "1.5 KB");
+    assert_eq!(base64::format_file_size(2 * 1024 * 1024), "2.0 MB");
+}
+
+/// `chunk_size` 바이트씩 나눠 스트리밍 디코딩
+fn stream_decode(input: &str, mode: &Base64Mode, chunk_size: usize) -> Result<Vec<u8>, String> {
+    let mut decoder = Base64StreamDecoder::new(mode.clone());
+    let mut decoded = Vec::new();
+    for chunk in input.as_bytes().chunks(chunk_size.max(1)) {
+        decoded.extend(decoder.push(chunk)?);
+    }
+    decoded.extend(decoder.finish()?);
+    Ok(decoded)
+}
+
+#[test]
+fn stream_decoder_errors() {
+    assert_eq!(stream_decode("Zm9v\r\nYmFy", &Base64Mode::Standard, 3), Ok(b"foobar".to_vec()));
+    assert_eq!(stream_decode("Zg", &Base64Mode::NoPadding, 1), Ok(b"f".to_vec()));
+    assert_eq!(stream_decode("", &Base64Mode::Standard, 4), Ok(Vec::new()));
+
+    // 위치는 공백을 뺀 글자 기준
+    assert!(stream_decode("Zm9v\nYm!y", &Base64Mode::Standard, 2).unwrap_err().contains("'!' at position 7"));
+    assert!(stream_decode("Zm9v", &Base64Mode::UrlSafe, 4).is_ok());
+    assert!(stream_decode("+/+/", &Base64Mode::UrlSafe, 4).unwrap_err().contains("'+' at position 1"));
+    assert!(stream_decode("Zm9v\u{1}", &Base64Mode::Standard, 4).unwrap_err().contains("0x01"));
+    assert!(stream_decode("Zg==Zm9v", &Base64Mode::Standard, 4).unwrap_err().contains("position 5"));
+    assert!(stream_decode("Zm9vY", &Base64Mode::Standard, 2).unwrap_err().contains("5 characters"));
+    assert!(stream_decode("Zm9vZ===", &Base64Mode::Standard, 8).unwrap_err().contains("too many"));
+    assert!(stream_decode("Zg==", &Base64Mode::NoPadding, 8).is_err());
+}
+
+proptest! {
+    #[test]
+    fn encode_then_decode_is_identity(bytes in prop::collection::vec(any::<u8>(), 0..256)) {
+        for mode in &MODES {
+            let encoded = base64::encode_with_mode(&bytes, mode);
+            prop_assert_eq!(base64::validate_base64_input(&encoded, mode), Ok(()));
+            prop_assert_eq!(base64::decode_with_mode(&encoded, mode), Ok(bytes.clone()), "{:?}", mode);
+        }
+    }
+
+    #[test]
+    fn chunked_output_still_decodes(bytes in prop::collection::vec(any::<u8>(), 0..256)) {
+        let encoded = base64::encode_with_mode(&bytes, &Base64Mode::Standard);
+        for format in [OutputFormat::LineBreaks76, OutputFormat::LineBreaks64, OutputFormat::Chunks4, OutputFormat::Chunks8] {
+            let formatted = base64::format_output(&encoded, &format, None);
+            prop_assert_eq!(base64::decode_with_mode(&formatted, &Base64Mode::Standard), Ok(bytes.clone()));
+        }
+    }
+
+    #[test]
+    fn data_url_round_trips(bytes in prop::collection::vec(any::<u8>(), 0..64)) {
+        let url = base64::create_data_url(&bytes, "application/octet-stream");
+        let (mime, data) = base64::parse_data_url(&url).unwrap();
+        prop_assert_eq!(mime, "application/octet-stream");
+        prop_assert_eq!(base64::decode_with_mode(&data, &Base64Mode::Standard), Ok(bytes));
+    }
+
+    #[test]
+    fn stream_decode_matches_one_shot(
+        bytes in prop::collection::vec(any::<u8>(), 0..512),
+        chunk_size in 1usize..100,
+        format in prop::sample::select(vec![OutputFormat::Continuous, OutputFormat::LineBreaks76, OutputFormat::Chunks4]),
+    ) {
+        for mode in &MODES {
+            let encoded = base64::format_chunk(&base64::encode_with_mode(&bytes, mode), &format);
+            prop_assert_eq!(stream_decode(&encoded, mode, chunk_size), Ok(bytes.clone()), "{:?}", mode);
+        }
+    }
+}
@@ -0,0 +1,53 @@
+use computools_core::bytes::{self, InputEncoding};
+use proptest::prelude::*;
+
+#[test]
+fn hex_accepts_common_notations() {
+    for input in ["616263", "61 62 63", "61:62:63", "0x61,0x62,0x63", "0x616263", "\\x61\\x62\\x63", "61\n62\t63\n"] {
+        assert_eq!(InputEncoding::Hex.decode(input), Ok(b"abc".to_vec()), "{}", input);
+    }
+    assert_eq!(InputEncoding::Hex.decode("  "), Ok(Vec::new()));
+
+    // 묶음마다 자릿수가 짝수여야 하므로 "6 1"을 0x61로 합치지 않음
+    assert!(InputEncoding::Hex.decode("abc").is_err());
+    assert!(InputEncoding::Hex.decode("6 1").is_err());
+    assert!(InputEncoding::Hex.decode("4G").is_err());
+}
+
+#[test]
+fn base64_and_utf8() {
+    assert_eq!(InputEncoding::Utf8.decode(" abc "), Ok(b" abc ".to_vec()));
+    assert_eq!(InputEncoding::Base64.decode("YWJj"), Ok(b"abc".to_vec()));
+    assert_eq!(InputEncoding::Base64.decode("YWI="), Ok(b"ab".to_vec()));
+    assert_eq!(InputEncoding::Base64.decode("YW\nI"), Ok(b"ab".to_vec()));
+    assert_eq!(InputEncoding::Base64.decode("-_8="), Ok(vec![0xfb, 0xff]));
+    assert_eq!(InputEncoding::Base64.decode("  "), Ok(Vec::new()));
+    assert!(InputEncoding::Base64.decode("Y").is_err());
+
+    assert_eq!(InputEncoding::Hex.encode(b"Hi"), "4869");
+    assert_eq!(InputEncoding::Base64.encode(b"Hi"), "SGk=");
+    assert_eq!(InputEncoding::Utf8.encode(b"Hi\xff"), "Hi\u{fffd}");
+}
+
+#[test]
+fn keys_and_random_bytes() {
+    for encoding in InputEncoding::all() {
+        assert_eq!(InputEncoding::from_key(encoding.key()), Some(encoding));
+    }
+    assert_eq!(InputEncoding::from_key(" Base64 "), Some(InputEncoding::Base64));
+    assert_eq!(InputEncoding::from_key("raw"), None);
+    assert!(!InputEncoding::binary().contains(&InputEncoding::Utf8));
+
+    let first = bytes::random_bytes(16).unwrap();
+    assert_eq!(first.len(), 16);
+    assert_ne!(first, bytes::random_bytes(16).unwrap());
+}
+
+proptest! {
+    #[test]
+    fn encode_and_decode_round_trip(data in proptest::collection::vec(any::<u8>(), 0..64)) {
+        for encoding in InputEncoding::binary() {
+            prop_assert_eq!(encoding.decode(&encoding.encode(&data)), Ok(data.clone()));
+        }
+    }
+}
@@ -0,0 +1,100 @@
+use computools_core::bytes::InputEncoding;
+use computools_core::cipher::{self, CipherMode};
+use proptest::prelude::*;
+
+fn hex(input: &str) -> Vec<u8> {
+    InputEncoding::Hex.decode(input).unwrap()
+}
+
+#[test]
+fn aes_gcm_matches_nist_test_case_2() {
+    let ciphertext = cipher::encrypt(CipherMode::Aes128Gcm, &[0; 16], &[0; 12], &[], &[0; 16]).unwrap();
+    assert_eq!(
+        hex::encode(&ciphertext),
+        "0388dace60b6a392f328c2b971b2fe78ab6e47d42cec13bdf53a67b21257bddf"
+    );
+    assert_eq!(cipher::decrypt(CipherMode::Aes128Gcm, &[0; 16], &[0; 12], &[], &ciphertext), Ok(vec![0; 16]));
+}
+
+#[test]
+fn aes_cbc_and_ctr_match_sp800_38a() {
+    let key = hex("2b7e151628aed2a6abf7158809cf4f3c");
+    let plaintext = hex("6bc1bee22e409f96e93d7e117393172a");
+
+    // F.2.1 CBC-AES128 (PKCS#7 패딩으로 블록 하나가 더 붙음)
+    let iv = hex("000102030405060708090a0b0c0d0e0f");
+    let cbc = cipher::encrypt(CipherMode::Aes128Cbc, &key, &iv, &[], &plaintext).unwrap();
+    assert_eq!(cbc.len(), 32);
+    assert_eq!(hex::encode(&cbc[..16]), "7649abac8119b246cee98e9b12e9197d");
+    assert_eq!(cipher::decrypt(CipherMode::Aes128Cbc, &key, &iv, &[], &cbc), Ok(plaintext.clone()));
+
+    // F.5.1 CTR-AES128
+    let counter = hex("f0f1f2f3f4f5f6f7f8f9fafbfcfdfeff");
+    let ctr = cipher::encrypt(CipherMode::Aes128Ctr, &key, &counter, &[], &plaintext).unwrap();
+    assert_eq!(hex::encode(&ctr), "874d6191b620e3261bef6864990db6ce");
+}
+
+#[test]
+fn chacha20_poly1305_matches_rfc8439() {
+    let key = hex("808182838485868788898a8b8c8d8e8f909192939495969798999a9b9c9d9e9f");
+    let nonce = hex("070000004041424344454647");
+    let aad = hex("50515253c0c1c2c3c4c5c6c7");
+    let plaintext = b"Ladies and Gentlemen of the class of '99: If I could offer you only one tip for the future, sunscreen would be it.";
+
+    let ciphertext = cipher::encrypt(CipherMode::ChaCha20Poly1305, &key, &nonce, &aad, plaintext).unwrap();
+    let (body, tag) = ciphertext.split_at(plaintext.len());
+    assert_eq!(hex::encode(&body[..16]), "d31a8d34648e60db7b86afbc53ef7ec2");
+    assert_eq!(hex::encode(tag), "1ae10b594f09e26a7e902ecbd0600691");
+}
+
+#[test]
+fn authentication_and_padding_failures_are_clear_errors() {
+    let key = [1u8; 32];
+    let nonce = [2u8; 12];
+    let mut ciphertext = cipher::encrypt(CipherMode::Aes256Gcm, &key, &nonce, b"header", b"secret").unwrap();
+
+    let wrong_aad = cipher::decrypt(CipherMode::Aes256Gcm, &key, &nonce, b"other", &ciphertext).unwrap_err();
+    assert!(wrong_aad.starts_with("Authentication failed"));
+    ciphertext[0] ^= 1;
+    assert!(cipher::decrypt(CipherMode::Aes256Gcm, &key, &nonce, b"header", &ciphertext).is_err());
+    assert!(cipher::decrypt(CipherMode::Aes256Gcm, &key, &nonce, b"", &[0; 8]).unwrap_err().contains("tag"));
+
+    let cbc = cipher::encrypt(CipherMode::Aes256Cbc, &key, &[0; 16], &[], b"secret").unwrap();
+    assert!(cipher::decrypt(CipherMode::Aes256Cbc, &[9; 32], &[0; 16], &[], &cbc).is_err());
+    assert!(cipher::decrypt(CipherMode::Aes256Cbc, &key, &[0; 16], &[], &cbc[..15]).is_err());
+}
+
+#[test]
+fn key_iv_and_aad_are_checked() {
+    assert_eq!(
+        cipher::encrypt(CipherMode::Aes256Gcm, &[0; 16], &[0; 12], &[], b"x"),
+        Err("AES-256-GCM needs a 32-byte key, got 16 bytes.".to_string())
+    );
+    assert_eq!(
+        cipher::encrypt(CipherMode::Aes128Cbc, &[0; 16], &[0; 12], &[], b"x"),
+        Err("AES-128-CBC needs a 16-byte IV, got 12 bytes.".to_string())
+    );
+    assert!(cipher::encrypt(CipherMode::Aes128Ctr, &[0; 16], &[0; 16], b"aad", b"x").is_err());
+    assert_eq!(CipherMode::from_key("AES-256-GCM"), Some(CipherMode::Aes256Gcm));
+}
+
+proptest! {
+    #[test]
+    fn every_mode_round_trips(
+        index in 0usize..7,
+        plaintext in proptest::collection::vec(any::<u8>(), 0..100),
+        aad in proptest::collection::vec(any::<u8>(), 0..20),
+        seed in any::<u8>(),
+    ) {
+        let mode = CipherMode::all()[index];
+        let key = vec![seed; mode.key_length()];
+        let iv = vec![seed.wrapping_add(1); mode.iv_length()];
+        let aad = if mode.is_aead() { aad } else { Vec::new() };
+
+        let ciphertext = cipher::encrypt(mode, &key, &iv, &aad, &plaintext).unwrap();
+        if mode.is_aead() {
+            prop_assert_eq!(ciphertext.len(), plaintext.len() + cipher::TAG_LENGTH);
+        }
+        prop_assert_eq!(cipher::decrypt(mode, &key, &iv, &aad, &ciphertext), Ok(plaintext));
+    }
+}
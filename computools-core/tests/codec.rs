@@ -0,0 +1,86 @@
+use computools_core::base64::Base64Mode;
+use computools_core::codec::{Codec, BASE58_MAX_BYTES};
+use proptest::prelude::*;
+
+fn check(codec: Codec, vectors: &[(&[u8], &str)]) {
+    for (plain, encoded) in vectors {
+        assert_eq!(codec.encode(plain).as_deref(), Ok(*encoded), "{:?} {:?}", codec, plain);
+        assert_eq!(codec.decode(encoded), Ok(plain.to_vec()), "{:?} {:?}", codec, encoded);
+    }
+}
+
+#[test]
+fn base32_rfc4648_vectors() {
+    check(
+        Codec::Base32,
+        &[(b"", ""), (b"f", "MY======"), (b"fo", "MZXQ===="), (b"foo", "MZXW6==="), (b"foob", "MZXW6YQ="), (b"fooba", "MZXW6YTB"), (b"foobar", "MZXW6YTBOI======")],
+    );
+    check(Codec::Base32Hex, &[(b"f", "CO======"), (b"foobar", "CPNMUOJ1E8======")]);
+
+    // TOTP 시크릿처럼 소문자, 공백, 패딩 생략도 받음
+    assert_eq!(Codec::Base32.decode("mzxw 6ytb oi"), Ok(b"foobar".to_vec()));
+    assert!(Codec::Base32.decode("MZXW1").is_err());
+    assert!(Codec::Base32.decode("M").is_err());
+}
+
+#[test]
+fn base58_and_base58check() {
+    check(Codec::Base58, &[(b"", ""), (b"Hello World!", "2NEpo7TZRRrLZSi2U"), (&[0, 0, 0x28, 0x7f, 0xb4, 0xcd], "11233QC4")]);
+    assert!(Codec::Base58.decode("0OIl").unwrap_err().contains("'0'"));
+
+    // 비트코인 제네시스 블록 주소: 버전 0x00 + HASH160
+    let payload = Codec::Base58Check.decode("1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNa").unwrap();
+    assert_eq!(hex::encode(&payload), "0062e907b15cbf27d5425399ebf6f0fb50ebb88f18");
+    assert_eq!(Codec::Base58Check.encode(&payload).unwrap(), "1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNa");
+    assert!(Codec::Base58Check.decode("1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNb").unwrap_err().contains("checksum"));
+
+    assert!(Codec::Base58.encode(&vec![1; BASE58_MAX_BYTES + 1]).is_err());
+}
+
+#[test]
+fn ascii85_and_z85() {
+    check(Codec::Ascii85, &[(b"Man ", "9jqo^"), (b"sure.", "F*2M7/c"), (&[0, 0, 0, 0, 1], "z!<"), (b"", "")]);
+    assert_eq!(Codec::Ascii85.decode("<~9jqo^\nF*2M7~>"), Ok(b"Man sure".to_vec()));
+    assert!(Codec::Ascii85.decode("9jqo^v").is_err());
+    assert!(Codec::Ascii85.decode("s8W-\"").unwrap_err().contains("32 bits"));
+
+    // ZeroMQ RFC 32 예제
+    check(Codec::Z85, &[(&[0x86, 0x4F, 0xD2, 0x6F, 0xB5, 0x59, 0xF7, 0x5B], "HelloWorld")]);
+    assert!(Codec::Z85.encode(b"abc").unwrap_err().contains("multiple of 4"));
+    assert!(Codec::Z85.decode("Hello").is_ok());
+    assert!(Codec::Z85.decode("Hell").is_err());
+}
+
+#[test]
+fn base45_rfc9285_vectors() {
+    check(Codec::Base45, &[(b"AB", "BB8"), (b"Hello!!", "%69 VD92EX0"), (b"base-45", "UJCLQE7W581"), (b"ietf!", "QED8WEX0")]);
+    assert!(Codec::Base45.decode("GGW").unwrap_err().contains("65535"));
+    assert!(Codec::Base45.decode("a").is_err());
+}
+
+#[test]
+fn keys_and_base64_modes() {
+    for codec in Codec::all() {
+        assert_eq!(Codec::from_key(codec.key()), Some(codec));
+    }
+    assert_eq!(Codec::Base64(Base64Mode::UrlSafe).encode(&[0xFB, 0xFF, 0xBF]), Ok("-_-_".to_string()));
+    assert!(Codec::Base64(Base64Mode::Standard).decode("Zm9").is_err());
+}
+
+proptest! {
+    #[test]
+    fn round_trip_and_block_concatenation(data in proptest::collection::vec(any::<u8>(), 0..200), split in 0usize..50) {
+        for codec in Codec::all() {
+            let data = if codec == Codec::Z85 { &data[..data.len() / 4 * 4] } else { &data[..] };
+            let encoded = codec.encode(data).unwrap();
+            prop_assert_eq!(codec.decode(&encoded), Ok(data.to_vec()), "{:?}", codec);
+
+            // 블록 크기의 배수로 나눠 인코딩해 이어 붙여도 같은 결과 (청크 처리)
+            if let Some(block) = codec.block_size() {
+                let at = (split * block).min(data.len() / block * block);
+                let joined = codec.encode(&data[..at]).unwrap() + &codec.encode(&data[at..]).unwrap();
+                prop_assert_eq!(joined, encoded, "{:?}", codec);
+            }
+        }
+    }
+}
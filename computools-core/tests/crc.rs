@@ -0,0 +1,302 @@
+use computools_core::crc::{
+    self, ByteFormatting, CrcAlgorithm, CrcParams, Endianness, HexStyle, InputMode, OutputMode,
+    OutputOptions,
+};
+use proptest::prelude::*;
+
+/// reveng CRC 카탈로그의 "123456789" check 값 (이름, 폭, check)
+const CHECK_VALUES: &[(&str, u8, u64)] = &[
+    ("CRC-3/GSM", 3, 0x4),
+    ("CRC-3/ROHC", 3, 0x6),
+    ("CRC-4/G-704", 4, 0x7),
+    ("CRC-4/INTERLAKEN", 4, 0xb),
+    ("CRC-5/EPC-C1G2", 5, 0x00),
+    ("CRC-5/G-704", 5, 0x07),
+    ("CRC-5/USB", 5, 0x19),
+    ("CRC-6/CDMA2000-A", 6, 0x0d),
+    ("CRC-6/CDMA2000-B", 6, 0x3b),
+    ("CRC-6/DARC", 6, 0x26),
+    ("CRC-6/GSM", 6, 0x13),
+    ("CRC-6/G-704", 6, 0x06),
+    ("CRC-7/MMC", 7, 0x75),
+    ("CRC-7/ROHC", 7, 0x53),
+    ("CRC-7/UMTS", 7, 0x61),
+    ("CRC-8/AUTOSAR", 8, 0xdf),
+    ("CRC-8/BLUETOOTH", 8, 0x26),
+    ("CRC-8/CDMA2000", 8, 0xda),
+    ("CRC-8/DARC", 8, 0x15),
+    ("CRC-8/DVB-S2", 8, 0xbc),
+    ("CRC-8/GSM-A", 8, 0x37),
+    ("CRC-8/GSM-B", 8, 0x94),
+    ("CRC-8/HITAG", 8, 0xb4),
+    ("CRC-8/I-432-1", 8, 0xa1),
+    ("CRC-8/I-CODE", 8, 0x7e),
+    ("CRC-8/LTE", 8, 0xea),
+    ("CRC-8/MAXIM-DOW", 8, 0xa1),
+    ("CRC-8/MIFARE-MAD", 8, 0x99),
+    ("CRC-8/NRSC-5", 8, 0xf7),
+    ("CRC-8/OPENSAFETY", 8, 0x3e),
+    ("CRC-8/ROHC", 8, 0xd0),
+    ("CRC-8/SAE-J1850", 8, 0x4b),
+    ("CRC-8/SMBUS", 8, 0xf4),
+    ("CRC-8/TECH-3250", 8, 0x97),
+    ("CRC-8/WCDMA", 8, 0x25),
+    ("CRC-10/ATM", 10, 0x199),
+    ("CRC-10/CDMA2000", 10, 0x233),
+    ("CRC-10/GSM", 10, 0x12a),
+    ("CRC-11/FLEXRAY", 11, 0x5a3),
+    ("CRC-11/UMTS", 11, 0x061),
+    ("CRC-12/CDMA2000", 12, 0xd4d),
+    ("CRC-12/DECT", 12, 0xf5b),
+    ("CRC-12/GSM", 12, 0xb34),
+    ("CRC-12/UMTS", 12, 0xdaf),
+    ("CRC-13/BBC", 13, 0x04fa),
+    ("CRC-14/DARC", 14, 0x082d),
+    ("CRC-14/GSM", 14, 0x30ae),
+    ("CRC-15/CAN", 15, 0x059e),
+    ("CRC-15/MPT1327", 15, 0x2566),
+    ("CRC-16/ARC", 16, 0xbb3d),
+    ("CRC-16/CDMA2000", 16, 0x4c06),
+    ("CRC-16/CMS", 16, 0xaee7),
+    ("CRC-16/DDS-110", 16, 0x9ecf),
+    ("CRC-16/DECT-R", 16, 0x007e),
+    ("CRC-16/DECT-X", 16, 0x007f),
+    ("CRC-16/DNP", 16, 0xea82),
+    ("CRC-16/EN-13757", 16, 0xc2b7),
+    ("CRC-16/GENIBUS", 16, 0xd64e),
+    ("CRC-16/GSM", 16, 0xce3c),
+    ("CRC-16/IBM-3740", 16, 0x29b1),
+    ("CRC-16/IBM-SDLC", 16, 0x906e),
+    ("CRC-16/ISO-IEC-14443-3-A", 16, 0xbf05),
+    ("CRC-16/KERMIT", 16, 0x2189),
+    ("CRC-16/LJ1200", 16, 0xbdf4),
+    ("CRC-16/M17", 16, 0x772b),
+    ("CRC-16/MAXIM-DOW", 16, 0x44c2),
+    ("CRC-16/MCRF4XX", 16, 0x6f91),
+    ("CRC-16/MODBUS", 16, 0x4b37),
+    ("CRC-16/NRSC-5", 16, 0xa066),
+    ("CRC-16/OPENSAFETY-A", 16, 0x5d38),
+    ("CRC-16/OPENSAFETY-B", 16, 0x20fe),
+    ("CRC-16/PROFIBUS", 16, 0xa819),
+    ("CRC-16/RIELLO", 16, 0x63d0),
+    ("CRC-16/SPI-FUJITSU", 16, 0xe5cc),
+    ("CRC-16/T10-DIF", 16, 0xd0db),
+    ("CRC-16/TELEDISK", 16, 0x0fb3),
+    ("CRC-16/TMS37157", 16, 0x26b1),
+    ("CRC-16/UMTS", 16, 0xfee8),
+    ("CRC-16/USB", 16, 0xb4c8),
+    ("CRC-16/XMODEM", 16, 0x31c3),
+    ("CRC-17/CAN-FD", 17, 0x04f03),
+    ("CRC-21/CAN-FD", 21, 0x0ed841),
+    ("CRC-24/BLE", 24, 0xc25a56),
+    ("CRC-24/FLEXRAY-A", 24, 0x7979bd),
+    ("CRC-24/FLEXRAY-B", 24, 0x1f23b8),
+    ("CRC-24/INTERLAKEN", 24, 0xb4f3e6),
+    ("CRC-24/LTE-A", 24, 0xcde703),
+    ("CRC-24/LTE-B", 24, 0x23ef52),
+    ("CRC-24/OPENPGP", 24, 0x21cf02),
+    ("CRC-24/OS-9", 24, 0x200fa5),
+    ("CRC-30/CDMA", 30, 0x04c34abf),
+    ("CRC-31/PHILIPS", 31, 0x0ce9e46c),
+    ("CRC-32/AIXM", 32, 0x3010bf7f),
+    ("CRC-32/AUTOSAR", 32, 0x1697d06a),
+    ("CRC-32/BASE91-D", 32, 0x87315576),
+    ("CRC-32/BZIP2", 32, 0xfc891918),
+    ("CRC-32/CD-ROM-EDC", 32, 0x6ec2edc4),
+    ("CRC-32/CKSUM", 32, 0x765e7680),
+    ("CRC-32/ISCSI", 32, 0xe3069283),
+    ("CRC-32/ISO-HDLC", 32, 0xcbf43926),
+    ("CRC-32/JAMCRC", 32, 0x340bc6d9),
+    ("CRC-32/MEF", 32, 0xd2c22f51),
+    ("CRC-32/MPEG-2", 32, 0x0376e6e7),
+    ("CRC-32/XFER", 32, 0xbd0be338),
+    ("CRC-40/GSM", 40, 0xd4164fc646),
+    ("CRC-64/ECMA-182", 64, 0x6c40df5f0b497347),
+    ("CRC-64/GO-ISO", 64, 0xb90956c775a41001),
+    ("CRC-64/MS", 64, 0x75d4b74f024eceea),
+    ("CRC-64/REDIS", 64, 0xe9c6d914c4b8d9ca),
+    ("CRC-64/WE", 64, 0x62ec59e3f1a4f00a),
+    ("CRC-64/XZ", 64, 0x995dc9bbdf1939fa),
+];
+
+#[test]
+fn every_algorithm_matches_catalogue_check_value() {
+    let algorithms = CrcAlgorithm::all();
+    assert_eq!(algorithms.len(), CHECK_VALUES.len());
+
+    for (algorithm, (name, width, check)) in algorithms.iter().zip(CHECK_VALUES) {
+        assert_eq!(algorithm.name(), *name);
+        assert_eq!(algorithm.calculate(b"123456789"), (*check, *width), "{}", name);
+    }
+}
+
+#[test]
+fn from_name_round_trips_every_algorithm() {
+    for algorithm in CrcAlgorithm::all() {
+        assert_eq!(CrcAlgorithm::from_name(algorithm.name()), Some(algorithm));
+    }
+    assert_eq!(CrcAlgorithm::from_name("CRC-99/UNKNOWN"), None);
+}
+
+#[test]
+fn catalogue_params_reproduce_check_values() {
+    for (algorithm, (name, _, check)) in CrcAlgorithm::all().iter().zip(CHECK_VALUES) {
+        let params = algorithm.params();
+        assert_eq!(params.validate(), Ok(()), "{}", name);
+        assert_eq!(params.check(), *check, "{}", name);
+    }
+}
+
+#[test]
+fn custom_algorithm_uses_its_params() {
+    let params = CrcParams { width: 16, poly: 0x8005, init: 0xFFFF, refin: true, refout: true, xorout: 0 };
+    assert_eq!(CrcAlgorithm::Custom(params).calculate(&[0x01, 0x03]), (0x2140, 16));
+    assert_eq!(CrcAlgorithm::Custom(params).name(), "Custom");
+    assert_eq!(CrcAlgorithm::from_name("Custom"), Some(CrcAlgorithm::Custom(CrcParams::default())));
+    assert_eq!(CrcParams::default(), CrcAlgorithm::Crc32IsoHdlc.params());
+}
+
+#[test]
+fn custom_params_edge_widths() {
+    // 1비트 CRC (poly 1)는 패리티 비트
+    let parity = CrcParams { width: 1, poly: 1, init: 0, refin: false, refout: false, xorout: 0 };
+    let ones: u32 = b"123456789".iter().map(|byte| byte.count_ones()).sum();
+    assert_eq!(parity.check(), (ones % 2) as u64);
+
+    let full = CrcParams { width: 64, poly: u64::MAX, init: u64::MAX, refin: false, refout: true, xorout: u64::MAX };
+    assert_eq!(full.validate(), Ok(()));
+    assert_eq!(full.mask(), u64::MAX);
+}
+
+#[test]
+fn custom_params_validation() {
+    let mut params = CrcParams { width: 0, ..CrcParams::default() };
+    assert!(params.validate().is_err());
+    params.width = 65;
+    assert!(params.validate().is_err());
+    params.width = 8;
+    params.poly = 0x107;
+    assert!(params.validate().unwrap_err().contains("Poly"));
+    params.poly = 0x07;
+    params.init = 0;
+    params.xorout = 0;
+    params.refin = false;
+    params.refout = false;
+    assert_eq!(params.validate(), Ok(()));
+    assert_eq!(params.check(), 0xF4);
+}
+
+#[test]
+fn parse_param_value_formats() {
+    assert_eq!(crc::parse_param_value("0x1021"), Ok(0x1021));
+    assert_eq!(crc::parse_param_value(" 04C11DB7 "), Ok(0x04C11DB7));
+    assert_eq!(crc::parse_param_value("1021h"), Ok(0x1021));
+    assert_eq!(crc::parse_param_value("0xFFFF_FFFF"), Ok(0xFFFFFFFF));
+    assert!(crc::parse_param_value("").is_err());
+    assert!(crc::parse_param_value("0x").is_err());
+    assert!(crc::parse_param_value("xyz").is_err());
+    assert!(crc::parse_param_value("1FFFFFFFFFFFFFFFF").is_err());
+}
+
+#[test]
+fn parse_input_accepts_all_hex_notations() {
+    let expected = vec![0x01, 0x03, 0xAB];
+    for input in ["01 03 AB", "0103ab", "0x01 0x03 0xAB", "0x010x030xAB", "\\x01\\x03\\xab", "x01 x03 xAB"] {
+        assert_eq!(crc::parse_input(input, &InputMode::Hex), Ok(expected.clone()), "{}", input);
+    }
+    assert_eq!(crc::parse_input("", &InputMode::Hex), Ok(vec![]));
+    assert!(crc::parse_input("0xZZ", &InputMode::Hex).is_err());
+}
+
+#[test]
+fn parse_input_other_modes() {
+    assert_eq!(crc::parse_input("AB", &InputMode::Ascii), Ok(b"AB".to_vec()));
+    assert_eq!(crc::parse_input("1 3 255", &InputMode::Decimal), Ok(vec![1, 3, 255]));
+    assert!(crc::parse_input("256", &InputMode::Decimal).is_err());
+    assert_eq!(crc::parse_input("0b00000001 0b11111111", &InputMode::Binary), Ok(vec![1, 255]));
+    assert_eq!(crc::parse_input("001 377", &InputMode::Octal), Ok(vec![1, 255]));
+}
+
+#[test]
+fn modbus_request_example() {
+    let data = crc::parse_input("01 03", &InputMode::Hex).unwrap();
+    let (value, width) = CrcAlgorithm::Crc16Modbus.calculate(&data);
+    assert_eq!(crc::format_crc_output(value, width, &OutputOptions::default()), "0x2140");
+}
+
+#[test]
+fn apply_endianness_swaps_bytes() {
+    assert_eq!(crc::apply_endianness(0x1234, 16, &Endianness::LittleEndian), 0x3412);
+    assert_eq!(crc::apply_endianness(0x123456, 24, &Endianness::LittleEndian), 0x563412);
+    assert_eq!(crc::apply_endianness(0xCBF43926, 32, &Endianness::LittleEndian), 0x2639F4CB);
+    assert_eq!(crc::apply_endianness(0x12, 8, &Endianness::LittleEndian), 0x12);
+    assert_eq!(crc::apply_endianness(0xCBF43926, 32, &Endianness::BigEndian), 0xCBF43926);
+}
+
+#[test]
+fn format_crc_output_modes() {
+    let mut options = OutputOptions::default();
+    assert_eq!(crc::format_crc_output(0xCBF43926, 32, &options), "0xCBF43926");
+
+    options.byte_formatting = ByteFormatting::ByteSeparated;
+    options.hex_style = HexStyle::NoPrefix;
+    assert_eq!(crc::format_crc_output(0xCBF43926, 32, &options), "CB F4 39 26");
+
+    options.output_mode = OutputMode::Decimal;
+    assert_eq!(crc::format_crc_output(0x4B37, 16, &options), "19255");
+
+    // 폭보다 짧은 값도 바이트 수만큼 0으로 채움
+    let options = OutputOptions::default();
+    assert_eq!(crc::format_crc_output(0x1, 16, &options), "0x0001");
+}
+
+proptest! {
+    #[test]
+    fn hex_string_parses_back_to_bytes(bytes in prop::collection::vec(any::<u8>(), 1..64)) {
+        let spaced = crc::bytes_to_string(&bytes);
+        prop_assert_eq!(crc::parse_input(&spaced, &InputMode::Hex), Ok(bytes.clone()));
+
+        let packed: String = bytes.iter().map(|byte| format!("{:02x}", byte)).collect();
+        prop_assert_eq!(crc::parse_input(&packed, &InputMode::Hex), Ok(bytes));
+    }
+
+    #[test]
+    fn little_endian_twice_is_identity(value in any::<u32>()) {
+        let swapped = crc::apply_endianness(value as u64, 32, &Endianness::LittleEndian);
+        prop_assert_eq!(crc::apply_endianness(swapped, 32, &Endianness::LittleEndian), value as u64);
+    }
+
+    #[test]
+    fn chunked_digest_matches_one_shot(
+        data in prop::collection::vec(any::<u8>(), 0..256),
+        chunk_size in 1usize..64,
+    ) {
+        let custom = CrcAlgorithm::Custom(CrcParams { width: 13, poly: 0x1CF5, init: 0x0ABC, refin: true, refout: false, xorout: 0x1FFF });
+        for algorithm in CrcAlgorithm::all().into_iter().chain([custom]) {
+            let engine = algorithm.engine();
+            let mut digest = engine.digest();
+            for chunk in data.chunks(chunk_size) {
+                digest.update(chunk);
+            }
+            prop_assert_eq!((digest.finalize(), engine.width()), algorithm.calculate(&data), "{}", algorithm.name());
+        }
+    }
+
+    #[test]
+    fn bitwise_params_match_table_driven_crc(data in prop::collection::vec(any::<u8>(), 0..64)) {
+        for algorithm in CrcAlgorithm::all() {
+            let (expected, _) = algorithm.calculate(&data);
+            prop_assert_eq!(algorithm.params().calculate(&data), expected, "{}", algorithm.name());
+        }
+    }
+
+    #[test]
+    fn crc_fits_in_width(data in prop::collection::vec(any::<u8>(), 0..32)) {
+        for algorithm in CrcAlgorithm::all() {
+            let (value, width) = algorithm.calculate(&data);
+            if width < 64 {
+                prop_assert!(value < (1u64 << width), "{}", algorithm.name());
+            }
+        }
+    }
+}
@@ -0,0 +1,327 @@
+use computools_core::crc::codegen::{self, CodeLanguage, CodeStyle};
+use computools_core::crc::{CrcAlgorithm, CrcParams};
+use proptest::prelude::*;
+
+#[test]
+fn identifier_from_algorithm_names() {
+    assert_eq!(codegen::identifier("CRC-16/MODBUS"), "crc16_modbus");
+    assert_eq!(codegen::identifier("CRC-8/I-432-1"), "crc8_i_432_1");
+    assert_eq!(codegen::identifier("CRC-32/ISO-HDLC"), "crc32_iso_hdlc");
+    assert_eq!(codegen::identifier("Custom"), "custom");
+    assert_eq!(codegen::identifier("16 bit"), "crc_16_bit");
+
+    let custom = CrcAlgorithm::Custom(CrcParams { width: 12, ..CrcParams::default() });
+    assert_eq!(codegen::code_name(&custom), "CRC-12/CUSTOM");
+    assert_eq!(codegen::identifier(&codegen::code_name(&custom)), "crc12_custom");
+    assert_eq!(codegen::code_name(&CrcAlgorithm::Crc16Modbus), "CRC-16/MODBUS");
+}
+
+#[test]
+fn register_bits_round_up_to_native_types() {
+    assert_eq!(codegen::register_bits(3), 8);
+    assert_eq!(codegen::register_bits(8), 8);
+    assert_eq!(codegen::register_bits(12), 16);
+    assert_eq!(codegen::register_bits(24), 32);
+    assert_eq!(codegen::register_bits(40), 64);
+}
+
+#[test]
+fn lookup_tables_match_well_known_values() {
+    let crc32 = codegen::lookup_table(&CrcAlgorithm::Crc32IsoHdlc.params());
+    assert_eq!(crc32.len(), 256);
+    assert_eq!((crc32[0], crc32[1], crc32[255]), (0, 0x77073096, 0x2D02EF8D));
+
+    let xmodem = codegen::lookup_table(&CrcAlgorithm::Crc16Xmodem.params());
+    assert_eq!((xmodem[1], xmodem[255]), (0x1021, 0x1EF0));
+
+    let smbus = codegen::lookup_table(&CrcAlgorithm::Crc8Smbus.params());
+    assert_eq!((smbus[1], smbus[255]), (0x07, 0xF3));
+
+    // 폭이 8보다 작은 비반사 알고리즘은 레지스터 최상위 쪽에 맞춤 (CRC-5/EPC-C1G2: poly 0x09)
+    let epc = codegen::lookup_table(&CrcAlgorithm::Crc5EpcC1g2.params());
+    assert_eq!(epc[1], 0x09 << 3);
+}
+
+#[test]
+fn format_table_has_eight_entries_per_line() {
+    let table = codegen::format_table(&CrcAlgorithm::Crc16Modbus.params());
+    let lines: Vec<&str> = table.lines().collect();
+    assert_eq!(lines.len(), 32);
+    assert!(lines[0].starts_with("0x0000, 0xC0C1, 0xC181"));
+}
+
+#[test]
+fn software_code_contains_function_and_parameters() {
+    let params = CrcAlgorithm::Crc16Modbus.params();
+    let c = codegen::generate(&params, "CRC-16/MODBUS", CodeLanguage::C, CodeStyle::TableDriven);
+    assert!(c.starts_with("// CRC-16/MODBUS: width=16 poly=0x8005"));
+    assert!(c.contains("static const uint16_t crc16_modbus_table[256]"));
+    assert!(c.contains("uint16_t crc16_modbus(const uint8_t *data, size_t len)"));
+
+    let rust = codegen::generate(&params, "CRC-16/MODBUS", CodeLanguage::Rust, CodeStyle::Bitwise);
+    assert!(rust.contains("pub fn crc16_modbus(data: &[u8]) -> u16"));
+    assert!(!rust.contains("TABLE"));
+
+    let python = codegen::generate(&params, "CRC-16/MODBUS", CodeLanguage::Python, CodeStyle::TableDriven);
+    assert!(python.contains("def crc16_modbus(data: bytes) -> int:"));
+    assert!(python.contains("CRC16_MODBUS_TABLE = ["));
+}
+
+#[test]
+fn reflect_helper_only_when_refin_differs_from_refout() {
+    let mixed = CrcParams { width: 12, poly: 0x80F, init: 0, refin: false, refout: true, xorout: 0 };
+    let c = codegen::generate(&mixed, "CRC-12/UMTS", CodeLanguage::C, CodeStyle::TableDriven);
+    assert!(c.contains("crc12_umts_reflect"));
+
+    let plain = codegen::generate(&CrcAlgorithm::Crc16Modbus.params(), "CRC-16/MODBUS", CodeLanguage::C, CodeStyle::TableDriven);
+    assert!(!plain.contains("reflect"));
+}
+
+#[test]
+fn hdl_modules_have_one_equation_per_bit() {
+    let params = CrcAlgorithm::Crc16Modbus.params();
+    let verilog = codegen::generate(&params, "CRC-16/MODBUS", CodeLanguage::Verilog, CodeStyle::TableDriven);
+    assert!(verilog.contains("module crc16_modbus ("));
+    assert!(verilog.contains("crc <= 16'hFFFF;"));
+    assert_eq!(verilog.matches("assign next[").count(), 16);
+
+    let vhdl = codegen::generate(&params, "CRC-16/MODBUS", CodeLanguage::Vhdl, CodeStyle::Bitwise);
+    assert!(vhdl.contains("entity crc16_modbus is"));
+    assert!(vhdl.contains("crc <= \"1111111111111111\";"));
+    assert_eq!(vhdl.matches("next_crc(").count(), 16);
+}
+
+#[test]
+fn language_names_round_trip() {
+    for language in CodeLanguage::all() {
+        assert_eq!(CodeLanguage::from_name(language.name()), Some(language));
+    }
+    assert_eq!(CodeLanguage::from_name("vhdl"), Some(CodeLanguage::Vhdl));
+    assert!(CodeLanguage::Verilog.is_hdl());
+    assert!(!CodeLanguage::Python.is_hdl());
+}
+
+/// 생성 코드의 `0x...` 리터럴을 모두 읽습니다.
+fn hex_literals(text: &str) -> Vec<u64> {
+    text.split(|c: char| !c.is_ascii_alphanumeric())
+        .filter_map(|token| token.strip_prefix("0x"))
+        .map(|digits| u64::from_str_radix(digits, 16).unwrap())
+        .collect()
+}
+
+/// `start`로 시작하는 줄 다음부터 `end`로 시작하는 줄 전까지의 테이블 리터럴
+fn table_literal(code: &str, start: &str, end: &str) -> Vec<u64> {
+    let body: Vec<&str> = code
+        .lines()
+        .skip_while(|line| !line.starts_with(start))
+        .skip(1)
+        .take_while(|line| !line.starts_with(end))
+        .collect();
+    hex_literals(&body.join("\n"))
+}
+
+/// 생성된 C 테이블 구현을 그대로 따라 실행합니다. (타입, 초깃값, 테이블, 시프트, 반사, xorout을 코드에서 읽음)
+fn run_generated_c(code: &str, params: &CrcParams, data: &[u8]) -> u64 {
+    let line = |prefix: &str| code.lines().map(str::trim).find(|line| line.starts_with(prefix));
+    let table_line = line("static const uint").unwrap();
+    let bits: u32 = table_line["static const uint".len()..].split('_').next().unwrap().parse().unwrap();
+    let type_mask = u64::MAX >> (64 - bits);
+    let table = table_literal(code, "static const uint", "};");
+    assert_eq!(table.len(), 256);
+    let init = hex_literals(line(&format!("uint{}_t crc =", bits)).unwrap())[0];
+
+    let mut crc = init;
+    for &byte in data {
+        let byte = byte as u64;
+        crc = if bits == 8 {
+            table[(crc ^ byte) as usize]
+        } else if params.refin {
+            (crc >> 8) ^ table[((crc ^ byte) & 0xFF) as usize]
+        } else {
+            ((crc << 8) ^ table[(((crc >> (bits - 8)) ^ byte) & 0xFF) as usize]) & type_mask
+        };
+    }
+    finish_generated_c(code, params, crc)
+}
+
+/// 생성된 C 비트 단위 구현을 그대로 따라 실행합니다. (입력 시프트, 검사 비트, poly, 방향을 코드에서 읽음)
+fn run_generated_bitwise_c(code: &str, params: &CrcParams, data: &[u8]) -> u64 {
+    let line = |prefix: &str| code.lines().map(str::trim).find(|line| line.starts_with(prefix)).unwrap();
+    let init_line = code.lines().map(str::trim).find(|line| line.starts_with("uint") && line.contains("_t crc =")).unwrap();
+    let bits: u32 = init_line["uint".len()..].split('_').next().unwrap().parse().unwrap();
+    let type_mask = u64::MAX >> (64 - bits);
+    let init = hex_literals(init_line)[0];
+
+    // crc ^= *data++;  또는  crc ^= (uintN_t)*data++ << S;
+    let input_shift = line("crc ^= ").split_once("<< ").map_or(0, |(_, shift)| shift.trim_end_matches(';').parse().unwrap());
+    // crc = (crc & TEST) ? (crc >> 1) ^ POLY : crc >> 1;
+    let update = line("crc = (crc & ");
+    let test = update["crc = (crc & ".len()..].split(')').next().unwrap();
+    let test = test.strip_prefix("0x").map_or_else(|| test.parse().unwrap(), |digits| u64::from_str_radix(digits, 16).unwrap());
+    let poly = *hex_literals(update).last().unwrap();
+    let shift_right = update.contains("(crc >> 1)");
+
+    let mut crc = init;
+    for &byte in data {
+        crc ^= (byte as u64) << input_shift;
+        for _ in 0..8 {
+            let set = crc & test != 0;
+            crc = if shift_right { crc >> 1 } else { (crc << 1) & type_mask };
+            if set {
+                crc ^= poly;
+            }
+        }
+    }
+    finish_generated_c(code, params, crc)
+}
+
+/// 두 C 구현에 공통인 끝부분: 정렬 시프트, 출력 반사, xorout
+fn finish_generated_c(code: &str, params: &CrcParams, mut crc: u64) -> u64 {
+    let line = |prefix: &str| code.lines().map(str::trim).find(|line| line.starts_with(prefix));
+    if let Some(shift) = line("crc >>= ") {
+        crc >>= shift["crc >>= ".len()..].trim_end_matches(';').parse::<u32>().unwrap();
+    }
+    if code.lines().any(|line| line.trim().ends_with("_reflect(crc);")) {
+        crc = crc.reverse_bits() >> (64 - params.width as u32);
+    }
+    crc ^ hex_literals(line("return crc ^ ").unwrap())[0]
+}
+
+#[test]
+fn generated_tables_compute_check_values() {
+    for algorithm in CrcAlgorithm::all() {
+        let params = algorithm.params();
+        let name = codegen::code_name(&algorithm);
+        let c = codegen::generate(&params, &name, CodeLanguage::C, CodeStyle::TableDriven);
+        assert_eq!(run_generated_c(&c, &params, b"123456789"), params.check(), "{}", name);
+
+        // Rust/Python도 같은 테이블을 내보냄
+        let table = table_literal(&c, "static const uint", "};");
+        let rust = codegen::generate(&params, &name, CodeLanguage::Rust, CodeStyle::TableDriven);
+        assert_eq!(table_literal(&rust, "const ", "];"), table, "{}", name);
+        let python = codegen::generate(&params, &name, CodeLanguage::Python, CodeStyle::TableDriven);
+        assert_eq!(table_literal(&python, &codegen::identifier(&name).to_uppercase(), "]"), table, "{}", name);
+    }
+}
+
+#[test]
+fn generated_bitwise_code_computes_check_values() {
+    for algorithm in CrcAlgorithm::all() {
+        let params = algorithm.params();
+        let name = codegen::code_name(&algorithm);
+        let c = codegen::generate(&params, &name, CodeLanguage::C, CodeStyle::Bitwise);
+        assert!(!c.contains("table"), "{}", name);
+        assert_eq!(run_generated_bitwise_c(&c, &params, b"123456789"), params.check(), "{}", name);
+    }
+}
+
+/// HDL 식의 피연산자 (레지스터 비트 또는 입력 바이트 비트)
+enum Operand {
+    Crc(usize),
+    Data(usize),
+}
+
+fn parse_operands(expression: &str, separator: &str) -> Vec<Operand> {
+    expression
+        .split(separator)
+        .map(str::trim)
+        .filter(|operand| !matches!(*operand, "1'b0" | "'0'"))
+        .map(|operand| {
+            let index = |prefix: &str| operand[prefix.len()..operand.len() - 1].parse::<usize>().unwrap();
+            if operand.starts_with("crc") {
+                Operand::Crc(index("crc["))
+            } else {
+                Operand::Data(index("data_in["))
+            }
+        })
+        .collect()
+}
+
+/// 한 클럭에 한 바이트씩 `next` 식을 적용합니다.
+fn clock_bytes(equations: &[Vec<Operand>], init: u64, data: &[u8]) -> u64 {
+    data.iter().fold(init, |crc, &byte| {
+        equations.iter().enumerate().fold(0, |next, (bit, operands)| {
+            let parity = operands.iter().fold(0, |parity, operand| match operand {
+                Operand::Crc(i) => parity ^ (crc >> i & 1),
+                Operand::Data(i) => parity ^ (byte as u64 >> i & 1),
+            });
+            next | parity << bit
+        })
+    })
+}
+
+#[test]
+fn generated_hdl_computes_check_values() {
+    for algorithm in CrcAlgorithm::all() {
+        let params = algorithm.params();
+        let name = codegen::code_name(&algorithm);
+
+        let verilog = codegen::generate(&params, &name, CodeLanguage::Verilog, CodeStyle::TableDriven);
+        let lines: Vec<&str> = verilog.lines().map(str::trim).collect();
+        let equations: Vec<Vec<Operand>> = lines
+            .iter()
+            .filter_map(|line| line.strip_prefix("assign next["))
+            .map(|line| parse_operands(line.split_once("= ").unwrap().1.trim_end_matches(';'), "^"))
+            .collect();
+        assert_eq!(equations.len(), params.width as usize);
+        let literal = |line: &str| u64::from_str_radix(line.rsplit_once("'h").unwrap().1.trim_end_matches(';'), 16).unwrap();
+        let init = literal(lines.iter().find(|line| line.starts_with("crc <= ") && line.contains("'h")).unwrap());
+        let crc = clock_bytes(&equations, init, b"123456789");
+
+        // assign crc_out = {crc[msb], ..., crc[lsb]} ^ W'hXOR;
+        let output = lines.iter().find(|line| line.starts_with("assign crc_out")).unwrap();
+        let (concat, _) = output.split_once('}').unwrap();
+        let out_bits = parse_operands(concat.split_once('{').unwrap().1, ",");
+        let value = out_bits.iter().rev().enumerate().fold(0, |value, (bit, operand)| match operand {
+            Operand::Crc(i) => value | (crc >> i & 1) << bit,
+            Operand::Data(_) => unreachable!(),
+        });
+        assert_eq!(value ^ literal(output), params.check(), "Verilog {}", name);
+
+        let vhdl = codegen::generate(&params, &name, CodeLanguage::Vhdl, CodeStyle::TableDriven);
+        let lines: Vec<&str> = vhdl.lines().map(str::trim).collect();
+        let equations: Vec<Vec<Operand>> = lines
+            .iter()
+            .filter_map(|line| line.strip_prefix("next_crc("))
+            .map(|line| {
+                let expression = line.split_once("<= ").unwrap().1.trim_end_matches(';');
+                parse_operands(&expression.replace('(', "[").replace(')', "]"), "xor")
+            })
+            .collect();
+        let init_line = lines.iter().find(|line| line.starts_with("crc <= \"")).unwrap();
+        let init = u64::from_str_radix(init_line.trim_start_matches("crc <= \"").trim_end_matches("\";"), 2).unwrap();
+        let crc = clock_bytes(&equations, init, b"123456789");
+
+        // crc_out(i) <= crc(j) [xor '1'];
+        let value = lines.iter().filter_map(|line| line.strip_prefix("crc_out(")).fold(0, |value, line| {
+            let (bit, rest) = line.split_once(") <= crc(").unwrap();
+            let (source, rest) = rest.split_once(')').unwrap();
+            let inverted = rest.contains("xor '1'") as u64;
+            value | ((crc >> source.parse::<u32>().unwrap() & 1) ^ inverted) << bit.parse::<u32>().unwrap()
+        });
+        assert_eq!(value, params.check(), "VHDL {}", name);
+    }
+}
+
+proptest! {
+    #[test]
+    fn parallel_equations_match_bitwise_update(
+        algorithm_index in 0usize..CrcAlgorithm::all().len(),
+        register in any::<u64>(),
+        byte in any::<u8>(),
+    ) {
+        let params = CrcAlgorithm::all()[algorithm_index].params();
+        let register = register & params.mask();
+        // init=레지스터, 출력 반사/XOR 없이 한 바이트를 처리하면 다음 레지스터 값
+        let expected = CrcParams { init: register, refout: false, xorout: 0, ..params }.calculate(&[byte]);
+
+        let next = codegen::parallel_equations(&params)
+            .iter()
+            .enumerate()
+            .fold(0u64, |acc, (bit, term)| {
+                let parity = (register & term.crc_mask).count_ones() + (byte & term.data_mask).count_ones();
+                acc | ((parity as u64 & 1) << bit)
+            });
+        prop_assert_eq!(next, expected, "{}", params.describe());
+    }
+}
@@ -0,0 +1,85 @@
+use computools_core::crc::compare::{self, ComparisonSort, CrcComparison};
+use computools_core::crc::{CrcAlgorithm, Endianness, HexStyle, OutputMode, OutputOptions};
+use proptest::prelude::*;
+
+#[test]
+fn calculate_all_covers_whole_catalogue() {
+    let rows = compare::calculate_all(b"123456789", None);
+    assert_eq!(rows.len(), CrcAlgorithm::all().len());
+
+    let crc32 = rows
+        .iter()
+        .find(|row| row.algorithm == CrcAlgorithm::Crc32IsoHdlc)
+        .unwrap();
+    assert_eq!((crc32.crc, crc32.width), (0xCBF43926, 32));
+}
+
+#[test]
+fn calculate_all_filters_by_width() {
+    let rows = compare::calculate_all(b"123456789", Some(16));
+    assert!(!rows.is_empty());
+    assert!(rows.iter().all(|row| row.width == 16));
+    assert!(rows.iter().any(|row| row.algorithm == CrcAlgorithm::Crc16Modbus));
+
+    assert!(compare::calculate_all(b"123456789", Some(2)).is_empty());
+}
+
+#[test]
+fn catalogue_widths_are_sorted_and_unique() {
+    let widths = compare::catalogue_widths();
+    assert_eq!(widths.first(), Some(&3));
+    assert_eq!(widths.last(), Some(&64));
+    assert!(widths.windows(2).all(|pair| pair[0] < pair[1]));
+}
+
+#[test]
+fn sort_by_each_key() {
+    let mut rows = compare::calculate_all(b"123456789", None);
+
+    compare::sort_comparisons(&mut rows, ComparisonSort::Name, false);
+    assert!(rows.windows(2).all(|pair| pair[0].algorithm.name() <= pair[1].algorithm.name()));
+
+    compare::sort_comparisons(&mut rows, ComparisonSort::Width, true);
+    assert!(rows.windows(2).all(|pair| pair[0].width >= pair[1].width));
+
+    compare::sort_comparisons(&mut rows, ComparisonSort::Value, false);
+    assert!(rows.windows(2).all(|pair| pair[0].crc <= pair[1].crc));
+}
+
+#[test]
+fn query_matches_name_or_displayed_value() {
+    let row = CrcComparison {
+        algorithm: CrcAlgorithm::Crc32IsoHdlc,
+        crc: 0xCBF43926,
+        width: 32,
+    };
+    let options = OutputOptions::default();
+
+    assert!(compare::matches_query(&row, "", &options));
+    assert!(compare::matches_query(&row, "iso-hdlc", &options));
+    assert!(compare::matches_query(&row, "cbf4 3926", &options));
+    assert!(compare::matches_query(&row, "0xCBF43926", &options));
+    assert!(!compare::matches_query(&row, "2639f4cb", &options));
+
+    let little_endian = OutputOptions {
+        endianness: Endianness::LittleEndian,
+        hex_style: HexStyle::NoPrefix,
+        ..OutputOptions::default()
+    };
+    assert!(compare::matches_query(&row, "2639F4CB", &little_endian));
+
+    let decimal = OutputOptions {
+        output_mode: OutputMode::Decimal,
+        ..OutputOptions::default()
+    };
+    assert!(compare::matches_query(&row, "3421780262", &decimal));
+}
+
+proptest! {
+    #[test]
+    fn rows_agree_with_single_calculation(data in prop::collection::vec(any::<u8>(), 0..64)) {
+        for row in compare::calculate_all(&data, None) {
+            prop_assert_eq!((row.crc, row.width), row.algorithm.calculate(&data));
+        }
+    }
+}
@@ -0,0 +1,77 @@
+use computools_core::crc::forcing::{self, PatchPosition};
+use computools_core::crc::{CrcAlgorithm, CrcParams};
+use proptest::prelude::*;
+
+#[test]
+fn append_reaches_target() {
+    let forced = forcing::force(&CrcAlgorithm::Crc32IsoHdlc, b"firmware image", 0xDEADBEEF, PatchPosition::Append).unwrap();
+    assert_eq!(forced.offset, 14);
+    assert_eq!(forced.patch.len(), 4);
+    assert_eq!(&forced.data[..14], b"firmware image");
+    assert_eq!(&forced.data[14..], forced.patch.as_slice());
+    assert_eq!(CrcAlgorithm::Crc32IsoHdlc.calculate(&forced.data), (0xDEADBEEF, 32));
+}
+
+#[test]
+fn overwrite_keeps_surrounding_bytes() {
+    let data = b"header....payload";
+    let forced = forcing::force(&CrcAlgorithm::Crc16Modbus, data, 0x1234, PatchPosition::Overwrite(6)).unwrap();
+    assert_eq!(forced.data.len(), data.len());
+    assert_eq!(&forced.data[..6], b"header");
+    assert_eq!(&forced.data[8..], b"..payload");
+    assert_eq!(&forced.data[6..8], forced.patch.as_slice());
+    assert_eq!(CrcAlgorithm::Crc16Modbus.calculate(&forced.data).0, 0x1234);
+}
+
+#[test]
+fn forcing_rejects_bad_input() {
+    let algorithm = CrcAlgorithm::Crc16Modbus;
+    assert!(forcing::force(&algorithm, b"abc", 0x10000, PatchPosition::Append).is_err());
+    assert!(forcing::force(&algorithm, b"abc", 0x1234, PatchPosition::Overwrite(2)).is_err());
+    assert!(forcing::force(&algorithm, b"abc", 0x1234, PatchPosition::Overwrite(usize::MAX)).is_err());
+
+    // 상수항이 없는 다항식은 마지막 비트가 CRC에 영향을 주지 못함
+    let even_poly = CrcAlgorithm::Custom(CrcParams {
+        width: 8,
+        poly: 0x06,
+        init: 0,
+        refin: false,
+        refout: false,
+        xorout: 0,
+    });
+    assert!(forcing::force(&even_poly, b"abc", 0x01, PatchPosition::Append).is_err());
+}
+
+#[test]
+fn patch_len_rounds_up_to_bytes() {
+    assert_eq!(forcing::patch_len(3), 1);
+    assert_eq!(forcing::patch_len(16), 2);
+    assert_eq!(forcing::patch_len(17), 3);
+    assert_eq!(forcing::patch_len(64), 8);
+}
+
+proptest! {
+    #[test]
+    fn every_catalogue_algorithm_can_be_forced(
+        data in prop::collection::vec(any::<u8>(), 0..32),
+        target in any::<u64>(),
+        offset in any::<prop::sample::Index>(),
+    ) {
+        for algorithm in CrcAlgorithm::all() {
+            let params = algorithm.params();
+            let target = target & params.mask();
+
+            let forced = forcing::force(&algorithm, &data, target, PatchPosition::Append).unwrap();
+            prop_assert_eq!(algorithm.calculate(&forced.data).0, target);
+
+            let len = forcing::patch_len(params.width);
+            if data.len() >= len {
+                let offset = offset.index(data.len() - len + 1);
+                let forced = forcing::force(&algorithm, &data, target, PatchPosition::Overwrite(offset)).unwrap();
+                prop_assert_eq!(algorithm.calculate(&forced.data).0, target);
+                prop_assert_eq!(&forced.data[..offset], &data[..offset]);
+                prop_assert_eq!(&forced.data[offset + len..], &data[offset + len..]);
+            }
+        }
+    }
+}
@@ -0,0 +1,116 @@
+use computools_core::crc::reveng::{self, CrcMatch, CrcSample};
+use computools_core::crc::{apply_endianness, CrcAlgorithm, CrcParams, Endianness, InputMode};
+
+const MESSAGES: &[&[u8]] = &[b"123456789", b"hello world", b"\x01\x03\x00\x00\x00\x01", b"abcdefghijk"];
+
+fn samples_for(params: CrcParams, endianness: &Endianness) -> Vec<CrcSample> {
+    let bytes = params.width.div_ceil(8) as usize;
+    MESSAGES
+        .iter()
+        .map(|data| {
+            let value = apply_endianness(params.calculate(data), params.width, endianness);
+            CrcSample {
+                data: data.to_vec(),
+                crc: value.to_be_bytes()[8 - bytes..].to_vec(),
+            }
+        })
+        .collect()
+}
+
+#[test]
+fn parse_samples_splits_data_and_trailing_crc() {
+    let samples = reveng::parse_samples("01 03 00 00 00 01 840A\n\nhello world 0x1234\n", &InputMode::Hex);
+    assert!(samples.is_err(), "'hello world' is not hex");
+
+    let samples = reveng::parse_samples("01 03 00 00 00 01 840A\n01 06 00 01 00 03 980B", &InputMode::Hex).unwrap();
+    assert_eq!(samples.len(), 2);
+    assert_eq!(samples[0].data, vec![0x01, 0x03, 0x00, 0x00, 0x00, 0x01]);
+    assert_eq!(samples[0].crc, vec![0x84, 0x0A]);
+
+    let samples = reveng::parse_samples("hello world 0x1234", &InputMode::Ascii).unwrap();
+    assert_eq!(samples[0].data, b"hello world");
+
+    let err = reveng::parse_samples("00 11\nnocrc", &InputMode::Hex).unwrap_err();
+    assert!(err.starts_with("Line 2:"), "{}", err);
+}
+
+#[test]
+fn catalogue_search_finds_modbus_little_endian() {
+    let samples = reveng::parse_samples("01 03 00 00 00 01 840A\n01 06 00 01 00 03 980B", &InputMode::Hex).unwrap();
+    let matches = reveng::search_catalogue(&samples);
+    assert!(matches.contains(&CrcMatch {
+        algorithm: CrcAlgorithm::Crc16Modbus,
+        endianness: Endianness::LittleEndian,
+    }));
+}
+
+#[test]
+fn catalogue_search_requires_consistent_samples() {
+    let mut samples = samples_for(CrcAlgorithm::Crc32IsoHdlc.params(), &Endianness::BigEndian);
+    assert!(reveng::search_catalogue(&samples)
+        .iter()
+        .any(|m| m.algorithm == CrcAlgorithm::Crc32IsoHdlc && m.endianness == Endianness::BigEndian));
+
+    samples[1].crc[0] ^= 0xFF;
+    assert!(reveng::search_catalogue(&samples).is_empty());
+    assert!(reveng::search_catalogue(&[]).is_empty());
+}
+
+#[test]
+fn brute_force_recovers_catalogue_algorithm() {
+    let samples = samples_for(CrcAlgorithm::Crc8MaximDow.params(), &Endianness::BigEndian);
+    let matches = reveng::brute_force(&samples, 8).unwrap();
+    assert!(matches.contains(&CrcMatch {
+        algorithm: CrcAlgorithm::Crc8MaximDow,
+        endianness: Endianness::BigEndian,
+    }));
+
+    // 폭이 작으면 init/xorout이 다른 해가 더 나올 수 있지만 모두 샘플을 만족해야 함
+    for found in matches {
+        let params = found.algorithm.params();
+        for sample in &samples {
+            assert_eq!(params.calculate(&sample.data), sample.crc[0] as u64);
+        }
+    }
+}
+
+#[test]
+fn brute_force_recovers_custom_params() {
+    let params = CrcParams {
+        width: 12,
+        poly: 0x80F,
+        init: 0x123,
+        refin: true,
+        refout: true,
+        xorout: 0x555,
+    };
+    let samples = samples_for(params, &Endianness::LittleEndian);
+    let matches = reveng::brute_force(&samples, 12).unwrap();
+    assert!(matches.contains(&CrcMatch {
+        algorithm: CrcAlgorithm::Custom(params),
+        endianness: Endianness::LittleEndian,
+    }));
+}
+
+#[test]
+fn brute_force_rejects_unusable_input() {
+    let samples = samples_for(CrcAlgorithm::Crc16Modbus.params(), &Endianness::BigEndian);
+    assert!(reveng::brute_force(&samples, 17).is_err());
+    assert!(reveng::brute_force(&samples, 8).is_err(), "CRC length does not match width");
+
+    // 길이가 모두 다른 샘플로는 다항식을 분리할 수 없음
+    let distinct_lengths: Vec<CrcSample> = samples.into_iter().take(2).collect();
+    assert!(reveng::brute_force(&distinct_lengths, 16).is_err());
+}
+
+#[test]
+fn describe_uses_catalogue_format() {
+    assert_eq!(
+        CrcAlgorithm::Crc16Modbus.params().describe(),
+        "width=16 poly=0x8005 init=0xffff refin=true refout=true xorout=0x0000 check=0x4b37"
+    );
+    assert_eq!(
+        reveng::catalogue_or_custom(CrcAlgorithm::Crc16Modbus.params()),
+        CrcAlgorithm::Crc16Modbus
+    );
+}
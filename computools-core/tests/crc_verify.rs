@@ -0,0 +1,89 @@
+use computools_core::crc::verify;
+use computools_core::crc::{CrcAlgorithm, CrcEngine, CrcParams, Endianness};
+use proptest::prelude::*;
+
+fn catalogue_residue(algorithm: &CrcAlgorithm) -> u64 {
+    match algorithm.engine() {
+        CrcEngine::U8(crc) => crc.algorithm.residue as u64,
+        CrcEngine::U16(crc) => crc.algorithm.residue as u64,
+        CrcEngine::U32(crc) => crc.algorithm.residue as u64,
+        CrcEngine::U64(crc) => crc.algorithm.residue,
+        CrcEngine::Bitwise(_) => unreachable!("catalogue algorithms are table-driven"),
+    }
+}
+
+#[test]
+fn residue_matches_catalogue() {
+    for algorithm in CrcAlgorithm::all() {
+        assert_eq!(algorithm.params().residue(), catalogue_residue(&algorithm), "{}", algorithm.name());
+    }
+}
+
+#[test]
+fn modbus_frame_passes() {
+    let frame = [0x01, 0x03, 0x00, 0x00, 0x00, 0x01, 0x84, 0x0A];
+    let result = verify::verify(&CrcAlgorithm::Crc16Modbus, &frame, &Endianness::LittleEndian).unwrap();
+    assert!(result.matches());
+    assert_eq!(result.data_len, 6);
+    assert_eq!((result.expected, result.actual, result.width), (0x0A84, 0x0A84, 16));
+    assert_eq!(result.residue_ok, Some(true));
+
+    // 바이트 순서가 반대면 값 비교는 실패하고 residue는 판단하지 않음
+    let result = verify::verify(&CrcAlgorithm::Crc16Modbus, &frame, &Endianness::BigEndian).unwrap();
+    assert!(!result.matches());
+    assert_eq!(result.expected, 0x840A);
+    assert_eq!(result.residue_ok, None);
+}
+
+#[test]
+fn corrupted_frame_fails() {
+    let frame = [0x01, 0x03, 0x00, 0x00, 0x00, 0x02, 0x84, 0x0A];
+    let result = verify::verify(&CrcAlgorithm::Crc16Modbus, &frame, &Endianness::LittleEndian).unwrap();
+    assert!(!result.matches());
+    assert_eq!(result.residue_ok, Some(false));
+}
+
+#[test]
+fn residue_not_applicable_for_odd_widths() {
+    let params = CrcParams {
+        width: 12,
+        poly: 0x80F,
+        init: 0,
+        refin: false,
+        refout: false,
+        xorout: 0,
+    };
+    let frame = [0x31, 0x32, 0x33, 0x0F, 0x5B];
+    let result = verify::verify(&CrcAlgorithm::Custom(params), &frame, &Endianness::BigEndian).unwrap();
+    assert_eq!(result.actual, params.calculate(b"123"));
+    assert_eq!(result.residue_ok, None);
+}
+
+#[test]
+fn short_frame_is_rejected() {
+    assert!(verify::verify(&CrcAlgorithm::Crc32IsoHdlc, &[0x01, 0x02, 0x03], &Endianness::BigEndian).is_err());
+    // CRC만 있고 데이터가 없는 프레임은 빈 메시지로 검증
+    let crc = CrcAlgorithm::Crc32IsoHdlc.calculate(b"").0 as u32;
+    let result = verify::verify(&CrcAlgorithm::Crc32IsoHdlc, &crc.to_le_bytes(), &Endianness::LittleEndian).unwrap();
+    assert!(result.matches());
+}
+
+proptest! {
+    #[test]
+    fn appended_crc_verifies(data in prop::collection::vec(any::<u8>(), 0..32)) {
+        for algorithm in CrcAlgorithm::all() {
+            let params = algorithm.params();
+            let crc_len = params.width.div_ceil(8) as usize;
+            let (crc, _) = algorithm.calculate(&data);
+            for endianness in [Endianness::BigEndian, Endianness::LittleEndian] {
+                let mut frame = data.clone();
+                let value = computools_core::crc::apply_endianness(crc, params.width, &endianness);
+                frame.extend_from_slice(&value.to_be_bytes()[8 - crc_len..]);
+
+                let result = verify::verify(&algorithm, &frame, &endianness).unwrap();
+                prop_assert!(result.matches(), "{}", algorithm.name());
+                prop_assert_ne!(result.residue_ok, Some(false), "{}", algorithm.name());
+            }
+        }
+    }
+}
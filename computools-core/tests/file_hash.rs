@@ -0,0 +1,230 @@
+use computools_core::bytes::InputEncoding;
+use computools_core::file_hash::{self, FileHasher, HashAlgorithm, HashResults, OutputFormat};
+use proptest::prelude::*;
+
+const ABC_MD5: &str = "900150983cd24fb0d6963f7d28e17f72";
+const ABC_SHA1: &str = "a9993e364706816aba3e25717850c26c9cd0d89d";
+const ABC_SHA256: &str = "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad";
+
+fn abc_results() -> HashResults {
+    file_hash::compute_hashes(b"abc", &HashAlgorithm::all(), None)
+}
+
+#[test]
+fn compute_hashes_known_vectors() {
+    let results = abc_results();
+    assert_eq!(results.get(HashAlgorithm::Md5), Some(ABC_MD5));
+    assert_eq!(results.get(HashAlgorithm::Sha1), Some(ABC_SHA1));
+    assert_eq!(results.get(HashAlgorithm::Sha256), Some(ABC_SHA256));
+    assert!(results.get(HashAlgorithm::Sha512).unwrap().starts_with("ddaf35a193617aba"));
+    assert_eq!(results.get(HashAlgorithm::Crc32), Some("352441c2"));
+    assert!(results.hmacs.is_empty());
+
+    let only_md5 = file_hash::compute_hashes(b"abc", &[HashAlgorithm::Md5], None);
+    assert_eq!(only_md5.get(HashAlgorithm::Md5), Some(ABC_MD5));
+    assert_eq!(only_md5.get(HashAlgorithm::Sha1), None);
+    assert_eq!(only_md5.get(HashAlgorithm::Crc32), None);
+}
+
+#[test]
+fn compute_hashes_extended_algorithms() {
+    let results = abc_results();
+    let get = |algorithm| results.get(algorithm).unwrap();
+    assert_eq!(get(HashAlgorithm::Sha224), "23097d223405d8228642a477bda255b32aadbce4bda0b3f7e36c9da7");
+    assert_eq!(
+        get(HashAlgorithm::Sha384),
+        "cb00753f45a35e8bb5a03d699ac65007272c32ab0eded1631a8b605a43ff5bed8086072ba1e7cc2358baeca134c825a7"
+    );
+    assert_eq!(get(HashAlgorithm::Sha512_256), "53048e2681941ef99b2e29b76b4c7dabe4c2d0c634fc6d46e0e2f13107e7af23");
+    assert_eq!(get(HashAlgorithm::Sha3_256), "3a985da74fe225b2045c172d6bd390bd855f086e3e9d525b46bfe24511431532");
+    assert!(get(HashAlgorithm::Sha3_512).starts_with("b751850b1a57168a"));
+    assert!(get(HashAlgorithm::Blake2b).starts_with("ba80a53f981c4d0d"));
+    assert_eq!(get(HashAlgorithm::Blake3), "6437b3ac38465133ffb63b75273a8db548c558465d79db03fd359c6cd5bd9d85");
+    assert_eq!(get(HashAlgorithm::Ripemd160), "8eb208f7e05d987a9b044a8e98c6b087f15a0bfc");
+}
+
+#[test]
+fn registry_keys_and_lengths_are_consistent() {
+    let results = abc_results();
+    for algorithm in HashAlgorithm::all() {
+        assert_eq!(HashAlgorithm::from_key(algorithm.key()), Some(algorithm));
+        assert_eq!(results.get(algorithm).unwrap().len(), algorithm.output_len() * 2, "{}", algorithm.name());
+    }
+    assert_eq!(HashAlgorithm::from_key("unknown"), None);
+    assert_eq!(HashAlgorithm::Sha256.hmac_name(), "HMAC-SHA256");
+    assert_eq!(HashAlgorithm::Sha3_256.hmac_name(), "HMAC-SHA3-256");
+}
+
+#[test]
+fn chunked_updates_match_single_pass() {
+    let data: Vec<u8> = (0..10_000u32).map(|i| (i * 31 % 251) as u8).collect();
+    let expected = file_hash::compute_hashes(&data, &HashAlgorithm::all(), Some(b"key"));
+
+    let mut hasher = FileHasher::new(&HashAlgorithm::all(), Some(b"key"));
+    for chunk in data.chunks(777) {
+        hasher.update(chunk);
+    }
+    assert_eq!(hasher.finalize(), expected);
+
+    // BLAKE3와 CRC32는 HMAC을 지원하지 않음
+    assert_eq!(expected.hmacs.len(), HashAlgorithm::all().len() - 2);
+    assert_eq!(expected.hmac(HashAlgorithm::Blake3), None);
+}
+
+#[test]
+fn compare_hash_guesses_by_length_among_selected() {
+    // SHA-256이 없으면 같은 길이의 다른 선택된 알고리즘과 비교
+    let results = file_hash::compute_hashes(b"abc", &[HashAlgorithm::Sha3_256], None);
+    let mismatch = file_hash::compare_hash(&"0".repeat(64), &results).unwrap();
+    assert!(!mismatch.matches);
+    assert_eq!(mismatch.algorithm, "SHA3-256");
+
+    let results = abc_results();
+    let comparison = file_hash::compare_hash(results.get(HashAlgorithm::Blake3).unwrap(), &results).unwrap();
+    assert!(comparison.matches);
+    assert_eq!(comparison.algorithm, "BLAKE3");
+}
+
+#[test]
+fn hmac_rfc4231_test_case_2() {
+    let key = b"Jefe";
+    let data = b"what do ya want for nothing?";
+    assert_eq!(
+        file_hash::calculate_hmac(HashAlgorithm::Sha256, key, data),
+        Ok("5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843".to_string())
+    );
+    assert!(file_hash::calculate_hmac(HashAlgorithm::Crc32, key, data).is_err());
+
+    let results = file_hash::compute_hashes(data, &[HashAlgorithm::Sha256], Some(key));
+    assert_eq!(
+        results.hmac(HashAlgorithm::Sha256),
+        Some("5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843")
+    );
+    assert_eq!(results.hmac(HashAlgorithm::Md5), None);
+}
+
+#[test]
+fn normalize_hash_input_formats() {
+    assert_eq!(file_hash::normalize_hash_input(" ABCDEF01 "), Ok("abcdef01".to_string()));
+    assert_eq!(file_hash::normalize_hash_input("ab:cd:ef:01"), Ok("abcdef01".to_string()));
+    assert_eq!(file_hash::normalize_hash_input("{0xab, 0xCD, 0xef, 0x01}"), Ok("abcdef01".to_string()));
+    assert_eq!(file_hash::normalize_hash_input("q83vAQ=="), Ok("abcdef01".to_string()));
+
+    assert!(file_hash::normalize_hash_input("").is_err());
+    assert!(file_hash::normalize_hash_input("zz:yy").is_err());
+    assert!(file_hash::normalize_hash_input("{ab, cd}").is_err());
+    assert!(file_hash::normalize_hash_input("not a hash!").is_err());
+}
+
+#[test]
+fn normalize_hash_input_prefers_hex_over_base64() {
+    // 모두 올바른 Base64이기도 한 hex 해시 (길이가 4의 배수)
+    assert_eq!(file_hash::normalize_hash_input(ABC_MD5), Ok(ABC_MD5.to_string()));
+    assert_eq!(file_hash::normalize_hash_input("DEADBEEF"), Ok("deadbeef".to_string()));
+    let sha256 = "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad";
+    assert_eq!(file_hash::normalize_hash_input(sha256), Ok(sha256.to_string()));
+
+    // hex가 아닌 글자가 섞였거나 패딩이 있으면 Base64
+    assert_eq!(file_hash::normalize_hash_input("kAFQmDzST7DWlj99KOF/cg=="), Ok(ABC_MD5.to_string()));
+    assert_eq!(file_hash::normalize_hash_input("q83vAQ=="), Ok("abcdef01".to_string()));
+    // 홀수 길이의 hex는 Base64 길이 조건에 맞지 않으므로 그대로 hex
+    assert_eq!(file_hash::normalize_hash_input("abc"), Ok("abc".to_string()));
+}
+
+#[test]
+fn format_hash_output_variants() {
+    assert_eq!(file_hash::format_hash_output("abcdef01", &OutputFormat::Uppercase), "ABCDEF01");
+    assert_eq!(file_hash::format_hash_output("abcdef01", &OutputFormat::ColonSeparated), "ab:cd:ef:01");
+    assert_eq!(file_hash::format_hash_output("abcdef01", &OutputFormat::Base64), "q83vAQ==");
+    assert_eq!(file_hash::format_hash_output("abcdef01", &OutputFormat::CStyleArray), "{0xab, 0xcd, 0xef, 0x01}");
+    assert_eq!(file_hash::format_hash_output("", &OutputFormat::Uppercase), "");
+}
+
+#[test]
+fn compare_hash_finds_matching_algorithm() {
+    let results = abc_results();
+    let comparison = file_hash::compare_hash(ABC_SHA1, &results).unwrap();
+    assert!(comparison.matches);
+    assert_eq!(comparison.algorithm, "SHA-1");
+
+    let mismatch = file_hash::compare_hash(&"0".repeat(64), &results).unwrap();
+    assert!(!mismatch.matches);
+    assert_eq!(mismatch.algorithm, "SHA-256");
+
+    assert_eq!(file_hash::compare_hash("  ", &results), None);
+    assert_eq!(file_hash::compare_hash("not a hash!", &results).unwrap().algorithm, "Format Error");
+}
+
+#[test]
+fn compare_hmac_finds_matching_algorithm() {
+    let algorithms = HashAlgorithm::all();
+    let expected = file_hash::calculate_hmac(HashAlgorithm::Sha1, b"key", b"data").unwrap();
+    let comparison = file_hash::compare_hmac(&expected.to_uppercase(), b"key", b"data", &algorithms).unwrap();
+    assert!(comparison.matches);
+    assert_eq!(comparison.algorithm, "HMAC-SHA1");
+
+    let mismatch = file_hash::compare_hmac(&expected, b"other", b"data", &algorithms).unwrap();
+    assert!(!mismatch.matches);
+    assert_eq!(mismatch.algorithm, "HMAC-SHA1");
+
+    // HMAC을 지원하는 알고리즘이 선택되지 않으면 비교할 수 없음
+    assert_eq!(file_hash::compare_hmac(&expected, b"key", b"data", &[HashAlgorithm::Crc32]), None);
+}
+
+#[test]
+fn hmac_only_hasher_matches_calculate_hmac() {
+    let mut hasher = FileHasher::hmac_only(&HashAlgorithm::all(), b"key");
+    hasher.update(b"da");
+    hasher.update(b"ta");
+    let results = hasher.finalize();
+    assert!(results.hashes.is_empty());
+    for (algorithm, hmac) in &results.hmacs {
+        assert_eq!(*hmac, file_hash::calculate_hmac(*algorithm, b"key", b"data").unwrap());
+    }
+
+    let expected = file_hash::calculate_hmac(HashAlgorithm::Sha256, b"key", b"data").unwrap();
+    let comparison = file_hash::compare_hmac_results(&expected, &results).unwrap();
+    assert!(comparison.matches);
+    assert_eq!(comparison.algorithm, "HMAC-SHA256");
+}
+
+#[test]
+fn file_size_formatting() {
+    assert_eq!(file_hash::format_file_size(0), "0 B");
+    assert_eq!(file_hash::format_file_size(1536), "1.50 KB");
+    assert_eq!(file_hash::format_file_size(3 * 1024 * 1024 * 1024), "3.00 GB");
+}
+
+proptest! {
+    #[test]
+    fn every_output_format_normalizes_back(bytes in prop::collection::vec(any::<u8>(), 4..64)) {
+        let hash = hex_string(&bytes);
+        for format in [OutputFormat::Lowercase, OutputFormat::Uppercase, OutputFormat::ColonSeparated, OutputFormat::Base64, OutputFormat::CStyleArray] {
+            let formatted = file_hash::format_hash_output(&hash, &format);
+            prop_assert_eq!(file_hash::normalize_hash_input(&formatted), Ok(hash.clone()), "{:?}", format);
+        }
+    }
+
+    #[test]
+    fn computed_hashes_verify_against_themselves(data in prop::collection::vec(any::<u8>(), 0..256)) {
+        let results = file_hash::compute_hashes(&data, &HashAlgorithm::all(), None);
+        for (_, hash) in &results.hashes {
+            let comparison = file_hash::compare_hash(hash, &results).unwrap();
+            prop_assert!(comparison.matches);
+        }
+    }
+}
+
+fn hex_string(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+#[test]
+fn hmac_keys_in_any_encoding() {
+    // 같은 키를 어떤 인코딩으로 입력해도 HMAC 결과는 같음
+    let key = InputEncoding::Hex.decode("6b6579").unwrap();
+    assert_eq!(
+        file_hash::calculate_hmac(HashAlgorithm::Sha256, &key, b"data").unwrap(),
+        file_hash::calculate_hmac(HashAlgorithm::Sha256, b"key", b"data").unwrap()
+    );
+}
@@ -0,0 +1,280 @@
+use computools_core::file_hash::manifest::{self, CheckStatus, FileMatch, HashedFile, ManifestFormat, ManifestStyle};
+use computools_core::file_hash::{self, HashAlgorithm};
+use proptest::prelude::*;
+
+const ABC_SHA256: &str = "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad";
+const ABC_MD5: &str = "900150983cd24fb0d6963f7d28e17f72";
+
+#[test]
+fn parses_gnu_lines() {
+    let text = format!("{}  abc.txt\n{} *dist/abc.bin\n\n# comment\n", ABC_SHA256, ABC_MD5);
+    let parsed = manifest::parse(&text, Some("SHA256SUMS"));
+    assert!(parsed.malformed_lines.is_empty());
+    assert_eq!(parsed.entries.len(), 2);
+
+    let first = &parsed.entries[0];
+    assert_eq!((first.line, first.format), (1, ManifestFormat::Gnu));
+    assert_eq!((first.file_name.as_str(), first.algorithm), ("abc.txt", HashAlgorithm::Sha256));
+    assert_eq!(first.expected, ABC_SHA256);
+    assert_eq!(parsed.entries[1].file_name, "dist/abc.bin");
+    assert_eq!(parsed.entries[1].algorithm, HashAlgorithm::Md5);
+}
+
+#[test]
+fn gnu_escaped_names_are_unescaped() {
+    let text = format!("\\{}  new\\nline\\\\name", ABC_MD5);
+    let parsed = manifest::parse(&text, None);
+    assert_eq!(parsed.entries[0].file_name, "new\nline\\name");
+}
+
+#[test]
+fn manifest_name_resolves_ambiguous_lengths() {
+    let text = format!("{}  abc.txt", ABC_SHA256);
+    assert_eq!(manifest::parse(&text, None).entries[0].algorithm, HashAlgorithm::Sha256);
+    assert_eq!(manifest::parse(&text, Some("SHA3-256SUMS")).entries[0].algorithm, HashAlgorithm::Sha3_256);
+    assert_eq!(manifest::parse(&text, Some("release.b3")).entries[0].algorithm, HashAlgorithm::Blake3);
+    // 길이가 맞지 않는 힌트는 무시
+    assert_eq!(manifest::parse(&text, Some("MD5SUMS")).entries[0].algorithm, HashAlgorithm::Sha256);
+
+    assert_eq!(manifest::algorithm_from_manifest_name("SHA512SUMS"), Some(HashAlgorithm::Sha512));
+    assert_eq!(manifest::algorithm_from_manifest_name("files.sha512_256"), Some(HashAlgorithm::Sha512_256));
+    assert_eq!(manifest::algorithm_from_manifest_name("B2SUMS"), Some(HashAlgorithm::Blake2b));
+    assert_eq!(manifest::algorithm_from_manifest_name("notes.txt"), None);
+}
+
+#[test]
+fn parses_bsd_and_openssl_lines() {
+    let text = format!(
+        "SHA256 (abc.txt) = {}\nMD5(my file (1).txt)= {}\nSHA2-256(x.bin)= {}\nSHA512t256 (y) = 53048e2681941ef99b2e29b76b4c7dabe4c2d0c634fc6d46e0e2f13107e7af23",
+        ABC_SHA256,
+        ABC_MD5.to_uppercase(),
+        ABC_SHA256
+    );
+    let parsed = manifest::parse(&text, None);
+    assert!(parsed.malformed_lines.is_empty());
+    let entries: Vec<(&str, HashAlgorithm)> = parsed
+        .entries
+        .iter()
+        .map(|entry| (entry.file_name.as_str(), entry.algorithm))
+        .collect();
+    assert_eq!(
+        entries,
+        [
+            ("abc.txt", HashAlgorithm::Sha256),
+            ("my file (1).txt", HashAlgorithm::Md5),
+            ("x.bin", HashAlgorithm::Sha256),
+            ("y", HashAlgorithm::Sha512_256),
+        ]
+    );
+    assert!(parsed.entries.iter().all(|entry| entry.format == ManifestFormat::Bsd));
+    assert_eq!(parsed.entries[1].expected, ABC_MD5);
+}
+
+#[test]
+fn parses_bsd_lines_with_padded_base64_hashes() {
+    // OpenBSD `sha256 -b` 출력
+    let parsed = manifest::parse("SHA256 (a.txt) = ungWv48Bz+pBQUDeXa4iI7ADYaOWF3qctBD/YfIAFa0=\nMD5 (b (1).txt) = kAFQmDzST7DWlj99KOF/cg==", None);
+    assert!(parsed.malformed_lines.is_empty());
+    assert_eq!(parsed.entries.len(), 2);
+    assert_eq!((parsed.entries[0].file_name.as_str(), parsed.entries[0].expected.as_str()), ("a.txt", ABC_SHA256));
+    assert_eq!((parsed.entries[1].file_name.as_str(), parsed.entries[1].expected.as_str()), ("b (1).txt", ABC_MD5));
+}
+
+#[test]
+fn parses_sfv_and_reports_malformed_lines() {
+    let text = "; Generated by QuickSFV\r\nabc.txt 352441C2\r\nwith space.txt\t352441c2\r\nnot a checksum line\r\nSHA256 (short) = abcd\r\n";
+    let parsed = manifest::parse(text, Some("release.sfv"));
+    assert_eq!(parsed.entries.len(), 2);
+    assert!(parsed.entries.iter().all(|entry| entry.format == ManifestFormat::Sfv));
+    assert_eq!(parsed.entries[1].file_name, "with space.txt");
+    assert_eq!(parsed.entries[0].expected, "352441c2");
+    assert_eq!(parsed.malformed_lines, [4, 5]);
+}
+
+#[test]
+fn check_reports_ok_failed_missing() {
+    let text = format!(
+        "{}  abc.txt\n{}  dist/other.txt\n{}  gone.txt\nabc.txt 352441C2\n",
+        ABC_SHA256, ABC_SHA256, ABC_SHA256
+    );
+    let parsed = manifest::parse(&text, None);
+    let names = ["abc.txt", "other.txt"];
+    assert_eq!(parsed.algorithms_for(&names, 0), [HashAlgorithm::Sha256, HashAlgorithm::Crc32]);
+
+    let files: Vec<(String, _)> = names
+        .iter()
+        .enumerate()
+        .map(|(index, name)| {
+            let data: &[u8] = if *name == "abc.txt" { b"abc" } else { b"abd" };
+            (name.to_string(), file_hash::compute_hashes(data, &parsed.algorithms_for(&names, index), None))
+        })
+        .collect();
+    let checks = manifest::check_all(&parsed, &files);
+    let statuses: Vec<CheckStatus> = checks.iter().map(|check| check.status).collect();
+    assert_eq!(statuses, [CheckStatus::Ok, CheckStatus::Failed, CheckStatus::Missing, CheckStatus::Ok]);
+    assert_eq!(checks[2].actual, None);
+    assert_eq!(CheckStatus::Missing.label(), "MISSING");
+
+    let summary = manifest::summarize(&checks);
+    assert_eq!((summary.ok, summary.failed, summary.missing), (2, 1, 1));
+    assert!(!summary.all_ok());
+}
+
+#[test]
+fn same_base_name_in_different_directories() {
+    let sha256 = |data: &[u8]| file_hash::compute_hashes(data, &[HashAlgorithm::Sha256], None);
+    let text = format!(
+        "{}  sub1/f\n{}  ./sub2/f\n",
+        sha256(b"one").get(HashAlgorithm::Sha256).unwrap(),
+        sha256(b"two").get(HashAlgorithm::Sha256).unwrap()
+    );
+    let parsed = manifest::parse(&text, Some("SHA256SUMS"));
+
+    // 경로가 정확히 같은 파일과 비교
+    let names = ["sub1/f", "sub2/f"];
+    assert_eq!(manifest::find_file("sub2/f", &names), FileMatch::Found(1));
+    assert_eq!(parsed.algorithms_for(&names, 1), [HashAlgorithm::Sha256]);
+    let files = vec![(names[0].to_string(), sha256(b"one")), (names[1].to_string(), sha256(b"two"))];
+    let statuses: Vec<CheckStatus> = manifest::check_all(&parsed, &files).iter().map(|check| check.status).collect();
+    assert_eq!(statuses, [CheckStatus::Ok, CheckStatus::Ok]);
+
+    // 경로 없이 이름만 같은 파일이 하나면 그 파일, 여럿이면 알 수 없음
+    assert_eq!(manifest::find_file("sub1/f", &["f"]), FileMatch::Found(0));
+    assert_eq!(manifest::find_file("sub3/f", &names), FileMatch::Ambiguous);
+    assert_eq!(manifest::find_file("g", &names), FileMatch::Missing);
+    let files = vec![("a/f".to_string(), sha256(b"one")), ("b/f".to_string(), sha256(b"one"))];
+    assert!(parsed.algorithms_for(&["a/f", "b/f"], 0).is_empty());
+    let checks = manifest::check_all(&parsed, &files);
+    assert!(checks.iter().all(|check| check.status == CheckStatus::Ambiguous && check.actual.is_none()));
+    let summary = manifest::summarize(&checks);
+    assert_eq!(summary.ambiguous, 2);
+    assert!(!summary.all_ok());
+}
+
+#[test]
+fn find_file_by_path_or_base_name() {
+    assert_eq!(manifest::find_file("./abc.txt", &["abc.txt"]), FileMatch::Found(0));
+    assert_eq!(manifest::find_file("dist/abc.txt", &["abc.txt"]), FileMatch::Found(0));
+    assert_eq!(manifest::find_file("dist\\abc.txt", &["dist/abc.txt"]), FileMatch::Found(0));
+    assert_eq!(manifest::find_file("abc.txt", &["abc.txt.sig"]), FileMatch::Missing);
+
+    assert!(manifest::is_manifest_name("SHA256SUMS"));
+    assert!(manifest::is_manifest_name("release/files.sfv"));
+    assert!(manifest::is_manifest_name("checksums.txt"));
+    assert!(!manifest::is_manifest_name("app.tar.gz"));
+}
+
+fn hashed(name: &str, data: &[u8], algorithms: &[HashAlgorithm]) -> HashedFile {
+    HashedFile {
+        name: name.to_string(),
+        size: data.len() as u64,
+        results: file_hash::compute_hashes(data, algorithms, None),
+    }
+}
+
+#[test]
+fn generates_text_manifests() {
+    let files = [hashed("abc.txt", b"abc", &[HashAlgorithm::Sha256, HashAlgorithm::Crc32])];
+    assert_eq!(
+        manifest::generate(&files, ManifestStyle::Gnu, HashAlgorithm::Sha256),
+        Ok(format!("{}  abc.txt\n", ABC_SHA256))
+    );
+    assert_eq!(
+        manifest::generate(&files, ManifestStyle::Bsd, HashAlgorithm::Sha256),
+        Ok(format!("SHA256 (abc.txt) = {}\n", ABC_SHA256))
+    );
+    // SFV는 선택한 알고리즘과 관계없이 CRC32
+    assert_eq!(
+        manifest::generate(&files, ManifestStyle::Sfv, HashAlgorithm::Sha256),
+        Ok("abc.txt 352441C2\n".to_string())
+    );
+    assert!(manifest::generate(&files, ManifestStyle::Gnu, HashAlgorithm::Md5).is_err());
+}
+
+#[test]
+fn generates_json_with_every_hash() {
+    let files = [hashed("dir/abc.txt", b"abc", &[HashAlgorithm::Md5, HashAlgorithm::Sha256])];
+    let json = manifest::generate(&files, ManifestStyle::Json, HashAlgorithm::Sha256).unwrap();
+    let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+    assert_eq!(
+        value,
+        serde_json::json!([{ "file": "dir/abc.txt", "size": 3, "md5": ABC_MD5, "sha256": ABC_SHA256 }])
+    );
+}
+
+#[test]
+fn gnu_names_with_newlines_round_trip() {
+    let files = [hashed("new\nline\\name", b"abc", &[HashAlgorithm::Md5])];
+    let text = manifest::generate(&files, ManifestStyle::Gnu, HashAlgorithm::Md5).unwrap();
+    assert_eq!(text, format!("\\{}  new\\nline\\\\name\n", ABC_MD5));
+    assert_eq!(manifest::parse(&text, None).entries[0].file_name, "new\nline\\name");
+}
+
+#[test]
+fn default_manifest_file_names() {
+    assert_eq!(ManifestStyle::Gnu.file_name(HashAlgorithm::Sha256), "SHA256SUMS");
+    assert_eq!(ManifestStyle::Gnu.file_name(HashAlgorithm::Md5), "MD5SUMS");
+    assert_eq!(ManifestStyle::Bsd.file_name(HashAlgorithm::Blake2b), "B2SUMS");
+    assert_eq!(ManifestStyle::Gnu.file_name(HashAlgorithm::Sha512_256), "SHA512_256SUMS");
+    assert_eq!(ManifestStyle::Sfv.file_name(HashAlgorithm::Sha256), "checksums.sfv");
+    assert_eq!(ManifestStyle::Json.file_name(HashAlgorithm::Sha256), "checksums.json");
+
+    // 기본 이름에서 알고리즘을 다시 추정할 수 있어야 함
+    for algorithm in HashAlgorithm::all() {
+        let name = ManifestStyle::Gnu.file_name(algorithm);
+        assert_eq!(manifest::algorithm_from_manifest_name(&name), Some(algorithm), "{}", name);
+    }
+    assert_eq!(ManifestStyle::from_key("bsd"), Some(ManifestStyle::Bsd));
+}
+
+proptest! {
+    #[test]
+    fn generated_manifests_verify(files in prop::collection::vec(prop::collection::vec(any::<u8>(), 0..64), 1..5)) {
+        let algorithms = [HashAlgorithm::Md5, HashAlgorithm::Sha1, HashAlgorithm::Sha256, HashAlgorithm::Sha512, HashAlgorithm::Crc32];
+        let mut text = String::new();
+        let mut computed = Vec::new();
+        for (index, data) in files.iter().enumerate() {
+            let name = format!("file {}.bin", index);
+            let results = file_hash::compute_hashes(data, &algorithms, None);
+            for (algorithm, hash) in &results.hashes {
+                let line = match algorithm {
+                    HashAlgorithm::Crc32 => format!("{} {}\n", name, hash.to_uppercase()),
+                    HashAlgorithm::Sha512 => format!("SHA512 ({}) = {}\n", name, hash),
+                    _ => format!("{} *{}\n", hash, name),
+                };
+                text.push_str(&line);
+            }
+            computed.push((name, results));
+        }
+
+        let parsed = manifest::parse(&text, None);
+        prop_assert!(parsed.malformed_lines.is_empty());
+        prop_assert_eq!(parsed.entries.len(), files.len() * algorithms.len());
+        let checks = manifest::check_all(&parsed, &computed);
+        prop_assert!(manifest::summarize(&checks).all_ok());
+    }
+
+    #[test]
+    fn generated_manifests_parse_back(
+        files in prop::collection::vec(("[a-z0-9 ._-]{1,12}", prop::collection::vec(any::<u8>(), 0..64)), 1..5),
+        algorithm in prop::sample::select(HashAlgorithm::all().to_vec()),
+    ) {
+        let files: Vec<HashedFile> = files
+            .iter()
+            .enumerate()
+            // 이름이 겹치지 않도록 번호를 붙이고, 앞뒤 공백은 SFV에서 보존되지 않으므로 제거
+            .map(|(index, (name, data))| hashed(&format!("{}{}", index, name.trim()), data, &[algorithm, HashAlgorithm::Crc32]))
+            .collect();
+        let computed: Vec<(String, _)> = files.iter().map(|file| (file.name.clone(), file.results.clone())).collect();
+
+        for style in [ManifestStyle::Gnu, ManifestStyle::Bsd, ManifestStyle::Sfv] {
+            let text = manifest::generate(&files, style, algorithm).unwrap();
+            let parsed = manifest::parse(&text, Some(&style.file_name(algorithm)));
+            prop_assert!(parsed.malformed_lines.is_empty(), "{:?}\n{}", style, text);
+            let names: Vec<&str> = parsed.entries.iter().map(|entry| entry.file_name.as_str()).collect();
+            let expected: Vec<&str> = files.iter().map(|file| file.name.as_str()).collect();
+            prop_assert_eq!(names, expected);
+            prop_assert!(manifest::summarize(&manifest::check_all(&parsed, &computed)).all_ok());
+        }
+    }
+}
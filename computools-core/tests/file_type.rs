@@ -0,0 +1,120 @@
+use computools_core::file_type::{detect_file_type, SNIFF_LENGTH};
+use proptest::prelude::*;
+
+fn detect(data: &[u8]) -> Option<(&'static str, &'static str)> {
+    detect_file_type(data).map(|file_type| (file_type.mime_type, file_type.extension))
+}
+
+/// 앞에 `header`, `offset` 위치에 `marker`를 둔 데이터
+fn with_marker(header: &[u8], offset: usize, marker: &[u8]) -> Vec<u8> {
+    let mut data = header.to_vec();
+    data.resize(data.len().max(offset), 0);
+    data.truncate(offset);
+    data.extend_from_slice(marker);
+    data
+}
+
+#[test]
+fn simple_signatures() {
+    assert_eq!(detect(b"\x89PNG\r\n\x1a\n"), Some(("image/png", "png")));
+    assert_eq!(detect(&[0xFF, 0xD8, 0xFF, 0xE0]), Some(("image/jpeg", "jpg")));
+    assert_eq!(detect(b"%PDF-1.7\n"), Some(("application/pdf", "pdf")));
+    assert_eq!(detect(&[0x1F, 0x8B, 0x08, 0x00]), Some(("application/gzip", "gz")));
+    assert_eq!(detect(&[0xFD, b'7', b'z', b'X', b'Z', 0x00]), Some(("application/x-xz", "xz")));
+    assert_eq!(detect(b"\x7fELF\x02\x01\x01"), Some(("application/x-elf", "bin")));
+    assert_eq!(detect(b"\0asm\x01\0\0\0"), Some(("application/wasm", "wasm")));
+    assert_eq!(detect(b"SQLite format 3\0\x10\0"), Some(("application/vnd.sqlite3", "sqlite")));
+    assert_eq!(detect(b"ID3\x04\0\0"), Some(("audio/mpeg", "mp3")));
+    assert_eq!(detect(b"wOF2\0\x01"), Some(("font/woff2", "woff2")));
+    assert_eq!(detect(&with_marker(b"file.txt", 257, b"ustar\x0000")), Some(("application/x-tar", "tar")));
+}
+
+#[test]
+fn container_formats() {
+    assert_eq!(detect(b"RIFF\x24\0\0\0WAVEfmt "), Some(("audio/wav", "wav")));
+    assert_eq!(detect(b"RIFF\0\0\0\0WEBPVP8 "), Some(("image/webp", "webp")));
+
+    // ftyp 박스의 주/호환 브랜드
+    assert_eq!(detect(b"\0\0\0\x1cftypmif1\0\0\0\0mif1avifmiaf"), Some(("image/avif", "avif")));
+    assert_eq!(detect(b"\0\0\0\x18ftypheic\0\0\0\0mif1heic"), Some(("image/heic", "heic")));
+    assert_eq!(detect(b"\0\0\0\x18ftypisom\0\0\x02\0isomiso2"), Some(("video/mp4", "mp4")));
+    assert_eq!(detect(b"\0\0\0\x14ftypqt  \0\0\0\0qt  "), Some(("video/quicktime", "mov")));
+
+    // PE: e_lfanew가 가리키는 "PE\0\0"와 Characteristics의 DLL 비트
+    let mut pe = with_marker(b"MZ", 0x3C, &[0x40, 0, 0, 0]);
+    pe = with_marker(&pe, 0x40, b"PE\0\0");
+    pe.resize(0x58, 0);
+    assert_eq!(detect(&pe), Some(("application/vnd.microsoft.portable-executable", "exe")));
+    pe[0x56..0x58].copy_from_slice(&0x2022u16.to_le_bytes());
+    assert_eq!(detect(&pe), Some(("application/vnd.microsoft.portable-executable", "dll")));
+    assert_eq!(detect(b"MZ is not an executable"), Some(("text/plain", "txt")));
+
+    assert_eq!(detect(&with_marker(b"BM", 14, &[40, 0, 0, 0])), Some(("image/bmp", "bmp")));
+    assert_eq!(detect(b"BMW is a car brand"), Some(("text/plain", "txt")));
+
+    assert_eq!(detect(b"\x1a\x45\xdf\xa3\x9f\x42\x82\x84webm"), Some(("video/webm", "webm")));
+    assert_eq!(detect(&[0xCA, 0xFE, 0xBA, 0xBE, 0, 0, 0, 0x41]), Some(("application/java-vm", "class")));
+    assert_eq!(detect(&[0xCA, 0xFE, 0xBA, 0xBE, 0, 0, 0, 2]), Some(("application/x-mach-binary", "bin")));
+}
+
+#[test]
+fn zip_based_formats() {
+    let local_header = |name: &str| {
+        let mut entry = b"PK\x03\x04".to_vec();
+        entry.resize(26, 0);
+        entry.extend_from_slice(&(name.len() as u16).to_le_bytes());
+        entry.extend_from_slice(&[0, 0]);
+        entry.extend_from_slice(name.as_bytes());
+        entry
+    };
+    let archive = |names: &[&str]| names.iter().flat_map(|name| local_header(name)).collect::<Vec<u8>>();
+
+    assert_eq!(detect(&archive(&["[Content_Types].xml", "_rels/.rels", "word/document.xml"])).unwrap().1, "docx");
+    assert_eq!(detect(&archive(&["[Content_Types].xml", "xl/workbook.xml"])).unwrap().1, "xlsx");
+    assert_eq!(detect(&archive(&["[Content_Types].xml", "ppt/presentation.xml"])).unwrap().1, "pptx");
+    assert_eq!(detect(&archive(&["META-INF/MANIFEST.MF", "Main.class"])).unwrap().1, "jar");
+    assert_eq!(detect(&archive(&["AndroidManifest.xml", "classes.dex"])).unwrap().1, "apk");
+    assert_eq!(detect(&archive(&["readme.txt"])), Some(("application/zip", "zip")));
+    assert_eq!(detect(b"PK\x05\x06\0\0\0\0"), Some(("application/zip", "zip")));
+
+    let mut epub = local_header("mimetype");
+    epub.extend_from_slice(b"application/epub+zip");
+    assert_eq!(detect(&epub), Some(("application/epub+zip", "epub")));
+}
+
+#[test]
+fn text_formats() {
+    assert_eq!(detect(b"  {\"a\": [1, 2]}"), Some(("application/json", "json")));
+    assert_eq!(detect(b"{not json"), Some(("text/plain", "txt")));
+    assert_eq!(detect(b"-----BEGIN CERTIFICATE-----\nMIIB"), Some(("application/x-pem-file", "pem")));
+    assert_eq!(detect(b"\xef\xbb\xbf<!DOCTYPE html><html></html>"), Some(("text/html", "html")));
+    assert_eq!(detect(b"<?xml version=\"1.0\"?>\n<svg xmlns=\"http://www.w3.org/2000/svg\"/>"), Some(("image/svg+xml", "svg")));
+    assert_eq!(detect(b"<?xml version=\"1.0\"?><note/>"), Some(("application/xml", "xml")));
+    // 앞부분만 받아 끝에서 잘린 UTF-8 문자
+    assert_eq!(detect(&"한글".as_bytes()[..5]), Some(("text/plain", "txt")));
+    assert!(detect_file_type(b"plain").unwrap().is_text());
+    assert!(!detect_file_type(b"%PDF-").unwrap().is_text());
+}
+
+#[test]
+fn protobuf_guess_and_unknown_binary() {
+    // field 1 = varint 150, field 2 = "hi"
+    assert_eq!(detect(&[0x08, 0x96, 0x01, 0x12, 0x02, b'h', b'i']), Some(("application/x-protobuf", "bin")));
+    assert_eq!(detect(&[0x08, 0x96, 0x01, 0x12, 0x05, b'h', b'i']), None);
+    assert_eq!(detect(&[0x00, 0x01, 0x02, 0x03, 0x04]), None);
+    assert_eq!(detect(&[]), None);
+}
+
+#[test]
+fn only_the_head_is_inspected() {
+    let mut data = b"%PDF-1.4\n".to_vec();
+    data.resize(SNIFF_LENGTH * 2, 0);
+    assert_eq!(detect(&data), Some(("application/pdf", "pdf")));
+}
+
+proptest! {
+    #[test]
+    fn never_panics(data in proptest::collection::vec(any::<u8>(), 0..600)) {
+        let _ = detect_file_type(&data);
+    }
+}
@@ -0,0 +1,89 @@
+use computools_core::html::{self, EncodeOptions, EntityStyle, SelectiveMode};
+use proptest::prelude::*;
+
+fn options(entity_style: EntityStyle, selective_mode: SelectiveMode) -> EncodeOptions {
+    EncodeOptions { entity_style, selective_mode, custom_chars: String::new() }
+}
+
+#[test]
+fn encode_essential_characters() {
+    let input = r#"<a href="x">Tom & Jerry's</a>"#;
+    assert_eq!(
+        html::encode_html(input, &EncodeOptions::default()),
+        "&lt;a href=&quot;x&quot;&gt;Tom &amp; Jerry&apos;s&lt;/a&gt;"
+    );
+    assert_eq!(
+        html::encode_html("<&>", &options(EntityStyle::Decimal, SelectiveMode::Essential)),
+        "&#60;&#38;&#62;"
+    );
+    assert_eq!(
+        html::encode_html("<&>", &options(EntityStyle::Hexadecimal, SelectiveMode::Essential)),
+        "&#x3C;&#x26;&#x3E;"
+    );
+    assert_eq!(html::encode_html("<&>", &options(EntityStyle::None, SelectiveMode::All)), "<&>");
+}
+
+#[test]
+fn encode_selective_modes() {
+    assert_eq!(html::encode_html("café", &EncodeOptions::default()), "café");
+    assert_eq!(html::encode_html("café", &options(EntityStyle::Named, SelectiveMode::Extended)), "caf&eacute;");
+    assert_eq!(html::encode_html("한", &options(EntityStyle::Named, SelectiveMode::Unicode)), "&#xD55C;");
+
+    let custom = EncodeOptions {
+        entity_style: EntityStyle::Decimal,
+        selective_mode: SelectiveMode::Custom,
+        custom_chars: "a, b".to_string(),
+    };
+    assert_eq!(html::encode_html("abc", &custom), "&#97;&#98;c");
+    assert_eq!(html::parse_custom_chars(" x , yz ,, "), vec!['x', 'y']);
+}
+
+#[test]
+fn decode_named_and_numeric_entities() {
+    assert_eq!(html::decode_html("&lt;b&gt;&amp;&quot;&apos;"), Ok("<b>&\"'".to_string()));
+    assert_eq!(html::decode_html("&eacute;&Uuml;&euro;"), Ok("éÜ€".to_string()));
+    assert_eq!(html::decode_html("&#60;&#x3C;&#X3c;"), Ok("<<<".to_string()));
+    assert_eq!(html::decode_html("&#x1F600;"), Ok("😀".to_string()));
+    assert_eq!(html::decode_html("   "), Ok(String::new()));
+}
+
+#[test]
+fn decode_leaves_unknown_and_invalid_entities() {
+    assert_eq!(html::decode_html("&unknown; &lt"), Ok("&unknown; &lt".to_string()));
+    assert_eq!(html::decode_html("&#xD800;"), Ok("&#xD800;".to_string()));
+    assert_eq!(html::decode_html("&#99999999999;"), Ok("&#99999999999;".to_string()));
+}
+
+#[test]
+fn decode_is_single_pass() {
+    // 인코딩된 엔티티 텍스트는 한 번만 디코딩되어야 함
+    assert_eq!(html::decode_html("&amp;lt;"), Ok("&lt;".to_string()));
+    assert_eq!(html::decode_html("&#x26;#x3C;"), Ok("&#x3C;".to_string()));
+    assert_eq!(html::decode_html("&#38;#60;"), Ok("&#60;".to_string()));
+}
+
+#[test]
+fn detect_invalid_entities_reports_position() {
+    assert_eq!(html::detect_invalid_entities("&lt; &amp;"), None);
+    let warning = html::detect_invalid_entities("ok\n  &bogus;").unwrap();
+    assert!(warning.contains("&bogus;"), "{}", warning);
+    assert!(html::detect_invalid_entities("&lt").is_some());
+}
+
+proptest! {
+    #[test]
+    fn encode_then_decode_is_identity(text in "\\PC{0,64}") {
+        for entity_style in [EntityStyle::Named, EntityStyle::Decimal, EntityStyle::Hexadecimal, EntityStyle::MixedNamedHex, EntityStyle::MixedNamedDecimal] {
+            for selective_mode in [SelectiveMode::Essential, SelectiveMode::Extended, SelectiveMode::Unicode, SelectiveMode::All] {
+                let encoded = html::encode_html(&text, &options(entity_style.clone(), selective_mode.clone()));
+                prop_assert_eq!(html::decode_html(&encoded), Ok(text.clone()), "{:?} {:?}", entity_style, selective_mode);
+            }
+        }
+    }
+
+    #[test]
+    fn entity_like_text_round_trips(text in "(&(amp|lt|#x26|#60);|[a-z<>&#;]){0,16}") {
+        let encoded = html::encode_html(&text, &EncodeOptions::default());
+        prop_assert_eq!(html::decode_html(&encoded), Ok(text));
+    }
+}
@@ -0,0 +1,109 @@
+use computools_core::json::{self, SortDirection, TableState};
+use proptest::prelude::*;
+use serde_json::{json, Value};
+
+#[test]
+fn formatting_preserves_key_order() {
+    let value: Value = serde_json::from_str(r#"{"z":1,"a":[true,null]}"#).unwrap();
+    assert_eq!(json::format_json_with_order_preservation(&value, &json::indent_for_style("compact")), r#"{"z":1,"a":[true,null]}"#);
+    assert_eq!(
+        json::format_json_with_order_preservation(&value, &json::indent_for_style("2space")),
+        "{\n  \"z\": 1,\n  \"a\": [\n    true,\n    null\n  ]\n}"
+    );
+    assert_eq!(json::convert_to_yaml_with_order_preservation(&value), "z: 1\na:\n- true\n- null\n");
+}
+
+#[test]
+fn indent_styles() {
+    assert_eq!(json::indent_for_style("1tab"), b"\t".to_vec());
+    assert_eq!(json::indent_for_style("3space"), b"   ".to_vec());
+    assert_eq!(json::indent_for_style("unknown"), b"    ".to_vec());
+}
+
+#[test]
+fn error_message_marks_column() {
+    let input = "{\n  \"a\": ,\n}";
+    let err = serde_json::from_str::<Value>(input).unwrap_err();
+    let message = json::format_error_message(input, err);
+    assert!(message.starts_with("Invalid JSON:\n  \"a\": ,\n-------^"), "{}", message);
+}
+
+#[test]
+fn value_helpers() {
+    assert_eq!(json::format_value(&json!("x")), "\"x\"");
+    assert_eq!(json::format_value(&json!({"a": 1, "b": 2})), "{ 2 items }");
+    assert_eq!(json::format_value_for_table(&json!("x")), "x");
+    assert_eq!(json::get_value_type(&json!([1])), "array");
+    assert_eq!(json::get_type_icon(&json!(false)), "❌");
+}
+
+#[test]
+fn table_data_from_object_array() {
+    let value = json!([
+        {"name": "b", "age": 2},
+        {"name": "a", "age": 10, "extra": true},
+        "skipped",
+        {"age": 3}
+    ]);
+    let (columns, rows) = json::convert_to_table_data(&value).unwrap();
+    let names: Vec<&str> = columns.iter().map(|c| c.name.as_str()).collect();
+    assert_eq!(names, vec!["name", "age"]);
+    assert_eq!(columns[1].data_type, "number");
+    assert_eq!(rows.len(), 3);
+    assert_eq!(rows[2].values, vec!["".to_string(), "3".to_string()]);
+
+    assert_eq!(json::convert_to_table_data(&json!([])), None);
+    assert_eq!(json::convert_to_table_data(&json!([1, 2])), None);
+    assert_eq!(json::convert_to_table_data(&json!({"a": 1})), None);
+}
+
+#[test]
+fn table_sorting_search_and_pagination() {
+    let value = json!([{"n": "c"}, {"n": "a"}, {"n": "b"}, {"n": "ab"}]);
+    let (columns, rows) = json::convert_to_table_data(&value).unwrap();
+
+    let state = TableState {
+        sort_column: Some("n".to_string()),
+        sort_direction: SortDirection::Ascending,
+        rows_per_page: 3,
+        ..TableState::default()
+    };
+    let (page, total_pages) = json::get_sorted_and_paginated_rows(&columns, &rows, &state);
+    let values: Vec<&str> = page.iter().map(|row| row.values[0].as_str()).collect();
+    assert_eq!(values, vec!["a", "ab", "b"]);
+    assert_eq!(total_pages, 2);
+
+    let state = TableState { search_query: "A".to_string(), ..TableState::default() };
+    let (page, total_pages) = json::get_sorted_and_paginated_rows(&columns, &rows, &state);
+    assert_eq!(page.len(), 2);
+    assert_eq!(total_pages, 1);
+
+    let state = TableState { current_page: 5, ..TableState::default() };
+    assert!(json::get_sorted_and_paginated_rows(&columns, &rows, &state).0.is_empty());
+}
+
+fn json_value() -> impl Strategy<Value = Value> {
+    let leaf = prop_oneof![
+        Just(Value::Null),
+        any::<bool>().prop_map(Value::Bool),
+        any::<i64>().prop_map(|n| json!(n)),
+        "\\PC{0,8}".prop_map(Value::String),
+    ];
+    leaf.prop_recursive(3, 32, 4, |inner| {
+        prop_oneof![
+            prop::collection::vec(inner.clone(), 0..4).prop_map(Value::Array),
+            prop::collection::vec(("[a-z]{1,4}", inner), 0..4)
+                .prop_map(|entries| Value::Object(entries.into_iter().collect())),
+        ]
+    })
+}
+
+proptest! {
+    #[test]
+    fn formatted_output_parses_back(value in json_value()) {
+        for style in ["2space", "4space", "compact", "1tab"] {
+            let text = json::format_json_with_order_preservation(&value, &json::indent_for_style(style));
+            prop_assert_eq!(serde_json::from_str::<Value>(&text).unwrap(), value.clone());
+        }
+    }
+}
@@ -0,0 +1,80 @@
+use computools_core::jwt::{self, JwtAlgorithm};
+use proptest::prelude::*;
+use serde_json::json;
+
+// jwt.io의 기본 예제 토큰 (secret: "your-256-bit-secret")
+const EXAMPLE_TOKEN: &str = "eyJhbGciOiJIUzI1NiIsInR5cCI6IkpXVCJ9.\
+eyJzdWIiOiIxMjM0NTY3ODkwIiwibmFtZSI6IkpvaG4gRG9lIiwiaWF0IjoxNTE2MjM5MDIyfQ.\
+SflKxwRJSMeKKF2QT4fwpMeJf36POk6yJV_adQssw5c";
+
+#[test]
+fn decodes_and_verifies_example_token() {
+    let decoded = jwt::decode(&format!("Bearer {}\n", EXAMPLE_TOKEN)).unwrap();
+    assert_eq!(decoded.header, json!({"alg": "HS256", "typ": "JWT"}));
+    assert_eq!(decoded.payload["name"], "John Doe");
+    assert_eq!(decoded.algorithm(), "HS256");
+    assert_eq!(decoded.signature.len(), 32);
+
+    assert_eq!(jwt::verify(&decoded, b"your-256-bit-secret"), Ok(true));
+    assert_eq!(jwt::verify(&decoded, b"wrong-secret"), Ok(false));
+}
+
+#[test]
+fn signing_reproduces_example_token() {
+    let header = json!({"alg": "HS256", "typ": "JWT"});
+    let payload = json!({"sub": "1234567890", "name": "John Doe", "iat": 1516239022});
+    assert_eq!(jwt::sign(&header, &payload, JwtAlgorithm::Hs256, b"your-256-bit-secret"), Ok(EXAMPLE_TOKEN.to_string()));
+
+    // alg는 선택한 알고리즘으로 바뀌고 typ이 없으면 추가됨
+    let token = jwt::sign(&json!({"kid": "1"}), &payload, JwtAlgorithm::Hs512, b"k").unwrap();
+    let decoded = jwt::decode(&token).unwrap();
+    assert_eq!(decoded.header, json!({"kid": "1", "alg": "HS512", "typ": "JWT"}));
+    assert_eq!(decoded.signature.len(), 64);
+    assert!(jwt::sign(&json!([1]), &payload, JwtAlgorithm::Hs256, b"k").is_err());
+}
+
+#[test]
+fn malformed_tokens_are_errors() {
+    assert!(jwt::decode("abc").is_err());
+    assert!(jwt::decode("a.b.c.d.e").unwrap_err().contains("JWE"));
+    assert!(jwt::decode("!!!.e30.").is_err());
+    // 페이로드가 JSON 배열
+    assert!(jwt::decode("eyJhbGciOiJIUzI1NiJ9.WzFd.").is_err());
+
+    let unsigned = jwt::decode("eyJhbGciOiJub25lIn0.e30.").unwrap();
+    assert!(unsigned.signature.is_empty());
+    assert!(jwt::verify(&unsigned, b"secret").is_err());
+}
+
+#[test]
+fn time_claims_and_warnings() {
+    let payload = json!({"iat": 1000, "exp": 2000.5, "nbf": 1500, "sub": "x"});
+    assert_eq!(jwt::time_claims(&payload), vec![("exp", 2000), ("nbf", 1500), ("iat", 1000)]);
+
+    assert!(jwt::time_warnings(&payload, 1800).is_empty());
+    assert_eq!(jwt::time_warnings(&payload, 2000 + 90_061), vec!["Expired 1d 1h ago.".to_string()]);
+    assert_eq!(jwt::time_warnings(&payload, 1400), vec!["Not valid for another 1m 40s.".to_string()]);
+}
+
+proptest! {
+    #[test]
+    fn signed_tokens_round_trip(
+        subject in "\\PC{0,40}",
+        number in any::<i64>(),
+        secret in proptest::collection::vec(any::<u8>(), 0..80),
+        index in 0usize..3,
+    ) {
+        let algorithm = JwtAlgorithm::all()[index];
+        let payload = json!({"sub": subject, "n": number});
+        let token = jwt::sign(&json!({}), &payload, algorithm, &secret).unwrap();
+
+        let decoded = jwt::decode(&token).unwrap();
+        prop_assert_eq!(&decoded.payload, &payload);
+        prop_assert_eq!(JwtAlgorithm::from_name(decoded.algorithm()), Some(algorithm));
+        prop_assert_eq!(jwt::verify(&decoded, &secret), Ok(true));
+
+        let mut other = secret.clone();
+        other.push(1);
+        prop_assert_eq!(jwt::verify(&decoded, &other), Ok(false));
+    }
+}
@@ -0,0 +1,165 @@
+use computools_core::kdf::{self, KdfHash, KdfParams};
+use proptest::prelude::*;
+
+fn derive_hex(params: &KdfParams, password: &[u8], salt: &[u8], length: usize) -> String {
+    hex::encode(kdf::derive_key(params, password, salt, length).unwrap())
+}
+
+#[test]
+fn pbkdf2_matches_reference_vectors() {
+    let sha256 = KdfParams::Pbkdf2 { hash: KdfHash::Sha256, iterations: 4096 };
+    assert_eq!(
+        derive_hex(&sha256, b"password", b"salt", 32),
+        "c5e478d59288c841aa530db6845c4c8d962893a001ce4e11a4963873aa98134a"
+    );
+
+    let sha512 = KdfParams::Pbkdf2 { hash: KdfHash::Sha512, iterations: 1 };
+    assert_eq!(
+        derive_hex(&sha512, b"password", b"salt", 64),
+        "867f70cf1ade02cff3752599a3a53dc4af34c7a669815ae5d513554e1c8cf252\
+         c02d470a285a0501bad999bfe943c08f050235d7d68b1da55e63f73b60a57fce"
+    );
+}
+
+#[test]
+fn hkdf_matches_rfc5869_case_1() {
+    let params = KdfParams::Hkdf { hash: KdfHash::Sha256, info: hex::decode("f0f1f2f3f4f5f6f7f8f9").unwrap() };
+    let salt = hex::decode("000102030405060708090a0b0c").unwrap();
+    assert_eq!(
+        derive_hex(&params, &[0x0b; 22], &salt, 42),
+        "3cb25f25faacd57a90434f64d0362f2a2d2d0a90cf1a5a4c5db02d56ecc4c5bf34007208d5b887185865"
+    );
+
+    assert!(kdf::derive_key(&params, b"ikm", b"", kdf::MAX_KEY_LENGTH).is_ok());
+    assert!(kdf::derive_key(&params, b"ikm", b"", kdf::MAX_KEY_LENGTH + 1).is_err());
+}
+
+#[test]
+fn scrypt_matches_rfc7914_case_1() {
+    let params = KdfParams::Scrypt { log_n: 4, r: 1, p: 1 };
+    assert_eq!(
+        derive_hex(&params, b"", b"", 64),
+        "77d6576238657b203b19ca42c18a0497f16b4844e3074ae8dfdffa3fede21442\
+         fcd0069ded0948f8326a753a0fc81f17e8d3e0fb2e0d3628cf35e20c38d18906"
+    );
+}
+
+#[test]
+fn invalid_parameters_are_errors() {
+    let pbkdf2 = KdfParams::Pbkdf2 { hash: KdfHash::Sha256, iterations: 0 };
+    assert!(kdf::derive_key(&pbkdf2, b"pw", b"salt", 32).is_err());
+    assert!(kdf::derive_key(&KdfParams::Scrypt { log_n: 4, r: 8, p: 0 }, b"pw", b"salt", 32).is_err());
+    let argon2 = KdfParams::Argon2id { memory_kib: 1, iterations: 1, parallelism: 1 };
+    assert!(kdf::derive_key(&argon2, b"pw", b"saltsalt", 32).is_err());
+    assert!(kdf::derive_key(&KdfParams::Scrypt { log_n: 4, r: 1, p: 1 }, b"pw", b"salt", 0).is_err());
+    assert!(kdf::phc_string(&KdfParams::Hkdf { hash: KdfHash::Sha256, info: Vec::new() }, b"pw", b"saltsalt", 32).is_err());
+}
+
+#[test]
+fn excessive_costs_are_rejected() {
+    let pbkdf2 = KdfParams::Pbkdf2 { hash: KdfHash::Sha256, iterations: kdf::MAX_PBKDF2_ITERATIONS + 1 };
+    assert!(kdf::derive_key(&pbkdf2, b"pw", b"saltsalt", 32).is_err());
+    assert!(kdf::phc_string(&pbkdf2, b"pw", b"saltsalt", 32).is_err());
+    // 128 · r · N = 2 GiB
+    let scrypt = KdfParams::Scrypt { log_n: 21, r: 8, p: 1 };
+    assert!(kdf::derive_key(&scrypt, b"pw", b"saltsalt", 32).is_err());
+    assert!(kdf::derive_key(&KdfParams::Scrypt { log_n: 63, r: 1, p: 1 }, b"pw", b"saltsalt", 32).is_err());
+    assert!(kdf::derive_key(&KdfParams::Scrypt { log_n: 4, r: 1, p: kdf::MAX_SCRYPT_P + 1 }, b"pw", b"saltsalt", 32).is_err());
+    for (memory_kib, iterations, parallelism) in [
+        (kdf::MAX_ARGON2_MEMORY_KIB + 1, 1, 1),
+        (64, kdf::MAX_ARGON2_ITERATIONS + 1, 1),
+        (1024, 1, kdf::MAX_ARGON2_PARALLELISM + 1),
+    ] {
+        let argon2 = KdfParams::Argon2id { memory_kib, iterations, parallelism };
+        assert!(kdf::derive_key(&argon2, b"pw", b"saltsalt", 32).is_err());
+        assert!(kdf::phc_string(&argon2, b"pw", b"saltsalt", 32).is_err());
+    }
+    assert!(kdf::bcrypt_hash(b"pw", kdf::MAX_BCRYPT_COST + 1, [7; 16]).is_err());
+}
+
+#[test]
+fn stored_hashes_with_excessive_costs_are_not_verified() {
+    let argon2 = KdfParams::Argon2id { memory_kib: 64, iterations: 1, parallelism: 1 };
+    let phc = kdf::phc_string(&argon2, b"pw", b"saltsalt", 32).unwrap();
+    let hostile = phc.replace("m=64,", "m=4000000000,");
+    assert!(kdf::verify_password(b"pw", &hostile).unwrap_err().contains("memory"));
+    // 원래 문자열은 그대로 검증됨
+    assert_eq!(kdf::verify_password(b"pw", &phc), Ok(true));
+
+    let scrypt = kdf::phc_string(&KdfParams::Scrypt { log_n: 4, r: 8, p: 1 }, b"pw", b"saltsalt", 32).unwrap();
+    assert!(kdf::verify_password(b"pw", &scrypt.replace("ln=4,", "ln=30,")).is_err());
+
+    let pbkdf2 = KdfParams::Pbkdf2 { hash: KdfHash::Sha256, iterations: 10 };
+    let phc = kdf::phc_string(&pbkdf2, b"pw", b"saltsalt", 32).unwrap();
+    assert!(kdf::verify_password(b"pw", &phc.replace("i=10,", "i=4000000000,")).is_err());
+
+    let bcrypt = kdf::bcrypt_hash(b"pw", 4, [7; 16]).unwrap();
+    assert!(kdf::verify_password(b"pw", &bcrypt.replace("$2b$04$", "$2b$31$")).is_err());
+}
+
+#[test]
+fn bcrypt_reference_hash_verifies() {
+    let hash = "$2a$05$CCCCCCCCCCCCCCCCCCCCC.E5YPO9kmyuRGyh0XouQYb4YMJKvyOeW";
+    assert_eq!(kdf::verify_password(b"U*U", hash), Ok(true));
+    assert_eq!(kdf::verify_password(b"U*V", hash), Ok(false));
+
+    let info = kdf::inspect_password_hash(hash).unwrap();
+    assert_eq!(info.scheme, "bcrypt");
+    assert_eq!(info.params, vec![("version".to_string(), "2a".to_string()), ("cost".to_string(), "5".to_string())]);
+    assert_eq!(info.salt, "CCCCCCCCCCCCCCCCCCCCC.");
+
+    assert!(kdf::inspect_password_hash("$2b$05$tooshort").is_err());
+    assert!(kdf::verify_password(b"pw", "not a hash").is_err());
+}
+
+#[test]
+fn generated_bcrypt_hash_verifies() {
+    let hash = kdf::bcrypt_hash(b"secret", 4, [7; 16]).unwrap();
+    assert!(hash.starts_with("$2b$04$"));
+    assert_eq!(kdf::verify_password(b"secret", &hash), Ok(true));
+    assert_eq!(kdf::verify_password(b"Secret", &hash), Ok(false));
+}
+
+#[test]
+fn argon2id_phc_string_matches_raw_key() {
+    let params = KdfParams::Argon2id { memory_kib: 64, iterations: 2, parallelism: 1 };
+    let phc = kdf::phc_string(&params, b"password", b"somesalt", 32).unwrap();
+    assert!(phc.starts_with("$argon2id$v=19$m=64,t=2,p=1$"));
+    assert_eq!(kdf::verify_password(b"password", &phc), Ok(true));
+    assert_eq!(kdf::verify_password(b"passw0rd", &phc), Ok(false));
+
+    let info = kdf::inspect_password_hash(&phc).unwrap();
+    assert_eq!(info.scheme, "argon2id");
+    assert_eq!(info.output_len, 32);
+    assert!(info.params.contains(&("m".to_string(), "64".to_string())));
+
+    // PHC 문자열의 마지막 필드는 같은 파라미터로 유도한 원시 키
+    let raw = kdf::derive_key(&params, b"password", b"somesalt", 32).unwrap();
+    let encoded = phc.rsplit('$').next().unwrap();
+    use base64::Engine;
+    assert_eq!(base64::engine::general_purpose::STANDARD_NO_PAD.encode(raw), encoded);
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(16))]
+
+    #[test]
+    fn phc_strings_verify_only_their_password(
+        password in proptest::collection::vec(any::<u8>(), 0..32),
+        salt in proptest::collection::vec(any::<u8>(), 8..24),
+        use_scrypt in any::<bool>(),
+    ) {
+        let params = if use_scrypt {
+            KdfParams::Scrypt { log_n: 4, r: 1, p: 1 }
+        } else {
+            KdfParams::Pbkdf2 { hash: KdfHash::Sha256, iterations: 10 }
+        };
+        let phc = kdf::phc_string(&params, &password, &salt, 32).unwrap();
+        prop_assert_eq!(kdf::verify_password(&password, &phc), Ok(true));
+
+        // HMAC 키는 0으로 채워지므로 끝에 0이 아닌 바이트를 붙여 다른 비밀번호를 만듦
+        let mut other = password.clone();
+        other.push(b'x');
+        prop_assert_eq!(kdf::verify_password(&other, &phc), Ok(false));
+    }
+}
@@ -0,0 +1,98 @@
+use computools_core::codec::Codec;
+use computools_core::mime::{
+    decode_header, decode_quoted_printable, decode_uuencode, decode_yenc, encode_encoded_words, encode_quoted_printable,
+    encode_uuencode, encode_yenc,
+};
+use proptest::prelude::*;
+
+fn text(bytes: Result<Vec<u8>, String>) -> String {
+    String::from_utf8(bytes.unwrap()).unwrap()
+}
+
+#[test]
+fn quoted_printable() {
+    assert_eq!(encode_quoted_printable("Café = ok".as_bytes()), "Caf=C3=A9 =3D ok");
+    // 줄 끝 공백과 탭은 인코딩, 줄바꿈은 그대로
+    assert_eq!(encode_quoted_printable(b"a \r\nb\t\nc"), "a=20\r\nb=09\nc");
+
+    let long = "x".repeat(200);
+    let encoded = encode_quoted_printable(long.as_bytes());
+    assert!(encoded.lines().all(|line| line.len() <= 76));
+    assert_eq!(encoded.lines().next(), Some(format!("{}=", "x".repeat(75)).as_str()));
+
+    assert_eq!(text(decode_quoted_printable("soft=\nbreak, caf=c3=a9  \r\nnext")), "softbreak, café\r\nnext");
+    assert!(decode_quoted_printable("a=ZZ").unwrap_err().contains("line 1"));
+    assert!(decode_quoted_printable("ok\nbad=4").unwrap_err().contains("line 2"));
+}
+
+#[test]
+fn uuencode() {
+    assert_eq!(encode_uuencode(b"Cat", "cat.txt"), "begin 644 cat.txt\n#0V%T\n`\nend\n");
+    assert_eq!(encode_uuencode(b"", "empty"), "begin 644 empty\n`\nend\n");
+
+    // begin 앞의 메일 본문은 건너뛰고, 지워진 줄 끝 공백은 0으로 복원
+    let mail = "See attachment.\n\nbegin 644 cat.txt\n#0V%T\n`\nend\ntrailing text\n";
+    assert_eq!(decode_uuencode(mail), Ok(b"Cat".to_vec()));
+    assert_eq!(decode_uuencode("#``  \n"), decode_uuencode("#\n"));
+    assert!(decode_uuencode("begin 644 x\n#0v%t\nend").unwrap_err().contains("'v' on line 2"));
+}
+
+#[test]
+fn yenc() {
+    let encoded = encode_yenc(b"123456789", "check.txt");
+    assert_eq!(encoded, "=ybegin line=128 size=9 name=check.txt\n[\\]^_`abc\n=yend size=9 crc32=cbf43926\n");
+    assert_eq!(decode_yenc(&encoded), Ok(b"123456789".to_vec()));
+
+    // NUL, CR, LF, '=' 이 되는 바이트는 이스케이프
+    let critical = [214u8, 224, 227, 19];
+    let encoded = encode_yenc(&critical, "x");
+    assert!(encoded.contains("=@=J=M=}"));
+    assert_eq!(decode_yenc(&encoded), Ok(critical.to_vec()));
+
+    let corrupted = "=ybegin line=128 size=3 name=x\n[\\^\n=yend size=3 crc32=884863d2\n";
+    assert!(decode_yenc(corrupted).unwrap_err().contains("CRC32 mismatch"));
+    assert!(decode_yenc("=ybegin size=5 name=x\n[\\]\n=yend size=5\n").unwrap_err().contains("size mismatch"));
+    assert!(decode_yenc("한").is_err());
+}
+
+#[test]
+fn mime_header_block() {
+    let header = "From: =?ISO-8859-1?Q?Andr=E9?= <andre@example.com>\r\n\
+                  Subject: =?UTF-8?B?7ZWc6riA?=\r\n =?utf-8?q?_and_more?=\r\n\
+                  X-Plain: =?not an encoded word?=\r\n";
+    assert_eq!(
+        text(decode_header(header)),
+        "From: André <andre@example.com>\nSubject: 한글 and more\nX-Plain: =?not an encoded word?=\n"
+    );
+
+    // RFC 2047 6.2: 인코딩된 단어 사이의 공백만 지움
+    assert_eq!(text(decode_header("(=?ISO-8859-1?Q?a?= b)")), "(a b)");
+    assert_eq!(text(decode_header("(=?ISO-8859-1?Q?a?=  \t =?ISO-8859-1?Q?b?=)")), "(ab)");
+    assert_eq!(text(decode_header("=?windows-1252?Q?=93quoted=94?=")), "“quoted”");
+    assert_eq!(text(decode_header("=?UTF-8?B?QQ?=")), "A");
+    assert!(decode_header("=?ISO-2022-JP?B?GyRCJDMbKEI=?=").unwrap_err().contains("ISO-2022-JP"));
+}
+
+#[test]
+fn encoded_words_fold_within_76_chars() {
+    let subject = "한글 제목이 아주 길어서 인코딩된 단어 여러 개로 나뉘어야 하는 경우";
+    let encoded = encode_encoded_words(subject.as_bytes());
+    assert!(encoded.lines().count() > 1);
+    assert!(encoded.lines().all(|line| line.len() <= 76));
+    assert_eq!(text(decode_header(&format!("Subject: {}", encoded))), format!("Subject: {}", subject));
+    // 단어마다 완전한 UTF-8 문자만 담음
+    for word in encoded.split_whitespace() {
+        assert!(text(decode_header(word)).chars().all(|c| c != char::REPLACEMENT_CHARACTER));
+    }
+}
+
+proptest! {
+    #[test]
+    fn email_codecs_round_trip(data in proptest::collection::vec(any::<u8>(), 0..400)) {
+        for codec in [Codec::QuotedPrintable, Codec::Uuencode, Codec::Yenc, Codec::MimeHeader] {
+            let encoded = codec.encode(&data).unwrap();
+            prop_assert_eq!(codec.decode(&encoded), Ok(data.clone()), "{:?}", codec);
+        }
+        prop_assert!(encode_quoted_printable(&data).split('\n').all(|line| line.trim_end_matches('\r').len() <= 76));
+    }
+}
@@ -0,0 +1,77 @@
+use computools_core::file_type::detect_file_type;
+use computools_core::preview::{decode_text, hex_dump, hex_dump_page, hex_dump_pages, TextEncoding, HEX_DUMP_PAGE_SIZE};
+use proptest::prelude::*;
+
+fn utf16(text: &str, little_endian: bool, bom: bool) -> Vec<u8> {
+    let units = if bom { "\u{feff}" } else { "" }.encode_utf16().chain(text.encode_utf16());
+    units.flat_map(|unit| if little_endian { unit.to_le_bytes() } else { unit.to_be_bytes() }).collect()
+}
+
+#[test]
+fn utf8_text() {
+    assert_eq!(decode_text("안녕, world\n".as_bytes()), Some((TextEncoding::Utf8, "안녕, world\n".to_string())));
+    assert_eq!(decode_text(b"\xef\xbb\xbfbom"), Some((TextEncoding::Utf8, "bom".to_string())));
+    assert_eq!(decode_text(b"nul\0inside"), None);
+    assert_eq!(decode_text(&[0xC3, 0x28]), None);
+}
+
+#[test]
+fn utf16_text() {
+    for bom in [true, false] {
+        assert_eq!(decode_text(&utf16("Hello, 세계!", true, bom)), Some((TextEncoding::Utf16Le, "Hello, 세계!".to_string())));
+        assert_eq!(decode_text(&utf16("Hello, 세계!", false, bom)), Some((TextEncoding::Utf16Be, "Hello, 세계!".to_string())));
+    }
+    // BOM이 있으면 ASCII가 아닌 글자만 있어도 인식
+    assert_eq!(decode_text(&utf16("한글만", true, true)).unwrap().0, TextEncoding::Utf16Le);
+    // 짝이 맞지 않는 서로게이트
+    assert_eq!(decode_text(&[0xFF, 0xFE, 0x00, 0xDC, b'a', 0]), None);
+
+    assert_eq!(detect_file_type(&utf16("<?xml?>", true, true)).unwrap().name, "UTF-16 text");
+    assert!(detect_file_type(&utf16("plain", false, false)).unwrap().is_text());
+}
+
+#[test]
+fn hex_dump_lines() {
+    assert_eq!(
+        hex_dump(b"Hello, World!\n", 0),
+        "00000000  48 65 6c 6c 6f 2c 20 57  6f 72 6c 64 21 0a        |Hello, World!.|\n"
+    );
+    let dump = hex_dump(&(0u8..=20).collect::<Vec<u8>>(), 0x100);
+    let lines = dump.lines().collect::<Vec<_>>();
+    assert_eq!(lines.len(), 2);
+    assert!(lines[0].starts_with("00000100  00 01 02 03 04 05 06 07  08 09 0a 0b"));
+    assert!(lines[1].starts_with("00000110  10 11 12 13 14 "));
+    assert_eq!(lines[0].len(), lines[1].len() + 11);
+    assert_eq!(hex_dump(&[], 0), "");
+}
+
+#[test]
+fn hex_dump_paging() {
+    let data = vec![0xAB; HEX_DUMP_PAGE_SIZE + 20];
+    assert_eq!(hex_dump_pages(data.len()), 2);
+    assert_eq!(hex_dump_pages(0), 1);
+    assert_eq!(hex_dump_page(&data, 0).lines().count(), HEX_DUMP_PAGE_SIZE / 16);
+    let last = hex_dump_page(&data, 1);
+    assert_eq!(last.lines().count(), 2);
+    assert!(last.starts_with(&format!("{:08x}", HEX_DUMP_PAGE_SIZE)));
+    assert_eq!(hex_dump_page(&data, 5), "");
+}
+
+proptest! {
+    #[test]
+    fn hex_dump_covers_every_byte(data in proptest::collection::vec(any::<u8>(), 0..200)) {
+        let dump = hex_dump(&data, 0);
+        prop_assert_eq!(dump.lines().count(), data.len().div_ceil(16));
+        let bytes: Vec<u8> = dump
+            .lines()
+            .flat_map(|line| line[10..59].split_whitespace().map(|hex| u8::from_str_radix(hex, 16).unwrap()).collect::<Vec<_>>())
+            .collect();
+        prop_assert_eq!(bytes, data);
+    }
+
+    #[test]
+    fn utf16_round_trip(text in "[ -~가-힣\n]{2,80}") {
+        prop_assert_eq!(decode_text(&utf16(&text, true, true)), Some((TextEncoding::Utf16Le, text.clone())));
+        prop_assert_eq!(decode_text(&utf16(&text, false, true)), Some((TextEncoding::Utf16Be, text)));
+    }
+}
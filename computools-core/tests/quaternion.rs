@@ -0,0 +1,74 @@
+use std::f64::consts::{FRAC_PI_2, PI};
+
+use computools_core::quaternion::{self, EulerAngles, Quaternion};
+use proptest::prelude::*;
+
+const EPSILON: f64 = 1e-9;
+
+fn assert_close(actual: f64, expected: f64) {
+    assert!((actual - expected).abs() < EPSILON, "{} != {}", actual, expected);
+}
+
+#[test]
+fn identity_quaternion_has_zero_angles() {
+    let (euler, q) = quaternion::quaternion_to_euler(Quaternion { w: 1.0, x: 0.0, y: 0.0, z: 0.0 });
+    assert_eq!(euler, EulerAngles { roll: 0.0, pitch: 0.0, yaw: 0.0 });
+    assert_eq!(q, Quaternion { w: 1.0, x: 0.0, y: 0.0, z: 0.0 });
+}
+
+#[test]
+fn zero_quaternion_is_treated_as_identity() {
+    let (euler, _) = quaternion::quaternion_to_euler(Quaternion { w: 0.0, x: 0.0, y: 0.0, z: 0.0 });
+    assert_eq!(euler, EulerAngles { roll: 0.0, pitch: 0.0, yaw: 0.0 });
+}
+
+#[test]
+fn quaternion_is_normalized() {
+    let (euler, q) = quaternion::quaternion_to_euler(Quaternion { w: 2.0, x: 2.0, y: 0.0, z: 0.0 });
+    let half = 0.5f64.sqrt();
+    assert_close(q.w, half);
+    assert_close(q.x, half);
+    assert_close(euler.roll, FRAC_PI_2);
+    assert_close(euler.pitch, 0.0);
+    assert_close(euler.yaw, 0.0);
+}
+
+#[test]
+fn quarter_turn_about_z() {
+    let (_, q) = quaternion::euler_to_quaternion(EulerAngles { roll: 0.0, pitch: 0.0, yaw: FRAC_PI_2 });
+    let half = 0.5f64.sqrt();
+    assert_close(q.w, half);
+    assert_close(q.x, 0.0);
+    assert_close(q.y, 0.0);
+    assert_close(q.z, half);
+}
+
+#[test]
+fn normalize_angle_wraps_into_range() {
+    assert_close(quaternion::normalize_angle(3.0 * PI), PI);
+    assert_close(quaternion::normalize_angle(-PI), PI);
+    assert_close(quaternion::normalize_angle(2.5 * PI), FRAC_PI_2);
+    assert_close(quaternion::normalize_angle(-2.5 * PI), -FRAC_PI_2);
+}
+
+proptest! {
+    #[test]
+    fn euler_round_trips_away_from_gimbal_lock(
+        roll in -3.0f64..3.0,
+        pitch in -1.5f64..1.5,
+        yaw in -3.0f64..3.0,
+    ) {
+        let (angles, q) = quaternion::euler_to_quaternion(EulerAngles { roll, pitch, yaw });
+        let (euler, _) = quaternion::quaternion_to_euler(q);
+        prop_assert!((euler.roll - angles.roll).abs() < 1e-6);
+        prop_assert!((euler.pitch - angles.pitch).abs() < 1e-6);
+        prop_assert!((euler.yaw - angles.yaw).abs() < 1e-6);
+    }
+
+    #[test]
+    fn euler_to_quaternion_is_unit_length(roll in -10.0f64..10.0, pitch in -10.0f64..10.0, yaw in -10.0f64..10.0) {
+        let (_, q) = quaternion::euler_to_quaternion(EulerAngles { roll, pitch, yaw });
+        let norm = (q.w * q.w + q.x * q.x + q.y * q.y + q.z * q.z).sqrt();
+        prop_assert!((norm - 1.0).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,72 @@
+use chrono::{TimeZone, Utc};
+use computools_core::unixtime;
+use proptest::prelude::*;
+
+#[test]
+fn formats_in_each_form() {
+    assert_eq!(unixtime::unixtime_to_datetime_str_form1(0, "UTC"), "01/01/1970, 12:00:00 AM");
+    assert_eq!(unixtime::unixtime_to_datetime_str_form2(1_700_000_000, "UTC"), "Nov 14, 2023, 10:13:20 PM");
+    assert_eq!(unixtime::unixtime_to_datetime_str_form3(1_700_000_000, "Asia/Seoul"), "2023-11-15T07:13:20");
+    // 알 수 없는 타임존은 기본 문자열
+    assert_eq!(unixtime::unixtime_to_datetime_str_form3(1_700_000_000, "Mars/Olympus"), "1970-01-01T00:00:00");
+}
+
+#[test]
+fn datetime_str_to_unixtime_cases() {
+    assert_eq!(unixtime::datetime_str_to_unixtime("1970-01-01T00:00:00", "UTC"), Some(0));
+    assert_eq!(unixtime::datetime_str_to_unixtime("2024-01-01T09:00:00", "Asia/Seoul"), Some(1_704_067_200));
+    assert_eq!(unixtime::datetime_str_to_unixtime("2024-07-01T12:00:00", "America/New_York"), Some(1_719_849_600));
+    assert_eq!(unixtime::datetime_str_to_unixtime("2024-01-01T12:00:00", "America/New_York"), Some(1_704_128_400));
+
+    // 서머타임 시작 직전 현지 시각은 표준시 오프셋을 사용
+    assert_eq!(unixtime::datetime_str_to_unixtime("2024-03-31T01:30:00", "Europe/Berlin"), Some(1_711_845_000));
+    // 서머타임 전환으로 존재하지 않는 현지 시각
+    assert_eq!(unixtime::datetime_str_to_unixtime("2024-03-31T02:30:00", "Europe/Berlin"), None);
+
+    assert_eq!(unixtime::datetime_str_to_unixtime("2024-01-01 09:00:00", "UTC"), None);
+    assert_eq!(unixtime::datetime_str_to_unixtime("2024-01-01T09:00", "UTC"), None);
+    assert_eq!(unixtime::datetime_str_to_unixtime("2024-02-30T00:00:00", "UTC"), None);
+    assert_eq!(unixtime::datetime_str_to_unixtime("2024-01-01T00:00:00", "Mars/Olympus"), None);
+}
+
+#[test]
+fn parse_unixtime_input_clamps_range() {
+    assert_eq!(unixtime::parse_unixtime_input(" 1700000000 "), 1_700_000_000);
+    assert_eq!(unixtime::parse_unixtime_input("100000000000"), 100_000_000_000);
+    assert_eq!(unixtime::parse_unixtime_input("100000000001"), 0);
+    assert_eq!(unixtime::parse_unixtime_input("-1"), 0);
+    assert_eq!(unixtime::parse_unixtime_input("abc"), 0);
+}
+
+#[test]
+fn normalize_datetime_input_appends_seconds() {
+    assert_eq!(unixtime::normalize_datetime_input("2024-01-01T09:00"), "2024-01-01T09:00:00");
+    assert_eq!(unixtime::normalize_datetime_input("2024-01-01T09:00:30"), "2024-01-01T09:00:30");
+}
+
+#[test]
+fn format_duration_shows_two_largest_units() {
+    assert_eq!(unixtime::format_duration(0), "0s");
+    assert_eq!(unixtime::format_duration(-5), "0s");
+    assert_eq!(unixtime::format_duration(100), "1m 40s");
+    assert_eq!(unixtime::format_duration(90_061), "1d 1h");
+    assert_eq!(unixtime::format_duration(7_200), "2h");
+}
+
+#[test]
+fn timezone_list_labels_offsets() {
+    let now = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+    let zones = unixtime::timezone_list(now);
+    assert!(zones.contains(&("Asia/Seoul (UTC+09:00)".to_string(), 9 * 3600)));
+    assert!(zones.contains(&("Asia/Kolkata (UTC+05:30)".to_string(), 5 * 3600 + 1800)));
+    assert!(zones.contains(&("America/St_Johns (UTC-03:30)".to_string(), -(3 * 3600 + 1800))));
+}
+
+proptest! {
+    // 2000년 이후 서머타임이 없는 타임존만 사용 (전환 구간의 현지 시각은 되돌릴 수 없음)
+    #[test]
+    fn form3_round_trips(timestamp in 946_684_800i64..4_102_444_800, zone in prop::sample::select(vec!["UTC", "Asia/Seoul", "Asia/Kolkata", "Asia/Kathmandu"])) {
+        let text = unixtime::unixtime_to_datetime_str_form3(timestamp, zone);
+        prop_assert_eq!(unixtime::datetime_str_to_unixtime(&text, zone), Some(timestamp));
+    }
+}
@@ -0,0 +1,31 @@
+use computools_core::url;
+use proptest::prelude::*;
+
+#[test]
+fn encode_reserved_and_unicode_characters() {
+    assert_eq!(url::encode_url("a b&c=d/e?f"), "a%20b%26c%3Dd%2Fe%3Ff");
+    assert_eq!(url::encode_url("한"), "%ED%95%9C");
+    assert_eq!(url::encode_url("AZaz09-_.~"), "AZaz09-_.~");
+}
+
+#[test]
+fn decode_percent_sequences() {
+    assert_eq!(url::decode_url("a%20b%26c"), Ok("a b&c".to_string()));
+    assert_eq!(url::decode_url("%ED%95%9C"), Ok("한".to_string()));
+    // '+'는 공백으로 바꾸지 않음
+    assert_eq!(url::decode_url("a+b"), Ok("a+b".to_string()));
+    assert_eq!(url::decode_url("%FF"), Err("Decoding Error".to_string()));
+}
+
+proptest! {
+    #[test]
+    fn encode_then_decode_is_identity(text in "\\PC{0,64}") {
+        prop_assert_eq!(url::decode_url(&url::encode_url(&text)), Ok(text));
+    }
+
+    #[test]
+    fn encoded_output_is_url_safe(text in "\\PC{0,64}") {
+        let encoded = url::encode_url(&text);
+        prop_assert!(encoded.chars().all(|c| c.is_ascii_alphanumeric() || "-_.~%".contains(c)));
+    }
+}
@@ -0,0 +1,19 @@
+use computools_core::uuid;
+
+#[test]
+fn generate_v4_has_version_and_variant_bits() {
+    for _ in 0..32 {
+        let value = uuid::generate_v4();
+        let bytes = value.as_bytes();
+        assert_eq!(value.len(), 36);
+        assert_eq!([bytes[8], bytes[13], bytes[18], bytes[23]], [b'-'; 4]);
+        assert_eq!(bytes[14], b'4');
+        assert!(matches!(bytes[19], b'8' | b'9' | b'a' | b'b'), "{}", value);
+        assert!(value.chars().all(|c| c == '-' || c.is_ascii_digit() || ('a'..='f').contains(&c)));
+    }
+}
+
+#[test]
+fn generate_v4_is_random() {
+    assert_ne!(uuid::generate_v4(), uuid::generate_v4());
+}
@@ -0,0 +1,9 @@
+// 파일 해시 웹 워커 진입점. Trunk가 `data-type="worker"`로 따로 빌드합니다.
+#[path = "../tools/file_hash/worker.rs"]
+mod worker;
+
+use gloo::worker::Registrable;
+
+fn main() {
+    worker::HashWorker::registrar().encoding::<worker::JsCodec>().register();
+}
@@ -0,0 +1,9 @@
+// 키 유도 웹 워커 진입점. Trunk가 `data-type="worker"`로 따로 빌드합니다.
+#[path = "../tools/kdf/worker.rs"]
+mod worker;
+
+use gloo::worker::Registrable;
+
+fn main() {
+    worker::KdfWorker::registrar().register();
+}
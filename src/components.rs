@@ -1,2 +1,4 @@
+pub mod download;
+pub mod json_tree;
 pub mod thumbnail;
 pub mod tool_category;
\ No newline at end of file
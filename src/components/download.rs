@@ -0,0 +1,28 @@
+use wasm_bindgen::JsCast;
+use web_sys::{window, Blob, BlobPropertyBag, HtmlElement, Url};
+
+/// `data`를 `file_name` 파일로 내려받습니다. 텍스트는 `text.as_bytes()`와 "text/plain"으로 넘깁니다.
+pub fn download_bytes(file_name: &str, data: &[u8], mime_type: &str) {
+    let Some(document) = window().and_then(|window| window.document()) else {
+        return;
+    };
+    let array = js_sys::Uint8Array::from(data);
+    let parts = js_sys::Array::of1(&array);
+    let options = BlobPropertyBag::new();
+    options.set_type(mime_type);
+    let Ok(blob) = Blob::new_with_u8_array_sequence_and_options(&parts, &options) else {
+        return;
+    };
+    let Ok(url) = Url::create_object_url_with_blob(&blob) else {
+        return;
+    };
+
+    if let Ok(anchor) = document.create_element("a") {
+        let _ = anchor.set_attribute("href", &url);
+        let _ = anchor.set_attribute("download", file_name);
+        if let Ok(anchor) = anchor.dyn_into::<HtmlElement>() {
+            anchor.click();
+        }
+    }
+    let _ = Url::revoke_object_url(&url);
+}
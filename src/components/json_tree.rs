@@ -0,0 +1,163 @@
+use computools_core::json::{format_value, get_type_icon};
+use std::collections::HashSet;
+use yew::prelude::*;
+
+/// 접고 펼 수 있는 JSON 트리. JSON 도구의 Tree View와 디코딩 결과를 트리로 보여주는 도구에서 씁니다.
+pub struct JsonTree {
+    expanded_nodes: HashSet<String>,
+}
+
+#[derive(Properties, PartialEq)]
+pub struct Props {
+    pub value: serde_json::Value,
+    /// 처음에 펼쳐 둘 깊이 (0이면 모두 접힘)
+    #[prop_or_default]
+    pub expand_depth: usize,
+}
+
+pub enum Msg {
+    ToggleNode(String),
+}
+
+impl Component for JsonTree {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        let mut expanded_nodes = HashSet::new();
+        Self::collect_paths("root", &ctx.props().value, ctx.props().expand_depth, &mut expanded_nodes);
+        Self { expanded_nodes }
+    }
+
+    fn update(&mut self, _ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            Msg::ToggleNode(path) => {
+                if self.expanded_nodes.contains(&path) {
+                    self.expanded_nodes.remove(&path);
+                } else {
+                    self.expanded_nodes.insert(path);
+                }
+                true
+            }
+        }
+    }
+
+    fn changed(&mut self, ctx: &Context<Self>, _old_props: &Self::Properties) -> bool {
+        // 사용자가 펼친 노드는 유지하고, 새 값의 기본 펼침 노드만 추가
+        Self::collect_paths("root", &ctx.props().value, ctx.props().expand_depth, &mut self.expanded_nodes);
+        true
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        html! {
+            <div class="tree-view-container" style="max-height: 400px; overflow-y: auto; font-family: monospace; font-size: 12px;">
+                { self.render_tree_node("root", &ctx.props().value, ctx) }
+            </div>
+        }
+    }
+}
+
+impl JsonTree {
+    fn child_path(path: &str, key: &str) -> String {
+        if path == "root" {
+            key.to_string()
+        } else {
+            format!("{}.{}", path, key)
+        }
+    }
+
+    fn collect_paths(path: &str, value: &serde_json::Value, depth: usize, paths: &mut HashSet<String>) {
+        if depth == 0 {
+            return;
+        }
+        match value {
+            serde_json::Value::Object(obj) => {
+                paths.insert(path.to_string());
+                for (k, v) in obj {
+                    Self::collect_paths(&Self::child_path(path, k), v, depth - 1, paths);
+                }
+            }
+            serde_json::Value::Array(arr) => {
+                paths.insert(path.to_string());
+                for (i, v) in arr.iter().enumerate() {
+                    Self::collect_paths(&format!("{}[{}]", path, i), v, depth - 1, paths);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn render_tree_node(&self, path: &str, value: &serde_json::Value, ctx: &Context<Self>) -> Html {
+        match value {
+            serde_json::Value::Object(obj) => {
+                let is_expanded = self.expanded_nodes.contains(path);
+                let path_string = path.to_string();
+                let toggle_callback = ctx.link().callback(move |_| Msg::ToggleNode(path_string.clone()));
+
+                html! {
+                    <div class="tree-node" style="margin-left: 20px;">
+                        <div class="node-header" style="display: flex; align-items: center; cursor: pointer; padding: 2px 0;" onclick={toggle_callback}>
+                            <span style="margin-right: 5px;">
+                                { if is_expanded { "📁" } else { "📂" } }
+                            </span>
+                            <span style="font-weight: bold; color: var(--color-fourth);">{ path }</span>
+                            <span style="margin-left: 10px; color: var(--color-subfont); font-size: 11px;">
+                                { format!("({} items)", obj.len()) }
+                            </span>
+                        </div>
+                        if is_expanded {
+                            <div class="node-children">
+                                { for obj.iter().map(|(k, v)| self.render_tree_node(&Self::child_path(path, k), v, ctx)) }
+                            </div>
+                        }
+                    </div>
+                }
+            }
+            serde_json::Value::Array(arr) => {
+                let is_expanded = self.expanded_nodes.contains(path);
+                let path_string = path.to_string();
+                let toggle_callback = ctx.link().callback(move |_| Msg::ToggleNode(path_string.clone()));
+
+                html! {
+                    <div class="tree-node" style="margin-left: 20px;">
+                        <div class="node-header" style="display: flex; align-items: center; cursor: pointer; padding: 2px 0;" onclick={toggle_callback}>
+                            <span style="margin-right: 5px;">
+                                { if is_expanded { "📋" } else { "📄" } }
+                            </span>
+                            <span style="font-weight: bold; color: var(--color-fourth);">{ path }</span>
+                            <span style="margin-left: 10px; color: var(--color-subfont); font-size: 11px;">
+                                { format!("({} items)", arr.len()) }
+                            </span>
+                        </div>
+                        if is_expanded {
+                            <div class="node-children">
+                                { for arr.iter().enumerate().map(|(i, v)| {
+                                    let child_path = format!("{}[{}]", path, i);
+                                    self.render_tree_node(&child_path, v, ctx)
+                                }) }
+                            </div>
+                        }
+                    </div>
+                }
+            }
+            _ => {
+                html! {
+                    <div class="tree-node" style="margin-left: 20px;">
+                        <div class="node-item" style="display: flex; align-items: center; padding: 2px 0;">
+                            <span style="margin-right: 5px;">
+                                { get_type_icon(value) }
+                            </span>
+                            <span style="font-weight: bold; color: var(--color-font);">{ path }</span>
+                            <span style="margin-left: 10px; color: var(--color-subfont);">
+                                { ":" }
+                            </span>
+                            <span style="margin-left: 5px; color: var(--color-font); word-break: break-all;">
+                                { format_value(value) }
+                            </span>
+                        </div>
+                    </div>
+                }
+            }
+        }
+    }
+}
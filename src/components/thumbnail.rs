@@ -44,6 +44,10 @@ impl Component for Thumbnail {
             "html" => "HTML EntityConverter",
             "url" => "URL Converter",
             "uuid" => "UUID Generator",
+            "kdf" => "Password Hash & KDF Tool",
+            "jwt" => "JWT Decoder & Verifier",
+            "cipher" => "Encryption & Decryption",
+            "pki" => "PEM / X.509 Decoder",
             _ => "Content not found",
         };
 
@@ -80,14 +80,46 @@ impl ToolCategoryManager {
                 tags: vec!["file", "hash", "md5", "sha1", "sha256", "sha512", "integrity", "checksum"].iter().map(|s| s.to_string()).collect(),
                 icon: "fa-solid fa-file-shield".to_string(),
             },
+            ToolInfo {
+                route_name: "kdf".to_string(),
+                display_name: "Password Hash & KDF Tool".to_string(),
+                description: "Derive keys with PBKDF2, HKDF, scrypt and Argon2id, and verify bcrypt/PHC password hashes".to_string(),
+                category: ToolCategory::SecurityHash,
+                tags: vec!["kdf", "pbkdf2", "hkdf", "scrypt", "argon2", "bcrypt", "password", "hash", "phc"].iter().map(|s| s.to_string()).collect(),
+                icon: "fa-solid fa-key".to_string(),
+            },
+            ToolInfo {
+                route_name: "jwt".to_string(),
+                display_name: "JWT Decoder & Verifier".to_string(),
+                description: "Decode JSON Web Tokens, check exp/iat/nbf dates, verify and sign HS256/384/512".to_string(),
+                category: ToolCategory::SecurityHash,
+                tags: vec!["jwt", "token", "json", "base64url", "hmac", "hs256", "signature", "decode"].iter().map(|s| s.to_string()).collect(),
+                icon: "fa-solid fa-ticket".to_string(),
+            },
+            ToolInfo {
+                route_name: "cipher".to_string(),
+                display_name: "Encryption & Decryption (AES / ChaCha20)".to_string(),
+                description: "Encrypt and decrypt text or files with AES-GCM/CBC/CTR and ChaCha20-Poly1305".to_string(),
+                category: ToolCategory::SecurityHash,
+                tags: vec!["aes", "gcm", "cbc", "ctr", "chacha20", "poly1305", "encrypt", "decrypt", "cipher"].iter().map(|s| s.to_string()).collect(),
+                icon: "fa-solid fa-lock".to_string(),
+            },
+            ToolInfo {
+                route_name: "pki".to_string(),
+                display_name: "PEM / X.509 Certificate Decoder".to_string(),
+                description: "Decode PEM/DER certificates and RSA, EC and Ed25519 keys with fingerprints".to_string(),
+                category: ToolCategory::SecurityHash,
+                tags: vec!["pem", "der", "x509", "certificate", "ssl", "tls", "rsa", "ec", "ed25519", "fingerprint"].iter().map(|s| s.to_string()).collect(),
+                icon: "fa-solid fa-certificate".to_string(),
+            },
 
             // Text & Encoding 카테고리
             ToolInfo {
                 route_name: "base64".to_string(),
                 display_name: "Base64 Encoder/Decoder".to_string(),
-                description: "Encode and decode Base64 data with support for files and URLs".to_string(),
+                description: "Encode and decode Base64, Base32, Base58, Ascii85, Z85, Base45 and email encodings (quoted-printable, uuencode, yEnc, MIME headers)".to_string(),
                 category: ToolCategory::TextEncoding,
-                tags: vec!["base64", "encode", "decode", "transmission", "data", "file", "url"].iter().map(|s| s.to_string()).collect(),
+                tags: vec!["base64", "base32", "base58", "ascii85", "z85", "base45", "quoted-printable", "uuencode", "yenc", "mime", "email", "encode", "decode", "transmission", "data", "file", "url"].iter().map(|s| s.to_string()).collect(),
                 icon: "fa-solid fa-key".to_string(),
             },
             ToolInfo {
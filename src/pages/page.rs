@@ -1,6 +1,7 @@
 use crate::tools::{
     ascii::ToolAscii, base::ToolBase, base64::ToolBase64, crc::ToolCrc, json::ToolJson, quaternion::ToolQuaternion,
     unixtime::ToolUnixtime, file_hash::ToolFileHash, html::ToolHtml, url::ToolUrl, uuid::ToolUuid,
+    kdf::ToolKdf, jwt::ToolJwt, cipher::ToolCipher, pki::ToolPki,
 };
 use log::info;
 use web_sys::window;
@@ -44,6 +45,10 @@ impl Component for Page {
             "html" => html! { <ToolHtml /> },
             "url" => html! { <ToolUrl /> },
             "uuid" => html! { <ToolUuid /> },
+            "kdf" => html! { <ToolKdf /> },
+            "jwt" => html! { <ToolJwt /> },
+            "cipher" => html! { <ToolCipher /> },
+            "pki" => html! { <ToolPki /> },
             _ => html! { <p>{ "Content not found" }</p> },
         };
         self.add_item(title.as_str());
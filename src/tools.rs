@@ -8,4 +8,8 @@ pub mod base;
 pub mod file_hash;
 pub mod html;
 pub mod url;
-pub mod uuid;
\ No newline at end of file
+pub mod uuid;
+pub mod kdf;
+pub mod jwt;
+pub mod cipher;
+pub mod pki;
\ No newline at end of file
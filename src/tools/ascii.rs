@@ -1,39 +1,12 @@
+use computools_core::ascii::{
+    bytes_to_text, convert_text_to_ascii, parse_input, AsciiMode, BinaryStyle, FormatStyles, HexStyle,
+    OctalStyle,
+};
 use wasm_bindgen_futures::JsFuture;
 use web_sys::{window, HtmlInputElement, Storage};
 use yew::prelude::*;
 use crate::components::tool_category::ToolCategoryManager;
 
-#[derive(Clone, PartialEq)]
-pub enum AsciiMode {
-    Decimal,
-    Hex,
-    Binary, // 새로운 바이너리 모드 추가
-    Octal, // 8진수 모드 추가
-}
-
-#[derive(Clone, PartialEq)]
-pub enum HexStyle {
-    WithPrefix,     // 0x48
-    ShortPrefix,    // x48
-    NoPrefix,       // 48
-    EscapeSequence, // \x48
-}
-
-#[derive(Clone, PartialEq)]
-pub enum BinaryStyle {
-    WithPrefix,    // 0b01001000
-    ShortPrefix,   // b01001000
-    NoPrefix,      // 01001000
-}
-
-#[derive(Clone, PartialEq)]
-pub enum OctalStyle {
-    WithPrefix,     // 0o110
-    ShortPrefix,    // o110
-    NoPrefix,       // 110
-    EscapeSequence, // \110
-}
-
 pub struct ToolAscii {
     input_ascii: String,
     output_text: String,
@@ -80,34 +53,10 @@ impl Component for ToolAscii {
                     return true;
                 }
 
-                let parsed_bytes = match self.mode {
-                    AsciiMode::Decimal => self.parse_decimal_input(&value),
-                    AsciiMode::Hex => self.parse_hex_input(&value),
-                    AsciiMode::Binary => self.parse_binary_input(&value),
-                    AsciiMode::Octal => self.parse_octal_input(&value),
-                };
-
-                match parsed_bytes {
+                match parse_input(&value, &self.mode) {
                     Ok(bytes) => {
                         // Extended ASCII 지원 (0-255)
-                        match String::from_utf8(bytes.clone()) {
-                            Ok(text) => {
-                            self.output_text = text;
-                            }
-                            Err(_) => {
-                                // UTF-8로 변환할 수 없는 경우, 각 바이트를 문자로 변환 시도
-                                let mut result = String::new();
-                                for byte in bytes {
-                                    if byte <= 127 {
-                                        result.push(char::from(byte));
-                        } else {
-                                        // Extended ASCII (128-255)는 물음표로 표시
-                                        result.push('?');
-                                    }
-                                }
-                                self.output_text = result;
-                            }
-                        }
+                        self.output_text = bytes_to_text(bytes);
                     }
                     Err(err) => {
                         self.error_message = Some(err);
@@ -939,462 +888,12 @@ impl ToolAscii {
     }
 
     fn convert_text_to_ascii(&self, input_bytes: &[u8]) -> String {
-        match self.mode {
-            AsciiMode::Decimal => {
-                input_bytes
-                    .iter()
-                    .map(|byte| byte.to_string())
-                    .collect::<Vec<String>>()
-                    .join(" ")
-            }
-            AsciiMode::Hex => {
-                input_bytes
-                    .iter()
-                    .map(|byte| match self.hex_style {
-                        HexStyle::WithPrefix => format!("0x{:02X}", byte),
-                        HexStyle::ShortPrefix => format!("x{:02X}", byte),
-                        HexStyle::NoPrefix => format!("{:02X}", byte),
-                        HexStyle::EscapeSequence => format!("\\x{:02X}", byte),
-                    })
-                    .collect::<Vec<String>>()
-                    .join(" ")
-            }
-            AsciiMode::Binary => {
-                input_bytes
-                    .iter()
-                    .map(|byte| match self.binary_style {
-                        BinaryStyle::WithPrefix => format!("0b{:08b}", byte),
-                        BinaryStyle::ShortPrefix => format!("b{:08b}", byte),
-                        BinaryStyle::NoPrefix => format!("{:08b}", byte),
-                    })
-                    .collect::<Vec<String>>()
-                    .join(" ")
-            }
-            AsciiMode::Octal => {
-                input_bytes
-                    .iter()
-                    .map(|byte| match self.octal_style {
-                        OctalStyle::WithPrefix => format!("0o{:03o}", byte),
-                        OctalStyle::ShortPrefix => format!("o{:03o}", byte),
-                        OctalStyle::NoPrefix => format!("{:03o}", byte),
-                        OctalStyle::EscapeSequence => format!("\\{:03o}", byte),
-                    })
-                    .collect::<Vec<String>>()
-                    .join(" ")
-            }
-        }
-    }
-
-    fn parse_hex_input(&self, input: &str) -> Result<Vec<u8>, String> {
-        if input.trim().is_empty() {
-            return Ok(Vec::new());
-        }
-
-        let mut result = Vec::new();
-        let mut current_number = String::new();
-        let mut chars = input.chars().peekable();
-
-        while let Some(&c) = chars.peek() {
-            match c {
-                // 공백 문자 처리
-                ' ' | '\n' | '\t' | '\r' => {
-                    if !current_number.is_empty() {
-                        result.push(self.parse_hex_string(&current_number)?);
-                        current_number.clear();
-                    }
-                    chars.next();
-                }
-                // "0x" 또는 "\x" 접두사 처리
-                '0' => {
-                    chars.next();
-                    if let Some(&next) = chars.peek() {
-                        if next == 'x' || next == 'X' {
-                            if !current_number.is_empty() {
-                                result.push(self.parse_hex_string(&current_number)?);
-                                current_number.clear();
-                            }
-                            chars.next(); // 'x' 건너뛰기
-                            current_number = self.collect_hex_digits(&mut chars)?;
-                            if !current_number.is_empty() {
-                                result.push(self.parse_hex_string(&current_number)?);
-                                current_number.clear();
-                            }
-                        } else {
-                            current_number.push('0');
-                        }
-                    } else {
-                        current_number.push('0');
-                    }
-                }
-                '\\' => {
-                    chars.next();
-                    if let Some(&next) = chars.peek() {
-                        if next == 'x' || next == 'X' {
-                            if !current_number.is_empty() {
-                                result.push(self.parse_hex_string(&current_number)?);
-                                current_number.clear();
-                            }
-                            chars.next(); // 'x' 건너뛰기
-                            current_number = self.collect_hex_digits(&mut chars)?;
-                            if !current_number.is_empty() {
-                                result.push(self.parse_hex_string(&current_number)?);
-                                current_number.clear();
-                            }
-                        } else {
-                            return Err("Invalid escape sequence: expected 'x' after '\\'".to_string());
-                        }
-                    } else {
-                        return Err("Incomplete escape sequence: unexpected end of input after '\\'".to_string());
-                    }
-                }
-                'x' | 'X' => {
-                    if !current_number.is_empty() {
-                        result.push(self.parse_hex_string(&current_number)?);
-                        current_number.clear();
-                    }
-                    chars.next(); // 'x' 건너뛰기
-                    current_number = self.collect_hex_digits(&mut chars)?;
-                    if !current_number.is_empty() {
-                        result.push(self.parse_hex_string(&current_number)?);
-                        current_number.clear();
-                    }
-                }
-                // 16진수 숫자 수집
-                _ => {
-                    if c.is_ascii_hexdigit() {
-                        current_number.push(chars.next().unwrap());
-                    } else {
-                        return Err(format!("Invalid character '{}' in hexadecimal input", c));
-                    }
-
-                    // 두 자리가 모이면 바이트로 변환
-                    if current_number.len() == 2 {
-                        result.push(self.parse_hex_string(&current_number)?);
-                        current_number.clear();
-                    }
-                }
-            }
-        }
-
-        // 남은 숫자 처리
-        if !current_number.is_empty() {
-            // 한 자리 숫자인 경우 앞에 0을 붙임
-            if current_number.len() == 1 {
-                current_number.insert(0, '0');
-            }
-            result.push(self.parse_hex_string(&current_number)?);
-        }
-
-        if result.is_empty() {
-            return Err("No valid hex values found in input".to_string());
-        }
-
-        Ok(result)
-    }
-
-    fn collect_hex_digits(
-        &self,
-        chars: &mut std::iter::Peekable<std::str::Chars>,
-    ) -> Result<String, String> {
-        let mut hex_str = String::new();
-
-        while let Some(&c) = chars.peek() {
-            if c.is_ascii_hexdigit() {
-                hex_str.push(chars.next().unwrap());
-                if hex_str.len() == 2 {
-                    break;
-                }
-            } else {
-                break;
-            }
-        }
-
-        if hex_str.is_empty() {
-            return Err("Expected hexadecimal digits after prefix".to_string());
-        }
-
-        // 한 자리 숫자인 경우 앞에 0을 붙임
-        if hex_str.len() == 1 {
-            hex_str.insert(0, '0');
-        }
-
-        Ok(hex_str)
-    }
-
-    fn parse_hex_string(&self, hex_str: &str) -> Result<u8, String> {
-        u8::from_str_radix(hex_str, 16).map_err(|_| format!("Invalid hexadecimal value: '{}'", hex_str))
-    }
-
-    fn parse_decimal_input(&self, input: &str) -> Result<Vec<u8>, String> {
-        if input.trim().is_empty() {
-            return Ok(Vec::new());
-        }
-
-        input
-            .split_whitespace()
-            .map(|s| {
-                s.parse::<u16>()
-                    .map_err(|_| format!("Invalid decimal number: '{}'", s))
-                    .and_then(|num| {
-                        if num > 255 {
-                            Err(format!("Decimal value {} exceeds maximum ASCII range (0-255)", num))
-                        } else {
-                            Ok(num as u8)
-                        }
-                    })
-            })
-            .collect()
-    }
-
-    fn parse_binary_input(&self, input: &str) -> Result<Vec<u8>, String> {
-        if input.trim().is_empty() {
-            return Ok(Vec::new());
-        }
-
-        let mut result = Vec::new();
-        let mut current_number = String::new();
-        let mut chars = input.chars().peekable();
-
-        while let Some(&c) = chars.peek() {
-            match c {
-                // 공백 문자 처리
-                ' ' | '\n' | '\t' | '\r' => {
-                    if !current_number.is_empty() {
-                        result.push(self.parse_binary_string(&current_number)?);
-                        current_number.clear();
-                    }
-                    chars.next();
-                }
-                // "0b" 접두사 처리
-                '0' => {
-                    chars.next();
-                    if let Some(&next) = chars.peek() {
-                        if next == 'b' || next == 'B' {
-                            if !current_number.is_empty() {
-                                result.push(self.parse_binary_string(&current_number)?);
-                                current_number.clear();
-                            }
-                            chars.next(); // 'b' 건너뛰기
-                            current_number = self.collect_binary_digits(&mut chars)?;
-                            if !current_number.is_empty() {
-                                result.push(self.parse_binary_string(&current_number)?);
-                                current_number.clear();
-                            }
-                        } else {
-                            // 일반 '0' 이진수 숫자
-                            current_number.push('0');
-                            if current_number.len() == 8 {
-                                result.push(self.parse_binary_string(&current_number)?);
-                                current_number.clear();
-                            }
-                        }
-                    } else {
-                        // 마지막 문자가 '0'인 경우
-                        current_number.push('0');
-                        if current_number.len() == 8 {
-                            result.push(self.parse_binary_string(&current_number)?);
-                            current_number.clear();
-                        }
-                    }
-                }
-                'b' | 'B' => {
-                    if !current_number.is_empty() {
-                        result.push(self.parse_binary_string(&current_number)?);
-                        current_number.clear();
-                    }
-                    chars.next(); // 'b' 건너뛰기
-                    current_number = self.collect_binary_digits(&mut chars)?;
-                    if !current_number.is_empty() {
-                        result.push(self.parse_binary_string(&current_number)?);
-                        current_number.clear();
-                    }
-                }
-                // 이진수 숫자 수집
-                '1' => {
-                    current_number.push(chars.next().unwrap());
-                    // 8비트가 모이면 바이트로 변환
-                    if current_number.len() == 8 {
-                        result.push(self.parse_binary_string(&current_number)?);
-                        current_number.clear();
-                    }
-                }
-                _ => {
-                    return Err(format!("Invalid character '{}' in binary input. Only 0 and 1 are allowed.", c));
-                }
-            }
-        }
-
-        // 남은 숫자 처리
-        if !current_number.is_empty() {
-            if current_number.len() > 8 {
-                return Err(format!("Binary sequence '{}' is longer than 8 bits", current_number));
-            }
-            result.push(self.parse_binary_string(&current_number)?);
-        }
-
-        if result.is_empty() {
-            return Err("No valid binary values found in input".to_string());
-        }
-
-        Ok(result)
-    }
-
-    fn collect_binary_digits(
-        &self,
-        chars: &mut std::iter::Peekable<std::str::Chars>,
-    ) -> Result<String, String> {
-        let mut binary_str = String::new();
-
-        while let Some(&c) = chars.peek() {
-            if c == '0' || c == '1' {
-                binary_str.push(chars.next().unwrap());
-                if binary_str.len() == 8 {
-                    break;
-                }
-            } else {
-                break;
-            }
-        }
-
-        if binary_str.is_empty() {
-            return Err("Expected binary digits after prefix".to_string());
-        }
-
-        Ok(binary_str)
-    }
-
-    fn parse_binary_string(&self, binary_str: &str) -> Result<u8, String> {
-        if binary_str.len() > 8 {
-            return Err(format!("Binary sequence '{}' exceeds 8 bits", binary_str));
-        }
-        u8::from_str_radix(binary_str, 2).map_err(|_| format!("Invalid binary value: '{}'", binary_str))
-    }
-
-    fn parse_octal_input(&self, input: &str) -> Result<Vec<u8>, String> {
-        if input.trim().is_empty() {
-            return Ok(Vec::new());
-        }
-
-        let mut result = Vec::new();
-        let mut current_number = String::new();
-        let mut chars = input.chars().peekable();
-
-        while let Some(&c) = chars.peek() {
-            match c {
-                // 공백 문자 처리
-                ' ' | '\n' | '\t' | '\r' => {
-                    if !current_number.is_empty() {
-                        result.push(self.parse_octal_string(&current_number)?);
-                        current_number.clear();
-                    }
-                    chars.next();
-                }
-                // "\NNN" escape sequence 처리
-                '\\' => {
-                    if !current_number.is_empty() {
-                        result.push(self.parse_octal_string(&current_number)?);
-                        current_number.clear();
-                    }
-                    chars.next(); // '\' 건너뛰기
-                    current_number = self.collect_octal_digits(&mut chars)?;
-                    if !current_number.is_empty() {
-                        result.push(self.parse_octal_string(&current_number)?);
-                        current_number.clear();
-                    }
-                }
-                // "0o" 접두사 처리
-                '0' => {
-                    chars.next();
-                    if let Some(&next) = chars.peek() {
-                        if next == 'o' || next == 'O' {
-                            if !current_number.is_empty() {
-                                result.push(self.parse_octal_string(&current_number)?);
-                                current_number.clear();
-                            }
-                            chars.next(); // 'o' 건너뛰기
-                            current_number = self.collect_octal_digits(&mut chars)?;
-                            if !current_number.is_empty() {
-                                result.push(self.parse_octal_string(&current_number)?);
-                                current_number.clear();
-                            }
-                        } else {
-                            current_number.push('0');
-                        }
-                    } else {
-                        current_number.push('0');
-                    }
-                }
-                'o' | 'O' => {
-                    if !current_number.is_empty() {
-                        result.push(self.parse_octal_string(&current_number)?);
-                        current_number.clear();
-                    }
-                    chars.next(); // 'o' 건너뛰기
-                    current_number = self.collect_octal_digits(&mut chars)?;
-                    if !current_number.is_empty() {
-                        result.push(self.parse_octal_string(&current_number)?);
-                        current_number.clear();
-                    }
-                }
-                // 8진수 숫자 수집
-                '0'..='7' => {
-                    current_number.push(chars.next().unwrap());
-                    // 3자리가 모이면 바이트로 변환 (8진수 377 = 255)
-                    if current_number.len() == 3 {
-                        result.push(self.parse_octal_string(&current_number)?);
-                        current_number.clear();
-                    }
-                }
-                _ => {
-                    return Err(format!("Invalid character '{}' in octal input. Only 0-7 are allowed.", c));
-                }
-            }
-        }
-
-        // 남은 숫자 처리
-        if !current_number.is_empty() {
-            if current_number.len() > 3 {
-                return Err(format!("Octal sequence '{}' is longer than 3 digits", current_number));
-            }
-            result.push(self.parse_octal_string(&current_number)?);
-        }
-
-        if result.is_empty() {
-            return Err("No valid octal values found in input".to_string());
-        }
-
-        Ok(result)
-    }
-
-    fn collect_octal_digits(
-        &self,
-        chars: &mut std::iter::Peekable<std::str::Chars>,
-    ) -> Result<String, String> {
-        let mut octal_str = String::new();
-
-        while let Some(&c) = chars.peek() {
-            if c >= '0' && c <= '7' {
-                octal_str.push(chars.next().unwrap());
-                if octal_str.len() == 3 {
-                    break;
-                }
-            } else {
-                break;
-            }
-        }
-
-        if octal_str.is_empty() {
-            return Err("Expected octal digits after prefix".to_string());
-        }
-
-        Ok(octal_str)
-    }
-
-    fn parse_octal_string(&self, octal_str: &str) -> Result<u8, String> {
-        if octal_str.len() > 3 {
-            return Err(format!("Octal sequence '{}' exceeds 3 digits", octal_str));
-        }
-        u8::from_str_radix(octal_str, 8).map_err(|_| format!("Invalid octal value: '{}'", octal_str))
+        let styles = FormatStyles {
+            hex_style: self.hex_style.clone(),
+            binary_style: self.binary_style.clone(),
+            octal_style: self.octal_style.clone(),
+        };
+        convert_text_to_ascii(input_bytes, &self.mode, &styles)
     }
 
     fn render_ascii_table(&self) -> Html {
@@ -1,3 +1,6 @@
+use computools_core::base::{
+    convert_float_to_base, format_integer, parse_float_input, parse_integer_input, validate_input,
+};
 use gloo_timers::callback::Timeout;
 use log::info;
 use std::collections::{HashMap, BTreeMap};
@@ -52,14 +55,14 @@ impl Component for ToolBase {
                 }
 
                 // 입력 검증
-                match self.validate_input(&value, base) {
+                match validate_input(&value, base, self.supports_float) {
                     Ok(_) => {
                         // 유효한 입력인 경우
                         self.error_messages.insert(base, None);
                         
                         if self.supports_float {
                             // 부동소수점 모드
-                            match self.parse_float_input(&value, base) {
+                            match parse_float_input(&value, base) {
                                 Ok(float_value) => {
                                     self.update_all_float_except(float_value, Some(base));
                                 }
@@ -74,28 +77,18 @@ impl Component for ToolBase {
                             }
                         } else {
                             // 정수 모드 (기존 로직)
-                            // 음수 부호 처리
-                            let (is_negative, number_part) = if value.trim().starts_with('-') {
-                                (true, &value.trim()[1..])
-                            } else {
-                                (false, value.trim())
-                            };
-
                             // 유연한 포맷 파싱
-                            if let Ok(cleaned_input) = self.parse_flexible_format(number_part, base) {
-                                // prefix만 입력된 경우 (예: "0b", "0x") - 다른 필드들만 초기화
-                                if cleaned_input.is_empty() {
+                            match parse_integer_input(&value, base) {
+                                Ok(Some(num)) => self.update_all_except(num, Some(base)),
+                                Ok(None) => {
+                                    // prefix만 입력된 경우 (예: "0b", "0x") - 다른 필드들만 초기화
                                     for (other_base, val) in self.bases.iter_mut() {
                                         if *other_base != base {
                                             val.clear();
                                         }
                                     }
-                                } else if let Ok(mut num) = i64::from_str_radix(&cleaned_input, base) {
-                                    if is_negative {
-                                        num = -num;
-                                    }
-                                    self.update_all_except(num, Some(base));
                                 }
+                                Err(_) => {}
                             }
                         }
                     }
@@ -681,435 +674,17 @@ impl ToolBase {
         }
     }
 
-    fn validate_input(&self, input: &str, base: u32) -> Result<(), String> {
-        if input.trim().is_empty() {
-            return Ok(());
-        }
-
-        let input = input.trim();
-        
-        // 음수 부호 처리
-        let (is_negative, number_part) = if input.starts_with('-') {
-            (true, &input[1..])
-        } else {
-            (false, input)
-        };
-
-        if number_part.is_empty() {
-            return Err("Input value is empty.".to_string());
-        }
-
-        // 부동소수점 모드에서 소수점 처리
-        if self.supports_float && number_part.contains('.') {
-            return self.validate_float_input(number_part, base);
-        }
-
-        // 다양한 포맷에서 실제 숫자 부분 추출
-        let cleaned_input = self.parse_flexible_format(number_part, base)?;
-
-        // prefix만 입력된 경우 (예: "0b", "0x", "0o") - 타이핑 중으로 간주
-        if cleaned_input.is_empty() {
-            return Ok(());
-        }
-
-        // 각 진수별 유효한 문자 집합 정의
-        let valid_chars = match base {
-            2 => "01",
-            3 => "012",
-            4 => "0123",
-            5 => "01234",
-            6 => "012345",
-            7 => "0123456",
-            8 => "01234567",
-            9 => "012345678",
-            10 => "0123456789",
-            11 => "0123456789A",
-            12 => "0123456789AB",
-            13 => "0123456789ABC",
-            14 => "0123456789ABCD",
-            15 => "0123456789ABCDE",
-            16 => "0123456789ABCDEF",
-            17..=36 => "0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ",
-            _ => return Err("Unsupported base.".to_string()),
-        };
-
-        let max_digit = if base <= 16 { 
-            &valid_chars[..base as usize] 
-        } else { 
-            &valid_chars[..base as usize] 
-        };
-
-        // 각 문자가 해당 진수에서 유효한지 검사
-        for ch in cleaned_input.chars() {
-            let upper_ch = ch.to_ascii_uppercase();
-            if !max_digit.contains(upper_ch) {
-                return Err(format!(
-                    "'{}' is not a valid character for base {}. Valid characters: {}",
-                    ch,
-                    base,
-                    max_digit
-                ));
-            }
-        }
-
-        // 실제 파싱 테스트
-        match i64::from_str_radix(&cleaned_input, base) {
-            Ok(_) => Ok(()),
-            Err(_) => Err(format!("Cannot convert to base {}.", base)),
-        }
-    }
-
-    fn validate_float_input(&self, input: &str, base: u32) -> Result<(), String> {
-        let parts: Vec<&str> = input.split('.').collect();
-        if parts.len() > 2 {
-            return Err("Multiple decimal points are not allowed.".to_string());
-        }
-
-        let integer_part = parts[0];
-        let fractional_part = if parts.len() == 2 { parts[1] } else { "" };
-
-        // 정수 부분 검증
-        if !integer_part.is_empty() {
-            let cleaned_integer = self.parse_flexible_format(integer_part, base)?;
-            if !cleaned_integer.is_empty() {
-                self.validate_digits(&cleaned_integer, base)?;
-            }
-        }
-
-        // 소수 부분 검증 (prefix 없이)
-        if !fractional_part.is_empty() {
-            self.validate_digits(fractional_part, base)?;
-        }
-
-        Ok(())
-    }
-
-    fn validate_digits(&self, input: &str, base: u32) -> Result<(), String> {
-        let valid_chars = match base {
-            2 => "01",
-            3 => "012",
-            4 => "0123",
-            5 => "01234",
-            6 => "012345",
-            7 => "0123456",
-            8 => "01234567",
-            9 => "012345678",
-            10 => "0123456789",
-            11 => "0123456789A",
-            12 => "0123456789AB",
-            13 => "0123456789ABC",
-            14 => "0123456789ABCD",
-            15 => "0123456789ABCDE",
-            16 => "0123456789ABCDEF",
-            17..=36 => "0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ",
-            _ => return Err("Unsupported base.".to_string()),
-        };
-
-        let max_digit = if base <= 16 { 
-            &valid_chars[..base as usize] 
-        } else { 
-            &valid_chars[..base as usize] 
-        };
-
-        for ch in input.chars() {
-            let upper_ch = ch.to_ascii_uppercase();
-            if !max_digit.contains(upper_ch) {
-                return Err(format!(
-                    "'{}' is not a valid character for base {}. Valid characters: {}",
-                    ch,
-                    base,
-                    max_digit
-                ));
-            }
-        }
-
-        Ok(())
-    }
-
-    fn parse_float_input(&self, input: &str, base: u32) -> Result<f64, String> {
-        let input = input.trim();
-        
-        // 음수 처리
-        let (is_negative, number_part) = if input.starts_with('-') {
-            (true, &input[1..])
-        } else {
-            (false, input)
-        };
-
-        if !number_part.contains('.') {
-            // 정수인 경우
-            let cleaned = self.parse_flexible_format(number_part, base)?;
-            if cleaned.is_empty() {
-                return Ok(0.0);
-            }
-            let int_value = i64::from_str_radix(&cleaned, base)
-                .map_err(|_| format!("Cannot convert to base {}.", base))? as f64;
-            return Ok(if is_negative { -int_value } else { int_value });
-        }
-
-        // 소수점 분리
-        let parts: Vec<&str> = number_part.split('.').collect();
-        if parts.len() != 2 {
-            return Err("Invalid decimal format.".to_string());
-        }
-
-        let integer_part = parts[0];
-        let fractional_part = parts[1];
-
-        // 정수 부분 변환
-        let integer_value = if integer_part.is_empty() {
-            0.0
-        } else {
-            let cleaned = self.parse_flexible_format(integer_part, base)?;
-            if cleaned.is_empty() {
-                0.0
-            } else {
-                i64::from_str_radix(&cleaned, base)
-                    .map_err(|_| format!("Cannot convert integer part to base {}.", base))? as f64
-            }
-        };
-
-        // 소수 부분 변환
-        let fractional_value = if fractional_part.is_empty() {
-            0.0
-        } else {
-            self.convert_fractional_from_base(fractional_part, base)?
-        };
-
-        let result = integer_value + fractional_value;
-        Ok(if is_negative { -result } else { result })
-    }
-
-    fn convert_fractional_from_base(&self, fractional: &str, base: u32) -> Result<f64, String> {
-        let mut result = 0.0;
-        let mut power = 1.0 / base as f64;
-
-        for ch in fractional.chars() {
-            let digit_value = self.char_to_digit(ch)?;
-            if digit_value >= base {
-                return Err(format!("Invalid digit '{}' for base {}.", ch, base));
-            }
-            result += digit_value as f64 * power;
-            power /= base as f64;
-        }
-
-        Ok(result)
-    }
-
-    fn char_to_digit(&self, ch: char) -> Result<u32, String> {
-        match ch.to_ascii_uppercase() {
-            '0'..='9' => Ok(ch as u32 - '0' as u32),
-            'A'..='Z' => Ok(ch.to_ascii_uppercase() as u32 - 'A' as u32 + 10),
-            _ => Err(format!("Invalid character: {}", ch)),
-        }
-    }
-
-    fn convert_float_to_base(&self, value: f64, base: u32) -> String {
-        if value.is_nan() {
-            return "NaN".to_string();
-        }
-        if value.is_infinite() {
-            return if value.is_sign_positive() { "∞" } else { "-∞" }.to_string();
-        }
-
-        let is_negative = value < 0.0;
-        let abs_value = value.abs();
-        
-        let integer_part = abs_value.trunc() as i64;
-        let fractional_part = abs_value.fract();
-
-        // 정수 부분 변환
-        let integer_str = if integer_part == 0 {
-            "0".to_string()
-        } else {
-            self.convert_integer_to_base(integer_part, base)
-        };
-
-        // 소수 부분 변환
-        let fractional_str = if fractional_part == 0.0 {
-            String::new()
-        } else {
-            self.convert_fractional_to_base(fractional_part, base)
-        };
-
-        let result = if fractional_str.is_empty() {
-            integer_str
-        } else {
-            format!("{}.{}", integer_str, fractional_str)
-        };
-
-        if is_negative {
-            format!("-{}", result)
-        } else {
-            result
-        }
-    }
-
-    fn convert_integer_to_base(&self, mut num: i64, base: u32) -> String {
-        if num == 0 {
-            return "0".to_string();
-        }
-
-        let digits = "0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ";
-        let mut result = String::new();
-
-        while num > 0 {
-            let digit = (num % base as i64) as usize;
-            result.insert(0, digits.chars().nth(digit).unwrap());
-            num /= base as i64;
-        }
-
-        result
-    }
-
-    fn convert_fractional_to_base(&self, mut fractional: f64, base: u32) -> String {
-        let digits = "0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ";
-        let mut result = String::new();
-        let mut seen_values = Vec::new();
-
-        for i in 0..self.decimal_precision {
-            if fractional == 0.0 {
-                break;
-            }
-
-            // 반복 소수 감지
-            if seen_values.contains(&fractional) {
-                // 반복 패턴 찾기
-                let repeat_start = seen_values.iter().position(|&x| (x - fractional).abs() < 1e-15).unwrap_or(0);
-                let repeating_part = &result[repeat_start..];
-                if !repeating_part.is_empty() {
-                    return format!("{}({})", &result[..repeat_start], repeating_part);
-                }
-                break;
-            }
-
-            seen_values.push(fractional);
-            fractional *= base as f64;
-            let digit = fractional.trunc() as usize;
-            
-            if digit < base as usize {
-                result.push(digits.chars().nth(digit).unwrap());
-            }
-            
-            fractional = fractional.fract();
-        }
-
-        // 정밀도 한계에 도달한 경우 끝에 ... 추가
-        if fractional != 0.0 && result.len() == self.decimal_precision as usize {
-            result.push_str("...");
-        }
-
-        result
-    }
-
-    fn parse_flexible_format(&self, input: &str, base: u32) -> Result<String, String> {
-        let input = input.trim();
-        
-        if input.is_empty() {
-            return Ok(input.to_string());
-        }
-        
-        match base {
-            2 => {
-                // Binary: 0b101010, b101010, 101010
-                if input.starts_with("0b") || input.starts_with("0B") {
-                    let remaining = &input[2..];
-                    Ok(remaining.to_string())
-                } else if input.starts_with("b") || input.starts_with("B") {
-                    let remaining = &input[1..];
-                    Ok(remaining.to_string())
-                } else {
-                    Ok(input.to_string())
-                }
-            },
-            8 => {
-                // Octal: 0o52, o52, 052, 52, \052
-                if input.starts_with("0o") || input.starts_with("0O") {
-                    let remaining = &input[2..];
-                    Ok(remaining.to_string())
-                } else if input.starts_with("o") || input.starts_with("O") {
-                    let remaining = &input[1..];
-                    Ok(remaining.to_string())
-                } else if input.starts_with("\\") && input.len() > 1 {
-                    // Octal escape sequence: \052
-                    let remaining = &input[1..];
-                    Ok(remaining.to_string())
-                } else if input.starts_with("0") && input.len() > 1 && 
-                         !input.starts_with("0x") && !input.starts_with("0X") && 
-                         !input.starts_with("0b") && !input.starts_with("0B") && 
-                         !input.starts_with("0o") && !input.starts_with("0O") {
-                    // Leading zero for octal (traditional C style)
-                    // But exclude other prefixes like 0x, 0b, 0o
-                    Ok(input[1..].to_string())
-                } else {
-                    Ok(input.to_string())
-                }
-            },
-            16 => {
-                // Hexadecimal: 0x2A, x2A, 2A, \x2A
-                if input.starts_with("0x") || input.starts_with("0X") {
-                    let remaining = &input[2..];
-                    Ok(remaining.to_string())
-                } else if input.starts_with("x") || input.starts_with("X") {
-                    let remaining = &input[1..];
-                    Ok(remaining.to_string())
-                } else if input.starts_with("\\x") || input.starts_with("\\X") {
-                    let remaining = &input[2..];
-                    Ok(remaining.to_string())
-                } else {
-                    Ok(input.to_string())
-                }
-            },
-            _ => {
-                // Other bases: just return as-is
-                Ok(input.to_string())
-            }
-        }
-    }
-
     fn update_all(&mut self, num: i64) {
         self.update_all_except(num, None);
     }
 
     fn update_all_except(&mut self, num: i64, except_base: Option<u32>) {
-        let sign = if num < 0 { "-" } else { "" };
-        
         // 모든 진수에 대해 변환 진행 (except_base 제외)
-        for base in 2..=36 {
-            if let Some(except) = except_base {
-                if base == except {
-                    continue; // 현재 입력 중인 필드는 건드리지 않음
-                }
-            }
-            
-            if let Some(value) = self.bases.get_mut(&base) {
-                // 진수별 서식 지정
-                match base {
-                    10 => *value = format!("{}", num), // 10진수는 그대로 표현
-                    _ => {
-                        // 진수 변환을 위한 문자 집합 (0-9, A-Z)
-                        let digits = "0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ";
-                        let mut result = String::new();
-                        let mut n = num.abs();
-                        
-                        // 숫자가 0인 경우 처리
-                        if n == 0 {
-                            *value = "0".to_string();
-                            continue;
-                        }
-                        
-                        // 진수 변환 알고리즘
-                        while n > 0 {
-                            let digit = (n % base as i64) as usize;
-                            result.insert(0, digits.chars().nth(digit).unwrap());
-                            n /= base as i64;
-                        }
-                        
-                        // 음수인 경우 부호 추가
-                        *value = format!("{}{}", sign, result);
-                    }
-                }
+        for (base, value) in self.bases.iter_mut() {
+            if Some(*base) == except_base {
+                continue; // 현재 입력 중인 필드는 건드리지 않음
             }
+            *value = format_integer(num, *base);
         }
     }
 
@@ -1123,7 +698,7 @@ impl ToolBase {
             }
             
             // convert_float_to_base 호출을 먼저 수행하여 borrowing 충돌 방지
-            let converted_value = self.convert_float_to_base(float_value, base);
+            let converted_value = convert_float_to_base(float_value, base, self.decimal_precision);
             
             if let Some(field_value) = self.bases.get_mut(&base) {
                 *field_value = converted_value;
@@ -1,32 +1,21 @@
-use base64::{decode, encode};
+use computools_core::base64::{
+    create_data_url, format_chunk, format_file_size, format_output,
+    get_detailed_base64_error, get_file_icon, is_chunkable_format, parse_data_url,
+    validate_base64_input, Base64Mode, Base64StreamDecoder, FileInfo, OutputFormat,
+};
+use computools_core::codec::Codec;
+use computools_core::file_type::{detect_file_type, FileType};
+use computools_core::preview::{decode_text, hex_dump_page, hex_dump_pages, TextEncoding, HEX_DUMP_PAGE_SIZE};
 use gloo_file::futures::read_as_bytes;
 use gloo_file::File as GlooFile;
 use gloo_timers;
 use js_sys;
 use wasm_bindgen::JsCast;
 use wasm_bindgen_futures::JsFuture;
-use web_sys::{window, HtmlInputElement, Event, FileList, Blob, BlobPropertyBag, Url, Document, Element, HtmlElement, MouseEvent, DragEvent, Storage};
+use web_sys::{window, HtmlInputElement, Event, FileList, Blob, BlobPropertyBag, Url, Document, HtmlElement, MouseEvent, DragEvent, Storage};
 use yew::prelude::*;
 use crate::components::tool_category::ToolCategoryManager;
 
-#[derive(Clone, PartialEq)]
-pub enum Base64Mode {
-    Standard,   // RFC 4648 표준 Base64
-    UrlSafe,    // URL-safe Base64 (+ → -, / → _)
-    NoPadding,  // 패딩 없는 Base64
-}
-
-#[derive(Clone, PartialEq)]
-pub enum OutputFormat {
-    Continuous,     // 연속된 문자열
-    LineBreaks76,   // 76자마다 줄바꿈 (MIME)
-    LineBreaks64,   // 64자마다 줄바꿈
-    Chunks4,        // 4자마다 공백
-    Chunks8,        // 8자마다 공백
-    DataUrlImg,     // data:image/[type];base64,[data] for <img>
-    DataUrlCss,     // url(data:image/[type];base64,[data]) for CSS
-}
-
 pub struct ToolBase64 {
     input_string: String,
     output_base64: String,
@@ -34,7 +23,7 @@ pub struct ToolBase64 {
     output_string: String,
     convert: bool,
     error_message: Option<String>, // 에러 메시지 추가
-    base64_mode: Base64Mode,
+    codec: Codec,
     output_format: OutputFormat,
     // 파일 업로드 관련
     uploaded_file: Option<GlooFile>,
@@ -48,19 +37,19 @@ pub struct ToolBase64 {
     // 포맷팅 관련
     formatting_state: Option<FormattingState>,
     is_formatting: bool,
-    // 디코딩된 이미지 관련
+    // 디코딩 결과 미리보기 관련
     decoded_image_data: Option<String>, // Data URL for image preview
-    decoded_image_mime: Option<String>, // MIME type of decoded image
+    decoded_mime: Option<String>, // MIME type of decoded data (Data URL 또는 시그니처)
     decoded_binary_data: Option<Vec<u8>>, // Raw binary data
+    decoded_file_type: Option<FileType>, // 시그니처로 판별한 디코딩 결과 종류
+    decoded_text_encoding: Option<TextEncoding>, // 텍스트로 읽힌 경우의 인코딩
+    decoded_object_url: Option<String>, // PDF/오디오/비디오 미리보기용 Blob URL
+    hex_dump_page: usize, // 헥스 덤프 현재 페이지
+    uploaded_file_type: Option<FileType>, // 시그니처로 판별한 업로드 파일 종류
     // 드래그 앤 드롭 관련
     is_drag_over: bool, // 드래그 오버 상태
-}
-
-#[derive(Clone, PartialEq)]
-pub struct FileInfo {
-    pub name: String,
-    pub size: usize,
-    pub mime_type: String,
+    // 디코딩할 Base64 파일
+    decode_file_info: Option<FileInfo>,
 }
 
 #[derive(Clone)]
@@ -70,6 +59,10 @@ pub struct ProcessingState {
     pub total_chunks: usize,
     pub chunk_size: usize,
     pub result: String,
+    // 디코딩일 때만 Some (결과는 decoded에 쌓임)
+    pub decoder: Option<Base64StreamDecoder>,
+    pub decoded: Vec<u8>,
+    pub data_url_mime: Option<String>,
 }
 
 #[derive(Clone)]
@@ -88,7 +81,7 @@ pub enum Msg {
     UpdateBase64(String),
     Convert,
     CopyToClipboard(String),
-    ModeChanged(Base64Mode),
+    ModeChanged(Codec),
     FormatChanged(OutputFormat),
     FileSelected(Vec<GlooFile>),
     FileProcessed(Vec<u8>, FileInfo),
@@ -96,271 +89,84 @@ pub enum Msg {
     StartChunkedProcessing(Vec<u8>, FileInfo),
     ProcessNextChunk,
     ChunkedProcessingComplete(String),
+    DecodeFileSelected(Vec<GlooFile>),
+    DecodeFileLoaded(Vec<u8>, FileInfo),
+    StartChunkedDecoding(Vec<u8>, Option<String>),
+    ChunkedDecodingComplete(Vec<u8>, Option<String>),
+    CancelProcessing,
     StartChunkedFormatting(String, OutputFormat, Option<FileInfo>),
     ProcessNextFormatChunk,
     FormattingComplete(String),
-    DownloadDecodedImage,
+    DownloadDecoded,
+    HexDumpPage(usize),
     DragOver,
     DragLeave,
     FileDrop(Vec<GlooFile>),
 }
 
 impl ToolBase64 {
-    fn encode_with_mode(&self, input: &str) -> String {
-        let encoded = match self.base64_mode {
-            Base64Mode::Standard => encode(input),
-            Base64Mode::UrlSafe => {
-                // URL-safe Base64: + → -, / → _
-                encode(input)
-                    .chars()
-                    .map(|c| match c {
-                        '+' => '-',
-                        '/' => '_',
-                        other => other,
-                    })
-                    .collect()
-            }
-            Base64Mode::NoPadding => {
-                // 패딩 제거
-                encode(input).trim_end_matches('=').to_string()
-            }
-        };
-
-        self.format_output_with_file_info(&encoded, None)
+    fn encode_with_mode(&self, input: &str) -> Result<String, String> {
+        let encoded = self.codec.encode(input.as_bytes())?;
+        Ok(self.format_output_with_file_info(&encoded, None))
     }
 
     fn decode_with_mode(&self, input: &str) -> Result<Vec<u8>, String> {
-        // 포맷팅 제거 (공백, 줄바꿈 등)
-        let cleaned_input = input
-            .chars()
-            .filter(|c| !c.is_whitespace())
-            .collect::<String>();
-
-        let standardized_input = match self.base64_mode {
-            Base64Mode::Standard => cleaned_input,
-            Base64Mode::UrlSafe => {
-                // URL-safe를 표준으로 변환: - → +, _ → /
-                cleaned_input
-                    .chars()
-                    .map(|c| match c {
-                        '-' => '+',
-                        '_' => '/',
-                        other => other,
-                    })
-                    .collect()
-            }
-            Base64Mode::NoPadding => {
-                // 패딩 추가
-                let mut padded = cleaned_input;
-                while padded.len() % 4 != 0 {
-                    padded.push('=');
-                }
-                padded
-            }
-        };
-
-        decode(&standardized_input).map_err(|_| "Failed to decode Base64".to_string())
+        self.codec.decode(input)
     }
 
-    fn format_output(&self, input: &str) -> String {
+    // Data URL은 Base64 전용이고 이메일 인코딩은 자체 줄 구조가 있으므로 이어서 출력
+    fn effective_output_format(&self) -> OutputFormat {
         match self.output_format {
-            OutputFormat::Continuous => input.to_string(),
-            OutputFormat::LineBreaks76 => {
-                input
-                    .chars()
-                    .collect::<Vec<char>>()
-                    .chunks(76)
-                    .map(|chunk| chunk.iter().collect::<String>())
-                    .collect::<Vec<String>>()
-                    .join("\n")
-            }
-            OutputFormat::LineBreaks64 => {
-                input
-                    .chars()
-                    .collect::<Vec<char>>()
-                    .chunks(64)
-                    .map(|chunk| chunk.iter().collect::<String>())
-                    .collect::<Vec<String>>()
-                    .join("\n")
-            }
-            OutputFormat::Chunks4 => {
-                input
-                    .chars()
-                    .collect::<Vec<char>>()
-                    .chunks(4)
-                    .map(|chunk| chunk.iter().collect::<String>())
-                    .collect::<Vec<String>>()
-                    .join(" ")
-            }
-            OutputFormat::Chunks8 => {
-                input
-                    .chars()
-                    .collect::<Vec<char>>()
-                    .chunks(8)
-                    .map(|chunk| chunk.iter().collect::<String>())
-                    .collect::<Vec<String>>()
-                    .join(" ")
-            }
-            OutputFormat::DataUrlImg | OutputFormat::DataUrlCss => {
-                // 파일 컨텍스트에서만 사용됨, 여기서는 기본 처리
-                input.to_string()
-            }
+            _ if self.codec.is_line_structured() => OutputFormat::Continuous,
+            OutputFormat::DataUrlImg | OutputFormat::DataUrlCss if !self.codec.is_base64() => OutputFormat::Continuous,
+            ref format => format.clone(),
         }
     }
 
-    fn format_output_with_file_info(&self, input: &str, file_info: Option<&FileInfo>) -> String {
-        match self.output_format {
-            OutputFormat::DataUrlImg => {
-                if let Some(file_info) = file_info {
-                    format!("data:{};base64,{}", file_info.mime_type, input)
-                } else {
-                    format!("data:text/plain;base64,{}", input)
-                }
-            }
-            OutputFormat::DataUrlCss => {
-                if let Some(file_info) = file_info {
-                    format!("url(data:{};base64,{})", file_info.mime_type, input)
-                } else {
-                    format!("url(data:text/plain;base64,{})", input)
-                }
-            }
-            _ => self.format_output(input)
-        }
+    fn format_output(&self, input: &str) -> String {
+        format_chunk(input, &self.effective_output_format())
     }
 
-    fn validate_base64_input(&self, input: &str) -> Result<(), String> {
-        if input.trim().is_empty() {
-            return Ok(());
-        }
-
-        // 포맷팅 제거 (공백, 줄바꿈 등)
-        let cleaned_input = input
-            .chars()
-            .filter(|c| !c.is_whitespace())
-            .collect::<String>();
-
-        // Base64 모드별 유효한 문자 집합
-        let valid_chars = match self.base64_mode {
-            Base64Mode::Standard => "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/=",
-            Base64Mode::UrlSafe => "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_=",
-            Base64Mode::NoPadding => "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/",
-        };
-
-        // 길이 검증 (NoPadding 모드는 예외)
-        if self.base64_mode != Base64Mode::NoPadding && cleaned_input.len() % 4 != 0 {
-            return Err(format!("Invalid Base64 length: {} characters. Base64 strings must be multiples of 4 characters.", cleaned_input.len()));
-        }
+    fn format_output_with_file_info(&self, input: &str, file_info: Option<&FileInfo>) -> String {
+        format_output(input, &self.effective_output_format(), file_info)
+    }
 
-        // 문자 집합 검증
-        for (index, ch) in cleaned_input.chars().enumerate() {
-            if !valid_chars.contains(ch) {
-                let mode_name = match self.base64_mode {
-                    Base64Mode::Standard => "Standard Base64",
-                    Base64Mode::UrlSafe => "URL-safe Base64",
-                    Base64Mode::NoPadding => "Base64 without padding",
-                };
-                return Err(format!("Invalid character '{}' at position {} for {}. Allowed characters: {}", ch, index + 1, mode_name, valid_chars));
+    fn set_encoded_output(&mut self, result: Result<String, String>) {
+        match result {
+            Ok(encoded) => self.output_base64 = encoded,
+            Err(error_msg) => {
+                self.error_message = Some(error_msg);
+                self.output_base64.clear();
             }
         }
+    }
 
-        // 패딩 검증 (Standard와 UrlSafe 모드)
-        if self.base64_mode != Base64Mode::NoPadding {
-            let padding_start = cleaned_input.find('=');
-            if let Some(start_pos) = padding_start {
-                let padding_part = &cleaned_input[start_pos..];
-                if !padding_part.chars().all(|c| c == '=') {
-                    return Err("Invalid padding: '=' characters can only appear at the end of Base64 string.".to_string());
-                }
-                
-                if padding_part.len() > 2 {
-                    return Err(format!("Invalid padding: too many '=' characters ({}). Maximum allowed is 2.", padding_part.len()));
-                }
-            }
+    fn validate_base64_input(&self, input: &str) -> Result<(), String> {
+        match &self.codec {
+            Codec::Base64(mode) => validate_base64_input(input, mode),
+            _ => Ok(()),
         }
-
-        Ok(())
     }
 
     fn get_detailed_base64_error(&self, input: &str) -> String {
-        // 더 구체적인 에러 분석
-        if input.trim().is_empty() {
-            return "Input is empty.".to_string();
-        }
-
-        let input = input.trim();
-        
-        // 길이 검사
-        if input.len() % 4 != 0 {
-            let missing = 4 - (input.len() % 4);
-            return format!("Invalid length: {} characters. Need {} more character(s) to make it a multiple of 4.", input.len(), missing);
-        }
-
-        // 잘못된 문자 찾기
-        let valid_chars = "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/=";
-        for (index, ch) in input.chars().enumerate() {
-            if !valid_chars.contains(ch) {
-                if ch.is_whitespace() {
-                    return format!("Whitespace character found at position {}. Remove all spaces, tabs, and newlines.", index + 1);
-                } else if ch.is_ascii_punctuation() && ch != '+' && ch != '/' && ch != '=' {
-                    return format!("Invalid punctuation '{}' at position {}. Only '+', '/', and '=' are allowed.", ch, index + 1);
-                } else {
-                    return format!("Invalid character '{}' at position {}. Use only A-Z, a-z, 0-9, +, /, =", ch, index + 1);
-                }
-            }
+        if self.codec.is_base64() {
+            get_detailed_base64_error(input)
+        } else {
+            self.codec.decode(input).err().unwrap_or_default()
         }
-
-        "Unknown Base64 format error.".to_string()
     }
 
     fn format_file_size(size: usize) -> String {
-        if size < 1024 {
-            format!("{} B", size)
-        } else if size < 1024 * 1024 {
-            format!("{:.1} KB", size as f64 / 1024.0)
-        } else if size < 1024 * 1024 * 1024 {
-            format!("{:.1} MB", size as f64 / (1024.0 * 1024.0))
-        } else {
-            format!("{:.1} GB", size as f64 / (1024.0 * 1024.0 * 1024.0))
-        }
+        format_file_size(size)
     }
 
     fn get_file_icon(mime_type: &str) -> &str {
-        if mime_type.starts_with("image/") {
-            "🖼️"
-        } else if mime_type == "application/pdf" {
-            "📄"
-        } else if mime_type.starts_with("text/") {
-            "📝"
-        } else if mime_type.starts_with("audio/") {
-            "🎵"
-        } else if mime_type.starts_with("video/") {
-            "🎬"
-        } else if mime_type.contains("zip") || mime_type.contains("rar") || mime_type.contains("tar") {
-            "📦"
-        } else {
-            "📁"
-        }
+        get_file_icon(mime_type)
     }
 
-    fn encode_file_content(&self, content: &[u8]) -> String {
-        let encoded = match self.base64_mode {
-            Base64Mode::Standard => encode(content),
-            Base64Mode::UrlSafe => {
-                encode(content)
-                    .chars()
-                    .map(|c| match c {
-                        '+' => '-',
-                        '/' => '_',
-                        other => other,
-                    })
-                    .collect()
-            }
-            Base64Mode::NoPadding => {
-                encode(content).trim_end_matches('=').to_string()
-            }
-        };
-
-        self.format_output_with_file_info(&encoded, self.file_info.as_ref())
+    fn encode_file_content(&self, content: &[u8]) -> Result<String, String> {
+        let encoded = self.codec.encode(content)?;
+        Ok(self.format_output_with_file_info(&encoded, self.file_info.as_ref()))
     }
 
     // 대용량 파일을 위한 청크 단위 처리
@@ -372,50 +178,71 @@ impl ToolBase64 {
         size > Self::LARGE_FILE_THRESHOLD
     }
 
-    fn encode_chunk(&self, chunk: &[u8]) -> String {
-        match self.base64_mode {
-            Base64Mode::Standard => encode(chunk),
-            Base64Mode::UrlSafe => {
-                encode(chunk)
-                    .chars()
-                    .map(|c| match c {
-                        '+' => '-',
-                        '/' => '_',
-                        other => other,
-                    })
-                    .collect()
-            }
-            Base64Mode::NoPadding => {
-                encode(chunk).trim_end_matches('=').to_string()
-            }
-        }
+    fn encode_chunk(&self, chunk: &[u8]) -> Result<String, String> {
+        self.codec.encode(chunk)
     }
 
     fn create_processing_state(&self, data: Vec<u8>) -> ProcessingState {
-        let total_chunks = (data.len() + Self::CHUNK_SIZE - 1) / Self::CHUNK_SIZE;
+        // 청크 경계가 코덱의 블록 경계와 맞아야 중간에 패딩이 끼지 않음
+        let chunk_size = match self.codec.block_size() {
+            Some(block) => Self::CHUNK_SIZE - Self::CHUNK_SIZE % block,
+            None => data.len().max(1),
+        };
+        let total_chunks = data.len().div_ceil(chunk_size);
+        ProcessingState {
+            data,
+            current_chunk: 0,
+            total_chunks,
+            chunk_size,
+            result: String::new(),
+            decoder: None,
+            decoded: Vec::new(),
+            data_url_mime: None,
+        }
+    }
+
+    // 큰 Base64 입력은 스트리밍 디코더로 청크 단위 디코딩 (청크 경계는 어디든 상관없음)
+    const DECODE_CHUNK_SIZE: usize = 1024 * 1024; // 1MB 청크
+    const MAX_DECODE_FILE_SIZE: usize = 512 * 1024 * 1024; // 512MB 최대 Base64 파일 크기
+    const MAX_INLINE_PREVIEW: usize = 8 * 1024 * 1024; // 이보다 큰 결과는 텍스트/이미지 대신 헥스 덤프로 표시
+
+    fn create_decoding_state(&self, data: Vec<u8>, mode: Base64Mode, data_url_mime: Option<String>) -> ProcessingState {
+        let total_chunks = data.len().div_ceil(Self::DECODE_CHUNK_SIZE);
+        let capacity = data.len() / 4 * 3;
         ProcessingState {
             data,
             current_chunk: 0,
             total_chunks,
-            chunk_size: Self::CHUNK_SIZE,
+            chunk_size: Self::DECODE_CHUNK_SIZE,
             result: String::new(),
+            decoder: Some(Base64StreamDecoder::new(mode)),
+            decoded: Vec::with_capacity(capacity),
+            data_url_mime,
         }
     }
 
+    fn is_decoding(&self) -> bool {
+        self.processing_chunks.as_ref().is_some_and(|state| state.decoder.is_some())
+    }
+
+    fn stop_processing(&mut self) {
+        self.is_processing = false;
+        self.processing_progress = 0.0;
+        self.processing_chunks = None;
+        self.is_formatting = false;
+        self.formatting_state = None;
+    }
+
     // 대용량 결과를 위한 청크 단위 포맷팅
     const FORMAT_CHUNK_SIZE: usize = 256 * 1024; // 256KB 청크로 포맷팅 (더 작게)
     const LARGE_RESULT_THRESHOLD: usize = 1024 * 1024; // 1MB 이상은 청크 포맷팅 (더 보수적)
 
     fn should_use_chunked_formatting(&self, size: usize) -> bool {
-        size > Self::LARGE_RESULT_THRESHOLD && 
-        (self.output_format == OutputFormat::LineBreaks76 || 
-         self.output_format == OutputFormat::LineBreaks64 ||
-         self.output_format == OutputFormat::Chunks4 ||
-         self.output_format == OutputFormat::Chunks8)
+        size > Self::LARGE_RESULT_THRESHOLD && is_chunkable_format(&self.effective_output_format())
     }
 
     fn create_formatting_state(&self, base64_result: String, format_type: OutputFormat, file_info: Option<FileInfo>) -> FormattingState {
-        let max_chunks = (base64_result.len() + Self::FORMAT_CHUNK_SIZE - 1) / Self::FORMAT_CHUNK_SIZE;
+        let max_chunks = base64_result.len().div_ceil(Self::FORMAT_CHUNK_SIZE);
         FormattingState {
             base64_result,
             current_position: 0,
@@ -428,132 +255,191 @@ impl ToolBase64 {
     }
 
     fn format_chunk(&self, chunk: &str, format_type: &OutputFormat) -> String {
-        match format_type {
-            OutputFormat::LineBreaks76 => {
-                chunk
-                    .chars()
-                    .collect::<Vec<char>>()
-                    .chunks(76)
-                    .map(|chunk| chunk.iter().collect::<String>())
-                    .collect::<Vec<String>>()
-                    .join("\n")
-            }
-            OutputFormat::LineBreaks64 => {
-                chunk
-                    .chars()
-                    .collect::<Vec<char>>()
-                    .chunks(64)
-                    .map(|chunk| chunk.iter().collect::<String>())
-                    .collect::<Vec<String>>()
-                    .join("\n")
-            }
-            OutputFormat::Chunks4 => {
-                chunk
-                    .chars()
-                    .collect::<Vec<char>>()
-                    .chunks(4)
-                    .map(|chunk| chunk.iter().collect::<String>())
-                    .collect::<Vec<String>>()
-                    .join(" ")
+        format_chunk(chunk, format_type)
+    }
+
+    // 디코딩 결과를 종류에 따라 표시
+    // (이미지는 미리보기, PDF/오디오/비디오는 내장 플레이어, 텍스트는 그대로, 나머지 바이너리는 헥스 덤프)
+    fn show_decoded(&mut self, decoded: Vec<u8>, data_url_mime: Option<String>) {
+        let file_type = detect_file_type(&decoded);
+        let prefix = if data_url_mime.is_some() { "Data URL " } else { "" };
+        // Data URL에 적힌 MIME 타입을 우선하고, 없으면 시그니처로 판별
+        let mime_type = data_url_mime
+            .clone()
+            .or_else(|| file_type.as_ref().map(|file_type| file_type.mime_type.to_string()));
+        let type_name = file_type.as_ref().map(|file_type| file_type.name).unwrap_or("Binary data");
+        let is_binary = file_type.as_ref().is_some_and(|file_type| !file_type.is_text());
+        // 아주 큰 결과는 텍스트/이미지로 그리지 않고 헥스 덤프와 다운로드만 제공
+        let inline_preview = decoded.len() <= Self::MAX_INLINE_PREVIEW;
+        let text = if is_binary || !inline_preview { None } else { decode_text(&decoded) };
+
+        match mime_type.as_deref() {
+            Some(mime) if mime.starts_with("image/") && inline_preview => {
+                self.decoded_image_data = Some(self.create_image_data_url(&decoded, mime));
+                self.output_string = format!("{}Image detected: {} ({} bytes)", prefix, mime, decoded.len());
             }
-            OutputFormat::Chunks8 => {
-                chunk
-                    .chars()
-                    .collect::<Vec<char>>()
-                    .chunks(8)
-                    .map(|chunk| chunk.iter().collect::<String>())
-                    .collect::<Vec<String>>()
-                    .join(" ")
+            Some(mime) if Self::is_media_mime(mime) => {
+                self.decoded_object_url = Self::create_object_url(&decoded, mime);
+                self.output_string = format!("{}{} detected: {} ({} bytes)", prefix, type_name, mime, decoded.len());
             }
-            _ => chunk.to_string(),
+            _ => match text {
+                Some((encoding, text)) => {
+                    self.output_string = match &data_url_mime {
+                        Some(mime) => format!("Data URL decoded: {} ({} bytes)\n{}", mime, decoded.len(), text),
+                        None => text,
+                    };
+                    self.decoded_text_encoding = Some(encoding);
+                }
+                None if !is_binary && inline_preview && file_type.is_some() => {
+                    self.output_string = String::from_utf8_lossy(&decoded).to_string();
+                }
+                None => {
+                    let mime = mime_type.as_deref().unwrap_or("application/octet-stream");
+                    self.output_string = format!("{}{} detected: {} ({} bytes)", prefix, type_name, mime, decoded.len());
+                }
+            },
         }
+        self.decoded_mime = mime_type;
+        self.decoded_binary_data = Some(decoded);
+        self.decoded_file_type = file_type;
     }
 
-    fn is_valid_image_data(&self, data: &[u8]) -> Option<String> {
-        // 이미지 파일 시그니처 검사
-        if data.len() < 4 {
-            return None;
-        }
-        
-        // PNG
-        if data.starts_with(&[0x89, 0x50, 0x4E, 0x47]) {
-            return Some("image/png".to_string());
+    // 브라우저 내장 뷰어/플레이어로 보여줄 수 있는 종류
+    fn is_media_mime(mime_type: &str) -> bool {
+        mime_type == "application/pdf" || mime_type.starts_with("audio/") || mime_type.starts_with("video/")
+    }
+
+    fn clear_decoded(&mut self) {
+        if let Some(url) = self.decoded_object_url.take() {
+            let _ = Url::revoke_object_url(&url);
         }
-        
-        // JPEG
-        if data.starts_with(&[0xFF, 0xD8, 0xFF]) {
-            return Some("image/jpeg".to_string());
+        self.decoded_image_data = None;
+        self.decoded_mime = None;
+        self.decoded_binary_data = None;
+        self.decoded_file_type = None;
+        self.decoded_text_encoding = None;
+        self.hex_dump_page = 0;
+    }
+
+    fn create_object_url(data: &[u8], mime_type: &str) -> Option<String> {
+        let uint8_array = js_sys::Uint8Array::new_with_length(data.len() as u32);
+        uint8_array.copy_from(data);
+        let blob_parts = js_sys::Array::new();
+        blob_parts.push(&uint8_array);
+
+        let blob_options = BlobPropertyBag::new();
+        blob_options.set_type(mime_type);
+        let blob = Blob::new_with_u8_array_sequence_and_options(&blob_parts, &blob_options).ok()?;
+        Url::create_object_url_with_blob(&blob).ok()
+    }
+
+    fn decoded_extension(&self) -> &'static str {
+        match (&self.decoded_file_type, self.decoded_text_encoding) {
+            (Some(file_type), _) => file_type.extension,
+            (None, Some(_)) => "txt",
+            (None, None) => "bin",
         }
-        
-        // GIF
-        if data.starts_with(b"GIF87a") || data.starts_with(b"GIF89a") {
-            return Some("image/gif".to_string());
+    }
+
+    fn decoded_type_label(&self, size: usize) -> String {
+        let name = match (&self.decoded_file_type, self.decoded_text_encoding) {
+            (Some(file_type), _) => file_type.name,
+            (None, Some(_)) => "Plain text",
+            (None, None) => "Unknown binary data",
+        };
+        let mut label = format!("Detected type: {}", name);
+        if let Some(mime_type) = &self.decoded_mime {
+            label.push_str(&format!(" ({})", mime_type));
         }
-        
-        // WebP
-        if data.len() >= 12 && &data[0..4] == b"RIFF" && &data[8..12] == b"WEBP" {
-            return Some("image/webp".to_string());
+        if let Some(encoding) = self.decoded_text_encoding {
+            label.push_str(&format!(" • Encoding: {}", encoding.name()));
         }
-        
-        // BMP
-        if data.starts_with(b"BM") {
-            return Some("image/bmp".to_string());
+        label.push_str(&format!(" • Size: {}", Self::format_file_size(size)));
+        label
+    }
+
+    // 청크 처리 프로그레스바 (취소 버튼 포함)
+    fn view_progress(&self, ctx: &Context<Self>) -> Html {
+        let decoding = self.is_decoding();
+        html! {
+            <div style="margin-top: 10px;">
+                <div style="display: flex; align-items: center; justify-content: space-between; margin-bottom: 5px;">
+                    <span style="color: var(--color-subfont); font-size: 12px; margin-right: 10px;">
+                        if self.is_formatting {
+                            { format!("Formatting... {:.0}%", self.processing_progress * 100.0) }
+                        } else if decoding {
+                            { format!("Decoding... {:.0}%", self.processing_progress * 100.0) }
+                        } else {
+                            { format!("Processing... {:.0}%", self.processing_progress * 100.0) }
+                        }
+                    </span>
+                    <button
+                        type="button"
+                        class="tool-btn"
+                        style="width: auto; padding: 2px 10px; font-size: 12px;"
+                        onclick={ctx.link().callback(|_| Msg::CancelProcessing)}>
+                        { "Cancel" }
+                    </button>
+                </div>
+                <div style="width: 100%; background-color: var(--color-border); border-radius: 4px; height: 8px; overflow: hidden;">
+                    <div style={format!("width: {:.1}%; background-color: var(--color-primary); height: 100%; transition: width 0.2s ease;", self.processing_progress * 100.0)}></div>
+                </div>
+                <div style="color: var(--color-subfont); font-size: 11px; margin-top: 2px;">
+                    if self.is_formatting {
+                        { "Applying output formatting to large result - almost done!" }
+                    } else if decoding {
+                        { "Large input detected - decoding in chunks to prevent browser freeze" }
+                    } else {
+                        { "Large file detected - processing in chunks to prevent browser freeze" }
+                    }
+                </div>
+            </div>
         }
-        
-        // ICO
-        if data.starts_with(&[0x00, 0x00, 0x01, 0x00]) {
-            return Some("image/x-icon".to_string());
+    }
+
+    // 임의의 바이너리는 페이지 단위 헥스+ASCII 덤프로 표시
+    fn view_hex_dump(&self, ctx: &Context<Self>, data: &[u8]) -> Html {
+        let pages = hex_dump_pages(data.len());
+        let page = self.hex_dump_page.min(pages - 1);
+        let start = page * HEX_DUMP_PAGE_SIZE;
+        let end = (start + HEX_DUMP_PAGE_SIZE).min(data.len());
+
+        html! {
+            <div>
+                <pre style="margin: 0; max-height: 300px; overflow: auto; padding: 10px; border: 1px solid var(--color-border); border-radius: 8px; background-color: var(--color-background-secondary); font-family: monospace; font-size: 11px; line-height: 1.4; text-align: left;">
+                    { hex_dump_page(data, page) }
+                </pre>
+                if pages > 1 {
+                    <div style="display: flex; justify-content: center; align-items: center; gap: 10px; margin-top: 8px; font-size: 12px; color: var(--color-subfont);">
+                        <button
+                            type="button"
+                            class="tool-btn"
+                            style="width: auto; padding: 2px 10px;"
+                            disabled={page == 0}
+                            onclick={ctx.link().callback(move |_| Msg::HexDumpPage(page.saturating_sub(1)))}>
+                            <i class="fa-solid fa-chevron-left"></i>
+                        </button>
+                        { format!("Page {} / {} (bytes {}–{} of {})", page + 1, pages, start, end.saturating_sub(1), data.len()) }
+                        <button
+                            type="button"
+                            class="tool-btn"
+                            style="width: auto; padding: 2px 10px;"
+                            disabled={page + 1 >= pages}
+                            onclick={ctx.link().callback(move |_| Msg::HexDumpPage(page + 1))}>
+                            <i class="fa-solid fa-chevron-right"></i>
+                        </button>
+                    </div>
+                }
+            </div>
         }
-        
-        None
     }
-    
+
     fn create_image_data_url(&self, data: &[u8], mime_type: &str) -> String {
-        let base64_data = encode(data);
-        format!("data:{};base64,{}", mime_type, base64_data)
+        create_data_url(data, mime_type)
     }
 
     fn parse_data_url(&self, input: &str) -> Option<(String, String)> {
-        // data: URL 형식 파싱
-        // data:image/png;base64,iVBORw0KGgo... 또는
-        // url(data:image/png;base64,iVBORw0KGgo...)
-        
-        let cleaned_input = input.trim();
-        
-        // CSS url() 래퍼 제거
-        let data_part = if cleaned_input.starts_with("url(") && cleaned_input.ends_with(")") {
-            &cleaned_input[4..cleaned_input.len()-1]
-        } else {
-            cleaned_input
-        };
-        
-        // data: URL 형식 확인
-        if !data_part.starts_with("data:") {
-            return None;
-        }
-        
-        // data: 이후 부분 파싱
-        let without_data = &data_part[5..]; // "data:" 제거
-        
-        // MIME 타입과 base64 데이터 분리
-        if let Some(comma_pos) = without_data.find(',') {
-            let header = &without_data[..comma_pos];
-            let base64_data = &without_data[comma_pos + 1..];
-            
-            // MIME 타입 추출 (;base64 제거)
-            let mime_type = if let Some(semicolon_pos) = header.find(';') {
-                header[..semicolon_pos].to_string()
-            } else {
-                header.to_string()
-            };
-            
-            // base64 키워드 확인
-            if header.contains("base64") {
-                return Some((mime_type, base64_data.to_string()));
-            }
-        }
-        
-        None
+        parse_data_url(input)
     }
 }
 
@@ -570,17 +456,19 @@ impl Component for ToolBase64 {
             Msg::UpdateInput(value) => {
                 self.input_string = value;
                 self.error_message = None; // 에러 메시지 초기화
-                self.output_base64 = self.encode_with_mode(&self.input_string);
+                self.set_encoded_output(self.encode_with_mode(&self.input_string));
                 true
             }
             Msg::UpdateBase64(value) => {
                 self.input_base64 = value.clone();
                 self.error_message = None; // 에러 메시지 초기화
+                self.decode_file_info = None;
+                if self.is_decoding() {
+                    self.stop_processing();
+                }
                 
-                // 디코딩된 이미지 데이터 초기화
-                self.decoded_image_data = None;
-                self.decoded_image_mime = None;
-                self.decoded_binary_data = None;
+                // 디코딩 결과 초기화
+                self.clear_decoded();
 
                 // 입력값이 비어있으면 출력도 초기화
                 if value.trim().is_empty() {
@@ -588,33 +476,22 @@ impl Component for ToolBase64 {
                     return true;
                 }
 
+                // 큰 Base64 입력은 청크 단위로 디코딩
+                if self.codec.is_base64() && value.len() > Self::LARGE_FILE_THRESHOLD {
+                    let (data_url_mime, base64_data) = match self.parse_data_url(&value) {
+                        Some((mime_type, base64_data)) => (Some(mime_type), base64_data),
+                        None => (None, value),
+                    };
+                    self.output_string = String::new();
+                    _ctx.link().send_message(Msg::StartChunkedDecoding(base64_data.into_bytes(), data_url_mime));
+                    return true;
+                }
+
                 // 먼저 데이터 URL 형식인지 확인
-                if let Some((mime_type, base64_data)) = self.parse_data_url(&value) {
+                if let Some((mime_type, base64_data)) = self.parse_data_url(&value).filter(|_| self.codec.is_base64()) {
                     // 데이터 URL 형식인 경우
                     match self.decode_with_mode(&base64_data) {
-                        Ok(decoded) => {
-                            // MIME 타입이 이미지인지 확인하거나 바이너리 시그니처로 재확인
-                            let detected_mime = if mime_type.starts_with("image/") {
-                                Some(mime_type.clone())
-                            } else {
-                                self.is_valid_image_data(&decoded)
-                            };
-                            
-                            if let Some(final_mime_type) = detected_mime {
-                                // 이미지인 경우
-                                self.decoded_image_mime = Some(final_mime_type.clone());
-                                self.decoded_binary_data = Some(decoded.clone());
-                                self.decoded_image_data = Some(self.create_image_data_url(&decoded, &final_mime_type));
-                                self.output_string = format!("Data URL Image detected: {} ({} bytes)", final_mime_type, decoded.len());
-                            } else {
-                                // 텍스트인 경우
-                                self.output_string = format!("Data URL decoded: {} ({} bytes)\n{}", 
-                                    mime_type, 
-                                    decoded.len(),
-                                    String::from_utf8_lossy(&decoded).to_string()
-                                );
-                            }
-                        }
+                        Ok(decoded) => self.show_decoded(decoded, Some(mime_type)),
                         Err(_) => {
                             self.error_message = Some("Invalid Base64 data in Data URL".to_string());
                             self.output_string = String::new();
@@ -627,19 +504,7 @@ impl Component for ToolBase64 {
                         Ok(_) => {
                             // 검증 통과 시 디코딩 시도
                             match self.decode_with_mode(&value) {
-                                Ok(decoded) => {
-                                    // 이미지 데이터인지 확인
-                                    if let Some(mime_type) = self.is_valid_image_data(&decoded) {
-                                        // 이미지인 경우
-                                        self.decoded_image_mime = Some(mime_type.clone());
-                                        self.decoded_binary_data = Some(decoded.clone());
-                                        self.decoded_image_data = Some(self.create_image_data_url(&decoded, &mime_type));
-                                        self.output_string = format!("Image detected: {} ({} bytes)", mime_type, decoded.len());
-                                    } else {
-                                        // 텍스트인 경우
-                                        self.output_string = String::from_utf8_lossy(&decoded).to_string();
-                                    }
-                                }
+                                Ok(decoded) => self.show_decoded(decoded, None),
                                 Err(_) => {
                                     // 디코딩 실패 시 구체적인 에러 메시지
                                     self.error_message = Some(self.get_detailed_base64_error(&value));
@@ -661,22 +526,18 @@ impl Component for ToolBase64 {
                 self.convert = !self.convert;
                 self.error_message = None; // 모드 변경 시 에러 메시지 초기화
                 
-                // 디코딩된 이미지 데이터 초기화
-                self.decoded_image_data = None;
-                self.decoded_image_mime = None;
-                self.decoded_binary_data = None;
+                // 디코딩 결과 초기화
+                self.clear_decoded();
                 
                 self.save_to_storage();
                 true
             }
             Msg::ModeChanged(mode) => {
-                self.base64_mode = mode;
+                self.codec = mode;
                 self.error_message = None;
                 
-                // 디코딩된 이미지 데이터 초기화
-                self.decoded_image_data = None;
-                self.decoded_image_mime = None;
-                self.decoded_binary_data = None;
+                // 디코딩 결과 초기화
+                self.clear_decoded();
                 
                 // 청크 처리 또는 포맷팅 중이면 처리하지 않음
                 if self.is_processing || self.is_formatting {
@@ -688,7 +549,7 @@ impl Component for ToolBase64 {
                 if !self.convert {
                     // Text to Base64 모드
                     if !self.input_string.is_empty() {
-                        self.output_base64 = self.encode_with_mode(&self.input_string);
+                        self.set_encoded_output(self.encode_with_mode(&self.input_string));
                     } else if let Some(content) = &self.file_content {
                         // 파일이 업로드된 경우
                         if self.should_use_chunked_processing(content.len()) {
@@ -696,38 +557,16 @@ impl Component for ToolBase64 {
                             let file_info = self.file_info.clone().unwrap();
                             _ctx.link().send_message(Msg::StartChunkedProcessing(content.clone(), file_info));
                         } else {
-                            self.output_base64 = self.encode_file_content(content);
+                            self.set_encoded_output(self.encode_file_content(content));
                         }
                     }
                 } else if self.convert && !self.input_base64.is_empty() {
                     // Base64 to Text 모드 - 재검증 및 변환
                     // 먼저 데이터 URL 형식인지 확인
-                    if let Some((mime_type, base64_data)) = self.parse_data_url(&self.input_base64) {
+                    if let Some((mime_type, base64_data)) = self.parse_data_url(&self.input_base64).filter(|_| self.codec.is_base64()) {
                         // 데이터 URL 형식인 경우
                         match self.decode_with_mode(&base64_data) {
-                            Ok(decoded) => {
-                                // MIME 타입이 이미지인지 확인하거나 바이너리 시그니처로 재확인
-                                let detected_mime = if mime_type.starts_with("image/") {
-                                    Some(mime_type.clone())
-                                } else {
-                                    self.is_valid_image_data(&decoded)
-                                };
-                                
-                                if let Some(final_mime_type) = detected_mime {
-                                    // 이미지인 경우
-                                    self.decoded_image_mime = Some(final_mime_type.clone());
-                                    self.decoded_binary_data = Some(decoded.clone());
-                                    self.decoded_image_data = Some(self.create_image_data_url(&decoded, &final_mime_type));
-                                    self.output_string = format!("Data URL Image detected: {} ({} bytes)", final_mime_type, decoded.len());
-                                } else {
-                                    // 텍스트인 경우
-                                    self.output_string = format!("Data URL decoded: {} ({} bytes)\n{}", 
-                                        mime_type, 
-                                        decoded.len(),
-                                        String::from_utf8_lossy(&decoded).to_string()
-                                    );
-                                }
-                            }
+                            Ok(decoded) => self.show_decoded(decoded, Some(mime_type)),
                             Err(_) => {
                                 self.error_message = Some("Invalid Base64 data in Data URL".to_string());
                                 self.output_string = String::new();
@@ -736,19 +575,7 @@ impl Component for ToolBase64 {
                     } else {
                         // 일반 Base64 문자열인 경우 기존 로직 사용
                         match self.decode_with_mode(&self.input_base64) {
-                            Ok(decoded) => {
-                                // 이미지 데이터인지 확인
-                                if let Some(mime_type) = self.is_valid_image_data(&decoded) {
-                                    // 이미지인 경우
-                                    self.decoded_image_mime = Some(mime_type.clone());
-                                    self.decoded_binary_data = Some(decoded.clone());
-                                    self.decoded_image_data = Some(self.create_image_data_url(&decoded, &mime_type));
-                                    self.output_string = format!("Image detected: {} ({} bytes)", mime_type, decoded.len());
-                                } else {
-                                    // 텍스트인 경우
-                                    self.output_string = String::from_utf8_lossy(&decoded).to_string();
-                                }
-                            }
+                            Ok(decoded) => self.show_decoded(decoded, None),
                             Err(error_msg) => {
                                 self.error_message = Some(error_msg);
                                 self.output_string = String::new();
@@ -770,7 +597,7 @@ impl Component for ToolBase64 {
                 
                 // Encode to Base64 모드일 때만 출력 포맷 재적용
                 if !self.convert && !self.input_string.is_empty() {
-                    self.output_base64 = self.encode_with_mode(&self.input_string);
+                    self.set_encoded_output(self.encode_with_mode(&self.input_string));
                 } else if !self.convert && self.file_content.is_some() {
                     // 파일이 업로드된 상태에서 포맷 변경
                     if let Some(content) = &self.file_content {
@@ -779,7 +606,7 @@ impl Component for ToolBase64 {
                             let file_info = self.file_info.clone().unwrap();
                             _ctx.link().send_message(Msg::StartChunkedProcessing(content.clone(), file_info));
                         } else {
-                            self.output_base64 = self.encode_file_content(content);
+                            self.set_encoded_output(self.encode_file_content(content));
                         }
                     }
                 }
@@ -826,7 +653,7 @@ impl Component for ToolBase64 {
                 }
                 true
             }
-            Msg::FileProcessed(bytes, file_info) => {
+            Msg::FileProcessed(bytes, mut file_info) => {
                 self.is_loading = false;
                 
                 if bytes.is_empty() {
@@ -835,6 +662,13 @@ impl Component for ToolBase64 {
                     self.file_info = None;
                     self.file_content = None;
                 } else {
+                    // 브라우저가 MIME 타입을 모르면 시그니처로 판별한 타입을 씀 (Data URL 출력용)
+                    self.uploaded_file_type = detect_file_type(&bytes);
+                    if let Some(file_type) = &self.uploaded_file_type {
+                        if file_info.mime_type.is_empty() || file_info.mime_type == "application/octet-stream" {
+                            file_info.mime_type = file_type.mime_type.to_string();
+                        }
+                    }
                     self.file_content = Some(bytes.clone());
                     self.file_info = Some(file_info.clone());
                     
@@ -846,7 +680,7 @@ impl Component for ToolBase64 {
                             _ctx.link().send_message(Msg::StartChunkedProcessing(bytes, file_info));
                         } else {
                             // 작은 파일은 즉시 처리
-                            self.output_base64 = self.encode_file_content(&bytes);
+                            self.set_encoded_output(self.encode_file_content(&bytes));
                         }
                         // 텍스트 입력 초기화
                         self.input_string.clear();
@@ -858,17 +692,12 @@ impl Component for ToolBase64 {
                 self.uploaded_file = None;
                 self.file_content = None;
                 self.file_info = None;
+                self.uploaded_file_type = None;
                 self.is_loading = false;
                 self.error_message = None;
                 
-                // 프로그레스 상태 초기화
-                self.is_processing = false;
-                self.processing_progress = 0.0;
-                self.processing_chunks = None;
-                
-                // 포맷팅 상태 초기화
-                self.is_formatting = false;
-                self.formatting_state = None;
+                // 프로그레스/포맷팅 상태 초기화
+                self.stop_processing();
                 
                 // Encode to Base64 모드에서 출력 초기화
                 if !self.convert {
@@ -877,6 +706,11 @@ impl Component for ToolBase64 {
                 true
             }
             Msg::StartChunkedProcessing(data, file_info) => {
+                if let Err(error_msg) = self.codec.check_input_length(data.len()) {
+                    self.error_message = Some(error_msg);
+                    self.output_base64.clear();
+                    return true;
+                }
                 self.is_processing = true;
                 self.processing_progress = 0.0;
                 self.file_info = Some(file_info);
@@ -894,8 +728,15 @@ impl Component for ToolBase64 {
                         let end = std::cmp::min(start + state.chunk_size, state.data.len());
                         let chunk = &state.data[start..end];
                         
-                        let encoded_chunk = self.encode_chunk(chunk);
-                        state.result.push_str(&encoded_chunk);
+                        let chunk_result = match &mut state.decoder {
+                            Some(decoder) => decoder.push(chunk).map(|decoded_chunk| state.decoded.extend(decoded_chunk)),
+                            None => self.encode_chunk(chunk).map(|encoded_chunk| state.result.push_str(&encoded_chunk)),
+                        };
+                        if let Err(error_msg) = chunk_result {
+                            self.error_message = Some(error_msg);
+                            self.is_processing = false;
+                            return true;
+                        }
                         
                         state.current_chunk += 1;
                         self.processing_progress = (state.current_chunk as f32) / (state.total_chunks as f32);
@@ -910,6 +751,18 @@ impl Component for ToolBase64 {
                                 gloo_timers::future::TimeoutFuture::new(1).await; // 1ms 지연
                                 link.send_message(Msg::ProcessNextChunk);
                             });
+                        } else if let Some(decoder) = state.decoder {
+                            // 모든 청크 디코딩 완료: 남은 글자와 패딩 처리
+                            match decoder.finish() {
+                                Ok(rest) => {
+                                    state.decoded.extend(rest);
+                                    _ctx.link().send_message(Msg::ChunkedDecodingComplete(state.decoded, state.data_url_mime));
+                                }
+                                Err(error_msg) => {
+                                    self.error_message = Some(error_msg);
+                                    self.is_processing = false;
+                                }
+                            }
                         } else {
                             // 모든 청크 처리 완료
                             _ctx.link().send_message(Msg::ChunkedProcessingComplete(state.result));
@@ -940,7 +793,7 @@ impl Component for ToolBase64 {
                     // 결과가 큰 경우에만 청크 포맷팅 시작
                     _ctx.link().send_message(Msg::StartChunkedFormatting(
                         result, 
-                        self.output_format.clone(), 
+                        self.effective_output_format(), 
                         self.file_info.clone()
                     ));
                 }
@@ -1076,66 +929,131 @@ impl Component for ToolBase64 {
                 self.output_base64 = formatted_result;
                 true
             }
-            Msg::DownloadDecodedImage => {
-                if let (Some(binary_data), Some(mime_type)) = (&self.decoded_binary_data, &self.decoded_image_mime) {
-                    // 파일 확장자 결정
-                    let extension = match mime_type.as_str() {
-                        "image/png" => "png",
-                        "image/jpeg" => "jpg",
-                        "image/gif" => "gif",
-                        "image/webp" => "webp",
-                        "image/bmp" => "bmp",
-                        "image/x-icon" => "ico",
-                        _ => "bin",
-                    };
-                    
-                    let filename = format!("decoded_image.{}", extension);
-                    
-                    if let Some(window) = window() {
-                        // Uint8Array 생성
-                        let uint8_array = js_sys::Uint8Array::new_with_length(binary_data.len() as u32);
-                        uint8_array.copy_from(binary_data);
-                        
-                        // Blob 생성
-                        let blob_parts = js_sys::Array::new();
-                        blob_parts.push(&uint8_array);
-                        
-                        let mut blob_options = BlobPropertyBag::new();
-                        blob_options.set_type(mime_type);
-                        
-                        if let Ok(blob) = Blob::new_with_u8_array_sequence_and_options(&blob_parts, &blob_options) {
-                            // URL 생성
-                            if let Ok(url) = Url::create_object_url_with_blob(&blob) {
-                                // 다운로드 링크 생성 및 클릭
-                                if let Some(document) = window.document() {
-                                    if let Ok(anchor) = document.create_element("a") {
-                                        if let Ok(anchor) = anchor.dyn_into::<Element>() {
-                                            let _ = anchor.set_attribute("href", &url);
-                                            let _ = anchor.set_attribute("download", &filename);
-                                            let _ = anchor.set_attribute("style", "display: none;");
-                                            
-                                            if let Some(body) = document.body() {
-                                                let _ = body.append_child(&anchor);
-                                                
-                                                // HTMLElement로 캐스팅하여 click 메서드 호출
-                                                if let Ok(html_anchor) = anchor.clone().dyn_into::<web_sys::HtmlElement>() {
-                                                    html_anchor.click();
-                                                }
-                                                
-                                                let _ = body.remove_child(&anchor);
-                                            }
-                                            
-                                            // URL 정리
-                                            let _ = Url::revoke_object_url(&url);
-                                        }
-                                    }
+            Msg::DownloadDecoded => {
+                if let Some(binary_data) = &self.decoded_binary_data {
+                    let mime_type = self.decoded_mime.as_deref().unwrap_or("application/octet-stream");
+                    let filename = format!("decoded.{}", self.decoded_extension());
+
+                    if let (Some(document), Some(url)) =
+                        (window().and_then(|window| window.document()), Self::create_object_url(binary_data, mime_type))
+                    {
+                        // 다운로드 링크 생성 및 클릭
+                        if let Ok(anchor) = document.create_element("a") {
+                            let _ = anchor.set_attribute("href", &url);
+                            let _ = anchor.set_attribute("download", &filename);
+                            let _ = anchor.set_attribute("style", "display: none;");
+
+                            if let Some(body) = document.body() {
+                                let _ = body.append_child(&anchor);
+
+                                // HTMLElement로 캐스팅하여 click 메서드 호출
+                                if let Ok(html_anchor) = anchor.clone().dyn_into::<HtmlElement>() {
+                                    html_anchor.click();
                                 }
+
+                                let _ = body.remove_child(&anchor);
                             }
                         }
+                        // URL 정리
+                        let _ = Url::revoke_object_url(&url);
                     }
                 }
                 false // 리렌더링 필요 없음
             }
+            Msg::HexDumpPage(page) => {
+                let pages = self.decoded_binary_data.as_ref().map(|data| hex_dump_pages(data.len())).unwrap_or(1);
+                self.hex_dump_page = page.min(pages - 1);
+                true
+            }
+            Msg::DecodeFileSelected(files) => {
+                if let Some(file) = files.into_iter().next() {
+                    let file_size = file.size() as usize;
+                    if file_size > Self::MAX_DECODE_FILE_SIZE {
+                        self.error_message = Some(format!(
+                            "File size too large. Maximum {} supported. (Current file: {})",
+                            Self::format_file_size(Self::MAX_DECODE_FILE_SIZE),
+                            Self::format_file_size(file_size)
+                        ));
+                        return true;
+                    }
+
+                    let file_info = FileInfo {
+                        name: file.name(),
+                        size: file_size,
+                        mime_type: file.raw_mime_type(),
+                    };
+
+                    if self.is_decoding() {
+                        self.stop_processing();
+                    }
+                    self.clear_decoded();
+                    self.input_base64.clear();
+                    self.output_string.clear();
+                    self.error_message = None;
+                    self.decode_file_info = Some(file_info.clone());
+                    self.is_loading = true;
+
+                    let link = _ctx.link().clone();
+                    wasm_bindgen_futures::spawn_local(async move {
+                        match read_as_bytes(&file).await {
+                            Ok(bytes) => link.send_message(Msg::DecodeFileLoaded(bytes, file_info)),
+                            // 에러 처리는 DecodeFileLoaded에서 빈 벡터로 처리
+                            Err(_) => link.send_message(Msg::DecodeFileLoaded(vec![], file_info)),
+                        }
+                    });
+                }
+                true
+            }
+            Msg::DecodeFileLoaded(bytes, file_info) => {
+                self.is_loading = false;
+                // 읽는 사이에 다른 입력으로 바뀌었으면 무시
+                if self.decode_file_info.as_ref() != Some(&file_info) {
+                    return true;
+                }
+
+                if bytes.is_empty() {
+                    self.error_message = Some("Failed to read file".to_string());
+                    self.decode_file_info = None;
+                } else if self.codec.is_base64() {
+                    _ctx.link().send_message(Msg::StartChunkedDecoding(bytes, None));
+                } else {
+                    // 다른 코덱은 청크 디코딩을 지원하지 않으므로 한 번에 디코딩
+                    match self.codec.decode(&String::from_utf8_lossy(&bytes)) {
+                        Ok(decoded) => self.show_decoded(decoded, None),
+                        Err(error_msg) => self.error_message = Some(error_msg),
+                    }
+                }
+                true
+            }
+            Msg::StartChunkedDecoding(data, data_url_mime) => {
+                if let Codec::Base64(mode) = self.codec.clone() {
+                    self.clear_decoded();
+                    self.is_processing = true;
+                    self.processing_progress = 0.0;
+                    self.processing_chunks = Some(self.create_decoding_state(data, mode, data_url_mime));
+
+                    // 첫 번째 청크 처리 시작
+                    _ctx.link().send_message(Msg::ProcessNextChunk);
+                }
+                true
+            }
+            Msg::ChunkedDecodingComplete(decoded, data_url_mime) => {
+                self.is_processing = false;
+                self.processing_progress = 1.0;
+                self.processing_chunks = None;
+                self.show_decoded(decoded, data_url_mime);
+                true
+            }
+            Msg::CancelProcessing => {
+                if self.is_decoding() {
+                    self.output_string.clear();
+                } else {
+                    self.output_base64.clear();
+                }
+                self.stop_processing();
+                self.error_message = Some("Processing cancelled.".to_string());
+                true
+            }
             Msg::DragOver => {
                 self.is_drag_over = true;
                 true
@@ -1239,6 +1157,10 @@ impl Component for ToolBase64 {
                             <h3>{"🔧 Advanced Base64 Features:"}</h3>
                             <ul>
                                 <li><strong>{"Multiple Base64 Variants:"}</strong> {"Standard RFC 4648, URL-Safe (- and _ instead of + and /), and No-Padding formats."}</li>
+                                <li><strong>{"File Type Detection:"}</strong> {"Decoded data and uploaded files are identified by their magic bytes (PNG, JPEG, WebP, AVIF, PDF, ZIP/DOCX/JAR, gzip, ELF, PE, WASM, SQLite and more), so every decoded result downloads with the right extension."}</li>
+                                <li><strong>{"Decoded Previews:"}</strong> {"Images, PDFs (embedded viewer), audio and video play inline; UTF-8 and UTF-16 text is shown as text; any other binary gets a paged hex + ASCII dump."}</li>
+                                <li><strong>{"Email Encodings:"}</strong> {"Quoted-Printable bodies, uuencoded and yEnc attachments, and MIME encoded-words (=?UTF-8?B?...?= / =?ISO-8859-1?Q?...?=). Paste a whole raw header block to read every encoded Subject, From and filename as plain text."}</li>
+                                <li><strong>{"Other Base-N Encodings:"}</strong> {"Base32 and Base32hex (RFC 4648, e.g. TOTP secrets), Base58 and Base58Check (Bitcoin addresses and keys), Ascii85 (PDF, Git binary patches), Z85 (ZeroMQ), and Base45 (RFC 9285, EU Digital COVID Certificate QR codes)."}</li>
                                 <li><strong>{"Flexible Output Formatting:"}</strong> {"Continuous string, MIME (76-char lines), 64-char lines, or chunked (4/8-char) output."}</li>
                                 <li><strong>{"Data URL Generation:"}</strong> {"Automatic creation of ready-to-use data URLs for HTML <img> tags and CSS background properties."}</li>
                                 <li><strong>{"Smart Input Parsing:"}</strong> {"Handles existing data URLs (data:image/...;base64,... or url(data:...)) and extracts Base64 content."}</li>
@@ -1255,6 +1177,7 @@ impl Component for ToolBase64 {
                             <h3>{"⚡ Performance & User Experience:"}</h3>
                             <ul>
                                 <li><strong>{"Chunked Processing:"}</strong> {"Large files are processed in 64KB chunks to maintain browser responsiveness."}</li>
+                                <li><strong>{"Streaming Decode:"}</strong> {"Base64 inputs over 1MB, pasted or opened as a file (up to 512MB), are decoded in 1MB chunks with progress and cancellation; chunk boundaries may fall anywhere, even inside a line."}</li>
                                 <li><strong>{"Progress Tracking:"}</strong> {"Real-time progress bars for encoding (0-95%) and formatting (95-100%) phases."}</li>
                                 <li><strong>{"Local Processing:"}</strong> {"All operations happen in your browser - no data is sent to external servers."}</li>
                                 <li><strong>{"Copy-to-Clipboard:"}</strong> {"One-click copying of results with visual feedback for instant workflow integration."}</li>
@@ -1382,7 +1305,7 @@ impl Component for ToolBase64 {
                                     <li>{"Switch to 'Decode from Base64' mode by clicking the rotate icon (⟲)."}</li>
                                     <li>{"Paste any Base64 string, data URL, or CSS url() format."}</li>
                                     <li>{"The tool automatically detects the format and extracts Base64 content."}</li>
-                                    <li>{"View decoded text or image preview with download option."}</li>
+                                    <li>{"View decoded text or the image, PDF, audio/video or hex dump preview, and download the result."}</li>
                                     <li>{"For images, use the download button to save the decoded file."}</li>
                                 </ol>
                                 <div class="example-box">
@@ -1392,7 +1315,7 @@ impl Component for ToolBase64 {
                                         <li>{"Data URL: 'data:image/png;base64,iVBORw0KGgo...'"}</li>
                                         <li>{"CSS Format: 'url(data:image/png;base64,iVBORw0KGgo...)'"}</li>
                                     </ul>
-                                    <p><strong>{"Smart Detection:"}</strong> {"🖼️ Images show preview + download | 📝 Text shows content"}</p>
+                                    <p><strong>{"Smart Detection:"}</strong> {"🖼️ Images, PDFs and media show a preview | 📝 UTF-8/UTF-16 text shows content | 🔢 Other binaries show a hex dump — all with download"}</p>
                                 </div>
                             </div>
 
@@ -1490,6 +1413,16 @@ impl Component for ToolBase64 {
                                 <p>{"A: Standard Base64 uses +, / and = padding. URL-Safe replaces + with - and / with _ for safe use in URLs. No-Padding removes = characters entirely. Each mode produces different but valid Base64 output for the same input data."}</p>
                             </div>
 
+                            <div class="faq-item">
+                                <h3>{"Q: How do I read encoded email headers and attachments?"}</h3>
+                                <p>{"A: Choose 'MIME Encoded-Word' and paste the raw headers: folded lines are joined, B (Base64) and Q words are decoded from UTF-8, US-ASCII, ISO-8859-1 or Windows-1252, and the rest of each header is kept as-is. Use 'Quoted-Printable' for bodies with Content-Transfer-Encoding: quoted-printable, and 'uuencode' or 'yEnc' for attachments (yEnc size and CRC32 are checked). Base64 attachment bodies use the Base64 mode with 'MIME (76 chars)' output."}</p>
+                            </div>
+
+                            <div class="faq-item">
+                                <h3>{"Q: When should I use Base32, Base58, Ascii85, Z85 or Base45 instead?"}</h3>
+                                <p>{"A: Pick the encoding the receiving system expects. Base32 is case-insensitive and used for TOTP secrets; Base58 drops look-alike characters (0, O, I, l) and Base58Check adds a 4-byte double-SHA256 checksum; Ascii85 and Z85 are about 7% more compact than Base64; Base45 fits the QR code alphanumeric mode. Z85 requires input lengths that are a multiple of 4 bytes, Base58 is limited to 8 KB, and Data URL output is only available for Base64."}</p>
+                            </div>
+
                             <div class="faq-item">
                                 <h3>{"Q: Can I decode data URLs and CSS url() formats?"}</h3>
                                 <p>{"A: Yes! The tool intelligently parses data:image/png;base64,... and url(data:...) formats, automatically extracts the Base64 content, detects MIME types, and provides appropriate previews for images or text output for other data."}</p>
@@ -1521,8 +1454,13 @@ impl Component for ToolBase64 {
                             </div>
 
                             <div class="faq-item">
-                                <h3>{"Q: Can I download decoded images?"}</h3>
-                                <p>{"A: Yes! When you decode Base64 that contains image data, the tool automatically detects it, shows a preview, and provides a download button to save the image file with the correct extension and MIME type."}</p>
+                                <h3>{"Q: Can I download decoded images and other files?"}</h3>
+                                <p>{"A: Yes! Every decoded result has a download button. The file type is detected from its magic bytes, so images, PDFs, archives and media are saved with the correct extension and MIME type; unrecognized text is saved as .txt and other binary as .bin."}</p>
+                            </div>
+
+                            <div class="faq-item">
+                                <h3>{"Q: What does the hex dump show?"}</h3>
+                                <p>{"A: Binary data that is not an image, PDF, audio or video is shown as a hex + ASCII dump (offset, 16 bytes in hex, printable characters), 4 KB per page. Use the arrows below the dump to move between pages."}</p>
                             </div>
 
                             <div class="faq-item">
@@ -1539,6 +1477,11 @@ impl Component for ToolBase64 {
                                 <h3>{"Q: Does Base64 increase file size?"}</h3>
                                 <p>{"A: Yes, Base64 encoding increases size by approximately 33% due to the encoding overhead. A 100KB file becomes ~133KB when Base64-encoded. Consider this when embedding large files in documents or APIs."}</p>
                             </div>
+
+                            <div class="faq-item">
+                                <h3>{"Q: Can I decode very large Base64 data?"}</h3>
+                                <p>{"A: Yes. In decode mode, use Open File to load a Base64 text file of up to 512MB instead of pasting it. Large Base64 input is decoded in chunks with a progress bar and a Cancel button, and whitespace and line breaks are skipped wherever they appear. Results over 8MB are shown as a hex dump instead of text or an image, and the download button saves the full result."}</p>
+                            </div>
                         </div>
 
                         <div class="content-section">
@@ -1581,9 +1524,9 @@ impl Component for ToolBase64 {
                         <div style="display: flex; align-items: center; margin-bottom: 10px; margin-top: 5px;">
                             <div style="width: 90%;">
                                 if !convert {
-                                    {"Encode to Base64"}
+                                    { format!("Encode to {}", self.codec.name()) }
                                 } else {
-                                    {"Decode from Base64"}
+                                    { format!("Decode from {}", self.codec.name()) }
                                 }
                             </div>
                             <div onclick={on_convert} class="tool-change" style="width: 10%; display: flex; justify-content: center;">
@@ -1594,26 +1537,23 @@ impl Component for ToolBase64 {
                         // Base64 모드 선택
                         <div style="display: flex; align-items: center; margin-bottom: 10px;">
                             <div style="width: 50%; margin-right: 10px;">
-                                <label style="margin-right: 8px; font-size: 14px;">{"Base64 Mode:"}</label>
+                                <label style="margin-right: 8px; font-size: 14px;">{"Encoding:"}</label>
                                 <select
                                     style="width: 100%;"
                                     onchange={_ctx.link().callback(|e: Event| {
                                         let value = e.target_unchecked_into::<web_sys::HtmlSelectElement>().value();
-                                        match value.as_str() {
-                                            "standard" => Msg::ModeChanged(Base64Mode::Standard),
-                                            "urlsafe" => Msg::ModeChanged(Base64Mode::UrlSafe),
-                                            "nopadding" => Msg::ModeChanged(Base64Mode::NoPadding),
-                                            _ => unreachable!(),
-                                        }
+                                        Msg::ModeChanged(Codec::from_key(&value).unwrap())
                                     })}>
-                                    <option value="standard" selected={self.base64_mode == Base64Mode::Standard}>{ "Standard" }</option>
-                                    <option value="urlsafe" selected={self.base64_mode == Base64Mode::UrlSafe}>{ "URL-Safe" }</option>
-                                    <option value="nopadding" selected={self.base64_mode == Base64Mode::NoPadding}>{ "No Padding" }</option>
+                                    {
+                                        for Codec::all().iter().map(|codec| html! {
+                                            <option value={codec.key()} selected={self.codec == *codec}>{ codec.name() }</option>
+                                        })
+                                    }
                                 </select>
                             </div>
                             
-                            // 출력 포맷 선택 (Encode to Base64 모드일 때만)
-                            if !convert {
+                            // 출력 포맷 선택 (Encode to Base64 모드일 때만, 이메일 인코딩은 자체 줄 구조 사용)
+                            if !convert && !self.codec.is_line_structured() {
                                 <div style="width: 50%;">
                                     <label style="margin-right: 8px; font-size: 14px;">{"Output Format:"}</label>
                                     <select
@@ -1636,8 +1576,10 @@ impl Component for ToolBase64 {
                                         <option value="linebreaks64" selected={self.output_format == OutputFormat::LineBreaks64}>{ "64 chars/line" }</option>
                                         <option value="chunks4" selected={self.output_format == OutputFormat::Chunks4}>{ "4-char chunks" }</option>
                                         <option value="chunks8" selected={self.output_format == OutputFormat::Chunks8}>{ "8-char chunks" }</option>
-                                        <option value="dataurlimg" selected={self.output_format == OutputFormat::DataUrlImg}>{ "Data URL (for <img>)" }</option>
-                                        <option value="dataurlcss" selected={self.output_format == OutputFormat::DataUrlCss}>{ "Data URL (for CSS)" }</option>
+                                        if self.codec.is_base64() {
+                                            <option value="dataurlimg" selected={self.output_format == OutputFormat::DataUrlImg}>{ "Data URL (for <img>)" }</option>
+                                            <option value="dataurlcss" selected={self.output_format == OutputFormat::DataUrlCss}>{ "Data URL (for CSS)" }</option>
+                                        }
                                     </select>
                                 </div>
                             }
@@ -1691,7 +1633,7 @@ impl Component for ToolBase64 {
                                                 // 첫 번째 행: 파일 정보
                                                 <div style="display: flex; align-items: center; margin-bottom: 10px;">
                                                     <span style="font-size: 20px; margin-right: 8px;">
-                                                        { Self::get_file_icon(&file_info.mime_type) }
+                                                        { Self::get_file_icon(self.uploaded_file_type.as_ref().map_or(file_info.mime_type.as_str(), |file_type| file_type.mime_type)) }
                                                     </span>
                                                     <div style="text-align: left; overflow-y: auto;">
                                                         <div style="font-weight: bold; color: var(--color-font);">
@@ -1700,6 +1642,11 @@ impl Component for ToolBase64 {
                                                         <div style="font-size: 12px; color: var(--color-subfont);">
                                                             { format!("{} • {}", Self::format_file_size(file_info.size), &file_info.mime_type) }
                                                         </div>
+                                                        if let Some(file_type) = &self.uploaded_file_type {
+                                                            <div style="font-size: 12px; color: var(--color-subfont);">
+                                                                { format!("Detected type: {}", file_type.name) }
+                                                            </div>
+                                                        }
                                                     </div>
                                                 </div>
                                                 // 두 번째 행: Remove 버튼
@@ -1720,27 +1667,7 @@ impl Component for ToolBase64 {
                                             
                                             // 청크 처리 프로그레스바
                                             if self.is_processing || self.is_formatting {
-                                                <div style="margin-top: 10px;">
-                                                    <div style="display: flex; align-items: center; margin-bottom: 5px;">
-                                                        <span style="color: var(--color-subfont); font-size: 12px; margin-right: 10px;">
-                                                            if self.is_formatting {
-                                                                { format!("Formatting... {:.0}%", self.processing_progress * 100.0) }
-                                                            } else {
-                                                                { format!("Processing... {:.0}%", self.processing_progress * 100.0) }
-                                                            }
-                                                        </span>
-                                                    </div>
-                                                    <div style="width: 100%; background-color: var(--color-border); border-radius: 4px; height: 8px; overflow: hidden;">
-                                                        <div style={format!("width: {:.1}%; background-color: var(--color-primary); height: 100%; transition: width 0.2s ease;", self.processing_progress * 100.0)}></div>
-                                                    </div>
-                                                    <div style="color: var(--color-subfont); font-size: 11px; margin-top: 2px;">
-                                                        if self.is_formatting {
-                                                            { "Applying output formatting to large result - almost done!" }
-                                                        } else {
-                                                            { "Large file detected - processing in chunks to prevent browser freeze" }
-                                                        }
-                                                    </div>
-                                                </div>
+                                                { self.view_progress(_ctx) }
                                             }
                                         } else {
                                             // 파일 업로드 대기 상태
@@ -1812,7 +1739,7 @@ impl Component for ToolBase64 {
                             </div>
                             <div class="tool-inner" style="margin-top: 10px;">
                                 <div>
-                                    <div class="tool-subtitle">{ "Base64 Output" }</div>
+                                    <div class="tool-subtitle">{ format!("{} Output", self.codec.name()) }</div>
                                     <textarea
                                         type="text"
                                         readonly=true
@@ -1828,7 +1755,40 @@ impl Component for ToolBase64 {
                         } else {
                             <div class="tool-inner">
                                 <div>
-                                    <div class="tool-subtitle" style="margin-bottom: 5px;">{ "Base64 Input" }</div>
+                                    <div class="tool-subtitle" style="margin-bottom: 5px;">{ format!("{} Input", self.codec.name()) }</div>
+                                    // 큰 Base64 파일은 텍스트 영역에 붙여넣지 않고 파일로 바로 디코딩
+                                    <div style="display: flex; align-items: center; gap: 8px; margin-bottom: 8px; font-size: 12px; color: var(--color-subfont);">
+                                        <input
+                                            type="file"
+                                            id="decode-file-upload"
+                                            style="display: none;"
+                                            onchange={_ctx.link().callback(|e: Event| {
+                                                let input: HtmlInputElement = e.target_unchecked_into();
+                                                let mut file_list = Vec::new();
+                                                if let Some(files) = input.files() {
+                                                    for i in 0..files.length() {
+                                                        if let Some(file) = files.get(i) {
+                                                            file_list.push(GlooFile::from(file));
+                                                        }
+                                                    }
+                                                }
+                                                // 같은 파일을 다시 고를 수 있도록 초기화
+                                                input.set_value("");
+                                                Msg::DecodeFileSelected(file_list)
+                                            })}
+                                        />
+                                        <label
+                                            for="decode-file-upload"
+                                            style="display: inline-block; background: var(--color-primary); color: white; padding: 4px 10px; border-radius: 4px; cursor: pointer; white-space: nowrap;">
+                                            <i class="fa-solid fa-file-import" style="margin-right: 5px;"></i>
+                                            { "Open File" }
+                                        </label>
+                                        if let Some(file_info) = &self.decode_file_info {
+                                            <span>{ format!("{} • {}", file_info.name, Self::format_file_size(file_info.size)) }</span>
+                                        } else {
+                                            <span>{ format!("Decode a text file (up to {})", Self::format_file_size(Self::MAX_DECODE_FILE_SIZE)) }</span>
+                                        }
+                                    </div>
                                     <textarea
                                         type="text"
                                         style={if self.error_message.is_some() { 
@@ -1849,8 +1809,28 @@ impl Component for ToolBase64 {
                                         </div>
                                     }
                                     <div style="color: var(--color-subfont); font-size: 11px; margin-top: 2px;">
-                                        {"Supports: Base64 strings, data:image/...;base64,... or url(data:image/...;base64,...)"}
+                                        if self.codec.is_base64() {
+                                            {"Supports: Base64 strings, data:image/...;base64,... or url(data:image/...;base64,...)"}
+                                        } else if self.codec == Codec::MimeHeader {
+                                            {"Paste a raw header block: folded lines are joined and =?charset?B|Q?...?= words are decoded"}
+                                        } else if self.codec == Codec::QuotedPrintable {
+                                            {"Soft line breaks ('=' at the end of a line) are removed"}
+                                        } else if self.codec.is_line_structured() {
+                                            {"Text before the 'begin' / '=ybegin' line is skipped"}
+                                        } else if self.codec == Codec::Base45 {
+                                            {"Line breaks are ignored (space is part of the Base45 alphabet)"}
+                                        } else {
+                                            {"Whitespace and line breaks are ignored"}
+                                        }
                                     </div>
+                                    if self.is_loading {
+                                        <div style="margin-top: 10px; color: var(--color-subfont); font-size: 12px;">
+                                            <i class="fa-solid fa-spinner fa-spin"></i> { " Reading file..." }
+                                        </div>
+                                    }
+                                    if self.is_processing {
+                                        { self.view_progress(_ctx) }
+                                    }
                                 </div>
                             </div>
                             <div class="tool-inner" style="margin-top: 10px;">
@@ -1870,9 +1850,13 @@ impl Component for ToolBase64 {
                                                     style="max-width: 100%; max-height: 300px; border-radius: 4px; box-shadow: 0 2px 4px rgba(0,0,0,0.1);"
                                                 />
                                             </div>
-                                            if let Some(mime_type) = &self.decoded_image_mime {
+                                            if let Some(mime_type) = &self.decoded_mime {
                                                 <div style="margin-top: 5px; font-size: 11px; color: var(--color-subfont); text-align: center;">
-                                                    { format!("Type: {}", mime_type) }
+                                                    if let Some(file_type) = &self.decoded_file_type {
+                                                        { format!("Type: {} ({})", file_type.name, mime_type) }
+                                                    } else {
+                                                        { format!("Type: {}", mime_type) }
+                                                    }
                                                     if let Some(binary_data) = &self.decoded_binary_data {
                                                         { format!(" • Size: {}", Self::format_file_size(binary_data.len())) }
                                                     }
@@ -1882,12 +1866,45 @@ impl Component for ToolBase64 {
                                                 <button 
                                                     type="button"
                                                     style="background: var(--color-primary); color: white; border: none; border-radius: 4px; padding: 8px 16px; cursor: pointer; font-size: 12px;"
-                                                    onclick={_ctx.link().callback(|_| Msg::DownloadDecodedImage)}>
+                                                    onclick={_ctx.link().callback(|_| Msg::DownloadDecoded)}>
                                                     <i class="fa-solid fa-download" style="margin-right: 5px;"></i>
                                                     { "Download Image" }
                                                 </button>
                                             </div>
                                         </div>
+                                    } else if let Some(binary_data) = &self.decoded_binary_data {
+                                        // 이미지가 아닌 결과: 종류 표시 + 미리보기 + 다운로드
+                                        <div style="margin-bottom: 15px;">
+                                            <div style="margin-bottom: 8px; font-size: 12px; color: var(--color-subfont);">
+                                                { self.decoded_type_label(binary_data.len()) }
+                                            </div>
+                                            if let Some(object_url) = &self.decoded_object_url {
+                                                <div style="border: 1px solid var(--color-border); border-radius: 8px; padding: 10px; background-color: var(--color-background-secondary); text-align: center;">
+                                                    if self.decoded_mime.as_deref() == Some("application/pdf") {
+                                                        <object data={object_url.clone()} type="application/pdf" style="width: 100%; height: 400px; border-radius: 4px;">
+                                                            <div style="font-size: 12px; color: var(--color-subfont);">
+                                                                { "This browser cannot show PDFs inline. Use the download button below." }
+                                                            </div>
+                                                        </object>
+                                                    } else if self.decoded_mime.as_deref().is_some_and(|mime| mime.starts_with("audio/")) {
+                                                        <audio controls=true src={object_url.clone()} style="width: 100%;" />
+                                                    } else {
+                                                        <video controls=true src={object_url.clone()} style="max-width: 100%; max-height: 300px; border-radius: 4px;" />
+                                                    }
+                                                </div>
+                                            } else if self.decoded_text_encoding.is_none() {
+                                                { self.view_hex_dump(_ctx, binary_data) }
+                                            }
+                                            <div style="margin-top: 10px; text-align: center;">
+                                                <button
+                                                    type="button"
+                                                    style="background: var(--color-primary); color: white; border: none; border-radius: 4px; padding: 8px 16px; cursor: pointer; font-size: 12px;"
+                                                    onclick={_ctx.link().callback(|_| Msg::DownloadDecoded)}>
+                                                    <i class="fa-solid fa-download" style="margin-right: 5px;"></i>
+                                                    { format!("Download .{}", self.decoded_extension()) }
+                                                </button>
+                                            </div>
+                                        </div>
                                     }
                                     
                                     <textarea
@@ -1909,6 +1926,11 @@ impl Component for ToolBase64 {
         }
     }
 
+    fn destroy(&mut self, _ctx: &Context<Self>) {
+        // 미리보기용 Blob URL 해제
+        self.clear_decoded();
+    }
+
     fn rendered(&mut self, _ctx: &Context<Self>, first_render: bool) {
         if first_render {
             if let Some(window) = window() {
@@ -1940,16 +1962,16 @@ impl ToolBase64 {
     fn load_from_storage() -> Self {
         let storage = Self::get_local_storage();
         
-        let base64_mode = storage
+        let codec = storage
             .as_ref()
             .and_then(|s| s.get_item(Self::STORAGE_KEY_BASE64_MODE).ok().flatten())
             .and_then(|s| match s.as_str() {
-                "standard" => Some(Base64Mode::Standard),
-                "urlsafe" => Some(Base64Mode::UrlSafe),
-                "nopadding" => Some(Base64Mode::NoPadding),
-                _ => None,
+                // 이전 버전에서 저장한 값
+                "urlsafe" => Codec::from_key("url-safe"),
+                "nopadding" => Codec::from_key("no-padding"),
+                key => Codec::from_key(key),
             })
-            .unwrap_or(Base64Mode::Standard);
+            .unwrap_or(Codec::Base64(Base64Mode::Standard));
 
         let output_format = storage
             .as_ref()
@@ -1979,7 +2001,7 @@ impl ToolBase64 {
             output_string: String::new(),
             convert,
             error_message: None,
-            base64_mode,
+            codec,
             output_format,
             // 파일 업로드 관련
             uploaded_file: None,
@@ -1993,23 +2015,24 @@ impl ToolBase64 {
             // 포맷팅 관련
             formatting_state: None,
             is_formatting: false,
-            // 디코딩된 이미지 관련
+            // 디코딩 결과 미리보기 관련
             decoded_image_data: None, // Data URL for image preview
-            decoded_image_mime: None, // MIME type of decoded image
+            decoded_mime: None,
             decoded_binary_data: None, // Raw binary data
+            decoded_file_type: None,
+            decoded_text_encoding: None,
+            decoded_object_url: None,
+            hex_dump_page: 0,
+            uploaded_file_type: None,
             // 드래그 앤 드롭 관련
             is_drag_over: false, // 드래그 오버 상태
+            decode_file_info: None,
         }
     }
 
     fn save_to_storage(&self) {
         if let Some(storage) = Self::get_local_storage() {
-            let base64_mode_str = match self.base64_mode {
-                Base64Mode::Standard => "standard",
-                Base64Mode::UrlSafe => "urlsafe",
-                Base64Mode::NoPadding => "nopadding",
-            };
-            let _ = storage.set_item(Self::STORAGE_KEY_BASE64_MODE, base64_mode_str);
+            let _ = storage.set_item(Self::STORAGE_KEY_BASE64_MODE, self.codec.key());
 
             let output_format_str = match self.output_format {
                 OutputFormat::Continuous => "continuous",
@@ -0,0 +1,459 @@
+use computools_core::bytes::{self, InputEncoding};
+use computools_core::cipher::{self, CipherMode};
+use gloo_file::futures::read_as_bytes;
+use gloo_file::File as GlooFile;
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{window, HtmlInputElement};
+use yew::prelude::*;
+use crate::components::download::download_bytes;
+use crate::components::tool_category::ToolCategoryManager;
+
+pub struct ToolCipher {
+    decrypt: bool,
+    mode: CipherMode,
+    param_format: InputEncoding,
+    key: String,
+    iv: String,
+    aad: String,
+    data_format: InputEncoding,
+    text_input: String,
+    /// 업로드한 파일 (이름, 내용). 있으면 텍스트 입력 대신 사용합니다.
+    file_input: Option<(String, Vec<u8>)>,
+    output: Option<Result<Vec<u8>, String>>,
+}
+
+pub enum Msg {
+    DirectionChanged(String),
+    ModeChanged(String),
+    ParamFormatChanged(String),
+    DataFormatChanged(String),
+    KeyChanged(String),
+    IvChanged(String),
+    AadChanged(String),
+    RandomKey,
+    RandomIv,
+    TextChanged(String),
+    FileSelected(Event),
+    FileLoaded(String, Result<Vec<u8>, String>),
+    ClearFile,
+    Download,
+    CopyToClipboard(String),
+}
+
+impl Component for ToolCipher {
+    type Message = Msg;
+    type Properties = ();
+
+    fn create(_ctx: &Context<Self>) -> Self {
+        Self {
+            decrypt: false,
+            mode: CipherMode::Aes256Gcm,
+            param_format: InputEncoding::Hex,
+            key: String::new(),
+            iv: String::new(),
+            aad: String::new(),
+            data_format: InputEncoding::Base64,
+            text_input: String::new(),
+            file_input: None,
+            output: None,
+        }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            Msg::DirectionChanged(direction) => {
+                self.decrypt = direction == "decrypt";
+            }
+            Msg::ModeChanged(key) => {
+                self.mode = CipherMode::from_key(&key).unwrap_or(CipherMode::Aes256Gcm);
+                if !self.mode.is_aead() {
+                    self.aad.clear();
+                }
+            }
+            Msg::ParamFormatChanged(key) => {
+                self.param_format = InputEncoding::from_key(&key).unwrap_or(InputEncoding::Hex);
+            }
+            Msg::DataFormatChanged(key) => {
+                self.data_format = InputEncoding::from_key(&key).unwrap_or(InputEncoding::Base64);
+            }
+            Msg::KeyChanged(key) => self.key = key,
+            Msg::IvChanged(iv) => self.iv = iv,
+            Msg::AadChanged(aad) => self.aad = aad,
+            Msg::RandomKey => match bytes::random_bytes(self.mode.key_length()) {
+                Ok(key) => self.key = self.param_format.encode(&key),
+                Err(error) => self.output = Some(Err(error)),
+            },
+            Msg::RandomIv => match bytes::random_bytes(self.mode.iv_length()) {
+                Ok(iv) => self.iv = self.param_format.encode(&iv),
+                Err(error) => self.output = Some(Err(error)),
+            },
+            Msg::TextChanged(text) => self.text_input = text,
+            Msg::FileSelected(e) => {
+                let input: HtmlInputElement = e.target_unchecked_into();
+                if let Some(file) = input.files().and_then(|files| files.get(0)) {
+                    let file = GlooFile::from(file);
+                    let link = ctx.link().clone();
+                    wasm_bindgen_futures::spawn_local(async move {
+                        let bytes = read_as_bytes(&file).await.map_err(|e| format!("Failed to read file: {}", e));
+                        link.send_message(Msg::FileLoaded(file.name(), bytes));
+                    });
+                }
+                input.set_value("");
+                return false;
+            }
+            Msg::FileLoaded(name, bytes) => match bytes {
+                Ok(bytes) => self.file_input = Some((name, bytes)),
+                Err(error) => {
+                    self.output = Some(Err(error));
+                    return true;
+                }
+            },
+            Msg::ClearFile => self.file_input = None,
+            Msg::Download => {
+                if let Some(Ok(output)) = &self.output {
+                    let file_name = match (&self.file_input, self.decrypt) {
+                        (Some((name, _)), false) => format!("{}.enc", name),
+                        (Some((name, _)), true) => name.strip_suffix(".enc").unwrap_or("decrypted.bin").to_string(),
+                        (None, false) => "ciphertext.bin".to_string(),
+                        (None, true) => "plaintext.bin".to_string(),
+                    };
+                    download_bytes(&file_name, output, "application/octet-stream");
+                }
+                return false;
+            }
+            Msg::CopyToClipboard(value) => {
+                if let Some(clipboard) = window().map(|w| w.navigator().clipboard()) {
+                    wasm_bindgen_futures::spawn_local(async move {
+                        let promise = clipboard.write_text(&value);
+                        let _ = JsFuture::from(promise).await;
+                    });
+                }
+                return false;
+            }
+        }
+
+        self.refresh_output();
+        true
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let link = ctx.link();
+
+        html! {
+            <>
+                <h1 class="tool-title">{ "Encryption & Decryption (AES / ChaCha20)" }</h1>
+                <div class="tool-wrapper">
+                    <div class="tool-intro">
+                        <div class="content-section">
+                            <h2>{"🔐 What is Symmetric Encryption?"}</h2>
+                            <p>{"Symmetric encryption uses the same secret key to encrypt and decrypt data. AES is the most widely used block cipher; ChaCha20-Poly1305 is a fast stream cipher favored on devices without AES hardware. The mode of operation decides how blocks are chained and whether the ciphertext is authenticated."}</p>
+                            <ul>
+                                <li><strong>{"GCM / ChaCha20-Poly1305:"}</strong> {"Authenticated encryption (AEAD) with a 12-byte nonce and a 16-byte tag."}</li>
+                                <li><strong>{"CBC:"}</strong> {"Block chaining with a 16-byte IV and PKCS#7 padding."}</li>
+                                <li><strong>{"CTR:"}</strong> {"Turns AES into a stream cipher with a 16-byte initial counter block."}</li>
+                            </ul>
+                        </div>
+                        <div class="content-section">
+                            <h2>{"⚙️ How This Tool Works"}</h2>
+                            <ul>
+                                <li><strong>{"Encrypt or Decrypt:"}</strong> {"Pick a direction and cipher, then enter the key, IV or nonce and optional AAD in hex or Base64."}</li>
+                                <li><strong>{"Text or Files:"}</strong> {"Type text, paste hex or Base64 ciphertext, or load a file; results update as you type."}</li>
+                                <li><strong>{"Tag Format:"}</strong> {"AEAD ciphertext is the encrypted data followed by the 16-byte authentication tag, as produced by WebCrypto and most libraries."}</li>
+                                <li><strong>{"Clear Errors:"}</strong> {"A wrong key, nonce, AAD or modified ciphertext is reported as an authentication failure instead of garbage output."}</li>
+                                <li><strong>{"Local Processing:"}</strong> {"Keys and data never leave your browser."}</li>
+                            </ul>
+                        </div>
+                        <div class="content-section">
+                            <h2>{"📚 Example"}</h2>
+                            <div class="example-box">
+                                <p><strong>{"AES-128-GCM, key and nonce of zero bytes, plaintext of 16 zero bytes:"}</strong></p>
+                                <ul>
+                                    <li>{"Ciphertext: 0388dace60b6a392f328c2b971b2fe78"}</li>
+                                    <li>{"Tag: ab6e47d42cec13bdf53a67b21257bddf"}</li>
+                                </ul>
+                            </div>
+                        </div>
+                        <div class="content-section">
+                            <h2>{"💡 Common Use Cases"}</h2>
+                            <ul>
+                                <li><strong>{"Debugging Payloads:"}</strong> {"Decrypt messages captured from an API or message queue to see what was sent."}</li>
+                                <li><strong>{"Interoperability Tests:"}</strong> {"Check that two implementations produce the same ciphertext for the same key and nonce."}</li>
+                                <li><strong>{"Test Data:"}</strong> {"Encrypt fixtures for unit tests of decryption code."}</li>
+                            </ul>
+                        </div>
+                        <div class="content-section">
+                            <h2>{"❓ Frequently Asked Questions"}</h2>
+                            <div class="faq-item">
+                                <h3>{"Q: Why does decryption say authentication failed?"}</h3>
+                                <p>{"A: GCM and ChaCha20-Poly1305 check a tag before returning any plaintext. The key, nonce or AAD differs from the one used to encrypt, the tag is missing from the end of the ciphertext, or the data was changed."}</p>
+                            </div>
+                            <div class="faq-item">
+                                <h3>{"Q: My library stores the tag separately. What do I do?"}</h3>
+                                <p>{"A: Append the 16-byte tag to the end of the ciphertext before decrypting."}</p>
+                            </div>
+                            <div class="faq-item">
+                                <h3>{"Q: Does CTR or CBC detect a wrong key?"}</h3>
+                                <p>{"A: CTR never does; it returns unreadable bytes. CBC usually reports invalid padding, but a wrong key can occasionally produce valid-looking padding."}</p>
+                            </div>
+                        </div>
+                        <div class="content-section">
+                            <h2>{"🎯 Best Practices"}</h2>
+                            <ul>
+                                <li><strong>{"Prefer AEAD:"}</strong> {"Use AES-GCM or ChaCha20-Poly1305 so tampering is detected."}</li>
+                                <li><strong>{"Never Reuse a Nonce:"}</strong> {"Reusing a nonce with the same key breaks GCM, CTR and ChaCha20 completely."}</li>
+                                <li><strong>{"Random Keys:"}</strong> {"Generate keys with a secure random source, or derive them from passwords with a KDF."}</li>
+                            </ul>
+                        </div>
+                        <div class="content-section">
+                            <h2>{"🔗 Related Tools"}</h2>
+                            <ul>
+                                {
+                                    ToolCategoryManager::get_related_tools("cipher")
+                                        .iter()
+                                        .map(|tool| {
+                                            html! {
+                                                <li>
+                                                    <a href={format!("/{}/", tool.route_name)}>
+                                                        { &tool.display_name }
+                                                    </a>
+                                                    { " - " }
+                                                    { &tool.description }
+                                                </li>
+                                            }
+                                        })
+                                        .collect::<Html>()
+                                }
+                            </ul>
+                        </div>
+                    </div>
+                    <div class="tool-container">
+                        <div style="display: flex; align-items: center; gap: 10px; padding-left: 20px; padding-right: 20px; margin-bottom: 10px; margin-top: 5px;">
+                            <select
+                                style="width: 40%;"
+                                onchange={link.callback(|e: Event| {
+                                    let select: HtmlInputElement = e.target_unchecked_into();
+                                    Msg::DirectionChanged(select.value())
+                                })}>
+                                <option value="encrypt" selected={!self.decrypt}>{ "Encrypt" }</option>
+                                <option value="decrypt" selected={self.decrypt}>{ "Decrypt" }</option>
+                            </select>
+                            <select
+                                style="width: 60%;"
+                                onchange={link.callback(|e: Event| {
+                                    let select: HtmlInputElement = e.target_unchecked_into();
+                                    Msg::ModeChanged(select.value())
+                                })}>
+                                { for CipherMode::all().into_iter().map(|mode| html! {
+                                    <option value={mode.key()} selected={self.mode == mode}>{ mode.name() }</option>
+                                }) }
+                            </select>
+                        </div>
+                        <div class="tool-inner">
+                            { self.view_params(ctx) }
+                        </div>
+                        <div class="tool-inner" style="margin-top: 10px;">
+                            { self.view_input(ctx) }
+                        </div>
+                        <div class="tool-inner" style="margin-top: 10px;">
+                            { self.view_output(ctx) }
+                        </div>
+                    </div>
+                </div>
+            </>
+        }
+    }
+
+    fn rendered(&mut self, _ctx: &Context<Self>, first_render: bool) {
+        if first_render {
+            if let Some(window) = window() {
+                let document = window.document();
+                if let Some(doc) = document {
+                    doc.set_title("Encryption & Decryption (AES / ChaCha20) | CompuTools");
+
+                    if let Some(meta_tag) =
+                        doc.query_selector("meta[name=\"description\"]").unwrap()
+                    {
+                        meta_tag.set_attribute("content", "Encrypt and decrypt text or files with AES-128/256 in GCM, CBC and CTR modes and ChaCha20-Poly1305. Enter keys, IVs, nonces and AAD in hex or Base64, and get clear errors when an authentication tag doesn't match. All processing happens locally in your browser.").unwrap();
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl ToolCipher {
+    fn refresh_output(&mut self) {
+        let input = match &self.file_input {
+            Some((_, bytes)) => Ok(bytes.clone()),
+            None if self.text_input.is_empty() => {
+                self.output = None;
+                return;
+            }
+            None if self.decrypt => self.data_format.decode(&self.text_input),
+            None => Ok(self.text_input.as_bytes().to_vec()),
+        };
+
+        self.output = Some(input.and_then(|input| {
+            let key = self.param_format.decode(&self.key)?;
+            let iv = self.param_format.decode(&self.iv)?;
+            let aad = self.param_format.decode(&self.aad)?;
+            if self.decrypt {
+                cipher::decrypt(self.mode, &key, &iv, &aad, &input)
+            } else {
+                cipher::encrypt(self.mode, &key, &iv, &aad, &input)
+            }
+        }));
+    }
+
+    fn view_format_select(ctx: &Context<Self>, selected: InputEncoding, on_change: fn(String) -> Msg) -> Html {
+        html! {
+            <select
+                style="width: 30%;"
+                onchange={ctx.link().callback(move |e: Event| {
+                    let select: HtmlInputElement = e.target_unchecked_into();
+                    on_change(select.value())
+                })}>
+                { for InputEncoding::binary().into_iter().map(|format| html! {
+                    <option value={format.key()} selected={selected == format}>{ format.name() }</option>
+                }) }
+            </select>
+        }
+    }
+
+    fn view_params(&self, ctx: &Context<Self>) -> Html {
+        let link = ctx.link();
+        let param_row = |label: String, value: &String, on_input: fn(String) -> Msg, random: Option<Callback<MouseEvent>>| {
+            html! {
+                <div style="margin-bottom: 10px;">
+                    <div style="display: flex; align-items: center; gap: 10px; margin-bottom: 5px;">
+                        <div class="tool-subtitle" style="flex: 1; margin-bottom: 0px;">{ label }</div>
+                        if let Some(random) = random {
+                            <button class="tool-btn" style="width: auto; padding: 2px 10px;" onclick={random}>
+                                { "Random" }
+                            </button>
+                        }
+                    </div>
+                    <input
+                        type="text"
+                        autocomplete="off"
+                        style="font-family: monospace;"
+                        value={value.clone()}
+                        oninput={link.callback(move |e: InputEvent| {
+                            let input: HtmlInputElement = e.target_unchecked_into();
+                            on_input(input.value())
+                        })}
+                    />
+                </div>
+            }
+        };
+
+        html! {
+            <>
+                <div style="display: flex; align-items: center; gap: 10px; margin-bottom: 10px;">
+                    <div class="tool-subtitle" style="flex: 1; margin-bottom: 0px;">{ "Key / IV / AAD Format" }</div>
+                    { Self::view_format_select(ctx, self.param_format, Msg::ParamFormatChanged) }
+                </div>
+                { param_row(format!("Key ({} bytes)", self.mode.key_length()), &self.key, Msg::KeyChanged, Some(link.callback(|_| Msg::RandomKey))) }
+                { param_row(
+                    format!("{} ({} bytes)", self.mode.iv_label(), self.mode.iv_length()),
+                    &self.iv,
+                    Msg::IvChanged,
+                    Some(link.callback(|_| Msg::RandomIv)),
+                ) }
+                if self.mode.is_aead() {
+                    { param_row("AAD (optional)".to_string(), &self.aad, Msg::AadChanged, None) }
+                }
+            </>
+        }
+    }
+
+    fn view_input(&self, ctx: &Context<Self>) -> Html {
+        let link = ctx.link();
+
+        html! {
+            <>
+                <div style="display: flex; align-items: center; gap: 10px; margin-bottom: 5px;">
+                    <div class="tool-subtitle" style="flex: 1; margin-bottom: 0px;">
+                        { if self.decrypt { "Ciphertext" } else { "Plaintext" } }
+                    </div>
+                    if self.decrypt && self.file_input.is_none() {
+                        { Self::view_format_select(ctx, self.data_format, Msg::DataFormatChanged) }
+                    }
+                    <input type="file" id="cipher-upload" style="display: none;" onchange={link.callback(Msg::FileSelected)} />
+                    <label
+                        for="cipher-upload"
+                        style="display: inline-block; background: var(--color-primary); color: white; padding: 2px 10px; border-radius: 4px; cursor: pointer; margin-bottom: 0px;">
+                        { "Load File" }
+                    </label>
+                </div>
+                if let Some((name, bytes)) = &self.file_input {
+                    <div style="display: flex; align-items: center; gap: 10px; font-size: 12px;">
+                        <span style="flex: 1;">{ format!("📄 {} ({} bytes, raw)", name, bytes.len()) }</span>
+                        <button class="tool-btn" style="width: auto; padding: 2px 10px;" onclick={link.callback(|_| Msg::ClearFile)}>
+                            { "Remove" }
+                        </button>
+                    </div>
+                } else {
+                    <textarea
+                        style="width: 100%; height: 120px; font-family: monospace; font-size: 12px;"
+                        placeholder={if self.decrypt { "Paste ciphertext (with the tag at the end for GCM/ChaCha20)..." } else { "Type text to encrypt..." }}
+                        value={self.text_input.clone()}
+                        oninput={link.callback(|e: InputEvent| {
+                            let input: HtmlInputElement = e.target_unchecked_into();
+                            Msg::TextChanged(input.value())
+                        })}
+                    />
+                }
+            </>
+        }
+    }
+
+    fn view_output(&self, ctx: &Context<Self>) -> Html {
+        let link = ctx.link();
+        let (text, note) = match &self.output {
+            Some(Ok(output)) if !self.decrypt => (self.data_format.encode(output), None),
+            Some(Ok(output)) => match std::str::from_utf8(output) {
+                Ok(text) => (text.to_string(), None),
+                Err(_) => (InputEncoding::Hex.encode(output), Some("The plaintext isn't UTF-8 text; showing hex.")),
+            },
+            _ => (String::new(), None),
+        };
+
+        html! {
+            <>
+                <div style="display: flex; align-items: center; gap: 10px; margin-bottom: 5px;">
+                    <div class="tool-subtitle" style="flex: 1; margin-bottom: 0px;">
+                        { if self.decrypt { "Plaintext" } else { "Ciphertext" } }
+                    </div>
+                    if !self.decrypt {
+                        { Self::view_format_select(ctx, self.data_format, Msg::DataFormatChanged) }
+                    }
+                    <button
+                        class="tool-btn"
+                        style="width: auto; padding: 2px 10px;"
+                        disabled={!matches!(self.output, Some(Ok(_)))}
+                        onclick={link.callback(|_| Msg::Download)}>
+                        { "Download" }
+                    </button>
+                </div>
+                if let Some(Err(error)) = &self.output {
+                    <div style="font-size: 12px; color: var(--color-error); font-weight: bold;">{ format!("✗ {}", error) }</div>
+                } else {
+                    <textarea
+                        readonly=true
+                        style="width: 100%; height: 120px; font-family: monospace; font-size: 12px; cursor: pointer;"
+                        placeholder="Output here ..."
+                        value={text}
+                        onclick={link.callback(|e: MouseEvent| {
+                            let input: HtmlInputElement = e.target_unchecked_into();
+                            Msg::CopyToClipboard(input.value())
+                        })}
+                    />
+                    if let Some(note) = note {
+                        <div style="font-size: 12px; color: var(--color-subfont);">{ note }</div>
+                    }
+                }
+            </>
+        }
+    }
+}
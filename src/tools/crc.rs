@@ -1,999 +1,25 @@
-use crc::{
-    Crc, CRC_10_ATM, CRC_10_CDMA2000, CRC_10_GSM, CRC_11_FLEXRAY, CRC_11_UMTS, CRC_12_CDMA2000,
-    CRC_12_DECT, CRC_12_GSM, CRC_12_UMTS, CRC_13_BBC, CRC_14_DARC, CRC_14_GSM, CRC_15_CAN,
-    CRC_15_MPT1327, CRC_16_ARC, CRC_16_CDMA2000, CRC_16_CMS, CRC_16_DDS_110, CRC_16_DECT_R,
-    CRC_16_DECT_X, CRC_16_DNP, CRC_16_EN_13757, CRC_16_GENIBUS, CRC_16_GSM, CRC_16_IBM_3740,
-    CRC_16_IBM_SDLC, CRC_16_ISO_IEC_14443_3_A, CRC_16_KERMIT, CRC_16_LJ1200, CRC_16_M17,
-    CRC_16_MAXIM_DOW, CRC_16_MCRF4XX, CRC_16_MODBUS, CRC_16_NRSC_5, CRC_16_OPENSAFETY_A,
-    CRC_16_OPENSAFETY_B, CRC_16_PROFIBUS, CRC_16_RIELLO, CRC_16_SPI_FUJITSU, CRC_16_T10_DIF,
-    CRC_16_TELEDISK, CRC_16_TMS37157, CRC_16_UMTS, CRC_16_USB, CRC_16_XMODEM, CRC_17_CAN_FD,
-    CRC_21_CAN_FD, CRC_24_BLE, CRC_24_FLEXRAY_A, CRC_24_FLEXRAY_B, CRC_24_INTERLAKEN, CRC_24_LTE_A,
-    CRC_24_LTE_B, CRC_24_OPENPGP, CRC_24_OS_9, CRC_30_CDMA, CRC_31_PHILIPS, CRC_32_AIXM,
-    CRC_32_AUTOSAR, CRC_32_BASE91_D, CRC_32_BZIP2, CRC_32_CD_ROM_EDC, CRC_32_CKSUM, CRC_32_ISCSI,
-    CRC_32_ISO_HDLC, CRC_32_JAMCRC, CRC_32_MEF, CRC_32_MPEG_2, CRC_32_XFER, CRC_3_GSM, CRC_3_ROHC,
-    CRC_40_GSM, CRC_4_G_704, CRC_4_INTERLAKEN, CRC_5_EPC_C1G2, CRC_5_G_704, CRC_5_USB,
-    CRC_64_ECMA_182, CRC_64_GO_ISO, CRC_64_MS, CRC_64_REDIS, CRC_64_WE, CRC_64_XZ,
-    CRC_6_CDMA2000_A, CRC_6_CDMA2000_B, CRC_6_DARC, CRC_6_GSM, CRC_6_G_704, CRC_7_MMC, CRC_7_ROHC,
-    CRC_7_UMTS, CRC_8_AUTOSAR, CRC_8_BLUETOOTH, CRC_8_CDMA2000, CRC_8_DARC, CRC_8_DVB_S2,
-    CRC_8_GSM_A, CRC_8_GSM_B, CRC_8_HITAG, CRC_8_I_432_1, CRC_8_I_CODE, CRC_8_LTE, CRC_8_MAXIM_DOW,
-    CRC_8_MIFARE_MAD, CRC_8_NRSC_5, CRC_8_OPENSAFETY, CRC_8_ROHC, CRC_8_SAE_J1850, CRC_8_SMBUS,
-    CRC_8_TECH_3250, CRC_8_WCDMA,
+use computools_core::crc::codegen::{self, CodeLanguage, CodeStyle};
+use computools_core::crc::compare::{self, ComparisonSort, CrcComparison};
+use computools_core::crc::forcing::{self, ForcedCrc, PatchPosition};
+use computools_core::crc::reveng::{self, CrcMatch, MAX_BRUTE_FORCE_WIDTH};
+use computools_core::crc::verify::{self, CrcVerification};
+use computools_core::crc::{
+    bytes_to_string, format_crc_output, parse_input, parse_param_value, BinaryStyle,
+    ByteFormatting, CrcAlgorithm, CrcParams, Endianness, HexStyle, InputMode, OctalStyle,
+    OutputMode, OutputOptions,
 };
 
+use std::cell::Cell;
+use std::rc::Rc;
+
+use computools_core::file_hash::format_file_size;
 use log::info;
-use regex::Regex;
-use std::str::FromStr;
 use wasm_bindgen_futures::{spawn_local, JsFuture};
-use web_sys::{window, HtmlInputElement, HtmlSelectElement, Storage};
+use wasm_bindgen::JsCast;
+use web_sys::{window, DragEvent, File, HtmlInputElement, HtmlSelectElement, Storage};
 use yew::prelude::*;
 use crate::components::tool_category::ToolCategoryManager;
-
-#[derive(PartialEq, Clone)]
-enum InputMode {
-    Ascii,
-    Hex,
-    Binary,
-    Decimal,
-    Octal,
-}
-
-#[derive(Clone, PartialEq)]
-pub enum OutputMode {
-    Decimal,
-    Hex,
-    Binary,
-    Octal,
-}
-
-#[derive(Clone, PartialEq)]
-pub enum HexStyle {
-    WithPrefix,     // 0x48
-    ShortPrefix,    // x48
-    NoPrefix,       // 48
-    EscapeSequence, // \x48
-}
-
-#[derive(Clone, PartialEq)]
-pub enum BinaryStyle {
-    WithPrefix,    // 0b01001000
-    ShortPrefix,   // b01001000
-    NoPrefix,      // 01001000
-}
-
-#[derive(Clone, PartialEq)]
-pub enum OctalStyle {
-    WithPrefix,     // 0o110
-    ShortPrefix,    // o110
-    NoPrefix,       // 110
-    EscapeSequence, // \110
-}
-
-#[derive(Clone, PartialEq)]
-pub enum Endianness {
-    BigEndian,    // 네트워크 바이트 순서 (MSB first)
-    LittleEndian, // 인텔 x86 바이트 순서 (LSB first)
-}
-
-#[derive(Clone, PartialEq)]
-pub enum ByteFormatting {
-    Continuous,   // 0x12345678
-    ByteSeparated, // 0x12 0x34 0x56 0x78
-}
-
-#[derive(PartialEq, Clone, Copy)]
-enum CrcAlgorithm {
-    Crc3Gsm,
-    Crc3Rohc,
-    Crc4G704,
-    Crc4Interlaken,
-    Crc5EpcC1g2,
-    Crc5G704,
-    Crc5Usb,
-    Crc6Cdma2000A,
-    Crc6Cdma2000B,
-    Crc6Darc,
-    Crc6Gsm,
-    Crc6G704,
-    Crc7Mmc,
-    Crc7Rohc,
-    Crc7Umts,
-    Crc8Autosar,
-    Crc8Bluetooth,
-    Crc8Cdma2000,
-    Crc8Darc,
-    Crc8DvbS2,
-    Crc8GsmA,
-    Crc8GsmB,
-    Crc8Hitag,
-    Crc8I4321,
-    Crc8ICode,
-    Crc8Lte,
-    Crc8MaximDow,
-    Crc8MifareMad,
-    Crc8Nrsc5,
-    Crc8Opensafety,
-    Crc8Rohc,
-    Crc8SaeJ1850,
-    Crc8Smbus,
-    Crc8Tech3250,
-    Crc8Wcdma,
-    Crc10Atm,
-    Crc10Cdma2000,
-    Crc10Gsm,
-    Crc11Flexray,
-    Crc11Umts,
-    Crc12Cdma2000,
-    Crc12Dect,
-    Crc12Gsm,
-    Crc12Umts,
-    Crc13Bbc,
-    Crc14Darc,
-    Crc14Gsm,
-    Crc15Can,
-    Crc15Mpt1327,
-    Crc16Arc,
-    Crc16Cdma2000,
-    Crc16Cms,
-    Crc16Dds110,
-    Crc16DectR,
-    Crc16DectX,
-    Crc16Dnp,
-    Crc16En13757,
-    Crc16Genibus,
-    Crc16Gsm,
-    Crc16Ibm3740,
-    Crc16IbmSdlc,
-    Crc16IsoIec144433A,
-    Crc16Kermit,
-    Crc16Lj1200,
-    Crc16M17,
-    Crc16MaximDow,
-    Crc16Mcrf4xx,
-    Crc16Modbus,
-    Crc16Nrsc5,
-    Crc16OpensafetyA,
-    Crc16OpensafetyB,
-    Crc16Profibus,
-    Crc16Riello,
-    Crc16SpiFujitsu,
-    Crc16T10Dif,
-    Crc16Teledisk,
-    Crc16Tms37157,
-    Crc16Umts,
-    Crc16Usb,
-    Crc16Xmodem,
-    Crc17CanFd,
-    Crc21CanFd,
-    Crc24Ble,
-    Crc24FlexrayA,
-    Crc24FlexrayB,
-    Crc24Interlaken,
-    Crc24LteA,
-    Crc24LteB,
-    Crc24Openpgp,
-    Crc24Os9,
-    Crc30Cdma,
-    Crc31Philips,
-    Crc32Aixm,
-    Crc32Autosar,
-    Crc32Base91D,
-    Crc32Bzip2,
-    Crc32CdRomEdc,
-    Crc32Cksum,
-    Crc32Iscsi,
-    Crc32IsoHdlc,
-    Crc32Jamcrc,
-    Crc32Mef,
-    Crc32Mpeg2,
-    Crc32Xfer,
-    Crc40Gsm,
-    Crc64Ecma182,
-    Crc64GoIso,
-    Crc64Ms,
-    Crc64Redis,
-    Crc64We,
-    Crc64Xz,
-}
-
-impl CrcAlgorithm {
-    fn name(&self) -> &str {
-        match self {
-            CrcAlgorithm::Crc3Gsm => "CRC-3/GSM",
-            CrcAlgorithm::Crc3Rohc => "CRC-3/ROHC",
-            CrcAlgorithm::Crc4G704 => "CRC-4/G-704",
-            CrcAlgorithm::Crc4Interlaken => "CRC-4/INTERLAKEN",
-            CrcAlgorithm::Crc5EpcC1g2 => "CRC-5/EPC-C1G2",
-            CrcAlgorithm::Crc5G704 => "CRC-5/G-704",
-            CrcAlgorithm::Crc5Usb => "CRC-5/USB",
-            CrcAlgorithm::Crc6Cdma2000A => "CRC-6/CDMA2000-A",
-            CrcAlgorithm::Crc6Cdma2000B => "CRC-6/CDMA2000-B",
-            CrcAlgorithm::Crc6Darc => "CRC-6/DARC",
-            CrcAlgorithm::Crc6Gsm => "CRC-6/GSM",
-            CrcAlgorithm::Crc6G704 => "CRC-6/G-704",
-            CrcAlgorithm::Crc7Mmc => "CRC-7/MMC",
-            CrcAlgorithm::Crc7Rohc => "CRC-7/ROHC",
-            CrcAlgorithm::Crc7Umts => "CRC-7/UMTS",
-            CrcAlgorithm::Crc8Autosar => "CRC-8/AUTOSAR",
-            CrcAlgorithm::Crc8Bluetooth => "CRC-8/BLUETOOTH",
-            CrcAlgorithm::Crc8Cdma2000 => "CRC-8/CDMA2000",
-            CrcAlgorithm::Crc8Darc => "CRC-8/DARC",
-            CrcAlgorithm::Crc8DvbS2 => "CRC-8/DVB-S2",
-            CrcAlgorithm::Crc8GsmA => "CRC-8/GSM-A",
-            CrcAlgorithm::Crc8GsmB => "CRC-8/GSM-B",
-            CrcAlgorithm::Crc8Hitag => "CRC-8/HITAG",
-            CrcAlgorithm::Crc8I4321 => "CRC-8/I-432-1",
-            CrcAlgorithm::Crc8ICode => "CRC-8/I-CODE",
-            CrcAlgorithm::Crc8Lte => "CRC-8/LTE",
-            CrcAlgorithm::Crc8MaximDow => "CRC-8/MAXIM-DOW",
-            CrcAlgorithm::Crc8MifareMad => "CRC-8/MIFARE-MAD",
-            CrcAlgorithm::Crc8Nrsc5 => "CRC-8/NRSC-5",
-            CrcAlgorithm::Crc8Opensafety => "CRC-8/OPENSAFETY",
-            CrcAlgorithm::Crc8Rohc => "CRC-8/ROHC",
-            CrcAlgorithm::Crc8SaeJ1850 => "CRC-8/SAE-J1850",
-            CrcAlgorithm::Crc8Smbus => "CRC-8/SMBUS",
-            CrcAlgorithm::Crc8Tech3250 => "CRC-8/TECH-3250",
-            CrcAlgorithm::Crc8Wcdma => "CRC-8/WCDMA",
-            CrcAlgorithm::Crc10Atm => "CRC-10/ATM",
-            CrcAlgorithm::Crc10Cdma2000 => "CRC-10/CDMA2000",
-            CrcAlgorithm::Crc10Gsm => "CRC-10/GSM",
-            CrcAlgorithm::Crc11Flexray => "CRC-11/FLEXRAY",
-            CrcAlgorithm::Crc11Umts => "CRC-11/UMTS",
-            CrcAlgorithm::Crc12Cdma2000 => "CRC-12/CDMA2000",
-            CrcAlgorithm::Crc12Dect => "CRC-12/DECT",
-            CrcAlgorithm::Crc12Gsm => "CRC-12/GSM",
-            CrcAlgorithm::Crc12Umts => "CRC-12/UMTS",
-            CrcAlgorithm::Crc13Bbc => "CRC-13/BBC",
-            CrcAlgorithm::Crc14Darc => "CRC-14/DARC",
-            CrcAlgorithm::Crc14Gsm => "CRC-14/GSM",
-            CrcAlgorithm::Crc15Can => "CRC-15/CAN",
-            CrcAlgorithm::Crc15Mpt1327 => "CRC-15/MPT1327",
-            CrcAlgorithm::Crc16Arc => "CRC-16/ARC",
-            CrcAlgorithm::Crc16Cdma2000 => "CRC-16/CDMA2000",
-            CrcAlgorithm::Crc16Cms => "CRC-16/CMS",
-            CrcAlgorithm::Crc16Dds110 => "CRC-16/DDS-110",
-            CrcAlgorithm::Crc16DectR => "CRC-16/DECT-R",
-            CrcAlgorithm::Crc16DectX => "CRC-16/DECT-X",
-            CrcAlgorithm::Crc16Dnp => "CRC-16/DNP",
-            CrcAlgorithm::Crc16En13757 => "CRC-16/EN-13757",
-            CrcAlgorithm::Crc16Genibus => "CRC-16/GENIBUS",
-            CrcAlgorithm::Crc16Gsm => "CRC-16/GSM",
-            CrcAlgorithm::Crc16Ibm3740 => "CRC-16/IBM-3740",
-            CrcAlgorithm::Crc16IbmSdlc => "CRC-16/IBM-SDLC",
-            CrcAlgorithm::Crc16IsoIec144433A => "CRC-16/ISO-IEC-14443-3-A",
-            CrcAlgorithm::Crc16Kermit => "CRC-16/KERMIT",
-            CrcAlgorithm::Crc16Lj1200 => "CRC-16/LJ1200",
-            CrcAlgorithm::Crc16M17 => "CRC-16/M17",
-            CrcAlgorithm::Crc16MaximDow => "CRC-16/MAXIM-DOW",
-            CrcAlgorithm::Crc16Mcrf4xx => "CRC-16/MCRF4XX",
-            CrcAlgorithm::Crc16Modbus => "CRC-16/MODBUS",
-            CrcAlgorithm::Crc16Nrsc5 => "CRC-16/NRSC-5",
-            CrcAlgorithm::Crc16OpensafetyA => "CRC-16/OPENSAFETY-A",
-            CrcAlgorithm::Crc16OpensafetyB => "CRC-16/OPENSAFETY-B",
-            CrcAlgorithm::Crc16Profibus => "CRC-16/PROFIBUS",
-            CrcAlgorithm::Crc16Riello => "CRC-16/RIELLO",
-            CrcAlgorithm::Crc16SpiFujitsu => "CRC-16/SPI-FUJITSU",
-            CrcAlgorithm::Crc16T10Dif => "CRC-16/T10-DIF",
-            CrcAlgorithm::Crc16Teledisk => "CRC-16/TELEDISK",
-            CrcAlgorithm::Crc16Tms37157 => "CRC-16/TMS37157",
-            CrcAlgorithm::Crc16Umts => "CRC-16/UMTS",
-            CrcAlgorithm::Crc16Usb => "CRC-16/USB",
-            CrcAlgorithm::Crc16Xmodem => "CRC-16/XMODEM",
-            CrcAlgorithm::Crc17CanFd => "CRC-17/CAN-FD",
-            CrcAlgorithm::Crc21CanFd => "CRC-21/CAN-FD",
-            CrcAlgorithm::Crc24Ble => "CRC-24/BLE",
-            CrcAlgorithm::Crc24FlexrayA => "CRC-24/FLEXRAY-A",
-            CrcAlgorithm::Crc24FlexrayB => "CRC-24/FLEXRAY-B",
-            CrcAlgorithm::Crc24Interlaken => "CRC-24/INTERLAKEN",
-            CrcAlgorithm::Crc24LteA => "CRC-24/LTE-A",
-            CrcAlgorithm::Crc24LteB => "CRC-24/LTE-B",
-            CrcAlgorithm::Crc24Openpgp => "CRC-24/OPENPGP",
-            CrcAlgorithm::Crc24Os9 => "CRC-24/OS-9",
-            CrcAlgorithm::Crc30Cdma => "CRC-30/CDMA",
-            CrcAlgorithm::Crc31Philips => "CRC-31/PHILIPS",
-            CrcAlgorithm::Crc32Aixm => "CRC-32/AIXM",
-            CrcAlgorithm::Crc32Autosar => "CRC-32/AUTOSAR",
-            CrcAlgorithm::Crc32Base91D => "CRC-32/BASE91-D",
-            CrcAlgorithm::Crc32Bzip2 => "CRC-32/BZIP2",
-            CrcAlgorithm::Crc32CdRomEdc => "CRC-32/CD-ROM-EDC",
-            CrcAlgorithm::Crc32Cksum => "CRC-32/CKSUM",
-            CrcAlgorithm::Crc32Iscsi => "CRC-32/ISCSI",
-            CrcAlgorithm::Crc32IsoHdlc => "CRC-32/ISO-HDLC",
-            CrcAlgorithm::Crc32Jamcrc => "CRC-32/JAMCRC",
-            CrcAlgorithm::Crc32Mef => "CRC-32/MEF",
-            CrcAlgorithm::Crc32Mpeg2 => "CRC-32/MPEG-2",
-            CrcAlgorithm::Crc32Xfer => "CRC-32/XFER",
-            CrcAlgorithm::Crc40Gsm => "CRC-40/GSM",
-            CrcAlgorithm::Crc64Ecma182 => "CRC-64/ECMA-182",
-            CrcAlgorithm::Crc64GoIso => "CRC-64/GO-ISO",
-            CrcAlgorithm::Crc64Ms => "CRC-64/MS",
-            CrcAlgorithm::Crc64Redis => "CRC-64/REDIS",
-            CrcAlgorithm::Crc64We => "CRC-64/WE",
-            CrcAlgorithm::Crc64Xz => "CRC-64/XZ",
-        }
-    }
-
-    fn from_name(name: &str) -> Option<CrcAlgorithm> {
-        match name {
-            "CRC-3/GSM" => Some(CrcAlgorithm::Crc3Gsm),
-            "CRC-3/ROHC" => Some(CrcAlgorithm::Crc3Rohc),
-            "CRC-4/G-704" => Some(CrcAlgorithm::Crc4G704),
-            "CRC-4/INTERLAKEN" => Some(CrcAlgorithm::Crc4Interlaken),
-            "CRC-5/EPC-C1G2" => Some(CrcAlgorithm::Crc5EpcC1g2),
-            "CRC-5/G-704" => Some(CrcAlgorithm::Crc5G704),
-            "CRC-5/USB" => Some(CrcAlgorithm::Crc5Usb),
-            "CRC-6/CDMA2000-A" => Some(CrcAlgorithm::Crc6Cdma2000A),
-            "CRC-6/CDMA2000-B" => Some(CrcAlgorithm::Crc6Cdma2000B),
-            "CRC-6/DARC" => Some(CrcAlgorithm::Crc6Darc),
-            "CRC-6/GSM" => Some(CrcAlgorithm::Crc6Gsm),
-            "CRC-6/G-704" => Some(CrcAlgorithm::Crc6G704),
-            "CRC-7/MMC" => Some(CrcAlgorithm::Crc7Mmc),
-            "CRC-7/ROHC" => Some(CrcAlgorithm::Crc7Rohc),
-            "CRC-7/UMTS" => Some(CrcAlgorithm::Crc7Umts),
-            "CRC-8/AUTOSAR" => Some(CrcAlgorithm::Crc8Autosar),
-            "CRC-8/BLUETOOTH" => Some(CrcAlgorithm::Crc8Bluetooth),
-            "CRC-8/CDMA2000" => Some(CrcAlgorithm::Crc8Cdma2000),
-            "CRC-8/DARC" => Some(CrcAlgorithm::Crc8Darc),
-            "CRC-8/DVB-S2" => Some(CrcAlgorithm::Crc8DvbS2),
-            "CRC-8/GSM-A" => Some(CrcAlgorithm::Crc8GsmA),
-            "CRC-8/GSM-B" => Some(CrcAlgorithm::Crc8GsmB),
-            "CRC-8/HITAG" => Some(CrcAlgorithm::Crc8Hitag),
-            "CRC-8/I-432-1" => Some(CrcAlgorithm::Crc8I4321),
-            "CRC-8/I-CODE" => Some(CrcAlgorithm::Crc8ICode),
-            "CRC-8/LTE" => Some(CrcAlgorithm::Crc8Lte),
-            "CRC-8/MAXIM-DOW" => Some(CrcAlgorithm::Crc8MaximDow),
-            "CRC-8/MIFARE-MAD" => Some(CrcAlgorithm::Crc8MifareMad),
-            "CRC-8/NRSC-5" => Some(CrcAlgorithm::Crc8Nrsc5),
-            "CRC-8/OPENSAFETY" => Some(CrcAlgorithm::Crc8Opensafety),
-            "CRC-8/ROHC" => Some(CrcAlgorithm::Crc8Rohc),
-            "CRC-8/SAE-J1850" => Some(CrcAlgorithm::Crc8SaeJ1850),
-            "CRC-8/SMBUS" => Some(CrcAlgorithm::Crc8Smbus),
-            "CRC-8/TECH-3250" => Some(CrcAlgorithm::Crc8Tech3250),
-            "CRC-8/WCDMA" => Some(CrcAlgorithm::Crc8Wcdma),
-            "CRC-10/ATM" => Some(CrcAlgorithm::Crc10Atm),
-            "CRC-10/CDMA2000" => Some(CrcAlgorithm::Crc10Cdma2000),
-            "CRC-10/GSM" => Some(CrcAlgorithm::Crc10Gsm),
-            "CRC-11/FLEXRAY" => Some(CrcAlgorithm::Crc11Flexray),
-            "CRC-11/UMTS" => Some(CrcAlgorithm::Crc11Umts),
-            "CRC-12/CDMA2000" => Some(CrcAlgorithm::Crc12Cdma2000),
-            "CRC-12/DECT" => Some(CrcAlgorithm::Crc12Dect),
-            "CRC-12/GSM" => Some(CrcAlgorithm::Crc12Gsm),
-            "CRC-12/UMTS" => Some(CrcAlgorithm::Crc12Umts),
-            "CRC-13/BBC" => Some(CrcAlgorithm::Crc13Bbc),
-            "CRC-14/DARC" => Some(CrcAlgorithm::Crc14Darc),
-            "CRC-14/GSM" => Some(CrcAlgorithm::Crc14Gsm),
-            "CRC-15/CAN" => Some(CrcAlgorithm::Crc15Can),
-            "CRC-15/MPT1327" => Some(CrcAlgorithm::Crc15Mpt1327),
-            "CRC-16/ARC" => Some(CrcAlgorithm::Crc16Arc),
-            "CRC-16/CDMA2000" => Some(CrcAlgorithm::Crc16Cdma2000),
-            "CRC-16/CMS" => Some(CrcAlgorithm::Crc16Cms),
-            "CRC-16/DDS-110" => Some(CrcAlgorithm::Crc16Dds110),
-            "CRC-16/DECT-R" => Some(CrcAlgorithm::Crc16DectR),
-            "CRC-16/DECT-X" => Some(CrcAlgorithm::Crc16DectX),
-            "CRC-16/DNP" => Some(CrcAlgorithm::Crc16Dnp),
-            "CRC-16/EN-13757" => Some(CrcAlgorithm::Crc16En13757),
-            "CRC-16/GENIBUS" => Some(CrcAlgorithm::Crc16Genibus),
-            "CRC-16/GSM" => Some(CrcAlgorithm::Crc16Gsm),
-            "CRC-16/IBM-3740" => Some(CrcAlgorithm::Crc16Ibm3740),
-            "CRC-16/IBM-SDLC" => Some(CrcAlgorithm::Crc16IbmSdlc),
-            "CRC-16/ISO-IEC-14443-3-A" => Some(CrcAlgorithm::Crc16IsoIec144433A),
-            "CRC-16/KERMIT" => Some(CrcAlgorithm::Crc16Kermit),
-            "CRC-16/LJ1200" => Some(CrcAlgorithm::Crc16Lj1200),
-            "CRC-16/M17" => Some(CrcAlgorithm::Crc16M17),
-            "CRC-16/MAXIM-DOW" => Some(CrcAlgorithm::Crc16MaximDow),
-            "CRC-16/MCRF4XX" => Some(CrcAlgorithm::Crc16Mcrf4xx),
-            "CRC-16/MODBUS" => Some(CrcAlgorithm::Crc16Modbus),
-            "CRC-16/NRSC-5" => Some(CrcAlgorithm::Crc16Nrsc5),
-            "CRC-16/OPENSAFETY-A" => Some(CrcAlgorithm::Crc16OpensafetyA),
-            "CRC-16/OPENSAFETY-B" => Some(CrcAlgorithm::Crc16OpensafetyB),
-            "CRC-16/PROFIBUS" => Some(CrcAlgorithm::Crc16Profibus),
-            "CRC-16/RIELLO" => Some(CrcAlgorithm::Crc16Riello),
-            "CRC-16/SPI-FUJITSU" => Some(CrcAlgorithm::Crc16SpiFujitsu),
-            "CRC-16/T10-DIF" => Some(CrcAlgorithm::Crc16T10Dif),
-            "CRC-16/TELEDISK" => Some(CrcAlgorithm::Crc16Teledisk),
-            "CRC-16/TMS37157" => Some(CrcAlgorithm::Crc16Tms37157),
-            "CRC-16/UMTS" => Some(CrcAlgorithm::Crc16Umts),
-            "CRC-16/USB" => Some(CrcAlgorithm::Crc16Usb),
-            "CRC-16/XMODEM" => Some(CrcAlgorithm::Crc16Xmodem),
-            "CRC-17/CAN-FD" => Some(CrcAlgorithm::Crc17CanFd),
-            "CRC-21/CAN-FD" => Some(CrcAlgorithm::Crc21CanFd),
-            "CRC-24/BLE" => Some(CrcAlgorithm::Crc24Ble),
-            "CRC-24/FLEXRAY-A" => Some(CrcAlgorithm::Crc24FlexrayA),
-            "CRC-24/FLEXRAY-B" => Some(CrcAlgorithm::Crc24FlexrayB),
-            "CRC-24/INTERLAKEN" => Some(CrcAlgorithm::Crc24Interlaken),
-            "CRC-24/LTE-A" => Some(CrcAlgorithm::Crc24LteA),
-            "CRC-24/LTE-B" => Some(CrcAlgorithm::Crc24LteB),
-            "CRC-24/OPENPGP" => Some(CrcAlgorithm::Crc24Openpgp),
-            "CRC-24/OS-9" => Some(CrcAlgorithm::Crc24Os9),
-            "CRC-30/CDMA" => Some(CrcAlgorithm::Crc30Cdma),
-            "CRC-31/PHILIPS" => Some(CrcAlgorithm::Crc31Philips),
-            "CRC-32/AIXM" => Some(CrcAlgorithm::Crc32Aixm),
-            "CRC-32/AUTOSAR" => Some(CrcAlgorithm::Crc32Autosar),
-            "CRC-32/BASE91-D" => Some(CrcAlgorithm::Crc32Base91D),
-            "CRC-32/BZIP2" => Some(CrcAlgorithm::Crc32Bzip2),
-            "CRC-32/CD-ROM-EDC" => Some(CrcAlgorithm::Crc32CdRomEdc),
-            "CRC-32/CKSUM" => Some(CrcAlgorithm::Crc32Cksum),
-            "CRC-32/ISCSI" => Some(CrcAlgorithm::Crc32Iscsi),
-            "CRC-32/ISO-HDLC" => Some(CrcAlgorithm::Crc32IsoHdlc),
-            "CRC-32/JAMCRC" => Some(CrcAlgorithm::Crc32Jamcrc),
-            "CRC-32/MEF" => Some(CrcAlgorithm::Crc32Mef),
-            "CRC-32/MPEG-2" => Some(CrcAlgorithm::Crc32Mpeg2),
-            "CRC-32/XFER" => Some(CrcAlgorithm::Crc32Xfer),
-            "CRC-40/GSM" => Some(CrcAlgorithm::Crc40Gsm),
-            "CRC-64/ECMA-182" => Some(CrcAlgorithm::Crc64Ecma182),
-            "CRC-64/GO-ISO" => Some(CrcAlgorithm::Crc64GoIso),
-            "CRC-64/MS" => Some(CrcAlgorithm::Crc64Ms),
-            "CRC-64/REDIS" => Some(CrcAlgorithm::Crc64Redis),
-            "CRC-64/WE" => Some(CrcAlgorithm::Crc64We),
-            "CRC-64/XZ" => Some(CrcAlgorithm::Crc64Xz),
-            _ => None, // name이 매칭되지 않으면 None 반환
-        }
-    }
-
-    fn calculate(&self, data: &[u8]) -> (u64, u8) {
-        match self {
-            CrcAlgorithm::Crc3Gsm => {
-                let crc = Crc::<u8>::new(&CRC_3_GSM);
-                (crc.checksum(data) as u64, 3)
-            }
-            CrcAlgorithm::Crc3Rohc => {
-                let crc = Crc::<u8>::new(&CRC_3_ROHC);
-                (crc.checksum(data) as u64, 3)
-            }
-            CrcAlgorithm::Crc4G704 => {
-                let crc = Crc::<u8>::new(&CRC_4_G_704);
-                (crc.checksum(data) as u64, 4)
-            }
-            CrcAlgorithm::Crc4Interlaken => {
-                let crc = Crc::<u8>::new(&CRC_4_INTERLAKEN);
-                (crc.checksum(data) as u64, 4)
-            }
-            CrcAlgorithm::Crc5EpcC1g2 => {
-                let crc = Crc::<u8>::new(&CRC_5_EPC_C1G2);
-                (crc.checksum(data) as u64, 5)
-            }
-            CrcAlgorithm::Crc5G704 => {
-                let crc = Crc::<u8>::new(&CRC_5_G_704);
-                (crc.checksum(data) as u64, 5)
-            }
-            CrcAlgorithm::Crc5Usb => {
-                let crc = Crc::<u8>::new(&CRC_5_USB);
-                (crc.checksum(data) as u64, 5)
-            }
-            CrcAlgorithm::Crc6Cdma2000A => {
-                let crc = Crc::<u8>::new(&CRC_6_CDMA2000_A);
-                (crc.checksum(data) as u64, 6)
-            }
-            CrcAlgorithm::Crc6Cdma2000B => {
-                let crc = Crc::<u8>::new(&CRC_6_CDMA2000_B);
-                (crc.checksum(data) as u64, 6)
-            }
-            CrcAlgorithm::Crc6Darc => {
-                let crc = Crc::<u8>::new(&CRC_6_DARC);
-                (crc.checksum(data) as u64, 6)
-            }
-            CrcAlgorithm::Crc6Gsm => {
-                let crc = Crc::<u8>::new(&CRC_6_GSM);
-                (crc.checksum(data) as u64, 6)
-            }
-            CrcAlgorithm::Crc6G704 => {
-                let crc = Crc::<u8>::new(&CRC_6_G_704);
-                (crc.checksum(data) as u64, 6)
-            }
-            CrcAlgorithm::Crc7Mmc => {
-                let crc = Crc::<u8>::new(&CRC_7_MMC);
-                (crc.checksum(data) as u64, 7)
-            }
-            CrcAlgorithm::Crc7Rohc => {
-                let crc = Crc::<u8>::new(&CRC_7_ROHC);
-                (crc.checksum(data) as u64, 7)
-            }
-            CrcAlgorithm::Crc7Umts => {
-                let crc = Crc::<u8>::new(&CRC_7_UMTS);
-                (crc.checksum(data) as u64, 7)
-            }
-            CrcAlgorithm::Crc8Autosar => {
-                let crc = Crc::<u8>::new(&CRC_8_AUTOSAR);
-                (crc.checksum(data) as u64, 8)
-            }
-            CrcAlgorithm::Crc8Bluetooth => {
-                let crc = Crc::<u8>::new(&CRC_8_BLUETOOTH);
-                (crc.checksum(data) as u64, 8)
-            }
-            CrcAlgorithm::Crc8Cdma2000 => {
-                let crc = Crc::<u8>::new(&CRC_8_CDMA2000);
-                (crc.checksum(data) as u64, 8)
-            }
-            CrcAlgorithm::Crc8Darc => {
-                let crc = Crc::<u8>::new(&CRC_8_DARC);
-                (crc.checksum(data) as u64, 8)
-            }
-            CrcAlgorithm::Crc8DvbS2 => {
-                let crc = Crc::<u8>::new(&CRC_8_DVB_S2);
-                (crc.checksum(data) as u64, 8)
-            }
-            CrcAlgorithm::Crc8GsmA => {
-                let crc = Crc::<u8>::new(&CRC_8_GSM_A);
-                (crc.checksum(data) as u64, 8)
-            }
-            CrcAlgorithm::Crc8GsmB => {
-                let crc = Crc::<u8>::new(&CRC_8_GSM_B);
-                (crc.checksum(data) as u64, 8)
-            }
-            CrcAlgorithm::Crc8Hitag => {
-                let crc = Crc::<u8>::new(&CRC_8_HITAG);
-                (crc.checksum(data) as u64, 8)
-            }
-            CrcAlgorithm::Crc8I4321 => {
-                let crc = Crc::<u8>::new(&CRC_8_I_432_1);
-                (crc.checksum(data) as u64, 8)
-            }
-            CrcAlgorithm::Crc8ICode => {
-                let crc = Crc::<u8>::new(&CRC_8_I_CODE);
-                (crc.checksum(data) as u64, 8)
-            }
-            CrcAlgorithm::Crc8Lte => {
-                let crc = Crc::<u8>::new(&CRC_8_LTE);
-                (crc.checksum(data) as u64, 8)
-            }
-            CrcAlgorithm::Crc8MaximDow => {
-                let crc = Crc::<u8>::new(&CRC_8_MAXIM_DOW);
-                (crc.checksum(data) as u64, 8)
-            }
-            CrcAlgorithm::Crc8MifareMad => {
-                let crc = Crc::<u8>::new(&CRC_8_MIFARE_MAD);
-                (crc.checksum(data) as u64, 8)
-            }
-            CrcAlgorithm::Crc8Nrsc5 => {
-                let crc = Crc::<u8>::new(&CRC_8_NRSC_5);
-                (crc.checksum(data) as u64, 8)
-            }
-            CrcAlgorithm::Crc8Opensafety => {
-                let crc = Crc::<u8>::new(&CRC_8_OPENSAFETY);
-                (crc.checksum(data) as u64, 8)
-            }
-            CrcAlgorithm::Crc8Rohc => {
-                let crc = Crc::<u8>::new(&CRC_8_ROHC);
-                (crc.checksum(data) as u64, 8)
-            }
-            CrcAlgorithm::Crc8SaeJ1850 => {
-                let crc = Crc::<u8>::new(&CRC_8_SAE_J1850);
-                (crc.checksum(data) as u64, 8)
-            }
-            CrcAlgorithm::Crc8Smbus => {
-                let crc = Crc::<u8>::new(&CRC_8_SMBUS);
-                (crc.checksum(data) as u64, 8)
-            }
-            CrcAlgorithm::Crc8Tech3250 => {
-                let crc = Crc::<u8>::new(&CRC_8_TECH_3250);
-                (crc.checksum(data) as u64, 8)
-            }
-            CrcAlgorithm::Crc8Wcdma => {
-                let crc = Crc::<u8>::new(&CRC_8_WCDMA);
-                (crc.checksum(data) as u64, 8)
-            }
-            CrcAlgorithm::Crc10Atm => {
-                let crc = Crc::<u16>::new(&CRC_10_ATM);
-                (crc.checksum(data) as u64, 10)
-            }
-            CrcAlgorithm::Crc10Cdma2000 => {
-                let crc = Crc::<u16>::new(&CRC_10_CDMA2000);
-                (crc.checksum(data) as u64, 10)
-            }
-            CrcAlgorithm::Crc10Gsm => {
-                let crc = Crc::<u16>::new(&CRC_10_GSM);
-                (crc.checksum(data) as u64, 10)
-            }
-            CrcAlgorithm::Crc11Flexray => {
-                let crc = Crc::<u16>::new(&CRC_11_FLEXRAY);
-                (crc.checksum(data) as u64, 11)
-            }
-            CrcAlgorithm::Crc11Umts => {
-                let crc = Crc::<u16>::new(&CRC_11_UMTS);
-                (crc.checksum(data) as u64, 11)
-            }
-            CrcAlgorithm::Crc12Cdma2000 => {
-                let crc = Crc::<u16>::new(&CRC_12_CDMA2000);
-                (crc.checksum(data) as u64, 12)
-            }
-            CrcAlgorithm::Crc12Dect => {
-                let crc = Crc::<u16>::new(&CRC_12_DECT);
-                (crc.checksum(data) as u64, 12)
-            }
-            CrcAlgorithm::Crc12Gsm => {
-                let crc = Crc::<u16>::new(&CRC_12_GSM);
-                (crc.checksum(data) as u64, 12)
-            }
-            CrcAlgorithm::Crc12Umts => {
-                let crc = Crc::<u16>::new(&CRC_12_UMTS);
-                (crc.checksum(data) as u64, 12)
-            }
-            CrcAlgorithm::Crc13Bbc => {
-                let crc = Crc::<u16>::new(&CRC_13_BBC);
-                (crc.checksum(data) as u64, 13)
-            }
-            CrcAlgorithm::Crc14Darc => {
-                let crc = Crc::<u16>::new(&CRC_14_DARC);
-                (crc.checksum(data) as u64, 14)
-            }
-            CrcAlgorithm::Crc14Gsm => {
-                let crc = Crc::<u16>::new(&CRC_14_GSM);
-                (crc.checksum(data) as u64, 14)
-            }
-            CrcAlgorithm::Crc15Can => {
-                let crc = Crc::<u16>::new(&CRC_15_CAN);
-                (crc.checksum(data) as u64, 15)
-            }
-            CrcAlgorithm::Crc15Mpt1327 => {
-                let crc = Crc::<u16>::new(&CRC_15_MPT1327);
-                (crc.checksum(data) as u64, 15)
-            }
-            CrcAlgorithm::Crc16Arc => {
-                let crc = Crc::<u16>::new(&CRC_16_ARC);
-                (crc.checksum(data) as u64, 16)
-            }
-            CrcAlgorithm::Crc16Cdma2000 => {
-                let crc = Crc::<u16>::new(&CRC_16_CDMA2000);
-                (crc.checksum(data) as u64, 16)
-            }
-            CrcAlgorithm::Crc16Cms => {
-                let crc = Crc::<u16>::new(&CRC_16_CMS);
-                (crc.checksum(data) as u64, 16)
-            }
-            CrcAlgorithm::Crc16Dds110 => {
-                let crc = Crc::<u16>::new(&CRC_16_DDS_110);
-                (crc.checksum(data) as u64, 16)
-            }
-            CrcAlgorithm::Crc16DectR => {
-                let crc = Crc::<u16>::new(&CRC_16_DECT_R);
-                (crc.checksum(data) as u64, 16)
-            }
-            CrcAlgorithm::Crc16DectX => {
-                let crc = Crc::<u16>::new(&CRC_16_DECT_X);
-                (crc.checksum(data) as u64, 16)
-            }
-            CrcAlgorithm::Crc16Dnp => {
-                let crc = Crc::<u16>::new(&CRC_16_DNP);
-                (crc.checksum(data) as u64, 16)
-            }
-            CrcAlgorithm::Crc16En13757 => {
-                let crc = Crc::<u16>::new(&CRC_16_EN_13757);
-                (crc.checksum(data) as u64, 16)
-            }
-            CrcAlgorithm::Crc16Genibus => {
-                let crc = Crc::<u16>::new(&CRC_16_GENIBUS);
-                (crc.checksum(data) as u64, 16)
-            }
-            CrcAlgorithm::Crc16Gsm => {
-                let crc = Crc::<u16>::new(&CRC_16_GSM);
-                (crc.checksum(data) as u64, 16)
-            }
-            CrcAlgorithm::Crc16Ibm3740 => {
-                let crc = Crc::<u16>::new(&CRC_16_IBM_3740);
-                (crc.checksum(data) as u64, 16)
-            }
-            CrcAlgorithm::Crc16IbmSdlc => {
-                let crc = Crc::<u16>::new(&CRC_16_IBM_SDLC);
-                (crc.checksum(data) as u64, 16)
-            }
-            CrcAlgorithm::Crc16IsoIec144433A => {
-                let crc = Crc::<u16>::new(&CRC_16_ISO_IEC_14443_3_A);
-                (crc.checksum(data) as u64, 16)
-            }
-            CrcAlgorithm::Crc16Kermit => {
-                let crc = Crc::<u16>::new(&CRC_16_KERMIT);
-                (crc.checksum(data) as u64, 16)
-            }
-            CrcAlgorithm::Crc16Lj1200 => {
-                let crc = Crc::<u16>::new(&CRC_16_LJ1200);
-                (crc.checksum(data) as u64, 16)
-            }
-            CrcAlgorithm::Crc16M17 => {
-                let crc = Crc::<u16>::new(&CRC_16_M17);
-                (crc.checksum(data) as u64, 16)
-            }
-            CrcAlgorithm::Crc16MaximDow => {
-                let crc = Crc::<u16>::new(&CRC_16_MAXIM_DOW);
-                (crc.checksum(data) as u64, 16)
-            }
-            CrcAlgorithm::Crc16Mcrf4xx => {
-                let crc = Crc::<u16>::new(&CRC_16_MCRF4XX);
-                (crc.checksum(data) as u64, 16)
-            }
-            CrcAlgorithm::Crc16Modbus => {
-                let crc = Crc::<u16>::new(&CRC_16_MODBUS);
-                (crc.checksum(data) as u64, 16)
-            }
-            CrcAlgorithm::Crc16Nrsc5 => {
-                let crc = Crc::<u16>::new(&CRC_16_NRSC_5);
-                (crc.checksum(data) as u64, 16)
-            }
-            CrcAlgorithm::Crc16OpensafetyA => {
-                let crc = Crc::<u16>::new(&CRC_16_OPENSAFETY_A);
-                (crc.checksum(data) as u64, 16)
-            }
-            CrcAlgorithm::Crc16OpensafetyB => {
-                let crc = Crc::<u16>::new(&CRC_16_OPENSAFETY_B);
-                (crc.checksum(data) as u64, 16)
-            }
-            CrcAlgorithm::Crc16Profibus => {
-                let crc = Crc::<u16>::new(&CRC_16_PROFIBUS);
-                (crc.checksum(data) as u64, 16)
-            }
-            CrcAlgorithm::Crc16Riello => {
-                let crc = Crc::<u16>::new(&CRC_16_RIELLO);
-                (crc.checksum(data) as u64, 16)
-            }
-            CrcAlgorithm::Crc16SpiFujitsu => {
-                let crc = Crc::<u16>::new(&CRC_16_SPI_FUJITSU);
-                (crc.checksum(data) as u64, 16)
-            }
-            CrcAlgorithm::Crc16T10Dif => {
-                let crc = Crc::<u16>::new(&CRC_16_T10_DIF);
-                (crc.checksum(data) as u64, 16)
-            }
-            CrcAlgorithm::Crc16Teledisk => {
-                let crc = Crc::<u16>::new(&CRC_16_TELEDISK);
-                (crc.checksum(data) as u64, 16)
-            }
-            CrcAlgorithm::Crc16Tms37157 => {
-                let crc = Crc::<u16>::new(&CRC_16_TMS37157);
-                (crc.checksum(data) as u64, 16)
-            }
-            CrcAlgorithm::Crc16Umts => {
-                let crc = Crc::<u16>::new(&CRC_16_UMTS);
-                (crc.checksum(data) as u64, 16)
-            }
-            CrcAlgorithm::Crc16Usb => {
-                let crc = Crc::<u16>::new(&CRC_16_USB);
-                (crc.checksum(data) as u64, 16)
-            }
-            CrcAlgorithm::Crc16Xmodem => {
-                let crc = Crc::<u16>::new(&CRC_16_XMODEM);
-                (crc.checksum(data) as u64, 16)
-            }
-            CrcAlgorithm::Crc17CanFd => {
-                let crc = Crc::<u32>::new(&CRC_17_CAN_FD);
-                (crc.checksum(data) as u64, 17)
-            }
-            CrcAlgorithm::Crc21CanFd => {
-                let crc = Crc::<u32>::new(&CRC_21_CAN_FD);
-                (crc.checksum(data) as u64, 21)
-            }
-            CrcAlgorithm::Crc24Ble => {
-                let crc = Crc::<u32>::new(&CRC_24_BLE);
-                (crc.checksum(data) as u64, 24)
-            }
-            CrcAlgorithm::Crc24FlexrayA => {
-                let crc = Crc::<u32>::new(&CRC_24_FLEXRAY_A);
-                (crc.checksum(data) as u64, 24)
-            }
-            CrcAlgorithm::Crc24FlexrayB => {
-                let crc = Crc::<u32>::new(&CRC_24_FLEXRAY_B);
-                (crc.checksum(data) as u64, 24)
-            }
-            CrcAlgorithm::Crc24Interlaken => {
-                let crc = Crc::<u32>::new(&CRC_24_INTERLAKEN);
-                (crc.checksum(data) as u64, 24)
-            }
-            CrcAlgorithm::Crc24LteA => {
-                let crc = Crc::<u32>::new(&CRC_24_LTE_A);
-                (crc.checksum(data) as u64, 24)
-            }
-            CrcAlgorithm::Crc24LteB => {
-                let crc = Crc::<u32>::new(&CRC_24_LTE_B);
-                (crc.checksum(data) as u64, 24)
-            }
-            CrcAlgorithm::Crc24Openpgp => {
-                let crc = Crc::<u32>::new(&CRC_24_OPENPGP);
-                (crc.checksum(data) as u64, 24)
-            }
-            CrcAlgorithm::Crc24Os9 => {
-                let crc = Crc::<u32>::new(&CRC_24_OS_9);
-                (crc.checksum(data) as u64, 24)
-            }
-            CrcAlgorithm::Crc30Cdma => {
-                let crc = Crc::<u32>::new(&CRC_30_CDMA);
-                (crc.checksum(data) as u64, 30)
-            }
-            CrcAlgorithm::Crc31Philips => {
-                let crc = Crc::<u32>::new(&CRC_31_PHILIPS);
-                (crc.checksum(data) as u64, 31)
-            }
-            CrcAlgorithm::Crc32Aixm => {
-                let crc = Crc::<u32>::new(&CRC_32_AIXM);
-                (crc.checksum(data) as u64, 32)
-            }
-            CrcAlgorithm::Crc32Autosar => {
-                let crc = Crc::<u32>::new(&CRC_32_AUTOSAR);
-                (crc.checksum(data) as u64, 32)
-            }
-            CrcAlgorithm::Crc32Base91D => {
-                let crc = Crc::<u32>::new(&CRC_32_BASE91_D);
-                (crc.checksum(data) as u64, 32)
-            }
-            CrcAlgorithm::Crc32Bzip2 => {
-                let crc = Crc::<u32>::new(&CRC_32_BZIP2);
-                (crc.checksum(data) as u64, 32)
-            }
-            CrcAlgorithm::Crc32CdRomEdc => {
-                let crc = Crc::<u32>::new(&CRC_32_CD_ROM_EDC);
-                (crc.checksum(data) as u64, 32)
-            }
-            CrcAlgorithm::Crc32Cksum => {
-                let crc = Crc::<u32>::new(&CRC_32_CKSUM);
-                (crc.checksum(data) as u64, 32)
-            }
-            CrcAlgorithm::Crc32Iscsi => {
-                let crc = Crc::<u32>::new(&CRC_32_ISCSI);
-                (crc.checksum(data) as u64, 32)
-            }
-            CrcAlgorithm::Crc32IsoHdlc => {
-                let crc = Crc::<u32>::new(&CRC_32_ISO_HDLC);
-                (crc.checksum(data) as u64, 32)
-            }
-            CrcAlgorithm::Crc32Jamcrc => {
-                let crc = Crc::<u32>::new(&CRC_32_JAMCRC);
-                (crc.checksum(data) as u64, 32)
-            }
-            CrcAlgorithm::Crc32Mef => {
-                let crc = Crc::<u32>::new(&CRC_32_MEF);
-                (crc.checksum(data) as u64, 32)
-            }
-            CrcAlgorithm::Crc32Mpeg2 => {
-                let crc = Crc::<u32>::new(&CRC_32_MPEG_2);
-                (crc.checksum(data) as u64, 32)
-            }
-            CrcAlgorithm::Crc32Xfer => {
-                let crc = Crc::<u32>::new(&CRC_32_XFER);
-                (crc.checksum(data) as u64, 32)
-            }
-            CrcAlgorithm::Crc40Gsm => {
-                let crc = Crc::<u64>::new(&CRC_40_GSM);
-                (crc.checksum(data) as u64, 40)
-            }
-            CrcAlgorithm::Crc64Ecma182 => {
-                let crc = Crc::<u64>::new(&CRC_64_ECMA_182);
-                (crc.checksum(data) as u64, 64)
-            }
-            CrcAlgorithm::Crc64GoIso => {
-                let crc = Crc::<u64>::new(&CRC_64_GO_ISO);
-                (crc.checksum(data) as u64, 64)
-            }
-            CrcAlgorithm::Crc64Ms => {
-                let crc = Crc::<u64>::new(&CRC_64_MS);
-                (crc.checksum(data) as u64, 64)
-            }
-            CrcAlgorithm::Crc64Redis => {
-                let crc = Crc::<u64>::new(&CRC_64_REDIS);
-                (crc.checksum(data) as u64, 64)
-            }
-            CrcAlgorithm::Crc64We => {
-                let crc = Crc::<u64>::new(&CRC_64_WE);
-                (crc.checksum(data) as u64, 64)
-            }
-            CrcAlgorithm::Crc64Xz => {
-                let crc = Crc::<u64>::new(&CRC_64_XZ);
-                (crc.checksum(data) as u64, 64)
-            }
-        }
-    }
-
-    fn all() -> Vec<CrcAlgorithm> {
-        vec![
-            CrcAlgorithm::Crc3Gsm,
-            CrcAlgorithm::Crc3Rohc,
-            CrcAlgorithm::Crc4G704,
-            CrcAlgorithm::Crc4Interlaken,
-            CrcAlgorithm::Crc5EpcC1g2,
-            CrcAlgorithm::Crc5G704,
-            CrcAlgorithm::Crc5Usb,
-            CrcAlgorithm::Crc6Cdma2000A,
-            CrcAlgorithm::Crc6Cdma2000B,
-            CrcAlgorithm::Crc6Darc,
-            CrcAlgorithm::Crc6Gsm,
-            CrcAlgorithm::Crc6G704,
-            CrcAlgorithm::Crc7Mmc,
-            CrcAlgorithm::Crc7Rohc,
-            CrcAlgorithm::Crc7Umts,
-            CrcAlgorithm::Crc8Autosar,
-            CrcAlgorithm::Crc8Bluetooth,
-            CrcAlgorithm::Crc8Cdma2000,
-            CrcAlgorithm::Crc8Darc,
-            CrcAlgorithm::Crc8DvbS2,
-            CrcAlgorithm::Crc8GsmA,
-            CrcAlgorithm::Crc8GsmB,
-            CrcAlgorithm::Crc8Hitag,
-            CrcAlgorithm::Crc8I4321,
-            CrcAlgorithm::Crc8ICode,
-            CrcAlgorithm::Crc8Lte,
-            CrcAlgorithm::Crc8MaximDow,
-            CrcAlgorithm::Crc8MifareMad,
-            CrcAlgorithm::Crc8Nrsc5,
-            CrcAlgorithm::Crc8Opensafety,
-            CrcAlgorithm::Crc8Rohc,
-            CrcAlgorithm::Crc8SaeJ1850,
-            CrcAlgorithm::Crc8Smbus,
-            CrcAlgorithm::Crc8Tech3250,
-            CrcAlgorithm::Crc8Wcdma,
-            CrcAlgorithm::Crc10Atm,
-            CrcAlgorithm::Crc10Cdma2000,
-            CrcAlgorithm::Crc10Gsm,
-            CrcAlgorithm::Crc11Flexray,
-            CrcAlgorithm::Crc11Umts,
-            CrcAlgorithm::Crc12Cdma2000,
-            CrcAlgorithm::Crc12Dect,
-            CrcAlgorithm::Crc12Gsm,
-            CrcAlgorithm::Crc12Umts,
-            CrcAlgorithm::Crc13Bbc,
-            CrcAlgorithm::Crc14Darc,
-            CrcAlgorithm::Crc14Gsm,
-            CrcAlgorithm::Crc15Can,
-            CrcAlgorithm::Crc15Mpt1327,
-            CrcAlgorithm::Crc16Arc,
-            CrcAlgorithm::Crc16Cdma2000,
-            CrcAlgorithm::Crc16Cms,
-            CrcAlgorithm::Crc16Dds110,
-            CrcAlgorithm::Crc16DectR,
-            CrcAlgorithm::Crc16DectX,
-            CrcAlgorithm::Crc16Dnp,
-            CrcAlgorithm::Crc16En13757,
-            CrcAlgorithm::Crc16Genibus,
-            CrcAlgorithm::Crc16Gsm,
-            CrcAlgorithm::Crc16Ibm3740,
-            CrcAlgorithm::Crc16IbmSdlc,
-            CrcAlgorithm::Crc16IsoIec144433A,
-            CrcAlgorithm::Crc16Kermit,
-            CrcAlgorithm::Crc16Lj1200,
-            CrcAlgorithm::Crc16M17,
-            CrcAlgorithm::Crc16MaximDow,
-            CrcAlgorithm::Crc16Mcrf4xx,
-            CrcAlgorithm::Crc16Modbus,
-            CrcAlgorithm::Crc16Nrsc5,
-            CrcAlgorithm::Crc16OpensafetyA,
-            CrcAlgorithm::Crc16OpensafetyB,
-            CrcAlgorithm::Crc16Profibus,
-            CrcAlgorithm::Crc16Riello,
-            CrcAlgorithm::Crc16SpiFujitsu,
-            CrcAlgorithm::Crc16T10Dif,
-            CrcAlgorithm::Crc16Teledisk,
-            CrcAlgorithm::Crc16Tms37157,
-            CrcAlgorithm::Crc16Umts,
-            CrcAlgorithm::Crc16Usb,
-            CrcAlgorithm::Crc16Xmodem,
-            CrcAlgorithm::Crc17CanFd,
-            CrcAlgorithm::Crc21CanFd,
-            CrcAlgorithm::Crc24Ble,
-            CrcAlgorithm::Crc24FlexrayA,
-            CrcAlgorithm::Crc24FlexrayB,
-            CrcAlgorithm::Crc24Interlaken,
-            CrcAlgorithm::Crc24LteA,
-            CrcAlgorithm::Crc24LteB,
-            CrcAlgorithm::Crc24Openpgp,
-            CrcAlgorithm::Crc24Os9,
-            CrcAlgorithm::Crc30Cdma,
-            CrcAlgorithm::Crc31Philips,
-            CrcAlgorithm::Crc32Aixm,
-            CrcAlgorithm::Crc32Autosar,
-            CrcAlgorithm::Crc32Base91D,
-            CrcAlgorithm::Crc32Bzip2,
-            CrcAlgorithm::Crc32CdRomEdc,
-            CrcAlgorithm::Crc32Cksum,
-            CrcAlgorithm::Crc32Iscsi,
-            CrcAlgorithm::Crc32IsoHdlc,
-            CrcAlgorithm::Crc32Jamcrc,
-            CrcAlgorithm::Crc32Mef,
-            CrcAlgorithm::Crc32Mpeg2,
-            CrcAlgorithm::Crc32Xfer,
-            CrcAlgorithm::Crc40Gsm,
-            CrcAlgorithm::Crc64Ecma182,
-            CrcAlgorithm::Crc64GoIso,
-            CrcAlgorithm::Crc64Ms,
-            CrcAlgorithm::Crc64Redis,
-            CrcAlgorithm::Crc64We,
-            CrcAlgorithm::Crc64Xz,
-        ]
-    }
-}
+use crate::tools::file_hash::{read_slice_as_array_buffer, CHUNK_SIZE};
 
 pub struct ToolCrc {
     input: String,
@@ -1005,6 +31,39 @@ pub struct ToolCrc {
     endianness: Endianness,
     byte_formatting: ByteFormatting,
     selected_algorithm: CrcAlgorithm,
+    // Custom 알고리즘 파라미터 (입력 중인 문자열과 마지막으로 유효했던 값)
+    custom_params: CrcParams,
+    custom_width: String,
+    custom_poly: String,
+    custom_init: String,
+    custom_xorout: String,
+    custom_error: Option<String>,
+    // 전체 알고리즘 비교 표
+    compare_all: bool,
+    compare_width: Option<u8>,
+    compare_query: String,
+    compare_sort: ComparisonSort,
+    compare_descending: bool,
+    comparison: Vec<CrcComparison>,
+    // 검증 모드: 입력 끝의 CRC 바이트를 떼어 나머지 데이터의 CRC와 비교
+    verify_mode: bool,
+    // 리버스 엔지니어링: 한 줄에 "데이터 CRC" 샘플 하나
+    reveng_input: String,
+    reveng_width: String,
+    reveng_matches: Option<Vec<CrcMatch>>,
+    reveng_error: Option<String>,
+    // CRC 강제: 목표 CRC와 덮어쓸 오프셋 (비어 있으면 끝에 덧붙임)
+    force_target: String,
+    force_offset: String,
+    // 코드 생성기: 언어가 None이면 룩업 테이블만 출력
+    codegen_language: Option<CodeLanguage>,
+    codegen_style: CodeStyle,
+    // 파일 입력: 파일 전체를 메모리에 올리지 않고 CHUNK_SIZE 단위로 읽어 CRC를 갱신
+    file: Option<File>,
+    file_job: Rc<Cell<u32>>, // 진행 중인 계산 번호, 값이 바뀌면 이전 계산은 중단
+    file_progress: f64,
+    is_computing: bool,
+    is_dragging: bool,
     bytes: Vec<u8>,
     bytes_string: String,
     crc_result: u64,
@@ -1022,6 +81,32 @@ pub enum Msg {
     EndiannessChanged(Endianness),
     ByteFormattingChanged(ByteFormatting),
     SelectAlgorithm(String),
+    CustomWidthChanged(String),
+    CustomPolyChanged(String),
+    CustomInitChanged(String),
+    CustomXorOutChanged(String),
+    CustomRefInChanged(bool),
+    CustomRefOutChanged(bool),
+    CompareAllToggled(bool),
+    VerifyModeToggled(bool),
+    CompareWidthChanged(String),
+    CompareQueryChanged(String),
+    CompareSortChanged(ComparisonSort),
+    RevEngInputChanged(String),
+    RevEngWidthChanged(String),
+    RevEngSearch(bool),
+    RevEngApply(usize),
+    ForceTargetChanged(String),
+    ForceOffsetChanged(String),
+    CodegenLanguageChanged(String),
+    CodegenStyleChanged(CodeStyle),
+    FileSelected(File),
+    FileProgress(u32, f64),
+    FileCrcComputed(u32, Result<u64, String>),
+    ClearFile,
+    DragEnter,
+    DragLeave,
+    NoOp,
     CopyToClipboard(String),
     Calculate,
 }
@@ -1035,8 +120,13 @@ impl Component for ToolCrc {
     }
 
     fn update(&mut self, _ctx: &Context<Self>, msg: Self::Message) -> bool {
-        match msg {
+        let algorithm_before = self.selected_algorithm;
+        let should_render = match msg {
             Msg::InputChanged(value) => {
+                // 직접 입력하면 파일 입력은 해제
+                if self.file.is_some() {
+                    self.clear_file();
+                }
                 self.input = value.clone();
                 self.error_message = None;
 
@@ -1044,33 +134,22 @@ impl Component for ToolCrc {
                     self.bytes.clear();
                     self.bytes_string.clear();
                     self.crc_result = 0;
+                    self.comparison.clear();
                     return true;
                 }
 
-                let parsed_bytes = match self.input_mode {
-                    InputMode::Ascii => Ok(self.input.as_bytes().to_vec()),
-                    InputMode::Hex => self.parse_hex_input(&self.input),
-                    InputMode::Binary => self.parse_binary_input(&self.input),
-                    InputMode::Decimal => self.parse_decimal_input(&self.input),
-                    InputMode::Octal => self.parse_octal_input(&self.input),
-                };
-
-                match parsed_bytes {
+                match parse_input(&self.input, &self.input_mode) {
                     Ok(bytes) => {
                         self.bytes = bytes;
-                self.calculate_crc();
-                self.bytes_string = self
-                    .bytes
-                    .iter()
-                    .map(|byte| format!("0x{:02X}", byte))
-                    .collect::<Vec<String>>()
-                    .join(" ");
+                        self.calculate_crc();
+                        self.bytes_string = bytes_to_string(&self.bytes);
                     }
                     Err(err) => {
                         self.error_message = Some(err);
                         self.bytes.clear();
                         self.bytes_string.clear();
                         self.crc_result = 0;
+                        self.comparison.clear();
                     }
                 }
                 true
@@ -1082,6 +161,7 @@ impl Component for ToolCrc {
                 self.bytes.clear();
                 self.bytes_string.clear();
                 self.crc_result = 0;
+                self.comparison.clear();
                 self.save_to_storage();
                 true
             }
@@ -1117,7 +197,10 @@ impl Component for ToolCrc {
             }
             Msg::SelectAlgorithm(value) => {
                 if let Some(algorithm) = CrcAlgorithm::from_name(&value) {
-                    self.selected_algorithm = algorithm;
+                    self.selected_algorithm = match algorithm {
+                        CrcAlgorithm::Custom(_) => CrcAlgorithm::Custom(self.custom_params),
+                        other => other,
+                    };
                     self.calculate_crc();
                     self.save_to_storage();
                 true
@@ -1125,16 +208,176 @@ impl Component for ToolCrc {
                     false
                 }
             }
+            Msg::CustomWidthChanged(value) => {
+                self.custom_width = value;
+                self.apply_custom_params();
+                true
+            }
+            Msg::CustomPolyChanged(value) => {
+                self.custom_poly = value;
+                self.apply_custom_params();
+                true
+            }
+            Msg::CustomInitChanged(value) => {
+                self.custom_init = value;
+                self.apply_custom_params();
+                true
+            }
+            Msg::CustomXorOutChanged(value) => {
+                self.custom_xorout = value;
+                self.apply_custom_params();
+                true
+            }
+            Msg::CustomRefInChanged(value) => {
+                self.custom_params.refin = value;
+                self.apply_custom_params();
+                true
+            }
+            Msg::CustomRefOutChanged(value) => {
+                self.custom_params.refout = value;
+                self.apply_custom_params();
+                true
+            }
+            Msg::CompareAllToggled(enabled) => {
+                self.compare_all = enabled;
+                self.calculate_crc();
+                self.save_to_storage();
+                true
+            }
+            Msg::VerifyModeToggled(enabled) => {
+                self.verify_mode = enabled;
+                self.save_to_storage();
+                true
+            }
+            Msg::CompareWidthChanged(value) => {
+                self.compare_width = value.parse::<u8>().ok();
+                self.calculate_crc();
+                self.save_to_storage();
+                true
+            }
+            Msg::CompareQueryChanged(value) => {
+                self.compare_query = value;
+                true
+            }
+            Msg::CompareSortChanged(sort) => {
+                // 같은 열을 다시 누르면 정렬 방향을 뒤집음
+                if self.compare_sort == sort {
+                    self.compare_descending = !self.compare_descending;
+                } else {
+                    self.compare_sort = sort;
+                    self.compare_descending = false;
+                }
+                compare::sort_comparisons(&mut self.comparison, self.compare_sort, self.compare_descending);
+                true
+            }
+            Msg::RevEngInputChanged(value) => {
+                self.reveng_input = value;
+                self.reveng_error = None;
+                self.reveng_matches = None;
+                true
+            }
+            Msg::RevEngWidthChanged(value) => {
+                self.reveng_width = value;
+                true
+            }
+            Msg::RevEngSearch(brute_force) => {
+                match self.run_reveng(brute_force) {
+                    Ok(matches) => {
+                        self.reveng_matches = Some(matches);
+                        self.reveng_error = None;
+                    }
+                    Err(err) => {
+                        self.reveng_matches = None;
+                        self.reveng_error = Some(err);
+                    }
+                }
+                true
+            }
+            Msg::RevEngApply(index) => {
+                let Some(found) = self.reveng_matches.as_ref().and_then(|m| m.get(index)).cloned() else {
+                    return false;
+                };
+                if let CrcAlgorithm::Custom(params) = found.algorithm {
+                    self.set_custom_params(params);
+                }
+                self.selected_algorithm = found.algorithm;
+                self.endianness = found.endianness;
+                self.calculate_crc();
+                self.save_to_storage();
+                true
+            }
+            Msg::ForceTargetChanged(value) => {
+                self.force_target = value;
+                true
+            }
+            Msg::ForceOffsetChanged(value) => {
+                self.force_offset = value;
+                true
+            }
+            Msg::CodegenLanguageChanged(value) => {
+                self.codegen_language = CodeLanguage::from_name(&value);
+                self.save_to_storage();
+                true
+            }
+            Msg::CodegenStyleChanged(style) => {
+                self.codegen_style = style;
+                self.save_to_storage();
+                true
+            }
             Msg::Calculate => {
                 self.calculate_crc();
-                self.bytes_string = self
-                    .bytes
-                    .iter()
-                    .map(|byte| format!("0x{:02X}", byte))
-                    .collect::<Vec<String>>()
-                    .join(" ");
+                self.bytes_string = bytes_to_string(&self.bytes);
+                true
+            }
+            Msg::FileSelected(file) => {
+                self.is_dragging = false;
+                self.input.clear();
+                self.error_message = None;
+                self.bytes.clear();
+                self.bytes_string.clear();
+                self.comparison.clear();
+                self.file = Some(file);
+                self.start_file_crc(_ctx);
+                true
+            }
+            Msg::FileProgress(job, progress) => {
+                if job != self.file_job.get() {
+                    return false;
+                }
+                self.file_progress = progress;
+                true
+            }
+            Msg::FileCrcComputed(job, result) => {
+                if job != self.file_job.get() {
+                    return false;
+                }
+                self.is_computing = false;
+                match result {
+                    Ok(crc) => {
+                        self.crc_result = crc;
+                        self.width = self.selected_algorithm.params().width;
+                        self.file_progress = 1.0;
+                    }
+                    Err(err) => {
+                        self.error_message = Some(err);
+                        self.crc_result = 0;
+                    }
+                }
+                true
+            }
+            Msg::ClearFile => {
+                self.clear_file();
                 true
             }
+            Msg::DragEnter => {
+                self.is_dragging = true;
+                true
+            }
+            Msg::DragLeave => {
+                self.is_dragging = false;
+                true
+            }
+            Msg::NoOp => false,
             Msg::CopyToClipboard(value) => {
                 if let Some(clipboard) = window().map(|w| w.navigator().clipboard()) {
                     wasm_bindgen_futures::spawn_local(async move {
@@ -1151,7 +394,13 @@ impl Component for ToolCrc {
                 }
                 false
             }
+        };
+
+        // 파일 입력 중에 알고리즘이나 Custom 파라미터가 바뀌면 파일을 다시 읽어 계산
+        if self.file.is_some() && self.selected_algorithm != algorithm_before {
+            self.start_file_crc(_ctx);
         }
+        should_render
     }
 
     fn view(&self, _ctx: &Context<Self>) -> Html {
@@ -1891,8 +1140,43 @@ bool validate_sensor_data(uint8_t *sensor_data) {
                                             </option>
                                         }
                                     }).collect::<Html>()}
+                                    <option
+                                        value="Custom"
+                                        selected={self.is_custom_selected()}
+                                    >
+                                        { "Custom" }
+                                    </option>
                             </select>
                         </div>
+                            if self.is_custom_selected() {
+                                { self.view_custom_params(_ctx) }
+                            }
+
+                            <div style="display: flex; align-items: center; margin-bottom: 6px;">
+                                <div style="width: 70%; font-size: 13px;">
+                                    {"Compare All Algorithms: "}
+                                </div>
+                                <input
+                                    type="checkbox"
+                                    checked={self.compare_all}
+                                    onchange={_ctx.link().callback(|e: Event| {
+                                        let input: HtmlInputElement = e.target_unchecked_into();
+                                        Msg::CompareAllToggled(input.checked())
+                                    })} />
+                            </div>
+
+                            <div style="display: flex; align-items: center; margin-bottom: 6px;">
+                                <div style="width: 70%; font-size: 13px;">
+                                    {"Verify Trailing CRC: "}
+                                </div>
+                                <input
+                                    type="checkbox"
+                                    checked={self.verify_mode}
+                                    onchange={_ctx.link().callback(|e: Event| {
+                                        let input: HtmlInputElement = e.target_unchecked_into();
+                                        Msg::VerifyModeToggled(input.checked())
+                                    })} />
+                            </div>
                             
                             <div style="display: flex; align-items: center; margin-bottom: 6px;">
                                 <div style="width: 70%; font-size: 13px;">
@@ -2070,6 +1354,7 @@ bool validate_sensor_data(uint8_t *sensor_data) {
                                         InputMode::Octal => "Supports: 0o101, o101, 101, \\101 formats",
                                     }}
                                 </div>
+                                { self.view_file_input(_ctx) }
                             </div>
                             <div>
                                 <div class="tool-subtitle" style="margin-top: 10px; font-size: 14px;">{ "Processed data" }</div>
@@ -2087,6 +1372,18 @@ bool validate_sensor_data(uint8_t *sensor_data) {
                         <div class="tool-inner" style="margin-top: 8px;">
                             <div>
                                 <div class="tool-subtitle" style="font-size: 14px;">{ format!("{} Result", self.selected_algorithm.name()) }</div>
+                                if self.is_computing {
+                                    <div style="width: 100%; background-color: var(--color-third); border-radius: 4px; height: 20px; overflow: hidden;">
+                                        <div
+                                            style={format!("width: {}%; background-color: var(--color-fourth); height: 20px; border-radius: 4px;",
+                                                (self.file_progress * 100.0).clamp(0.0, 100.0))}
+                                        >
+                                        </div>
+                                    </div>
+                                    <div style="text-align: center; margin-top: 5px; font-size: 12px;">
+                                        { format!("Processing: {:.1}%", self.file_progress * 100.0) }
+                                    </div>
+                                } else {
                                 <input
                                     type="text"
                                     name="crc"
@@ -2097,8 +1394,18 @@ bool validate_sensor_data(uint8_t *sensor_data) {
                                         let input: HtmlInputElement = e.target_unchecked_into();
                                         Msg::CopyToClipboard(input.value())
                                     })} />
+                                }
                             </div>
                         </div>
+                        if self.verify_mode {
+                            { self.view_verification() }
+                        }
+                        if self.compare_all {
+                            { self.view_comparison(_ctx) }
+                        }
+                        { self.view_reveng(_ctx) }
+                        { self.view_forcing(_ctx) }
+                        { self.view_codegen(_ctx) }
                     </div>
                 </div>
             </>
@@ -2124,571 +1431,716 @@ bool validate_sensor_data(uint8_t *sensor_data) {
 }
 
 impl ToolCrc {
-    fn parse_hex_input(&self, input: &str) -> Result<Vec<u8>, String> {
-        if input.trim().is_empty() {
-            return Ok(Vec::new());
-        }
+    fn is_custom_selected(&self) -> bool {
+        matches!(self.selected_algorithm, CrcAlgorithm::Custom(_))
+    }
 
-        let mut result = Vec::new();
-        let mut current_number = String::new();
-        let mut chars = input.chars().peekable();
-
-        while let Some(&c) = chars.peek() {
-            match c {
-                // 공백 문자 처리
-                ' ' | '\n' | '\t' | '\r' => {
-                    if !current_number.is_empty() {
-                        result.push(self.parse_hex_string(&current_number)?);
-                        current_number.clear();
-                    }
-                    chars.next();
-                }
-                // "0x" 또는 "\x" 접두사 처리
-                '0' => {
-                    chars.next();
-                    if let Some(&next) = chars.peek() {
-                        if next == 'x' || next == 'X' {
-                            if !current_number.is_empty() {
-                                result.push(self.parse_hex_string(&current_number)?);
-                                current_number.clear();
-                            }
-                            chars.next(); // 'x' 건너뛰기
-                            current_number = self.collect_hex_digits(&mut chars)?;
-                            if !current_number.is_empty() {
-                                result.push(self.parse_hex_string(&current_number)?);
-                                current_number.clear();
-                            }
-                        } else {
-                            current_number.push('0');
-                        }
-                    } else {
-                        current_number.push('0');
-                    }
-                }
-                '\\' => {
-                    chars.next();
-                    if let Some(&next) = chars.peek() {
-                        if next == 'x' || next == 'X' {
-                            if !current_number.is_empty() {
-                                result.push(self.parse_hex_string(&current_number)?);
-                                current_number.clear();
-                            }
-                            chars.next(); // 'x' 건너뛰기
-                            current_number = self.collect_hex_digits(&mut chars)?;
-                            if !current_number.is_empty() {
-                                result.push(self.parse_hex_string(&current_number)?);
-                                current_number.clear();
-                            }
-                        } else {
-                            return Err("Invalid escape sequence: expected 'x' after '\\'".to_string());
-                        }
-                    } else {
-                        return Err("Incomplete escape sequence: unexpected end of input after '\\'".to_string());
-                    }
-                }
-                'x' | 'X' => {
-                    if !current_number.is_empty() {
-                        result.push(self.parse_hex_string(&current_number)?);
-                        current_number.clear();
-                    }
-                    chars.next(); // 'x' 건너뛰기
-                    current_number = self.collect_hex_digits(&mut chars)?;
-                    if !current_number.is_empty() {
-                        result.push(self.parse_hex_string(&current_number)?);
-                        current_number.clear();
-                    }
-                }
-                // 16진수 숫자 수집
-                _ => {
-                    if c.is_ascii_hexdigit() {
-                        current_number.push(chars.next().unwrap());
-                    } else {
-                        return Err(format!("Invalid character '{}' in hexadecimal input. Only 0-9, A-F, a-f are allowed.", c));
-                    }
+    /// 입력 중인 Custom 파라미터 문자열을 검증하고, 유효하면 적용 후 저장합니다.
+    fn apply_custom_params(&mut self) {
+        let params = self.parse_custom_params().and_then(|params| {
+            params.validate()?;
+            Ok(params)
+        });
 
-                    // 두 자리가 모이면 바이트로 변환
-                    if current_number.len() == 2 {
-                        result.push(self.parse_hex_string(&current_number)?);
-                        current_number.clear();
-                    }
+        match params {
+            Ok(params) => {
+                self.custom_params = params;
+                self.custom_error = None;
+                if self.is_custom_selected() {
+                    self.selected_algorithm = CrcAlgorithm::Custom(params);
+                    self.calculate_crc();
                 }
+                self.save_to_storage();
             }
+            Err(err) => self.custom_error = Some(err),
         }
+    }
 
-        // 남은 숫자 처리
-        if !current_number.is_empty() {
-            // 한 자리 숫자인 경우 앞에 0을 붙임
-            if current_number.len() == 1 {
-                current_number.insert(0, '0');
-            }
-            result.push(self.parse_hex_string(&current_number)?);
-        }
-
-        if result.is_empty() {
-            return Err("No valid hexadecimal values found in input".to_string());
-        }
+    fn parse_custom_params(&self) -> Result<CrcParams, String> {
+        let width = self
+            .custom_width
+            .trim()
+            .parse::<u8>()
+            .map_err(|_| format!("Invalid width: {}", self.custom_width))?;
+        let hex_field = |name: &str, value: &str| {
+            parse_param_value(value).map_err(|e| format!("{}: {}", name, e))
+        };
 
-        Ok(result)
+        Ok(CrcParams {
+            width,
+            poly: hex_field("Poly", &self.custom_poly)?,
+            init: hex_field("Init", &self.custom_init)?,
+            refin: self.custom_params.refin,
+            refout: self.custom_params.refout,
+            xorout: hex_field("XorOut", &self.custom_xorout)?,
+        })
     }
 
-    fn collect_hex_digits(
-        &self,
-        chars: &mut std::iter::Peekable<std::str::Chars>,
-    ) -> Result<String, String> {
-        let mut hex_str = String::new();
-
-        while let Some(&c) = chars.peek() {
-            if c.is_ascii_hexdigit() {
-                hex_str.push(chars.next().unwrap());
-                if hex_str.len() == 2 {
-                    break;
+    fn view_custom_params(&self, ctx: &Context<Self>) -> Html {
+        let link = ctx.link();
+        let row_style = "display: flex; align-items: center; margin-bottom: 6px;";
+        let label_style = "width: 70%; font-size: 13px;";
+        let input_style = "width: 30%; padding: 2px; font-size: 12px;";
+        let check = self.custom_params.check();
+
+        html! {
+            <div style="border-left: 2px solid var(--color-secondary); padding-left: 8px; margin-bottom: 6px;">
+                <div style={row_style}>
+                    <div style={label_style}>{"Width (1-64): "}</div>
+                    <input
+                        type="number"
+                        min="1"
+                        max="64"
+                        style={input_style}
+                        value={self.custom_width.clone()}
+                        oninput={link.callback(|e: InputEvent| {
+                            let input: HtmlInputElement = e.target_unchecked_into();
+                            Msg::CustomWidthChanged(input.value())
+                        })} />
+                </div>
+                <div style={row_style}>
+                    <div style={label_style}>{"Poly (hex): "}</div>
+                    <input
+                        type="text"
+                        style={input_style}
+                        value={self.custom_poly.clone()}
+                        oninput={link.callback(|e: InputEvent| {
+                            let input: HtmlInputElement = e.target_unchecked_into();
+                            Msg::CustomPolyChanged(input.value())
+                        })} />
+                </div>
+                <div style={row_style}>
+                    <div style={label_style}>{"Init (hex): "}</div>
+                    <input
+                        type="text"
+                        style={input_style}
+                        value={self.custom_init.clone()}
+                        oninput={link.callback(|e: InputEvent| {
+                            let input: HtmlInputElement = e.target_unchecked_into();
+                            Msg::CustomInitChanged(input.value())
+                        })} />
+                </div>
+                <div style={row_style}>
+                    <div style={label_style}>{"XorOut (hex): "}</div>
+                    <input
+                        type="text"
+                        style={input_style}
+                        value={self.custom_xorout.clone()}
+                        oninput={link.callback(|e: InputEvent| {
+                            let input: HtmlInputElement = e.target_unchecked_into();
+                            Msg::CustomXorOutChanged(input.value())
+                        })} />
+                </div>
+                <div style={row_style}>
+                    <div style={label_style}>{"RefIn: "}</div>
+                    <input
+                        type="checkbox"
+                        checked={self.custom_params.refin}
+                        onchange={link.callback(|e: Event| {
+                            let input: HtmlInputElement = e.target_unchecked_into();
+                            Msg::CustomRefInChanged(input.checked())
+                        })} />
+                </div>
+                <div style={row_style}>
+                    <div style={label_style}>{"RefOut: "}</div>
+                    <input
+                        type="checkbox"
+                        checked={self.custom_params.refout}
+                        onchange={link.callback(|e: Event| {
+                            let input: HtmlInputElement = e.target_unchecked_into();
+                            Msg::CustomRefOutChanged(input.checked())
+                        })} />
+                </div>
+                if let Some(error_msg) = &self.custom_error {
+                    <div style="color: var(--color-error); font-size: 11px; margin-bottom: 4px; line-height: 1.2;">
+                        { error_msg }
+                    </div>
                 }
-            } else {
-                break;
-            }
+                <div style="color: var(--color-subfont); font-size: 12px;">
+                    { format!("Check (\"123456789\"): {}", format_crc_output(check, self.custom_params.width, &self.output_options())) }
+                </div>
+            </div>
         }
+    }
 
-        if hex_str.is_empty() {
-            return Err("Expected hexadecimal digits after prefix".to_string());
-        }
+    fn view_file_input(&self, ctx: &Context<Self>) -> Html {
+        let link = ctx.link();
 
-        // 한 자리 숫자인 경우 앞에 0을 붙임
-        if hex_str.len() == 1 {
-            hex_str.insert(0, '0');
+        html! {
+            <div
+                style={format!("border: 2px dashed {}; border-radius: 8px; padding: 8px; margin-top: 6px; text-align: center; font-size: 12px;",
+                    if self.is_dragging { "var(--color-primary)" } else { "var(--color-border)" })}
+                ondragover={link.callback(|e: DragEvent| {
+                    e.prevent_default();
+                    Msg::NoOp
+                })}
+                ondragenter={link.callback(|e: DragEvent| {
+                    e.prevent_default();
+                    Msg::DragEnter
+                })}
+                ondragleave={link.callback(|e: DragEvent| {
+                    e.prevent_default();
+                    Msg::DragLeave
+                })}
+                ondrop={link.callback(|e: DragEvent| {
+                    e.prevent_default();
+
+                    // file_hash 도구와 같이 dataTransfer.files에 직접 접근
+                    let event_obj = wasm_bindgen::JsValue::from(e);
+                    js_sys::Reflect::get(&event_obj, &"dataTransfer".into())
+                        .and_then(|data_transfer| js_sys::Reflect::get(&data_transfer, &"files".into()))
+                        .ok()
+                        .and_then(|files| files.dyn_into::<web_sys::FileList>().ok())
+                        .and_then(|files| files.get(0))
+                        .map(Msg::FileSelected)
+                        .unwrap_or(Msg::DragLeave)
+                })}
+            >
+                if let Some(file) = &self.file {
+                    <div style="display: flex; align-items: center; justify-content: space-between; gap: 6px;">
+                        <div style="text-align: left; overflow: hidden; text-overflow: ellipsis; white-space: nowrap;">
+                            <strong>{ file.name() }</strong>
+                            { format!(" • {}", format_file_size(file.size() as usize)) }
+                        </div>
+                        <button
+                            type="button"
+                            style="background: var(--color-error); color: white; border: none; border-radius: 4px; padding: 4px 10px; cursor: pointer;"
+                            onclick={link.callback(|_| Msg::ClearFile)}>
+                            { "Remove" }
+                        </button>
+                    </div>
+                } else {
+                    <span style="color: var(--color-subfont);">{ "Or drop a file here to compute its CRC " }</span>
+                    <input
+                        type="file"
+                        id="crc-file-upload"
+                        style="display: none;"
+                        onchange={link.callback(|e: Event| {
+                            let input: HtmlInputElement = e.target_unchecked_into();
+                            let file = input.files().and_then(|files| files.get(0));
+                            input.set_value(""); // 같은 파일을 다시 선택할 수 있도록 초기화
+                            match file {
+                                Some(file) => Msg::FileSelected(file),
+                                None => Msg::NoOp,
+                            }
+                        })} />
+                    <label
+                        for="crc-file-upload"
+                        style="color: var(--color-primary); cursor: pointer; text-decoration: underline;">
+                        { "(choose file)" }
+                    </label>
+                }
+            </div>
         }
-
-        Ok(hex_str)
     }
 
-    fn parse_hex_string(&self, hex_str: &str) -> Result<u8, String> {
-        u8::from_str_radix(hex_str, 16).map_err(|_| format!("Invalid hexadecimal value: '{}'", hex_str))
-    }
-
-    fn calculate_crc(&mut self) {
-        if self.bytes.is_empty() {
-        self.crc_result = 0;
+    /// 선택된 파일을 CHUNK_SIZE 단위로 읽으며 CRC를 갱신합니다.
+    ///
+    /// 새 계산을 시작하면 `file_job` 번호가 바뀌어 이전 계산은 다음 청크에서 멈춥니다.
+    fn start_file_crc(&mut self, ctx: &Context<Self>) {
+        let Some(file) = self.file.clone() else {
             return;
-        }
+        };
 
-                (self.crc_result, self.width) = self.selected_algorithm.calculate(&self.bytes);
-            }
+        let job = self.file_job.get().wrapping_add(1);
+        self.file_job.set(job);
+        self.is_computing = true;
+        self.file_progress = 0.0;
+
+        let current_job = self.file_job.clone();
+        let algorithm = self.selected_algorithm;
+        let link = ctx.link().clone();
+
+        spawn_local(async move {
+            let engine = algorithm.engine();
+            let mut digest = engine.digest();
+            let size = file.size();
+            let mut offset = 0.0;
+
+            while offset < size {
+                let end = (offset + CHUNK_SIZE as f64).min(size);
+                let chunk = match file.slice_with_f64_and_f64(offset, end) {
+                    Ok(blob) => read_slice_as_array_buffer(&blob).await,
+                    Err(err) => Err(err),
+                };
+                if current_job.get() != job {
+                    return;
+                }
 
-    fn format_crc_output(&self) -> String {
-        // 엔디안에 따라 바이트 순서 조정
-        let crc_value = match self.endianness {
-            Endianness::BigEndian => self.crc_result,
-            Endianness::LittleEndian => {
-                match self.width {
-                    8 => self.crc_result, // 8비트는 엔디안 무관
-                    16 => ((self.crc_result & 0xFF) << 8) | ((self.crc_result >> 8) & 0xFF),
-                    24 => {
-                        let b0 = (self.crc_result >> 16) & 0xFF;
-                        let b1 = (self.crc_result >> 8) & 0xFF;
-                        let b2 = self.crc_result & 0xFF;
-                        (b2 << 16) | (b1 << 8) | b0
-                    },
-                    32 => {
-                        let b0 = (self.crc_result >> 24) & 0xFF;
-                        let b1 = (self.crc_result >> 16) & 0xFF;
-                        let b2 = (self.crc_result >> 8) & 0xFF;
-                        let b3 = self.crc_result & 0xFF;
-                        (b3 << 24) | (b2 << 16) | (b1 << 8) | b0
-                    },
-                    64 => {
-                        let mut result = 0u64;
-                        for i in 0..8 {
-                            let byte = (self.crc_result >> (i * 8)) & 0xFF;
-                            result |= byte << ((7 - i) * 8);
-                        }
-                        result
-                    },
-                    _ => {
-                        // 다른 비트 크기에 대한 일반적인 처리
-                        let bytes = ((self.width + 7) / 8) as usize;
-                        let mut result = 0u64;
-                        for i in 0..bytes {
-                            let byte = (self.crc_result >> (i * 8)) & 0xFF;
-                            result |= byte << (((bytes - 1 - i) as u64) * 8);
-                        }
-                        result
+                match chunk {
+                    Ok(bytes) => digest.update(&bytes),
+                    Err(_) => {
+                        link.send_message(Msg::FileCrcComputed(job, Err(format!("Failed to read {}.", file.name()))));
+                        return;
                     }
                 }
-            }
-        };
 
-        match self.output_mode {
-            OutputMode::Decimal => {
-                format!("{}", crc_value)
-            }
-            OutputMode::Hex => {
-                self.format_hex_output(crc_value)
-            }
-            OutputMode::Binary => {
-                self.format_binary_output(crc_value)
-            }
-            OutputMode::Octal => {
-                self.format_octal_output(crc_value)
+                offset = end;
+                link.send_message(Msg::FileProgress(job, offset / size));
             }
-        }
+
+            link.send_message(Msg::FileCrcComputed(job, Ok(digest.finalize())));
+        });
     }
 
-    fn format_hex_output(&self, value: u64) -> String {
-        let bytes = ((self.width + 7) / 8) as usize;
-        
-        match self.byte_formatting {
-            ByteFormatting::Continuous => {
-                let digits = bytes * 2;
-                match self.hex_style {
-                    HexStyle::WithPrefix => format!("0x{:0width$X}", value, width = digits),
-                    HexStyle::ShortPrefix => format!("x{:0width$X}", value, width = digits),
-                    HexStyle::NoPrefix => format!("{:0width$X}", value, width = digits),
-                    HexStyle::EscapeSequence => format!("\\x{:0width$X}", value, width = digits),
-                }
-            }
-            ByteFormatting::ByteSeparated => {
-                let mut byte_strings = Vec::new();
-                for i in 0..bytes {
-                    let byte = (value >> ((bytes - 1 - i) * 8)) & 0xFF;
-                    let byte_str = match self.hex_style {
-                        HexStyle::WithPrefix => format!("0x{:02X}", byte),
-                        HexStyle::ShortPrefix => format!("x{:02X}", byte),
-                        HexStyle::NoPrefix => format!("{:02X}", byte),
-                        HexStyle::EscapeSequence => format!("\\x{:02X}", byte),
-                    };
-                    byte_strings.push(byte_str);
-                }
-                byte_strings.join(" ")
-            }
-        }
+    fn clear_file(&mut self) {
+        // 진행 중인 계산 중단
+        self.file_job.set(self.file_job.get().wrapping_add(1));
+        self.file = None;
+        self.is_computing = false;
+        self.file_progress = 0.0;
+        self.crc_result = 0;
     }
 
-    fn format_binary_output(&self, value: u64) -> String {
-        match self.byte_formatting {
-            ByteFormatting::Continuous => {
-                let binary_str = format!("{:0width$b}", value, width = self.width as usize);
-                match self.binary_style {
-                    BinaryStyle::WithPrefix => format!("0b{}", binary_str),
-                    BinaryStyle::ShortPrefix => format!("b{}", binary_str),
-                    BinaryStyle::NoPrefix => binary_str,
-                }
-            }
-            ByteFormatting::ByteSeparated => {
-                let bytes = ((self.width + 7) / 8) as usize;
-                let mut byte_strings = Vec::new();
-                for i in 0..bytes {
-                    let byte = (value >> ((bytes - 1 - i) * 8)) & 0xFF;
-                    let byte_str = match self.binary_style {
-                        BinaryStyle::WithPrefix => format!("0b{:08b}", byte),
-                        BinaryStyle::ShortPrefix => format!("b{:08b}", byte),
-                        BinaryStyle::NoPrefix => format!("{:08b}", byte),
-                    };
-                    byte_strings.push(byte_str);
-                }
-                byte_strings.join(" ")
+    fn view_comparison(&self, ctx: &Context<Self>) -> Html {
+        let link = ctx.link();
+        let options = self.output_options();
+        let header_style = "padding: 6px; border: 1px solid #ddd; cursor: pointer;";
+        let sort_label = |label: &str, sort: ComparisonSort| {
+            if self.compare_sort != sort {
+                label.to_string()
+            } else if self.compare_descending {
+                format!("{} ▼", label)
+            } else {
+                format!("{} ▲", label)
             }
+        };
+
+        html! {
+            <div class="tool-inner" style="margin-top: 8px;">
+                <div>
+                    <div class="tool-subtitle" style="margin-bottom: 3px; font-size: 14px;">{ "All Algorithms" }</div>
+                    <div style="display: flex; align-items: center; gap: 6px; margin-bottom: 6px;">
+                        <select
+                            style="width: 30%; padding: 2px; font-size: 12px;"
+                            onchange={link.callback(|e: Event| {
+                                let select: HtmlInputElement = e.target_unchecked_into();
+                                Msg::CompareWidthChanged(select.value())
+                            })}>
+                            <option value="all" selected={self.compare_width.is_none()}>{ "All widths" }</option>
+                            { for compare::catalogue_widths().into_iter().map(|width| html! {
+                                <option value={width.to_string()} selected={self.compare_width == Some(width)}>
+                                    { format!("{}-bit", width) }
+                                </option>
+                            }) }
+                        </select>
+                        <input
+                            type="text"
+                            style="width: 70%; padding: 2px; font-size: 12px;"
+                            placeholder="Search by name or known result value..."
+                            value={self.compare_query.clone()}
+                            oninput={link.callback(|e: InputEvent| {
+                                let input: HtmlInputElement = e.target_unchecked_into();
+                                Msg::CompareQueryChanged(input.value())
+                            })} />
+                    </div>
+                    if self.bytes.is_empty() {
+                        <div style="color: var(--color-subfont); font-size: 12px;">{ "Enter input to compare all algorithms." }</div>
+                    } else {
+                        <div style="max-height: 400px; overflow-y: auto; font-family: monospace; font-size: 12px; border: 1px solid #ddd; border-radius: 5px;">
+                            <table style="width: 100%; border-collapse: collapse;">
+                                <thead>
+                                    <tr style="background-color: var(--color-fourth); color: white; position: sticky; top: 0;">
+                                        <th style={header_style} onclick={link.callback(|_| Msg::CompareSortChanged(ComparisonSort::Name))}>
+                                            { sort_label("Algorithm", ComparisonSort::Name) }
+                                        </th>
+                                        <th style={header_style} onclick={link.callback(|_| Msg::CompareSortChanged(ComparisonSort::Width))}>
+                                            { sort_label("Width", ComparisonSort::Width) }
+                                        </th>
+                                        <th style={header_style} onclick={link.callback(|_| Msg::CompareSortChanged(ComparisonSort::Value))}>
+                                            { sort_label("Result", ComparisonSort::Value) }
+                                        </th>
+                                    </tr>
+                                </thead>
+                                <tbody>
+                                    { for self.comparison.iter()
+                                        .filter(|row| compare::matches_query(row, &self.compare_query, &options))
+                                        .map(|row| {
+                                            let name = row.algorithm.name().to_string();
+                                            let row_style = if row.algorithm == self.selected_algorithm {
+                                                "cursor: pointer; background-color: var(--color-third); font-weight: bold;"
+                                            } else {
+                                                "cursor: pointer;"
+                                            };
+                                            html! {
+                                                <tr
+                                                    style={row_style}
+                                                    title="Click to select this algorithm"
+                                                    onclick={link.callback(move |_| Msg::SelectAlgorithm(name.clone()))}>
+                                                    <td style="padding: 3px; border: 1px solid #ddd;">{ row.algorithm.name() }</td>
+                                                    <td style="padding: 3px; border: 1px solid #ddd; text-align: center;">{ row.width }</td>
+                                                    <td style="padding: 3px; border: 1px solid #ddd;">{ format_crc_output(row.crc, row.width, &options) }</td>
+                                                </tr>
+                                            }
+                                        }) }
+                                </tbody>
+                            </table>
+                        </div>
+                    }
+                </div>
+            </div>
         }
     }
 
-    fn format_octal_output(&self, value: u64) -> String {
-        let bytes = ((self.width + 7) / 8) as usize;
-        
-        match self.byte_formatting {
-            ByteFormatting::Continuous => {
-                let digits = ((self.width as f32 / 3.0).ceil()) as usize;
-                match self.octal_style {
-                    OctalStyle::WithPrefix => format!("0o{:0width$o}", value, width = digits),
-                    OctalStyle::ShortPrefix => format!("o{:0width$o}", value, width = digits),
-                    OctalStyle::NoPrefix => format!("{:0width$o}", value, width = digits),
-                    OctalStyle::EscapeSequence => format!("\\{:0width$o}", value, width = digits),
-                }
-            }
-            ByteFormatting::ByteSeparated => {
-                let mut byte_strings = Vec::new();
-                for i in 0..bytes {
-                    let byte = (value >> ((bytes - 1 - i) * 8)) & 0xFF;
-                    let byte_str = match self.octal_style {
-                        OctalStyle::WithPrefix => format!("0o{:03o}", byte),
-                        OctalStyle::ShortPrefix => format!("o{:03o}", byte),
-                        OctalStyle::NoPrefix => format!("{:03o}", byte),
-                        OctalStyle::EscapeSequence => format!("\\{:03o}", byte),
-                    };
-                    byte_strings.push(byte_str);
+    fn set_custom_params(&mut self, params: CrcParams) {
+        self.custom_params = params;
+        self.custom_width = params.width.to_string();
+        self.custom_poly = format!("0x{:X}", params.poly);
+        self.custom_init = format!("0x{:X}", params.init);
+        self.custom_xorout = format!("0x{:X}", params.xorout);
+        self.custom_error = None;
+    }
+
+    /// 샘플을 파싱해 카탈로그를 검색하고, 요청 시 전수 탐색 결과를 덧붙입니다.
+    fn run_reveng(&self, brute_force: bool) -> Result<Vec<CrcMatch>, String> {
+        let samples = reveng::parse_samples(&self.reveng_input, &self.input_mode)?;
+        if samples.is_empty() {
+            return Err("Enter at least one sample.".to_string());
+        }
+
+        let mut matches = reveng::search_catalogue(&samples);
+        if brute_force {
+            let width = self
+                .reveng_width
+                .trim()
+                .parse::<u8>()
+                .map_err(|_| format!("Invalid width: {}", self.reveng_width))?;
+            for found in reveng::brute_force(&samples, width)? {
+                if !matches.contains(&found) {
+                    matches.push(found);
                 }
-                byte_strings.join(" ")
             }
         }
+        Ok(matches)
     }
 
-    fn parse_binary_input(&self, input: &str) -> Result<Vec<u8>, String> {
-        if input.trim().is_empty() {
-            return Ok(Vec::new());
-        }
+    fn view_reveng(&self, ctx: &Context<Self>) -> Html {
+        let link = ctx.link();
 
-        let mut result = Vec::new();
-        let mut current_number = String::new();
-        let mut chars = input.chars().peekable();
-
-        while let Some(&c) = chars.peek() {
-            match c {
-                // 공백 문자 처리
-                ' ' | '\n' | '\t' | '\r' => {
-                    if !current_number.is_empty() {
-                        result.push(self.parse_binary_string(&current_number)?);
-                        current_number.clear();
+        html! {
+            <div class="tool-inner" style="margin-top: 8px;">
+                <div>
+                    <div class="tool-subtitle" style="margin-bottom: 3px; font-size: 14px;">{ "Reverse Engineering" }</div>
+                    <textarea
+                        style={format!("{}; height: 80px; resize: vertical;", if self.reveng_error.is_some() {
+                            "overflow: auto; border: 2px solid var(--color-error)"
+                        } else {
+                            "overflow: auto"
+                        })}
+                        value={self.reveng_input.clone()}
+                        oninput={link.callback(|e: InputEvent| {
+                            let input: HtmlInputElement = e.target_unchecked_into();
+                            Msg::RevEngInputChanged(input.value())
+                        })}
+                        placeholder="One sample per line: <data> <crc>, e.g. 01 03 00 00 00 01 840A" />
+                    <div style="color: var(--color-subfont); font-size: 10px; margin-top: 2px;">
+                        { "Data uses the current input format; the last token on each line is the CRC in hex, in the byte order it was received." }
+                    </div>
+                    <div style="display: flex; align-items: center; gap: 6px; margin-top: 6px;">
+                        <button class="tool-btn" onclick={link.callback(|_| Msg::RevEngSearch(false))}>
+                            { "Search Catalogue" }
+                        </button>
+                        <button class="tool-btn" onclick={link.callback(|_| Msg::RevEngSearch(true))}>
+                            { "Brute Force" }
+                        </button>
+                        <span style="font-size: 12px;">{ format!("Width (1-{}):", MAX_BRUTE_FORCE_WIDTH) }</span>
+                        <input
+                            type="number"
+                            min="1"
+                            max={MAX_BRUTE_FORCE_WIDTH.to_string()}
+                            style="width: 60px; padding: 2px; font-size: 12px;"
+                            value={self.reveng_width.clone()}
+                            oninput={link.callback(|e: InputEvent| {
+                                let input: HtmlInputElement = e.target_unchecked_into();
+                                Msg::RevEngWidthChanged(input.value())
+                            })} />
+                    </div>
+                    if let Some(error_msg) = &self.reveng_error {
+                        <div style="color: var(--color-error); font-size: 11px; margin-top: 4px; line-height: 1.2;">
+                            { error_msg }
+                        </div>
                     }
-                    chars.next();
-                }
-                // "0b" 접두사 처리
-                '0' => {
-                    chars.next();
-                    if let Some(&next) = chars.peek() {
-                        if next == 'b' || next == 'B' {
-                            if !current_number.is_empty() {
-                                result.push(self.parse_binary_string(&current_number)?);
-                                current_number.clear();
-                            }
-                            chars.next(); // 'b' 건너뛰기
-                            current_number = self.collect_binary_digits(&mut chars)?;
-                            if !current_number.is_empty() {
-                                result.push(self.parse_binary_string(&current_number)?);
-                                current_number.clear();
-                            }
+                    if let Some(matches) = &self.reveng_matches {
+                        if matches.is_empty() {
+                            <div style="font-size: 12px; margin-top: 4px;">{ "No matching CRC parameters found." }</div>
                         } else {
-                            // 일반 '0' 이진수 숫자
-                            current_number.push('0');
-                            if current_number.len() == 8 {
-                                result.push(self.parse_binary_string(&current_number)?);
-                                current_number.clear();
-                            }
-                        }
-                    } else {
-                        // 마지막 문자가 '0'인 경우
-                        current_number.push('0');
-                        if current_number.len() == 8 {
-                            result.push(self.parse_binary_string(&current_number)?);
-                            current_number.clear();
+                            <div style="font-size: 12px; margin-top: 4px;">
+                                { for matches.iter().enumerate().map(|(index, found)| {
+                                    let endianness = match found.endianness {
+                                        Endianness::BigEndian => "Big Endian",
+                                        Endianness::LittleEndian => "Little Endian",
+                                    };
+                                    html! {
+                                        <div
+                                            style="cursor: pointer; padding: 3px 0; border-bottom: 1px solid var(--color-third);"
+                                            title="Click to use these parameters"
+                                            onclick={link.callback(move |_| Msg::RevEngApply(index))}>
+                                            <strong>{ found.algorithm.name() }</strong>
+                                            { format!(" ({})", endianness) }
+                                            <div style="font-family: monospace; font-size: 11px; color: var(--color-subfont);">
+                                                { found.algorithm.params().describe() }
+                                            </div>
+                                        </div>
+                                    }
+                                }) }
+                            </div>
                         }
                     }
-                }
-                'b' | 'B' => {
-                    if !current_number.is_empty() {
-                        result.push(self.parse_binary_string(&current_number)?);
-                        current_number.clear();
-                    }
-                    chars.next(); // 'b' 건너뛰기
-                    current_number = self.collect_binary_digits(&mut chars)?;
-                    if !current_number.is_empty() {
-                        result.push(self.parse_binary_string(&current_number)?);
-                        current_number.clear();
-                    }
-                }
-                // 이진수 숫자 수집
-                '1' => {
-                    current_number.push(chars.next().unwrap());
-                    // 8비트가 모이면 바이트로 변환
-                    if current_number.len() == 8 {
-                        result.push(self.parse_binary_string(&current_number)?);
-                        current_number.clear();
-                    }
-                }
-                _ => {
-                    return Err(format!("Invalid character '{}' in binary input. Only 0 and 1 are allowed.", c));
-                }
-            }
-        }
-
-        // 남은 숫자 처리
-        if !current_number.is_empty() {
-            if current_number.len() > 8 {
-                return Err(format!("Binary sequence '{}' is longer than 8 bits", current_number));
-            }
-            result.push(self.parse_binary_string(&current_number)?);
-        }
-
-        if result.is_empty() {
-            return Err("No valid binary values found in input".to_string());
+                </div>
+            </div>
         }
-
-        Ok(result)
     }
 
-    fn collect_binary_digits(
-        &self,
-        chars: &mut std::iter::Peekable<std::str::Chars>,
-    ) -> Result<String, String> {
-        let mut binary_str = String::new();
-
-        while let Some(&c) = chars.peek() {
-            if c == '0' || c == '1' {
-                binary_str.push(chars.next().unwrap());
-                if binary_str.len() == 8 {
-                    break;
-                }
-            } else {
-                break;
+    fn view_verification(&self) -> Html {
+        let options = self.output_options();
+        let content = if self.file.is_some() {
+            html! { <div style="font-size: 12px;">{ "Verification works on typed input. For files, use the CLI: computools crc --verify --file PATH" }</div> }
+        } else if self.bytes.is_empty() {
+            html! { <div style="color: var(--color-subfont); font-size: 12px;">{ "Enter a frame with its CRC appended, e.g. 01 03 00 00 00 01 84 0A." }</div> }
+        } else {
+            match verify::verify(&self.selected_algorithm, &self.bytes, &self.endianness) {
+                Ok(result) => Self::view_verification_result(&result, &options),
+                Err(err) => html! {
+                    <div style="color: var(--color-error); font-size: 11px; line-height: 1.2;">{ err }</div>
+                },
             }
-        }
+        };
 
-        if binary_str.is_empty() {
-            return Err("Expected binary digits after prefix".to_string());
+        html! {
+            <div class="tool-inner" style="margin-top: 8px;">
+                <div>
+                    <div class="tool-subtitle" style="margin-bottom: 3px; font-size: 14px;">{ "Verification" }</div>
+                    { content }
+                    <div style="color: var(--color-subfont); font-size: 10px; margin-top: 4px;">
+                        { "The trailing CRC bytes are read in the selected endianness. The residue check recomputes the CRC over the whole frame and only applies when the CRC is appended in the algorithm's own bit order." }
+                    </div>
+                </div>
+            </div>
         }
-
-        Ok(binary_str)
     }
 
-    fn parse_binary_string(&self, binary_str: &str) -> Result<u8, String> {
-        if binary_str.len() > 8 {
-            return Err(format!("Binary sequence '{}' exceeds 8 bits", binary_str));
+    fn view_verification_result(result: &CrcVerification, options: &OutputOptions) -> Html {
+        let matches = result.matches();
+        let residue = match result.residue_ok {
+            Some(true) => "passed",
+            Some(false) => "failed",
+            None => "not applicable",
+        };
+
+        html! {
+            <div style={format!("padding: 10px; border-radius: 5px; {}",
+                if matches {
+                    "background-color: #d4edda; color: #155724; border: 1px solid #c3e6cb;"
+                } else {
+                    "background-color: #f8d7da; color: #721c24; border: 1px solid #f5c6cb;"
+                })}>
+                <div style="font-weight: bold; margin-bottom: 5px;">
+                    if matches {
+                        {"✅ CRC matches!"}
+                    } else {
+                        {"❌ CRC mismatch!"}
+                    }
+                </div>
+                <div style="font-size: 12px;">
+                    <div>{ format!("Data: {} byte(s)", result.data_len) }</div>
+                    <div>{ format!("Frame CRC: {}", format_crc_output(result.expected, result.width, options)) }</div>
+                    <div>{ format!("Computed: {}", format_crc_output(result.actual, result.width, options)) }</div>
+                    <div>{ format!("Residue check: {}", residue) }</div>
+                </div>
+            </div>
         }
-        u8::from_str_radix(binary_str, 2).map_err(|_| format!("Invalid binary value: '{}'", binary_str))
     }
 
-    fn parse_decimal_input(&self, input: &str) -> Result<Vec<u8>, String> {
-        if input.trim().is_empty() {
-            return Ok(Vec::new());
+    /// 목표 CRC가 비어 있으면 None
+    fn run_forcing(&self) -> Option<Result<ForcedCrc, String>> {
+        if self.force_target.trim().is_empty() {
+            return None;
         }
-
-        input
-            .split_whitespace()
-            .map(|s| {
-                s.parse::<u16>()
-                    .map_err(|_| format!("Invalid decimal number: '{}'", s))
-                    .and_then(|num| {
-                        if num > 255 {
-                            Err(format!("Decimal value {} exceeds maximum range (0-255)", num))
-                        } else {
-                            Ok(num as u8)
-                        }
-                    })
-            })
-            .collect()
+        let result = parse_param_value(&self.force_target).and_then(|target| {
+            let position = match self.force_offset.trim() {
+                "" => PatchPosition::Append,
+                offset => PatchPosition::Overwrite(
+                    offset
+                        .parse::<usize>()
+                        .map_err(|_| format!("Invalid offset: '{}'", offset))?,
+                ),
+            };
+            forcing::force(&self.selected_algorithm, &self.bytes, target, position)
+        });
+        Some(result)
     }
 
-    fn parse_octal_input(&self, input: &str) -> Result<Vec<u8>, String> {
-        if input.trim().is_empty() {
-            return Ok(Vec::new());
-        }
+    fn view_forcing(&self, ctx: &Context<Self>) -> Html {
+        let link = ctx.link();
+        let result = if self.file.is_some() { None } else { self.run_forcing() };
+        let patch_len = forcing::patch_len(self.selected_algorithm.params().width);
 
-        let mut result = Vec::new();
-        let mut current_number = String::new();
-        let mut chars = input.chars().peekable();
-
-        while let Some(&c) = chars.peek() {
-            match c {
-                // 공백 문자 처리
-                ' ' | '\n' | '\t' | '\r' => {
-                    if !current_number.is_empty() {
-                        result.push(self.parse_octal_string(&current_number)?);
-                        current_number.clear();
-                    }
-                    chars.next();
-                }
-                // "\NNN" escape sequence 처리
-                '\\' => {
-                    if !current_number.is_empty() {
-                        result.push(self.parse_octal_string(&current_number)?);
-                        current_number.clear();
-                    }
-                    chars.next(); // '\' 건너뛰기
-                    current_number = self.collect_octal_digits(&mut chars)?;
-                    if !current_number.is_empty() {
-                        result.push(self.parse_octal_string(&current_number)?);
-                        current_number.clear();
-                    }
-                }
-                // "0o" 접두사 처리
-                '0' => {
-                    chars.next();
-                    if let Some(&next) = chars.peek() {
-                        if next == 'o' || next == 'O' {
-                            if !current_number.is_empty() {
-                                result.push(self.parse_octal_string(&current_number)?);
-                                current_number.clear();
-                            }
-                            chars.next(); // 'o' 건너뛰기
-                            current_number = self.collect_octal_digits(&mut chars)?;
-                            if !current_number.is_empty() {
-                                result.push(self.parse_octal_string(&current_number)?);
-                                current_number.clear();
-                            }
-                        } else {
-                            current_number.push('0');
-                        }
-                    } else {
-                        current_number.push('0');
-                    }
-                }
-                'o' | 'O' => {
-                    if !current_number.is_empty() {
-                        result.push(self.parse_octal_string(&current_number)?);
-                        current_number.clear();
+        html! {
+            <div class="tool-inner" style="margin-top: 8px;">
+                <div>
+                    <div class="tool-subtitle" style="margin-bottom: 3px; font-size: 14px;">{ "CRC Forcing" }</div>
+                    <div style="display: flex; align-items: center; gap: 6px;">
+                        <input
+                            type="text"
+                            style={format!("width: 50%; padding: 2px; font-size: 12px;{}",
+                                if matches!(result, Some(Err(_))) { " border: 2px solid var(--color-error);" } else { "" })}
+                            placeholder="Target CRC (hex), e.g. 0xDEADBEEF"
+                            value={self.force_target.clone()}
+                            oninput={link.callback(|e: InputEvent| {
+                                let input: HtmlInputElement = e.target_unchecked_into();
+                                Msg::ForceTargetChanged(input.value())
+                            })} />
+                        <input
+                            type="text"
+                            style="width: 50%; padding: 2px; font-size: 12px;"
+                            placeholder="Overwrite offset (empty = append)"
+                            value={self.force_offset.clone()}
+                            oninput={link.callback(|e: InputEvent| {
+                                let input: HtmlInputElement = e.target_unchecked_into();
+                                Msg::ForceOffsetChanged(input.value())
+                            })} />
+                    </div>
+                    <div style="color: var(--color-subfont); font-size: 10px; margin-top: 2px;">
+                        { format!("Computes the {} byte(s) to append or overwrite so the input's CRC equals the target.", patch_len) }
+                    </div>
+                    if self.file.is_some() {
+                        <div style="font-size: 12px; margin-top: 4px;">
+                            { "Forcing works on typed input. For files, use the CLI: computools crc --force HEX --file PATH" }
+                        </div>
                     }
-                    chars.next(); // 'o' 건너뛰기
-                    current_number = self.collect_octal_digits(&mut chars)?;
-                    if !current_number.is_empty() {
-                        result.push(self.parse_octal_string(&current_number)?);
-                        current_number.clear();
+                    if let Some(Err(error_msg)) = &result {
+                        <div style="color: var(--color-error); font-size: 11px; margin-top: 4px; line-height: 1.2;">
+                            { error_msg }
+                        </div>
                     }
-                }
-                // 8진수 숫자 수집
-                '0'..='7' => {
-                    current_number.push(chars.next().unwrap());
-                    // 3자리가 모이면 바이트로 변환 (8진수 377 = 255)
-                    if current_number.len() == 3 {
-                        result.push(self.parse_octal_string(&current_number)?);
-                        current_number.clear();
+                    if let Some(Ok(forced)) = &result {
+                        <div class="tool-subtitle" style="margin-top: 6px; font-size: 12px;">
+                            { format!("Patch bytes at offset {}", forced.offset) }
+                        </div>
+                        <input
+                            type="text"
+                            readonly=true
+                            style="cursor: pointer; font-weight: bold;"
+                            value={bytes_to_string(&forced.patch)}
+                            onclick={link.callback(|e: MouseEvent| {
+                                let input: HtmlInputElement = e.target_unchecked_into();
+                                Msg::CopyToClipboard(input.value())
+                            })} />
+                        <div class="tool-subtitle" style="margin-top: 6px; font-size: 12px;">{ "Patched data" }</div>
+                        <textarea
+                            readonly=true
+                            style="cursor: pointer; height: 50px; resize: vertical;"
+                            value={bytes_to_string(&forced.data)}
+                            onclick={link.callback(|e: MouseEvent| {
+                                let input: HtmlInputElement = e.target_unchecked_into();
+                                Msg::CopyToClipboard(input.value())
+                            })} />
                     }
-                }
-                _ => {
-                    return Err(format!("Invalid character '{}' in octal input. Only 0-7 are allowed.", c));
-                }
-            }
+                </div>
+            </div>
         }
+    }
 
-        // 남은 숫자 처리
-        if !current_number.is_empty() {
-            if current_number.len() > 3 {
-                return Err(format!("Octal sequence '{}' is longer than 3 digits", current_number));
-            }
-            result.push(self.parse_octal_string(&current_number)?);
+    fn generated_code(&self) -> String {
+        let params = self.selected_algorithm.params();
+        match self.codegen_language {
+            Some(language) => codegen::generate(
+                &params,
+                &codegen::code_name(&self.selected_algorithm),
+                language,
+                self.codegen_style,
+            ),
+            None => codegen::format_table(&params),
         }
+    }
 
-        if result.is_empty() {
-            return Err("No valid octal values found in input".to_string());
-        }
+    fn view_codegen(&self, ctx: &Context<Self>) -> Html {
+        let link = ctx.link();
+        // 룩업 테이블과 HDL은 구현 방식 선택이 의미 없음
+        let style_disabled = self.codegen_language.is_none_or(|language| language.is_hdl());
 
-        Ok(result)
+        html! {
+            <div class="tool-inner" style="margin-top: 8px;">
+                <div>
+                    <div class="tool-subtitle" style="margin-bottom: 3px; font-size: 14px;">{ "Code Generator" }</div>
+                    <div style="display: flex; align-items: center; gap: 6px; margin-bottom: 6px;">
+                        <select
+                            style="width: 50%; padding: 2px; font-size: 12px;"
+                            onchange={link.callback(|e: Event| {
+                                let select: HtmlInputElement = e.target_unchecked_into();
+                                Msg::CodegenLanguageChanged(select.value())
+                            })}>
+                            { for CodeLanguage::all().into_iter().map(|language| html! {
+                                <option value={language.name()} selected={self.codegen_language == Some(language)}>
+                                    { language.name() }
+                                </option>
+                            }) }
+                            <option value="table" selected={self.codegen_language.is_none()}>{ "Lookup Table" }</option>
+                        </select>
+                        <select
+                            style="width: 50%; padding: 2px; font-size: 12px;"
+                            disabled={style_disabled}
+                            onchange={link.callback(|e: Event| {
+                                let select: HtmlInputElement = e.target_unchecked_into();
+                                match select.value().as_str() {
+                                    "bitwise" => Msg::CodegenStyleChanged(CodeStyle::Bitwise),
+                                    _ => Msg::CodegenStyleChanged(CodeStyle::TableDriven),
+                                }
+                            })}>
+                            <option value="table" selected={self.codegen_style == CodeStyle::TableDriven}>{ "Table-driven" }</option>
+                            <option value="bitwise" selected={self.codegen_style == CodeStyle::Bitwise}>{ "Bitwise" }</option>
+                        </select>
+                    </div>
+                    <textarea
+                        readonly=true
+                        style="cursor: pointer; height: 240px; resize: vertical; font-family: monospace; font-size: 12px; white-space: pre; overflow: auto;"
+                        value={self.generated_code()}
+                        onclick={link.callback(|e: MouseEvent| {
+                            let input: HtmlInputElement = e.target_unchecked_into();
+                            Msg::CopyToClipboard(input.value())
+                        })} />
+                    <div style="color: var(--color-subfont); font-size: 10px; margin-top: 2px;">
+                        { "Verilog/VHDL modules process one byte per clock. Click to copy." }
+                    </div>
+                </div>
+            </div>
+        }
     }
 
-    fn collect_octal_digits(
-        &self,
-        chars: &mut std::iter::Peekable<std::str::Chars>,
-    ) -> Result<String, String> {
-        let mut octal_str = String::new();
-
-        while let Some(&c) = chars.peek() {
-            if c >= '0' && c <= '7' {
-                octal_str.push(chars.next().unwrap());
-                if octal_str.len() == 3 {
-                    break;
-                }
-            } else {
-                break;
-            }
+    fn calculate_crc(&mut self) {
+        // 파일 입력의 결과는 start_file_crc가 채움
+        if self.file.is_some() {
+            return;
         }
 
-        if octal_str.is_empty() {
-            return Err("Expected octal digits after prefix".to_string());
+        if self.compare_all && !self.bytes.is_empty() {
+            self.comparison = compare::calculate_all(&self.bytes, self.compare_width);
+            compare::sort_comparisons(&mut self.comparison, self.compare_sort, self.compare_descending);
+        } else {
+            self.comparison.clear();
         }
 
-        Ok(octal_str)
+        if self.bytes.is_empty() {
+            self.crc_result = 0;
+            return;
+        }
+
+        (self.crc_result, self.width) = self.selected_algorithm.calculate(&self.bytes);
     }
 
-    fn parse_octal_string(&self, octal_str: &str) -> Result<u8, String> {
-        if octal_str.len() > 3 {
-            return Err(format!("Octal sequence '{}' exceeds 3 digits", octal_str));
+    fn output_options(&self) -> OutputOptions {
+        OutputOptions {
+            output_mode: self.output_mode.clone(),
+            hex_style: self.hex_style.clone(),
+            binary_style: self.binary_style.clone(),
+            octal_style: self.octal_style.clone(),
+            endianness: self.endianness.clone(),
+            byte_formatting: self.byte_formatting.clone(),
         }
-        u8::from_str_radix(octal_str, 8).map_err(|_| format!("Invalid octal value: '{}'", octal_str))
+    }
+
+    fn format_crc_output(&self) -> String {
+        format_crc_output(self.crc_result, self.width, &self.output_options())
     }
 
     // Local Storage 키 상수들
@@ -2700,6 +2152,17 @@ impl ToolCrc {
     const STORAGE_KEY_ENDIANNESS: &'static str = "crc_endianness";
     const STORAGE_KEY_BYTE_FORMATTING: &'static str = "crc_byte_formatting";
     const STORAGE_KEY_CRC_ALGORITHM: &'static str = "crc_algorithm";
+    const STORAGE_KEY_COMPARE_ALL: &'static str = "crc_compare_all";
+    const STORAGE_KEY_VERIFY_MODE: &'static str = "crc_verify_mode";
+    const STORAGE_KEY_COMPARE_WIDTH: &'static str = "crc_compare_width";
+    const STORAGE_KEY_CUSTOM_WIDTH: &'static str = "crc_custom_width";
+    const STORAGE_KEY_CUSTOM_POLY: &'static str = "crc_custom_poly";
+    const STORAGE_KEY_CUSTOM_INIT: &'static str = "crc_custom_init";
+    const STORAGE_KEY_CUSTOM_REFIN: &'static str = "crc_custom_refin";
+    const STORAGE_KEY_CUSTOM_REFOUT: &'static str = "crc_custom_refout";
+    const STORAGE_KEY_CUSTOM_XOROUT: &'static str = "crc_custom_xorout";
+    const STORAGE_KEY_CODEGEN_LANGUAGE: &'static str = "crc_codegen_language";
+    const STORAGE_KEY_CODEGEN_STYLE: &'static str = "crc_codegen_style";
 
     fn get_local_storage() -> Option<Storage> {
         window()?.local_storage().ok()?
@@ -2788,10 +2251,65 @@ impl ToolCrc {
             })
             .unwrap_or(ByteFormatting::Continuous);
 
+        let compare_all = storage
+            .as_ref()
+            .and_then(|s| s.get_item(Self::STORAGE_KEY_COMPARE_ALL).ok().flatten())
+            .map(|s| s == "true")
+            .unwrap_or(false);
+
+        let verify_mode = storage
+            .as_ref()
+            .and_then(|s| s.get_item(Self::STORAGE_KEY_VERIFY_MODE).ok().flatten())
+            .map(|s| s == "true")
+            .unwrap_or(false);
+
+        let compare_width = storage
+            .as_ref()
+            .and_then(|s| s.get_item(Self::STORAGE_KEY_COMPARE_WIDTH).ok().flatten())
+            .and_then(|s| s.parse::<u8>().ok());
+
+        let custom_item = |key: &str| storage.as_ref().and_then(|s| s.get_item(key).ok().flatten());
+        let stored_params = CrcParams {
+            width: custom_item(Self::STORAGE_KEY_CUSTOM_WIDTH)
+                .and_then(|s| s.parse::<u8>().ok())
+                .unwrap_or(0),
+            poly: custom_item(Self::STORAGE_KEY_CUSTOM_POLY)
+                .and_then(|s| parse_param_value(&s).ok())
+                .unwrap_or(0),
+            init: custom_item(Self::STORAGE_KEY_CUSTOM_INIT)
+                .and_then(|s| parse_param_value(&s).ok())
+                .unwrap_or(0),
+            refin: custom_item(Self::STORAGE_KEY_CUSTOM_REFIN).as_deref() == Some("true"),
+            refout: custom_item(Self::STORAGE_KEY_CUSTOM_REFOUT).as_deref() == Some("true"),
+            xorout: custom_item(Self::STORAGE_KEY_CUSTOM_XOROUT)
+                .and_then(|s| parse_param_value(&s).ok())
+                .unwrap_or(0),
+        };
+        // 저장된 값이 없거나 손상되었으면 기본값(CRC-32/ISO-HDLC 파라미터)을 사용
+        let custom_params = if stored_params.validate().is_ok() {
+            stored_params
+        } else {
+            CrcParams::default()
+        };
+
+        let codegen_language = match custom_item(Self::STORAGE_KEY_CODEGEN_LANGUAGE).as_deref() {
+            Some("table") => None,
+            Some(name) => Some(CodeLanguage::from_name(name).unwrap_or(CodeLanguage::C)),
+            None => Some(CodeLanguage::C),
+        };
+        let codegen_style = match custom_item(Self::STORAGE_KEY_CODEGEN_STYLE).as_deref() {
+            Some("bitwise") => CodeStyle::Bitwise,
+            _ => CodeStyle::TableDriven,
+        };
+
         let selected_algorithm = storage
             .as_ref()
             .and_then(|s| s.get_item(Self::STORAGE_KEY_CRC_ALGORITHM).ok().flatten())
             .and_then(|s| CrcAlgorithm::from_name(&s))
+            .map(|algorithm| match algorithm {
+                CrcAlgorithm::Custom(_) => CrcAlgorithm::Custom(custom_params),
+                other => other,
+            })
             .unwrap_or(CrcAlgorithm::Crc32IsoHdlc);
 
         Self {
@@ -2804,6 +2322,32 @@ impl ToolCrc {
             endianness,
             byte_formatting,
             selected_algorithm,
+            custom_params,
+            custom_width: custom_params.width.to_string(),
+            custom_poly: format!("0x{:X}", custom_params.poly),
+            custom_init: format!("0x{:X}", custom_params.init),
+            custom_xorout: format!("0x{:X}", custom_params.xorout),
+            custom_error: None,
+            compare_all,
+            compare_width,
+            compare_query: String::new(),
+            compare_sort: ComparisonSort::Name,
+            compare_descending: false,
+            comparison: Vec::new(),
+            verify_mode,
+            file: None,
+            file_job: Rc::new(Cell::new(0)),
+            file_progress: 0.0,
+            is_computing: false,
+            is_dragging: false,
+            reveng_input: String::new(),
+            reveng_width: "16".to_string(),
+            reveng_matches: None,
+            reveng_error: None,
+            force_target: String::new(),
+            force_offset: String::new(),
+            codegen_language,
+            codegen_style,
             bytes: Vec::new(),
             bytes_string: String::new(),
             crc_result: 0,
@@ -2867,6 +2411,27 @@ impl ToolCrc {
             let _ = storage.set_item(Self::STORAGE_KEY_BYTE_FORMATTING, byte_formatting_str);
 
             let _ = storage.set_item(Self::STORAGE_KEY_CRC_ALGORITHM, self.selected_algorithm.name());
+
+            let _ = storage.set_item(Self::STORAGE_KEY_COMPARE_ALL, &self.compare_all.to_string());
+            let _ = storage.set_item(Self::STORAGE_KEY_VERIFY_MODE, &self.verify_mode.to_string());
+            let compare_width = self.compare_width.map(|width| width.to_string()).unwrap_or_default();
+            let _ = storage.set_item(Self::STORAGE_KEY_COMPARE_WIDTH, &compare_width);
+
+            let params = &self.custom_params;
+            let _ = storage.set_item(Self::STORAGE_KEY_CUSTOM_WIDTH, &params.width.to_string());
+            let _ = storage.set_item(Self::STORAGE_KEY_CUSTOM_POLY, &format!("0x{:X}", params.poly));
+            let _ = storage.set_item(Self::STORAGE_KEY_CUSTOM_INIT, &format!("0x{:X}", params.init));
+            let _ = storage.set_item(Self::STORAGE_KEY_CUSTOM_REFIN, &params.refin.to_string());
+            let _ = storage.set_item(Self::STORAGE_KEY_CUSTOM_REFOUT, &params.refout.to_string());
+            let _ = storage.set_item(Self::STORAGE_KEY_CUSTOM_XOROUT, &format!("0x{:X}", params.xorout));
+
+            let codegen_language = self.codegen_language.map(|language| language.name()).unwrap_or("table");
+            let _ = storage.set_item(Self::STORAGE_KEY_CODEGEN_LANGUAGE, codegen_language);
+            let codegen_style = match self.codegen_style {
+                CodeStyle::TableDriven => "table",
+                CodeStyle::Bitwise => "bitwise",
+            };
+            let _ = storage.set_item(Self::STORAGE_KEY_CODEGEN_STYLE, codegen_style);
         }
     }
 }
@@ -1,8 +1,14 @@
+use gloo::worker::{Spawnable, WorkerBridge};
 use gloo_timers::future::TimeoutFuture;
 use indexmap::IndexMap;
-use md5::{Digest as Md5Digest, Md5};
-use sha1::{Digest as Sha1Digest, Sha1};
-use sha2::{Digest as Sha2Digest, Sha256, Sha512};
+use computools_core::bytes::InputEncoding;
+use computools_core::file_hash::manifest::{self, CheckStatus, HashedFile, Manifest, ManifestCheck, ManifestStyle};
+use computools_core::file_hash::{
+    compare_hash, compare_hmac_results, format_file_size, format_hash_output, get_file_icon,
+    normalize_hash_input, compute_hashes, HashAlgorithm, HashComparison, HashResults,
+    OutputFormat, VerificationType, ALLOWED_MIME_TYPES,
+};
+use computools_core::file_type::{detect_file_type, FileType, SNIFF_LENGTH};
 use wasm_bindgen::closure::Closure;
 use wasm_bindgen::{JsCast, JsValue};
 use wasm_bindgen_futures::{spawn_local, JsFuture};
@@ -10,14 +16,17 @@ use web_sys::Blob;
 use web_sys::{window, HtmlInputElement, DragEvent, Storage};
 use web_sys::{File, FileReader as WebFileReader, ProgressEvent};
 use yew::prelude::*;
+use crate::components::download::download_bytes;
 use crate::components::tool_category::ToolCategoryManager;
-use base64::{engine::general_purpose::STANDARD as BASE64_STANDARD, Engine};
-use hex;
-use hmac::{Hmac, Mac};
-use crc::{Crc, CRC_32_ISO_HDLC};
 
-// 청크 처리를 위한 상수 - 성능 향상을 위해 청크 크기 증가
-const CHUNK_SIZE: usize = 16 * 1024 * 1024;
+pub mod worker;
+
+use worker::{HashRequest, HashResponse, HashTask, HashWorker, JsCodec};
+
+// 청크 처리를 위한 상수 - 해시는 워커에서 같은 크기로 읽음
+pub(crate) use worker::CHUNK_SIZE;
+// Trunk가 워커 바이너리(hash-worker)와 함께 만드는 로더 스크립트
+const WORKER_LOADER_PATH: &str = "/hash-worker_loader.js";
 const PROGRESS_UPDATE_INTERVAL: u32 = 1;
 const UI_UPDATE_DELAY_MS: u32 = 10;
 const PROGRESS_UPDATE_RETURN: u32 = 20;
@@ -28,83 +37,15 @@ const STORAGE_KEY_HASH_ALGORITHMS: &str = "file_hash_algorithms";
 const STORAGE_KEY_OUTPUT_FORMAT: &str = "file_hash_output_format";
 const STORAGE_KEY_HMAC_ENABLED: &str = "file_hash_hmac_enabled";
 
-// 파일 크기 제한 및 검증을 위한 상수들
-const MAX_FILE_SIZE: usize = 2 * 1024 * 1024 * 1024; // 2GB 제한
-const ALLOWED_MIME_TYPES: &[&str] = &[
-    // 일반적으로 안전한 파일 타입들
-    "application/octet-stream",
-    "application/pdf",
-    "application/zip",
-    "application/x-zip-compressed",
-    "application/x-rar-compressed",
-    "application/x-7z-compressed",
-    "application/gzip",
-    "application/x-tar",
-    "application/json",
-    "application/xml",
-    "application/msword",
-    "application/vnd.openxmlformats-officedocument.wordprocessingml.document",
-    "application/vnd.ms-excel",
-    "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet",
-    "application/vnd.ms-powerpoint",
-    "application/vnd.openxmlformats-officedocument.presentationml.presentation",
-    "text/plain",
-    "text/csv",
-    "text/html",
-    "text/css",
-    "text/javascript",
-    "text/xml",
-    "image/jpeg",
-    "image/png",
-    "image/gif",
-    "image/bmp",
-    "image/webp",
-    "image/svg+xml",
-    "image/tiff",
-    "audio/mpeg",
-    "audio/wav",
-    "audio/ogg",
-    "audio/mp4",
-    "video/mp4",
-    "video/mpeg",
-    "video/quicktime",
-    "video/x-msvideo",
-    "video/webm",
-];
-
-// 출력 포맷 옵션
-#[derive(Clone, PartialEq)]
-pub enum OutputFormat {
-    Lowercase,     // d85e5d59b4d49efce3398dc6b6d4b91b
-    Uppercase,     // D85E5D59B4D49EFCE3398DC6B6D4B91B
-    ColonSeparated, // d8:5e:5d:59:b4:d4:9e:fc:e3:39:8d:c6:b6:d4:b9:1b
-    Base64,        // 2F5dWbTUnvzjOY3GttS5Gw==
-    CStyleArray,   // {0xd8, 0x5e, 0x5d, 0x59, 0xb4, 0xd4, 0x9e, 0xfc, 0xe3, 0x39, 0x8d, 0xc6, 0xb6, 0xd4, 0xb9, 0x1b}
-}
-
-// 검증 타입 옵션
-#[derive(Clone, PartialEq)]
-pub enum VerificationType {
-    Hash,  // 일반 해시 검증
-    Hmac,  // HMAC 검증
-}
-
 pub struct ToolFileHash {
     file_info: Option<FileInfo>, // 파일 정보
-    file_data: Option<Vec<u8>>, // 파일 데이터 저장
-    hash_md5: String,
-    hash_sha1: String,
-    hash_sha256: String,
-    hash_sha512: String,
-    hmac_md5: String,
-    hmac_sha1: String,
-    hmac_sha256: String,
-    hmac_sha512: String,
-    crc32: String,
+    file: Option<File>, // 선택한 파일 (HMAC 검증 때 워커가 다시 읽음)
+    file_type: Option<FileType>, // 파일 앞부분의 시그니처로 판별한 종류
+    results: HashResults, // 해시와 HMAC 계산 결과
     is_computing: bool,
     step: bool,
     progress: f64,
-    selected: IndexMap<String, bool>,
+    selected: IndexMap<HashAlgorithm, bool>,
     error_message: Option<String>, // 에러 메시지 추가
     is_dragging: bool, // 드래그 상태
     expected_hash: String, // 예상 해시값
@@ -113,9 +54,44 @@ pub struct ToolFileHash {
     output_format: OutputFormat, // 출력 포맷
     show_file_metadata: bool, // 파일 메타데이터 표시 여부
     hmac_key: String, // HMAC 키
+    hmac_key_encoding: InputEncoding, // HMAC 키 해석 방식 (UTF-8, Hex, Base64)
     show_hmac_section: bool, // HMAC 섹션 표시 여부
     verification_type: VerificationType, // 검증 타입 (일반 해시 vs HMAC)
     verification_hmac_key: String, // 검증용 HMAC 키
+    show_text_section: bool, // 텍스트 해시 섹션 표시 여부
+    text_input: String, // 해시할 텍스트
+    text_encoding: InputEncoding, // 텍스트 해석 방식
+    text_results: Result<HashResults, String>, // 텍스트 해시 결과 또는 디코딩 에러
+    show_manifest_section: bool, // 매니페스트 검증 섹션 표시 여부
+    manifest_text: String, // 붙여넣거나 불러온 체크섬 매니페스트
+    manifest_name: Option<String>, // 불러온 매니페스트 파일 이름 (알고리즘 추정용)
+    manifest_files: Vec<File>, // 매니페스트로 검증할 파일들
+    manifest_checks: Vec<ManifestCheck>, // 줄별 검증 결과
+    manifest_malformed: usize, // 형식을 알 수 없는 줄 수
+    manifest_file_progress: Vec<f64>, // 파일별 진행률 (manifest_files와 같은 순서)
+    manifest_overall: Option<f64>, // 전체 진행률, 계산 중일 때만 Some
+    manifest_style: ManifestStyle, // 생성할 매니페스트 형식
+    manifest_algorithm: HashAlgorithm, // GNU/BSD 매니페스트에 쓸 알고리즘
+    generated_manifest: Option<(String, String)>, // (파일 이름, 내용)
+    worker: Option<WorkerBridge<HashWorker>>, // 해시 워커, 처음 계산할 때 생성
+    next_job: u32, // 워커 작업 번호
+    file_job: Option<u32>, // 진행 중인 파일 해시 작업
+    hmac_job: Option<u32>, // 진행 중인 HMAC 검증 작업
+    manifest_job: Option<ManifestJob>, // 진행 중인 매니페스트 검증/생성 작업
+}
+
+/// 워커에서 진행 중인 매니페스트 작업
+struct ManifestJob {
+    id: u32,
+    files: Vec<(usize, String, f64)>, // 요청 순서대로 (manifest_files 번호, 경로, 크기)
+    hashed: Vec<HashedFile>,
+    task: ManifestTask,
+}
+
+/// 매니페스트 파일들을 모두 해시한 뒤 할 일
+enum ManifestTask {
+    Verify(Manifest),
+    Generate(ManifestStyle, HashAlgorithm),
 }
 
 // 파일 정보를 저장하는 구조체
@@ -127,22 +103,12 @@ pub struct FileInfo {
     pub last_modified: Option<f64>,
 }
 
-// 해시 비교 결과
-#[derive(Clone, PartialEq)]
-pub struct HashComparison {
-    pub algorithm: String,
-    pub matches: bool,
-    pub expected: String,
-    pub actual: String,
-}
-
 pub enum Msg {
     FileSelected(File),
-    HashesComputed(String, String, String, String, String, String, String, String, String), // CRC32와 HMAC들 추가
+    FileTypeDetected(String, Option<FileType>), // (파일 이름, 판별한 종류)
+    Worker(HashResponse), // 해시 워커의 진행률/결과
     CopyToClipboard(String),
-    ComputeStarted,
-    ProgressUpdate(bool, f64),
-    Toggle(String),
+    Toggle(HashAlgorithm),
     FileValidationError(String), // 파일 검증 에러 메시지
     DragEnter,
     DragLeave,
@@ -155,10 +121,24 @@ pub enum Msg {
     OutputFormatChanged(OutputFormat), // 출력 포맷 변경
     ToggleFileMetadata, // 파일 메타데이터 토글
     HmacKeyChanged(String), // HMAC 키 변경
+    HmacKeyEncodingChanged(String), // HMAC 키 인코딩 변경
     ToggleHmacSection, // HMAC 섹션 토글
-    FileDataLoaded(Vec<u8>), // 파일 데이터 로드 완료 메시지
     VerificationTypeChanged(VerificationType), // 검증 타입 변경
     VerificationHmacKeyChanged(String), // 검증용 HMAC 키 변경
+    ToggleTextSection, // 텍스트 해시 섹션 토글
+    TextInputChanged(String),
+    TextEncodingChanged(String),
+    ToggleManifestSection, // 매니페스트 검증 섹션 토글
+    ManifestTextChanged(String),
+    ManifestFilesSelected(Vec<File>), // 여러 파일 선택 (매니페스트 파일은 자동으로 구분)
+    ManifestLoaded(String, String), // (파일 이름, 내용)
+    ClearManifestFiles,
+    VerifyManifest,
+    CancelManifest, // 진행 중인 매니페스트 검증/생성 중단
+    ManifestStyleChanged(String),
+    ManifestAlgorithmChanged(String),
+    GenerateManifest,
+    DownloadManifest,
     NoOp,
 }
 
@@ -195,170 +175,50 @@ impl Component for ToolFileHash {
                     last_modified: Some(last_modified),
                 });
                 
-                let link = _ctx.link().clone();
-                
-                // 계산 시작 상태로 변경
-                link.send_message(Msg::ComputeStarted);
-                
-                // 선택된 해시 알고리즘 확인
-                let selected = self.selected.clone();
-                let hmac_enabled = self.show_hmac_section;
-                let hmac_key = self.hmac_key.clone();
-                
-                // 해시 계산을 청크 단위로 수행하여 UI 블로킹 방지
-                spawn_local(async move {
-                    // 전체 파일 데이터를 먼저 읽어서 저장 (작은 파일용)
-                    let file_data = match read_slice_as_array_buffer(&file).await {
-                        Ok(data) => data,
-                        Err(_) => {
-                            link.send_message(Msg::HashesComputed(
-                                String::new(),
-                                String::new(),
-                                String::new(),
-                                String::new(),
-                                String::new(),
-                                String::new(),
-                                String::new(),
-                                String::new(),
-                                String::new()
-                            ));
-                            return;
-                        }
-                    };
-                    
-                    let mut md5_hasher = 
-                        selected.get("md5").copied().unwrap_or(false).then(Md5::new);
-                    let mut sha1_hasher = selected
-                        .get("sha1")
-                        .copied()
-                        .unwrap_or(false)
-                        .then(Sha1::new);
-                    let mut sha256_hasher = selected
-                        .get("sha256")
-                        .copied()
-                        .unwrap_or(false)
-                        .then(Sha256::new);
-                    let mut sha512_hasher = selected
-                        .get("sha512")
-                        .copied()
-                        .unwrap_or(false)
-                        .then(Sha512::new);
-                    
-                    // CRC32 계산
-                    let mut crc32_result = String::new();
-                    if selected.get("crc32").copied().unwrap_or(false) {
-                        let crc = Crc::<u32>::new(&CRC_32_ISO_HDLC);
-                        let checksum = crc.checksum(&file_data);
-                        crc32_result = format!("{:08x}", checksum);
+                // 선택된 해시 알고리즘과 HMAC 키 (옵션이 활성화되어 있고 키가 있는 경우)
+                let algorithms = self.selected_algorithms();
+                let hmac_key = match self.hmac_key_bytes() {
+                    Ok(key) => key,
+                    Err(error) => {
+                        self.error_message = Some(error);
+                        return true;
                     }
-                    
-                    // HMAC 계산 (옵션이 활성화되어 있고 키가 있는 경우)
-                    let mut hmac_md5_result = String::new();
-                    let mut hmac_sha1_result = String::new();
-                    let mut hmac_sha256_result = String::new();
-                    let mut hmac_sha512_result = String::new();
-                    
-                    if hmac_enabled && !hmac_key.trim().is_empty() {
-                        let hmac_key_bytes = hmac_key.as_bytes();
-                        
-                        // 선택된 알고리즘에 대해서만 HMAC 계산
-                        if selected.get("md5").copied().unwrap_or(false) {
-                            if let Ok(calculated_hmac) = Self::calculate_hmac_md5(hmac_key_bytes, &file_data) {
-                                hmac_md5_result = calculated_hmac;
-                            }
-                        }
-                        
-                        if selected.get("sha1").copied().unwrap_or(false) {
-                            if let Ok(calculated_hmac) = Self::calculate_hmac_sha1(hmac_key_bytes, &file_data) {
-                                hmac_sha1_result = calculated_hmac;
-                            }
-                        }
-                        
-                        if selected.get("sha256").copied().unwrap_or(false) {
-                            if let Ok(calculated_hmac) = Self::calculate_hmac_sha256(hmac_key_bytes, &file_data) {
-                                hmac_sha256_result = calculated_hmac;
-                            }
-                        }
-                        
-                        if selected.get("sha512").copied().unwrap_or(false) {
-                            if let Ok(calculated_hmac) = Self::calculate_hmac_sha512(hmac_key_bytes, &file_data) {
-                                hmac_sha512_result = calculated_hmac;
-                            }
-                        }
-                    }
-                    
-                    link.send_message(Msg::ProgressUpdate(false, 0.0));
-                    
-                    // 해시 계산
-                        if let Some(h) = &mut md5_hasher {
-                        h.update(&file_data);
-                        }
-                        if let Some(h) = &mut sha1_hasher {
-                        h.update(&file_data);
-                        }
-                        if let Some(h) = &mut sha256_hasher {
-                        h.update(&file_data);
-                        }
-                        if let Some(h) = &mut sha512_hasher {
-                        h.update(&file_data);
-                    }
-                    
-                    // 최종 진행률 업데이트
-                    link.send_message(Msg::ProgressUpdate(true, 1.0));
-                    
-                    // 최종 해시 값 계산
-                    let md5_result = md5_hasher.map(|h| format!("{:x}", h.finalize()));
-                    let sha1_result = sha1_hasher.map(|h| format!("{:x}", h.finalize()));
-                    let sha256_result = sha256_hasher.map(|h| format!("{:x}", h.finalize()));
-                    let sha512_result = sha512_hasher.map(|h| format!("{:x}", h.finalize()));
-                    
-                    // 파일 데이터와 해시 값 전송
-                    link.send_message(Msg::FileDataLoaded(file_data));
-                    link.send_message(Msg::HashesComputed(
-                        md5_result.unwrap_or_default(),
-                        sha1_result.unwrap_or_default(),
-                        sha256_result.unwrap_or_default(),
-                        sha512_result.unwrap_or_default(),
-                        hmac_md5_result,
-                        hmac_sha1_result,
-                        hmac_sha256_result,
-                        hmac_sha512_result,
-                        crc32_result,
-                    ));
-                });
-                
-                true
-            }
-            Msg::ComputeStarted => {
+                };
+
+                // 이전 계산은 중단하고 워커에서 청크 단위로 해시
+                let job = self.file_job.take();
+                self.cancel_job(job);
+                let job = self.hmac_job.take();
+                self.cancel_job(job);
+                self.results = HashResults::default();
                 self.is_computing = true;
+                self.step = false;
                 self.progress = 0.0;
+                let task = HashTask { file: file.clone(), algorithms: algorithm_keys(&algorithms) };
+                self.file_job = Some(self.start_job(_ctx, vec![task], hmac_key, false));
+
+                // 종류 판별은 앞부분만 읽음
+                self.file_type = None;
+                if let Ok(head) = file.slice_with_f64_and_f64(0.0, SNIFF_LENGTH as f64) {
+                    let link = _ctx.link().clone();
+                    spawn_local(async move {
+                        if let Ok(bytes) = read_slice_as_array_buffer(&head).await {
+                            link.send_message(Msg::FileTypeDetected(file_name, detect_file_type(&bytes)));
+                        }
+                    });
+                }
+                self.file = Some(file);
+
                 true
             }
-            Msg::ProgressUpdate(step, progress) => {
-                self.step = step;
-                self.progress = progress;
-                true
-            }
-            Msg::HashesComputed(md5, sha1, sha256, sha512, hmac_md5, hmac_sha1, hmac_sha256, hmac_sha512, crc32) => {
-                self.hash_md5 = md5;
-                self.hash_sha1 = sha1;
-                self.hash_sha256 = sha256;
-                self.hash_sha512 = sha512;
-                self.hmac_md5 = hmac_md5;
-                self.hmac_sha1 = hmac_sha1;
-                self.hmac_sha256 = hmac_sha256;
-                self.hmac_sha512 = hmac_sha512;
-                self.crc32 = crc32;
-                self.is_computing = false;
-                self.progress = 1.0;
-                
-                // 예상 해시가 있다면 비교 수행
-                if !self.expected_hash.trim().is_empty() {
-                    self.perform_hash_comparison();
+            Msg::FileTypeDetected(name, file_type) => {
+                // 그사이 다른 파일을 고른 경우 무시
+                if self.file_info.as_ref().is_some_and(|info| info.name == name) {
+                    self.file_type = file_type;
                 }
-                
                 true
             }
+            Msg::Worker(response) => self.worker_response(_ctx, response),
             Msg::CopyToClipboard(value) => {
                 // input_ref에서 HtmlInputElement를 가져옴
                 if let Some(clipboard) = window().map(|w| w.navigator().clipboard()) {
@@ -377,11 +237,12 @@ impl Component for ToolFileHash {
                 }
                 false // 리렌더링 필요 없음
             }
-            Msg::Toggle(key) => {
-                if let Some(value) = self.selected.get_mut(&key) {
+            Msg::Toggle(algorithm) => {
+                if let Some(value) = self.selected.get_mut(&algorithm) {
                     *value = !*value;
                 }
                 self.save_to_storage();
+                self.refresh_text_results();
                 true // 상태가 변경되었으므로 리렌더링
             }
             Msg::FileValidationError(error) => {
@@ -410,7 +271,7 @@ impl Component for ToolFileHash {
                 self.expected_hash = hash;
                 // 해시 계산이 완료된 상태라면 즉시 비교 수행
                 if self.progress == 1.0 && !self.is_computing {
-                    self.perform_hash_comparison();
+                    self.perform_hash_comparison(_ctx);
                 }
                 true
             }
@@ -427,17 +288,15 @@ impl Component for ToolFileHash {
                 true
             }
             Msg::ClearFile => {
+                // 계산 중이면 워커 작업도 중단
+                let job = self.file_job.take();
+                self.cancel_job(job);
+                let job = self.hmac_job.take();
+                self.cancel_job(job);
                 self.file_info = None;
-                self.file_data = None;
-                self.hash_md5 = "".to_string();
-                self.hash_sha1 = "".to_string();
-                self.hash_sha256 = "".to_string();
-                self.hash_sha512 = "".to_string();
-                self.hmac_md5 = "".to_string();
-                self.hmac_sha1 = "".to_string();
-                self.hmac_sha256 = "".to_string();
-                self.hmac_sha512 = "".to_string();
-                self.crc32 = "".to_string();
+                self.file_type = None;
+                self.file = None;
+                self.results = HashResults::default();
                 self.is_computing = false;
                 self.progress = 0.0;
                 self.hash_comparison = None;
@@ -460,15 +319,20 @@ impl Component for ToolFileHash {
             }
             Msg::HmacKeyChanged(key) => {
                 self.hmac_key = key;
+                self.refresh_text_results();
+                true
+            }
+            Msg::HmacKeyEncodingChanged(key) => {
+                if let Some(encoding) = InputEncoding::from_key(&key) {
+                    self.hmac_key_encoding = encoding;
+                    self.refresh_text_results();
+                }
                 true
             }
             Msg::ToggleHmacSection => {
                 self.show_hmac_section = !self.show_hmac_section;
                 self.save_to_storage();
-                true
-            }
-            Msg::FileDataLoaded(data) => {
-                self.file_data = Some(data);
+                self.refresh_text_results();
                 true
             }
             Msg::VerificationTypeChanged(new_type) => {
@@ -479,26 +343,139 @@ impl Component for ToolFileHash {
                 self.verification_hmac_key = key;
                 true
             }
+            Msg::ToggleTextSection => {
+                self.show_text_section = !self.show_text_section;
+                true
+            }
+            Msg::TextInputChanged(text) => {
+                self.text_input = text;
+                self.refresh_text_results();
+                true
+            }
+            Msg::TextEncodingChanged(key) => {
+                if let Some(encoding) = InputEncoding::from_key(&key) {
+                    self.text_encoding = encoding;
+                    self.refresh_text_results();
+                }
+                true
+            }
+            Msg::ToggleManifestSection => {
+                self.show_manifest_section = !self.show_manifest_section;
+                true
+            }
+            Msg::ManifestTextChanged(text) => {
+                self.manifest_text = text;
+                self.manifest_name = None;
+                true
+            }
+            Msg::ManifestFilesSelected(files) => {
+                self.show_manifest_section = true;
+                for file in files {
+                    if manifest::is_manifest_name(&file.name()) {
+                        // 매니페스트 파일은 텍스트로 읽어서 입력란에 채움
+                        let link = _ctx.link().clone();
+                        spawn_local(async move {
+                            if let Ok(bytes) = read_slice_as_array_buffer(&file).await {
+                                let text = String::from_utf8_lossy(&bytes).into_owned();
+                                link.send_message(Msg::ManifestLoaded(file.name(), text));
+                            }
+                        });
+                    } else {
+                        // 같은 경로의 파일은 새로 선택한 것으로 교체
+                        let path = file_path(&file);
+                        self.manifest_files.retain(|existing| file_path(existing) != path);
+                        self.manifest_files.push(file);
+                    }
+                }
+                self.manifest_file_progress = vec![0.0; self.manifest_files.len()];
+                self.manifest_checks.clear();
+                self.generated_manifest = None;
+                true
+            }
+            Msg::ManifestLoaded(name, text) => {
+                self.manifest_text = text;
+                self.manifest_name = Some(name);
+                self.manifest_checks.clear();
+                true
+            }
+            Msg::ClearManifestFiles => {
+                let job = self.manifest_job.take().map(|job| job.id);
+                self.cancel_job(job);
+                self.manifest_files.clear();
+                self.manifest_file_progress.clear();
+                self.manifest_overall = None;
+                self.manifest_checks.clear();
+                self.generated_manifest = None;
+                true
+            }
+            Msg::VerifyManifest => {
+                let parsed = manifest::parse(&self.manifest_text, self.manifest_name.as_deref());
+                self.manifest_malformed = parsed.malformed_lines.len();
+                self.manifest_checks.clear();
+                if parsed.entries.is_empty() {
+                    self.error_message = Some("No checksum lines found in the manifest.".to_string());
+                    return true;
+                }
+
+                // 매니페스트에 있는 파일만, 그 파일에 필요한 알고리즘으로 해시
+                let names: Vec<String> = self.manifest_files.iter().map(file_path).collect();
+                let jobs: Vec<(usize, Vec<HashAlgorithm>)> = (0..names.len())
+                    .map(|index| (index, parsed.algorithms_for(&names, index)))
+                    .filter(|(_, algorithms)| !algorithms.is_empty())
+                    .collect();
+                self.hash_manifest_files(_ctx, jobs, ManifestTask::Verify(parsed));
+                true
+            }
+            Msg::CancelManifest => {
+                let job = self.manifest_job.take().map(|job| job.id);
+                self.cancel_job(job);
+                self.manifest_file_progress = vec![0.0; self.manifest_files.len()];
+                self.manifest_overall = None;
+                true
+            }
+            Msg::ManifestStyleChanged(key) => {
+                if let Some(style) = ManifestStyle::from_key(&key) {
+                    self.manifest_style = style;
+                    self.generated_manifest = None;
+                }
+                true
+            }
+            Msg::ManifestAlgorithmChanged(key) => {
+                if let Some(algorithm) = HashAlgorithm::from_key(&key) {
+                    self.manifest_algorithm = algorithm;
+                    self.generated_manifest = None;
+                }
+                true
+            }
+            Msg::GenerateManifest => {
+                let style = self.manifest_style;
+                let algorithm = self.manifest_algorithm;
+                // JSON은 위에서 선택한 알고리즘을 모두 사용
+                let algorithms = match style.algorithm(algorithm) {
+                    Some(algorithm) => vec![algorithm],
+                    None => self.selected_algorithms(),
+                };
+                if algorithms.is_empty() {
+                    self.error_message = Some("Select at least one hash algorithm for the JSON manifest.".to_string());
+                    return true;
+                }
+
+                self.generated_manifest = None;
+                let jobs = (0..self.manifest_files.len()).map(|index| (index, algorithms.clone())).collect();
+                self.hash_manifest_files(_ctx, jobs, ManifestTask::Generate(style, algorithm));
+                true
+            }
+            Msg::DownloadManifest => {
+                if let Some((name, text)) = &self.generated_manifest {
+                    download_bytes(name, text.as_bytes(), "text/plain");
+                }
+                false
+            }
             Msg::NoOp => false,
         }
     }
 
     fn view(&self, _ctx: &Context<Self>) -> Html {
-        let hashes = vec![
-            ("md5", "MD5", &self.hash_md5),
-            ("sha1", "SHA-1", &self.hash_sha1),
-            ("sha256", "SHA-256", &self.hash_sha256),
-            ("sha512", "SHA-512", &self.hash_sha512),
-            ("crc32", "CRC32", &self.crc32),
-        ];
-        
-        let hmac_hashes = vec![
-            ("hmac_md5", "HMAC-MD5", &self.hmac_md5),
-            ("hmac_sha1", "HMAC-SHA1", &self.hmac_sha1),
-            ("hmac_sha256", "HMAC-SHA256", &self.hmac_sha256),
-            ("hmac_sha512", "HMAC-SHA512", &self.hmac_sha512),
-        ];
-
         html! {
             <>
                 <h1 class="tool-title">
@@ -517,7 +494,7 @@ impl Component for ToolFileHash {
                             <p>{"This tool computes cryptographic hash values for any file you select. It supports multiple algorithms and processes files locally in your browser for privacy and speed."}</p>
                             <h3>{"Supported Features:"}</h3>
                             <ul>
-                                <li><strong>{"Multiple Algorithms:"}</strong> {"MD5, SHA-1, SHA-256, SHA-512, and CRC32 supported with selective computation."}</li>
+                                <li><strong>{"Multiple Algorithms:"}</strong> {"MD5, SHA-1, SHA-224/256/384/512, SHA-512/256, SHA3-256/512, BLAKE2b, BLAKE3, RIPEMD-160 and CRC32 supported with selective computation."}</li>
                                 <li><strong>{"Real-time Progress:"}</strong> {"See progress for large files as they are processed in chunks."}</li>
                                 <li><strong>{"Selective Hashing:"}</strong> {"Choose which hash algorithms to compute to save time and resources."}</li>
                                 <li><strong>{"Copy with Notification:"}</strong> {"Click any output field to copy results with visual feedback."}</li>
@@ -525,6 +502,8 @@ impl Component for ToolFileHash {
                                 <li><strong>{"Drag & Drop Support:"}</strong> {"Simply drag files into the drop zone for easy processing."}</li>
                                 <li><strong>{"File Size Validation:"}</strong> {"Automatic validation with size limits and security checks."}</li>
                                 <li><strong>{"Hash Verification:"}</strong> {"Compare computed hashes with expected values for integrity verification."}</li>
+                                <li><strong>{"Checksum Manifests:"}</strong> {"Verify dropped files against SHA256SUMS, BSD-style or SFV manifests, or generate a downloadable SHA256SUMS/MD5SUMS/SFV/JSON manifest for many files or a whole directory."}</li>
+                                <li><strong>{"Text Hashing:"}</strong> {"Paste UTF-8 text, hex bytes or Base64 and get every selected hash and HMAC instantly. HMAC keys can also be entered as UTF-8, hex or Base64."}</li>
                                 <li><strong>{"Multiple Output Formats:"}</strong> {"Choose from lowercase, uppercase, colon-separated, Base64, or C-style array formats."}</li>
                                 <li><strong>{"File Metadata Display:"}</strong> {"View detailed file information including size, MIME type, and modification date."}</li>
                                 <li><strong>{"HMAC Support:"}</strong> {"Generate Hash-based Message Authentication Codes for secure authentication."}</li>
@@ -538,7 +517,7 @@ impl Component for ToolFileHash {
                                 <ul>
                                     <li>{"Select any file from your device (e.g., image.jpg, document.pdf, archive.zip)"}</li>
                                     <li>{"Drag and drop files directly into the drop zone"}</li>
-                                    <li>{"Files up to 2GB in size are supported"}</li>
+                                    <li>{"Files of any size are supported; hashing runs in a background worker"}</li>
                                     <li>{"Your preferred algorithms and output format will be remembered for next time"}</li>
                                 </ul>
                             </div>
@@ -882,7 +861,7 @@ impl Component for ToolFileHash {
                             <div class="faq-item">
                                 <h3>{"Q: Can I hash very large files?"}</h3>
                                 <p>
-                                    {"A: Yes, this tool processes files in chunks and shows progress for large files. Files up to 2GB are supported. For even larger files, consider using command-line tools like 'sha256sum' or 'certutil' which don't have browser memory limitations."}
+                                    {"A: Yes. Files are read in 16 MB chunks by a background Web Worker, so there is no size limit and only one chunk is held in memory at a time. Progress is shown while hashing and you can cancel at any point. Command-line tools like 'sha256sum' or 'certutil' will still be faster for multi-gigabyte files."}
                                 </p>
                             </div>
                             
@@ -903,7 +882,7 @@ impl Component for ToolFileHash {
                             <div class="faq-item">
                                 <h3>{"Q: What if I select a huge file?"}</h3>
                                 <p>
-                                    {"A: The tool will process it efficiently with a progress bar. However, very large files (approaching 2GB) may use significant memory and take longer. The browser might become less responsive during processing, but won't crash."}
+                                    {"A: The tool hashes it in a background worker with a progress bar, so the page stays responsive. Very large files simply take longer; press Cancel to stop hashing at any time."}
                                 </p>
                             </div>
                             
@@ -1037,18 +1016,17 @@ impl Component for ToolFileHash {
                         <div style="display: flex; flex-wrap: wrap; justify-content: space-between; align-items: center; margin-bottom: 10px;">
                                     <div class="tool-subtitle" style="margin-bottom: 5px; width: 100%;">{ "Choose Hash Algorithms" }</div>
                                     <div style="display: flex; flex-wrap: wrap; gap: 20px; align-items: center; justify-content: right;">
-                                        { for self.selected.iter().map(|(key, &checked)| {
-                                            let key_clone = key.clone();
-                                            let id = format!("checkbox-{}", key); // 고유 ID 생성
+                                        { for self.selected.iter().map(|(&algorithm, &checked)| {
+                                            let id = format!("checkbox-{}", algorithm.key()); // 고유 ID 생성
                                             html! {
                                                 <div style="display: flex; align-items: center; gap: 5px;">
                                                     <input
                                                         type="checkbox"
                                                         id={id.clone()} // ID 적용
                                                         checked={checked}
-                                                        onclick={_ctx.link().callback(move |_| Msg::Toggle(key_clone.clone()))}
+                                                        onclick={_ctx.link().callback(move |_| Msg::Toggle(algorithm))}
                                                     />
-                                                    <label for={id.clone()} style="cursor: pointer; margin-bottom: 0px;">{ key.clone() }</label> // 라벨 클릭 가능
+                                                    <label for={id.clone()} style="cursor: pointer; margin-bottom: 0px;">{ algorithm.name() }</label> // 라벨 클릭 가능
                                                 </div>
                                             }
                                         })}
@@ -1106,22 +1084,33 @@ impl Component for ToolFileHash {
                             </div>
                             
                             if self.show_hmac_section {
-                                <input
-                                    type="text"
-                                    placeholder="Enter HMAC secret key (e.g., 'secret' or hex: '48656c6c6f')"
-                                    value={self.hmac_key.clone()}
-                                    oninput={_ctx.link().callback(|e: InputEvent| {
-                                        let input: HtmlInputElement = e.target_unchecked_into();
-                                        Msg::HmacKeyChanged(input.value())
-                                    })}
-                                    style="width: 100%;"
-                                />
-                                <div style="font-size: 12px; color: var(--color-subfont); margin-top: 2px;">
-                                    {"HMAC will be calculated for all selected hash algorithms above"}
+                                <div style="display: flex; gap: 6px;">
+                                    <input
+                                        type="text"
+                                        placeholder="Enter HMAC secret key (e.g., 'secret' or hex: '48656c6c6f')"
+                                        value={self.hmac_key.clone()}
+                                        oninput={_ctx.link().callback(|e: InputEvent| {
+                                            let input: HtmlInputElement = e.target_unchecked_into();
+                                            Msg::HmacKeyChanged(input.value())
+                                        })}
+                                        style="flex: 1;"
+                                    />
+                                    { Self::view_encoding_select(_ctx, self.hmac_key_encoding, Msg::HmacKeyEncodingChanged) }
                                 </div>
+                                if let Err(error) = self.hmac_key_bytes() {
+                                    <div style="font-size: 12px; color: var(--color-error); margin-top: 2px;">{ error }</div>
+                                } else {
+                                    <div style="font-size: 12px; color: var(--color-subfont); margin-top: 2px;">
+                                        {"HMAC will be calculated for all selected hash algorithms above"}
+                                    </div>
+                                }
                             }
                         </div>
                         
+                        { self.view_text(_ctx) }
+
+                        { self.view_manifest(_ctx) }
+
                         <div class="tool-inner" style="width: 100%; margin-bottom: 10px;">
                             <div>
                                 // 파일 업로드 영역
@@ -1150,8 +1139,8 @@ impl Component for ToolFileHash {
                                         if let Ok(data_transfer) = js_sys::Reflect::get(&event_obj, &"dataTransfer".into()) {
                                             if let Ok(files) = js_sys::Reflect::get(&data_transfer, &"files".into()) {
                                                 if let Ok(file_list_obj) = files.dyn_into::<web_sys::FileList>() {
-                                                    if let Some(file) = file_list_obj.get(0) {
-                                                        return Msg::Drop(file);
+                                                    if let Some(msg) = Self::route_files(&file_list_obj) {
+                                                        return msg;
                                                     }
                                                 }
                                             }
@@ -1165,7 +1154,7 @@ impl Component for ToolFileHash {
                                             // 첫 번째 행: 파일 정보
                                             <div style="display: flex; align-items: center; margin-bottom: 10px;">
                                                 <span style="font-size: 20px; margin-right: 8px;">
-                                                    { Self::get_file_icon(&file_info.mime_type) }
+                                                    { Self::get_file_icon(self.file_type.as_ref().map_or(file_info.mime_type.as_str(), |file_type| file_type.mime_type)) }
                                                 </span>
                                                 <div style="text-align: left; overflow-y: auto;">
                                                     <div style="font-weight: bold; color: var(--color-font);">
@@ -1174,6 +1163,11 @@ impl Component for ToolFileHash {
                                                     <div style="font-size: 12px; color: var(--color-subfont);">
                                                         { format!("{} • {}", Self::format_file_size(file_info.size), &file_info.mime_type) }
                                                     </div>
+                                                    if let Some(file_type) = &self.file_type {
+                                                        <div style="font-size: 12px; color: var(--color-subfont);">
+                                                            { format!("Detected type: {}", file_type.name) }
+                                                        </div>
+                                                    }
                                                 </div>
                                             </div>
                                             // 두 번째 행: Remove 버튼
@@ -1201,19 +1195,19 @@ impl Component for ToolFileHash {
                                                 { "Drop files here or click to upload" }
                                             </div>
                                             <div style="font-size: 12px; color: var(--color-subfont); margin-bottom: 10px;">
-                                                { format!("Supports any file type (Max: {})", Self::format_file_size(MAX_FILE_SIZE)) }
+                                                { "Supports any file type and size. Drop several files with a SHA256SUMS/SFV manifest to verify them all." }
                                             </div>
                                             <input
                                                 type="file"
                                                 id="file-upload"
+                                                multiple=true
                                                 style="display: none;"
                                         onchange={_ctx.link().callback(|e: Event| {
                                             let input: web_sys::HtmlInputElement = e.target_unchecked_into();
                                             if let Some(files) = input.files() {
-                                                if let Some(file) = files.get(0) {
-                                                            let file_clone = file.clone();
+                                                if let Some(msg) = Self::route_files(&files) {
                                                             input.set_value(""); // 같은 파일을 다시 선택할 수 있도록 초기화
-                                                    return Msg::FileSelected(file_clone);
+                                                    return msg;
                                                 }
                                                     }
                                             Msg::NoOp
@@ -1267,6 +1261,17 @@ impl Component for ToolFileHash {
                                                 <span style="font-weight: bold;">{"MIME Type:"}</span>
                                                 <span>{ &file_info.mime_type }</span>
                                             </div>
+
+                                            <div style="display: flex; justify-content: space-between; align-items: center; padding: 8px; background-color: var(--color-third); border-radius: 4px;">
+                                                <span style="font-weight: bold;">{"Detected Type:"}</span>
+                                                <span style="text-align: right;">
+                                                    if let Some(file_type) = &self.file_type {
+                                                        { format!("{} ({}, .{})", file_type.name, file_type.mime_type, file_type.extension) }
+                                                    } else {
+                                                        {"Unknown binary data"}
+                                                    }
+                                                </span>
+                                            </div>
                                             
                                             if let Some(last_modified) = file_info.last_modified {
                                                 <div style="display: flex; justify-content: space-between; align-items: center; padding: 8px; background-color: var(--color-third); border-radius: 4px;">
@@ -1425,6 +1430,12 @@ impl Component for ToolFileHash {
                                     } else {
                                         { format!("Loading...") }
                                     }
+                                    <button
+                                        class="tool-btn"
+                                        style="margin-left: 10px;"
+                                        onclick={_ctx.link().callback(|_| Msg::ClearFile)}>
+                                        { "Cancel" }
+                                    </button>
                                 </div>
                             </div>
                         } else if self.progress == 1.0 {
@@ -1449,9 +1460,9 @@ impl Component for ToolFileHash {
                                     />
                                 </div>
                             {
-                                for hashes.iter().filter(|(key, _, _)| *self.selected.get(*key).unwrap_or(&false)).map(|(_key, label, value)| html! {
+                                for self.results.hashes.iter().filter(|(algorithm, _)| *self.selected.get(algorithm).unwrap_or(&false)).map(|(algorithm, value)| html! {
                                     <div>
-                                        <div class="tool-subtitle" style="margin-bottom: 5px; margin-top: 10px;">{ *label } </div>
+                                        <div class="tool-subtitle" style="margin-bottom: 5px; margin-top: 10px;">{ algorithm.name() } </div>
                                         <input
                                             type="text"
                                             readonly=true
@@ -1469,13 +1480,12 @@ impl Component for ToolFileHash {
                             // HMAC 값들 표시 (HMAC 옵션이 활성화되어 있고 키가 있는 경우)
                             if self.show_hmac_section && !self.hmac_key.trim().is_empty() {
                                 {
-                                    for hmac_hashes.iter().filter(|(key, _, value)| {
-                                        // 해당 알고리즘이 선택되어 있고 HMAC 값이 있는 경우만 표시
-                                        let base_algorithm = key.replace("hmac_", "");
-                                        *self.selected.get(&base_algorithm).unwrap_or(&false) && !value.is_empty()
-                                    }).map(|(_key, label, value)| html! {
+                                    for self.results.hmacs.iter().filter(|(algorithm, _)| {
+                                        // 해당 알고리즘이 선택되어 있는 경우만 표시
+                                        *self.selected.get(algorithm).unwrap_or(&false)
+                                    }).map(|(algorithm, value)| html! {
                                         <div>
-                                            <div class="tool-subtitle" style="margin-bottom: 5px; margin-top: 10px;">{ *label } </div>
+                                            <div class="tool-subtitle" style="margin-bottom: 5px; margin-top: 10px;">{ algorithm.hmac_name() } </div>
                                             <input
                                                 type="text"
                                                 readonly=true
@@ -1508,7 +1518,7 @@ impl Component for ToolFileHash {
                     if let Some(meta_tag) =
                         doc.query_selector("meta[name=\"description\"]").unwrap()
                     {
-                        meta_tag.set_attribute("content", "Professional File Hash Generator & Verifier with HMAC support. Compute MD5, SHA-1, SHA-256, SHA-512, CRC32 hashes for any file. Features drag & drop upload, hash verification with multiple input formats (hex, colon-separated, Base64, C-array), HMAC authentication, real-time progress tracking, smart Local Storage settings, and comprehensive file metadata display. Supports files up to 2MB with chunked processing for optimal performance. All processing happens locally in your browser for maximum security and privacy.").unwrap();
+                        meta_tag.set_attribute("content", "Professional File Hash Generator & Verifier with HMAC support. Compute MD5, SHA-1, SHA-2, SHA-3, BLAKE2b, BLAKE3, RIPEMD-160 and CRC32 hashes for any file. Features drag & drop upload, hash verification with multiple input formats (hex, colon-separated, Base64, C-array), HMAC authentication, real-time progress tracking, smart Local Storage settings, and comprehensive file metadata display. Supports files of any size, hashed in a background Web Worker. All processing happens locally in your browser for maximum security and privacy.").unwrap();
                     }
                 }
             }
@@ -1517,130 +1527,11 @@ impl Component for ToolFileHash {
 }
 
 impl ToolFileHash {
-    // 다양한 해시 입력 포맷을 표준 hex로 변환하는 함수
-    fn normalize_hash_input(input: &str) -> Result<String, String> {
-        let trimmed = input.trim();
-        
-        if trimmed.is_empty() {
-            return Err("Empty input".to_string());
-        }
-        
-        // 1. Colon-separated format (d8:5e:5d:59:b4:d4...)
-        if trimmed.contains(':') {
-            let hex_string: String = trimmed.split(':')
-                .map(|part| part.trim())
-                .collect::<Vec<_>>()
-                .join("");
-            
-            // 유효한 hex인지 확인
-            if hex_string.chars().all(|c| c.is_ascii_hexdigit()) {
-                return Ok(hex_string.to_lowercase());
-            } else {
-                return Err("Invalid colon-separated hex format".to_string());
-            }
-        }
-        
-        // 2. C-style array format ({0xd8, 0x5e, 0x5d...})
-        if trimmed.starts_with('{') && trimmed.ends_with('}') {
-            let inner = &trimmed[1..trimmed.len()-1];
-            let hex_parts: Result<Vec<String>, String> = inner
-                .split(',')
-                .map(|part| {
-                    let part = part.trim();
-                    if part.starts_with("0x") || part.starts_with("0X") {
-                        let hex_part = &part[2..];
-                        if hex_part.len() == 2 && hex_part.chars().all(|c| c.is_ascii_hexdigit()) {
-                            Ok(hex_part.to_string())
-                        } else {
-                            Err("Invalid hex byte in C-style array".to_string())
-                        }
-                    } else {
-                        Err("C-style array must contain 0x prefixed values".to_string())
-                    }
-                })
-                .collect();
-            
-            match hex_parts {
-                Ok(parts) => return Ok(parts.join("").to_lowercase()),
-                Err(e) => return Err(e),
-            }
-        }
-        
-        // 3. Base64 format (길이와 문자셋으로 추정)
-        if trimmed.chars().all(|c| c.is_ascii_alphanumeric() || c == '+' || c == '/' || c == '=') 
-            && (trimmed.len() % 4 == 0 || trimmed.ends_with('=')) {
-            
-            match BASE64_STANDARD.decode(trimmed) {
-                Ok(bytes) => {
-                    let hex_string = bytes.iter()
-                        .map(|b| format!("{:02x}", b))
-                        .collect::<String>();
-                    return Ok(hex_string);
-                }
-                Err(_) => {
-                    // Base64 디코딩 실패 시 일반 hex로 처리
-                }
-            }
-        }
-        
-        // 4. 일반 hex format (기본값)
-        let clean_hex = trimmed.to_lowercase();
-        if clean_hex.chars().all(|c| c.is_ascii_hexdigit()) {
-            Ok(clean_hex)
-        } else {
-            Err("Invalid hash format. Supported formats: hex, colon-separated (d8:5e:5d), Base64, or C-style array ({0xd8, 0x5e})".to_string())
-        }
-    }
-
-    // HMAC 계산 헬퍼 함수들
-    fn calculate_hmac_md5(key: &[u8], data: &[u8]) -> Result<String, String> {
-        Hmac::<Md5>::new_from_slice(key)
-            .map_err(|_| "Invalid key".to_string())
-            .map(|mut hmac| {
-                hmac.update(data);
-                format!("{:x}", hmac.finalize().into_bytes())
-            })
-    }
-    
-    fn calculate_hmac_sha1(key: &[u8], data: &[u8]) -> Result<String, String> {
-        Hmac::<Sha1>::new_from_slice(key)
-            .map_err(|_| "Invalid key".to_string())
-            .map(|mut hmac| {
-                hmac.update(data);
-                format!("{:x}", hmac.finalize().into_bytes())
-            })
-    }
-    
-    fn calculate_hmac_sha256(key: &[u8], data: &[u8]) -> Result<String, String> {
-        Hmac::<Sha256>::new_from_slice(key)
-            .map_err(|_| "Invalid key".to_string())
-            .map(|mut hmac| {
-                hmac.update(data);
-                format!("{:x}", hmac.finalize().into_bytes())
-            })
-    }
-    
-    fn calculate_hmac_sha512(key: &[u8], data: &[u8]) -> Result<String, String> {
-        Hmac::<Sha512>::new_from_slice(key)
-            .map_err(|_| "Invalid key".to_string())
-            .map(|mut hmac| {
-                hmac.update(data);
-                format!("{:x}", hmac.finalize().into_bytes())
-            })
-    }
-
     fn validate_file(&self, file: &File) -> Result<(), String> {
         let file_size = file.size() as usize;
-        
-        // 파일 크기 검증
-        if file_size > MAX_FILE_SIZE {
-            return Err(format!(
-                "File size ({}) exceeds maximum allowed size ({}). Please select a smaller file.",
-                Self::format_file_size(file_size),
-                Self::format_file_size(MAX_FILE_SIZE)
-            ));
-        }
-        
+
+        // 크기 제한 없음: 워커가 파일을 조각으로 읽으므로 전체를 메모리에 올리지 않음
+
         // 빈 파일 검증
         if file_size == 0 {
             return Err("Cannot process empty files. Please select a file with content.".to_string());
@@ -1657,23 +1548,10 @@ impl ToolFileHash {
     }
     
     fn format_file_size(size: usize) -> String {
-        const UNITS: &[&str] = &["B", "KB", "MB", "GB"];
-        let mut size_f = size as f64;
-        let mut unit_index = 0;
-        
-        while size_f >= 1024.0 && unit_index < UNITS.len() - 1 {
-            size_f /= 1024.0;
-            unit_index += 1;
-        }
-        
-        if unit_index == 0 {
-            format!("{} {}", size, UNITS[unit_index])
-        } else {
-            format!("{:.2} {}", size_f, UNITS[unit_index])
-        }
+        format_file_size(size)
     }
-    
-    fn format_timestamp(timestamp: f64) -> String {
+
+        fn format_timestamp(timestamp: f64) -> String {
         let date = js_sys::Date::new(&JsValue::from(timestamp));
         let year = date.get_full_year();
         let month = format!("{:02}", date.get_month() + 1);
@@ -1684,247 +1562,582 @@ impl ToolFileHash {
     }
 
     fn format_hash_output(&self, hash: &str) -> String {
-        if hash.is_empty() {
-            return String::new();
+        format_hash_output(hash, &self.output_format)
+    }
+
+    fn perform_hash_comparison(&mut self, ctx: &Context<Self>) {
+        let job = self.hmac_job.take();
+        self.cancel_job(job);
+        self.hash_comparison = match self.verification_type {
+            VerificationType::Hash => {
+                // 일반 해시 검증
+                compare_hash(&self.expected_hash, &self.results)
+            }
+            VerificationType::Hmac => {
+                // HMAC 검증
+                if self.expected_hash.trim().is_empty() {
+                    None
+                } else if let Err(error) = normalize_hash_input(&self.expected_hash) {
+                    Some(HashComparison {
+                        algorithm: "Format Error".to_string(),
+                        matches: false,
+                        expected: self.expected_hash.trim().to_string(),
+                        actual: error,
+                    })
+                } else if self.verification_hmac_key.trim().is_empty() {
+                    Some(HashComparison {
+                        algorithm: "HMAC".to_string(),
+                        matches: false,
+                        expected: normalize_hash_input(&self.expected_hash).unwrap_or_default(),
+                        actual: "Secret key required for HMAC verification".to_string(),
+                    })
+                } else if let Some(file) = self.file.clone() {
+                    // 파일과 키가 있으면 워커가 파일을 다시 읽어 HMAC을 계산, 끝나면 비교
+                    let task = HashTask { file, algorithms: algorithm_keys(&self.selected_algorithms()) };
+                    let key = Some(self.verification_hmac_key.clone().into_bytes());
+                    self.hmac_job = Some(self.start_job(ctx, vec![task], key, true));
+                    None
+                } else {
+                    Some(HashComparison {
+                        algorithm: "HMAC".to_string(),
+                        matches: false,
+                        expected: normalize_hash_input(&self.expected_hash).unwrap_or_default(),
+                        actual: "File not available for HMAC calculation".to_string(),
+                    })
+                }
+            }
+        };
+    }
+
+    /// 파일이 하나이고 매니페스트가 아니면 일반 해시 계산, 그 밖에는 매니페스트 검증으로 보냅니다.
+    fn route_files(list: &web_sys::FileList) -> Option<Msg> {
+        let files: Vec<File> = (0..list.length()).filter_map(|index| list.get(index)).collect();
+        match files.as_slice() {
+            [] => None,
+            [file] if !manifest::is_manifest_name(&file.name()) => Some(Msg::Drop(file.clone())),
+            _ => Some(Msg::ManifestFilesSelected(files)),
         }
-        
-        match self.output_format {
-            OutputFormat::Lowercase => hash.to_lowercase(),
-            OutputFormat::Uppercase => hash.to_uppercase(),
-            OutputFormat::ColonSeparated => {
-                let lower_hash = hash.to_lowercase();
-                let mut result = String::new();
-                for (i, c) in lower_hash.chars().enumerate() {
-                    if i > 0 && i % 2 == 0 {
-                        result.push(':');
+    }
+
+    /// 워커에 해시 작업을 보내고 작업 번호를 돌려줍니다. 워커는 처음 쓸 때 생성합니다.
+    fn start_job(
+        &mut self,
+        ctx: &Context<Self>,
+        files: Vec<HashTask>,
+        hmac_key: Option<Vec<u8>>,
+        hmac_only: bool,
+    ) -> u32 {
+        self.next_job = self.next_job.wrapping_add(1);
+        let job = self.next_job;
+        let link = ctx.link().clone();
+        let worker = self.worker.get_or_insert_with(|| {
+            HashWorker::spawner()
+                .encoding::<JsCodec>()
+                .callback(move |response| link.send_message(Msg::Worker(response)))
+                .spawn_with_loader(WORKER_LOADER_PATH)
+        });
+        worker.send(HashRequest::Hash { job, files, hmac_key, hmac_only });
+        job
+    }
+
+    fn cancel_job(&self, job: Option<u32>) {
+        if let (Some(job), Some(worker)) = (job, &self.worker) {
+            worker.send(HashRequest::Cancel(job));
+        }
+    }
+
+    /// 워커 응답을 작업 번호로 구분해 처리합니다. 취소된 작업의 응답은 무시합니다.
+    fn worker_response(&mut self, ctx: &Context<Self>, response: HashResponse) -> bool {
+        match response {
+            HashResponse::Progress { job, index, progress } => {
+                if self.file_job == Some(job) {
+                    self.step = true;
+                    self.progress = progress;
+                    true
+                } else {
+                    self.manifest_progress(job, index, progress)
+                }
+            }
+            HashResponse::Hashed { job, index, hashes, hmacs } => {
+                let results = results_from_worker(hashes, hmacs);
+                if self.file_job == Some(job) {
+                    self.results = results;
+                } else if self.hmac_job == Some(job) {
+                    self.hash_comparison = compare_hmac_results(&self.expected_hash, &results);
+                } else if let Some(manifest_job) = self.manifest_job.as_mut().filter(|manifest_job| manifest_job.id == job) {
+                    let (file_index, name, size) = manifest_job.files[index].clone();
+                    manifest_job.hashed.push(HashedFile { name, size: size as u64, results });
+                    if let Some(progress) = self.manifest_file_progress.get_mut(file_index) {
+                        *progress = 1.0;
                     }
-                    result.push(c);
+                } else {
+                    return false;
                 }
-                result
+                true
             }
-            OutputFormat::Base64 => {
-                // hex 문자열을 바이트로 변환한 후 base64 인코딩
-                if let Ok(bytes) = hex::decode(hash) {
-                    BASE64_STANDARD.encode(&bytes)
+            HashResponse::Failed { job, error, .. } => {
+                if self.file_job == Some(job) || self.hmac_job == Some(job) {
+                    self.error_message = Some(format!("Failed to read the file: {}", error));
+                    true
                 } else {
-                    hash.to_string()
+                    // 매니페스트에서 읽지 못한 파일은 MISSING으로 보고됨
+                    false
                 }
             }
-            OutputFormat::CStyleArray => {
-                // hex 문자열을 바이트로 변환한 후 C 스타일 배열로 포맷팅
-                if let Ok(bytes) = hex::decode(hash) {
-                    let mut result = String::from("{");
-                    for (i, byte) in bytes.iter().enumerate() {
-                        if i > 0 {
-                            result.push_str(", ");
-                        }
-                        result.push_str(&format!("0x{:02x}", byte));
+            HashResponse::Finished { job } => {
+                if self.file_job == Some(job) {
+                    self.file_job = None;
+                    self.is_computing = false;
+                    self.progress = 1.0;
+
+                    // 예상 해시가 있다면 비교 수행
+                    if !self.expected_hash.trim().is_empty() {
+                        self.perform_hash_comparison(ctx);
+                    }
+                    true
+                } else if self.hmac_job == Some(job) {
+                    self.hmac_job = None;
+                    false
+                } else if self.manifest_job.as_ref().is_some_and(|manifest_job| manifest_job.id == job) {
+                    if let Some(manifest_job) = self.manifest_job.take() {
+                        self.finish_manifest_job(manifest_job);
                     }
-                    result.push('}');
-                    result
+                    true
                 } else {
-                    hash.to_string()
+                    false
                 }
             }
         }
     }
 
-    fn perform_hash_comparison(&mut self) {
-        if self.expected_hash.trim().is_empty() {
-            self.hash_comparison = None;
-            return;
+    fn manifest_progress(&mut self, job: u32, index: usize, progress: f64) -> bool {
+        let Some(manifest_job) = self.manifest_job.as_ref().filter(|manifest_job| manifest_job.id == job) else {
+            return false;
+        };
+        let file_index = manifest_job.files[index].0;
+        if let Some(file_progress) = self.manifest_file_progress.get_mut(file_index) {
+            *file_progress = progress;
         }
-        
-        // 입력된 해시를 표준 hex 포맷으로 정규화
-        let expected_clean = match Self::normalize_hash_input(&self.expected_hash) {
-            Ok(normalized) => normalized,
-            Err(error) => {
-                self.hash_comparison = Some(HashComparison {
-                    algorithm: "Format Error".to_string(),
-                    matches: false,
-                    expected: self.expected_hash.trim().to_string(),
-                    actual: error,
-                });
-                return;
+
+        // 전체 진행률은 파일 크기로 가중 평균
+        let total: f64 = manifest_job.files.iter().map(|(_, _, size)| size).sum();
+        let done: f64 = manifest_job
+            .files
+            .iter()
+            .map(|(file_index, _, size)| self.manifest_file_progress.get(*file_index).copied().unwrap_or(0.0) * size)
+            .sum();
+        self.manifest_overall = Some(if total > 0.0 { done / total } else { 1.0 });
+        true
+    }
+
+    fn finish_manifest_job(&mut self, manifest_job: ManifestJob) {
+        self.manifest_overall = None;
+        match manifest_job.task {
+            ManifestTask::Verify(parsed) => {
+                // 해시하지 않은(읽지 못한) 파일도 빈 결과로 넣어 같은 이름의 파일을 구분 (MISSING/AMBIGUOUS로 보고됨)
+                let computed: Vec<(String, HashResults)> = self
+                    .manifest_files
+                    .iter()
+                    .map(|file| {
+                        let name = file_path(file);
+                        let results = manifest_job
+                            .hashed
+                            .iter()
+                            .find(|hashed| hashed.name == name)
+                            .map(|hashed| hashed.results.clone())
+                            .unwrap_or_default();
+                        (name, results)
+                    })
+                    .collect();
+                self.manifest_checks = manifest::check_all(&parsed, &computed);
+            }
+            ManifestTask::Generate(style, algorithm) => {
+                match manifest::generate(&manifest_job.hashed, style, algorithm) {
+                    Ok(text) => self.generated_manifest = Some((style.file_name(algorithm), text)),
+                    Err(error) => self.error_message = Some(error),
+                }
             }
+        }
+    }
+
+    /// `jobs`의 (파일 번호, 알고리즘) 순서대로 매니페스트 파일들을 워커에서 해시하고,
+    /// 끝나면 `task`를 수행합니다. 새 작업을 시작하면 이전 작업은 중단됩니다.
+    fn hash_manifest_files(&mut self, ctx: &Context<Self>, jobs: Vec<(usize, Vec<HashAlgorithm>)>, task: ManifestTask) {
+        let previous = self.manifest_job.take().map(|job| job.id);
+        self.cancel_job(previous);
+        self.manifest_file_progress = vec![0.0; self.manifest_files.len()];
+        self.manifest_overall = Some(0.0);
+
+        let mut files = Vec::new();
+        let mut tasks = Vec::new();
+        for (index, algorithms) in jobs {
+            let file = self.manifest_files[index].clone();
+            files.push((index, file_path(&file), file.size()));
+            tasks.push(HashTask { file, algorithms: algorithm_keys(&algorithms) });
+        }
+        let id = self.start_job(ctx, tasks, None, false);
+        self.manifest_job = Some(ManifestJob { id, files, hashed: Vec::new(), task });
+    }
+
+    fn manifest_files_selected(e: Event) -> Msg {
+        let input: HtmlInputElement = e.target_unchecked_into();
+        let files: Vec<File> = input
+            .files()
+            .map(|list| (0..list.length()).filter_map(|index| list.get(index)).collect())
+            .unwrap_or_default();
+        input.set_value("");
+        Msg::ManifestFilesSelected(files)
+    }
+
+    fn manifest_style_label(style: ManifestStyle) -> &'static str {
+        match style {
+            ManifestStyle::Gnu => "GNU (sha256sum)",
+            ManifestStyle::Bsd => "BSD (SHA256 (file) = ...)",
+            ManifestStyle::Sfv => "SFV (CRC32)",
+            ManifestStyle::Json => "JSON (selected algorithms)",
+        }
+    }
+
+    /// HMAC 키가 설정되어 있으면 선택한 인코딩으로 해석한 바이트를 돌려줍니다.
+    fn hmac_key_bytes(&self) -> Result<Option<Vec<u8>>, String> {
+        if !self.show_hmac_section || self.hmac_key.trim().is_empty() {
+            return Ok(None);
+        }
+        self.hmac_key_encoding.decode(&self.hmac_key).map(Some)
+    }
+
+    /// 텍스트 입력은 작으므로 입력이 바뀔 때마다 메인 스레드에서 바로 다시 계산합니다.
+    fn refresh_text_results(&mut self) {
+        self.text_results = if self.text_input.is_empty() {
+            Ok(HashResults::default())
+        } else {
+            self.text_encoding.decode(&self.text_input).and_then(|data| {
+                let key = self.hmac_key_bytes()?;
+                Ok(compute_hashes(&data, &self.selected_algorithms(), key.as_deref()))
+            })
         };
-        
-        match self.verification_type {
-            VerificationType::Hash => {
-                // 일반 해시 검증
-                let hashes = vec![
-                    ("MD5", &self.hash_md5),
-                    ("SHA-1", &self.hash_sha1),
-                    ("SHA-256", &self.hash_sha256),
-                    ("SHA-512", &self.hash_sha512),
-                    ("CRC32", &self.crc32),
-                ];
-                
-                for (algorithm, actual_hash) in hashes {
-                    if !actual_hash.is_empty() && actual_hash.to_lowercase() == expected_clean {
-                        self.hash_comparison = Some(HashComparison {
-                            algorithm: algorithm.to_string(),
-                            matches: true,
-                            expected: expected_clean.clone(),
-                            actual: actual_hash.to_lowercase(),
-                        });
-                        return;
+    }
+
+    fn view_encoding_select(
+        ctx: &Context<Self>,
+        selected: InputEncoding,
+        on_change: fn(String) -> Msg,
+    ) -> Html {
+        html! {
+            <select
+                style="padding: 2px; font-size: 12px;"
+                onchange={ctx.link().callback(move |e: Event| {
+                    let select: HtmlInputElement = e.target_unchecked_into();
+                    on_change(select.value())
+                })}>
+                { for InputEncoding::all().into_iter().map(|encoding| html! {
+                    <option value={encoding.key()} selected={selected == encoding}>{ encoding.name() }</option>
+                }) }
+            </select>
+        }
+    }
+
+    fn view_text(&self, ctx: &Context<Self>) -> Html {
+        let link = ctx.link();
+        let copy_row = |name: String, value: &String| html! {
+            <div>
+                <div class="tool-subtitle" style="margin-bottom: 5px; margin-top: 10px;">{ name }</div>
+                <input
+                    type="text"
+                    readonly=true
+                    style="cursor: pointer;"
+                    value={self.format_hash_output(value)}
+                    onclick={link.callback(|e: MouseEvent| {
+                        let input: HtmlInputElement = e.target_unchecked_into();
+                        Msg::CopyToClipboard(input.value())
+                    })}
+                />
+            </div>
+        };
+
+        html! {
+            <div style="margin-bottom: 10px;">
+                <div style="display: flex; align-items: center; gap: 10px; margin-bottom: 5px;">
+                    <input
+                        type="checkbox"
+                        id="text-enable"
+                        checked={self.show_text_section}
+                        onclick={link.callback(|_| Msg::ToggleTextSection)}
+                    />
+                    <label for="text-enable" style="cursor: pointer; margin-bottom: 0px; font-weight: bold;">
+                        {"Hash Text Input"}
+                    </label>
+                    if self.show_text_section {
+                        { Self::view_encoding_select(ctx, self.text_encoding, Msg::TextEncodingChanged) }
                     }
-                }
-                
-                // 일치하는 해시가 없는 경우, 길이를 기준으로 가장 가능성 높은 알고리즘 추정
-                let expected_len = expected_clean.len();
-                let (algorithm, actual_hash) = match expected_len {
-                    8 => ("CRC32", &self.crc32),
-                    32 => ("MD5", &self.hash_md5),
-                    40 => ("SHA-1", &self.hash_sha1), 
-                    64 => ("SHA-256", &self.hash_sha256),
-                    128 => ("SHA-512", &self.hash_sha512),
-                    _ => {
-                        // 길이가 일치하지 않는 경우, 비어있지 않은 첫 번째 해시 사용
-                        if !self.hash_sha256.is_empty() {
-                            ("SHA-256", &self.hash_sha256)
-                        } else if !self.crc32.is_empty() {
-                            ("CRC32", &self.crc32)
-                        } else if !self.hash_md5.is_empty() {
-                            ("MD5", &self.hash_md5)
-                        } else if !self.hash_sha1.is_empty() {
-                            ("SHA-1", &self.hash_sha1)
-                        } else {
-                            ("SHA-512", &self.hash_sha512)
-                        }
+                </div>
+
+                if self.show_text_section {
+                    <textarea
+                        style="width: 100%; min-height: 70px; font-family: monospace; font-size: 12px;"
+                        placeholder={match self.text_encoding {
+                            InputEncoding::Utf8 => "Type or paste text to hash...",
+                            InputEncoding::Hex => "Paste hex bytes, e.g. 48 65 6c 6c 6f",
+                            InputEncoding::Base64 => "Paste Base64, e.g. SGVsbG8=",
+                        }}
+                        value={self.text_input.clone()}
+                        oninput={link.callback(|e: InputEvent| {
+                            let input: HtmlInputElement = e.target_unchecked_into();
+                            Msg::TextInputChanged(input.value())
+                        })}
+                    />
+                    if let Err(error) = &self.text_results {
+                        <div style="font-size: 12px; color: var(--color-error);">{ error }</div>
+                    } else if let Ok(results) = &self.text_results {
+                        { for results.hashes.iter().map(|(algorithm, value)| copy_row(algorithm.name().to_string(), value)) }
+                        { for results.hmacs.iter().map(|(algorithm, value)| copy_row(algorithm.hmac_name(), value)) }
                     }
-                };
-                
-                if !actual_hash.is_empty() {
-                    self.hash_comparison = Some(HashComparison {
-                        algorithm: algorithm.to_string(),
-                        matches: false,
-                        expected: expected_clean,
-                        actual: actual_hash.to_lowercase(),
-                    });
                 }
-            }
-            VerificationType::Hmac => {
-                // HMAC 검증
-                if self.verification_hmac_key.trim().is_empty() {
-                    self.hash_comparison = Some(HashComparison {
-                        algorithm: "HMAC".to_string(),
-                        matches: false,
-                        expected: expected_clean,
-                        actual: "Secret key required for HMAC verification".to_string(),
-                    });
-                    return;
-                }
-                
-                // 파일 데이터와 키가 있으면 HMAC 계산해서 비교
-                if let Some(file_data) = &self.file_data {
-                    let hmac_key_bytes = self.verification_hmac_key.as_bytes();
-                    
-                    // 각 HMAC 알고리즘과 비교
-                    if let Ok(calculated_hmac) = Self::calculate_hmac_md5(hmac_key_bytes, file_data) {
-                        if calculated_hmac.to_lowercase() == expected_clean {
-                            self.hash_comparison = Some(HashComparison {
-                                algorithm: "HMAC-MD5".to_string(),
-                                matches: true,
-                                expected: expected_clean.clone(),
-                                actual: calculated_hmac.to_lowercase(),
-                            });
-                            return;
+            </div>
+        }
+    }
+
+    fn view_manifest(&self, ctx: &Context<Self>) -> Html {
+        let link = ctx.link();
+        let summary = manifest::summarize(&self.manifest_checks);
+        let cell_style = "padding: 3px; border: 1px solid #ddd;";
+
+        html! {
+            <div style="margin-bottom: 10px;">
+                <div style="display: flex; align-items: center; gap: 10px; margin-bottom: 5px;">
+                    <input
+                        type="checkbox"
+                        id="manifest-enable"
+                        checked={self.show_manifest_section}
+                        onclick={link.callback(|_| Msg::ToggleManifestSection)}
+                    />
+                    <label for="manifest-enable" style="cursor: pointer; margin-bottom: 0px; font-weight: bold;">
+                        {"Checksum Manifest (verify or generate)"}
+                    </label>
+                </div>
+
+                if self.show_manifest_section {
+                    <textarea
+                        style="width: 100%; min-height: 90px; font-family: monospace; font-size: 12px;"
+                        placeholder="Paste SHA256SUMS / md5sum output, BSD lines like 'SHA256 (file) = ...', or an SFV file..."
+                        value={self.manifest_text.clone()}
+                        oninput={link.callback(|e: InputEvent| {
+                            let input: HtmlInputElement = e.target_unchecked_into();
+                            Msg::ManifestTextChanged(input.value())
+                        })}
+                    />
+                    <div style="font-size: 12px; color: var(--color-subfont); margin-top: 2px;">
+                        if let Some(name) = &self.manifest_name {
+                            { format!("Loaded from {}. ", name) }
                         }
-                    }
-                    
-                    if let Ok(calculated_hmac) = Self::calculate_hmac_sha1(hmac_key_bytes, file_data) {
-                        if calculated_hmac.to_lowercase() == expected_clean {
-                            self.hash_comparison = Some(HashComparison {
-                                algorithm: "HMAC-SHA1".to_string(),
-                                matches: true,
-                                expected: expected_clean.clone(),
-                                actual: calculated_hmac.to_lowercase(),
-                            });
-                            return;
+                        {"Files are matched by path (select a folder to keep paths), or by name when only one file has that name."}
+                    </div>
+
+                    <div style="display: flex; flex-wrap: wrap; align-items: center; gap: 8px; margin-top: 8px;">
+                        <input
+                            type="file"
+                            id="manifest-upload"
+                            multiple=true
+                            style="display: none;"
+                            onchange={link.callback(Self::manifest_files_selected)}
+                        />
+                        <label
+                            for="manifest-upload"
+                            style="display: inline-block; background: var(--color-primary); color: white; padding: 6px 12px; border-radius: 4px; cursor: pointer;">
+                            { "Add Files / Manifest" }
+                        </label>
+                        // 디렉터리 전체 선택 (파일마다 상대 경로가 매니페스트 이름이 됨)
+                        <input
+                            type="file"
+                            id="manifest-directory"
+                            webkitdirectory=true
+                            style="display: none;"
+                            onchange={link.callback(Self::manifest_files_selected)}
+                        />
+                        <label
+                            for="manifest-directory"
+                            style="display: inline-block; background: var(--color-primary); color: white; padding: 6px 12px; border-radius: 4px; cursor: pointer;">
+                            { "Add Directory" }
+                        </label>
+                        <button
+                            class="tool-btn"
+                            disabled={self.manifest_text.trim().is_empty() || self.manifest_overall.is_some()}
+                            onclick={link.callback(|_| Msg::VerifyManifest)}>
+                            { "Verify" }
+                        </button>
+                        if !self.manifest_files.is_empty() {
+                            <button class="tool-btn" onclick={link.callback(|_| Msg::ClearManifestFiles)}>
+                                { "Clear Files" }
+                            </button>
                         }
+                    </div>
+
+                    if !self.manifest_files.is_empty() {
+                        <div style="max-height: 160px; overflow-y: auto; font-size: 12px; margin-top: 5px; border: 1px solid #ddd; border-radius: 5px; padding: 3px 6px;">
+                            { for self.manifest_files.iter().enumerate().map(|(index, file)| {
+                                let progress = self.manifest_file_progress.get(index).copied().unwrap_or(0.0);
+                                html! {
+                                    <div style="display: flex; justify-content: space-between; gap: 8px;">
+                                        <span style="word-break: break-all;">{ file_path(file) }</span>
+                                        <span style="white-space: nowrap; color: var(--color-subfont);">
+                                            { Self::format_file_size(file.size() as usize) }
+                                            if self.manifest_overall.is_some() || progress > 0.0 {
+                                                { format!(" • {:.0}%", progress * 100.0) }
+                                            }
+                                        </span>
+                                    </div>
+                                }
+                            }) }
+                        </div>
+
+                        // 매니페스트 생성
+                        <div style="display: flex; flex-wrap: wrap; align-items: center; gap: 8px; margin-top: 8px;">
+                            <select
+                                style="padding: 2px; font-size: 12px;"
+                                onchange={link.callback(|e: Event| {
+                                    let select: HtmlInputElement = e.target_unchecked_into();
+                                    Msg::ManifestStyleChanged(select.value())
+                                })}>
+                                { for ManifestStyle::all().into_iter().map(|style| html! {
+                                    <option value={style.key()} selected={self.manifest_style == style}>
+                                        { Self::manifest_style_label(style) }
+                                    </option>
+                                }) }
+                            </select>
+                            if matches!(self.manifest_style, ManifestStyle::Gnu | ManifestStyle::Bsd) {
+                                <select
+                                    style="padding: 2px; font-size: 12px;"
+                                    onchange={link.callback(|e: Event| {
+                                        let select: HtmlInputElement = e.target_unchecked_into();
+                                        Msg::ManifestAlgorithmChanged(select.value())
+                                    })}>
+                                    { for HashAlgorithm::all().into_iter().map(|algorithm| html! {
+                                        <option value={algorithm.key()} selected={self.manifest_algorithm == algorithm}>
+                                            { algorithm.name() }
+                                        </option>
+                                    }) }
+                                </select>
+                            }
+                            <button
+                                class="tool-btn"
+                                disabled={self.manifest_overall.is_some()}
+                                onclick={link.callback(|_| Msg::GenerateManifest)}>
+                                { "Generate Manifest" }
+                            </button>
+                        </div>
                     }
-                    
-                    if let Ok(calculated_hmac) = Self::calculate_hmac_sha256(hmac_key_bytes, file_data) {
-                        if calculated_hmac.to_lowercase() == expected_clean {
-                            self.hash_comparison = Some(HashComparison {
-                                algorithm: "HMAC-SHA256".to_string(),
-                                matches: true,
-                                expected: expected_clean.clone(),
-                                actual: calculated_hmac.to_lowercase(),
-                            });
-                            return;
-                        }
+
+                    if let Some(overall) = self.manifest_overall {
+                        <div style="margin-top: 8px; font-size: 12px;">
+                            <div style="width: 100%; background-color: var(--color-third); border-radius: 4px; height: 12px; overflow: hidden;">
+                                <div style={format!("width: {:.1}%; background-color: var(--color-fourth); height: 12px;", overall * 100.0)}></div>
+                            </div>
+                            <div style="text-align: center; margin-top: 3px;">
+                                { format!("Total: {:.1}%", overall * 100.0) }
+                                <button
+                                    class="tool-btn"
+                                    style="margin-left: 8px;"
+                                    onclick={link.callback(|_| Msg::CancelManifest)}>
+                                    { "Cancel" }
+                                </button>
+                            </div>
+                        </div>
                     }
-                    
-                    if let Ok(calculated_hmac) = Self::calculate_hmac_sha512(hmac_key_bytes, file_data) {
-                        if calculated_hmac.to_lowercase() == expected_clean {
-                            self.hash_comparison = Some(HashComparison {
-                                algorithm: "HMAC-SHA512".to_string(),
-                                matches: true,
-                                expected: expected_clean.clone(),
-                                actual: calculated_hmac.to_lowercase(),
-                            });
-                            return;
-                        }
+
+                    if let Some((name, text)) = &self.generated_manifest {
+                        <div style="margin-top: 8px;">
+                            <div style="display: flex; justify-content: space-between; align-items: center; margin-bottom: 3px;">
+                                <span class="tool-subtitle" style="font-size: 14px;">{ name }</span>
+                                <span style="display: flex; gap: 6px;">
+                                    <button class="tool-btn" onclick={
+                                        let text = text.clone();
+                                        link.callback(move |_| Msg::CopyToClipboard(text.clone()))
+                                    }>
+                                        { "Copy" }
+                                    </button>
+                                    <button class="tool-btn" onclick={link.callback(|_| Msg::DownloadManifest)}>
+                                        <i class="fa-solid fa-download" style="margin-right: 5px;"></i>
+                                        { "Download" }
+                                    </button>
+                                </span>
+                            </div>
+                            <textarea
+                                readonly=true
+                                style="width: 100%; min-height: 120px; font-family: monospace; font-size: 12px;"
+                                value={text.clone()}
+                            />
+                        </div>
                     }
-                    
-                    // 일치하는 HMAC이 없는 경우, 길이를 기준으로 가장 가능성 높은 알고리즘으로 비교
-                    let expected_len = expected_clean.len();
-                    let result = match expected_len {
-                        32 => ("HMAC-MD5", Self::calculate_hmac_md5(hmac_key_bytes, file_data)),
-                        40 => ("HMAC-SHA1", Self::calculate_hmac_sha1(hmac_key_bytes, file_data)),
-                        64 => ("HMAC-SHA256", Self::calculate_hmac_sha256(hmac_key_bytes, file_data)),
-                        128 => ("HMAC-SHA512", Self::calculate_hmac_sha512(hmac_key_bytes, file_data)),
-                        _ => ("HMAC-SHA256", Self::calculate_hmac_sha256(hmac_key_bytes, file_data)),
-                    };
-                    
-                    match result {
-                        (algorithm, Ok(calculated_hmac)) => {
-                            self.hash_comparison = Some(HashComparison {
-                                algorithm: algorithm.to_string(),
-                                matches: false,
-                                expected: expected_clean,
-                                actual: calculated_hmac.to_lowercase(),
-                            });
-                        }
-                        (algorithm, Err(err)) => {
-                            self.hash_comparison = Some(HashComparison {
-                                algorithm: algorithm.to_string(),
-                                matches: false,
-                                expected: expected_clean,
-                                actual: format!("Error: {}", err),
-                            });
-                        }
+
+                    if !self.manifest_checks.is_empty() {
+                        <div style={format!("margin-top: 8px; padding: 10px; border-radius: 5px; {}",
+                            if summary.all_ok() {
+                                "background-color: #d4edda; color: #155724; border: 1px solid #c3e6cb;"
+                            } else {
+                                "background-color: #f8d7da; color: #721c24; border: 1px solid #f5c6cb;"
+                            })}>
+                            <div style="font-weight: bold;">
+                                { format!("{} OK, {} FAILED, {} MISSING", summary.ok, summary.failed, summary.missing) }
+                                if summary.ambiguous > 0 {
+                                    { format!(", {} AMBIGUOUS", summary.ambiguous) }
+                                }
+                            </div>
+                            if self.manifest_malformed > 0 {
+                                <div style="font-size: 12px;">
+                                    { format!("{} line(s) were not recognized and skipped.", self.manifest_malformed) }
+                                </div>
+                            }
+                        </div>
+                        <div style="max-height: 300px; overflow-y: auto; font-family: monospace; font-size: 12px; border: 1px solid #ddd; border-radius: 5px; margin-top: 5px;">
+                            <table style="width: 100%; border-collapse: collapse;">
+                                <thead>
+                                    <tr style="background-color: var(--color-fourth); color: white; position: sticky; top: 0;">
+                                        <th style={cell_style}>{ "Line" }</th>
+                                        <th style={cell_style}>{ "File" }</th>
+                                        <th style={cell_style}>{ "Algorithm" }</th>
+                                        <th style={cell_style}>{ "Status" }</th>
+                                    </tr>
+                                </thead>
+                                <tbody>
+                                    { for self.manifest_checks.iter().map(|check| {
+                                        let color = match check.status {
+                                            CheckStatus::Ok => "#155724",
+                                            CheckStatus::Failed => "#721c24",
+                                            CheckStatus::Missing => "var(--color-subfont)",
+                                            CheckStatus::Ambiguous => "#856404",
+                                        };
+                                        let title = match &check.actual {
+                                            Some(actual) => format!("Expected: {}\nActual: {}", check.entry.expected, actual),
+                                            None => format!("Expected: {}", check.entry.expected),
+                                        };
+                                        html! {
+                                            <tr title={title}>
+                                                <td style={format!("{} text-align: center;", cell_style)}>{ check.entry.line }</td>
+                                                <td style={format!("{} word-break: break-all;", cell_style)}>{ &check.entry.file_name }</td>
+                                                <td style={cell_style}>{ format!("{} ({})", check.entry.algorithm.name(), check.entry.format.name()) }</td>
+                                                <td style={format!("{} color: {}; font-weight: bold;", cell_style, color)}>{ check.status.label() }</td>
+                                            </tr>
+                                        }
+                                    }) }
+                                </tbody>
+                            </table>
+                        </div>
                     }
-                } else {
-                    self.hash_comparison = Some(HashComparison {
-                        algorithm: "HMAC".to_string(),
-                        matches: false,
-                        expected: expected_clean,
-                        actual: "File data not available for HMAC calculation".to_string(),
-                    });
                 }
-            }
+            </div>
         }
     }
 
+    fn selected_algorithms(&self) -> Vec<HashAlgorithm> {
+        self.selected
+            .iter()
+            .filter(|(_, &checked)| checked)
+            .map(|(&algorithm, _)| algorithm)
+            .collect()
+    }
+
     fn get_file_icon(mime_type: &str) -> &'static str {
-        match mime_type {
-            t if t.starts_with("image/") => "🖼️",
-            t if t.starts_with("video/") => "🎥",
-            t if t.starts_with("audio/") => "🎵",
-            t if t.starts_with("text/") => "📄",
-            "application/pdf" => "📕",
-            "application/zip" | "application/x-zip-compressed" => "📦",
-            "application/json" => "📋",
-            "application/xml" => "📰",
-            t if t.contains("word") => "📝",
-            t if t.contains("excel") | t.contains("sheet") => "📊",
-            t if t.contains("powerpoint") | t.contains("presentation") => "📽️",
-            _ => "🗂️",
-        }
+        get_file_icon(mime_type)
     }
 
     // Local Storage 관련 메서드들
@@ -1937,26 +2150,18 @@ impl ToolFileHash {
         
         // Hash algorithms 불러오기
         let mut selected = IndexMap::new();
-        let default_items = vec!["md5", "sha1", "sha256", "sha512", "crc32"];
-        
-        if let Some(ref storage) = storage {
-            if let Ok(Some(saved_algorithms)) = storage.get_item(STORAGE_KEY_HASH_ALGORITHMS) {
-                // 저장된 설정을 파싱
-                for item in default_items.iter() {
-                    let is_selected = saved_algorithms.contains(&format!("{}:true", item));
-                    selected.insert(item.to_string(), is_selected);
-                }
-            } else {
+        let saved_algorithms = storage
+            .as_ref()
+            .and_then(|s| s.get_item(STORAGE_KEY_HASH_ALGORITHMS).ok().flatten());
+
+        for algorithm in HashAlgorithm::all() {
+            let is_selected = match &saved_algorithms {
+                // 저장된 설정을 파싱 ("md5:true,sha1:false,...")
+                Some(saved) => saved.split(',').any(|item| item == format!("{}:true", algorithm.key())),
                 // 저장된 설정이 없으면 기본값 사용
-                for item in default_items {
-                    selected.insert(item.to_string(), true);
-                }
-            }
-        } else {
-            // Local Storage가 없으면 기본값 사용
-            for item in default_items {
-                selected.insert(item.to_string(), true);
-            }
+                None => algorithm.is_default(),
+            };
+            selected.insert(algorithm, is_selected);
         }
 
         // Output format 불러오기
@@ -1982,16 +2187,9 @@ impl ToolFileHash {
 
         Self {
             file_info: None,
-            file_data: None,
-            hash_md5: "".to_string(),
-            hash_sha1: "".to_string(),
-            hash_sha256: "".to_string(),
-            hash_sha512: "".to_string(),
-            hmac_md5: "".to_string(),
-            hmac_sha1: "".to_string(),
-            hmac_sha256: "".to_string(),
-            hmac_sha512: "".to_string(),
-            crc32: "".to_string(),
+            file_type: None,
+            file: None,
+            results: HashResults::default(),
             is_computing: false,
             step: false,
             progress: 0.0,
@@ -2004,9 +2202,30 @@ impl ToolFileHash {
             output_format,
             show_file_metadata: false,
             hmac_key: String::new(),
+            hmac_key_encoding: InputEncoding::Utf8,
             show_hmac_section,
             verification_type: VerificationType::Hash,
             verification_hmac_key: String::new(),
+            show_text_section: false,
+            text_input: String::new(),
+            text_encoding: InputEncoding::Utf8,
+            text_results: Ok(HashResults::default()),
+            show_manifest_section: false,
+            manifest_text: String::new(),
+            manifest_name: None,
+            manifest_files: Vec::new(),
+            manifest_checks: Vec::new(),
+            manifest_malformed: 0,
+            manifest_file_progress: Vec::new(),
+            manifest_overall: None,
+            manifest_style: ManifestStyle::Gnu,
+            manifest_algorithm: HashAlgorithm::Sha256,
+            generated_manifest: None,
+            worker: None,
+            next_job: 0,
+            file_job: None,
+            hmac_job: None,
+            manifest_job: None,
         }
     }
 
@@ -2015,7 +2234,7 @@ impl ToolFileHash {
             // Hash algorithms 저장
             let algorithms_string: String = self.selected
                 .iter()
-                .map(|(key, &value)| format!("{}:{}", key, value))
+                .map(|(algorithm, &value)| format!("{}:{}", algorithm.key(), value))
                 .collect::<Vec<_>>()
                 .join(",");
             let _ = storage.set_item(STORAGE_KEY_HASH_ALGORITHMS, &algorithms_string);
@@ -2036,7 +2255,31 @@ impl ToolFileHash {
     }
 }
 
-async fn read_slice_as_array_buffer(slice: &Blob) -> Result<Vec<u8>, JsValue> {
+/// 디렉터리에서 고른 파일은 상대 경로(`webkitRelativePath`), 그 밖에는 파일 이름
+fn file_path(file: &File) -> String {
+    js_sys::Reflect::get(file, &"webkitRelativePath".into())
+        .ok()
+        .and_then(|path| path.as_string())
+        .filter(|path| !path.is_empty())
+        .unwrap_or_else(|| file.name())
+}
+
+fn algorithm_keys(algorithms: &[HashAlgorithm]) -> Vec<String> {
+    algorithms.iter().map(|algorithm| algorithm.key().to_string()).collect()
+}
+
+/// 워커가 알고리즘 키로 보낸 결과를 `HashResults`로 되돌립니다.
+fn results_from_worker(hashes: Vec<(String, String)>, hmacs: Vec<(String, String)>) -> HashResults {
+    let parse = |entries: Vec<(String, String)>| {
+        entries
+            .into_iter()
+            .filter_map(|(key, hash)| HashAlgorithm::from_key(&key).map(|algorithm| (algorithm, hash)))
+            .collect()
+    };
+    HashResults { hashes: parse(hashes), hmacs: parse(hmacs) }
+}
+
+pub(crate) async fn read_slice_as_array_buffer(slice: &Blob) -> Result<Vec<u8>, JsValue> {
     let promise = js_sys::Promise::new(&mut |resolve, reject| {
         let reader = WebFileReader::new().unwrap();
         let onload = Closure::once(Box::new(move |event: ProgressEvent| {
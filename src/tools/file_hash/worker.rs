@@ -0,0 +1,161 @@
+//! 파일 해시를 계산하는 웹 워커
+//!
+//! 메인 스레드는 `File` 핸들만 넘기고, 워커가 파일을 `CHUNK_SIZE` 조각으로 읽어 해시합니다.
+//! 파일 전체를 메모리에 올리지 않으므로 크기 제한이 없고, 계산 중에도 UI가 멈추지 않습니다.
+//! 웹 앱과 워커 바이너리(`src/bin/hash_worker.rs`)가 이 파일을 함께 씁니다.
+
+use std::cell::RefCell;
+use std::collections::HashSet;
+use std::rc::Rc;
+
+use computools_core::file_hash::{FileHasher, HashAlgorithm, HashResults};
+use gloo::worker::{Codec, HandlerId, Worker, WorkerScope};
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::JsValue;
+use wasm_bindgen_futures::{spawn_local, JsFuture};
+use web_sys::File;
+
+/// 한 번에 읽는 조각 크기
+pub const CHUNK_SIZE: usize = 16 * 1024 * 1024;
+
+/// 워커에 보내는 요청
+#[derive(Serialize, Deserialize)]
+pub enum HashRequest {
+    /// `files`를 순서대로 해시합니다. `hmac_key`가 있으면 HMAC도 계산하고,
+    /// `hmac_only`이면 일반 해시는 건너뜁니다.
+    Hash {
+        job: u32,
+        files: Vec<HashTask>,
+        hmac_key: Option<Vec<u8>>,
+        hmac_only: bool,
+    },
+    /// 진행 중인 작업을 다음 조각을 읽기 전에 중단합니다.
+    Cancel(u32),
+}
+
+/// 해시할 파일 하나와 그 파일에 쓸 알고리즘 키
+#[derive(Serialize, Deserialize)]
+pub struct HashTask {
+    // `File`은 직렬화하지 않고 그대로 postMessage로 넘김 (구조화 복제는 내용을 복사하지 않음)
+    #[serde(with = "serde_wasm_bindgen::preserve")]
+    pub file: File,
+    pub algorithms: Vec<String>,
+}
+
+/// 워커가 보내는 응답. `index`는 요청한 `files`에서의 위치입니다.
+#[derive(Serialize, Deserialize)]
+pub enum HashResponse {
+    Progress { job: u32, index: usize, progress: f64 },
+    Hashed { job: u32, index: usize, hashes: Vec<(String, String)>, hmacs: Vec<(String, String)> },
+    Failed { job: u32, index: usize, error: String },
+    Finished { job: u32 },
+}
+
+/// 메시지를 bincode 대신 serde-wasm-bindgen으로 변환하여 `File` 핸들을 그대로 주고받습니다.
+pub struct JsCodec;
+
+impl Codec for JsCodec {
+    fn encode<I>(input: I) -> JsValue
+    where
+        I: Serialize,
+    {
+        serde_wasm_bindgen::to_value(&input).expect("can't serialize a hash worker message")
+    }
+
+    fn decode<O>(input: JsValue) -> O
+    where
+        O: for<'de> Deserialize<'de>,
+    {
+        serde_wasm_bindgen::from_value(input).expect("can't deserialize a hash worker message")
+    }
+}
+
+pub struct HashWorker {
+    active: Rc<RefCell<HashSet<u32>>>, // 진행 중인 작업 번호, 빠지면 중단
+}
+
+impl Worker for HashWorker {
+    type Message = ();
+    type Input = HashRequest;
+    type Output = HashResponse;
+
+    fn create(_scope: &WorkerScope<Self>) -> Self {
+        Self { active: Rc::new(RefCell::new(HashSet::new())) }
+    }
+
+    fn update(&mut self, _scope: &WorkerScope<Self>, _msg: Self::Message) {}
+
+    fn received(&mut self, scope: &WorkerScope<Self>, msg: Self::Input, id: HandlerId) {
+        match msg {
+            HashRequest::Hash { job, files, hmac_key, hmac_only } => {
+                self.active.borrow_mut().insert(job);
+                let active = self.active.clone();
+                let scope = scope.clone();
+                spawn_local(async move {
+                    let is_active = || active.borrow().contains(&job);
+                    for (index, task) in files.into_iter().enumerate() {
+                        let algorithms: Vec<HashAlgorithm> =
+                            task.algorithms.iter().filter_map(|key| HashAlgorithm::from_key(key)).collect();
+                        let key = hmac_key.as_deref();
+                        let mut hasher = match key {
+                            Some(key) if hmac_only => FileHasher::hmac_only(&algorithms, key),
+                            _ => FileHasher::new(&algorithms, key),
+                        };
+
+                        let read = hash_file(&task.file, &mut hasher, &is_active, |progress| {
+                            scope.respond(id, HashResponse::Progress { job, index, progress });
+                        })
+                        .await;
+                        if !is_active() {
+                            return;
+                        }
+                        let response = match read {
+                            Ok(()) => {
+                                let HashResults { hashes, hmacs } = hasher.finalize();
+                                HashResponse::Hashed { job, index, hashes: keyed(hashes), hmacs: keyed(hmacs) }
+                            }
+                            Err(error) => HashResponse::Failed {
+                                job,
+                                index,
+                                error: error.as_string().unwrap_or_else(|| format!("{:?}", error)),
+                            },
+                        };
+                        scope.respond(id, response);
+                    }
+                    active.borrow_mut().remove(&job);
+                    scope.respond(id, HashResponse::Finished { job });
+                });
+            }
+            HashRequest::Cancel(job) => {
+                self.active.borrow_mut().remove(&job);
+            }
+        }
+    }
+}
+
+/// 파일을 `CHUNK_SIZE` 조각으로 읽어 `hasher`에 넣습니다. `is_active`가 false가 되면 그만 읽습니다.
+async fn hash_file(
+    file: &File,
+    hasher: &mut FileHasher,
+    is_active: &impl Fn() -> bool,
+    on_progress: impl Fn(f64),
+) -> Result<(), JsValue> {
+    let size = file.size();
+    let mut offset = 0.0;
+    while offset < size && is_active() {
+        let end = (offset + CHUNK_SIZE as f64).min(size);
+        let slice = file.slice_with_f64_and_f64(offset, end)?;
+        let buffer = JsFuture::from(slice.array_buffer()).await?;
+        hasher.update(&js_sys::Uint8Array::new(&buffer).to_vec());
+        offset = end;
+        on_progress(offset / size);
+    }
+    Ok(())
+}
+
+fn keyed(entries: Vec<(HashAlgorithm, String)>) -> Vec<(String, String)> {
+    entries
+        .into_iter()
+        .map(|(algorithm, hash)| (algorithm.key().to_string(), hash))
+        .collect()
+}
@@ -2,32 +2,10 @@ use yew::prelude::*;
 use wasm_bindgen_futures::JsFuture;
 use web_sys::{window, HtmlInputElement, Storage};
 use crate::components::tool_category::ToolCategoryManager;
-use regex::Regex;
-
-#[derive(Clone, PartialEq)]
-pub enum HtmlMode {
-    Encode,
-    Decode,
-}
-
-#[derive(Clone, PartialEq)]
-pub enum EntityStyle {
-    Named,         // &lt; &gt; &amp; &quot; &apos;
-    Decimal,       // &#60; &#62; &#38; &#34; &#39;
-    Hexadecimal,   // &#x3C; &#x3E; &#x26; &#x22; &#x27;
-    MixedNamedHex, // Named for common, hex for others
-    MixedNamedDecimal, // Named for common, decimal for others
-    None,          // No encoding (pass through)
-}
-
-#[derive(Clone, PartialEq)]
-pub enum SelectiveMode {
-    Essential,     // Only &, <, >, ", '
-    Extended,      // Common HTML entities + accented characters
-    Unicode,       // All non-ASCII characters
-    All,          // Everything that can be encoded
-    Custom,       // User-defined characters
-}
+use computools_core::html::{
+    decode_html, detect_invalid_entities, encode_html, EncodeOptions, EntityStyle, HtmlMode,
+    SelectiveMode,
+};
 
 pub struct ToolHtml {
     input_text: String,
@@ -75,8 +53,8 @@ impl Component for ToolHtml {
                         self.output_text = self.encode_html(&self.input_text);
                     }
                     HtmlMode::Decode => {
-                        let warning_msg = ToolHtml::detect_invalid_entities(&self.input_text);
-                        match self.decode_html(&self.input_text) {
+                        let warning_msg = detect_invalid_entities(&self.input_text);
+                        match decode_html(&self.input_text) {
                             Ok(decoded) => {
                                 self.output_text = decoded;
                                 if let Some(warning) = warning_msg {
@@ -150,8 +128,8 @@ impl Component for ToolHtml {
                         self.output_text = self.encode_html(&self.input_text);
                     }
                     HtmlMode::Decode => {
-                        let warning_msg = ToolHtml::detect_invalid_entities(&self.input_text);
-                        match self.decode_html(&self.input_text) {
+                        let warning_msg = detect_invalid_entities(&self.input_text);
+                        match decode_html(&self.input_text) {
                             Ok(decoded) => {
                                 self.output_text = decoded;
                                 if let Some(warning) = warning_msg {
@@ -769,341 +747,12 @@ impl ToolHtml {
     }
 
     fn encode_html(&self, input: &str) -> String {
-        // None 모드일 때는 그대로 반환
-        if self.entity_style == EntityStyle::None {
-            return input.to_string();
-        }
-
-        let chars: Vec<char> = input.chars().collect();
-        let mut result = String::new();
-
-        for &ch in &chars {
-            if self.should_encode_char(ch) {
-                result.push_str(&self.encode_char(ch));
-            } else {
-                result.push(ch);
-            }
-        }
-
-        result
-    }
-
-    fn should_encode_char(&self, ch: char) -> bool {
-        match self.selective_mode {
-            SelectiveMode::Essential => {
-                matches!(ch, '&' | '<' | '>' | '"' | '\'')
-            }
-            SelectiveMode::Extended => {
-                matches!(ch, '&' | '<' | '>' | '"' | '\'' | '©' | '®' | '™' | '€' | '£' | '¥' | '¢' | '¿' | '¡' | 'á' | 'à' | 'â' | 'ä' | 'ã' | 'å' | 'ą' | 'æ' | 'ć' | 'ç' | 'é' | 'è' | 'ê' | 'ë' | 'ę' | 'í' | 'ì' | 'î' | 'ï' | 'ł' | 'ñ' | 'ó' | 'ò' | 'ô' | 'ö' | 'õ' | 'ø' | 'ś' | 'š' | 'ú' | 'ù' | 'û' | 'ü' | 'ý' | 'ÿ' | 'ź' | 'ž' | 'Á' | 'À' | 'Â' | 'Ä' | 'Ã' | 'Å' | 'Ą' | 'Æ' | 'Ć' | 'Ç' | 'É' | 'È' | 'Ê' | 'Ë' | 'Ę' | 'Í' | 'Ì' | 'Î' | 'Ï' | 'Ł' | 'Ñ' | 'Ó' | 'Ò' | 'Ô' | 'Ö' | 'Õ' | 'Ø' | 'Ś' | 'Š' | 'Ú' | 'Ù' | 'Û' | 'Ü' | 'Ý' | 'Ÿ' | 'Ź' | 'Ž')
-            }
-            SelectiveMode::Unicode => {
-                !ch.is_ascii() || matches!(ch, '&' | '<' | '>' | '"' | '\'')
-            }
-            SelectiveMode::All => {
-                !ch.is_ascii_alphanumeric() && ch != ' ' && ch != '\n' && ch != '\r' && ch != '\t'
-            }
-            SelectiveMode::Custom => {
-                // 쉼표로 구분된 문자들을 파싱하여 정확히 매칭
-                self.parse_custom_chars().contains(&ch)
-            }
-        }
-    }
-
-    fn parse_custom_chars(&self) -> Vec<char> {
-        if self.custom_chars.trim().is_empty() {
-            return Vec::new();
-        }
-        
-        self.custom_chars
-            .split(',')
-            .filter_map(|s| {
-                let trimmed = s.trim();
-                if trimmed.len() == 1 {
-                    trimmed.chars().next()
-                } else if trimmed.is_empty() {
-                    None
-                } else {
-                    // 여러 문자로 이루어진 경우 첫 번째 문자만 사용
-                    trimmed.chars().next()
-                }
-            })
-            .collect()
-    }
-
-    fn encode_char(&self, ch: char) -> String {
-        match self.entity_style {
-            EntityStyle::Named => self.encode_char_named(ch),
-            EntityStyle::Decimal => format!("&#{};", ch as u32),
-            EntityStyle::Hexadecimal => format!("&#x{:X};", ch as u32),
-            EntityStyle::MixedNamedHex => {
-                // Named for common characters, hex for others
-                match ch {
-                    '&' => "&amp;".to_string(),
-                    '<' => "&lt;".to_string(),
-                    '>' => "&gt;".to_string(),
-                    '"' => "&quot;".to_string(),
-                    '\'' => "&apos;".to_string(),
-                    ' ' => "&nbsp;".to_string(),
-                    '©' => "&copy;".to_string(),
-                    '®' => "&reg;".to_string(),
-                    '™' => "&trade;".to_string(),
-                    _ => format!("&#x{:X};", ch as u32),
-                }
-            }
-            EntityStyle::MixedNamedDecimal => {
-                // Named for common characters, decimal for others
-                match ch {
-                    '&' => "&amp;".to_string(),
-                    '<' => "&lt;".to_string(),
-                    '>' => "&gt;".to_string(),
-                    '"' => "&quot;".to_string(),
-                    '\'' => "&apos;".to_string(),
-                    ' ' => "&nbsp;".to_string(),
-                    '©' => "&copy;".to_string(),
-                    '®' => "&reg;".to_string(),
-                    '™' => "&trade;".to_string(),
-                    _ => format!("&#{};", ch as u32),
-                }
-            }
-            EntityStyle::None => {
-                ch.to_string()
-            }
-        }
-    }
-
-    fn encode_char_named(&self, ch: char) -> String {
-        match ch {
-            '&' => "&amp;".to_string(),
-            '<' => "&lt;".to_string(),
-            '>' => "&gt;".to_string(),
-            '"' => "&quot;".to_string(),
-            '\'' => "&apos;".to_string(),
-            ' ' => "&nbsp;".to_string(),
-            '©' => "&copy;".to_string(),
-            '®' => "&reg;".to_string(),
-            '™' => "&trade;".to_string(),
-            '€' => "&euro;".to_string(),
-            '£' => "&pound;".to_string(),
-            '¥' => "&yen;".to_string(),
-            '¢' => "&cent;".to_string(),
-            'á' => "&aacute;".to_string(),
-            'à' => "&agrave;".to_string(),
-            'â' => "&acirc;".to_string(),
-            'ä' => "&auml;".to_string(),
-            'ã' => "&atilde;".to_string(),
-            'å' => "&aring;".to_string(),
-            'æ' => "&aelig;".to_string(),
-            'ç' => "&ccedil;".to_string(),
-            'é' => "&eacute;".to_string(),
-            'è' => "&egrave;".to_string(),
-            'ê' => "&ecirc;".to_string(),
-            'ë' => "&euml;".to_string(),
-            'í' => "&iacute;".to_string(),
-            'ì' => "&igrave;".to_string(),
-            'î' => "&icirc;".to_string(),
-            'ï' => "&iuml;".to_string(),
-            'ñ' => "&ntilde;".to_string(),
-            'ó' => "&oacute;".to_string(),
-            'ò' => "&ograve;".to_string(),
-            'ô' => "&ocirc;".to_string(),
-            'ö' => "&ouml;".to_string(),
-            'õ' => "&otilde;".to_string(),
-            'ø' => "&oslash;".to_string(),
-            'ú' => "&uacute;".to_string(),
-            'ù' => "&ugrave;".to_string(),
-            'û' => "&ucirc;".to_string(),
-            'ü' => "&uuml;".to_string(),
-            'ý' => "&yacute;".to_string(),
-            'ÿ' => "&yuml;".to_string(),
-            // 대문자 버전들
-            'Á' => "&Aacute;".to_string(),
-            'À' => "&Agrave;".to_string(),
-            'Â' => "&Acirc;".to_string(),
-            'Ä' => "&Auml;".to_string(),
-            'Ã' => "&Atilde;".to_string(),
-            'Å' => "&Aring;".to_string(),
-            'Æ' => "&AElig;".to_string(),
-            'Ç' => "&Ccedil;".to_string(),
-            'É' => "&Eacute;".to_string(),
-            'È' => "&Egrave;".to_string(),
-            'Ê' => "&Ecirc;".to_string(),
-            'Ë' => "&Euml;".to_string(),
-            'Í' => "&Iacute;".to_string(),
-            'Ì' => "&Igrave;".to_string(),
-            'Î' => "&Icirc;".to_string(),
-            'Ï' => "&Iuml;".to_string(),
-            'Ñ' => "&Ntilde;".to_string(),
-            'Ó' => "&Oacute;".to_string(),
-            'Ò' => "&Ograve;".to_string(),
-            'Ô' => "&Ocirc;".to_string(),
-            'Ö' => "&Ouml;".to_string(),
-            'Õ' => "&Otilde;".to_string(),
-            'Ø' => "&Oslash;".to_string(),
-            'Ú' => "&Uacute;".to_string(),
-            'Ù' => "&Ugrave;".to_string(),
-            'Û' => "&Ucirc;".to_string(),
-            'Ü' => "&Uuml;".to_string(),
-            'Ý' => "&Yacute;".to_string(),
-            // 명명된 엔티티가 없는 경우 16진수로 폴백
-            _ => format!("&#x{:X};", ch as u32),
-        }
-    }
-
-    fn decode_html(&self, input: &str) -> Result<String, String> {
-        if input.trim().is_empty() {
-            return Ok(String::new());
-        }
-
-        // 1. 불완전/잘못된 엔티티 감지 (경고용)
-        let warning_msg = Self::detect_invalid_entities(input);
-        
-        let mut result = input.to_string();
-        
-        // 기본 HTML 엔티티를 먼저 처리 (명명된 엔티티)
-        let named_entities = [
-            ("&amp;", "&"),
-            ("&lt;", "<"),
-            ("&gt;", ">"),
-            ("&quot;", "\""),
-            ("&apos;", "'"),
-            ("&nbsp;", " "),
-            ("&copy;", "©"),
-            ("&reg;", "®"),
-            ("&trade;", "™"),
-            ("&euro;", "€"),
-            ("&pound;", "£"),
-            ("&yen;", "¥"),
-            ("&cent;", "¢"),
-            // 라틴 문자들
-            ("&aacute;", "á"), ("&agrave;", "à"), ("&acirc;", "â"), ("&auml;", "ä"),
-            ("&atilde;", "ã"), ("&aring;", "å"), ("&aelig;", "æ"), ("&ccedil;", "ç"),
-            ("&eacute;", "é"), ("&egrave;", "è"), ("&ecirc;", "ê"), ("&euml;", "ë"),
-            ("&iacute;", "í"), ("&igrave;", "ì"), ("&icirc;", "î"), ("&iuml;", "ï"),
-            ("&ntilde;", "ñ"), ("&oacute;", "ó"), ("&ograve;", "ò"), ("&ocirc;", "ô"),
-            ("&ouml;", "ö"), ("&otilde;", "õ"), ("&oslash;", "ø"), ("&uacute;", "ú"),
-            ("&ugrave;", "ù"), ("&ucirc;", "û"), ("&uuml;", "ü"), ("&yacute;", "ý"),
-            ("&yuml;", "ÿ"),
-            // 대문자들
-            ("&Aacute;", "Á"), ("&Agrave;", "À"), ("&Acirc;", "Â"), ("&Auml;", "Ä"),
-            ("&Atilde;", "Ã"), ("&Aring;", "Å"), ("&AElig;", "Æ"), ("&Ccedil;", "Ç"),
-            ("&Eacute;", "É"), ("&Egrave;", "È"), ("&Ecirc;", "Ê"), ("&Euml;", "Ë"),
-            ("&Iacute;", "Í"), ("&Igrave;", "Ì"), ("&Icirc;", "Î"), ("&Iuml;", "Ï"),
-            ("&Ntilde;", "Ñ"), ("&Oacute;", "Ó"), ("&Ograve;", "Ò"), ("&Ocirc;", "Ô"),
-            ("&Ouml;", "Ö"), ("&Otilde;", "Õ"), ("&Oslash;", "Ø"), ("&Uacute;", "Ú"),
-            ("&Ugrave;", "Ù"), ("&Ucirc;", "Û"), ("&Uuml;", "Ü"), ("&Yacute;", "Ý"),
-        ];
-        
-        for (entity, replacement) in named_entities.iter() {
-            result = result.replace(entity, replacement);
-        }
-        
-        // 16진수 엔티티 처리 (&#x[0-9A-F]+; 형식)
-        let hex_re = Regex::new(r"&#x([0-9A-Fa-f]+);").map_err(|_| "Regex compilation failed")?;
-        
-        while hex_re.is_match(&result) {
-            result = hex_re.replace_all(&result, |caps: &regex::Captures| {
-                let hex_str = &caps[1];
-                if let Ok(code_point) = u32::from_str_radix(hex_str, 16) {
-                    if let Some(character) = char::from_u32(code_point) {
-                        character.to_string()
-                    } else {
-                        caps[0].to_string() // 유효하지 않은 코드 포인트는 원래 문자열 유지
-                    }
-                } else {
-                    caps[0].to_string() // 16진수 파싱 실패시 원래 문자열 유지
-                }
-            }).to_string();
-        }
-        
-        // 10진수 엔티티 처리 (&#[0-9]+; 형식)
-        let decimal_re = Regex::new(r"&#([0-9]+);").map_err(|_| "Regex compilation failed")?;
-        
-        result = decimal_re.replace_all(&result, |caps: &regex::Captures| {
-            let decimal_str = &caps[1];
-            if let Ok(code_point) = decimal_str.parse::<u32>() {
-                if let Some(character) = char::from_u32(code_point) {
-                    character.to_string()
-                } else {
-                    caps[0].to_string()
-                }
-            } else {
-                caps[0].to_string()
-            }
-        }).to_string();
-        
-        // 경고 메시지가 있으면 Ok(결과)로 반환, 경고는 UI에서 표시
-        if let Some(warning) = warning_msg {
-            // UI에서 self.error_message = Some(warning)으로 처리됨
-            return Ok(result);
-        }
-        
-        Ok(result)
-    }
-
-    // 불완전/잘못된 엔티티 감지 함수 추가
-    fn detect_invalid_entities(input: &str) -> Option<String> {
-        use std::collections::HashSet;
-        let known_entities: HashSet<&'static str> = [
-            "&amp;", "&lt;", "&gt;", "&quot;", "&apos;", "&nbsp;", "&copy;", "&reg;", "&trade;", "&euro;", "&pound;", "&yen;", "&cent;",
-            "&aacute;", "&agrave;", "&acirc;", "&auml;", "&atilde;", "&aring;", "&aelig;", "&ccedil;", "&eacute;", "&egrave;", "&ecirc;", "&euml;",
-            "&iacute;", "&igrave;", "&icirc;", "&iuml;", "&ntilde;", "&oacute;", "&ograve;", "&ocirc;", "&ouml;", "&otilde;", "&oslash;", "&uacute;",
-            "&ugrave;", "&ucirc;", "&uuml;", "&yacute;", "&yuml;",
-            "&Aacute;", "&Agrave;", "&Acirc;", "&Auml;", "&Atilde;", "&Aring;", "&AElig;", "&Ccedil;", "&Eacute;", "&Egrave;", "&Ecirc;", "&Euml;",
-            "&Iacute;", "&Igrave;", "&Icirc;", "&Iuml;", "&Ntilde;", "&Oacute;", "&Ograve;", "&Ocirc;", "&Ouml;", "&Otilde;", "&Oslash;", "&Uacute;",
-            "&Ugrave;", "&Ucirc;", "&Uuml;", "&Yacute;"
-        ].iter().cloned().collect();
-        let re = Regex::new(r"&[#a-zA-Z0-9xX]+;?").unwrap();
-        for mat in re.find_iter(input) {
-            let entity = mat.as_str();
-            let idx = mat.start();
-            // 줄 번호, 열 번호 계산
-            let (line, col) = {
-                let mut line = 1;
-                let mut last_newline = 0;
-                for (i, c) in input[..idx].char_indices() {
-                    if c == '\n' {
-                        line += 1;
-                        last_newline = i + 1;
-                    }
-                }
-                (line, idx - last_newline + 1)
-            };
-            let pos_info = format!(" at line {}, column {}", line, col);
-            if entity.starts_with("&#x") || entity.starts_with("&#X") {
-                // 16진수 엔티티
-                let hex = entity.trim_start_matches("&#x").trim_start_matches("&#X").trim_end_matches(';');
-                if hex.is_empty() {
-                    return Some(format!("Invalid hexadecimal entity (no value): {}{}", entity, pos_info));
-                }
-                if u32::from_str_radix(hex, 16).is_err() {
-                    return Some(format!("Invalid hexadecimal entity: {}{}", entity, pos_info));
-                }
-                if !entity.ends_with(';') {
-                    return Some(format!("Incomplete hexadecimal entity (missing semicolon): {}{}", entity, pos_info));
-                }
-            } else if entity.starts_with("&#") {
-                // 10진수 엔티티
-                let dec = entity.trim_start_matches("&#").trim_end_matches(';');
-                if dec.is_empty() {
-                    return Some(format!("Invalid decimal entity (no value): {}{}", entity, pos_info));
-                }
-                if dec.parse::<u32>().is_err() {
-                    return Some(format!("Invalid decimal entity: {}{}", entity, pos_info));
-                }
-                if !entity.ends_with(';') {
-                    return Some(format!("Incomplete decimal entity (missing semicolon): {}{}", entity, pos_info));
-                }
-            } else if entity.ends_with(';') {
-                // 명명된 엔티티
-                if !known_entities.contains(entity) {
-                    return Some(format!("Unknown named entity: {}{}", entity, pos_info));
-                }
-            } else {
-                // 세미콜론 없는 불완전 명명 엔티티
-                return Some(format!("Incomplete named entity (missing semicolon): {}{}", entity, pos_info));
-            }
-        }
-        None
+        let options = EncodeOptions {
+            entity_style: self.entity_style.clone(),
+            selective_mode: self.selective_mode.clone(),
+            custom_chars: self.custom_chars.clone(),
+        };
+        encode_html(input, &options)
     }
 
     fn render_entity_table(&self, ctx: &Context<Self>) -> Html {
@@ -1279,67 +928,3 @@ impl ToolHtml {
         }
     }
 }
-
-fn encode_unicode_text(input: &str) -> String {
-    input.chars().map(|c| {
-        // 유니코드 문자일 경우 &#x<유니코드>로 변환
-        if c.is_ascii() {
-            c.to_string()
-        } else {
-            format!("&#x{:X};", c as u32)
-        }
-    }).collect()
-}
-
-fn decode_html_custom(input: &str) -> String {
-    // 기본 HTML 엔티티를 먼저 처리 (공통 엔티티)
-    let basic_entities = [
-        ("&amp;", "&"),
-        ("&lt;", "<"),
-        ("&gt;", ">"),
-        ("&quot;", "\""),
-        ("&apos;", "'"),
-    ];
-    
-    let mut result = input.to_string();
-    for (entity, replacement) in basic_entities.iter() {
-        result = result.replace(entity, replacement);
-    }
-    
-    // 유니코드 엔티티 처리 (&#x[0-9A-F]+; 형식)
-    let re = Regex::new(r"&#x([0-9A-Fa-f]+);").unwrap();
-    
-    // 모든 유니코드 엔티티를 찾아서 처리
-    while re.is_match(&result) {
-        result = re.replace_all(&result, |caps: &regex::Captures| {
-            let hex_str = &caps[1];
-            if let Ok(code_point) = u32::from_str_radix(hex_str, 16) {
-                if let Some(character) = char::from_u32(code_point) {
-                    character.to_string()
-                } else {
-                    caps[0].to_string() // 유효하지 않은 코드 포인트는 원래 문자열 유지
-                }
-            } else {
-                caps[0].to_string() // 16진수 파싱 실패시 원래 문자열 유지
-            }
-        }).to_string();
-    }
-    
-    // 10진수 엔티티도 처리 (&#[0-9]+; 형식)
-    let re_decimal = Regex::new(r"&#([0-9]+);").unwrap();
-    
-    result = re_decimal.replace_all(&result, |caps: &regex::Captures| {
-        let decimal_str = &caps[1];
-        if let Ok(code_point) = decimal_str.parse::<u32>() {
-            if let Some(character) = char::from_u32(code_point) {
-                character.to_string()
-            } else {
-                caps[0].to_string()
-            }
-        } else {
-            caps[0].to_string()
-        }
-    }).to_string();
-    
-    result
-}
\ No newline at end of file
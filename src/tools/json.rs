@@ -1,47 +1,14 @@
-use serde::ser::Serialize;
-use serde_json::ser::{PrettyFormatter, Serializer};
-use std::io::Cursor;
-use std::collections::HashSet;
+use computools_core::json::{
+    convert_to_table_data, convert_to_yaml_with_order_preservation, format_error_message,
+    format_json_with_order_preservation, get_sorted_and_paginated_rows, indent_for_style, JsonViewMode, SortDirection, TableColumn, TableRow,
+    TableState,
+};
 use wasm_bindgen_futures::JsFuture;
 use web_sys::{window, HtmlInputElement, Storage};
 use yew::prelude::*;
+use crate::components::json_tree::JsonTree;
 use crate::components::tool_category::ToolCategoryManager;
 
-#[derive(Clone, PartialEq)]
-pub enum JsonViewMode {
-    Formatted,
-    TreeView,
-    Yaml,
-    Table,
-}
-
-#[derive(Clone, PartialEq)]
-pub struct TableColumn {
-    pub name: String,
-    pub data_type: String,
-}
-
-#[derive(Clone, PartialEq)]
-pub struct TableRow {
-    pub values: Vec<String>,
-}
-
-#[derive(Clone, PartialEq)]
-pub enum SortDirection {
-    Ascending,
-    Descending,
-    None,
-}
-
-#[derive(Clone, PartialEq)]
-pub struct TableState {
-    pub sort_column: Option<String>,
-    pub sort_direction: SortDirection,
-    pub current_page: usize,
-    pub rows_per_page: usize,
-    pub search_query: String,
-}
-
 pub struct ToolJson {
     input: String,
     output: String,
@@ -49,7 +16,6 @@ pub struct ToolJson {
     tab_style: String,
     compact: bool,
     show_tree_view: bool,
-    expanded_nodes: HashSet<String>,
     view_mode: JsonViewMode,
     table_state: TableState,
 }
@@ -60,7 +26,6 @@ pub enum Msg {
     CopyToClipboard(String),
     UpdateTabSize(String),
     UpdateViewMode(String),
-    ToggleNode(String),
     SortColumn(String),
     ChangePage(usize),
     ChangeRowsPerPage(usize),
@@ -83,22 +48,15 @@ impl Component for ToolJson {
 
                 match serde_json::from_str::<serde_json::Value>(&self.input) {
                     Ok(json_value) => {
-                        let indent = match self.tab_style.as_str() {
-                            "2space" => vec![b' '; 2],
-                            "3space" => vec![b' '; 3],
-                            "4space" => vec![b' '; 4],
-                            "compact" => vec![],
-                            "1tab" => vec![b'\t'],
-                            _ => vec![b' '; 4],
-                        };
+                        let indent = indent_for_style(&self.tab_style);
                         let is_compact = indent.is_empty();
-                        self.output = self.format_json_with_order_preservation(&json_value, &indent);
+                        self.output = format_json_with_order_preservation(&json_value, &indent);
                         self.compact = is_compact;
                         self.error = None;
                     }
                     Err(err) => {
                         self.output.clear();
-                        self.error = Some(self.format_error_message(&self.input, err));
+                        self.error = Some(format_error_message(&self.input, err));
                     }
                 }
 
@@ -128,22 +86,15 @@ impl Component for ToolJson {
 
                 match serde_json::from_str::<serde_json::Value>(&self.input) {
                     Ok(json_value) => {
-                        let indent = match self.tab_style.as_str() {
-                            "2space" => vec![b' '; 2],
-                            "3space" => vec![b' '; 3],
-                            "4space" => vec![b' '; 4],
-                            "compact" => vec![],
-                            "1tab" => vec![b'\t'],
-                            _ => vec![b' '; 4],
-                        };
+                        let indent = indent_for_style(&self.tab_style);
                         let is_compact = indent.is_empty();
-                        self.output = self.format_json_with_order_preservation(&json_value, &indent);
+                        self.output = format_json_with_order_preservation(&json_value, &indent);
                         self.compact = is_compact;
                         self.error = None;
                     }
                     Err(err) => {
                         self.output.clear();
-                        self.error = Some(self.format_error_message(&self.input, err));
+                        self.error = Some(format_error_message(&self.input, err));
                     }
                 }
 
@@ -162,14 +113,6 @@ impl Component for ToolJson {
                 self.save_to_storage();
                 true
             }
-            Msg::ToggleNode(path) => {
-                if self.expanded_nodes.contains(&path) {
-                    self.expanded_nodes.remove(&path);
-                } else {
-                    self.expanded_nodes.insert(path);
-                }
-                true
-            }
             Msg::SortColumn(column_name) => {
                 if self.table_state.sort_column.as_ref() == Some(&column_name) {
                     // 같은 컬럼을 클릭한 경우 정렬 방향 변경
@@ -923,15 +866,8 @@ impl ToolJson {
             tab_style,
             compact: false,
             show_tree_view,
-            expanded_nodes: HashSet::new(),
             view_mode,
-            table_state: TableState {
-                sort_column: None,
-                sort_direction: SortDirection::None,
-                current_page: 0,
-                rows_per_page: 5,
-                search_query: String::new(),
-            },
+            table_state: TableState::default(),
         }
     }
 
@@ -949,19 +885,6 @@ impl ToolJson {
         }
     }
 
-    fn format_error_message(&self, input: &str, err: serde_json::Error) -> String {
-        let line = err.line();
-        let column = err.column();
-        let lines: Vec<&str> = input.lines().collect();
-
-        if line > 0 && line <= lines.len() {
-            let error_line = lines[line - 1];
-            let marker = format!("{}^", "-".repeat(column.saturating_sub(1)));
-            return format!("Invalid JSON:\n{}\n{}\nError: {}", error_line, marker, err);
-        }
-        format!("Invalid JSON: {}", err)
-    }
-
     fn view_output(&self) -> String {
         if let Some(error) = &self.error {
             format!("{}", error)
@@ -976,7 +899,7 @@ impl ToolJson {
     fn convert_to_yaml(&self) -> String {
         match serde_json::from_str::<serde_json::Value>(&self.input) {
             Ok(json_value) => {
-                self.convert_to_yaml_with_order_preservation(&json_value)
+                convert_to_yaml_with_order_preservation(&json_value)
             }
             Err(_) => {
                 if let Some(error) = &self.error {
@@ -988,38 +911,11 @@ impl ToolJson {
         }
     }
 
-    fn format_json_with_order_preservation(&self, json_value: &serde_json::Value, indent: &[u8]) -> String {
-        let mut output = Vec::new();
-        
-        if indent.is_empty() {
-            let mut serializer = Serializer::with_formatter(
-                Cursor::new(&mut output),
-                serde_json::ser::CompactFormatter,
-            );
-            json_value.serialize(&mut serializer).unwrap();
-        } else {
-            let formatter = PrettyFormatter::with_indent(indent);
-            let mut serializer = Serializer::with_formatter(Cursor::new(&mut output), formatter);
-            json_value.serialize(&mut serializer).unwrap();
-        }
-        
-        String::from_utf8(output).unwrap()
-    }
-
-    fn convert_to_yaml_with_order_preservation(&self, json_value: &serde_json::Value) -> String {
-        match serde_yaml::to_string(json_value) {
-            Ok(yaml_string) => yaml_string,
-            Err(_) => "Error converting to YAML".to_string()
-        }
-    }
-
     fn render_tree_view(&self, _ctx: &Context<Self>) -> Html {
         match serde_json::from_str::<serde_json::Value>(&self.input) {
             Ok(json_value) => {
                 html! {
-                    <div class="tree-view-container" style="max-height: 400px; overflow-y: auto; font-family: monospace; font-size: 12px;">
-                        { self.render_tree_node("root", &json_value, _ctx) }
-                    </div>
+                    <JsonTree value={json_value} />
                 }
             }
             Err(_) => {
@@ -1032,113 +928,8 @@ impl ToolJson {
         }
     }
 
-    fn render_tree_node(&self, path: &str, value: &serde_json::Value, _ctx: &Context<Self>) -> Html {
-        match value {
-            serde_json::Value::Object(obj) => {
-                let is_expanded = self.expanded_nodes.contains(path);
-                let path_string = path.to_string();
-                let toggle_callback = _ctx.link().callback(move |_| Msg::ToggleNode(path_string.clone()));
-                
-                html! {
-                    <div class="tree-node" style="margin-left: 20px;">
-                        <div class="node-header" style="display: flex; align-items: center; cursor: pointer; padding: 2px 0;" onclick={toggle_callback}>
-                            <span style="margin-right: 5px;">
-                                { if is_expanded { "📁" } else { "📂" } }
-                            </span>
-                            <span style="font-weight: bold; color: var(--color-fourth);">{ path }</span>
-                            <span style="margin-left: 10px; color: var(--color-subfont); font-size: 11px;">
-                                { format!("({} items)", obj.len()) }
-                            </span>
-                        </div>
-                        if is_expanded {
-                            <div class="node-children">
-                                { for obj.keys().map(|k| {
-                                    let v = obj.get(k).unwrap();
-                                    let child_path = if path == "root" { 
-                                        k.clone() 
-                                    } else { 
-                                        format!("{}.{}", path, k) 
-                                    };
-                                    self.render_tree_node(&child_path, v, _ctx)
-                                }) }
-                            </div>
-                        }
-                    </div>
-                }
-            }
-            serde_json::Value::Array(arr) => {
-                let is_expanded = self.expanded_nodes.contains(path);
-                let path_string = path.to_string();
-                let toggle_callback = _ctx.link().callback(move |_| Msg::ToggleNode(path_string.clone()));
-                
-                html! {
-                    <div class="tree-node" style="margin-left: 20px;">
-                        <div class="node-header" style="display: flex; align-items: center; cursor: pointer; padding: 2px 0;" onclick={toggle_callback}>
-                            <span style="margin-right: 5px;">
-                                { if is_expanded { "📋" } else { "📄" } }
-                            </span>
-                            <span style="font-weight: bold; color: var(--color-fourth);">{ path }</span>
-                            <span style="margin-left: 10px; color: var(--color-subfont); font-size: 11px;">
-                                { format!("({} items)", arr.len()) }
-                            </span>
-                        </div>
-                        if is_expanded {
-                            <div class="node-children">
-                                { for arr.iter().enumerate().map(|(i, v)| {
-                                    let child_path = format!("{}[{}]", path, i);
-                                    self.render_tree_node(&child_path, v, _ctx)
-                                }) }
-                            </div>
-                        }
-                    </div>
-                }
-            }
-            _ => {
-                html! {
-                    <div class="tree-node" style="margin-left: 20px;">
-                        <div class="node-item" style="display: flex; align-items: center; padding: 2px 0;">
-                            <span style="margin-right: 5px;">
-                                { self.get_type_icon(value) }
-                            </span>
-                            <span style="font-weight: bold; color: var(--color-font);">{ path }</span>
-                            <span style="margin-left: 10px; color: var(--color-subfont);">
-                                { ":" }
-                            </span>
-                            <span style="margin-left: 5px; color: var(--color-font);">
-                                { self.format_value(value) }
-                            </span>
-                        </div>
-                    </div>
-                }
-            }
-        }
-    }
-
-    fn get_type_icon(&self, value: &serde_json::Value) -> &'static str {
-        match value {
-            serde_json::Value::String(_) => "📄",
-            serde_json::Value::Number(_) => "🔢",
-            serde_json::Value::Bool(true) => "✅",
-            serde_json::Value::Bool(false) => "❌",
-            serde_json::Value::Null => "❓",
-            serde_json::Value::Object(_) => "📁",
-            serde_json::Value::Array(_) => "📋",
-        }
-    }
-
-    fn format_value(&self, value: &serde_json::Value) -> String {
-        match value {
-            serde_json::Value::String(s) => format!("\"{}\"", s),
-            serde_json::Value::Number(n) => n.to_string(),
-            serde_json::Value::Bool(b) => b.to_string(),
-            serde_json::Value::Null => "null".to_string(),
-            serde_json::Value::Object(obj) => format!("{{ {} items }}", obj.len()),
-            serde_json::Value::Array(arr) => format!("[ {} items ]", arr.len()),
-        }
-    }
-
     fn render_table(&self, _ctx: &Context<Self>, columns: Vec<TableColumn>, rows: Vec<TableRow>) -> Html {
-        let (sorted_rows, total_pages) = self.get_sorted_and_paginated_rows(&columns, &rows);
+        let (sorted_rows, total_pages) = get_sorted_and_paginated_rows(&columns, &rows, &self.table_state);
         
         html! {
             <div style="overflow-x: auto;">
@@ -1192,7 +983,7 @@ impl ToolJson {
                     if !self.table_state.search_query.is_empty() {
                         { format!("Showing {} filtered results from {} total rows, {} columns", 
                             if let Ok(json_value) = serde_json::from_str::<serde_json::Value>(&self.input) {
-                                if let Ok((_, all_rows)) = self.convert_to_table_data(&json_value) {
+                                if let Some((_, all_rows)) = convert_to_table_data(&json_value) {
                                     let filtered_count = all_rows.iter().filter(|row| {
                                         row.values.iter().any(|value| {
                                             value.to_lowercase().contains(&self.table_state.search_query.to_lowercase())
@@ -1218,15 +1009,15 @@ impl ToolJson {
     fn render_table_view(&self, _ctx: &Context<Self>) -> Html {
         match serde_json::from_str::<serde_json::Value>(&self.input) {
             Ok(json_value) => {
-                match self.convert_to_table_data(&json_value) {
-                    Ok((columns, rows)) => {
+                match convert_to_table_data(&json_value) {
+                    Some((columns, rows)) => {
                         html! {
                             <div class="table-view-container" style="max-height: 400px; overflow-y: auto; font-family: monospace; font-size: 12px;">
                                 { self.render_table(_ctx, columns, rows) }
                             </div>
                         }
                     }
-                    Err(_) => {
+                    None => {
                         html! {
                             <div style="color: var(--color-error); padding: 10px;">
                                 { "JSON structure is not suitable for table view (requires array of objects)" }
@@ -1245,128 +1036,6 @@ impl ToolJson {
         }
     }
 
-    fn convert_to_table_data(&self, value: &serde_json::Value) -> Result<(Vec<TableColumn>, Vec<TableRow>), ()> {
-        match value {
-            serde_json::Value::Array(arr) => {
-                if arr.is_empty() {
-                    return Err(());
-                }
-
-                // 첫 번째 객체에서 컬럼 순서를 결정
-                let first_item = &arr[0];
-                let column_order: Vec<String> = if let serde_json::Value::Object(obj) = first_item {
-                    obj.keys().cloned().collect()
-                } else {
-                    return Err(());
-                };
-
-                // 컬럼 정보 생성 (첫 번째 객체의 순서 유지)
-                let columns: Vec<TableColumn> = column_order.iter().map(|key| {
-                    let data_type = if let serde_json::Value::Object(obj) = first_item {
-                        obj.get(key)
-                            .map(|v| self.get_value_type(v))
-                            .unwrap_or_else(|| "unknown".to_string())
-                    } else {
-                        "unknown".to_string()
-                    };
-                    
-                    TableColumn {
-                        name: key.clone(),
-                        data_type,
-                    }
-                }).collect();
-
-                // 모든 행 데이터 추출 (컬럼 순서 유지)
-                let rows: Vec<TableRow> = arr.iter().filter_map(|item| {
-                    if let serde_json::Value::Object(obj) = item {
-                        let values: Vec<String> = column_order.iter().map(|col_name| {
-                            obj.get(col_name)
-                                .map(|v| self.format_value_for_table(v))
-                                .unwrap_or_else(|| "".to_string())
-                        }).collect();
-                        Some(TableRow { values })
-                    } else {
-                        None
-                    }
-                }).collect();
-
-                if rows.is_empty() {
-                    Err(())
-                } else {
-                    Ok((columns, rows))
-                }
-            }
-            _ => Err(()),
-        }
-    }
-
-    fn get_value_type(&self, value: &serde_json::Value) -> String {
-        match value {
-            serde_json::Value::String(_) => "string".to_string(),
-            serde_json::Value::Number(_) => "number".to_string(),
-            serde_json::Value::Bool(_) => "boolean".to_string(),
-            serde_json::Value::Null => "null".to_string(),
-            serde_json::Value::Object(_) => "object".to_string(),
-            serde_json::Value::Array(_) => "array".to_string(),
-        }
-    }
-
-    fn format_value_for_table(&self, value: &serde_json::Value) -> String {
-        match value {
-            serde_json::Value::String(s) => s.clone(),
-            serde_json::Value::Number(n) => n.to_string(),
-            serde_json::Value::Bool(b) => b.to_string(),
-            serde_json::Value::Null => "null".to_string(),
-            serde_json::Value::Object(obj) => format!("{{ {} items }}", obj.len()),
-            serde_json::Value::Array(arr) => format!("[ {} items ]", arr.len()),
-        }
-    }
-
-    fn get_sorted_and_paginated_rows(&self, columns: &[TableColumn], rows: &[TableRow]) -> (Vec<TableRow>, usize) {
-        let mut filtered_rows = rows.to_vec();
-        
-        // 검색 필터링 적용
-        if !self.table_state.search_query.is_empty() {
-            filtered_rows = filtered_rows.into_iter().filter(|row| {
-                row.values.iter().any(|value| {
-                    value.to_lowercase().contains(&self.table_state.search_query.to_lowercase())
-                })
-            }).collect();
-        }
-        
-        // 정렬이 명시적으로 요청된 경우에만 정렬 적용
-        if let Some(sort_column) = &self.table_state.sort_column {
-            if let Some(column_index) = columns.iter().position(|col| &col.name == sort_column) {
-                filtered_rows.sort_by(|a, b| {
-                    let a_val = &a.values[column_index];
-                    let b_val = &b.values[column_index];
-                    
-                    let comparison = match self.table_state.sort_direction {
-                        SortDirection::Ascending => a_val.cmp(b_val),
-                        SortDirection::Descending => b_val.cmp(a_val),
-                        SortDirection::None => std::cmp::Ordering::Equal,
-                    };
-                    
-                    comparison
-                });
-            }
-        }
-        // 정렬이 요청되지 않은 경우 원본 순서 유지
-        
-        // 페이지네이션 적용
-        let total_pages = (filtered_rows.len() + self.table_state.rows_per_page - 1) / self.table_state.rows_per_page;
-        let start_index = self.table_state.current_page * self.table_state.rows_per_page;
-        let end_index = std::cmp::min(start_index + self.table_state.rows_per_page, filtered_rows.len());
-        
-        let paginated_rows = if start_index < filtered_rows.len() {
-            filtered_rows[start_index..end_index].to_vec()
-        } else {
-            vec![]
-        };
-        
-        (paginated_rows, total_pages)
-    }
-
     fn render_pagination(&self, _ctx: &Context<Self>, total_pages: usize) -> Html {
         if total_pages <= 1 {
             return html! {};
@@ -0,0 +1,663 @@
+use computools_core::bytes::{self, InputEncoding};
+use computools_core::kdf::{self, KdfHash, PasswordHashInfo};
+use gloo::worker::{Spawnable, WorkerBridge};
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{window, HtmlInputElement};
+use yew::prelude::*;
+use crate::components::tool_category::ToolCategoryManager;
+
+pub mod worker;
+
+use worker::{DeriveForm, KdfRequest, KdfResponse, KdfWorker};
+
+// Trunk가 워커 바이너리(kdf-worker)와 함께 만드는 로더 스크립트
+const WORKER_LOADER_PATH: &str = "/kdf-worker_loader.js";
+
+/// 화면에서 고를 수 있는 알고리즘 (key, 표시 이름)
+const ALGORITHMS: [(&str, &str); 5] = [
+    ("pbkdf2", "PBKDF2-HMAC"),
+    ("hkdf", "HKDF"),
+    ("scrypt", "scrypt"),
+    ("argon2id", "Argon2id"),
+    ("bcrypt", "bcrypt"),
+];
+
+/// 숫자 파라미터 입력칸
+#[derive(Clone, Copy, PartialEq)]
+pub enum Param {
+    Iterations,
+    LogN,
+    BlockSize,
+    Parallelism,
+    MemoryKib,
+    Cost,
+    Length,
+}
+
+pub struct ToolKdf {
+    verify_mode: bool,
+    algorithm: String,
+    digest: KdfHash,
+    password: String,
+    password_encoding: InputEncoding,
+    show_password: bool,
+    salt: String,
+    salt_encoding: InputEncoding,
+    info: String,
+    pbkdf2_iterations: String,
+    argon2_iterations: String,
+    log_n: String,
+    block_size: String,
+    parallelism: String,
+    memory_kib: String,
+    cost: String,
+    length: String,
+    /// 유도 결과 (이름, 값)
+    output: Result<Vec<(String, String)>, String>,
+    stored_hash: String,
+    verify_result: Option<Result<(PasswordHashInfo, bool), String>>,
+    worker: Option<WorkerBridge<KdfWorker>>, // 키 유도 워커, 처음 계산할 때 생성
+    next_job: u32, // 워커 작업 번호
+    derive_job: Option<u32>, // 진행 중인 유도 작업
+    verify_job: Option<(u32, PasswordHashInfo)>, // 진행 중인 검증 작업과 미리 읽은 해시 정보
+}
+
+pub enum Msg {
+    ModeChanged(String),
+    AlgorithmChanged(String),
+    DigestChanged(String),
+    PasswordChanged(String),
+    PasswordEncodingChanged(String),
+    ToggleShowPassword,
+    SaltChanged(String),
+    SaltEncodingChanged(String),
+    RandomSalt,
+    InfoChanged(String),
+    ParamChanged(Param, String),
+    StoredHashChanged(String),
+    Derive,
+    Verify,
+    Worker(KdfResponse), // 키 유도 워커의 결과
+    CopyToClipboard(String),
+}
+
+impl Component for ToolKdf {
+    type Message = Msg;
+    type Properties = ();
+
+    fn create(_ctx: &Context<Self>) -> Self {
+        Self {
+            verify_mode: false,
+            algorithm: "pbkdf2".to_string(),
+            digest: KdfHash::Sha256,
+            password: String::new(),
+            password_encoding: InputEncoding::Utf8,
+            show_password: false,
+            salt: String::new(),
+            salt_encoding: InputEncoding::Hex,
+            info: String::new(),
+            pbkdf2_iterations: kdf::DEFAULT_PBKDF2_ITERATIONS.to_string(),
+            argon2_iterations: kdf::DEFAULT_ARGON2_ITERATIONS.to_string(),
+            log_n: kdf::DEFAULT_SCRYPT_LOG_N.to_string(),
+            block_size: kdf::DEFAULT_SCRYPT_R.to_string(),
+            parallelism: kdf::DEFAULT_SCRYPT_P.to_string(),
+            memory_kib: kdf::DEFAULT_ARGON2_MEMORY_KIB.to_string(),
+            cost: kdf::DEFAULT_BCRYPT_COST.to_string(),
+            length: "32".to_string(),
+            output: Ok(Vec::new()),
+            stored_hash: String::new(),
+            verify_result: None,
+            worker: None,
+            next_job: 0,
+            derive_job: None,
+            verify_job: None,
+        }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            Msg::ModeChanged(mode) => {
+                self.verify_mode = mode == "verify";
+                true
+            }
+            Msg::AlgorithmChanged(algorithm) => {
+                self.algorithm = algorithm;
+                self.output = Ok(Vec::new());
+                self.derive_job = None;
+                true
+            }
+            Msg::DigestChanged(key) => {
+                self.digest = KdfHash::from_key(&key).unwrap_or(KdfHash::Sha256);
+                true
+            }
+            Msg::PasswordChanged(password) => {
+                self.password = password;
+                true
+            }
+            Msg::PasswordEncodingChanged(key) => {
+                self.password_encoding = InputEncoding::from_key(&key).unwrap_or(InputEncoding::Utf8);
+                true
+            }
+            Msg::ToggleShowPassword => {
+                self.show_password = !self.show_password;
+                true
+            }
+            Msg::SaltChanged(salt) => {
+                self.salt = salt;
+                true
+            }
+            Msg::SaltEncodingChanged(key) => {
+                self.salt_encoding = InputEncoding::from_key(&key).unwrap_or(InputEncoding::Hex);
+                true
+            }
+            Msg::RandomSalt => {
+                match bytes::random_bytes(16) {
+                    Ok(salt) => {
+                        self.salt = InputEncoding::Hex.encode(&salt);
+                        self.salt_encoding = InputEncoding::Hex;
+                    }
+                    Err(error) => self.output = Err(error),
+                }
+                true
+            }
+            Msg::InfoChanged(info) => {
+                self.info = info;
+                true
+            }
+            Msg::ParamChanged(param, value) => {
+                let field = match param {
+                    Param::Iterations if self.algorithm == "argon2id" => &mut self.argon2_iterations,
+                    Param::Iterations => &mut self.pbkdf2_iterations,
+                    Param::LogN => &mut self.log_n,
+                    Param::BlockSize => &mut self.block_size,
+                    Param::Parallelism => &mut self.parallelism,
+                    Param::MemoryKib => &mut self.memory_kib,
+                    Param::Cost => &mut self.cost,
+                    Param::Length => &mut self.length,
+                };
+                *field = value;
+                true
+            }
+            Msg::StoredHashChanged(hash) => {
+                self.stored_hash = hash;
+                self.verify_result = None;
+                self.verify_job = None;
+                true
+            }
+            // 비용이 큰 계산이므로 입력할 때마다가 아니라 버튼을 눌렀을 때만 워커에서 계산
+            Msg::Derive => {
+                match self.derive_form() {
+                    Ok(form) => {
+                        let job = self.start_job(ctx, |job| KdfRequest::Derive { job, form: Box::new(form) });
+                        self.derive_job = Some(job);
+                    }
+                    Err(error) => self.output = Err(error),
+                }
+                true
+            }
+            Msg::Verify => {
+                self.verify_result = None;
+                if self.stored_hash.trim().is_empty() {
+                    return true;
+                }
+                match self.verify_input() {
+                    Ok((password, info)) => {
+                        let hash = self.stored_hash.clone();
+                        let job = self.start_job(ctx, |job| KdfRequest::Verify { job, password, hash });
+                        self.verify_job = Some((job, info));
+                    }
+                    Err(error) => self.verify_result = Some(Err(error)),
+                }
+                true
+            }
+            // 그사이 다시 계산을 시작했거나 입력이 바뀐 작업의 결과는 무시
+            Msg::Worker(KdfResponse::Derived { job, output }) => {
+                if self.derive_job != Some(job) {
+                    return false;
+                }
+                self.derive_job = None;
+                self.output = output;
+                true
+            }
+            Msg::Worker(KdfResponse::Verified { job, result }) => {
+                match self.verify_job.take() {
+                    Some((pending, info)) if pending == job => {
+                        self.verify_result = Some(result.map(|matched| (info, matched)));
+                        true
+                    }
+                    other => {
+                        self.verify_job = other;
+                        false
+                    }
+                }
+            }
+            Msg::CopyToClipboard(value) => {
+                if let Some(clipboard) = window().map(|w| w.navigator().clipboard()) {
+                    wasm_bindgen_futures::spawn_local(async move {
+                        let promise = clipboard.write_text(&value);
+                        let _ = JsFuture::from(promise).await;
+                    });
+                }
+                false
+            }
+        }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let link = ctx.link();
+
+        html! {
+            <>
+                <h1 class="tool-title">{ "Password Hash & KDF Tool" }</h1>
+                <div class="tool-wrapper">
+                    <div class="tool-intro">
+                        <div class="content-section">
+                            <h2>{"🔑 What is a Key Derivation Function?"}</h2>
+                            <p>{"A key derivation function (KDF) turns a password or other secret into a fixed-length key. Password-hashing KDFs such as PBKDF2, scrypt, Argon2id and bcrypt are deliberately slow and salted, so stored hashes resist brute-force attacks. HKDF is a fast KDF for expanding an already strong secret into several keys."}</p>
+                            <p>{"Password hashes are usually stored as self-describing strings that carry the algorithm, parameters, salt and output:"}</p>
+                            <ul>
+                                <li>{"$argon2id$v=19$m=19456,t=2,p=1$<salt>$<hash>"}</li>
+                                <li>{"$2b$12$<22-char salt><31-char hash>"}</li>
+                            </ul>
+                        </div>
+                        <div class="content-section">
+                            <h2>{"⚙️ How This Tool Works"}</h2>
+                            <ul>
+                                <li><strong>{"Derive Key:"}</strong> {"Choose PBKDF2-HMAC-SHA256/512, HKDF, scrypt or Argon2id, enter the password, salt and cost parameters, and get the raw key as hex and Base64 plus the matching PHC string."}</li>
+                                <li><strong>{"bcrypt:"}</strong> {"Create a $2b$ hash from a password, cost and 16-byte salt."}</li>
+                                <li><strong>{"Verify Hash:"}</strong> {"Paste a stored bcrypt, $argon2id$, $scrypt$ or $pbkdf2-sha256$ string to see its parameters and check whether a password matches it."}</li>
+                                <li><strong>{"Hex and Base64 Input:"}</strong> {"Passwords, salts and HKDF info can be entered as UTF-8 text, hex or Base64 bytes."}</li>
+                                <li><strong>{"Local Processing:"}</strong> {"Passwords and keys never leave your browser."}</li>
+                            </ul>
+                        </div>
+                        <div class="content-section">
+                            <h2>{"📚 Examples"}</h2>
+                            <div class="example-box">
+                                <p><strong>{"PBKDF2-HMAC-SHA256, password \"password\", salt \"salt\" (UTF-8), 4096 iterations, 32 bytes:"}</strong></p>
+                                <ul><li>{"c5e478d59288c841aa530db6845c4c8d962893a001ce4e11a4963873aa98134a"}</li></ul>
+                                <p><strong>{"bcrypt hash of \"U*U\" (cost 5):"}</strong></p>
+                                <ul><li>{"$2a$05$CCCCCCCCCCCCCCCCCCCCC.E5YPO9kmyuRGyh0XouQYb4YMJKvyOeW"}</li></ul>
+                            </div>
+                        </div>
+                        <div class="content-section">
+                            <h2>{"💡 Common Use Cases"}</h2>
+                            <ul>
+                                <li><strong>{"Incident Debugging:"}</strong> {"Check whether a stored password hash matches a known password without sending secrets to a server."}</li>
+                                <li><strong>{"Reproducing Keys:"}</strong> {"Re-derive an encryption key from a passphrase and salt to compare with what an application produced."}</li>
+                                <li><strong>{"Parameter Audits:"}</strong> {"Read the cost parameters embedded in stored hashes to find accounts that need rehashing."}</li>
+                                <li><strong>{"Test Fixtures:"}</strong> {"Generate password hashes with known passwords for test databases."}</li>
+                            </ul>
+                        </div>
+                        <div class="content-section">
+                            <h2>{"❓ Frequently Asked Questions"}</h2>
+                            <div class="faq-item">
+                                <h3>{"Q: Which algorithm should I use for new passwords?"}</h3>
+                                <p>{"A: Argon2id is the current recommendation. The defaults here follow the OWASP Password Storage Cheat Sheet: Argon2id with 19 MiB memory and 2 iterations, scrypt with N=2^17, r=8, p=1, PBKDF2-HMAC-SHA256 with 600,000 iterations, and bcrypt cost 12."}</p>
+                            </div>
+                            <div class="faq-item">
+                                <h3>{"Q: Why does deriving take a few seconds?"}</h3>
+                                <p>{"A: Password-hashing KDFs are slow on purpose. The calculation only runs when you click Derive or Verify, and it runs entirely in your browser in a background worker, so the page stays responsive while it works."}</p>
+                            </div>
+                            <div class="faq-item">
+                                <h3>{"Q: Why is my bcrypt hash different every time?"}</h3>
+                                <p>{"A: Each hash uses a new random salt. Use Verify to check a password against an existing hash instead of comparing strings."}</p>
+                            </div>
+                            <div class="faq-item">
+                                <h3>{"Q: Are passwords longer than 72 bytes supported by bcrypt?"}</h3>
+                                <p>{"A: bcrypt only uses the first 72 bytes of the password; the rest is ignored, as in other bcrypt implementations."}</p>
+                            </div>
+                        </div>
+                        <div class="content-section">
+                            <h2>{"🎯 Best Practices"}</h2>
+                            <ul>
+                                <li><strong>{"Use a Unique Salt:"}</strong> {"Generate a random salt of at least 16 bytes for every password."}</li>
+                                <li><strong>{"Store the PHC String:"}</strong> {"Keep the algorithm and parameters next to the hash so you can raise the cost later."}</li>
+                                <li><strong>{"Don't Use HKDF for Passwords:"}</strong> {"HKDF is fast and only suitable for secrets that already have high entropy."}</li>
+                                <li><strong>{"Protect Real Secrets:"}</strong> {"Avoid pasting production passwords on shared machines, even though nothing is sent over the network."}</li>
+                            </ul>
+                        </div>
+                        <div class="content-section">
+                            <h2>{"🔗 Related Tools"}</h2>
+                            <ul>
+                                {
+                                    ToolCategoryManager::get_related_tools("kdf")
+                                        .iter()
+                                        .map(|tool| {
+                                            html! {
+                                                <li>
+                                                    <a href={format!("/{}/", tool.route_name)}>
+                                                        { &tool.display_name }
+                                                    </a>
+                                                    { " - " }
+                                                    { &tool.description }
+                                                </li>
+                                            }
+                                        })
+                                        .collect::<Html>()
+                                }
+                            </ul>
+                        </div>
+                    </div>
+                    <div class="tool-container">
+                        <div style="display: flex; align-items: center; padding-left: 20px; padding-right: 20px; margin-bottom: 10px; margin-top: 5px; padding-top: 5px; padding-bottom: 5px;">
+                            <div class="tool-subtitle" style="width: 60%; margin-bottom: 0px;">{ "Mode" }</div>
+                            <select
+                                style="width: 40%;"
+                                onchange={link.callback(|e: Event| {
+                                    let select: HtmlInputElement = e.target_unchecked_into();
+                                    Msg::ModeChanged(select.value())
+                                })}>
+                                <option value="derive" selected={!self.verify_mode}>{ "Derive Key / Hash" }</option>
+                                <option value="verify" selected={self.verify_mode}>{ "Verify Hash" }</option>
+                            </select>
+                        </div>
+                        <div class="tool-inner">
+                            { self.view_password(ctx) }
+                            if self.verify_mode {
+                                { self.view_verify(ctx) }
+                            } else {
+                                { self.view_derive(ctx) }
+                            }
+                        </div>
+                    </div>
+                </div>
+            </>
+        }
+    }
+
+    fn rendered(&mut self, _ctx: &Context<Self>, first_render: bool) {
+        if first_render {
+            if let Some(window) = window() {
+                let document = window.document();
+                if let Some(doc) = document {
+                    doc.set_title("Password Hash & KDF Tool | CompuTools");
+
+                    if let Some(meta_tag) =
+                        doc.query_selector("meta[name=\"description\"]").unwrap()
+                    {
+                        meta_tag.set_attribute("content", "Derive keys with PBKDF2-HMAC-SHA256/512, HKDF, scrypt and Argon2id, create bcrypt hashes, and verify bcrypt, $argon2id$ and other PHC password hash strings. Inspect the parameters of stored hashes and reproduce derived keys entirely in your browser, without sending passwords to a server.").unwrap();
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl ToolKdf {
+    /// 입력칸 값을 워커에 보낼 유도 입력으로 모읍니다. 인코딩 오류는 바로 보여줍니다.
+    fn derive_form(&self) -> Result<DeriveForm, String> {
+        Ok(DeriveForm {
+            algorithm: self.algorithm.clone(),
+            digest: self.digest.key().to_string(),
+            password: self.password_encoding.decode(&self.password)?,
+            salt: self.salt_encoding.decode(&self.salt)?,
+            info: self.info.clone(),
+            pbkdf2_iterations: self.pbkdf2_iterations.clone(),
+            argon2_iterations: self.argon2_iterations.clone(),
+            log_n: self.log_n.clone(),
+            block_size: self.block_size.clone(),
+            parallelism: self.parallelism.clone(),
+            memory_kib: self.memory_kib.clone(),
+            cost: self.cost.clone(),
+            length: self.length.clone(),
+        })
+    }
+
+    /// 비밀번호를 디코딩하고 저장된 해시를 해석합니다. 느린 검증만 워커에서 합니다.
+    fn verify_input(&self) -> Result<(Vec<u8>, PasswordHashInfo), String> {
+        let password = self.password_encoding.decode(&self.password)?;
+        let info = kdf::inspect_password_hash(&self.stored_hash)?;
+        Ok((password, info))
+    }
+
+    /// 워커에 작업을 보내고 작업 번호를 돌려줍니다. 워커는 처음 쓸 때 생성합니다.
+    fn start_job(&mut self, ctx: &Context<Self>, request: impl FnOnce(u32) -> KdfRequest) -> u32 {
+        self.next_job = self.next_job.wrapping_add(1);
+        let job = self.next_job;
+        let link = ctx.link().clone();
+        let worker = self.worker.get_or_insert_with(|| {
+            KdfWorker::spawner()
+                .callback(move |response| link.send_message(Msg::Worker(response)))
+                .spawn_with_loader(WORKER_LOADER_PATH)
+        });
+        worker.send(request(job));
+        job
+    }
+
+    fn view_encoding_select(ctx: &Context<Self>, selected: InputEncoding, on_change: fn(String) -> Msg) -> Html {
+        html! {
+            <select
+                style="width: 30%;"
+                onchange={ctx.link().callback(move |e: Event| {
+                    let select: HtmlInputElement = e.target_unchecked_into();
+                    on_change(select.value())
+                })}>
+                { for InputEncoding::all().into_iter().map(|encoding| html! {
+                    <option value={encoding.key()} selected={selected == encoding}>{ encoding.name() }</option>
+                }) }
+            </select>
+        }
+    }
+
+    fn view_param(ctx: &Context<Self>, label: &str, param: Param, value: &str) -> Html {
+        html! {
+            <div style="flex: 1; min-width: 100px;">
+                <div class="tool-subtitle" style="margin-bottom: 5px;">{ label }</div>
+                <input
+                    type="number"
+                    min="0"
+                    value={value.to_string()}
+                    oninput={ctx.link().callback(move |e: InputEvent| {
+                        let input: HtmlInputElement = e.target_unchecked_into();
+                        Msg::ParamChanged(param, input.value())
+                    })}
+                />
+            </div>
+        }
+    }
+
+    fn view_password(&self, ctx: &Context<Self>) -> Html {
+        let link = ctx.link();
+
+        html! {
+            <div style="margin-bottom: 10px;">
+                <div style="display: flex; align-items: center; gap: 10px; margin-bottom: 5px;">
+                    <div class="tool-subtitle" style="flex: 1; margin-bottom: 0px;">{ "Password / Input Key" }</div>
+                    { Self::view_encoding_select(ctx, self.password_encoding, Msg::PasswordEncodingChanged) }
+                </div>
+                <input
+                    type={if self.show_password { "text" } else { "password" }}
+                    autocomplete="off"
+                    value={self.password.clone()}
+                    placeholder="Enter password..."
+                    oninput={link.callback(|e: InputEvent| {
+                        let input: HtmlInputElement = e.target_unchecked_into();
+                        Msg::PasswordChanged(input.value())
+                    })}
+                />
+                <div style="display: flex; align-items: center; gap: 5px; margin-top: 5px;">
+                    <input
+                        type="checkbox"
+                        id="kdf-show-password"
+                        checked={self.show_password}
+                        onclick={link.callback(|_| Msg::ToggleShowPassword)}
+                    />
+                    <label for="kdf-show-password" style="cursor: pointer; margin-bottom: 0px; font-size: 12px;">
+                        {"Show password"}
+                    </label>
+                </div>
+            </div>
+        }
+    }
+
+    fn view_derive(&self, ctx: &Context<Self>) -> Html {
+        let link = ctx.link();
+        let uses_digest = matches!(self.algorithm.as_str(), "pbkdf2" | "hkdf");
+
+        html! {
+            <>
+                <div style="display: flex; gap: 10px; margin-bottom: 10px;">
+                    <div style="flex: 1;">
+                        <div class="tool-subtitle" style="margin-bottom: 5px;">{ "Algorithm" }</div>
+                        <select
+                            onchange={link.callback(|e: Event| {
+                                let select: HtmlInputElement = e.target_unchecked_into();
+                                Msg::AlgorithmChanged(select.value())
+                            })}>
+                            { for ALGORITHMS.iter().map(|(key, name)| html! {
+                                <option value={*key} selected={self.algorithm == *key}>{ *name }</option>
+                            }) }
+                        </select>
+                    </div>
+                    if uses_digest {
+                        <div style="flex: 1;">
+                            <div class="tool-subtitle" style="margin-bottom: 5px;">{ "Hash" }</div>
+                            <select
+                                onchange={link.callback(|e: Event| {
+                                    let select: HtmlInputElement = e.target_unchecked_into();
+                                    Msg::DigestChanged(select.value())
+                                })}>
+                                { for KdfHash::all().into_iter().map(|hash| html! {
+                                    <option value={hash.key()} selected={self.digest == hash}>{ hash.name() }</option>
+                                }) }
+                            </select>
+                        </div>
+                    }
+                </div>
+
+                <div style="margin-bottom: 10px;">
+                    <div style="display: flex; align-items: center; gap: 10px; margin-bottom: 5px;">
+                        <div class="tool-subtitle" style="flex: 1; margin-bottom: 0px;">
+                            { if self.algorithm == "hkdf" { "Salt (optional)" } else { "Salt" } }
+                        </div>
+                        { Self::view_encoding_select(ctx, self.salt_encoding, Msg::SaltEncodingChanged) }
+                        <button class="tool-btn" style="width: auto; padding: 2px 10px;" onclick={link.callback(|_| Msg::RandomSalt)}>
+                            { "Random" }
+                        </button>
+                    </div>
+                    <input
+                        type="text"
+                        style="font-family: monospace;"
+                        value={self.salt.clone()}
+                        placeholder="Salt bytes..."
+                        oninput={link.callback(|e: InputEvent| {
+                            let input: HtmlInputElement = e.target_unchecked_into();
+                            Msg::SaltChanged(input.value())
+                        })}
+                    />
+                </div>
+
+                if self.algorithm == "hkdf" {
+                    <div style="margin-bottom: 10px;">
+                        <div class="tool-subtitle" style="margin-bottom: 5px;">{ "Info (context, UTF-8)" }</div>
+                        <input
+                            type="text"
+                            value={self.info.clone()}
+                            placeholder="e.g. encryption key v1"
+                            oninput={link.callback(|e: InputEvent| {
+                                let input: HtmlInputElement = e.target_unchecked_into();
+                                Msg::InfoChanged(input.value())
+                            })}
+                        />
+                    </div>
+                }
+
+                <div style="display: flex; flex-wrap: wrap; gap: 10px; margin-bottom: 10px;">
+                    {
+                        match self.algorithm.as_str() {
+                            "pbkdf2" => Self::view_param(ctx, "Iterations", Param::Iterations, &self.pbkdf2_iterations),
+                            "scrypt" => html! {
+                                <>
+                                    { Self::view_param(ctx, "log2 N", Param::LogN, &self.log_n) }
+                                    { Self::view_param(ctx, "r (block size)", Param::BlockSize, &self.block_size) }
+                                    { Self::view_param(ctx, "p (parallelism)", Param::Parallelism, &self.parallelism) }
+                                </>
+                            },
+                            "argon2id" => html! {
+                                <>
+                                    { Self::view_param(ctx, "Memory (KiB)", Param::MemoryKib, &self.memory_kib) }
+                                    { Self::view_param(ctx, "Iterations", Param::Iterations, &self.argon2_iterations) }
+                                    { Self::view_param(ctx, "Parallelism", Param::Parallelism, &self.parallelism) }
+                                </>
+                            },
+                            "bcrypt" => Self::view_param(ctx, "Cost", Param::Cost, &self.cost),
+                            _ => html! {},
+                        }
+                    }
+                    if self.algorithm != "bcrypt" {
+                        { Self::view_param(ctx, "Key Length (bytes)", Param::Length, &self.length) }
+                    }
+                </div>
+
+                <button class="tool-btn" disabled={self.derive_job.is_some()} onclick={link.callback(|_| Msg::Derive)}>
+                    { if self.derive_job.is_some() { "Deriving..." } else { "Derive" } }
+                </button>
+
+                if let Err(error) = &self.output {
+                    <div style="font-size: 12px; color: var(--color-error); margin-top: 10px;">{ error }</div>
+                } else if let Ok(rows) = &self.output {
+                    { for rows.iter().map(|(name, value)| self.view_output_row(ctx, name, value)) }
+                }
+            </>
+        }
+    }
+
+    fn view_verify(&self, ctx: &Context<Self>) -> Html {
+        let link = ctx.link();
+
+        html! {
+            <>
+                <div class="tool-subtitle" style="margin-bottom: 5px;">{ "Stored Hash" }</div>
+                <textarea
+                    style="width: 100%; min-height: 70px; font-family: monospace; font-size: 12px;"
+                    placeholder="$argon2id$v=19$m=19456,t=2,p=1$... or $2b$12$..."
+                    value={self.stored_hash.clone()}
+                    oninput={link.callback(|e: InputEvent| {
+                        let input: HtmlInputElement = e.target_unchecked_into();
+                        Msg::StoredHashChanged(input.value())
+                    })}
+                />
+                <button class="tool-btn" style="margin-top: 10px;" disabled={self.verify_job.is_some()} onclick={link.callback(|_| Msg::Verify)}>
+                    { if self.verify_job.is_some() { "Verifying..." } else { "Verify" } }
+                </button>
+
+                if let Some(Err(error)) = &self.verify_result {
+                    <div style="font-size: 12px; color: var(--color-error); margin-top: 10px;">{ error }</div>
+                } else if let Some(Ok((info, matched))) = &self.verify_result {
+                    <div style={format!(
+                        "margin-top: 10px; font-weight: bold; color: {};",
+                        if *matched { "var(--color-fourth)" } else { "var(--color-error)" }
+                    )}>
+                        { if *matched { "✓ Password matches" } else { "✗ Password does not match" } }
+                    </div>
+                    <table style="width: 100%; margin-top: 10px; border-collapse: collapse; font-size: 12px;">
+                        <tr><td style="padding: 3px; border: 1px solid #ddd;">{ "Scheme" }</td><td style="padding: 3px; border: 1px solid #ddd;">{ &info.scheme }</td></tr>
+                        { for info.params.iter().map(|(name, value)| html! {
+                            <tr><td style="padding: 3px; border: 1px solid #ddd;">{ name }</td><td style="padding: 3px; border: 1px solid #ddd;">{ value }</td></tr>
+                        }) }
+                        <tr><td style="padding: 3px; border: 1px solid #ddd;">{ "Salt" }</td><td style="padding: 3px; border: 1px solid #ddd; font-family: monospace;">{ &info.salt }</td></tr>
+                        <tr><td style="padding: 3px; border: 1px solid #ddd;">{ "Output Length" }</td><td style="padding: 3px; border: 1px solid #ddd;">{ format!("{} bytes", info.output_len) }</td></tr>
+                    </table>
+                }
+            </>
+        }
+    }
+
+    fn view_output_row(&self, ctx: &Context<Self>, name: &str, value: &str) -> Html {
+        html! {
+            <div>
+                <div class="tool-subtitle" style="margin-bottom: 5px; margin-top: 10px;">{ name }</div>
+                <input
+                    type="text"
+                    readonly=true
+                    style="cursor: pointer; font-family: monospace;"
+                    value={value.to_string()}
+                    onclick={ctx.link().callback(|e: MouseEvent| {
+                        let input: HtmlInputElement = e.target_unchecked_into();
+                        Msg::CopyToClipboard(input.value())
+                    })}
+                />
+            </div>
+        }
+    }
+}
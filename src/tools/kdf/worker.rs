@@ -0,0 +1,121 @@
+//! 키 유도와 비밀번호 해시 검증을 계산하는 웹 워커
+//!
+//! scrypt/Argon2id/bcrypt는 일부러 느리게 만든 함수라 기본 비용으로도 수백 ms~수 초가 걸립니다.
+//! 워커에서 계산하므로 그동안 UI가 멈추지 않습니다.
+//! 웹 앱과 워커 바이너리(`src/bin/kdf_worker.rs`)가 이 파일을 함께 씁니다.
+
+use computools_core::bytes::InputEncoding;
+use computools_core::file_hash::{format_hash_output, OutputFormat};
+use computools_core::kdf::{self, KdfHash, KdfParams};
+use gloo::worker::{HandlerId, Worker, WorkerScope};
+use serde::{Deserialize, Serialize};
+
+/// 유도 입력. 숫자 파라미터는 입력칸의 문자열 그대로 넘기고 워커에서 해석합니다.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct DeriveForm {
+    /// "pbkdf2", "hkdf", "scrypt", "argon2id", "bcrypt"
+    pub algorithm: String,
+    /// [`KdfHash`] 키
+    pub digest: String,
+    pub password: Vec<u8>,
+    pub salt: Vec<u8>,
+    pub info: String,
+    pub pbkdf2_iterations: String,
+    pub argon2_iterations: String,
+    pub log_n: String,
+    pub block_size: String,
+    pub parallelism: String,
+    pub memory_kib: String,
+    pub cost: String,
+    pub length: String,
+}
+
+/// 워커에 보내는 요청
+#[derive(Serialize, Deserialize)]
+pub enum KdfRequest {
+    Derive { job: u32, form: Box<DeriveForm> },
+    Verify { job: u32, password: Vec<u8>, hash: String },
+}
+
+/// 워커가 보내는 응답. 유도 결과는 (이름, 값) 목록입니다.
+#[derive(Serialize, Deserialize)]
+pub enum KdfResponse {
+    Derived { job: u32, output: Result<Vec<(String, String)>, String> },
+    Verified { job: u32, result: Result<bool, String> },
+}
+
+pub struct KdfWorker;
+
+impl Worker for KdfWorker {
+    type Message = ();
+    type Input = KdfRequest;
+    type Output = KdfResponse;
+
+    fn create(_scope: &WorkerScope<Self>) -> Self {
+        Self
+    }
+
+    fn update(&mut self, _scope: &WorkerScope<Self>, _msg: Self::Message) {}
+
+    fn received(&mut self, scope: &WorkerScope<Self>, msg: Self::Input, id: HandlerId) {
+        let response = match msg {
+            KdfRequest::Derive { job, form } => KdfResponse::Derived { job, output: derive(&form) },
+            KdfRequest::Verify { job, password, hash } => {
+                KdfResponse::Verified { job, result: kdf::verify_password(&password, &hash) }
+            }
+        };
+        scope.respond(id, response);
+    }
+}
+
+/// 선택한 알고리즘으로 키(또는 bcrypt 해시)를 유도하고 Hex/Base64/PHC 결과를 돌려줍니다.
+pub fn derive(form: &DeriveForm) -> Result<Vec<(String, String)>, String> {
+    if form.algorithm == "bcrypt" {
+        let cost = parse_number("Cost", &form.cost)?;
+        let salt: [u8; 16] = form.salt.as_slice().try_into().map_err(|_| {
+            format!("bcrypt needs a 16-byte salt, got {} bytes. Click Random to create one.", form.salt.len())
+        })?;
+        return Ok(vec![("bcrypt Hash".to_string(), kdf::bcrypt_hash(&form.password, cost, salt)?)]);
+    }
+
+    let params = kdf_params(form)?;
+    if form.salt.is_empty() && !matches!(params, KdfParams::Hkdf { .. }) {
+        return Err("Enter a salt or click Random to create one.".to_string());
+    }
+    let length = parse_number("Key length", &form.length)?;
+    let key = kdf::derive_key(&params, &form.password, &form.salt, length)?;
+    let key_hex = InputEncoding::Hex.encode(&key);
+
+    let mut output = vec![
+        (format!("{} Key (Hex)", params.name()), key_hex.clone()),
+        (format!("{} Key (Base64)", params.name()), format_hash_output(&key_hex, &OutputFormat::Base64)),
+    ];
+    if !matches!(params, KdfParams::Hkdf { .. }) {
+        // PHC salt 길이 제한(4~48 바이트)에 걸리면 원시 키만 보여주고 이유를 표시
+        let phc = kdf::phc_string(&params, &form.password, &form.salt, length).unwrap_or_else(|error| error);
+        output.push(("PHC String".to_string(), phc));
+    }
+    Ok(output)
+}
+
+fn kdf_params(form: &DeriveForm) -> Result<KdfParams, String> {
+    let hash = KdfHash::from_key(&form.digest).unwrap_or(KdfHash::Sha256);
+    Ok(match form.algorithm.as_str() {
+        "pbkdf2" => KdfParams::Pbkdf2 { hash, iterations: parse_number("Iterations", &form.pbkdf2_iterations)? },
+        "hkdf" => KdfParams::Hkdf { hash, info: form.info.as_bytes().to_vec() },
+        "scrypt" => KdfParams::Scrypt {
+            log_n: parse_number("log2 N", &form.log_n)?,
+            r: parse_number("r", &form.block_size)?,
+            p: parse_number("p", &form.parallelism)?,
+        },
+        _ => KdfParams::Argon2id {
+            memory_kib: parse_number("Memory", &form.memory_kib)?,
+            iterations: parse_number("Iterations", &form.argon2_iterations)?,
+            parallelism: parse_number("Parallelism", &form.parallelism)?,
+        },
+    })
+}
+
+fn parse_number<T: std::str::FromStr>(label: &str, value: &str) -> Result<T, String> {
+    value.trim().parse::<T>().map_err(|_| format!("{} must be a non-negative whole number.", label))
+}
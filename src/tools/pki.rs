@@ -0,0 +1,390 @@
+use computools_core::base64::{format_file_size, get_file_icon};
+use computools_core::json::{format_json_with_order_preservation, indent_for_style};
+use computools_core::pki;
+use gloo_file::futures::read_as_bytes;
+use gloo_file::File as GlooFile;
+use wasm_bindgen::JsCast;
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{window, DragEvent, HtmlInputElement};
+use yew::prelude::*;
+use crate::components::json_tree::JsonTree;
+use crate::components::tool_category::ToolCategoryManager;
+use crate::tools::unixtime::ToolUnixtime;
+
+pub struct ToolPki {
+    input: String,
+    /// PEM 텍스트가 아닌 파일(바이너리 DER)의 (이름, 내용)
+    der_file: Option<(String, Vec<u8>)>,
+    is_drag_over: bool,
+    result: Option<Result<serde_json::Value, String>>,
+    timezone: String,
+}
+
+pub enum Msg {
+    InputChanged(String),
+    DragOver,
+    DragLeave,
+    FileDrop(Vec<GlooFile>),
+    FileLoaded(String, Result<Vec<u8>, String>),
+    ClearFile,
+    CopyToClipboard(String),
+}
+
+impl Component for ToolPki {
+    type Message = Msg;
+    type Properties = ();
+
+    fn create(_ctx: &Context<Self>) -> Self {
+        let (timezone, _) = ToolUnixtime::fetch_time_zone_offset();
+        Self {
+            input: String::new(),
+            der_file: None,
+            is_drag_over: false,
+            result: None,
+            timezone,
+        }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            Msg::InputChanged(input) => {
+                self.input = input;
+                self.der_file = None;
+                self.refresh();
+                true
+            }
+            Msg::DragOver => {
+                self.is_drag_over = true;
+                true
+            }
+            Msg::DragLeave => {
+                self.is_drag_over = false;
+                true
+            }
+            Msg::FileDrop(files) => {
+                self.is_drag_over = false;
+                if let Some(file) = files.into_iter().next() {
+                    let link = ctx.link().clone();
+                    wasm_bindgen_futures::spawn_local(async move {
+                        let bytes = read_as_bytes(&file).await.map_err(|e| format!("Failed to read file: {}", e));
+                        link.send_message(Msg::FileLoaded(file.name(), bytes));
+                    });
+                }
+                true
+            }
+            Msg::FileLoaded(name, bytes) => {
+                match bytes {
+                    // PEM 파일은 텍스트 칸에 넣어 내용을 보고 고칠 수 있게 함
+                    Ok(bytes) => match String::from_utf8(bytes) {
+                        Ok(text) if text.contains("-----BEGIN ") => {
+                            self.input = text;
+                            self.der_file = None;
+                        }
+                        Ok(text) => self.der_file = Some((name, text.into_bytes())),
+                        Err(e) => self.der_file = Some((name, e.into_bytes())),
+                    },
+                    Err(error) => {
+                        self.result = Some(Err(error));
+                        return true;
+                    }
+                }
+                self.refresh();
+                true
+            }
+            Msg::ClearFile => {
+                self.der_file = None;
+                self.refresh();
+                true
+            }
+            Msg::CopyToClipboard(value) => {
+                if let Some(clipboard) = window().map(|w| w.navigator().clipboard()) {
+                    wasm_bindgen_futures::spawn_local(async move {
+                        let promise = clipboard.write_text(&value);
+                        let _ = JsFuture::from(promise).await;
+                    });
+                }
+                false
+            }
+        }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let link = ctx.link();
+
+        html! {
+            <>
+                <h1 class="tool-title">{ "PEM / X.509 Certificate Decoder" }</h1>
+                <div class="tool-wrapper">
+                    <div class="tool-intro">
+                        <div class="content-section">
+                            <h2>{"🔑 What are PEM, DER and X.509?"}</h2>
+                            <p>{"X.509 certificates and public/private keys are stored as ASN.1 structures encoded in binary DER. PEM wraps the same DER bytes in Base64 between lines such as -----BEGIN CERTIFICATE----- and -----END CERTIFICATE-----, so they can be pasted into config files and emails."}</p>
+                            <ul>
+                                <li><strong>{"Certificates:"}</strong> {"Bind a public key to a subject (a domain, person or CA) and are signed by an issuer for a limited validity period."}</li>
+                                <li><strong>{"Public Keys:"}</strong> {"SubjectPublicKeyInfo (BEGIN PUBLIC KEY) or PKCS#1 (BEGIN RSA PUBLIC KEY)."}</li>
+                                <li><strong>{"Private Keys:"}</strong> {"PKCS#8 (BEGIN PRIVATE KEY), PKCS#1 (BEGIN RSA PRIVATE KEY) or SEC1 (BEGIN EC PRIVATE KEY)."}</li>
+                            </ul>
+                        </div>
+                        <div class="content-section">
+                            <h2>{"⚙️ How This Tool Works"}</h2>
+                            <ul>
+                                <li><strong>{"Paste or Drop:"}</strong> {"Paste PEM text, or drop a .pem, .crt, .cer, .der or .key file onto the upload area."}</li>
+                                <li><strong>{"Field Tree:"}</strong> {"Subject, issuer, validity dates in your time zone, Subject Alternative Names, key usage and other extensions are shown as an expandable tree."}</li>
+                                <li><strong>{"Key Details:"}</strong> {"RSA modulus and exponent, EC curve and point, and Ed25519/X25519 key bytes."}</li>
+                                <li><strong>{"Fingerprints:"}</strong> {"SHA-1 and SHA-256 of the DER bytes, in the same format as openssl x509 -fingerprint."}</li>
+                                <li><strong>{"Chains:"}</strong> {"Several PEM blocks, such as a full certificate chain, are decoded one after another."}</li>
+                                <li><strong>{"Local Processing:"}</strong> {"Certificates and keys are decoded in your browser and never uploaded."}</li>
+                            </ul>
+                        </div>
+                        <div class="content-section">
+                            <h2>{"📚 Example"}</h2>
+                            <div class="example-box">
+                                <p><strong>{"Decoded certificate (abridged):"}</strong></p>
+                                <ul>
+                                    <li>{"subject: CN=test.example.com, O=CompuTools Test, C=KR"}</li>
+                                    <li>{"validity.status: Valid (expires in 3649d)"}</li>
+                                    <li>{"extensions.subjectAltName: DNS:test.example.com, DNS:*.example.com, IP:192.0.2.1"}</li>
+                                    <li>{"fingerprints.SHA-256: 43:0B:1B:7E:04:7C:46:15:..."}</li>
+                                </ul>
+                            </div>
+                        </div>
+                        <div class="content-section">
+                            <h2>{"💡 Common Use Cases"}</h2>
+                            <ul>
+                                <li><strong>{"TLS Troubleshooting:"}</strong> {"Check which host names a certificate covers and when it expires."}</li>
+                                <li><strong>{"Certificate Pinning:"}</strong> {"Get the SHA-256 fingerprint of a certificate or public key."}</li>
+                                <li><strong>{"Key Inspection:"}</strong> {"Find out whether a key file is RSA, EC or Ed25519, its size and its format."}</li>
+                                <li><strong>{"Chain Review:"}</strong> {"Follow issuer and subject through a bundle of certificates."}</li>
+                            </ul>
+                        </div>
+                        <div class="content-section">
+                            <h2>{"❓ Frequently Asked Questions"}</h2>
+                            <div class="faq-item">
+                                <h3>{"Q: Does this tool check that a certificate is trusted?"}</h3>
+                                <p>{"A: No. It decodes the fields and reports whether the current time is within the validity period, but it doesn't verify signatures, chains or revocation."}</p>
+                            </div>
+                            <div class="faq-item">
+                                <h3>{"Q: Can I decode an encrypted private key?"}</h3>
+                                <p>{"A: Not directly. Remove the passphrase first, for example with openssl pkey -in key.pem."}</p>
+                            </div>
+                            <div class="faq-item">
+                                <h3>{"Q: Is it safe to paste a private key here?"}</h3>
+                                <p>{"A: Everything runs locally in your browser, but treat real production private keys with care and prefer test keys whenever possible."}</p>
+                            </div>
+                        </div>
+                        <div class="content-section">
+                            <h2>{"🎯 Best Practices"}</h2>
+                            <ul>
+                                <li><strong>{"Watch Expiry:"}</strong> {"Renew certificates well before notAfter and automate renewal where possible."}</li>
+                                <li><strong>{"Check SANs:"}</strong> {"Browsers ignore the Common Name; every host name must be listed in subjectAltName."}</li>
+                                <li><strong>{"Compare Fingerprints:"}</strong> {"Use SHA-256 fingerprints rather than SHA-1 when confirming a certificate out of band."}</li>
+                            </ul>
+                        </div>
+                        <div class="content-section">
+                            <h2>{"🔗 Related Tools"}</h2>
+                            <ul>
+                                {
+                                    ToolCategoryManager::get_related_tools("pki")
+                                        .iter()
+                                        .map(|tool| {
+                                            html! {
+                                                <li>
+                                                    <a href={format!("/{}/", tool.route_name)}>
+                                                        { &tool.display_name }
+                                                    </a>
+                                                    { " - " }
+                                                    { &tool.description }
+                                                </li>
+                                            }
+                                        })
+                                        .collect::<Html>()
+                                }
+                            </ul>
+                        </div>
+                    </div>
+                    <div class="tool-container">
+                        <div class="tool-inner">
+                            <div class="tool-subtitle" style="margin-bottom: 5px;">{ "PEM / DER Input" }</div>
+                            <div
+                                style={format!("margin-bottom: 10px; border: 2px dashed {}; border-radius: 8px; padding: 15px; text-align: center; transition: all 0.2s ease;{}",
+                                    if self.is_drag_over { "var(--color-primary)" } else { "var(--color-border)" },
+                                    if self.is_drag_over { " background-color: rgba(var(--color-primary-rgb), 0.1);" } else { "" }
+                                )}
+                                ondragover={link.callback(|e: DragEvent| {
+                                    e.prevent_default();
+                                    Msg::DragOver
+                                })}
+                                ondragenter={link.callback(|e: DragEvent| {
+                                    e.prevent_default();
+                                    Msg::DragOver
+                                })}
+                                ondragleave={link.callback(|e: DragEvent| {
+                                    e.prevent_default();
+                                    Msg::DragLeave
+                                })}
+                                ondrop={link.callback(|e: DragEvent| {
+                                    e.prevent_default();
+                                    let mut file_list = Vec::new();
+
+                                    // wasm-bindgen을 통해 dataTransfer.files에 접근
+                                    let event_obj = wasm_bindgen::JsValue::from(e);
+                                    if let Ok(data_transfer) = js_sys::Reflect::get(&event_obj, &"dataTransfer".into()) {
+                                        if let Ok(files) = js_sys::Reflect::get(&data_transfer, &"files".into()) {
+                                            if let Ok(file_list_obj) = files.dyn_into::<web_sys::FileList>() {
+                                                for i in 0..file_list_obj.length() {
+                                                    if let Some(file) = file_list_obj.get(i) {
+                                                        file_list.push(GlooFile::from(file));
+                                                    }
+                                                }
+                                            }
+                                        }
+                                    }
+                                    Msg::FileDrop(file_list)
+                                })}
+                            >
+                                if let Some((name, bytes)) = &self.der_file {
+                                    <div>
+                                        <div style="display: flex; align-items: center; margin-bottom: 10px;">
+                                            <span style="font-size: 20px; margin-right: 8px;">
+                                                { get_file_icon("application/octet-stream") }
+                                            </span>
+                                            <div style="text-align: left;">
+                                                <div style="font-weight: bold; color: var(--color-font);">{ name }</div>
+                                                <div style="font-size: 12px; color: var(--color-subfont);">
+                                                    { format!("{} • DER", format_file_size(bytes.len())) }
+                                                </div>
+                                            </div>
+                                        </div>
+                                        <div style="display: flex; justify-content: center;">
+                                            <button
+                                                type="button"
+                                                style="background: var(--color-error); color: white; border: none; border-radius: 4px; padding: 8px 16px; cursor: pointer;"
+                                                onclick={link.callback(|_| Msg::ClearFile)}>
+                                                { "Remove" }
+                                            </button>
+                                        </div>
+                                    </div>
+                                } else {
+                                    <div>
+                                        <div style="margin-bottom: 10px;">
+                                            <i class="fa-solid fa-cloud-upload-alt" style="font-size: 24px; color: var(--color-subfont);"></i>
+                                        </div>
+                                        <div style="margin-bottom: 8px; font-weight: bold; color: var(--color-primary);">
+                                            { "Drop a certificate or key file here or click to upload" }
+                                        </div>
+                                        <div style="font-size: 12px; color: var(--color-subfont); margin-bottom: 10px;">
+                                            { "PEM (.pem, .crt, .key) or binary DER (.der, .cer)" }
+                                        </div>
+                                        <input
+                                            type="file"
+                                            id="pki-upload"
+                                            style="display: none;"
+                                            onchange={link.callback(|e: Event| {
+                                                let input: HtmlInputElement = e.target_unchecked_into();
+                                                let mut file_list = Vec::new();
+                                                if let Some(files) = input.files() {
+                                                    for i in 0..files.length() {
+                                                        if let Some(file) = files.get(i) {
+                                                            file_list.push(GlooFile::from(file));
+                                                        }
+                                                    }
+                                                }
+                                                input.set_value("");
+                                                Msg::FileDrop(file_list)
+                                            })}
+                                        />
+                                        <label
+                                            for="pki-upload"
+                                            style="display: inline-block; background: var(--color-primary); color: white; padding: 8px 16px; border-radius: 4px; cursor: pointer;">
+                                            { "Choose File" }
+                                        </label>
+                                    </div>
+                                }
+                            </div>
+                            if self.der_file.is_none() {
+                                <textarea
+                                    style="width: 100%; height: 180px; font-family: monospace; font-size: 12px;"
+                                    placeholder="-----BEGIN CERTIFICATE-----\n...\n-----END CERTIFICATE-----"
+                                    value={self.input.clone()}
+                                    oninput={link.callback(|e: InputEvent| {
+                                        let input: HtmlInputElement = e.target_unchecked_into();
+                                        Msg::InputChanged(input.value())
+                                    })}
+                                />
+                            }
+                        </div>
+                        <div class="tool-inner" style="margin-top: 10px;">
+                            { self.view_result(ctx) }
+                        </div>
+                    </div>
+                </div>
+            </>
+        }
+    }
+
+    fn rendered(&mut self, _ctx: &Context<Self>, first_render: bool) {
+        if first_render {
+            if let Some(window) = window() {
+                let document = window.document();
+                if let Some(doc) = document {
+                    doc.set_title("PEM / X.509 Certificate Decoder | CompuTools");
+
+                    if let Some(meta_tag) =
+                        doc.query_selector("meta[name=\"description\"]").unwrap()
+                    {
+                        meta_tag.set_attribute("content", "Decode PEM and DER X.509 certificates and RSA, EC and Ed25519 keys online. View subject, issuer, validity dates, Subject Alternative Names, extensions and SHA-1/SHA-256 fingerprints as a tree. All processing happens locally in your browser.").unwrap();
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl ToolPki {
+    fn refresh(&mut self) {
+        let data = match &self.der_file {
+            Some((_, bytes)) => bytes.as_slice(),
+            None if self.input.trim().is_empty() => {
+                self.result = None;
+                return;
+            }
+            None => self.input.as_bytes(),
+        };
+        let now = (js_sys::Date::now() / 1000.0) as i64;
+        self.result = Some(pki::inspect_input(data, &self.timezone, now));
+    }
+
+    fn view_result(&self, ctx: &Context<Self>) -> Html {
+        let Some(result) = &self.result else {
+            return html! {
+                <div style="font-size: 12px; color: var(--color-subfont);">{ "Decoded fields appear here." }</div>
+            };
+        };
+
+        if let Err(error) = result {
+            html! {
+                <div style="font-size: 12px; color: var(--color-error); font-weight: bold;">{ format!("✗ {}", error) }</div>
+            }
+        } else if let Ok(value) = result {
+            let json = format_json_with_order_preservation(value, &indent_for_style("2space"));
+            html! {
+                <>
+                    <div style="display: flex; align-items: center; gap: 10px; margin-bottom: 5px;">
+                        <div class="tool-subtitle" style="flex: 1; margin-bottom: 0px;">{ "Decoded Fields" }</div>
+                        <button
+                            class="tool-btn"
+                            style="width: auto; padding: 2px 10px;"
+                            onclick={ctx.link().callback(move |_| Msg::CopyToClipboard(json.clone()))}>
+                            { "Copy JSON" }
+                        </button>
+                    </div>
+                    <div style="min-height: 200px; max-height: 600px; overflow-y: auto; border: 1px solid var(--color-border); border-radius: 5px; padding: 10px; background-color: var(--color-bg);">
+                        <JsonTree value={value.clone()} expand_depth={2} />
+                    </div>
+                </>
+            }
+        } else {
+            html! {}
+        }
+    }
+}
@@ -1,25 +1,10 @@
 use log::info;
-use std::f64::consts::PI;
+use computools_core::quaternion::{euler_to_quaternion, quaternion_to_euler, EulerAngles, Quaternion};
 use wasm_bindgen_futures::{spawn_local, JsFuture};
 use web_sys::{window, HtmlInputElement, HtmlSelectElement};
 use yew::prelude::*;
 use crate::components::tool_category::ToolCategoryManager;
 
-#[derive(Clone, PartialEq)]
-struct Quaternion {
-    w: f64,
-    x: f64,
-    y: f64,
-    z: f64,
-}
-
-#[derive(Clone, PartialEq)]
-struct EulerAngles {
-    roll: f64,
-    pitch: f64,
-    yaw: f64,
-}
-
 pub struct ToolQuaternion {
     quaternion: Quaternion,
     quaternion_res: Quaternion,
@@ -97,7 +82,7 @@ impl Component for ToolQuaternion {
                 }
 
                 (self.euler, self.quaternion_res) =
-                    self.quaternion_to_euler(self.quaternion.clone());
+                    quaternion_to_euler(self.quaternion.clone());
 
                 true
             }
@@ -114,7 +99,7 @@ impl Component for ToolQuaternion {
                 }
 
                 (self.euler_res, self.convert_quat) =
-                    self.euler_to_quaternion(self.convert_euler.clone());
+                    euler_to_quaternion(self.convert_euler.clone());
 
                 true
             }
@@ -542,81 +527,3 @@ Yaw: 0.0"#}
         }
     }
 }
-
-impl ToolQuaternion {
-    pub fn quaternion_to_euler(&self, q: Quaternion) -> (EulerAngles, Quaternion) {
-        let qw = q.w;
-        let qx = q.x;
-        let qy = q.y;
-        let qz = q.z;
-
-        let norm = (qx * qx + qy * qy + qz * qz + qw * qw).sqrt();
-        if norm != 0.0 {
-            let x = qx / norm;
-            let y = qy / norm;
-            let z = qz / norm;
-            let w = qw / norm;
-
-            let sinr_cosp = 2.0 * (w * x + y * z);
-            let cosr_cosp = 1.0 - 2.0 * (x * x + y * y);
-            let roll = sinr_cosp.atan2(cosr_cosp);
-
-            let sinp = 2.0 * (w * y - z * x);
-            let pitch = if sinp.abs() >= 1.0 {
-                PI / 2.0 * sinp.signum()
-            } else {
-                sinp.asin()
-            };
-
-            let siny_cosp = 2.0 * (w * z + x * y);
-            let cosy_cosp = 1.0 - 2.0 * (y * y + z * z);
-            let yaw = siny_cosp.atan2(cosy_cosp);
-
-            (EulerAngles { roll, pitch, yaw }, Quaternion { x, y, z, w })
-        } else {
-            let x = 0.0;
-            let y = 0.0;
-            let z = 0.0;
-            let w = 1.0;
-
-            (
-                EulerAngles {
-                    roll: 0.0,
-                    pitch: 0.0,
-                    yaw: 0.0,
-                },
-                Quaternion { x, y, z, w },
-            )
-        }
-    }
-
-    pub fn euler_to_quaternion(&self, e: EulerAngles) -> (EulerAngles, Quaternion) {
-        let roll = self.normalize_angle(e.roll);
-        let pitch = self.normalize_angle(e.pitch);
-        let yaw = self.normalize_angle(e.yaw);
-
-        let cy = (yaw * 0.5).cos();
-        let sy = (yaw * 0.5).sin();
-        let cr = (roll * 0.5).cos();
-        let sr = (roll * 0.5).sin();
-        let cp = (pitch * 0.5).cos();
-        let sp = (pitch * 0.5).sin();
-
-        let w = cr * cp * cy + sr * sp * sy;
-        let x = sr * cp * cy - cr * sp * sy;
-        let y = cr * sp * cy + sr * cp * sy;
-        let z = cr * cp * sy - sr * sp * cy;
-
-        (EulerAngles { roll, pitch, yaw }, Quaternion { x, y, z, w })
-    }
-
-    fn normalize_angle(&self, angle: f64) -> f64 {
-        let mut norm_angle = angle % (2.0 * PI);
-        if norm_angle > PI {
-            norm_angle -= 2.0 * PI;
-        } else if norm_angle <= -PI {
-            norm_angle += 2.0 * PI;
-        }
-        norm_angle
-    }
-}
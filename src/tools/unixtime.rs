@@ -1,8 +1,9 @@
-use chrono::{
-    DateTime, Datelike, FixedOffset, Local, NaiveDateTime, Offset, TimeZone, Timelike, Utc,
-    Weekday,
+use chrono::Utc;
+use computools_core::unixtime::{
+    datetime_str_to_unixtime, normalize_datetime_input, parse_unixtime_input, timezone_list,
+    unixtime_to_datetime_str_form1, unixtime_to_datetime_str_form2,
+    unixtime_to_datetime_str_form3,
 };
-use chrono_tz::TZ_VARIANTS;
 use gloo_timers::callback::Interval;
 use js_sys::Date;
 use log::info;
@@ -57,9 +58,9 @@ impl Component for ToolUnixtime {
         let current_unixtime = input_unixtime.clone();
         _ctx.link()
             .send_message(Msg::UpdateUnixtime(input_unixtime.to_string()));
-        let current_datetime = ToolUnixtime::unixtime_to_datetime_str_form2(
+        let current_datetime = unixtime_to_datetime_str_form2(
             current_unixtime,
-            selected_timezone.clone(),
+            &selected_timezone,
         );
 
         let link = _ctx.link().clone();
@@ -67,30 +68,17 @@ impl Component for ToolUnixtime {
             link.send_message(Msg::Tick);
         });
 
-        let input_datetime = ToolUnixtime::unixtime_to_datetime_str_form3(
+        let input_datetime = unixtime_to_datetime_str_form3(
             current_unixtime,
-            selected_timezone.clone(),
+            &selected_timezone,
         );
-        let output_unixtime = ToolUnixtime::datetime_str_to_unixtime(
-            input_datetime.clone(),
-            selected_timezone.clone(),
+        let output_unixtime = datetime_str_to_unixtime(
+            &input_datetime,
+            &selected_timezone,
         )
         .unwrap_or(0);
 
-        let timezones: Vec<(String, i32)> = TZ_VARIANTS
-            .iter()
-            .map(|tz| {
-                let now = Utc::now();
-                let offset = tz
-                    .offset_from_utc_datetime(&now.naive_utc())
-                    .fix()
-                    .local_minus_utc();
-                let hours = offset / 3600;
-                let minutes = (offset % 3600) / 60;
-                let offset_str = format!("UTC{:+03}:{:02}", hours, minutes.abs());
-                (format!("{} ({})", tz.name(), offset_str), offset)
-            })
-            .collect();
+        let timezones = timezone_list(Utc::now());
 
         Self {
             selected_timezone,
@@ -117,51 +105,37 @@ impl Component for ToolUnixtime {
             Msg::TimezoneSelect(value) => {
                 self.selected_timezone = value;
 
-                self.output_datetime = ToolUnixtime::unixtime_to_datetime_str_form1(
+                self.output_datetime = unixtime_to_datetime_str_form1(
                     self.input_unixtime,
-                    self.selected_timezone.clone(),
+                    &self.selected_timezone,
                 );
 
-                self.output_unixtime = ToolUnixtime::datetime_str_to_unixtime(
-                    self.input_datetime.clone(),
-                    self.selected_timezone.clone(),
+                self.output_unixtime = datetime_str_to_unixtime(
+                    &self.input_datetime,
+                    &self.selected_timezone,
                 )
                 .unwrap_or(0);
 
-                self.current_datetime = ToolUnixtime::unixtime_to_datetime_str_form2(
+                self.current_datetime = unixtime_to_datetime_str_form2(
                     self.current_unixtime,
-                    self.selected_timezone.clone(),
+                    &self.selected_timezone,
                 );
 
                 true
             }
             Msg::UpdateUnixtime(value) => {
-                let mut parsed_value = value.trim().parse::<i64>().unwrap_or(0);
-
-                if parsed_value < 0 {
-                    parsed_value = 0;
-                } else if parsed_value > 100000000000 {
-                    parsed_value = 0;
-                }
-
-                self.input_unixtime = parsed_value;
-                self.output_datetime = ToolUnixtime::unixtime_to_datetime_str_form1(
+                self.input_unixtime = parse_unixtime_input(&value);
+                self.output_datetime = unixtime_to_datetime_str_form1(
                     self.input_unixtime,
-                    self.selected_timezone.clone(),
+                    &self.selected_timezone,
                 );
                 true
             }
             Msg::UpdateDatetime(value) => {
-                let mut value = value;
-                if value.len() <= 16 {
-                    // "YYYY-MM-DDTHH:MM" 길이 확인
-                    value.push_str(":00");
-                }
-
-                self.input_datetime = value.clone();
-                self.output_unixtime = ToolUnixtime::datetime_str_to_unixtime(
-                    self.input_datetime.clone(),
-                    self.selected_timezone.clone(),
+                self.input_datetime = normalize_datetime_input(&value);
+                self.output_unixtime = datetime_str_to_unixtime(
+                    &self.input_datetime,
+                    &self.selected_timezone,
                 )
                 .unwrap_or(0);
                 true
@@ -172,9 +146,9 @@ impl Component for ToolUnixtime {
             }
             Msg::Tick => {
                 self.current_unixtime = Date::now() as i64 / 1000;
-                self.current_datetime = ToolUnixtime::unixtime_to_datetime_str_form2(
+                self.current_datetime = unixtime_to_datetime_str_form2(
                     self.current_unixtime,
-                    self.selected_timezone.clone(),
+                    &self.selected_timezone,
                 );
                 true
             }
@@ -416,45 +390,6 @@ impl Component for ToolUnixtime {
 }
 
 impl ToolUnixtime {
-    pub fn unixtime_to_datetime_str_form1(unixtime: i64, selected_timezone: String) -> String {
-        let naive = NaiveDateTime::from_timestamp_opt(unixtime, 0)
-            .unwrap_or_else(|| NaiveDateTime::from_timestamp_opt(0, 0).unwrap());
-
-        if let Ok(tz) = selected_timezone.parse::<chrono_tz::Tz>() {
-            let datetime: DateTime<Utc> = DateTime::from_naive_utc_and_offset(naive, Utc);
-            let local_time = datetime.with_timezone(&tz);
-            format!("{}", local_time.format("%m/%d/%Y, %I:%M:%S %p"))
-        } else {
-            String::from("01/01/1970, 12:00:00 AM")
-        }
-    }
-
-    pub fn unixtime_to_datetime_str_form2(unixtime: i64, selected_timezone: String) -> String {
-        let naive = NaiveDateTime::from_timestamp_opt(unixtime, 0)
-            .unwrap_or_else(|| NaiveDateTime::from_timestamp_opt(0, 0).unwrap());
-
-        if let Ok(tz) = selected_timezone.parse::<chrono_tz::Tz>() {
-            let datetime: DateTime<Utc> = DateTime::from_naive_utc_and_offset(naive, Utc);
-            let local_time = datetime.with_timezone(&tz);
-            format!("{}", local_time.format("%b %d, %Y, %I:%M:%S %p"))
-        } else {
-            String::from("Jan 01, 1970, 12:00:00 AM")
-        }
-    }
-
-    pub fn unixtime_to_datetime_str_form3(unixtime: i64, selected_timezone: String) -> String {
-        let naive = NaiveDateTime::from_timestamp_opt(unixtime, 0)
-            .unwrap_or_else(|| NaiveDateTime::from_timestamp_opt(0, 0).unwrap());
-
-        if let Ok(tz) = selected_timezone.parse::<chrono_tz::Tz>() {
-            let datetime: DateTime<Utc> = DateTime::from_naive_utc_and_offset(naive, Utc);
-            let local_time = datetime.with_timezone(&tz);
-            format!("{}", local_time.format("%Y-%m-%dT%H:%M:%S"))
-        } else {
-            String::from("1970-01-01T00:00:00")
-        }
-    }
-
     pub fn fetch_time_zone_offset() -> (String, i32) {
         let js_value = getTimeZoneOffset(); // JavaScript 함수 호출
         let js_obj: js_sys::Object = js_value.into();
@@ -473,35 +408,4 @@ impl ToolUnixtime {
 
         (time_zone, offset_hour)
     }
-
-    // pub fn datetime_str_to_unixtime(datetime_str: String, timezone_offset: i32) -> Option<i64> {
-    //     let naive_datetime =
-    //         NaiveDateTime::parse_from_str(datetime_str.as_str(), "%Y-%m-%dT%H:%M:%S").ok()?;
-    //     let offset = FixedOffset::east_opt(timezone_offset * 3600)?;
-    //     let datetime_with_offset = offset.from_local_datetime(&naive_datetime).single()?;
-    //     Some(datetime_with_offset.timestamp())
-    // }
-
-    pub fn datetime_str_to_unixtime(
-        datetime_str: String,
-        selected_timezone: String,
-    ) -> Option<i64> {
-        let naive_datetime =
-            NaiveDateTime::parse_from_str(datetime_str.as_str(), "%Y-%m-%dT%H:%M:%S").ok()?;
-
-        // Parse the selected timezone
-        let tz = selected_timezone.parse::<chrono_tz::Tz>().ok()?;
-
-        // Get current offset for the timezone
-        let offset = tz
-            .offset_from_utc_datetime(&naive_datetime)
-            .fix()
-            .local_minus_utc();
-
-        // Create DateTime with offset
-        let fixed_offset = FixedOffset::east_opt(offset)?;
-        let datetime_with_offset = fixed_offset.from_local_datetime(&naive_datetime).single()?;
-
-        Some(datetime_with_offset.timestamp())
-    }
 }
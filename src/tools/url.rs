@@ -1,6 +1,5 @@
 use yew::prelude::*;
-use std::borrow::Cow;
-use urlencoding::{encode, decode};
+use computools_core::url::{decode_url, encode_url};
 use web_sys::{window, HtmlInputElement};
 use wasm_bindgen_futures::JsFuture;
 use log::info;
@@ -36,9 +35,9 @@ impl Component for ToolUrl {
                 self.input_text = text;
 
                 if self.mode == "encode" {
-                    self.output_text = encode(&self.input_text).into_owned();
+                    self.output_text = encode_url(&self.input_text);
                 } else {
-                    self.output_text = decode(&self.input_text).unwrap_or(Cow::from("Decoding Error")).into_owned();
+                    self.output_text = decode_url(&self.input_text).unwrap_or_else(|err| err);
                 }
                 true
             }
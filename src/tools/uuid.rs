@@ -1,5 +1,5 @@
 use js_sys;
-use uuid::Uuid;
+use computools_core::uuid::generate_v4;
 use wasm_bindgen_futures::{spawn_local, JsFuture};
 use web_sys::{window, HtmlInputElement, HtmlSelectElement};
 use yew::prelude::*;
@@ -20,14 +20,14 @@ impl Component for ToolUuid {
 
     fn create(_ctx: &Context<Self>) -> Self {
         Self {
-            uuid: Uuid::new_v4().to_string(),
+            uuid: generate_v4(),
         }
     }
 
     fn update(&mut self, _ctx: &Context<Self>, msg: Self::Message) -> bool {
         match msg {
             Msg::Generate => {
-                self.uuid = Uuid::new_v4().to_string();
+                self.uuid = generate_v4();
                 true // re-render
             }
             Msg::CopyToClipboard(value) => {